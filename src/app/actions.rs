@@ -1,58 +1,104 @@
+use std::path::PathBuf;
 use std::sync::atomic::Ordering;
+use std::sync::mpsc::{Receiver, TryRecvError};
 
-use chrono::Utc;
+use chrono::{Local, Utc};
 
-use crate::core::{Preset, SessionType, TimerEvent};
+use crate::core::{SessionType, TimerEvent, TimerState};
 use crate::data::{Config, ExportFormat, Exporter, Statistics};
-use crate::platform::SystemTray;
+use crate::integrations::{CompletedSession, PluginExport};
+use crate::ipc::IpcEventKind;
+use crate::platform::{NotificationAction, SystemTray};
 use crate::ui::settings::{SettingsAction, SettingsView};
-use crate::ui::stats::StatsAction;
-use crate::ui::theme::Theme;
+use crate::ui::stats::{StatsAction, StatsTab};
 use crate::ui::timer_view::TimerAction;
 
+use super::runtime_errors::RuntimeError;
 use super::PomodoRustApp;
 use super::View;
 
+/// A plugin export running in the background
+pub(super) struct PendingPluginExport {
+    plugin: String,
+    /// File the export goes to
+    path: PathBuf,
+    rx: Receiver<Result<(), String>>,
+}
+
 impl PomodoRustApp {
-    /// Handle timer completion
-    pub(super) fn on_timer_completed(&mut self) {
-        let session_type = self.session.session_type();
+    /// Handle timer completion of a session of the given type and length
+    pub(super) fn on_timer_completed(
+        &mut self,
+        session_type: SessionType,
+        duration: u64,
+        ctx: &egui::Context,
+    ) {
+        // Lift Do Not Disturb first so the completion notification shows
+        self.update_focus_mode();
+        self.publish_event(IpcEventKind::Completed, session_type, Some(duration));
 
         // Track if goal was reached before this session
-        let goal_was_reached_before = self
-            .statistics
-            .is_daily_goal_reached(self.config.goals.daily_target);
+        let goal_was_reached_before = self.statistics.is_daily_goal_reached(self.daily_target());
 
         // Record to database (link to current queue task if work session)
+        let mut completed = None;
+        let mut record_error = None;
         if let (Some(db), Some(start_time)) = (&self.database, self.session_start_time) {
-            let duration = self.session.timer().total_duration().as_secs();
-            let todo_id = if session_type == SessionType::Work {
-                db.get_current_queue_task()
-                    .ok()
-                    .flatten()
-                    .map(|t| t.todo_id)
+            let task = if session_type == SessionType::Work {
+                db.get_current_queue_task().ok().flatten()
             } else {
                 None
             };
-            if let Err(e) =
-                db.record_session(session_type, duration, duration, true, start_time, todo_id)
-            {
+            let todo_id = task.as_ref().map(|t| t.todo_id);
+            let tag = self.session_tag.take();
+            let intent = self.session_intent.take();
+            if let Err(e) = db.record_session(
+                session_type,
+                duration,
+                duration,
+                true,
+                start_time,
+                todo_id,
+                tag.as_deref(),
+                intent.as_deref(),
+            ) {
                 tracing::error!("Failed to record session: {e}");
+                record_error = Some(e.to_string());
             }
+            completed = Some(CompletedSession {
+                session_type,
+                start: start_time.with_timezone(&Local),
+                end: Local::now(),
+                minutes: duration / 60,
+                task: task.map(|t| t.title),
+                note: intent,
+                tag,
+            });
 
             // Reload statistics
-            self.statistics = Statistics::load(db);
+            self.statistics = Statistics::load_within(db, &self.config.working_hours);
+            self.reload_stats_tab();
+        }
+        if let Some(e) = record_error {
+            self.report_error(RuntimeError::RecordSession(e));
+        }
+        if let Some(completed) = completed {
+            self.log_daily_note(&completed);
+            self.integrations.record_session(&completed);
+            self.queue_notion_row(&completed);
         }
 
         // Check if goal was just reached
         let goal_just_reached = !goal_was_reached_before
-            && self
-                .statistics
-                .is_daily_goal_reached(self.config.goals.daily_target)
-            && session_type == SessionType::Work;
+            && self.statistics.is_daily_goal_reached(self.daily_target())
+            && session_type == SessionType::Work
+            && !self
+                .config
+                .goals
+                .is_rest_day(chrono::Local::now().date_naive());
 
         // Play sound
-        if self.config.sounds.enabled {
+        if self.sounds_audible() {
             if let Some(ref mut audio) = self.audio {
                 audio.play_notification(self.config.sounds.notification_sound);
             }
@@ -61,38 +107,57 @@ impl PomodoRustApp {
         // Show notification
         if self.config.system.notifications_enabled {
             let t = crate::i18n::tr();
-            let (title, body): (&str, String) =
-                if goal_just_reached && self.config.goals.notify_on_goal {
-                    (
-                        t.notif.daily_goal_reached,
-                        format!(
-                            "{} {}",
-                            self.config.goals.daily_target, t.settings.pomodoros
-                        ),
-                    )
-                } else {
-                    match session_type {
-                        SessionType::Work => {
-                            (t.notif.focus_complete, t.notif.time_for_break.to_string())
-                        }
-                        SessionType::ShortBreak => {
-                            (t.notif.break_over, t.notif.ready_to_focus.to_string())
-                        }
-                        SessionType::LongBreak => {
-                            (t.notif.long_break_over, t.notif.back_to_work.to_string())
-                        }
-                    }
+            let (title, body) = self.completion_text(session_type, goal_just_reached);
+
+            // The session has already moved on; offer buttons unless it auto-starts
+            let next = self.session.session_type();
+            let auto_start = match next {
+                SessionType::Work => self.config.timer.auto_start_work,
+                _ => self.config.timer.auto_start_breaks,
+            } && self.auto_start_allowed();
+            if auto_start {
+                crate::platform::show_notification(&title, &body);
+            } else {
+                let start_label = match next {
+                    SessionType::Work => t.notif.action_start_focus,
+                    _ => t.notif.action_start_break,
                 };
-            crate::platform::show_notification(title, &body);
+                let actions = [
+                    (NotificationAction::StartNext, start_label),
+                    (NotificationAction::Snooze, t.notif.action_snooze),
+                    (NotificationAction::Skip, t.notif.action_skip),
+                ];
+                let tx = self.notification_tx.clone();
+                let ctx = ctx.clone();
+                crate::platform::show_actionable_notification(&title, &body, &actions, move |a| {
+                    let _ = tx.send(a);
+                    ctx.request_repaint();
+                });
+            }
+        }
+        if self.integrations.notifies_complete() {
+            let (title, body) = self.completion_text(session_type, goal_just_reached);
+            self.integrations
+                .telegram_send(format!("{}\n{}", title, body));
         }
 
         // Flash window in taskbar to get attention
         crate::platform::flash_pomodorust_window(5);
+        if self.config.window.raise_on_complete {
+            self.raise_window(ctx);
+        }
 
         // Update pomodoro queue
         if session_type == SessionType::Work {
             if let Some(db) = &self.database {
                 if let Ok(Some(current)) = db.get_current_queue_task() {
+                    let minutes = (duration / 60) as u32;
+                    self.todoist_session_done(current.todo_id, minutes);
+                    self.taskwarrior_session_done(
+                        current.todo_id,
+                        current.completed_pomodoros + 1,
+                        minutes,
+                    );
                     if let Ok(all_done) = db.increment_queue_pomodoro(current.id) {
                         if all_done {
                             if let Err(e) = db.complete_queue_task(current.id, current.todo_id) {
@@ -108,8 +173,72 @@ impl PomodoRustApp {
         self.session_start_time = None;
     }
 
+    /// Record the focus time done so far as an interrupted session, when a
+    /// focus session is skipped, reset or cut short by quitting. Sessions
+    /// shorter than `timer.min_record_secs` aren't recorded, so skipping
+    /// through sessions doesn't fill the stats.
+    pub(super) fn record_unfinished_session(&mut self) {
+        if self.session.unfinished_focus().is_none() {
+            return;
+        }
+        let (Some(db), Some(start_time)) = (&self.database, self.session_start_time.take()) else {
+            return;
+        };
+        match db.record_unfinished_session(
+            &self.session,
+            start_time,
+            self.config.timer.min_record_secs as u64,
+            self.session_tag.as_deref(),
+            self.session_intent.as_deref(),
+        ) {
+            Ok(Some(elapsed)) => {
+                tracing::info!("Recorded {}s of the unfinished focus session", elapsed);
+                self.statistics = Statistics::load_within(db, &self.config.working_hours);
+                self.reload_stats_tab();
+            }
+            Ok(None) => {}
+            Err(e) => tracing::error!("Failed to record unfinished session: {e}"),
+        }
+    }
+
+    /// Handle an action from the timer view itself. Starting a fresh work
+    /// session there asks for an intent first when that is turned on;
+    /// starts from hotkeys, the tray or the CLI never wait on the prompt.
+    pub(super) fn handle_timer_view_action(&mut self, action: TimerAction) {
+        let fresh_work = self.session.session_type() == SessionType::Work
+            && self.session.timer().state() == TimerState::Idle;
+        if action == TimerAction::Toggle && self.config.timer.require_intent && fresh_work {
+            self.timer_view.ask_intent();
+        } else {
+            self.handle_timer_action(action);
+        }
+    }
+
+    /// Whether strict mode keeps the running focus session going
+    pub(super) fn strict_locked(&self) -> bool {
+        self.config.timer.strict
+            && self.session.session_type() == SessionType::Work
+            && self.session.timer().is_running()
+    }
+
+    /// Refuse to stop a focus session in strict mode, saying why
+    pub(super) fn strict_blocks(&mut self) -> bool {
+        let locked = self.strict_locked();
+        if locked {
+            self.show_status(crate::i18n::tr().notif.strict_focus);
+        }
+        locked
+    }
+
     /// Handle timer action
     pub(super) fn handle_timer_action(&mut self, action: TimerAction) {
+        let stops = matches!(
+            action,
+            TimerAction::Toggle | TimerAction::Skip | TimerAction::Reset
+        );
+        if stops && self.strict_blocks() {
+            return;
+        }
         match action {
             TimerAction::Toggle => {
                 let event = self.session.toggle();
@@ -118,10 +247,12 @@ impl PomodoRustApp {
                 }
             }
             TimerAction::Skip => {
+                self.record_unfinished_session();
                 self.session.skip();
                 self.session_start_time = None;
             }
             TimerAction::Reset => {
+                self.record_unfinished_session();
                 self.session.reset();
                 self.session_start_time = None;
             }
@@ -142,9 +273,37 @@ impl PomodoRustApp {
             TimerAction::OpenQueue => {
                 self.current_view = View::Queue;
             }
+            TimerAction::SetDuration { minutes } => {
+                let seconds = minutes as u64 * 60;
+                let timer = self.session.timer_mut();
+                if timer.total_duration().as_secs() != seconds {
+                    timer.reset_with_duration(seconds);
+                }
+            }
+            TimerAction::StartCustom { minutes } => {
+                self.start_one_off(self.session.session_type(), minutes);
+            }
+            TimerAction::StartWithIntent { intent } => {
+                self.session_intent = Some(intent);
+                self.handle_timer_action(TimerAction::Toggle);
+            }
         }
     }
 
+    /// Start a session of the given type with a one-off duration
+    fn start_one_off(&mut self, session_type: crate::core::SessionType, minutes: u32) {
+        // Switch to the requested session type, keeping the focus time done
+        self.record_unfinished_session();
+        self.session.switch_to(session_type);
+        // Reset timer with custom duration
+        self.session
+            .timer_mut()
+            .reset_with_duration(minutes as u64 * 60);
+        // Start the timer
+        self.session.start();
+        self.session_start_time = Some(Utc::now());
+    }
+
     /// Handle stats action
     pub(super) fn handle_stats_action(&mut self, action: StatsAction) {
         match action {
@@ -158,22 +317,19 @@ impl PomodoRustApp {
             StatsAction::QuickStart {
                 session_type,
                 minutes,
+                tag,
             } => {
-                // Switch to the requested session type
-                self.session.switch_to(session_type);
-                // Reset timer with custom duration
-                self.session
-                    .timer_mut()
-                    .reset_with_duration(minutes as u64 * 60);
-                // Start the timer
-                self.session.start();
-                self.session_start_time = Some(Utc::now());
+                self.start_one_off(session_type, minutes);
+                self.session_tag = tag;
                 // Go back to timer view
                 self.current_view = View::Timer;
             }
             StatsAction::Export { format } => {
                 self.export_statistics(format);
             }
+            StatsAction::PluginExport { export } => {
+                self.export_with_plugin(&export);
+            }
             StatsAction::UndoLastSession => {
                 self.undo_last_session();
             }
@@ -192,9 +348,92 @@ impl PomodoRustApp {
                         db.get_week_stats_for_date(reference).ok();
                 }
             }
+            StatsAction::ChangeMonth { offset } => {
+                self.stats_view.month_offset = offset;
+                self.load_month();
+            }
+            StatsAction::ShowHistory { day_offset } => {
+                self.stats_view.history_day_offset = day_offset;
+                self.load_history();
+            }
+            StatsAction::ChangeTagPeriod { period } => {
+                self.stats_view.tag_period = period;
+                self.load_tag_focus();
+            }
+        }
+    }
+
+    /// Reload the data of the open stats tab after the statistics changed
+    pub(super) fn reload_stats_tab(&mut self) {
+        match self.stats_view.tab {
+            StatsTab::Overview => {
+                self.load_today_sessions();
+                self.load_tag_focus();
+                self.load_plugin_panels();
+            }
+            StatsTab::Month => self.load_month(),
+            StatsTab::History => self.load_history(),
+        }
+    }
+
+    /// Load the daily hours for the month shown in the Month tab
+    fn load_month(&mut self) {
+        let Some(db) = &self.database else { return };
+        let month = self.stats_view.displayed_month();
+        self.stats_view.month_hours = db.get_month_stats_for_date(month).ok();
+    }
+
+    /// Load today's sessions for the Overview timeline
+    fn load_today_sessions(&mut self) {
+        let Some(db) = &self.database else { return };
+        let today = chrono::Local::now().date_naive();
+        self.stats_view.today_sessions = db.get_sessions_for_day(today).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load today's sessions: {}", e);
+            Vec::new()
+        });
+    }
+
+    /// Load the focus time per tag and per application for the Overview
+    /// breakdowns
+    pub(super) fn load_tag_focus(&mut self) {
+        let Some(db) = &self.database else { return };
+        let since = self.stats_view.tag_period.start();
+        self.stats_view.tag_focus = db.get_tag_focus_since(since).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load tag breakdown: {}", e);
+            Vec::new()
+        });
+        self.stats_view.app_focus = db.get_app_focus_since(since).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load focus time per application: {}", e);
+            Vec::new()
+        });
+    }
+
+    /// Have the plugins update their stats panels, and list their export
+    /// formats
+    fn load_plugin_panels(&mut self) {
+        let Some(plugins) = self.integrations.plugins() else {
+            self.stats_view.plugin_exports.clear();
+            return;
+        };
+        self.stats_view.plugin_exports = plugins.exports();
+        let Some(db) = &self.database else { return };
+        match Exporter::gather_data(db) {
+            Ok(data) => plugins.refresh_panels(&data),
+            Err(e) => tracing::warn!("Failed to load statistics for plugins: {}", e),
         }
     }
 
+    /// Load the session log for the day shown in the History tab
+    fn load_history(&mut self) {
+        let Some(db) = &self.database else { return };
+        let day = chrono::Local::now().date_naive()
+            + chrono::Duration::days(self.stats_view.history_day_offset as i64);
+        self.stats_view.history = db.get_sessions_for_day(day).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load session history: {}", e);
+            Vec::new()
+        });
+    }
+
     /// Reset all statistics
     fn reset_all_stats(&mut self) {
         let Some(db) = &self.database else {
@@ -206,12 +445,9 @@ impl PomodoRustApp {
             Ok(()) => {
                 tracing::info!("All statistics reset");
                 // Reload statistics
-                self.statistics = Statistics::load(db);
-                // Show notification
-                crate::platform::show_notification(
-                    crate::i18n::tr().notif.stats_reset,
-                    crate::i18n::tr().notif.stats_cleared,
-                );
+                self.statistics = Statistics::load_within(db, &self.config.working_hours);
+                self.reload_stats_tab();
+                self.offer_undo(crate::i18n::tr().notif.stats_cleared);
             }
             Err(e) => {
                 tracing::error!("Failed to reset statistics: {}", e);
@@ -230,12 +466,9 @@ impl PomodoRustApp {
             Ok(Some(session)) => {
                 tracing::info!("Undid session: {:?}", session);
                 // Reload statistics
-                self.statistics = Statistics::load(db);
-                // Show notification
-                crate::platform::show_notification(
-                    crate::i18n::tr().notif.session_undone,
-                    crate::i18n::tr().notif.session_removed,
-                );
+                self.statistics = Statistics::load_within(db, &self.config.working_hours);
+                self.reload_stats_tab();
+                self.offer_undo(crate::i18n::tr().notif.session_removed);
             }
             Ok(None) => {
                 tracing::info!("No session to undo");
@@ -247,7 +480,7 @@ impl PomodoRustApp {
     }
 
     /// Export statistics to file
-    fn export_statistics(&self, format: ExportFormat) {
+    fn export_statistics(&mut self, format: ExportFormat) {
         let Some(db) = &self.database else {
             tracing::error!("No database available for export");
             return;
@@ -280,11 +513,83 @@ impl PomodoRustApp {
                         crate::i18n::tr().notif.export_failed,
                         &format!("Error: {}", e),
                     );
+                    self.report_error(RuntimeError::Export(e.to_string()));
                 }
             }
         }
     }
 
+    /// Export statistics to file in a plugin's format. The plugin runs in
+    /// the background and `update_plugin_export` reports how it went.
+    fn export_with_plugin(&mut self, export: &PluginExport) {
+        if self.plugin_export.is_some() {
+            self.show_error("A plugin export is still running");
+            return;
+        }
+        let (Some(db), Some(plugins)) = (&self.database, self.integrations.plugins()) else {
+            return;
+        };
+
+        let default_filename = format!(
+            "pomodorust_stats_{}.{}",
+            chrono::Local::now().format("%Y%m%d_%H%M%S"),
+            export.extension
+        );
+        let file_dialog = rfd::FileDialog::new()
+            .set_title(crate::i18n::tr().notif.export_statistics)
+            .set_file_name(&default_filename)
+            .add_filter(&export.title, &[export.extension.as_str()]);
+        let Some(path) = file_dialog.save_file() else {
+            return;
+        };
+
+        match Exporter::gather_data(db) {
+            Ok(data) => {
+                let result = plugins.export(export, &data, path.clone());
+                self.plugin_export = Some(PendingPluginExport {
+                    plugin: export.plugin.clone(),
+                    path,
+                    rx: result,
+                });
+            }
+            Err(e) => self.plugin_export_failed(e.to_string()),
+        }
+    }
+
+    /// Report the plugin export once the plugin is done, or has timed out
+    pub(super) fn update_plugin_export(&mut self) {
+        let Some(pending) = &self.plugin_export else {
+            return;
+        };
+        let result = match pending.rx.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => Err("The plugin stopped".to_string()),
+        };
+        let Some(PendingPluginExport { plugin, path, .. }) = self.plugin_export.take() else {
+            return;
+        };
+        match result {
+            Ok(()) => {
+                tracing::info!("Statistics exported to {:?} by plugin {}", path, plugin);
+                crate::platform::show_notification(
+                    crate::i18n::tr().notif.export_complete,
+                    &format!("Statistics saved to {}", path.display()),
+                );
+            }
+            Err(e) => self.plugin_export_failed(e),
+        }
+    }
+
+    fn plugin_export_failed(&mut self, e: String) {
+        tracing::error!("Plugin export failed: {}", e);
+        crate::platform::show_notification(
+            crate::i18n::tr().notif.export_failed,
+            &format!("Error: {}", e),
+        );
+        self.report_error(RuntimeError::Export(e));
+    }
+
     /// Handle settings action
     pub(super) fn handle_settings_action(&mut self, action: SettingsAction, ctx: &egui::Context) {
         match action {
@@ -296,17 +601,20 @@ impl PomodoRustApp {
                 self.apply_config(new_config, ctx);
             }
             SettingsAction::SelectPreset(index) => {
-                let presets = [Preset::classic(), Preset::short(), Preset::long()];
                 let t = crate::i18n::tr();
                 let preset_names = [
                     t.settings.preset_classic,
                     t.settings.preset_short,
                     t.settings.preset_long,
                 ];
-                if let Some(preset) = presets.get(index) {
-                    self.config.apply_preset(preset);
-                    self.session.set_preset(preset.clone());
-                    let _ = self.config.save();
+                if let Some(preset) = self.config.presets.preset(index) {
+                    self.config.apply_preset(&preset);
+                    self.session.set_preset(preset);
+                    self.session.set_auto_start(
+                        self.config.timer.auto_start_breaks,
+                        self.config.timer.auto_start_work,
+                    );
+                    self.save_config();
 
                     if let Some(ref mut sv) = self.settings_view {
                         sv.reset_from_config(&self.config);
@@ -319,20 +627,15 @@ impl PomodoRustApp {
             }
             SettingsAction::ResetDefaults => {
                 self.config.reset();
-                let _ = self.config.save();
+                self.save_config();
 
                 self.session.set_preset(self.config.to_preset());
-                self.theme = Theme::from_mode(
-                    self.config.appearance.theme_mode,
-                    self.config.appearance.accent_color,
-                );
-                if self.config.accessibility.high_contrast {
-                    self.theme = self.theme.clone().with_high_contrast();
-                }
-                if self.config.accessibility.reduced_motion {
-                    self.theme = self.theme.clone().with_reduced_motion();
-                }
+                self.theme = Self::theme_for(&self.config);
                 self.todo_theme_dirty = true;
+                self.stats_view.quick_start = self.config.quick_start.entries.clone();
+                if let Some(ref mut audio) = self.audio {
+                    audio.set_custom_sounds(None, None);
+                }
 
                 // Reset language to auto
                 crate::i18n::set_language(self.config.appearance.language);
@@ -350,35 +653,49 @@ impl PomodoRustApp {
             }
             SettingsAction::TestSound(sound) => {
                 if let Some(ref mut audio) = self.audio {
-                    audio.play_notification(sound);
+                    audio.play_built_in(sound);
+                }
+            }
+            SettingsAction::TestSoundFile(path) => {
+                if let Some(ref mut audio) = self.audio {
+                    audio.play_file(&path);
                 }
             }
+            SettingsAction::SaveProfile(name) => {
+                self.save_profile(&name, ctx);
+            }
+            SettingsAction::UseProfile(name) => {
+                self.use_profile(&name, ctx);
+            }
+            SettingsAction::DeleteProfile(name) => {
+                self.delete_profile(&name, ctx);
+            }
         }
     }
 
     /// Apply new configuration
-    fn apply_config(&mut self, new_config: Config, ctx: &egui::Context) {
+    pub(super) fn apply_config(&mut self, new_config: Config, ctx: &egui::Context) {
+        self.reconfigure(new_config, ctx);
+        self.show_status(crate::i18n::tr().notif.settings_saved);
+    }
+
+    /// Switch to a new configuration and save it, without telling the user
+    pub(super) fn reconfigure(&mut self, new_config: Config, ctx: &egui::Context) {
         // Check if language changed
         if new_config.appearance.language != self.config.appearance.language {
             crate::i18n::set_language(new_config.appearance.language);
+            #[cfg(windows)]
+            Self::register_jump_list();
         }
 
         // Check if theme changed
         if new_config.appearance.theme_mode != self.config.appearance.theme_mode
             || new_config.appearance.accent_color != self.config.appearance.accent_color
+            || new_config.appearance.theme_schedule != self.config.appearance.theme_schedule
             || new_config.accessibility.high_contrast != self.config.accessibility.high_contrast
             || new_config.accessibility.reduced_motion != self.config.accessibility.reduced_motion
         {
-            self.theme = Theme::from_mode(
-                new_config.appearance.theme_mode,
-                new_config.appearance.accent_color,
-            );
-            if new_config.accessibility.high_contrast {
-                self.theme = self.theme.clone().with_high_contrast();
-            }
-            if new_config.accessibility.reduced_motion {
-                self.theme = self.theme.clone().with_reduced_motion();
-            }
+            self.theme = Self::theme_for(&new_config);
             self.todo_theme_dirty = true;
         }
 
@@ -389,6 +706,15 @@ impl PomodoRustApp {
             || new_config.timer.sessions_before_long != self.config.timer.sessions_before_long
         {
             self.session.set_preset(new_config.to_preset());
+            self.day_plan_date = None;
+        }
+
+        // Today's totals follow the working hours
+        let reload_stats = new_config.working_hours != self.config.working_hours;
+
+        // Take up today's plan again at the next idle moment
+        if new_config.weekdays != self.config.weekdays {
+            self.day_plan_date = None;
         }
 
         // Update auto-start
@@ -397,14 +723,22 @@ impl PomodoRustApp {
             new_config.timer.auto_start_work,
         );
 
-        // Update audio volume
+        // Update audio volume and the user's sound files
         if let Some(ref mut audio) = self.audio {
             audio.set_volume(new_config.sounds.volume as f32 / 100.0);
+            if new_config.sounds.custom_notification != self.config.sounds.custom_notification
+                || new_config.sounds.custom_tick != self.config.sounds.custom_tick
+            {
+                audio.set_custom_sounds(
+                    new_config.sounds.custom_notification.as_deref(),
+                    new_config.sounds.custom_tick.as_deref(),
+                );
+            }
         }
 
         // Update autostart
         if new_config.system.start_with_windows != self.config.system.start_with_windows {
-            let _ = crate::platform::set_autostart(new_config.system.start_with_windows);
+            self.apply_autostart(new_config.system.start_with_windows);
         }
 
         // Update always on top
@@ -423,14 +757,58 @@ impl PomodoRustApp {
         }
 
         // Sync system tray
-        if new_config.system.minimize_to_tray && self.system_tray.is_none() {
+        if new_config.system.wants_tray() && self.system_tray.is_none() {
             self.system_tray = SystemTray::new().ok();
-        } else if !new_config.system.minimize_to_tray && self.system_tray.is_some() {
+        } else if !new_config.system.wants_tray() && self.system_tray.is_some() {
             self.system_tray = None;
         }
 
+        // Restart integrations whose settings changed
+        self.integrations.reconfigure(&new_config);
+        let plugins_changed = new_config.plugins != self.config.plugins;
+        if new_config.todoist != self.config.todoist {
+            self.todoist = None;
+        }
+        if new_config.notion != self.config.notion {
+            self.notion = None;
+        }
+        if new_config.email != self.config.email {
+            self.mailer = None;
+            self.report_week = None;
+        }
+        if new_config.taskwarrior != self.config.taskwarrior {
+            self.taskwarrior = None;
+            self.taskwarrior_active = None;
+        }
+        if new_config.calendar != self.config.calendar {
+            self.calendar = None;
+        }
+
+        let old_hotkeys = &self.config.hotkeys;
+        let hotkeys_changed = new_config.hotkeys.enabled != old_hotkeys.enabled
+            || new_config.hotkeys.toggle != old_hotkeys.toggle
+            || new_config.hotkeys.skip != old_hotkeys.skip
+            || new_config.hotkeys.reset != old_hotkeys.reset
+            || new_config.hotkeys.mute != old_hotkeys.mute;
+
+        self.stats_view.quick_start = new_config.quick_start.entries.clone();
         self.config = new_config;
-        let _ = self.config.save();
-        self.show_status(crate::i18n::tr().notif.settings_saved);
+
+        // Re-register global hotkeys without a restart
+        if hotkeys_changed {
+            self.restart_hotkeys();
+        }
+        if let Some(ref mut db) = self.database {
+            db.set_rest_days(self.config.goals.rest_days);
+        }
+        if reload_stats {
+            if let Some(ref db) = self.database {
+                self.statistics = Statistics::load_within(db, &self.config.working_hours);
+            }
+        }
+        if plugins_changed {
+            self.load_plugin_panels();
+        }
+        self.save_config();
     }
 }
@@ -0,0 +1,134 @@
+//! Focus time per application, while `system.track_apps` is on
+//!
+//! During a running focus session a background thread samples the
+//! application in the foreground every few seconds and credits it with the
+//! time since the last sample. The totals go to the database once a minute
+//! and when the session stops, per day. Only application names are kept,
+//! never window titles, and nothing leaves this computer.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::{Local, NaiveDate};
+
+use super::{PomodoRustApp, View};
+use crate::core::SessionType;
+use crate::data::Database;
+
+/// How often the application in the foreground is sampled
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Longest gap between samples still counted, so time the computer spent
+/// asleep isn't credited to the application that was in front before
+const MAX_SAMPLE_GAP: Duration = Duration::from_secs(15);
+
+/// How often the counted time is written to the database
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Background sampler of the application in the foreground
+pub(super) struct AppUsageMonitor {
+    /// Whether a focus session is running, so samples count
+    counting: Arc<AtomicBool>,
+    /// Time per day and application not saved yet
+    unsaved: Arc<Mutex<HashMap<(NaiveDate, String), Duration>>>,
+    running: Arc<AtomicBool>,
+    last_save: Instant,
+}
+
+impl AppUsageMonitor {
+    /// Start sampling on a background thread
+    pub fn start() -> Self {
+        let counting = Arc::new(AtomicBool::new(false));
+        let unsaved = Arc::new(Mutex::new(HashMap::new()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let (count, totals, active) = (counting.clone(), unsaved.clone(), running.clone());
+        let spawned = thread::Builder::new()
+            .name("app-usage".into())
+            .spawn(move || {
+                let mut last_sample = Instant::now();
+                while active.load(Ordering::Relaxed) {
+                    thread::sleep(SAMPLE_INTERVAL);
+                    let elapsed = last_sample.elapsed();
+                    last_sample = Instant::now();
+                    if !count.load(Ordering::Relaxed) || elapsed > MAX_SAMPLE_GAP {
+                        continue;
+                    }
+                    if let Some(app) = crate::platform::focused_app() {
+                        let today = Local::now().date_naive();
+                        if let Ok(mut totals) = totals.lock() {
+                            *totals.entry((today, app)).or_default() += elapsed;
+                        }
+                    }
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start application tracking: {}", e);
+        }
+
+        Self {
+            counting,
+            unsaved,
+            running,
+            last_save: Instant::now(),
+        }
+    }
+
+    /// Count samples or not, returning whether counting just stopped
+    fn set_counting(&self, counting: bool) -> bool {
+        self.counting.swap(counting, Ordering::Relaxed) && !counting
+    }
+
+    /// Write the time counted so far to the database, on the day it was
+    /// counted
+    fn save(&mut self, db: &Database) {
+        self.last_save = Instant::now();
+        let unsaved = match self.unsaved.lock() {
+            Ok(mut unsaved) => std::mem::take(&mut *unsaved),
+            Err(_) => return,
+        };
+        for ((day, app), time) in unsaved {
+            if let Err(e) = db.add_app_focus(day, &app, time.as_secs()) {
+                tracing::warn!("Failed to save focus time in {}: {}", app, e);
+            }
+        }
+    }
+}
+
+impl Drop for AppUsageMonitor {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl PomodoRustApp {
+    /// Start or stop tracking with the config, count while focusing and save
+    /// the time counted now and then
+    pub(super) fn update_app_usage(&mut self) {
+        if !self.config.system.track_apps {
+            self.save_app_usage();
+            self.app_usage = None;
+            return;
+        }
+        let monitor = self.app_usage.get_or_insert_with(AppUsageMonitor::start);
+        let focusing =
+            self.session.session_type() == SessionType::Work && self.session.timer().is_running();
+        let stopped = monitor.set_counting(focusing);
+        if stopped || (focusing && monitor.last_save.elapsed() >= SAVE_INTERVAL) {
+            self.save_app_usage();
+            if self.current_view == View::Stats {
+                self.load_tag_focus();
+            }
+        }
+    }
+
+    /// Write the time counted so far to the database
+    pub(super) fn save_app_usage(&mut self) {
+        if let (Some(monitor), Some(db)) = (&mut self.app_usage, &self.database) {
+            monitor.save(db);
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Following the OS light/dark appearance or the time of day
+//!
+//! While the theme mode is "Auto" the system appearance is polled on a
+//! background thread (detection reads the registry on Windows and shells out
+//! on Linux and macOS) and the theme is rebuilt whenever it flips.
+//!
+//! In "By time of day" mode the theme is light between two fixed times, or
+//! between sunrise and sunset estimated from the date and a configured
+//! latitude. Solar noon is taken as 12:00 standard time, which is good to
+//! within an hour or so for most time zones.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Local, NaiveTime, TimeZone, Timelike};
+
+use crate::data::{Config, ThemeScheduleConfig};
+use crate::ui::theme::{AccentColor, Theme, ThemeMode};
+
+use super::PomodoRustApp;
+
+/// How often the system appearance is checked
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the time of day is checked in "By time of day" mode
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+const MINUTES_PER_DAY: i32 = 24 * 60;
+
+/// `"HH:MM"` as minutes after midnight
+fn parse_time(text: &str) -> Option<i32> {
+    let time = NaiveTime::parse_from_str(text.trim(), "%H:%M").ok()?;
+    Some((time.hour() * 60 + time.minute()) as i32)
+}
+
+/// Estimated sunrise and sunset, in minutes after local midnight, on day
+/// `ordinal` of the year at `latitude` degrees. `noon` is the solar noon.
+fn sun_times(ordinal: u32, latitude: f32, noon: i32) -> (i32, i32) {
+    let declination = (-23.44f32).to_radians()
+        * ((360.0 / 365.0) * (ordinal as f32 + 10.0))
+            .to_radians()
+            .cos();
+    // Clamped for the polar day and night
+    let cos_hour_angle = (-latitude.to_radians().tan() * declination.tan()).clamp(-1.0, 1.0);
+    // The sun moves 15 degrees an hour, so 4 minutes a degree
+    let half_day = (cos_hour_angle.acos().to_degrees() * 4.0).round() as i32;
+    (noon - half_day, noon + half_day)
+}
+
+/// Minutes the clock is ahead of standard time, i.e. daylight saving time
+fn daylight_saving_shift(now: &DateTime<Local>) -> i32 {
+    let offset_on = |month| {
+        Local
+            .with_ymd_and_hms(now.year(), month, 1, 12, 0, 0)
+            .single()
+            .map(|date| date.offset().local_minus_utc())
+    };
+    let current = now.offset().local_minus_utc();
+    let standard = [offset_on(1), offset_on(7)]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(current);
+    (current - standard) / 60
+}
+
+/// Whether `minute` falls between `light_from` and `dark_from`, which may
+/// wrap around midnight
+fn in_light_window(minute: i32, light_from: i32, dark_from: i32) -> bool {
+    if light_from <= dark_from {
+        (light_from..dark_from).contains(&minute)
+    } else {
+        minute >= light_from || minute < dark_from
+    }
+}
+
+/// Whether the schedule calls for the light theme at `now`
+fn schedule_is_light(schedule: &ThemeScheduleConfig, now: &DateTime<Local>) -> bool {
+    let minute = (now.hour() * 60 + now.minute()) as i32;
+    let (light_from, dark_from) = if schedule.follow_sun {
+        let noon = MINUTES_PER_DAY / 2 + daylight_saving_shift(now);
+        let (sunrise, sunset) = sun_times(now.ordinal(), schedule.latitude, noon);
+        // Midnight sun or polar night
+        if sunset - sunrise >= MINUTES_PER_DAY {
+            return true;
+        }
+        if sunset <= sunrise {
+            return false;
+        }
+        (sunrise, sunset)
+    } else {
+        let defaults = ThemeScheduleConfig::default();
+        (
+            parse_time(&schedule.light_at)
+                .or_else(|| parse_time(&defaults.light_at))
+                .unwrap_or_default(),
+            parse_time(&schedule.dark_at)
+                .or_else(|| parse_time(&defaults.dark_at))
+                .unwrap_or_default(),
+        )
+    };
+    in_light_window(minute, light_from, dark_from)
+}
+
+/// The plain light or dark theme
+fn light_or_dark(is_light: bool, accent: AccentColor) -> Theme {
+    if is_light {
+        Theme::new_light(accent)
+    } else {
+        Theme::new(accent)
+    }
+}
+
+/// Background sampler of the system light/dark setting
+pub(super) struct SystemThemeWatcher {
+    light: Arc<AtomicBool>,
+    running: Arc<AtomicBool>,
+}
+
+impl SystemThemeWatcher {
+    /// Start polling, waking the UI when the appearance changes
+    pub fn start(initial_light: bool, ctx: egui::Context) -> Self {
+        let light = Arc::new(AtomicBool::new(initial_light));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let (sample, active) = (light.clone(), running.clone());
+        let spawned = thread::Builder::new()
+            .name("theme-watcher".into())
+            .spawn(move || {
+                while active.load(Ordering::Relaxed) {
+                    thread::sleep(POLL_INTERVAL);
+                    let is_light = crate::platform::system_uses_light_theme();
+                    if sample.swap(is_light, Ordering::Relaxed) != is_light {
+                        ctx.request_repaint();
+                    }
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start theme watcher: {}", e);
+        }
+
+        Self { light, running }
+    }
+
+    /// Whether the system currently prefers a light appearance
+    pub fn is_light(&self) -> bool {
+        self.light.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for SystemThemeWatcher {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl PomodoRustApp {
+    /// Build the theme described by the appearance and accessibility settings
+    pub(super) fn theme_for(config: &Config) -> Theme {
+        let appearance = &config.appearance;
+        let theme = match appearance.theme_mode {
+            ThemeMode::Schedule => light_or_dark(
+                schedule_is_light(&appearance.theme_schedule, &Local::now()),
+                appearance.accent_color,
+            ),
+            mode => Theme::from_mode(mode, appearance.accent_color),
+        };
+        Self::with_accessibility(theme, config)
+    }
+
+    /// Apply the high contrast and reduced motion settings to a theme
+    fn with_accessibility(mut theme: Theme, config: &Config) -> Theme {
+        if config.accessibility.high_contrast {
+            theme = theme.with_high_contrast();
+        }
+        if config.accessibility.reduced_motion {
+            theme = theme.with_reduced_motion();
+        }
+        theme
+    }
+
+    /// Switch between the light and dark theme as the OS appearance or the
+    /// time of day changes, in the modes that follow them
+    pub(super) fn follow_auto_theme(&mut self, ctx: &egui::Context) {
+        match self.config.appearance.theme_mode {
+            ThemeMode::System => self.follow_system_theme(ctx),
+            ThemeMode::Schedule => {
+                self.theme_watcher = None;
+                let is_light =
+                    schedule_is_light(&self.config.appearance.theme_schedule, &Local::now());
+                if is_light != self.theme.is_light {
+                    tracing::info!(
+                        "Scheduled switch to the {} theme",
+                        if is_light { "light" } else { "dark" }
+                    );
+                    self.set_light_theme(is_light);
+                }
+                ctx.request_repaint_after(SCHEDULE_INTERVAL);
+            }
+            _ => self.theme_watcher = None,
+        }
+    }
+
+    /// Rebuild the plain light or dark theme
+    fn set_light_theme(&mut self, is_light: bool) {
+        let theme = light_or_dark(is_light, self.config.appearance.accent_color);
+        self.theme = Self::with_accessibility(theme, &self.config);
+        self.todo_theme_dirty = true;
+    }
+
+    fn follow_system_theme(&mut self, ctx: &egui::Context) {
+        let is_light = match &self.theme_watcher {
+            Some(watcher) => watcher.is_light(),
+            None => {
+                self.theme_watcher =
+                    Some(SystemThemeWatcher::start(self.theme.is_light, ctx.clone()));
+                return;
+            }
+        };
+
+        if is_light != self.theme.is_light {
+            tracing::info!(
+                "System appearance changed to {}",
+                if is_light { "light" } else { "dark" }
+            );
+            // Use the watcher's sample rather than detecting again
+            self.set_light_theme(is_light);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equinox_at_equator_is_twelve_hours() {
+        let (sunrise, sunset) = sun_times(80, 0.0, 12 * 60);
+        assert!((sunrise - 6 * 60).abs() <= 5, "sunrise {}", sunrise);
+        assert!((sunset - 18 * 60).abs() <= 5, "sunset {}", sunset);
+    }
+
+    #[test]
+    fn test_summer_days_are_longer_in_the_north() {
+        let (summer_rise, summer_set) = sun_times(172, 50.0, 12 * 60);
+        let (winter_rise, winter_set) = sun_times(355, 50.0, 12 * 60);
+        assert!(summer_set - summer_rise > 15 * 60);
+        assert!(winter_set - winter_rise < 9 * 60);
+    }
+
+    #[test]
+    fn test_light_window_wraps_midnight() {
+        assert!(in_light_window(8 * 60, 7 * 60, 19 * 60));
+        assert!(!in_light_window(20 * 60, 7 * 60, 19 * 60));
+        // Light from 22:00 to 06:00
+        assert!(in_light_window(23 * 60, 22 * 60, 6 * 60));
+        assert!(in_light_window(60, 22 * 60, 6 * 60));
+        assert!(!in_light_window(12 * 60, 22 * 60, 6 * 60));
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(parse_time("07:30"), Some(450));
+        assert_eq!(parse_time(" 19:00 "), Some(1140));
+        assert_eq!(parse_time("7pm"), None);
+    }
+}
@@ -0,0 +1,131 @@
+//! Image behind the timer view
+//!
+//! The picture is decoded, scaled down and blurred once, then kept as a
+//! texture until the path or the blur setting changes. A translucent layer
+//! of the window background dims it so the timer stays readable.
+
+use std::path::{Path, PathBuf};
+
+use egui::{Color32, Rect, Rounding, TextureHandle, Ui};
+
+use super::PomodoRustApp;
+use crate::ui::theme::Theme;
+
+/// Largest side kept after decoding, bigger pictures are scaled down
+const MAX_SIDE: u32 = 1600;
+
+/// Loaded background and the settings it was built from
+pub(super) struct BackgroundImage {
+    path: PathBuf,
+    blur: u32,
+    /// `None` when the file couldn't be read, so it isn't retried every frame
+    texture: Option<TextureHandle>,
+}
+
+impl BackgroundImage {
+    fn load(ctx: &egui::Context, path: &Path, blur: u32) -> Self {
+        let texture = match decode(path, blur) {
+            Ok(image) => Some(ctx.load_texture("background_image", image, Default::default())),
+            Err(e) => {
+                tracing::warn!("Failed to load background image {:?}: {}", path, e);
+                None
+            }
+        };
+        Self {
+            path: path.to_path_buf(),
+            blur,
+            texture,
+        }
+    }
+}
+
+fn decode(path: &Path, blur: u32) -> Result<egui::ColorImage, image::ImageError> {
+    let mut image = image::open(path)?;
+    if image.width() > MAX_SIDE || image.height() > MAX_SIDE {
+        image = image.thumbnail(MAX_SIDE, MAX_SIDE);
+    }
+    if blur > 0 {
+        image = image.fast_blur(blur as f32);
+    }
+    let rgba = image.into_rgba8();
+    let size = [rgba.width() as usize, rgba.height() as usize];
+    Ok(egui::ColorImage::from_rgba_unmultiplied(
+        size,
+        rgba.as_raw(),
+    ))
+}
+
+/// Part of the texture that covers `rect` without stretching, centered
+fn cover_uv(texture_size: egui::Vec2, rect: Rect) -> Rect {
+    let image_aspect = texture_size.x / texture_size.y.max(1.0);
+    let rect_aspect = rect.width() / rect.height().max(1.0);
+    if image_aspect > rect_aspect {
+        // Wider than the window, crop the sides
+        let visible = rect_aspect / image_aspect;
+        let margin = (1.0 - visible) / 2.0;
+        Rect::from_min_max(egui::pos2(margin, 0.0), egui::pos2(1.0 - margin, 1.0))
+    } else {
+        let visible = image_aspect / rect_aspect;
+        let margin = (1.0 - visible) / 2.0;
+        Rect::from_min_max(egui::pos2(0.0, margin), egui::pos2(1.0, 1.0 - margin))
+    }
+}
+
+impl PomodoRustApp {
+    /// Paint the configured background image over `rect`, loading it first
+    /// if needed. Does nothing when no image is set.
+    pub(super) fn paint_background(&mut self, ui: &Ui, rect: Rect, rounding: Rounding) {
+        let appearance = &self.config.appearance;
+        let Some(path) = &appearance.background_image else {
+            self.background = None;
+            return;
+        };
+
+        let blur = appearance.background_blur;
+        let stale = self
+            .background
+            .as_ref()
+            .is_none_or(|bg| bg.path != *path || bg.blur != blur);
+        if stale {
+            self.background = Some(BackgroundImage::load(ui.ctx(), path, blur));
+        }
+        let Some(texture) = self.background.as_ref().and_then(|bg| bg.texture.as_ref()) else {
+            return;
+        };
+
+        let mut shape = egui::epaint::RectShape::filled(rect, rounding, Color32::WHITE);
+        shape.fill_texture_id = texture.id();
+        shape.uv = cover_uv(texture.size_vec2(), rect);
+        ui.painter().add(shape);
+
+        let dim = (appearance.background_dim.min(100) * 255 / 100) as u8;
+        if dim > 0 {
+            ui.painter().rect_filled(
+                rect,
+                rounding,
+                Theme::with_alpha(self.theme.bg_primary, dim),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cover_uv_crops_wide_image() {
+        let rect = Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0));
+        let uv = cover_uv(egui::vec2(200.0, 100.0), rect);
+        assert_eq!(uv.min, egui::pos2(0.25, 0.0));
+        assert_eq!(uv.max, egui::pos2(0.75, 1.0));
+    }
+
+    #[test]
+    fn test_cover_uv_crops_tall_image() {
+        let rect = Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 50.0));
+        let uv = cover_uv(egui::vec2(100.0, 100.0), rect);
+        assert_eq!(uv.min, egui::pos2(0.0, 0.25));
+        assert_eq!(uv.max, egui::pos2(1.0, 0.75));
+    }
+}
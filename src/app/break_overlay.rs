@@ -0,0 +1,100 @@
+//! Full-screen break reminder
+//!
+//! While a break is running (and `[break_overlay]` is enabled) every
+//! connected display is covered by an always-on-top overlay showing the time
+//! left, so the break can't be dodged by looking at another monitor.
+
+use crate::core::SessionType;
+use crate::platform::MonitorRect;
+use crate::ui::timer_view::TimerAction;
+
+use super::PomodoRustApp;
+
+impl PomodoRustApp {
+    /// Show or hide the break overlays
+    pub(super) fn render_break_overlay(&mut self, ctx: &egui::Context) {
+        let on_break =
+            self.session.session_type() != SessionType::Work && self.session.timer().is_running();
+        if !self.config.break_overlay.enabled || !on_break {
+            // Re-detect displays next time, they may have changed
+            self.overlay_monitors = None;
+            return;
+        }
+
+        let monitors = self
+            .overlay_monitors
+            .get_or_insert_with(crate::platform::monitor_rects)
+            .clone();
+        let ppp = ctx.native_pixels_per_point().unwrap_or(1.0);
+
+        let t = crate::i18n::tr();
+        let remaining = self.session.timer().remaining_formatted();
+        let (bg, text, muted) = (
+            self.theme.bg_base,
+            self.theme.text_primary,
+            self.theme.text_secondary,
+        );
+        let allow_skip = self.config.break_overlay.allow_skip;
+        let mut skip = false;
+
+        // Without display detection, cover the main window's monitor
+        let targets: Vec<Option<MonitorRect>> = if monitors.is_empty() {
+            vec![None]
+        } else {
+            monitors.into_iter().map(Some).collect()
+        };
+
+        for (index, monitor) in targets.into_iter().enumerate() {
+            let mut builder = egui::ViewportBuilder::default()
+                .with_title("PomodoRust - Break")
+                .with_decorations(false)
+                .with_fullscreen(true)
+                .with_window_level(egui::WindowLevel::AlwaysOnTop)
+                .with_taskbar(false);
+            // Fullscreen windows take the display they are placed on
+            if let Some(monitor) = monitor {
+                builder = builder.with_position([
+                    (monitor.x as f32 + monitor.width as f32 / 2.0) / ppp,
+                    (monitor.y as f32 + monitor.height as f32 / 2.0) / ppp,
+                ]);
+            }
+
+            let id = egui::ViewportId::from_hash_of(("break_overlay", index));
+            ctx.show_viewport_immediate(id, builder, |ctx, _class| {
+                egui::CentralPanel::default()
+                    .frame(egui::Frame::none().fill(bg))
+                    .show(ctx, |ui| {
+                        ui.vertical_centered(|ui| {
+                            ui.add_space(ui.available_height() / 3.0);
+                            ui.label(
+                                egui::RichText::new(t.timer.break_overlay_title)
+                                    .size(32.0)
+                                    .color(text),
+                            );
+                            ui.add_space(8.0);
+                            ui.label(
+                                egui::RichText::new(remaining.as_str())
+                                    .size(96.0)
+                                    .color(text),
+                            );
+                            ui.label(
+                                egui::RichText::new(t.timer.break_overlay_hint)
+                                    .size(18.0)
+                                    .color(muted),
+                            );
+                            ui.add_space(32.0);
+                            if allow_skip && ui.button(t.timer.skip).clicked() {
+                                skip = true;
+                            }
+                        });
+                    });
+            });
+        }
+
+        if skip {
+            self.handle_timer_action(TimerAction::Skip);
+        }
+        // Keep the countdown ticking even when the main window is hidden
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,78 @@
+//! Floating timer bubble
+//!
+//! The countdown popped out into a separate small always-on-top viewport so
+//! it stays visible while the main window shows stats or settings. It is
+//! opened from the timer view's context menu or with `B`, clicking the ring
+//! toggles the timer and double-clicking docks it back into the main window.
+
+use super::mini_mode::{CompactAction, MINI_SIZE};
+use super::{PomodoRustApp, View};
+use crate::ui::timer_view::TimerAction;
+
+impl PomodoRustApp {
+    /// Open or close the floating bubble
+    pub(super) fn toggle_bubble(&mut self) {
+        self.bubble_open = !self.bubble_open;
+    }
+
+    /// Draw the floating bubble viewport while it is open
+    pub(super) fn show_bubble_viewport(&mut self, ctx: &egui::Context) {
+        // The mini widget already is the bubble, and nothing goes over the
+        // kiosk screen
+        if !self.bubble_open || self.config.window.mini_mode || self.kiosk {
+            return;
+        }
+
+        let mut builder = egui::ViewportBuilder::default()
+            .with_title("PomodoRust - Timer")
+            .with_inner_size(MINI_SIZE)
+            .with_decorations(false)
+            .with_transparent(true)
+            .with_resizable(false)
+            .with_taskbar(false)
+            .with_window_level(egui::WindowLevel::AlwaysOnTop);
+        if let (Some(x), Some(y)) = (self.config.window.bubble_x, self.config.window.bubble_y) {
+            builder = builder.with_position([x, y]);
+        }
+
+        let id = egui::ViewportId::from_hash_of("timer_bubble");
+        let (action, closed, pos) = ctx.show_viewport_immediate(id, builder, |ctx, _class| {
+            let hint = crate::i18n::tr().common.bubble_hint;
+            let action = self.show_compact_timer(ctx, hint);
+            ctx.input(|i| {
+                (
+                    action,
+                    i.viewport().close_requested(),
+                    i.viewport().outer_rect.map(|rect| rect.min),
+                )
+            })
+        });
+
+        if let Some(pos) = pos {
+            self.bubble_pos = Some(pos);
+        }
+        match action {
+            Some(CompactAction::Toggle) => self.handle_timer_action(TimerAction::Toggle),
+            Some(CompactAction::Restore) => {
+                self.bubble_open = false;
+                self.current_view = View::Timer;
+                self.settings_view = None;
+                self.raise_window(ctx);
+            }
+            None if closed => self.bubble_open = false,
+            None => {}
+        }
+        if !self.bubble_open {
+            self.remember_bubble_position();
+            self.save_config();
+        }
+    }
+
+    /// Store where the bubble was left so it reopens there
+    pub(super) fn remember_bubble_position(&mut self) {
+        if let Some(pos) = self.bubble_pos.take() {
+            self.config.window.bubble_x = Some(pos.x);
+            self.config.window.bubble_y = Some(pos.y);
+        }
+    }
+}
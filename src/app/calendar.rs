@@ -0,0 +1,104 @@
+//! Meetings from the calendar feed: a warning when a focus session would
+//! run into one, and shortening or pausing the session for it
+
+use chrono::{Local, NaiveDateTime, TimeDelta};
+
+use super::PomodoRustApp;
+use crate::core::SessionType;
+use crate::data::MeetingAction;
+use crate::integrations::{CalendarFeed, Meeting};
+
+impl PomodoRustApp {
+    /// Start or stop the feed with the config, and pause a running focus
+    /// session once a meeting starts when that's asked for
+    pub(super) fn update_calendar(&mut self) {
+        if !self.config.calendar.is_active() {
+            self.calendar = None;
+            return;
+        }
+        let calendar = self
+            .calendar
+            .get_or_insert_with(|| CalendarFeed::start(self.config.calendar.clone()));
+
+        if self.config.calendar.on_overlap != MeetingAction::Pause
+            || self.session.session_type() != SessionType::Work
+            || !self.session.timer().is_running()
+        {
+            return;
+        }
+        let now = Local::now().naive_local();
+        let Some(meeting) = calendar.next_meeting(now, now) else {
+            return;
+        };
+        // Once per meeting, it can be resumed by hand
+        if self.meeting_paused_for == Some(meeting.start) {
+            return;
+        }
+        self.meeting_paused_for = Some(meeting.start);
+        self.session.pause();
+        self.show_status(format!(
+            "{}: {}",
+            crate::i18n::tr().notif.meeting_paused,
+            meeting.summary
+        ));
+    }
+
+    /// Check a focus session that just started against the meetings ahead
+    pub(super) fn check_meeting_overlap(&mut self) {
+        let Some(calendar) = &self.calendar else {
+            return;
+        };
+        let now = Local::now().naive_local();
+        let end = now + TimeDelta::seconds(self.session.timer().remaining_secs() as i64);
+        let Some(meeting) = calendar.next_meeting(now, end) else {
+            return;
+        };
+        // Already under way, so it was started on purpose
+        if meeting.start <= now {
+            self.meeting_paused_for = Some(meeting.start);
+            return;
+        }
+
+        let t = crate::i18n::tr();
+        let at = meeting.start.format("%H:%M");
+        if self.config.calendar.on_overlap == MeetingAction::Shorten {
+            if let Some(secs) = shortened_secs(&meeting, now, self.config.calendar.margin_minutes) {
+                self.session.timer_mut().cut_to(secs);
+                self.show_status(format!(
+                    "{} {} ({})",
+                    t.notif.meeting_shortened, meeting.summary, at
+                ));
+                return;
+            }
+        }
+        self.show_status(format!(
+            "{} {}: {}",
+            t.notif.meeting_at, at, meeting.summary
+        ));
+    }
+}
+
+/// Seconds left for a session ending `margin_minutes` before the meeting,
+/// `None` when that leaves less than a minute
+fn shortened_secs(meeting: &Meeting, now: NaiveDateTime, margin_minutes: u32) -> Option<u64> {
+    let end = meeting.start - TimeDelta::minutes(margin_minutes.into());
+    let secs = (end - now).num_seconds();
+    (secs >= 60).then_some(secs as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortened_secs() {
+        let now = NaiveDateTime::parse_from_str("2024-03-04 10:00", "%Y-%m-%d %H:%M").unwrap();
+        let meeting = Meeting {
+            summary: "Standup".to_string(),
+            start: now + TimeDelta::minutes(12),
+            end: now + TimeDelta::minutes(27),
+        };
+        assert_eq!(shortened_secs(&meeting, now, 2), Some(600));
+        assert_eq!(shortened_secs(&meeting, now, 12), None);
+    }
+}
@@ -0,0 +1,96 @@
+//! Chimes at set points of a focus session
+//!
+//! The elapsed time is compared from one frame to the next, a chime sounds
+//! when it steps over the halfway point or a multiple of the interval. The
+//! end of the session is left to the completion sound.
+
+use std::time::Duration;
+
+use super::PomodoRustApp;
+use crate::core::SessionType;
+use crate::data::ChimeConfig;
+
+/// Whether a chime point lies after `before` and at or before `after`, in a
+/// session `total` long
+fn chime_crossed(chimes: &ChimeConfig, total: Duration, before: Duration, after: Duration) -> bool {
+    if after <= before || after >= total {
+        return false;
+    }
+    let crossed = |point: Duration| before < point && point <= after;
+
+    if chimes.halfway && crossed(total / 2) {
+        return true;
+    }
+    if chimes.interval_minutes > 0 {
+        let interval = Duration::from_secs(chimes.interval_minutes as u64 * 60);
+        // Last multiple of the interval reached so far
+        let point = interval * (after.as_secs() / interval.as_secs()) as u32;
+        if !point.is_zero() && crossed(point) {
+            return true;
+        }
+    }
+    false
+}
+
+impl PomodoRustApp {
+    /// Play a chime when a running focus session passes a chime point
+    pub(super) fn update_chimes(&mut self) {
+        let timer = self.session.timer();
+        let total = timer.total_duration();
+        let elapsed = total.saturating_sub(timer.remaining());
+        let before = self.chime_elapsed.replace(elapsed);
+
+        let due = self.sounds_audible()
+            && timer.is_running()
+            && self.session.session_type() == SessionType::Work
+            && before
+                .is_some_and(|before| chime_crossed(&self.config.chimes, total, before, elapsed));
+        if due {
+            if let Some(ref mut audio) = self.audio {
+                audio.play_chime();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn min(minutes: u64) -> Duration {
+        Duration::from_secs(minutes * 60)
+    }
+
+    #[test]
+    fn test_halfway_chime() {
+        let chimes = ChimeConfig {
+            halfway: true,
+            interval_minutes: 0,
+        };
+        assert!(chime_crossed(&chimes, min(50), min(24), min(25)));
+        assert!(!chime_crossed(&chimes, min(50), min(25), min(26)));
+    }
+
+    #[test]
+    fn test_interval_chimes() {
+        let chimes = ChimeConfig {
+            halfway: false,
+            interval_minutes: 10,
+        };
+        assert!(chime_crossed(&chimes, min(50), min(9), min(10)));
+        assert!(chime_crossed(&chimes, min(50), min(39), min(40)));
+        assert!(!chime_crossed(&chimes, min(50), min(10), min(19)));
+        // The end belongs to the completion sound
+        assert!(!chime_crossed(&chimes, min(50), min(49), min(50)));
+    }
+
+    #[test]
+    fn test_no_chime_going_backwards() {
+        let chimes = ChimeConfig {
+            halfway: true,
+            interval_minutes: 10,
+        };
+        // A new session starting over
+        assert!(!chime_crossed(&chimes, min(50), min(30), min(0)));
+    }
+}
@@ -0,0 +1,66 @@
+//! Banner under the title bar listing the problems found in config.toml
+
+use super::PomodoRustApp;
+use crate::data::ConfigIssue;
+
+impl PomodoRustApp {
+    /// Problems found while loading the config, shown until dismissed
+    pub fn with_config_issues(mut self, issues: Vec<ConfigIssue>) -> Self {
+        self.config_issues = issues;
+        self
+    }
+
+    /// Draw the banner while there are problems to report
+    pub(super) fn show_config_banner(&mut self, ui: &mut egui::Ui) {
+        let Some(first) = self.config_issues.first() else {
+            return;
+        };
+        let t = crate::i18n::tr();
+        let theme = &self.theme;
+
+        let mut summary = format!("{}: {}", t.common.config_problems, first);
+        if self.config_issues.len() > 1 {
+            summary.push_str(&format!(" (+{})", self.config_issues.len() - 1));
+        }
+        let details = self
+            .config_issues
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut dismissed = false;
+        egui::Frame::none()
+            .fill(theme.warning_muted)
+            .rounding(theme.rounding_sm)
+            .inner_margin(egui::Margin::symmetric(theme.spacing_sm, theme.spacing_xs))
+            .outer_margin(egui::Margin::symmetric(theme.spacing_md, 0.0))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.scope(|ui| {
+                        ui.set_max_width(ui.available_width() - 24.0);
+                        ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(summary).color(theme.warning).small(),
+                            )
+                            .truncate(),
+                        )
+                        .on_hover_text(details);
+                    });
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        dismissed = ui
+                            .add(
+                                egui::Button::new(egui::RichText::new("×").color(theme.text_muted))
+                                    .frame(false),
+                            )
+                            .on_hover_text(t.common.dismiss)
+                            .clicked();
+                    });
+                });
+            });
+
+        if dismissed {
+            self.config_issues.clear();
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Preset and daily goal chosen per weekday
+//!
+//! The day's preset is taken up the first time the timer is idle on a new
+//! day, so the first session already runs with it. The timer settings are
+//! left alone and come back on days without a plan.
+
+use chrono::{Datelike, Local};
+
+use super::PomodoRustApp;
+use crate::core::{Preset, SessionType, TimerState};
+
+/// Whether two presets time sessions the same way
+fn same_durations(a: &Preset, b: &Preset) -> bool {
+    a.work_duration == b.work_duration
+        && a.short_break == b.short_break
+        && a.long_break == b.long_break
+        && a.sessions_before_long_break == b.sessions_before_long_break
+}
+
+impl PomodoRustApp {
+    /// Switch to today's preset before the first session of the day
+    pub(super) fn update_day_plan(&mut self) {
+        let today = Local::now().date_naive();
+        if self.day_plan_date == Some(today)
+            || self.session.timer().state() != TimerState::Idle
+            || self.session.session_type() != SessionType::Work
+        {
+            return;
+        }
+        self.day_plan_date = Some(today);
+
+        let preset = self
+            .config
+            .weekdays
+            .plan_for(today.weekday())
+            .and_then(|plan| plan.preset.preset())
+            .unwrap_or_else(|| self.config.to_preset());
+        if !same_durations(&preset, self.session.preset()) {
+            tracing::info!("Using the {} preset for today", preset.name);
+            self.session.set_preset(preset);
+        }
+    }
+
+    /// Daily goal for today, from its plan or the goal settings
+    pub(super) fn daily_target(&self) -> u32 {
+        let weekday = Local::now().date_naive().weekday();
+        self.config
+            .weekdays
+            .plan_for(weekday)
+            .map(|plan| plan.daily_target)
+            .filter(|&target| target > 0)
+            .unwrap_or(self.config.goals.daily_target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_durations_ignores_the_name() {
+        let custom = Preset::custom("Custom", 50, 10, 30, 2);
+        assert!(same_durations(&custom, &Preset::long()));
+        assert!(!same_durations(&custom, &Preset::classic()));
+    }
+}
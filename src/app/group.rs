@@ -0,0 +1,159 @@
+//! Group sessions: hosting the timer for others on the local network or
+//! following a host's, and the line under the title bar saying who's in
+//!
+//! A member's timer is kept in line with the host's, so starting, pausing
+//! or skipping it by hand only lasts until the host's next message. Each
+//! member still records its own sessions.
+
+use chrono::Utc;
+
+use super::runtime_errors::RuntimeError;
+use super::PomodoRustApp;
+use crate::core::{SessionType, TimerEvent};
+use crate::integrations::{GroupSession, GroupTimer, SYNC_TOLERANCE_SECS};
+
+impl PomodoRustApp {
+    /// Start or stop the group session with the config, then pass the timer
+    /// on to the members or follow the host's
+    pub(super) fn update_group(&mut self, ctx: &egui::Context) {
+        if !self.config.group.is_active() {
+            if self.group_config.take().is_some() {
+                self.group = None;
+                self.clear_error(&RuntimeError::Group(String::new()));
+            }
+            return;
+        }
+        if self.group_config.as_ref() != Some(&self.config.group) {
+            // Stop the old one first, so a host gets its port back
+            self.group = None;
+            self.group_config = Some(self.config.group.clone());
+            match GroupSession::start(&self.config.group, Some(ctx.clone())) {
+                Ok(group) => {
+                    self.group = Some(group);
+                    self.clear_error(&RuntimeError::Group(String::new()));
+                }
+                Err(e) => {
+                    tracing::error!("Failed to host the group session: {}", e);
+                    self.report_error(RuntimeError::Group(e));
+                }
+            }
+        }
+
+        match &self.group {
+            Some(GroupSession::Host(host)) => host.publish(self.ipc_status()),
+            Some(GroupSession::Member(member)) => {
+                if let Some(timer) = member.timer() {
+                    self.follow_group_timer(&timer);
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Bring the timer in line with the host's. A session about to end is
+    /// left to run out by itself, so it's recorded as completed.
+    fn follow_group_timer(&mut self, host: &GroupTimer) {
+        let Some(session_type) = SessionType::from_db_str(&host.status.session_type) else {
+            return;
+        };
+        let remaining = host.remaining_secs();
+        let total = host.status.total_duration_secs;
+
+        let timer = self.session.timer();
+        if self.session.session_type() != session_type {
+            if timer.is_running() && timer.remaining_secs() <= SYNC_TOLERANCE_SECS {
+                return;
+            }
+            self.record_unfinished_session();
+            self.session.switch_to(session_type);
+            self.session_start_time = None;
+        }
+        if self.session.timer().total_duration().as_secs() != total {
+            self.record_unfinished_session();
+            self.session.timer_mut().reset_with_duration(total);
+            self.session_start_time = None;
+        }
+
+        let running = self.session.timer().is_running();
+        let paused = self.session.timer().is_paused();
+        let state = host.status.state.as_str();
+        match state {
+            "running" | "paused" if !running && !paused => {
+                if self.session.start() == TimerEvent::Started {
+                    let elapsed = total.saturating_sub(remaining) as i64;
+                    self.session_start_time = Some(Utc::now() - chrono::Duration::seconds(elapsed));
+                }
+                if state == "paused" {
+                    self.session.pause();
+                }
+            }
+            "running" if paused => {
+                self.session.start();
+            }
+            "paused" if running => {
+                self.session.pause();
+            }
+            "idle" | "completed" if running || paused => {
+                self.record_unfinished_session();
+                self.session.reset();
+                self.session_start_time = None;
+            }
+            _ => {}
+        }
+
+        if matches!(state, "running" | "paused") {
+            let local = self.session.timer().remaining_secs();
+            if local.abs_diff(remaining) > SYNC_TOLERANCE_SECS {
+                self.session
+                    .timer_mut()
+                    .adjust_elapsed(local as i64 - remaining as i64);
+            }
+        }
+    }
+
+    /// Draw the line saying how the group session goes, while there's one
+    pub(super) fn show_group_banner(&self, ui: &mut egui::Ui) {
+        let Some(group) = &self.group else {
+            return;
+        };
+        let t = &crate::i18n::tr().common;
+        let theme = &self.theme;
+
+        let participants = group.participants();
+        let (mut text, color) = match group {
+            GroupSession::Host(host) => (
+                t.group_hosting.replace("{port}", &host.port().to_string()),
+                theme.text_secondary,
+            ),
+            GroupSession::Member(member) => {
+                let connecting = t.group_connecting.replace("{address}", member.address());
+                match (participants.first(), member.error()) {
+                    (Some(host), _) => (
+                        t.group_following.replace("{host}", host),
+                        theme.text_secondary,
+                    ),
+                    (None, Some(error)) => (format!("{}: {}", connecting, error), theme.warning),
+                    (None, None) => (connecting, theme.text_muted),
+                }
+            }
+        };
+        if !participants.is_empty() {
+            text.push_str(" · ");
+            text.push_str(
+                &t.group_participants
+                    .replace("{count}", &participants.len().to_string()),
+            );
+        }
+
+        egui::Frame::none()
+            .inner_margin(egui::Margin::symmetric(theme.spacing_md, 0.0))
+            .show(ui, |ui| {
+                let label = ui.add(
+                    egui::Label::new(egui::RichText::new(text).color(color).small()).truncate(),
+                );
+                if !participants.is_empty() {
+                    label.on_hover_text(participants.join("\n"));
+                }
+            });
+    }
+}
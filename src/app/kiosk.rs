@@ -0,0 +1,146 @@
+//! Kiosk mode: the countdown filling the screen, for a projector
+//!
+//! Meant for workshops and exams, it shows the session type, the remaining
+//! time in digits as large as the screen allows, a progress bar and the
+//! optional `window.kiosk_message`, with no title bar or other chrome.
+//! Turned on from the timer view's context menu or with `pomodorust kiosk`;
+//! Space toggles the timer, Escape or a double-click leaves it. It isn't
+//! remembered across restarts, so the app never comes up full screen.
+
+use egui::{pos2, vec2, Align2, FontFamily, FontId, Rect};
+
+use crate::ipc::IpcResponse;
+
+use super::{PomodoRustApp, View};
+
+/// Width of a digit relative to the font size, for fitting the time
+const DIGIT_WIDTH: f32 = 0.62;
+
+/// Share of the screen height the digits may take
+const DIGITS_HEIGHT: f32 = 0.5;
+
+/// Height of the progress bar along the bottom
+const PROGRESS_HEIGHT: f32 = 12.0;
+
+impl PomodoRustApp {
+    /// Enter or leave kiosk mode
+    pub(super) fn set_kiosk(&mut self, enabled: bool, ctx: &egui::Context) {
+        if enabled == self.kiosk {
+            return;
+        }
+        if enabled {
+            self.set_mini_mode(false, ctx);
+            self.current_view = View::Timer;
+            self.settings_view = None;
+        }
+        self.kiosk = enabled;
+        ctx.send_viewport_cmd(egui::ViewportCommand::Fullscreen(enabled));
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(
+            if enabled || self.config.window.always_on_top {
+                egui::WindowLevel::AlwaysOnTop
+            } else {
+                egui::WindowLevel::Normal
+            },
+        ));
+    }
+
+    /// Turn kiosk mode on, off or the other way from the command line, optionally
+    /// setting the message shown under the time (empty to clear it)
+    pub(super) fn ipc_kiosk(
+        &mut self,
+        enabled: Option<bool>,
+        message: Option<String>,
+        ctx: &egui::Context,
+    ) -> IpcResponse {
+        if let Some(message) = message {
+            let message = message.trim().to_string();
+            if message != self.config.window.kiosk_message {
+                self.config.window.kiosk_message = message;
+                self.save_config();
+            }
+        }
+        let enabled = enabled.unwrap_or(!self.kiosk);
+        self.set_kiosk(enabled, ctx);
+        IpcResponse::ok_with_message(format!("Kiosk mode {}", if enabled { "on" } else { "off" }))
+    }
+
+    /// Draw the full-screen countdown in place of the normal UI
+    pub(super) fn render_kiosk(&mut self, ctx: &egui::Context) {
+        let mut leave = false;
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::none().fill(self.theme.bg_primary))
+            .show(ctx, |ui| {
+                let screen = ui.max_rect();
+                let background = ui.interact(
+                    screen,
+                    ui.id().with("kiosk_background"),
+                    egui::Sense::click(),
+                );
+                leave = background.double_clicked();
+
+                let timer = self.session.timer();
+                let session_type = self.session.session_type();
+                let (start_color, end_color) = self.theme.session_gradient(session_type);
+                let painter = ui.painter();
+
+                // As large as the width and the height both allow
+                let time = timer.remaining_formatted();
+                let digits_size = (screen.width() * 0.9
+                    / (time.chars().count() as f32 * DIGIT_WIDTH))
+                    .min(screen.height() * DIGITS_HEIGHT);
+                let label_size = (digits_size / 6.0).clamp(16.0, 64.0);
+                let center = screen.center();
+
+                painter.text(
+                    pos2(center.x, center.y - digits_size * 0.5 - label_size * 0.5),
+                    Align2::CENTER_BOTTOM,
+                    crate::i18n::tr().session_label(session_type),
+                    FontId::proportional(label_size),
+                    start_color,
+                );
+                painter.text(
+                    center,
+                    Align2::CENTER_CENTER,
+                    time,
+                    FontId::new(digits_size, FontFamily::Name("Timer".into())),
+                    if timer.is_paused() {
+                        self.theme.text_muted
+                    } else {
+                        self.theme.text_primary
+                    },
+                );
+
+                let message = &self.config.window.kiosk_message;
+                if !message.is_empty() {
+                    let galley = painter.layout(
+                        message.clone(),
+                        FontId::proportional(label_size),
+                        self.theme.text_secondary,
+                        screen.width() * 0.8,
+                    );
+                    let top = center.y + digits_size * 0.5 + label_size * 0.5;
+                    let left = center.x - galley.size().x / 2.0;
+                    painter.galley(pos2(left, top), galley, self.theme.text_secondary);
+                }
+
+                let track = Rect::from_min_size(
+                    pos2(screen.left(), screen.bottom() - PROGRESS_HEIGHT),
+                    vec2(screen.width(), PROGRESS_HEIGHT),
+                );
+                painter.rect_filled(track, 0.0, self.theme.bg_tertiary);
+                let filled = Rect::from_min_size(
+                    track.min,
+                    vec2(
+                        track.width() * timer.progress().clamp(0.0, 1.0),
+                        track.height(),
+                    ),
+                );
+                painter.rect_filled(filled, 0.0, end_color);
+            });
+
+        if leave {
+            self.set_kiosk(false, ctx);
+        }
+    }
+}
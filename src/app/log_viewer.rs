@@ -0,0 +1,63 @@
+//! Hidden log viewer, opened with Ctrl+Shift+L, for copying the latest log
+//! lines into a bug report
+
+use super::PomodoRustApp;
+
+/// Lines shown, counting back from the newest
+const VIEWER_LINES: usize = 500;
+
+impl PomodoRustApp {
+    /// Open the viewer with the latest lines, or close it
+    pub(super) fn toggle_log_viewer(&mut self) {
+        self.log_lines = match self.log_lines {
+            Some(_) => None,
+            None => Some(crate::logging::recent_lines(VIEWER_LINES)),
+        };
+    }
+
+    /// Draw the viewer while it's open
+    pub(super) fn show_log_viewer(&mut self, ctx: &egui::Context) {
+        let Some(lines) = &self.log_lines else {
+            return;
+        };
+        let t = crate::i18n::tr();
+        let theme = &self.theme;
+
+        let mut open = true;
+        let mut refresh = false;
+        egui::Window::new(t.common.logs)
+            .open(&mut open)
+            .default_size([560.0, 360.0])
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    refresh = ui.button(t.common.refresh).clicked();
+                    if ui.button(t.common.copy).clicked() {
+                        ctx.copy_text(lines.join("\n"));
+                    }
+                    if let Some(path) = crate::logging::log_path() {
+                        ui.label(
+                            egui::RichText::new(path.display().to_string())
+                                .color(theme.text_muted)
+                                .small(),
+                        );
+                    }
+                });
+                ui.separator();
+                egui::ScrollArea::both()
+                    .stick_to_bottom(true)
+                    .auto_shrink(false)
+                    .show(ui, |ui| {
+                        for line in lines {
+                            ui.label(egui::RichText::new(line).monospace().small());
+                        }
+                    });
+            });
+
+        if !open {
+            self.log_lines = None;
+        } else if refresh {
+            self.log_lines = Some(crate::logging::recent_lines(VIEWER_LINES));
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Mini mode: a tiny frameless widget showing only the ring and the time
+//!
+//! Toggled from the title bar and remembered in `window.mini_mode`. The
+//! widget stays on top and can be dragged anywhere; clicking the ring
+//! toggles the timer and double-clicking restores the normal window.
+
+use egui::{vec2, Vec2};
+
+use crate::ui::components::CircularProgress;
+use crate::ui::timer_view::TimerAction;
+
+use super::{PomodoRustApp, View};
+
+/// Interaction with a compact timer widget
+pub(super) enum CompactAction {
+    /// The ring was clicked
+    Toggle,
+    /// The widget was double-clicked
+    Restore,
+}
+
+/// Size of the mini widget
+pub(super) const MINI_SIZE: Vec2 = vec2(120.0, 60.0);
+
+/// Minimum size of the normal window
+const NORMAL_MIN_SIZE: Vec2 = vec2(320.0, 375.0);
+
+impl PomodoRustApp {
+    /// Switch between the mini widget and the normal window
+    pub(super) fn set_mini_mode(&mut self, enabled: bool, ctx: &egui::Context) {
+        if enabled == self.config.window.mini_mode {
+            return;
+        }
+        if enabled {
+            // Remember the normal size to restore it later
+            if let Some(size) = self.last_window_size {
+                self.config.window.width = size.x;
+                self.config.window.height = size.y;
+            }
+            ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(false));
+            self.current_view = View::Timer;
+            self.settings_view = None;
+        }
+        self.config.window.mini_mode = enabled;
+        self.apply_mini_mode(ctx);
+        self.save_config();
+    }
+
+    /// Size and stack the window for the current mode
+    pub(super) fn apply_mini_mode(&self, ctx: &egui::Context) {
+        let window = &self.config.window;
+        let (min_size, size, on_top) = if window.mini_mode {
+            (MINI_SIZE, MINI_SIZE, true)
+        } else {
+            (
+                NORMAL_MIN_SIZE,
+                vec2(window.width, window.height),
+                window.always_on_top,
+            )
+        };
+        ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(min_size));
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size));
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(if on_top {
+            egui::WindowLevel::AlwaysOnTop
+        } else {
+            egui::WindowLevel::Normal
+        }));
+    }
+
+    /// Draw the mini widget in place of the normal UI
+    pub(super) fn render_mini_mode(&mut self, ctx: &egui::Context) {
+        let hint = crate::i18n::tr().common.exit_mini_mode;
+        match self.show_compact_timer(ctx, hint) {
+            Some(CompactAction::Toggle) => self.handle_timer_action(TimerAction::Toggle),
+            Some(CompactAction::Restore) => self.set_mini_mode(false, ctx),
+            None => {}
+        }
+    }
+
+    /// Draw the ring and the remaining time filling the viewport
+    ///
+    /// Shared by the mini widget and the floating bubble.
+    pub(super) fn show_compact_timer(
+        &self,
+        ctx: &egui::Context,
+        hint: &str,
+    ) -> Option<CompactAction> {
+        let bg_alpha = (self.config.appearance.window_opacity as f32 / 100.0 * 255.0) as u8;
+        let bg = self.theme.bg_primary;
+        let bg_color = egui::Color32::from_rgba_unmultiplied(bg.r(), bg.g(), bg.b(), bg_alpha);
+
+        let mut action = None;
+
+        egui::CentralPanel::default()
+            .frame(
+                egui::Frame::none()
+                    .fill(bg_color)
+                    .rounding(self.theme.window_rounding())
+                    .inner_margin(egui::Margin::symmetric(8.0, 6.0)),
+            )
+            .show(ctx, |ui| {
+                // The whole widget is a drag handle
+                let background = ui.interact(
+                    ctx.screen_rect(),
+                    ui.id().with("compact_drag"),
+                    egui::Sense::click_and_drag(),
+                );
+                if background.drag_started() {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                }
+                if background.double_clicked() {
+                    action = Some(CompactAction::Restore);
+                }
+                background.on_hover_text(hint);
+
+                let timer = self.session.timer();
+                let (start_color, end_color) =
+                    self.theme.session_gradient(self.session.session_type());
+                let pulse = if timer.is_running() && !self.theme.reduced_motion {
+                    self.animations.pulse_value()
+                } else {
+                    0.0
+                };
+
+                ui.horizontal_centered(|ui| {
+                    let ring_left = ui.cursor().min;
+                    CircularProgress::new(timer.progress())
+                        .with_label(format!(
+                            "{}, {}",
+                            crate::i18n::tr().session_label(self.session.session_type()),
+                            timer.remaining_formatted()
+                        ))
+                        .with_radius(20.0)
+                        .with_thickness(4.0)
+                        .with_colors(start_color, end_color)
+                        .with_bg_color(self.theme.bg_tertiary)
+                        .with_pulse(pulse)
+                        .show(ui, |_| {});
+                    let ring_rect = egui::Rect::from_min_max(ring_left, ui.min_rect().max);
+                    let ring = ui.interact(
+                        ring_rect,
+                        ui.id().with("compact_ring"),
+                        egui::Sense::click(),
+                    );
+                    if ring.clicked() {
+                        action = Some(CompactAction::Toggle);
+                    }
+                    if ring.hovered() {
+                        ctx.set_cursor_icon(egui::CursorIcon::PointingHand);
+                    }
+
+                    ui.add_space(6.0);
+                    ui.label(
+                        egui::RichText::new(timer.remaining_formatted())
+                            .font(egui::FontId::new(
+                                18.0,
+                                egui::FontFamily::Name("Timer".into()),
+                            ))
+                            .color(self.theme.text_primary),
+                    );
+                });
+            });
+
+        action
+    }
+}
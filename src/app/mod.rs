@@ -1,23 +1,72 @@
 //! Main application struct and logic
 
 mod actions;
+mod app_usage;
+mod appearance;
+mod background;
+mod break_overlay;
+mod bubble;
+mod calendar;
+mod chimes;
+mod config_banner;
+mod day_plan;
+mod group;
+mod kiosk;
+mod log_viewer;
+mod mini_mode;
+mod notification_text;
+mod notion;
+mod obsidian;
+mod planning;
+mod power;
+mod presence;
+mod profiles;
 mod queue_view;
+mod reminders;
+mod runtime_errors;
+mod scheduler;
 mod system;
+mod taskwarrior;
 mod todo_handler;
+mod todoist;
+mod undo;
+mod weekly_report;
+mod working_hours;
 
 use std::sync::atomic::Ordering;
-use std::sync::mpsc::Receiver;
+use std::sync::mpsc::{self, Receiver, Sender};
 
 use chrono::Utc;
 
-use crate::core::{Session, TimerEvent};
-use crate::data::{Config, Database, Statistics};
-use crate::ipc::{IpcCommand, IpcServer};
-use crate::platform::{AudioPlayer, HotkeyAction, HotkeyManager, SystemTray};
+use actions::PendingPluginExport;
+use app_usage::AppUsageMonitor;
+use appearance::SystemThemeWatcher;
+use background::BackgroundImage;
+use power::PowerMonitor;
+use presence::{AwayState, LockState, PresenceMonitor};
+use reminders::PendingReminder;
+use runtime_errors::RuntimeError;
+use scheduler::Scheduler;
+use undo::PendingUndo;
+
+use crate::core::{clock_from_env, Session, SessionType, TimerEvent, TimerState};
+use crate::data::{
+    AmbientSound, BlockerConfig, Config, ConfigIssue, Database, GroupConfig, Statistics,
+};
+use crate::integrations::{
+    CalendarFeed, GroupSession, Integrations, Mailer, NotionClient, Taskwarrior, TodoistClient,
+};
+use crate::ipc::{IpcRequest, IpcServer};
+use crate::platform::{
+    AudioPlayer, DistractionBlocker, HotkeyAction, HotkeyManager, MediaController, MonitorRect,
+    NotificationAction, SleepMonitor, SystemTray, TaskbarProgress,
+};
 use crate::ui::{
     animations::AnimationState,
+    plan::PlanView,
     settings::{SettingsAction, SettingsView},
     stats::StatsView,
+    tasks::TasksView,
     theme::Theme,
     timer_view::{TimerAction, TimerView},
     titlebar::{TitleBar, TitleBarButton},
@@ -29,6 +78,8 @@ use crate::ui::{
 pub enum View {
     Timer,
     Queue,
+    Tasks,
+    Plan,
     Stats,
     Settings,
 }
@@ -48,6 +99,8 @@ pub struct PomodoRustApp {
     titlebar: TitleBar,
     timer_view: TimerView,
     stats_view: StatsView,
+    tasks_view: TasksView,
+    plan_view: PlanView,
     settings_view: Option<SettingsView>,
 
     // Animation state
@@ -58,26 +111,83 @@ pub struct PomodoRustApp {
 
     // Audio
     audio: Option<AudioPlayer>,
+    /// All sounds silenced for now, from the titlebar, a hotkey or the CLI (not saved)
+    muted: bool,
 
     // Session tracking
     session_start_time: Option<chrono::DateTime<Utc>>,
+    session_tag: Option<String>,
+    /// What the running work session is for, typed into the intent prompt
+    session_intent: Option<String>,
+    /// Elapsed time of the session at the last chime check
+    chime_elapsed: Option<std::time::Duration>,
+    /// Day whose weekday plan has been taken up
+    day_plan_date: Option<chrono::NaiveDate>,
+    /// Day whose planned tasks have been queued, or found queued already
+    plan_date: Option<chrono::NaiveDate>,
+    /// Problems found in config.toml at startup, until dismissed
+    config_issues: Vec<ConfigIssue>,
+    /// Problems that came up while running, until dealt with
+    runtime_errors: Vec<RuntimeError>,
+    /// Lines shown in the log viewer while it's open
+    log_lines: Option<Vec<String>>,
 
     // IPC for CLI integration
     ipc_server: IpcServer,
-    ipc_receiver: Option<Receiver<IpcCommand>>,
+    ipc_receiver: Option<Receiver<IpcRequest>>,
+    scheduler: Scheduler,
+
+    // Buttons clicked on OS notifications (sent from notification threads)
+    notification_tx: Sender<NotificationAction>,
+    notification_rx: Receiver<NotificationAction>,
+
+    // External integrations (MQTT, ...) and the last timer state they saw
+    integrations: Integrations,
+    last_timer_snapshot: Option<(TimerState, SessionType)>,
+    /// Todoist sync, started while it's enabled
+    todoist: Option<TodoistClient>,
+    /// Taskwarrior bridge, started while it's enabled
+    taskwarrior: Option<Taskwarrior>,
+    /// Uuid of the Taskwarrior task started for the running focus session
+    taskwarrior_active: Option<String>,
+    /// Notion sync, started while it's enabled
+    notion: Option<NotionClient>,
+    /// Weekly report mailer, started while it's enabled
+    mailer: Option<Mailer>,
+    /// Monday of the week whose report was sent or is on its way
+    report_week: Option<chrono::NaiveDate>,
+    /// Calendar feed, started while it's enabled
+    calendar: Option<CalendarFeed>,
+    /// Start of the meeting the focus session was last paused for
+    meeting_paused_for: Option<chrono::NaiveDateTime>,
+    /// Group session, started while it's enabled
+    group: Option<GroupSession>,
+    /// Plugin export running in the background
+    plugin_export: Option<PendingPluginExport>,
+    /// Config the group session was last started with
+    group_config: Option<GroupConfig>,
 
     // Global hotkeys (manager kept alive to maintain registrations)
     #[allow(dead_code)]
     hotkey_manager: HotkeyManager,
     hotkey_receiver: Option<Receiver<HotkeyAction>>,
+    media_keys_captured: bool,
+    watching_media: bool,
 
     // Window state tracking for persistence
     last_window_pos: Option<egui::Pos2>,
+    /// Full-screen countdown for a projector
+    kiosk: bool,
+    /// Floating timer bubble
+    bubble_open: bool,
+    bubble_pos: Option<egui::Pos2>,
     last_window_size: Option<egui::Vec2>,
     last_window_maximized: bool,
 
     // Toast notifications
     toasts: egui_notify::Toasts,
+    /// Stats reset or session undo that can still be taken back
+    pending_undo: Option<PendingUndo>,
 
     // Todo
     todo_window: TodoWindow,
@@ -88,6 +198,44 @@ pub struct PomodoRustApp {
     system_tray: Option<SystemTray>,
     hidden_to_tray: bool,
 
+    // Displays covered by the break overlay (detected when it appears)
+    overlay_monitors: Option<Vec<MonitorRect>>,
+
+    // Idle and screen lock handling
+    presence_monitor: Option<PresenceMonitor>,
+    /// Samples the application in the foreground while `system.track_apps` is on
+    app_usage: Option<AppUsageMonitor>,
+    /// Polls the OS appearance while the theme mode is Auto
+    theme_watcher: Option<SystemThemeWatcher>,
+    /// Picture behind the timer view, loaded on first use
+    background: Option<BackgroundImage>,
+    /// Polls the battery / battery saver state
+    power_monitor: Option<PowerMonitor>,
+    /// Whether animations are currently throttled to save power
+    power_saving: bool,
+    /// System suspend/resume notifications
+    sleep_monitor: SleepMonitor,
+    /// The running session was paused because the system went to sleep
+    sleep_paused: bool,
+    away: Option<AwayState>,
+    lock_state: LockState,
+
+    // Focus-time integrations: whether we turned on Do Not Disturb, paused
+    // media players or are blocking distractions
+    dnd_active: bool,
+    media_paused: bool,
+    media: Option<MediaController>,
+    blocking: Option<BlockerConfig>,
+    blocker: Option<DistractionBlocker>,
+
+    // Taskbar button progress (last state sent, finished session not yet acknowledged)
+    taskbar_progress: TaskbarProgress,
+    completion_pending: bool,
+    /// Finished session still being reminded of
+    reminder: Option<PendingReminder>,
+    // Last title sent to the native window
+    window_title: String,
+
     // Close confirmation dialog
     show_close_dialog: bool,
     force_quit: bool,
@@ -96,6 +244,9 @@ pub struct PomodoRustApp {
 /// Duration to show toast notifications
 const TOAST_DURATION: std::time::Duration = std::time::Duration::from_secs(2);
 
+/// Seconds at the end of a session over which the tick speeds up
+const TICK_SPEED_UP_SECS: f32 = 10.0;
+
 impl PomodoRustApp {
     /// Create a new application instance with the given config and optional tray
     pub fn with_config(
@@ -108,8 +259,8 @@ impl PomodoRustApp {
 
     /// Create a new application instance (loads config from disk)
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        let config = Config::load();
-        Self::init(cc, config, None)
+        let (config, issues) = Config::load_checked();
+        Self::init(cc, config, None).with_config_issues(issues)
     }
 
     /// Internal initialization with a config
@@ -121,54 +272,64 @@ impl PomodoRustApp {
         // Setup fonts with emoji fallback
         Self::setup_fonts(&cc.egui_ctx);
 
+        // The platform code acts on the main window by its handle
+        #[cfg(windows)]
+        {
+            use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+            if let Ok(handle) = cc.window_handle() {
+                if let RawWindowHandle::Win32(handle) = handle.as_raw() {
+                    crate::platform::set_main_window(handle.hwnd.get());
+                }
+            }
+        }
+
         // Ensure Start Menu shortcut for Windows toast notifications
         #[cfg(windows)]
         crate::platform::ensure_notification_shortcut();
 
+        // Taskbar jump list quick actions
+        #[cfg(windows)]
+        Self::register_jump_list();
+
         // Create theme from config
-        let mut theme =
-            Theme::from_mode(config.appearance.theme_mode, config.appearance.accent_color);
-        if config.accessibility.high_contrast {
-            theme = theme.with_high_contrast();
-        }
-        if config.accessibility.reduced_motion {
-            theme = theme.with_reduced_motion();
-        }
+        let theme = Self::theme_for(&config);
         theme.apply(&cc.egui_ctx);
 
         // Create session with config preset
         let preset = config.to_preset();
-        let mut session = Session::with_preset(preset);
+        let mut session = Session::with_clock(preset, clock_from_env());
         session.set_auto_start(config.timer.auto_start_breaks, config.timer.auto_start_work);
 
         // Initialize database
-        let database = match Database::open() {
-            Ok(db) => {
-                tracing::info!("Database initialized");
-                Some(db)
-            }
-            Err(e) => {
-                tracing::error!("Failed to initialize database: {}", e);
-                None
-            }
-        };
+        let database = runtime_errors::open_database(&config);
+        let database_error = database.as_ref().err().cloned();
+        let database = database.ok();
 
         // Load statistics
         let statistics = database
             .as_ref()
-            .map(Statistics::load)
+            .map(|db| Statistics::load_within(db, &config.working_hours))
             .unwrap_or_else(Statistics::empty);
 
         // Initialize audio
         let mut audio = AudioPlayer::new();
         if let Some(ref mut player) = audio {
             player.set_volume(config.sounds.volume as f32 / 100.0);
+            player.set_custom_sounds(
+                config.sounds.custom_notification.as_deref(),
+                config.sounds.custom_tick.as_deref(),
+            );
         }
 
         // Initialize IPC server for CLI
         let mut ipc_server = IpcServer::new();
         let ipc_receiver = ipc_server.take_receiver();
-        ipc_server.start();
+        let (notification_tx, notification_rx) = mpsc::channel();
+        ipc_server.set_repaint_context(cc.egui_ctx.clone());
+        let ipc_started = ipc_server.start();
+        if config.http.enabled {
+            ipc_server.start_http(&config.http);
+        }
 
         // Initialize global hotkeys
         let mut hotkey_manager = HotkeyManager::new();
@@ -178,9 +339,13 @@ impl PomodoRustApp {
                 &config.hotkeys.toggle,
                 &config.hotkeys.skip,
                 &config.hotkeys.reset,
+                &config.hotkeys.mute,
             );
         }
 
+        // Start external integrations
+        let integrations = Integrations::new(&config, &cc.egui_ctx);
+
         let shared_todo = new_shared_todo(theme.clone());
 
         let todo_auto_open = config.todo.auto_open;
@@ -194,26 +359,79 @@ impl PomodoRustApp {
             titlebar: TitleBar::new(),
             timer_view: TimerView::new(),
             stats_view: StatsView::new(),
+            tasks_view: TasksView::new(),
+            plan_view: PlanView::new(),
             settings_view: None,
             animations: AnimationState::new(),
             current_view: View::Timer,
             audio,
             session_start_time: None,
+            session_tag: None,
+            session_intent: None,
+            chime_elapsed: None,
+            day_plan_date: None,
+            plan_date: None,
+            config_issues: Vec::new(),
+            runtime_errors: Vec::new(),
+            log_lines: None,
+            muted: false,
             ipc_server,
             ipc_receiver,
+            scheduler: Scheduler::default(),
+            notification_tx,
+            notification_rx,
+            integrations,
+            last_timer_snapshot: None,
+            todoist: None,
+            taskwarrior: None,
+            taskwarrior_active: None,
+            notion: None,
+            mailer: None,
+            report_week: None,
+            calendar: None,
+            meeting_paused_for: None,
+            group: None,
+            group_config: None,
+            plugin_export: None,
             hotkey_manager,
             hotkey_receiver,
+            media_keys_captured: false,
+            watching_media: false,
             last_window_pos: None,
+            kiosk: false,
+            bubble_open: false,
+            bubble_pos: None,
             last_window_size: None,
             last_window_maximized: false,
             toasts: egui_notify::Toasts::default()
                 .with_anchor(egui_notify::Anchor::BottomRight)
                 .with_margin(egui::vec2(10.0, 10.0)),
+            pending_undo: None,
             todo_window: TodoWindow::new(),
             shared_todo,
             todo_theme_dirty: true,
             system_tray,
             hidden_to_tray: false,
+            overlay_monitors: None,
+            presence_monitor: None,
+            app_usage: None,
+            theme_watcher: None,
+            background: None,
+            power_monitor: None,
+            power_saving: false,
+            sleep_monitor: SleepMonitor::start(cc.egui_ctx.clone()),
+            sleep_paused: false,
+            away: None,
+            lock_state: LockState::default(),
+            dnd_active: false,
+            media_paused: false,
+            media: None,
+            blocking: None,
+            blocker: None,
+            taskbar_progress: TaskbarProgress::None,
+            completion_pending: false,
+            reminder: None,
+            window_title: String::new(),
             show_close_dialog: false,
             force_quit: false,
         };
@@ -222,13 +440,24 @@ impl PomodoRustApp {
             app.todo_window.open();
         }
 
+        if let Some(e) = database_error {
+            app.report_error(RuntimeError::Database(e));
+        }
+        app.check_ipc_start(ipc_started);
+
         // Set show_completed from config
         if let Ok(mut state) = app.shared_todo.data.write() {
             state.show_completed = app.config.todo.show_completed;
         }
 
-        // Initial data load for todo
+        // Initial data load for todo and the stats view
         app.refresh_todo_data();
+        app.stats_view.quick_start = app.config.quick_start.entries.clone();
+        app.reload_stats_tab();
+
+        if app.config.window.mini_mode {
+            app.apply_mini_mode(&cc.egui_ctx);
+        }
 
         app
     }
@@ -260,7 +489,26 @@ impl PomodoRustApp {
         if let Ok(mut data) = self.shared_todo.data.write() {
             data.is_always_on_top = enabled;
         }
-        let _ = self.config.save();
+        self.save_config();
+    }
+
+    /// Silence or bring back all sounds until the app is closed
+    fn set_muted(&mut self, muted: bool) {
+        if muted == self.muted {
+            return;
+        }
+        self.muted = muted;
+        let notif = &crate::i18n::tr().notif;
+        self.show_status(if muted {
+            notif.sounds_muted
+        } else {
+            notif.sounds_unmuted
+        });
+    }
+
+    /// Whether sounds play right now: on in the settings and not muted
+    fn sounds_audible(&self) -> bool {
+        self.config.sounds.enabled && !self.muted
     }
 }
 
@@ -275,24 +523,45 @@ impl eframe::App for PomodoRustApp {
             let maximized = i.viewport().maximized.unwrap_or(false);
             self.last_window_maximized = maximized;
 
-            // Only save position/size when not maximized (to preserve "normal" window state)
-            if !maximized {
+            // Only save position/size when not maximized or full screen (to
+            // preserve "normal" window state)
+            if !maximized && !self.kiosk {
                 if let Some(rect) = i.viewport().inner_rect {
                     self.last_window_pos = Some(rect.min);
-                    self.last_window_size = Some(rect.size());
+                    // The mini widget's size is fixed
+                    if !self.config.window.mini_mode {
+                        self.last_window_size = Some(rect.size());
+                    }
                 }
             }
         });
 
         // Handle IPC commands from CLI
-        self.handle_ipc_commands();
+        self.handle_ipc_commands(ctx);
+        self.handle_notification_actions(ctx);
+        self.handle_mqtt_commands(ctx);
+        self.handle_telegram_commands(ctx);
+        self.check_presence(ctx);
+        self.handle_sleep_events();
+        self.run_scheduled_commands(ctx);
+        self.update_todoist(ctx);
+        self.update_taskwarrior(ctx);
+        self.update_notion(ctx);
+        self.update_weekly_report(ctx);
+        self.update_calendar();
+        self.update_group(ctx);
+        self.update_plugin_export();
 
         // Handle global hotkey events
+        self.update_media_keys(ctx);
         self.handle_hotkey_events();
 
         // Handle system tray events
         self.handle_tray_events(ctx);
         self.update_tray_state();
+        self.update_taskbar_progress();
+        self.update_window_title(ctx);
+        self.update_focus_mode();
 
         // Keep polling when hidden to tray
         if self.hidden_to_tray {
@@ -305,44 +574,106 @@ impl eframe::App for PomodoRustApp {
             && !self.force_quit
         {
             ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
-            self.show_close_dialog = true;
+            if self.config.system.close_to_tray {
+                self.hide_to_tray(ctx);
+            } else {
+                self.show_close_dialog = true;
+            }
         }
 
-        // Apply theme
+        // Apply theme, following the OS appearance or the time of day
+        self.follow_auto_theme(ctx);
+        self.update_power_saver(ctx);
         self.theme.apply(ctx);
 
         // Handle window resize zones (for custom decorated window)
-        self.handle_resize_zones(ctx);
+        if !self.config.window.mini_mode && !self.kiosk {
+            self.handle_resize_zones(ctx);
+        }
 
-        // Update timer
+        // Update timer (the session transitions to the next type on completion,
+        // so remember what was running beforehand)
+        let running_type = self.session.session_type();
+        let running_duration = self.session.timer().total_duration().as_secs();
         let (event, should_auto_start) = self.session.update();
+        let should_auto_start = should_auto_start && self.auto_start_allowed();
         if let Some(TimerEvent::Completed) = event {
-            self.on_timer_completed();
+            self.on_timer_completed(running_type, running_duration, ctx);
+            self.completion_pending = !should_auto_start;
+            // Bounce the dock icon (there is no taskbar flash on macOS)
+            #[cfg(target_os = "macos")]
+            ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(
+                egui::UserAttentionType::Informational,
+            ));
             if should_auto_start {
                 self.session.start();
                 self.session_start_time = Some(Utc::now());
+            } else {
+                self.start_reminders(running_type);
             }
         }
 
+        // Publish timer state changes to integrations
+        self.track_state_changes();
+
+        self.update_chimes();
+        self.update_day_plan();
+        self.update_planning();
+        self.update_app_usage();
+        self.update_reminders(ctx);
+
         // Manage tick sound
+        let audible = self.sounds_audible();
         if let Some(ref mut audio) = self.audio {
-            let should_tick = self.config.sounds.enabled
-                && self.config.sounds.tick_enabled
-                && self.session.timer().is_running();
+            let should_tick =
+                audible && self.config.sounds.tick_enabled && self.session.timer().is_running();
 
             if should_tick && !audio.is_tick_playing() {
                 audio.start_tick();
             } else if !should_tick && audio.is_tick_playing() {
                 audio.stop_tick();
             }
+
+            // Up to twice as fast by the end of the session
+            let remaining = self.session.timer().remaining().as_secs_f32();
+            let speed = if self.config.sounds.tick_speed_up && remaining < TICK_SPEED_UP_SECS {
+                1.0 + (TICK_SPEED_UP_SECS - remaining) / TICK_SPEED_UP_SECS
+            } else {
+                1.0
+            };
+            audio.set_tick_speed(speed);
+
+            // Ambient sounds only while focusing
+            let focusing = audible
+                && self.session.timer().is_running()
+                && self.session.session_type() == SessionType::Work;
+            let levels = AmbientSound::all().iter().map(|&sound| {
+                let volume = if focusing {
+                    self.config.ambient.volume(sound)
+                } else {
+                    0
+                };
+                (sound, volume.min(100) as f32 / 100.0)
+            });
+            audio.set_ambient(&levels.collect::<Vec<_>>());
         }
 
         // Update animations
         self.animations.update(self.session.timer().is_running());
 
-        // Request continuous repaint when timer is running or animating
-        if self.session.timer().is_running() || self.animations.needs_repaint() {
+        // Repaint every frame only while something moves on screen: the
+        // timer pulse in a focused window without reduced motion, or a
+        // transition. Otherwise the running timer wakes up when its second
+        // changes.
+        let visible = ctx.input(|i| {
+            let viewport = i.viewport();
+            viewport.focused.unwrap_or(true) && !viewport.minimized.unwrap_or(false)
+        });
+        let pulsing = self.animations.is_pulsing() && !self.theme.reduced_motion;
+        if visible && (pulsing || self.animations.is_transitioning()) {
             ctx.request_repaint();
+        } else if self.session.timer().is_running() {
+            ctx.request_repaint_after(self.session.timer().until_next_second());
         }
 
         // Check if maximized for rounding
@@ -363,140 +694,246 @@ impl eframe::App for PomodoRustApp {
             bg_alpha,
         );
 
-        // Main panel with custom frame - no rounding or border when maximized
-        egui::CentralPanel::default()
-            .frame(
-                egui::Frame::none()
-                    .fill(bg_color)
-                    .rounding(if is_maximized {
-                        egui::Rounding::ZERO
-                    } else {
-                        self.theme.window_rounding()
-                    }),
-            )
-            .show(ctx, |ui| {
-                // Title bar
-                let (should_drag, button) = self.titlebar.show(
-                    ui,
-                    &self.theme,
-                    is_maximized,
-                    self.config.window.always_on_top,
-                );
-
-                if should_drag {
-                    ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
-                }
+        if self.kiosk {
+            self.render_kiosk(ctx);
+        } else if self.config.window.mini_mode {
+            self.render_mini_mode(ctx);
+        } else {
+            // Main panel with custom frame - no rounding or border when maximized
+            egui::CentralPanel::default()
+                .frame(
+                    egui::Frame::none()
+                        .fill(bg_color)
+                        .rounding(if is_maximized {
+                            egui::Rounding::ZERO
+                        } else {
+                            self.theme.window_rounding()
+                        }),
+                )
+                .show(ctx, |ui| {
+                    // Title bar
+                    let (profile_names, active_profile) = self.profile_names();
+                    let no_profile = crate::i18n::tr().settings.no_profile;
+                    let (should_drag, button) = self.titlebar.show(
+                        ui,
+                        &self.theme,
+                        is_maximized,
+                        self.config.window.always_on_top,
+                        self.muted,
+                        Some((
+                            active_profile.as_deref().unwrap_or(no_profile),
+                            &profile_names,
+                        )),
+                    );
+
+                    if should_drag {
+                        ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                    }
 
-                if let Some(button) = button {
-                    match button {
-                        TitleBarButton::AlwaysOnTop => {
-                            self.set_always_on_top(!self.config.window.always_on_top, ctx);
-                        }
-                        TitleBarButton::Minimize => {
-                            if self.config.system.minimize_to_tray && self.system_tray.is_some() {
-                                self.hide_to_tray(ctx);
-                            } else {
-                                ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+                    if let Some(button) = button {
+                        match button {
+                            TitleBarButton::MiniMode => {
+                                self.set_mini_mode(true, ctx);
                             }
-                        }
-                        TitleBarButton::Maximize => {
-                            ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(
-                                !ctx.input(|i| i.viewport().maximized.unwrap_or(false)),
-                            ));
-                        }
-                        TitleBarButton::Close => {
-                            if self.system_tray.is_some() {
-                                self.show_close_dialog = true;
-                            } else {
-                                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                            TitleBarButton::AlwaysOnTop => {
+                                self.set_always_on_top(!self.config.window.always_on_top, ctx);
+                            }
+                            TitleBarButton::Mute => {
+                                self.set_muted(!self.muted);
+                            }
+                            TitleBarButton::Profile(index) => {
+                                if let Some(name) = profile_names.get(index) {
+                                    self.use_profile(name, ctx);
+                                }
+                            }
+                            TitleBarButton::Minimize => {
+                                if self.config.system.minimize_to_tray && self.system_tray.is_some()
+                                {
+                                    self.hide_to_tray(ctx);
+                                } else {
+                                    ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+                                }
+                            }
+                            TitleBarButton::Maximize => {
+                                ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(
+                                    !ctx.input(|i| i.viewport().maximized.unwrap_or(false)),
+                                ));
+                            }
+                            TitleBarButton::Close => {
+                                if self.config.system.close_to_tray && self.system_tray.is_some() {
+                                    self.hide_to_tray(ctx);
+                                } else if self.system_tray.is_some() {
+                                    self.show_close_dialog = true;
+                                } else {
+                                    ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                                }
                             }
                         }
                     }
-                }
 
-                // Content area with padding
-                egui::Frame::none()
-                    .inner_margin(egui::Margin::symmetric(
-                        self.theme.spacing_md,
-                        self.theme.spacing_sm,
-                    ))
-                    .show(ui, |ui| {
-                        // Show current view
-                        match self.current_view {
-                            View::Timer => {
-                                let (current_task, queue) = self
-                                    .shared_todo
-                                    .data
-                                    .read()
-                                    .map(|s| (s.queue.first().cloned(), s.queue.clone()))
-                                    .unwrap_or_default();
-                                if let Some(action) = self.timer_view.show(
-                                    ui,
-                                    &self.session,
-                                    &self.theme,
-                                    self.animations.pulse_value(),
-                                    self.config.appearance.window_opacity,
-                                    current_task.as_ref(),
-                                    &queue,
-                                ) {
-                                    self.handle_timer_action(action);
-                                }
-                            }
-                            View::Queue => {
-                                let queue = self
-                                    .shared_todo
-                                    .data
-                                    .read()
-                                    .map(|s| s.queue.clone())
-                                    .unwrap_or_default();
-                                let queue_actions =
-                                    queue_view::render_queue_view(ui, &self.theme, &queue);
-                                for qa in queue_actions {
-                                    match qa {
-                                        queue_view::QueueViewAction::GoBack => {
-                                            self.current_view = View::Timer;
+                    self.show_config_banner(ui);
+                    self.show_error_banner(ui);
+                    self.show_group_banner(ui);
+
+                    // Content area with padding
+                    egui::Frame::none()
+                        .inner_margin(egui::Margin::symmetric(
+                            self.theme.spacing_md,
+                            self.theme.spacing_sm,
+                        ))
+                        .show(ui, |ui| {
+                            // Show current view
+                            match self.current_view {
+                                View::Timer => {
+                                    let (current_task, queue) = self
+                                        .shared_todo
+                                        .data
+                                        .read()
+                                        .map(|s| (s.queue.first().cloned(), s.queue.clone()))
+                                        .unwrap_or_default();
+                                    // Right-click on empty space to pop the timer out
+                                    let background = ui.interact(
+                                        ui.max_rect(),
+                                        ui.id().with("timer_background"),
+                                        egui::Sense::click(),
+                                    );
+                                    let bubble_label = if self.bubble_open {
+                                        crate::i18n::tr().common.dock_timer
+                                    } else {
+                                        crate::i18n::tr().common.pop_out_timer
+                                    };
+                                    background.context_menu(|ui| {
+                                        if ui.button(bubble_label).clicked() {
+                                            self.toggle_bubble();
+                                            ui.close_menu();
                                         }
-                                        queue_view::QueueViewAction::Remove(id) => {
-                                            if let Some(db) = &self.database {
-                                                let _ = db.remove_from_queue(id);
-                                                self.refresh_todo_data();
-                                            }
+                                        if ui.button(crate::i18n::tr().common.kiosk_mode).clicked()
+                                        {
+                                            self.set_kiosk(true, ctx);
+                                            ui.close_menu();
                                         }
-                                        queue_view::QueueViewAction::ClearAll => {
-                                            if let Some(db) = &self.database {
-                                                let _ = db.clear_queue();
-                                                self.refresh_todo_data();
-                                            }
+                                    });
+                                    // Cover the whole content area, margins included
+                                    let image_rect = ui.max_rect().expand2(egui::vec2(
+                                        self.theme.spacing_md,
+                                        self.theme.spacing_sm,
+                                    ));
+                                    let rounding = if is_maximized {
+                                        egui::Rounding::ZERO
+                                    } else {
+                                        let corner = self.theme.window_rounding().sw;
+                                        egui::Rounding {
+                                            sw: corner,
+                                            se: corner,
+                                            ..Default::default()
                                         }
-                                        queue_view::QueueViewAction::Reorder(ids) => {
-                                            if let Some(db) = &self.database {
-                                                let _ = db.reorder_queue(&ids);
-                                                self.refresh_todo_data();
+                                    };
+                                    self.paint_background(ui, image_rect, rounding);
+                                    self.timer_view.plan_progress = self.plan_progress();
+                                    if let Some(action) = self.timer_view.show(
+                                        ui,
+                                        &self.session,
+                                        &self.theme,
+                                        self.animations.pulse_value(),
+                                        self.config.appearance.window_opacity,
+                                        current_task.as_ref(),
+                                        &queue,
+                                        self.session_intent.as_deref(),
+                                    ) {
+                                        self.handle_timer_view_action(action);
+                                    }
+                                }
+                                View::Queue => {
+                                    let queue = self
+                                        .shared_todo
+                                        .data
+                                        .read()
+                                        .map(|s| s.queue.clone())
+                                        .unwrap_or_default();
+                                    let queue_actions =
+                                        queue_view::render_queue_view(ui, &self.theme, &queue);
+                                    for qa in queue_actions {
+                                        match qa {
+                                            queue_view::QueueViewAction::GoBack => {
+                                                self.current_view = View::Timer;
+                                            }
+                                            queue_view::QueueViewAction::Remove(id) => {
+                                                if let Some(db) = &self.database {
+                                                    let _ = db.remove_from_queue(id);
+                                                    self.refresh_todo_data();
+                                                }
+                                            }
+                                            queue_view::QueueViewAction::ClearAll => {
+                                                if let Some(db) = &self.database {
+                                                    let _ = db.clear_queue();
+                                                    self.refresh_todo_data();
+                                                }
+                                            }
+                                            queue_view::QueueViewAction::Reorder(ids) => {
+                                                if let Some(db) = &self.database {
+                                                    let _ = db.reorder_queue(&ids);
+                                                    self.refresh_todo_data();
+                                                }
+                                            }
+                                            queue_view::QueueViewAction::OpenTasks => {
+                                                self.current_view = View::Tasks;
+                                            }
+                                            queue_view::QueueViewAction::OpenPlan => {
+                                                self.open_plan();
                                             }
                                         }
                                     }
                                 }
-                            }
-                            View::Stats => {
-                                if let Some(action) = self.stats_view.show(
-                                    ui,
-                                    &self.session,
-                                    &self.statistics,
-                                    &self.theme,
-                                    self.animations.pulse_value(),
-                                    self.config.goals.daily_target,
-                                ) {
-                                    self.handle_stats_action(action);
+                                View::Tasks => {
+                                    let queue = self
+                                        .shared_todo
+                                        .data
+                                        .read()
+                                        .map(|s| s.queue.clone())
+                                        .unwrap_or_default();
+                                    let actions = self.tasks_view.show(ui, &self.theme, &queue);
+                                    for action in actions {
+                                        self.handle_tasks_action(action);
+                                    }
                                 }
-                            }
-                            View::Settings => {
-                                if let Some(ref mut sv) = self.settings_view {
-                                    settings_action = sv.show(ui, &self.config, &self.theme);
+                                View::Plan => {
+                                    let todos = self
+                                        .shared_todo
+                                        .data
+                                        .read()
+                                        .map(|s| s.todos.clone())
+                                        .unwrap_or_default();
+                                    let actions = self.plan_view.show(ui, &self.theme, &todos);
+                                    for action in actions {
+                                        self.handle_plan_action(action);
+                                    }
+                                }
+                                View::Stats => {
+                                    self.stats_view.plugin_panels = self
+                                        .integrations
+                                        .plugins()
+                                        .map(|plugins| plugins.panels())
+                                        .unwrap_or_default();
+                                    if let Some(action) = self.stats_view.show(
+                                        ui,
+                                        &self.session,
+                                        &self.statistics,
+                                        &self.theme,
+                                        self.animations.pulse_value(),
+                                        self.daily_target(),
+                                    ) {
+                                        self.handle_stats_action(action);
+                                    }
+                                }
+                                View::Settings => {
+                                    if let Some(ref mut sv) = self.settings_view {
+                                        settings_action = sv.show(ui, &self.config, &self.theme);
+                                    }
                                 }
                             }
-                        }
-                    });
-            });
+                        });
+                });
+        }
 
         // Handle settings action outside closure (needs ctx for viewport commands)
         if let Some(action) = settings_action {
@@ -508,11 +945,29 @@ impl eframe::App for PomodoRustApp {
             self.render_close_dialog(ctx);
         }
 
+        self.render_break_overlay(ctx);
+
+        self.render_undo_toast(ctx);
+
+        // Ask whether time away from the computer counts
+        if self.away_prompt_pending() {
+            self.render_away_dialog(ctx);
+        }
+
         // Force quit (from tray Quit action)
         if self.force_quit {
             ctx.send_viewport_cmd(egui::ViewportCommand::Close);
         }
 
+        self.show_bubble_viewport(ctx);
+
+        if ctx.input_mut(|i| {
+            i.consume_key(egui::Modifiers::CTRL | egui::Modifiers::SHIFT, egui::Key::L)
+        }) {
+            self.toggle_log_viewer();
+        }
+        self.show_log_viewer(ctx);
+
         // Show todo window if open
         if self.todo_window.is_open {
             self.show_todo_viewport(ctx);
@@ -520,20 +975,51 @@ impl eframe::App for PomodoRustApp {
 
         // Handle keyboard shortcuts (only when no text field is focused)
         let any_text_focused = ctx.memory(|m| m.focused().is_some());
-        let (space, escape, key_d, key_t, key_q, key_s) = ctx.input(|i| {
-            (
-                i.key_pressed(egui::Key::Space),
-                i.key_pressed(egui::Key::Escape),
-                i.key_pressed(egui::Key::D),
-                i.key_pressed(egui::Key::T),
-                i.key_pressed(egui::Key::Q),
-                i.key_pressed(egui::Key::S),
-            )
-        });
+        let (space, escape, key_b, key_d, key_l, key_m, key_p, key_t, key_q, key_s) =
+            ctx.input(|i| {
+                (
+                    i.key_pressed(egui::Key::Space),
+                    i.key_pressed(egui::Key::Escape),
+                    i.key_pressed(egui::Key::B),
+                    i.key_pressed(egui::Key::D),
+                    i.key_pressed(egui::Key::L),
+                    i.key_pressed(egui::Key::M),
+                    i.key_pressed(egui::Key::P),
+                    i.key_pressed(egui::Key::T),
+                    i.key_pressed(egui::Key::Q),
+                    i.key_pressed(egui::Key::S),
+                )
+            });
+
+        if self.kiosk {
+            if space && !any_text_focused {
+                self.handle_timer_action(TimerAction::Toggle);
+            }
+            if escape {
+                self.set_kiosk(false, ctx);
+            }
+            return;
+        }
+
+        if self.config.window.mini_mode {
+            if space && !any_text_focused {
+                self.handle_timer_action(TimerAction::Toggle);
+            }
+            if escape {
+                self.set_mini_mode(false, ctx);
+            }
+            return;
+        }
 
         if !any_text_focused {
+            if key_b {
+                self.toggle_bubble();
+            }
+            if key_m {
+                self.set_muted(!self.muted);
+            }
             if space && self.current_view == View::Timer {
-                self.handle_timer_action(TimerAction::Toggle);
+                self.handle_timer_view_action(TimerAction::Toggle);
             }
             if key_d && self.current_view == View::Timer {
                 self.current_view = View::Stats;
@@ -544,9 +1030,15 @@ impl eframe::App for PomodoRustApp {
                     self.shared_todo.dwm_applied.store(false, Ordering::Relaxed);
                 }
             }
+            if key_l && self.current_view == View::Timer {
+                self.current_view = View::Tasks;
+            }
             if key_q && self.current_view == View::Timer {
                 self.current_view = View::Queue;
             }
+            if key_p && self.current_view == View::Timer {
+                self.open_plan();
+            }
             if key_s && self.current_view == View::Timer {
                 self.settings_view = Some(SettingsView::new(&self.config));
                 self.current_view = View::Settings;
@@ -554,7 +1046,7 @@ impl eframe::App for PomodoRustApp {
         }
         if escape {
             match self.current_view {
-                View::Stats | View::Settings | View::Queue => {
+                View::Stats | View::Settings | View::Queue | View::Tasks | View::Plan => {
                     self.current_view = View::Timer;
                     self.settings_view = None;
                 }
@@ -570,6 +1062,21 @@ impl eframe::App for PomodoRustApp {
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        // Count the focus time of a session cut short by quitting
+        self.record_unfinished_session();
+        self.save_app_usage();
+
+        if self.dnd_active {
+            crate::platform::set_do_not_disturb(false);
+        }
+        if let (true, Some(media)) = (self.media_paused, &self.media) {
+            media.resume();
+        }
+        if let Some(blocker) = self.blocker.take() {
+            blocker.shutdown();
+        }
+        self.ipc_server.stop();
+
         // Save window state to config
         if let Some(size) = self.last_window_size {
             self.config.window.width = size.x;
@@ -582,6 +1089,7 @@ impl eframe::App for PomodoRustApp {
         }
 
         self.config.window.maximized = self.last_window_maximized;
+        self.remember_bubble_position();
 
         // Save todo window position/size (stored in signals by deferred viewport)
         if let Ok(sig) = self.shared_todo.signals.lock() {
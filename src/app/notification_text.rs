@@ -0,0 +1,96 @@
+//! Text of the notification shown when a session ends, either the built-in
+//! strings or the templates set in the settings
+
+use super::PomodoRustApp;
+use crate::core::SessionType;
+
+/// Replace the `{name}` placeholders of `template`, unknown ones are kept
+fn fill(template: &str, vars: &[(&str, String)]) -> String {
+    vars.iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+}
+
+impl PomodoRustApp {
+    /// Title and body for the end of a `session_type` session. The session
+    /// has already moved on to the next one.
+    pub(super) fn completion_text(
+        &self,
+        session_type: SessionType,
+        goal_just_reached: bool,
+    ) -> (String, String) {
+        let t = crate::i18n::tr();
+        let templates = &self.config.notification_text;
+        let (custom, title, body) = if goal_just_reached && self.config.goals.notify_on_goal {
+            (
+                &templates.goal_reached,
+                t.notif.daily_goal_reached,
+                format!("{} {}", self.daily_target(), t.settings.pomodoros),
+            )
+        } else {
+            match session_type {
+                SessionType::Work => (
+                    &templates.focus_complete,
+                    t.notif.focus_complete,
+                    t.notif.time_for_break.to_string(),
+                ),
+                SessionType::ShortBreak => (
+                    &templates.break_complete,
+                    t.notif.break_over,
+                    t.notif.ready_to_focus.to_string(),
+                ),
+                SessionType::LongBreak => (
+                    &templates.long_break_complete,
+                    t.notif.long_break_over,
+                    t.notif.back_to_work.to_string(),
+                ),
+            }
+        };
+
+        let session_name = match session_type {
+            SessionType::Work => t.tray.focus,
+            SessionType::ShortBreak => t.tray.short_break,
+            SessionType::LongBreak => t.tray.long_break,
+        };
+        let vars = [
+            ("session_type", session_name.to_string()),
+            (
+                "next_duration",
+                (self.session.timer().total_duration().as_secs() / 60).to_string(),
+            ),
+            ("today_count", self.statistics.today_pomodoros.to_string()),
+        ];
+        let pick = |template: &str, default: String| {
+            if template.trim().is_empty() {
+                default
+            } else {
+                fill(template, &vars)
+            }
+        };
+        (
+            pick(&custom.title, title.to_string()),
+            pick(&custom.body, body),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_replaces_known_placeholders() {
+        let vars = [
+            ("session_type", "Focus".to_string()),
+            ("next_duration", "5".to_string()),
+        ];
+        assert_eq!(
+            fill(
+                "{session_type} done, {next_duration} min break {unknown}",
+                &vars
+            ),
+            "Focus done, 5 min break {unknown}"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Notion sync: queues completed focus sessions in the local database and
+//! sends them in batches whenever Notion can be reached
+
+use super::PomodoRustApp;
+use crate::core::SessionType;
+use crate::integrations::{CompletedSession, NotionClient};
+
+/// Rows sent per batch
+const BATCH_SIZE: usize = 20;
+
+impl PomodoRustApp {
+    /// Start or stop the client with the config, take in the replies and
+    /// send the next batch when it's time
+    pub(super) fn update_notion(&mut self, ctx: &egui::Context) {
+        if !self.config.notion.is_active() {
+            self.notion = None;
+            return;
+        }
+        let client = self
+            .notion
+            .get_or_insert_with(|| NotionClient::start(self.config.notion.clone(), ctx.clone()));
+        let Some(db) = &self.database else {
+            return;
+        };
+
+        let mut failed = None;
+        let was_failing = client.failing();
+        while let Some(reply) = client.poll() {
+            if let Err(e) = db.remove_notion_rows(&reply.sent) {
+                tracing::warn!("DB remove_notion_rows: {e}");
+            }
+            if !reply.sent.is_empty() {
+                tracing::info!("Added {} sessions to Notion", reply.sent.len());
+            }
+            failed = reply.error;
+        }
+
+        if client.ready() {
+            match db.notion_rows(BATCH_SIZE) {
+                Ok(rows) => client.send(rows),
+                Err(e) => tracing::warn!("DB notion_rows: {e}"),
+            }
+        }
+
+        // Once per outage, the rows are retried quietly after that
+        if let Some(e) = failed.filter(|_| !was_failing) {
+            self.show_error(format!("{}: {}", crate::i18n::tr().notif.notion_failed, e));
+        }
+    }
+
+    /// Keep a completed focus session for Notion
+    pub(super) fn queue_notion_row(&mut self, session: &CompletedSession) {
+        if !self.config.notion.is_active() || session.session_type != SessionType::Work {
+            return;
+        }
+        let Some(db) = &self.database else {
+            return;
+        };
+        if let Err(e) = db.queue_notion_row(
+            &session.start,
+            session.minutes,
+            session.task.as_deref(),
+            session.tag.as_deref(),
+        ) {
+            tracing::warn!("DB queue_notion_row: {e}");
+        }
+        if let Some(client) = &mut self.notion {
+            client.wake();
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Session log in the Obsidian daily note
+
+use super::PomodoRustApp;
+use crate::core::SessionType;
+use crate::integrations::{obsidian, CompletedSession};
+
+impl PomodoRustApp {
+    /// Append a completed session to today's daily note
+    pub(super) fn log_daily_note(&mut self, entry: &CompletedSession) {
+        let config = &self.config.obsidian;
+        if !config.is_active() || (entry.session_type != SessionType::Work && !config.log_breaks) {
+            return;
+        }
+        match obsidian::append_entry(config, entry) {
+            Ok(path) => tracing::debug!("Logged session to {}", path.display()),
+            Err(e) => {
+                tracing::warn!("Failed to write the daily note: {}", e);
+                self.show_error(format!(
+                    "{}: {}",
+                    crate::i18n::tr().notif.daily_note_failed,
+                    e
+                ));
+            }
+        }
+    }
+}
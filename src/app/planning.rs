@@ -0,0 +1,86 @@
+//! Day plans: the pomodoros meant for each task on a day
+//!
+//! Tomorrow is laid out in the plan view. On the day, the planned tasks are
+//! queued the first time the timer is idle (once per day, even across
+//! restarts), so the pomodoros done on them count towards the plan, and the
+//! timer view shows the plan against what was done.
+
+use chrono::Local;
+
+use super::{PomodoRustApp, View};
+use crate::core::TimerState;
+use crate::data::Statistics;
+use crate::ui::plan::{PlanAction, PlanDay};
+
+impl PomodoRustApp {
+    /// Queue today's planned tasks before the first session of the day
+    pub(super) fn update_planning(&mut self) {
+        let today = Local::now().date_naive();
+        if self.plan_date == Some(today) || self.session.timer().state() != TimerState::Idle {
+            return;
+        }
+        // Running past midnight, today's plan isn't loaded yet
+        let new_day = self.plan_date.is_some();
+        self.plan_date = Some(today);
+        let Some(db) = &self.database else { return };
+
+        let queued = db.queue_day_plan(today).unwrap_or_else(|e| {
+            tracing::warn!("Failed to queue today's planned tasks: {}", e);
+            0
+        });
+        if new_day {
+            self.statistics = Statistics::load_within(db, &self.config.working_hours);
+        }
+        if queued > 0 {
+            self.refresh_todo_data();
+        }
+    }
+
+    /// Today's pomodoros done and planned, while there's a plan for today
+    pub(super) fn plan_progress(&self) -> Option<(i32, i32)> {
+        let plan = &self.statistics.today_plan;
+        (plan.planned > 0).then_some((plan.done, plan.planned))
+    }
+
+    /// Show the plan view
+    pub(super) fn open_plan(&mut self) {
+        self.current_view = View::Plan;
+        self.refresh_plan();
+    }
+
+    /// Load the plan of the day shown in the plan view
+    fn refresh_plan(&mut self) {
+        let Some(db) = &self.database else { return };
+        self.plan_view.plan = db
+            .get_day_plan(self.plan_view.day.date())
+            .unwrap_or_default();
+    }
+
+    pub(super) fn handle_plan_action(&mut self, action: PlanAction) {
+        match action {
+            PlanAction::GoBack => {
+                self.current_view = View::Timer;
+            }
+            PlanAction::ChangeDay(day) => {
+                self.plan_view.day = day;
+                self.refresh_plan();
+            }
+            PlanAction::SetPlanned { todo_id, planned } => {
+                let Some(db) = &self.database else { return };
+                let date = self.plan_view.day.date();
+                if let Err(e) = db.set_planned_pomodoros(date, todo_id, planned) {
+                    tracing::warn!("Plan update failed: {e}");
+                }
+                // Today's plan is already under way
+                if self.plan_view.day == PlanDay::Today {
+                    if planned > 0 {
+                        let _ = db.add_to_queue(todo_id, planned);
+                    }
+                    self.statistics = Statistics::load_within(db, &self.config.working_hours);
+                    self.refresh_todo_data();
+                }
+                self.refresh_plan();
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Power saver: go easy on the battery
+//!
+//! While the machine runs on battery or in battery saver mode (and
+//! `accessibility.power_saver` is on) the theme switches to reduced motion,
+//! which stops the timer pulse, so the running timer only repaints when its
+//! second changes instead of every frame.
+//!
+//! Sleep: the running session is paused when the system suspends and the
+//! `[sleep]` policy decides whether the time asleep counts. After waking up
+//! the global hotkeys, the IPC listeners and the tray icon are set up again,
+//! since the OS may have dropped them.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::data::SleepPolicy;
+use crate::platform::{SleepEvent, SystemTray};
+
+use super::PomodoRustApp;
+
+/// How often the power state is checked
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Background sampler of the battery / battery saver state
+pub(super) struct PowerMonitor {
+    saving: Arc<AtomicBool>,
+    running: Arc<AtomicBool>,
+}
+
+impl PowerMonitor {
+    /// Start polling, waking the UI when the power state changes
+    pub fn start(ctx: egui::Context) -> Self {
+        let saving = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let (sample, active) = (saving.clone(), running.clone());
+        let spawned = thread::Builder::new()
+            .name("power-monitor".into())
+            .spawn(move || {
+                while active.load(Ordering::Relaxed) {
+                    let is_saving = crate::platform::is_power_saving().unwrap_or(false);
+                    if sample.swap(is_saving, Ordering::Relaxed) != is_saving {
+                        ctx.request_repaint();
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start power monitor: {}", e);
+        }
+
+        Self { saving, running }
+    }
+
+    /// Whether the system is currently saving power
+    pub fn is_saving(&self) -> bool {
+        self.saving.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for PowerMonitor {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl PomodoRustApp {
+    /// Track the power state and throttle animations while saving power
+    pub(super) fn update_power_saver(&mut self, ctx: &egui::Context) {
+        let saving = if self.config.accessibility.power_saver {
+            self.power_monitor
+                .get_or_insert_with(|| PowerMonitor::start(ctx.clone()))
+                .is_saving()
+        } else {
+            self.power_monitor = None;
+            false
+        };
+
+        if saving != self.power_saving {
+            tracing::info!(
+                "Power saver {}",
+                if saving { "engaged" } else { "released" }
+            );
+            self.power_saving = saving;
+            if !saving {
+                self.theme = Self::theme_for(&self.config);
+                self.todo_theme_dirty = true;
+            }
+        }
+
+        // Theme rebuilds (settings, OS appearance) drop the override
+        if saving && !self.theme.reduced_motion {
+            self.theme = self.theme.clone().with_reduced_motion();
+            self.todo_theme_dirty = true;
+        }
+    }
+
+    /// React to the system going to sleep and waking up
+    pub(super) fn handle_sleep_events(&mut self) {
+        while let Some(event) = self.sleep_monitor.poll() {
+            match event {
+                SleepEvent::Suspending => {
+                    if self.session.timer().is_running() {
+                        self.session.pause();
+                        self.sleep_paused = true;
+                    }
+                }
+                SleepEvent::Resumed { slept } => {
+                    self.apply_sleep_policy(slept);
+                    self.rearm_after_wake();
+                }
+            }
+        }
+    }
+
+    /// Continue or keep pausing the session interrupted by sleep
+    fn apply_sleep_policy(&mut self, slept: Duration) {
+        let paused_for_sleep = std::mem::take(&mut self.sleep_paused);
+        let running = self.session.timer().is_running();
+        if !paused_for_sleep && !running {
+            return;
+        }
+
+        match self.config.sleep.policy {
+            SleepPolicy::Pause => {
+                // Without a suspend notice the session is still running
+                if running {
+                    self.session.pause();
+                }
+            }
+            SleepPolicy::Continue => {
+                self.session
+                    .timer_mut()
+                    .adjust_elapsed(slept.as_secs() as i64);
+                if paused_for_sleep {
+                    self.session.start();
+                }
+            }
+        }
+    }
+
+    /// Set up the OS integrations that may not survive a suspend
+    fn rearm_after_wake(&mut self) {
+        if self.config.hotkeys.enabled {
+            self.restart_hotkeys();
+        }
+
+        self.restart_ipc();
+
+        if self.system_tray.is_some() {
+            // Drop the old icon first so the new one takes its place
+            self.system_tray = None;
+            self.system_tray = SystemTray::new().ok();
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Away detection: react to the user leaving the computer
+//!
+//! Input idle time and the screen lock state are polled on a background
+//! thread (the Linux and macOS backends shell out).
+//! - Idle: once idle time passes the configured threshold the running focus
+//!   session is paused; when input resumes the user is asked whether the
+//!   time away should count towards the session.
+//! - Screen lock: depending on `[screen_lock]`, locking pauses the focus
+//!   session until unlock and/or starts the pending break.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::SessionType;
+
+use super::PomodoRustApp;
+
+/// How often idle time and lock state are sampled
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Marker for "idle time unknown"
+const UNKNOWN: u64 = u64::MAX;
+
+/// Screen lock samples
+const UNLOCKED: u8 = 0;
+const LOCKED: u8 = 1;
+const LOCK_UNKNOWN: u8 = 2;
+
+/// Background sampler of the input idle time and screen lock state
+pub(super) struct PresenceMonitor {
+    idle_ms: Arc<AtomicU64>,
+    locked: Arc<AtomicU8>,
+    running: Arc<AtomicBool>,
+}
+
+impl PresenceMonitor {
+    /// Start sampling on a background thread
+    pub fn start() -> Self {
+        let idle_ms = Arc::new(AtomicU64::new(UNKNOWN));
+        let locked = Arc::new(AtomicU8::new(LOCK_UNKNOWN));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let (idle, lock, active) = (idle_ms.clone(), locked.clone(), running.clone());
+        let spawned = thread::Builder::new()
+            .name("presence-monitor".into())
+            .spawn(move || {
+                while active.load(Ordering::Relaxed) {
+                    let sample =
+                        crate::platform::idle_duration().map_or(UNKNOWN, |d| d.as_millis() as u64);
+                    idle.store(sample, Ordering::Relaxed);
+                    let sample = match crate::platform::is_screen_locked() {
+                        Some(true) => LOCKED,
+                        Some(false) => UNLOCKED,
+                        None => LOCK_UNKNOWN,
+                    };
+                    lock.store(sample, Ordering::Relaxed);
+                    thread::sleep(POLL_INTERVAL);
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start presence monitor: {}", e);
+        }
+
+        Self {
+            idle_ms,
+            locked,
+            running,
+        }
+    }
+
+    /// Latest idle time sample
+    pub fn idle(&self) -> Option<Duration> {
+        match self.idle_ms.load(Ordering::Relaxed) {
+            UNKNOWN => None,
+            ms => Some(Duration::from_millis(ms)),
+        }
+    }
+
+    /// Latest screen lock sample
+    pub fn locked(&self) -> Option<bool> {
+        match self.locked.load(Ordering::Relaxed) {
+            LOCKED => Some(true),
+            UNLOCKED => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl Drop for PresenceMonitor {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+/// A focus session paused because the user walked away
+pub(super) struct AwayState {
+    /// When input stopped
+    since: Instant,
+    /// Idle seconds the timer kept running for before it was paused
+    ran_idle_secs: u64,
+    /// Total seconds away, set once input resumes
+    returned_after: Option<u64>,
+}
+
+/// Screen lock tracking
+#[derive(Default)]
+pub(super) struct LockState {
+    /// Last observed lock state
+    locked: bool,
+    /// Whether we paused the focus session on lock
+    paused_work: bool,
+}
+
+impl PomodoRustApp {
+    /// React to idleness and screen locks
+    pub(super) fn check_presence(&mut self, ctx: &egui::Context) {
+        let lock_enabled = self.config.screen_lock.is_enabled();
+        if !self.config.idle.enabled && !lock_enabled {
+            self.presence_monitor = None;
+            self.away = None;
+            self.lock_state = LockState::default();
+            return;
+        }
+
+        let monitor = self
+            .presence_monitor
+            .get_or_insert_with(PresenceMonitor::start);
+        let (idle, locked) = (monitor.idle(), monitor.locked());
+
+        if lock_enabled {
+            if let Some(locked) = locked {
+                self.check_screen_lock(locked);
+            }
+        }
+        if self.config.idle.enabled {
+            if let Some(idle) = idle {
+                self.check_idle(idle, ctx);
+            }
+        } else {
+            self.away = None;
+        }
+
+        // Keep sampling while the app may be sitting in the background
+        ctx.request_repaint_after(POLL_INTERVAL);
+    }
+
+    /// Apply the screen lock policy on lock/unlock transitions
+    fn check_screen_lock(&mut self, locked: bool) {
+        if locked == self.lock_state.locked {
+            return;
+        }
+        self.lock_state.locked = locked;
+        let policy = &self.config.screen_lock;
+        let timer = self.session.timer();
+
+        if locked {
+            let is_work = self.session.session_type() == SessionType::Work;
+            if policy.pause_work && is_work && timer.is_running() {
+                tracing::info!("Screen locked, pausing focus session");
+                self.session.pause();
+                self.lock_state.paused_work = true;
+            } else if policy.count_as_break && !is_work && !timer.is_running() {
+                tracing::info!("Screen locked, starting break");
+                self.session.start();
+            }
+        } else if std::mem::take(&mut self.lock_state.paused_work) && timer.is_paused() {
+            tracing::info!("Screen unlocked, resuming focus session");
+            self.session.start();
+        }
+    }
+
+    /// Pause the focus session on idle and notice when the user is back
+    fn check_idle(&mut self, idle: Duration, ctx: &egui::Context) {
+        let paused = self.session.timer().is_paused();
+
+        match &mut self.away {
+            None => {
+                let threshold =
+                    Duration::from_secs(self.config.idle.threshold_minutes.max(1) as u64 * 60);
+                let working = self.session.session_type() == SessionType::Work
+                    && self.session.timer().is_running();
+                if working && idle >= threshold {
+                    self.session.pause();
+                    tracing::info!("Idle for {}s, pausing focus session", idle.as_secs());
+                    self.away = Some(AwayState {
+                        since: Instant::now()
+                            .checked_sub(idle)
+                            .unwrap_or_else(Instant::now),
+                        ran_idle_secs: idle.as_secs(),
+                        returned_after: None,
+                    });
+                }
+            }
+            // Resumed or reset by other means: nothing to ask
+            Some(_) if !paused => self.away = None,
+            Some(away) if away.returned_after.is_none() => {
+                if idle < POLL_INTERVAL * 2 {
+                    away.returned_after = Some(away.since.elapsed().as_secs());
+                    ctx.request_repaint();
+                }
+            }
+            Some(_) => {}
+        }
+    }
+
+    /// Whether the "count time away?" prompt should be shown
+    pub(super) fn away_prompt_pending(&self) -> bool {
+        self.away
+            .as_ref()
+            .is_some_and(|a| a.returned_after.is_some())
+    }
+
+    /// Ask whether the time away counts, then resume the session
+    pub(super) fn render_away_dialog(&mut self, ctx: &egui::Context) {
+        let Some(away_secs) = self.away.as_ref().and_then(|a| a.returned_after) else {
+            return;
+        };
+
+        let t = crate::i18n::tr();
+        let mut count_it = None;
+        egui::Window::new(t.away.title)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.add_space(8.0);
+                ui.label(format!(
+                    "{} {} {}.",
+                    t.away.away_for,
+                    away_secs.div_ceil(60),
+                    t.common.min
+                ));
+                ui.label(t.away.question);
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui.button(t.away.count_it).clicked() {
+                        count_it = Some(true);
+                    }
+                    if ui.button(t.away.discard).clicked() {
+                        count_it = Some(false);
+                    }
+                });
+                ui.add_space(4.0);
+            });
+
+        let Some(count_it) = count_it else {
+            return;
+        };
+        if let Some(away) = self.away.take() {
+            // The timer ran for `ran_idle_secs` before pausing
+            let delta = if count_it {
+                away_secs.saturating_sub(away.ran_idle_secs) as i64
+            } else {
+                -(away.ran_idle_secs as i64)
+            };
+            self.session.timer_mut().adjust_elapsed(delta);
+            self.session.start();
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Named settings profiles
+//!
+//! A profile holds its own durations, sounds, goals and hotkeys. The one in
+//! use keeps whatever is changed while it's active, so switching back and
+//! forth never loses settings.
+
+use super::PomodoRustApp;
+use crate::ipc::IpcResponse;
+
+impl PomodoRustApp {
+    /// Names of the saved profiles and the one in use
+    pub(super) fn profile_names(&self) -> (Vec<String>, Option<String>) {
+        let profiles = &self.config.profiles;
+        (
+            profiles.saved.iter().map(|p| p.name.clone()).collect(),
+            profiles.active.clone(),
+        )
+    }
+
+    /// Switch to a saved profile and apply its settings
+    pub(super) fn use_profile(&mut self, name: &str, ctx: &egui::Context) -> Option<String> {
+        let mut config = self.config.clone();
+        let name = config.use_profile(name)?;
+        self.apply_profile_change(config, ctx);
+        self.show_status(format!(
+            "{}: {}",
+            crate::i18n::tr().notif.profile_switched,
+            name
+        ));
+        Some(name)
+    }
+
+    /// Save the current settings as a profile and make it the active one
+    pub(super) fn save_profile(&mut self, name: &str, ctx: &egui::Context) {
+        let mut config = self.config.clone();
+        config.save_profile(name);
+        self.apply_profile_change(config, ctx);
+        self.show_status(format!(
+            "{}: {}",
+            crate::i18n::tr().notif.profile_saved,
+            name.trim()
+        ));
+    }
+
+    /// Delete a saved profile
+    pub(super) fn delete_profile(&mut self, name: &str, ctx: &egui::Context) {
+        let mut config = self.config.clone();
+        if config.delete_profile(name) {
+            self.apply_profile_change(config, ctx);
+        }
+    }
+
+    fn apply_profile_change(&mut self, config: crate::data::Config, ctx: &egui::Context) {
+        self.reconfigure(config, ctx);
+        if let Some(ref mut sv) = self.settings_view {
+            sv.reset_from_config(&self.config);
+        }
+    }
+
+    /// Switch profiles for `pomodorust profile use <name>`
+    pub(super) fn ipc_use_profile(&mut self, name: &str, ctx: &egui::Context) -> IpcResponse {
+        match self.use_profile(name, ctx) {
+            Some(name) => IpcResponse::ok_with_message(format!("Profile {}", name)),
+            None => IpcResponse::error(format!("Unknown profile: {}", name)),
+        }
+    }
+
+    /// Saved profiles for `pomodorust profile list`
+    pub(super) fn ipc_list_profiles(&self) -> IpcResponse {
+        let (profiles, active) = self.profile_names();
+        IpcResponse::Profiles { profiles, active }
+    }
+}
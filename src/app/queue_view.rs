@@ -8,6 +8,8 @@ pub(super) enum QueueViewAction {
     Remove(i64),
     ClearAll,
     Reorder(Vec<i64>),
+    OpenTasks,
+    OpenPlan,
 }
 
 /// Wrapper for DnD: implements DragDropItem via unique queue id.
@@ -58,19 +60,45 @@ pub(super) fn render_queue_view(
                 .color(theme.text_primary),
         );
 
-        if !queue.is_empty() {
-            let total: u32 = queue
-                .iter()
-                .map(|t| t.planned_pomodoros.saturating_sub(t.completed_pomodoros))
-                .sum();
-            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let (edit_rect, edit_resp) =
+                ui.allocate_exact_size(egui::vec2(18.0, 18.0), egui::Sense::click());
+            let ir = egui::Rect::from_center_size(edit_rect.center(), egui::vec2(12.0, 12.0));
+            let edit_color = if edit_resp.hovered() {
+                theme.text_primary
+            } else {
+                theme.text_secondary
+            };
+            draw_icon(ui, Icon::ListTodo, ir, edit_color);
+            if edit_resp.on_hover_text(t.tasks.open).clicked() {
+                actions.push(QueueViewAction::OpenTasks);
+            }
+
+            let (plan_rect, plan_resp) =
+                ui.allocate_exact_size(egui::vec2(18.0, 18.0), egui::Sense::click());
+            let ir = egui::Rect::from_center_size(plan_rect.center(), egui::vec2(12.0, 12.0));
+            let plan_color = if plan_resp.hovered() {
+                theme.text_primary
+            } else {
+                theme.text_secondary
+            };
+            draw_icon(ui, Icon::Calendar, ir, plan_color);
+            if plan_resp.on_hover_text(t.plan.open).clicked() {
+                actions.push(QueueViewAction::OpenPlan);
+            }
+
+            if !queue.is_empty() {
+                let total: u32 = queue
+                    .iter()
+                    .map(|t| t.planned_pomodoros.saturating_sub(t.completed_pomodoros))
+                    .sum();
                 ui.label(
                     egui::RichText::new(format!("{} pom.", total))
                         .size(12.0)
                         .color(theme.text_muted),
                 );
-            });
-        }
+            }
+        });
     });
 
     ui.add_space(theme.spacing_sm);
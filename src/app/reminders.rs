@@ -0,0 +1,134 @@
+//! Reminders for a finished session nobody has acted on
+//!
+//! When a session ends without auto-starting the next one, the notification
+//! is repeated at the minutes set in `reminders.steps_minutes`, each time
+//! more insistent: the taskbar button flashes longer, from the second
+//! reminder on the window asks for attention urgently and the notification
+//! sound plays. Starting or skipping the next session, a notification
+//! button, or a click or key press in the window stops the reminders.
+
+use std::time::{Duration, Instant};
+
+use super::PomodoRustApp;
+use crate::core::SessionType;
+
+/// Taskbar flashes of the first reminder, each later one flashes this many more
+const FLASHES_PER_STEP: u32 = 5;
+
+/// A finished session waiting to be acknowledged
+pub(super) struct PendingReminder {
+    /// Type of the session that ended, for the notification text
+    finished: SessionType,
+    /// Type of the session waiting to start, skipping it acknowledges too
+    next: SessionType,
+    since: Instant,
+    /// Reminders sent so far
+    sent: usize,
+}
+
+/// Time until the next reminder is due, `None` once all were sent
+fn until_next_step(steps: &[u32], sent: usize, elapsed: Duration) -> Option<Duration> {
+    let minutes = *steps.get(sent)?;
+    Some(Duration::from_secs(minutes as u64 * 60).saturating_sub(elapsed))
+}
+
+impl PomodoRustApp {
+    /// Remind of the session that just ended until it is acknowledged
+    pub(super) fn start_reminders(&mut self, finished: SessionType) {
+        self.reminder = self.config.reminders.enabled.then(|| PendingReminder {
+            finished,
+            next: self.session.session_type(),
+            since: Instant::now(),
+            sent: 0,
+        });
+    }
+
+    /// Stop reminding, the finished session has been noticed
+    pub(super) fn acknowledge_reminders(&mut self) {
+        self.reminder = None;
+    }
+
+    /// Send the reminders that are due, or stop once the session is acted on
+    pub(super) fn update_reminders(&mut self, ctx: &egui::Context) {
+        let Some(reminder) = &self.reminder else {
+            return;
+        };
+        let timer = self.session.timer();
+        let touched = ctx.input(|i| {
+            i.pointer.any_pressed()
+                || i.events
+                    .iter()
+                    .any(|e| matches!(e, egui::Event::Key { pressed: true, .. }))
+        });
+        if !self.config.reminders.enabled
+            || timer.is_running()
+            || timer.is_paused()
+            || self.session.session_type() != reminder.next
+            || touched
+        {
+            self.acknowledge_reminders();
+            return;
+        }
+
+        let steps = &self.config.reminders.steps_minutes;
+        let elapsed = reminder.since.elapsed();
+        match until_next_step(steps, reminder.sent, elapsed) {
+            None => self.acknowledge_reminders(),
+            Some(wait) if !wait.is_zero() => ctx.request_repaint_after(wait),
+            Some(_) => {
+                let (finished, step) = (reminder.finished, reminder.sent);
+                if let Some(reminder) = &mut self.reminder {
+                    reminder.sent += 1;
+                }
+                self.send_reminder(finished, step, ctx);
+                ctx.request_repaint();
+            }
+        }
+    }
+
+    /// Repeat the completion notification, louder with each `step`
+    fn send_reminder(&mut self, finished: SessionType, step: usize, ctx: &egui::Context) {
+        tracing::info!(
+            "Reminder {} of the finished {:?} session",
+            step + 1,
+            finished
+        );
+        if self.config.system.notifications_enabled {
+            let (title, body) = self.completion_text(finished, false);
+            crate::platform::show_notification(&title, &body);
+        }
+
+        crate::platform::flash_pomodorust_window(FLASHES_PER_STEP * (step as u32 + 1));
+        let urgency = if step == 0 {
+            egui::UserAttentionType::Informational
+        } else {
+            egui::UserAttentionType::Critical
+        };
+        ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(urgency));
+
+        if step > 0 && self.config.reminders.sound && self.sounds_audible() {
+            if let Some(ref mut audio) = self.audio {
+                audio.play_notification(self.config.sounds.notification_sound);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn min(minutes: u64) -> Duration {
+        Duration::from_secs(minutes * 60)
+    }
+
+    #[test]
+    fn test_reminder_steps() {
+        let steps = [2, 5, 10];
+        assert_eq!(until_next_step(&steps, 0, min(1)), Some(min(1)));
+        assert_eq!(until_next_step(&steps, 0, min(3)), Some(Duration::ZERO));
+        assert_eq!(until_next_step(&steps, 1, min(3)), Some(min(2)));
+        assert_eq!(until_next_step(&steps, 3, min(30)), None);
+        assert_eq!(until_next_step(&[], 0, min(30)), None);
+    }
+}
@@ -0,0 +1,239 @@
+//! Banner under the title bar listing problems that came up while running,
+//! such as a database that won't open, so part of the app isn't quietly
+//! off. Problems that can be tried again get a retry button.
+
+use std::mem::discriminant;
+
+use super::PomodoRustApp;
+use crate::data::{Config, Database, Statistics};
+
+/// Something that failed, with the error message
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum RuntimeError {
+    /// The database didn't open, so nothing is recorded
+    Database(String),
+    /// A completed session couldn't be written to the database
+    RecordSession(String),
+    /// Settings couldn't be written to config.toml
+    ConfigSave(String),
+    /// Starting with the system couldn't be turned on or off
+    Autostart(String),
+    /// Global hotkeys that couldn't be registered
+    Hotkeys(String),
+    /// Statistics couldn't be exported
+    Export(String),
+    /// The command line socket isn't on its usual port, or on none at all
+    Ipc(String),
+    /// The group session couldn't be hosted
+    Group(String),
+}
+
+impl RuntimeError {
+    /// What the problem means for the user
+    fn summary(&self) -> &'static str {
+        let t = &crate::i18n::tr().errors;
+        match self {
+            RuntimeError::Database(_) => t.database,
+            RuntimeError::RecordSession(_) => t.record_session,
+            RuntimeError::ConfigSave(_) => t.config_save,
+            RuntimeError::Autostart(_) => t.autostart,
+            RuntimeError::Hotkeys(_) => t.hotkeys,
+            RuntimeError::Export(_) => t.export,
+            RuntimeError::Ipc(_) => t.ipc,
+            RuntimeError::Group(_) => t.group,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            RuntimeError::Database(message)
+            | RuntimeError::RecordSession(message)
+            | RuntimeError::ConfigSave(message)
+            | RuntimeError::Autostart(message)
+            | RuntimeError::Hotkeys(message)
+            | RuntimeError::Export(message)
+            | RuntimeError::Ipc(message)
+            | RuntimeError::Group(message) => message,
+        }
+    }
+
+    /// Whether trying again could help, as opposed to a one-off failure
+    fn can_retry(&self) -> bool {
+        !matches!(
+            self,
+            RuntimeError::RecordSession(_) | RuntimeError::Export(_)
+        )
+    }
+}
+
+/// Open the database with the rest days of the config
+pub(super) fn open_database(config: &Config) -> Result<Database, String> {
+    let mut db = Database::open().map_err(|e| {
+        tracing::error!("Failed to initialize database: {}", e);
+        e.to_string()
+    })?;
+    tracing::info!("Database initialized");
+    db.set_rest_days(config.goals.rest_days);
+    Ok(db)
+}
+
+impl PomodoRustApp {
+    /// List a problem in the banner, replacing an older one of its kind
+    pub(super) fn report_error(&mut self, error: RuntimeError) {
+        self.runtime_errors
+            .retain(|e| discriminant(e) != discriminant(&error));
+        self.runtime_errors.push(error);
+    }
+
+    /// Take problems of the kind of `error` off the banner, once they're
+    /// dealt with
+    pub(super) fn clear_error(&mut self, error: &RuntimeError) {
+        self.runtime_errors
+            .retain(|e| discriminant(e) != discriminant(error));
+    }
+
+    /// Save the config, reporting when it can't be
+    pub(super) fn save_config(&mut self) {
+        let kind = RuntimeError::ConfigSave(String::new());
+        match self.config.save() {
+            Ok(()) => self.clear_error(&kind),
+            Err(e) => {
+                tracing::error!("Failed to save config: {}", e);
+                self.report_error(RuntimeError::ConfigSave(e.to_string()));
+            }
+        }
+    }
+
+    /// Apply the autostart setting, reporting when it can't be
+    pub(super) fn apply_autostart(&mut self, enabled: bool) {
+        match crate::platform::set_autostart(enabled) {
+            Ok(()) => self.clear_error(&RuntimeError::Autostart(String::new())),
+            Err(e) => {
+                tracing::error!("Failed to set autostart: {}", e);
+                self.report_error(RuntimeError::Autostart(e.to_string()));
+            }
+        }
+    }
+
+    /// Report hotkeys the manager couldn't register
+    pub(super) fn check_hotkey_failures(&mut self) {
+        let failures = self.hotkey_manager.take_failures();
+        if !failures.is_empty() {
+            self.report_error(RuntimeError::Hotkeys(failures.join(", ")));
+        }
+    }
+
+    /// Report where the command line socket ended up, when it isn't on the
+    /// configured port
+    pub(super) fn check_ipc_start(&mut self, started: Result<u16, String>) {
+        let port = crate::ipc::ipc_port();
+        match started {
+            Ok(bound) if bound == port => self.clear_error(&RuntimeError::Ipc(String::new())),
+            Ok(bound) => {
+                let message = crate::i18n::tr()
+                    .errors
+                    .ipc_fallback
+                    .replace("{port}", &port.to_string())
+                    .replace("{fallback}", &bound.to_string());
+                self.report_error(RuntimeError::Ipc(message));
+            }
+            Err(e) => self.report_error(RuntimeError::Ipc(e)),
+        }
+    }
+
+    /// Try again whatever failed
+    fn retry(&mut self, error: RuntimeError) {
+        match error {
+            RuntimeError::Database(_) => match open_database(&self.config) {
+                Ok(db) => {
+                    self.statistics = Statistics::load_within(&db, &self.config.working_hours);
+                    self.database = Some(db);
+                    self.refresh_todo_data();
+                    self.reload_stats_tab();
+                }
+                Err(e) => self.report_error(RuntimeError::Database(e)),
+            },
+            RuntimeError::ConfigSave(_) => self.save_config(),
+            RuntimeError::Autostart(_) => {
+                self.apply_autostart(self.config.system.start_with_windows)
+            }
+            RuntimeError::Hotkeys(_) => self.restart_hotkeys(),
+            RuntimeError::Ipc(_) => self.restart_ipc(),
+            // Started again on the next frame
+            RuntimeError::Group(_) => self.group_config = None,
+            RuntimeError::RecordSession(_) | RuntimeError::Export(_) => {}
+        }
+    }
+
+    /// Draw the banner while there are problems to report
+    pub(super) fn show_error_banner(&mut self, ui: &mut egui::Ui) {
+        let Some(first) = self.runtime_errors.first() else {
+            return;
+        };
+        let t = crate::i18n::tr();
+        let theme = &self.theme;
+
+        let mut summary = format!("{}: {}", first.summary(), first.message());
+        if self.runtime_errors.len() > 1 {
+            summary.push_str(&format!(" (+{})", self.runtime_errors.len() - 1));
+        }
+        let details = self
+            .runtime_errors
+            .iter()
+            .map(|e| format!("{}: {}", e.summary(), e.message()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let can_retry = first.can_retry();
+
+        let mut retry = false;
+        let mut dismissed = false;
+        egui::Frame::none()
+            .fill(theme.error_muted)
+            .rounding(theme.rounding_sm)
+            .inner_margin(egui::Margin::symmetric(theme.spacing_sm, theme.spacing_xs))
+            .outer_margin(egui::Margin::symmetric(theme.spacing_md, 0.0))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let buttons_width = if can_retry { 80.0 } else { 24.0 };
+                    ui.scope(|ui| {
+                        ui.set_max_width(ui.available_width() - buttons_width);
+                        ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(summary).color(theme.error).small(),
+                            )
+                            .truncate(),
+                        )
+                        .on_hover_text(details);
+                    });
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        dismissed = ui
+                            .add(
+                                egui::Button::new(egui::RichText::new("×").color(theme.text_muted))
+                                    .frame(false),
+                            )
+                            .on_hover_text(t.common.dismiss)
+                            .clicked();
+                        if can_retry {
+                            retry = ui
+                                .add(
+                                    egui::Button::new(
+                                        egui::RichText::new(t.errors.retry)
+                                            .color(theme.text_primary)
+                                            .small(),
+                                    )
+                                    .frame(false),
+                                )
+                                .clicked();
+                        }
+                    });
+                });
+            });
+
+        if retry {
+            let error = self.runtime_errors.remove(0);
+            self.retry(error);
+        } else if dismissed {
+            self.runtime_errors.remove(0);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! One-off delayed commands (`start --at`, `pause --for`)
+
+use chrono::{DateTime, Utc};
+
+use crate::ipc::IpcCommand;
+
+/// Commands waiting to run at a given time
+#[derive(Default)]
+pub(super) struct Scheduler {
+    jobs: Vec<(DateTime<Utc>, IpcCommand)>,
+}
+
+impl Scheduler {
+    /// Queue a command to run at `at`
+    pub fn schedule(&mut self, at: DateTime<Utc>, command: IpcCommand) {
+        self.jobs.push((at, command));
+        self.jobs.sort_by_key(|(at, _)| *at);
+    }
+
+    /// Drop all pending commands, returning how many were cancelled
+    pub fn clear(&mut self) -> usize {
+        let count = self.jobs.len();
+        self.jobs.clear();
+        count
+    }
+
+    /// Remove and return every command that is due
+    pub fn take_due(&mut self, now: DateTime<Utc>) -> Vec<IpcCommand> {
+        let due = self.jobs.partition_point(|(at, _)| *at <= now);
+        self.jobs.drain(..due).map(|(_, cmd)| cmd).collect()
+    }
+
+    /// Time until the next command is due
+    pub fn next_due_in(&self, now: DateTime<Utc>) -> Option<std::time::Duration> {
+        self.jobs
+            .first()
+            .map(|(at, _)| (*at - now).to_std().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_due_in_order() {
+        let now = Utc::now();
+        let mut scheduler = Scheduler::default();
+        scheduler.schedule(now + chrono::Duration::minutes(10), IpcCommand::Resume);
+        scheduler.schedule(now - chrono::Duration::seconds(1), IpcCommand::Pause);
+
+        let due = scheduler.take_due(now);
+        assert_eq!(due.len(), 1);
+        assert!(matches!(due[0], IpcCommand::Pause));
+        assert!(scheduler.next_due_in(now).is_some());
+        assert_eq!(scheduler.clear(), 1);
+    }
+}
@@ -1,11 +1,25 @@
-use chrono::Utc;
+use chrono::{DateTime, Datelike, Local, NaiveDate, Utc};
 
-use crate::core::{SessionType, TimerEvent};
-use crate::ipc::{IpcCommand, IpcResponse, IpcStats, IpcStatus};
-use crate::platform::{HotkeyAction, TrayAction};
+use crate::core::{SessionType, TimerEvent, TimerState};
+use crate::data::TrayIconStyle;
+use crate::integrations::status_text;
+use crate::ipc::{
+    IpcCommand, IpcEvent, IpcEventKind, IpcRequest, IpcResponse, IpcStats, IpcStatus,
+};
+use crate::platform::{
+    DistractionBlocker, HotkeyAction, MediaController, NotificationAction, TaskbarProgress,
+    TrayAction, TrayIconFace,
+};
 use crate::ui::timer_view::TimerAction;
 
-use super::PomodoRustApp;
+use super::runtime_errors::RuntimeError;
+use super::{PomodoRustApp, View};
+
+/// Longest custom duration accepted from `start --duration`
+const MAX_CUSTOM_MINUTES: u32 = 240;
+
+/// Delay before the next session starts when a notification is snoozed
+const SNOOZE_MINUTES: i64 = 5;
 
 impl PomodoRustApp {
     /// Load system fallback fonts for Unicode symbols, emoji, and Phosphor icons
@@ -35,7 +49,9 @@ impl PomodoRustApp {
             vec!["Unbounded-Regular".to_string()],
         );
 
-        // Fallback fonts: symbols (box-drawing, math, etc.) + emoji
+        // Fallback fonts: symbols (box-drawing, math, etc.), Chinese, Japanese
+        // and Korean, emoji. Read from the system rather than bundled, the
+        // CJK fonts alone are tens of megabytes.
         let fallbacks: &[(&str, &[&str])] = &[
             #[cfg(windows)]
             (
@@ -46,6 +62,17 @@ impl PomodoRustApp {
                 ],
             ),
             #[cfg(windows)]
+            (
+                "cjk",
+                &[
+                    "C:\\Windows\\Fonts\\msyh.ttc",     // Microsoft YaHei (Chinese, kana)
+                    "C:\\Windows\\Fonts\\YuGothM.ttc",  // Yu Gothic (Japanese)
+                    "C:\\Windows\\Fonts\\msgothic.ttc", // MS Gothic (older Windows)
+                ],
+            ),
+            #[cfg(windows)]
+            ("korean", &["C:\\Windows\\Fonts\\malgun.ttf"]), // Malgun Gothic
+            #[cfg(windows)]
             (
                 "emoji",
                 &[
@@ -61,6 +88,18 @@ impl PomodoRustApp {
                 ],
             ),
             #[cfg(target_os = "linux")]
+            (
+                "cjk",
+                &[
+                    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+                    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+                    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+                    "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+                    "/usr/share/fonts/wenquanyi/wqy-microhei/wqy-microhei.ttc",
+                    "/usr/share/fonts/truetype/droid/DroidSansFallbackFull.ttf",
+                ],
+            ),
+            #[cfg(target_os = "linux")]
             (
                 "emoji",
                 &[
@@ -75,6 +114,16 @@ impl PomodoRustApp {
                 &["/System/Library/Fonts/Supplemental/Arial Unicode.ttf"],
             ),
             #[cfg(target_os = "macos")]
+            (
+                "cjk",
+                &[
+                    "/System/Library/Fonts/PingFang.ttc",
+                    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+                ],
+            ),
+            #[cfg(target_os = "macos")]
+            ("korean", &["/System/Library/Fonts/AppleSDGothicNeo.ttc"]),
+            #[cfg(target_os = "macos")]
             ("emoji", &["/System/Library/Fonts/Apple Color Emoji.ttc"]),
         ];
 
@@ -85,14 +134,16 @@ impl PomodoRustApp {
                     fonts
                         .font_data
                         .insert(name.to_string(), egui::FontData::from_owned(data));
-                    if let Some(family) = fonts.families.get_mut(&egui::FontFamily::Proportional) {
-                        if !family.contains(&name.to_string()) {
-                            family.push(name.to_string());
-                        }
-                    }
-                    if let Some(family) = fonts.families.get_mut(&egui::FontFamily::Monospace) {
-                        if !family.contains(&name.to_string()) {
-                            family.push(name.to_string());
+                    // Unbounded has no CJK, so the modern style falls back as well
+                    for family in [
+                        egui::FontFamily::Proportional,
+                        egui::FontFamily::Monospace,
+                        egui::FontFamily::Name("Modern".into()),
+                    ] {
+                        if let Some(family) = fonts.families.get_mut(&family) {
+                            if !family.contains(&name.to_string()) {
+                                family.push(name.to_string());
+                            }
                         }
                     }
                     tracing::info!("Loaded fallback font '{name}' from {path}");
@@ -105,33 +156,141 @@ impl PomodoRustApp {
     }
 
     /// Handle IPC commands from CLI
-    pub(super) fn handle_ipc_commands(&mut self) {
-        // Collect all pending commands first to avoid borrow issues
-        let commands: Vec<IpcCommand> = self
+    pub(super) fn handle_ipc_commands(&mut self, ctx: &egui::Context) {
+        // Collect all pending requests first to avoid borrow issues
+        let requests: Vec<IpcRequest> = self
             .ipc_receiver
             .as_ref()
             .map(|rx| rx.try_iter().collect())
             .unwrap_or_default();
 
-        // Process collected commands
-        for command in commands {
-            let response = self.process_ipc_command(command);
-            self.ipc_server.set_response(response);
+        // Answer each client with the response to its own command
+        for request in requests {
+            let response = self.process_ipc_command(request.command.clone(), ctx);
+            request.respond(response);
+        }
+    }
+
+    /// Run the buttons clicked on timer notifications
+    pub(super) fn handle_notification_actions(&mut self, ctx: &egui::Context) {
+        while let Ok(action) = self.notification_rx.try_recv() {
+            tracing::info!("Notification action: {:?}", action);
+            self.acknowledge_reminders();
+            let command = match action {
+                NotificationAction::StartNext => IpcCommand::Start {
+                    session_type: None,
+                    minutes: None,
+                    tag: None,
+                },
+                NotificationAction::Snooze => IpcCommand::Schedule {
+                    at: (Utc::now() + chrono::Duration::minutes(SNOOZE_MINUTES)).to_rfc3339(),
+                    action: Box::new(IpcCommand::Start {
+                        session_type: None,
+                        minutes: None,
+                        tag: None,
+                    }),
+                },
+                NotificationAction::Skip => IpcCommand::Skip,
+            };
+            if let IpcResponse::Error { message } = self.process_ipc_command(command, ctx) {
+                tracing::warn!("Notification action failed: {}", message);
+            }
+        }
+    }
+
+    /// Run the commands pressed on Home Assistant buttons
+    pub(super) fn handle_mqtt_commands(&mut self, ctx: &egui::Context) {
+        while let Some(command) = self.integrations.poll_command() {
+            if let IpcResponse::Error { message } = self.process_ipc_command(command, ctx) {
+                tracing::warn!("MQTT command failed: {}", message);
+            }
+        }
+    }
+
+    /// Run the commands sent from the Telegram chat, answering with the
+    /// resulting status
+    pub(super) fn handle_telegram_commands(&mut self, ctx: &egui::Context) {
+        while let Some(command) = self.integrations.poll_telegram() {
+            let reply = match self.process_ipc_command(command, ctx) {
+                IpcResponse::Error { message } => message,
+                _ => status_text(&self.ipc_status()),
+            };
+            self.integrations.telegram_send(reply);
+        }
+    }
+
+    /// Run scheduled commands that are due and wake up for the next one
+    pub(super) fn run_scheduled_commands(&mut self, ctx: &egui::Context) {
+        let now = Utc::now();
+        for command in self.scheduler.take_due(now) {
+            tracing::info!("Running scheduled command: {}", command.to_json());
+            if let IpcResponse::Error { message } = self.process_ipc_command(command, ctx) {
+                tracing::warn!("Scheduled command failed: {}", message);
+            }
+        }
+
+        if let Some(wait) = self.scheduler.next_due_in(now) {
+            ctx.request_repaint_after(wait);
         }
     }
 
     /// Process a single IPC command and return the response
-    fn process_ipc_command(&mut self, command: IpcCommand) -> IpcResponse {
+    fn process_ipc_command(&mut self, command: IpcCommand, ctx: &egui::Context) -> IpcResponse {
+        if self.strict_locked() && command.interrupts_session() {
+            return IpcResponse::error("Strict mode: the focus session can't be stopped");
+        }
+
         match command {
-            IpcCommand::Start { session_type } => {
+            IpcCommand::Start {
+                session_type,
+                minutes,
+                tag,
+            } => {
                 // Optionally switch session type
-                if let Some(st) = session_type {
-                    match st.to_lowercase().as_str() {
-                        "work" | "focus" => self.session.switch_to(SessionType::Work),
-                        "short" | "short_break" => self.session.switch_to(SessionType::ShortBreak),
-                        "long" | "long_break" => self.session.switch_to(SessionType::LongBreak),
+                let requested = match session_type.as_deref().map(str::to_lowercase) {
+                    None => None,
+                    Some(st) => match st.as_str() {
+                        "work" | "focus" => Some(SessionType::Work),
+                        "short" | "short_break" => Some(SessionType::ShortBreak),
+                        "long" | "long_break" => Some(SessionType::LongBreak),
                         _ => return IpcResponse::error(format!("Unknown session type: {}", st)),
-                    }
+                    },
+                };
+
+                // Check everything before changing anything
+                if minutes.is_some_and(|m| !(1..=MAX_CUSTOM_MINUTES).contains(&m)) {
+                    return IpcResponse::error(format!(
+                        "Duration must be between 1 and {} minutes",
+                        MAX_CUSTOM_MINUTES
+                    ));
+                }
+
+                // The session being replaced is recorded under its own tag
+                if minutes.is_some() || requested.is_some() {
+                    self.record_unfinished_session();
+                }
+                if let Some(tag) = tag {
+                    let tag = tag.trim();
+                    self.session_tag = (!tag.is_empty()).then(|| tag.to_string());
+                }
+
+                if let Some(minutes) = minutes {
+                    // Same as Quick Start: restart with a custom duration
+                    self.session
+                        .switch_to(requested.unwrap_or(self.session.session_type()));
+                    self.session
+                        .timer_mut()
+                        .reset_with_duration(minutes as u64 * 60);
+                    self.session.start();
+                    self.session_start_time = Some(Utc::now());
+                    return IpcResponse::ok_with_message(format!(
+                        "Timer started ({} min)",
+                        minutes
+                    ));
+                }
+
+                if let Some(st) = requested {
+                    self.session.switch_to(st);
                 }
 
                 if !self.session.timer().is_running() {
@@ -177,12 +336,14 @@ impl PomodoRustApp {
             }
 
             IpcCommand::Stop => {
+                self.record_unfinished_session();
                 self.session.reset();
                 self.session_start_time = None;
                 IpcResponse::ok_with_message("Timer stopped and reset")
             }
 
             IpcCommand::Skip => {
+                self.record_unfinished_session();
                 self.session.skip();
                 self.session_start_time = None;
                 IpcResponse::ok_with_message(format!(
@@ -191,72 +352,363 @@ impl PomodoRustApp {
                 ))
             }
 
-            IpcCommand::Status => {
-                let timer = self.session.timer();
-                let state = if timer.is_running() {
-                    "running"
-                } else if timer.is_paused() {
-                    "paused"
-                } else if timer.is_completed() {
-                    "completed"
-                } else {
-                    "idle"
-                };
+            IpcCommand::Status => IpcResponse::Status(self.ipc_status()),
 
-                let session_type = match self.session.session_type() {
-                    SessionType::Work => "work",
-                    SessionType::ShortBreak => "short_break",
-                    SessionType::LongBreak => "long_break",
+            IpcCommand::Stats { period, from, to } => {
+                match self.ipc_stats(&period, from.as_deref(), to.as_deref()) {
+                    Ok(stats) => IpcResponse::Stats(stats),
+                    Err(e) => IpcResponse::error(e),
+                }
+            }
+
+            IpcCommand::SetOption { option, value } => self.set_quick_option(&option, value, ctx),
+
+            IpcCommand::Kiosk { enabled, message } => self.ipc_kiosk(enabled, message, ctx),
+
+            IpcCommand::Schedule { at, action } => {
+                let at = match DateTime::parse_from_rfc3339(&at) {
+                    Ok(at) => at.with_timezone(&Utc),
+                    Err(_) => return IpcResponse::error(format!("Invalid time: {}", at)),
                 };
+                if matches!(
+                    *action,
+                    IpcCommand::Schedule { .. }
+                        | IpcCommand::CancelScheduled
+                        | IpcCommand::Subscribe
+                        | IpcCommand::Ping
+                ) {
+                    return IpcResponse::error("This command cannot be scheduled");
+                }
+                self.scheduler.schedule(at, *action);
+                IpcResponse::ok_with_message(format!(
+                    "Scheduled for {}",
+                    at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
+                ))
+            }
 
-                IpcResponse::Status(IpcStatus {
-                    state: state.to_string(),
-                    session_type: session_type.to_string(),
-                    remaining_secs: timer.remaining().as_secs(),
-                    remaining_formatted: timer.remaining_formatted(),
-                    progress: timer.progress(),
-                    current_session: self.session.current_session_in_cycle(),
-                    total_sessions: self.session.total_sessions_in_cycle(),
-                    total_duration_secs: timer.total_duration().as_secs(),
-                })
+            IpcCommand::CancelScheduled => {
+                let count = self.scheduler.clear();
+                IpcResponse::ok_with_message(format!("Cancelled {} scheduled command(s)", count))
             }
 
-            IpcCommand::Stats { period } => {
-                let period = if period.is_empty() { "today" } else { &period };
-
-                let (hours, pomodoros) = match period {
-                    "today" => (
-                        self.statistics.today_hours(),
-                        self.statistics.today_pomodoros,
-                    ),
-                    "week" => (
-                        self.statistics.week_hours(),
-                        self.statistics.today_pomodoros,
-                    ), // week doesn't have pomodoro count
-                    "all" => (
-                        self.statistics.total_hours() as f32,
-                        self.statistics.total_pomodoros,
-                    ),
-                    _ => return IpcResponse::error(format!("Unknown period: {}", period)),
+            IpcCommand::TaskAdd { title, pomodoros } => self.ipc_add_task(&title, pomodoros),
+
+            IpcCommand::TaskList { all } => self.ipc_list_tasks(all),
+
+            IpcCommand::TaskStart { id } => match self.ipc_focus_task(id) {
+                Ok(title) => {
+                    // A running focus session carries on with the task
+                    if self.session.session_type() != SessionType::Work {
+                        self.session.switch_to(SessionType::Work);
+                    }
+                    if !self.session.timer().is_running() {
+                        self.session.start();
+                        self.session_start_time = Some(Utc::now());
+                    }
+                    IpcResponse::ok_with_message(format!("Focusing on: {}", title))
+                }
+                Err(e) => IpcResponse::error(e),
+            },
+
+            IpcCommand::TaskDone { id } => self.ipc_complete_task(id),
+
+            IpcCommand::ProfileUse { name } => self.ipc_use_profile(&name, ctx),
+
+            IpcCommand::ProfileList => self.ipc_list_profiles(),
+
+            IpcCommand::Show { view } => {
+                let view = match view.as_deref() {
+                    None => None,
+                    Some("timer") => Some(View::Timer),
+                    Some("queue") => Some(View::Queue),
+                    Some("tasks") => Some(View::Tasks),
+                    Some("plan") => Some(View::Plan),
+                    Some("stats") => Some(View::Stats),
+                    Some("settings") => Some(View::Settings),
+                    Some(other) => return IpcResponse::error(format!("Unknown view: {}", other)),
                 };
+                self.raise_window(ctx);
+                match view {
+                    Some(View::Plan) => self.open_plan(),
+                    Some(view) => self.current_view = view,
+                    None => {}
+                }
+                IpcResponse::ok()
+            }
+
+            IpcCommand::Quit => {
+                self.force_quit = true;
+                IpcResponse::ok_with_message("Quitting")
+            }
+
+            IpcCommand::Ping => IpcResponse::pong(),
+
+            // Subscriptions are handled by the IPC server itself
+            IpcCommand::Subscribe => IpcResponse::error("Subscribe is not a one-shot command"),
+        }
+    }
+
+    /// Flip one of the quick-toggle settings and apply it like the settings view does
+    fn set_quick_option(
+        &mut self,
+        option: &str,
+        value: Option<bool>,
+        ctx: &egui::Context,
+    ) -> IpcResponse {
+        let option = option.replace('-', "_");
+        // Mute lasts until the app is closed, it isn't a setting
+        if option == "mute" {
+            let muted = value.unwrap_or(!self.muted);
+            self.set_muted(muted);
+            return IpcResponse::ok_with_message(format!(
+                "Mute {}",
+                if muted { "on" } else { "off" }
+            ));
+        }
+
+        let mut config = self.config.clone();
+        // DND is stored inverted (notifications enabled)
+        let (flag, label, inverted) = match option.as_str() {
+            "dnd" | "do_not_disturb" => (
+                &mut config.system.notifications_enabled,
+                "Do not disturb",
+                true,
+            ),
+            "always_on_top" => (&mut config.window.always_on_top, "Always on top", false),
+            _ => return IpcResponse::error(format!("Unknown option: {}", option)),
+        };
+
+        let enabled = value.unwrap_or(*flag == inverted);
+        *flag = enabled != inverted;
+
+        if config != self.config {
+            self.apply_config(config, ctx);
+        }
+        IpcResponse::ok_with_message(format!("{} {}", label, if enabled { "on" } else { "off" }))
+    }
+
+    /// Build statistics for a named period or a custom date range
+    fn ipc_stats(
+        &self,
+        period: &str,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<IpcStats, String> {
+        let today = Local::now().date_naive();
+        let parse_date = |s: &str| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .map_err(|_| format!("Invalid date: {} (expected YYYY-MM-DD)", s))
+        };
+
+        let (period, range) = if from.is_some() || to.is_some() {
+            let from = match from {
+                Some(s) => parse_date(s)?,
+                None => self
+                    .database
+                    .as_ref()
+                    .and_then(|db| db.get_earliest_stats_date().ok().flatten())
+                    .unwrap_or(today),
+            };
+            let to = to.map(parse_date).transpose()?.unwrap_or(today);
+            if from > to {
+                return Err(format!("Invalid range: {} is after {}", from, to));
+            }
+            ("range", Some((from, to)))
+        } else {
+            period_range(period, today)?
+        };
+
+        let (hours, pomodoros) = match range {
+            Some((from, to)) => {
+                let db = self
+                    .database
+                    .as_ref()
+                    .ok_or("Statistics database is not available")?;
+                let (seconds, pomodoros) = db
+                    .get_range_stats(from, to)
+                    .map_err(|e| format!("Failed to query statistics: {}", e))?;
+                (seconds as f32 / 3600.0, pomodoros)
+            }
+            None if period == "all" => (
+                self.statistics.total_hours() as f32,
+                self.statistics.total_pomodoros,
+            ),
+            None => (
+                self.statistics.today_hours(),
+                self.statistics.today_pomodoros,
+            ),
+        };
+
+        Ok(IpcStats {
+            period: period.to_string(),
+            from: range.map(|(from, _)| from.to_string()),
+            to: range.map(|(_, to)| to.to_string()),
+            hours,
+            pomodoros,
+            current_streak: self.statistics.current_streak,
+            longest_streak: self.statistics.longest_streak,
+            daily_goal: self.daily_target(),
+            today_pomodoros: self.statistics.today_pomodoros,
+            daily_hours: if period == "week" {
+                self.statistics.week_daily_hours.clone()
+            } else {
+                Vec::new()
+            },
+        })
+    }
+
+    /// Build a status snapshot of the current timer
+    pub(super) fn ipc_status(&self) -> IpcStatus {
+        let timer = self.session.timer();
+        let state = if timer.is_running() {
+            "running"
+        } else if timer.is_paused() {
+            "paused"
+        } else if timer.is_completed() {
+            "completed"
+        } else {
+            "idle"
+        };
+
+        IpcStatus {
+            state: state.to_string(),
+            session_type: self.session.session_type().as_str().to_string(),
+            remaining_secs: timer.remaining().as_secs(),
+            remaining_formatted: timer.remaining_formatted(),
+            progress: timer.progress(),
+            current_session: self.session.current_session_in_cycle(),
+            total_sessions: self.session.total_sessions_in_cycle(),
+            total_duration_secs: timer.total_duration().as_secs(),
+            tag: self.session_tag.clone(),
+        }
+    }
 
-                IpcResponse::Stats(IpcStats {
-                    period: period.to_string(),
-                    hours,
-                    pomodoros,
-                    current_streak: self.statistics.current_streak,
-                    longest_streak: self.statistics.longest_streak,
-                    daily_goal: self.config.goals.daily_target,
-                    today_pomodoros: self.statistics.today_pomodoros,
-                })
+    /// Publish a timer lifecycle event to all integrations
+    pub(super) fn publish_event(
+        &mut self,
+        kind: IpcEventKind,
+        session_type: SessionType,
+        duration_secs: Option<u64>,
+    ) {
+        let event = IpcEvent {
+            event: kind,
+            session_type: session_type.as_str().to_string(),
+            status: self.ipc_status(),
+            duration_secs,
+            timestamp: Utc::now().to_rfc3339(),
+        };
+        tracing::debug!("Timer event: {:?} ({})", kind, event.session_type);
+        self.integrations.dispatch(&event);
+        self.taskwarrior_event(kind, session_type);
+        self.ipc_server.broadcast(&event);
+        self.last_timer_snapshot =
+            Some((self.session.timer().state(), self.session.session_type()));
+    }
+
+    /// Detect timer state transitions since the last frame and publish them.
+    ///
+    /// Timer actions come from many places (UI, CLI, hotkeys, tray), so state
+    /// changes are derived here in one spot instead of at every call site.
+    /// Completions are published by `on_timer_completed` directly.
+    pub(super) fn track_state_changes(&mut self) {
+        let current = (self.session.timer().state(), self.session.session_type());
+        let Some((prev_state, prev_type)) = self.last_timer_snapshot else {
+            self.last_timer_snapshot = Some(current);
+            return;
+        };
+        if (prev_state, prev_type) == current {
+            return;
+        }
+
+        let (state, session_type) = current;
+        let kind = if state == TimerState::Running && prev_state != TimerState::Running {
+            if prev_state == TimerState::Paused && prev_type == session_type {
+                Some(IpcEventKind::Resumed)
+            } else {
+                Some(IpcEventKind::Started)
             }
+        } else if prev_state == TimerState::Running && state == TimerState::Paused {
+            Some(IpcEventKind::Paused)
+        } else if prev_type != session_type {
+            Some(IpcEventKind::Skipped)
+        } else if state == TimerState::Idle
+            && matches!(prev_state, TimerState::Running | TimerState::Paused)
+        {
+            Some(IpcEventKind::Reset)
+        } else {
+            None
+        };
 
-            IpcCommand::Ping => IpcResponse::Pong,
+        if kind == Some(IpcEventKind::Started) && session_type == SessionType::Work {
+            self.warn_if_late();
+            self.check_meeting_overlap();
+        }
+
+        match kind {
+            Some(IpcEventKind::Skipped) => {
+                self.publish_event(IpcEventKind::Skipped, prev_type, None)
+            }
+            Some(kind) => self.publish_event(kind, session_type, None),
+            None => self.last_timer_snapshot = Some(current),
+        }
+
+        // A tag or intent only applies to the session it was set for
+        if matches!(kind, Some(IpcEventKind::Reset | IpcEventKind::Skipped)) {
+            self.session_tag = None;
+            self.session_intent = None;
         }
     }
 
+    /// Capture the media keys unless they are disabled or, optionally,
+    /// media is playing
+    pub(super) fn update_media_keys(&mut self, ctx: &egui::Context) {
+        let hotkeys = &self.config.hotkeys;
+        let watch = hotkeys.enabled && hotkeys.media_keys && hotkeys.media_keys_unless_playing;
+        if watch != self.watching_media {
+            self.watching_media = watch;
+            self.media
+                .get_or_insert_with(MediaController::start)
+                .watch(watch);
+        }
+
+        if watch {
+            // Playback is re-checked in the background every couple of seconds
+            ctx.request_repaint_after(std::time::Duration::from_secs(2));
+        }
+        let playing = watch && self.media.as_ref().is_some_and(|m| m.is_playing());
+        let capture = hotkeys.enabled && hotkeys.media_keys && !playing;
+        if capture != self.media_keys_captured {
+            self.media_keys_captured = capture;
+            self.hotkey_manager.set_media_keys(capture);
+        }
+    }
+
+    /// Register the configured hotkeys again, dropping the old bindings
+    pub(super) fn restart_hotkeys(&mut self) {
+        self.clear_error(&RuntimeError::Hotkeys(String::new()));
+        self.hotkey_manager.stop();
+        let hotkeys = &self.config.hotkeys;
+        if hotkeys.enabled {
+            self.hotkey_manager.start(
+                &hotkeys.toggle,
+                &hotkeys.skip,
+                &hotkeys.reset,
+                &hotkeys.mute,
+            );
+        }
+    }
+
+    /// Bind the command line socket and the HTTP API again
+    pub(super) fn restart_ipc(&mut self) {
+        let http = self.config.http.enabled.then_some(&self.config.http);
+        let started = self.ipc_server.restart(http);
+        self.check_ipc_start(started);
+    }
+
     /// Handle global hotkey events
     pub(super) fn handle_hotkey_events(&mut self) {
+        self.check_hotkey_failures();
+
         // Collect all pending hotkey events
         let events: Vec<HotkeyAction> = self
             .hotkey_receiver
@@ -266,6 +718,13 @@ impl PomodoRustApp {
 
         // Process collected events
         for action in events {
+            let stops = matches!(
+                action,
+                HotkeyAction::Toggle | HotkeyAction::Skip | HotkeyAction::Reset
+            );
+            if stops && self.strict_blocks() {
+                continue;
+            }
             match action {
                 HotkeyAction::Toggle => {
                     let event = self.session.toggle();
@@ -275,15 +734,21 @@ impl PomodoRustApp {
                     tracing::info!("Hotkey: Toggle timer");
                 }
                 HotkeyAction::Skip => {
+                    self.record_unfinished_session();
                     self.session.skip();
                     self.session_start_time = None;
                     tracing::info!("Hotkey: Skip session");
                 }
                 HotkeyAction::Reset => {
+                    self.record_unfinished_session();
                     self.session.reset();
                     self.session_start_time = None;
                     tracing::info!("Hotkey: Reset timer");
                 }
+                HotkeyAction::Mute => {
+                    self.set_muted(!self.muted);
+                    tracing::info!("Hotkey: Toggle mute");
+                }
             }
         }
     }
@@ -359,6 +824,38 @@ impl PomodoRustApp {
 
         tray.update_tooltip(&tooltip);
 
+        let active = timer.is_running() || timer.is_paused();
+        let color = if timer.is_paused() {
+            [136, 136, 136]
+        } else {
+            match self.session.session_type() {
+                SessionType::Work => [59, 130, 246],
+                SessionType::ShortBreak => [34, 197, 94],
+                SessionType::LongBreak => [168, 85, 247],
+            }
+        };
+        let face = match self.config.system.tray_icon_style {
+            TrayIconStyle::Countdown if active => {
+                // Whole minutes left, rounded up; seconds in the last minute
+                let secs = timer.remaining_millis().div_ceil(1000);
+                let label = if secs > 60 {
+                    secs.div_ceil(60).min(99)
+                } else {
+                    secs
+                };
+                TrayIconFace::Countdown {
+                    label: label.to_string(),
+                    color,
+                }
+            }
+            TrayIconStyle::Progress if active => TrayIconFace::Progress {
+                remaining: ((1.0 - timer.progress()) * 60.0).round() as u8,
+                color,
+            },
+            _ => TrayIconFace::Logo,
+        };
+        tray.update_icon(face);
+
         let toggle_label = if timer.is_running() {
             t.tray.pause
         } else if timer.is_paused() {
@@ -369,6 +866,99 @@ impl PomodoRustApp {
         tray.update_toggle_label(toggle_label);
     }
 
+    /// Mirror the timer on the taskbar button: progress while running or
+    /// paused, red once a session finished until the next one starts.
+    pub(super) fn update_taskbar_progress(&mut self) {
+        let timer = self.session.timer();
+        if timer.is_running() || timer.is_paused() {
+            self.completion_pending = false;
+        }
+
+        let permille = (timer.progress() * 1000.0).round() as u16;
+        let progress = if timer.is_running() {
+            TaskbarProgress::Running(permille)
+        } else if timer.is_paused() {
+            TaskbarProgress::Paused(permille)
+        } else if self.completion_pending {
+            TaskbarProgress::Completed
+        } else {
+            TaskbarProgress::None
+        };
+
+        if progress != self.taskbar_progress {
+            self.taskbar_progress = progress;
+            crate::platform::set_taskbar_progress(progress);
+        }
+    }
+
+    /// Show the remaining time in the native window title, e.g.
+    /// "12:34 — Focus — PomodoRust", so it is readable from the taskbar
+    pub(super) fn update_window_title(&mut self, ctx: &egui::Context) {
+        let timer = self.session.timer();
+        let title =
+            if self.config.window.title_countdown && (timer.is_running() || timer.is_paused()) {
+                format!(
+                    "{} \u{2014} {} \u{2014} PomodoRust",
+                    timer.remaining_formatted(),
+                    crate::i18n::tr().session_label(self.session.session_type())
+                )
+            } else {
+                "PomodoRust".to_string()
+            };
+
+        if title != self.window_title {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title.clone()));
+            self.window_title = title;
+        }
+    }
+
+    /// Apply the focus-time integrations (Do Not Disturb, media pausing,
+    /// distraction blocking)
+    /// while a focus session is running or paused
+    pub(super) fn update_focus_mode(&mut self) {
+        let timer = self.session.timer();
+        let in_focus = self.session.session_type() == SessionType::Work
+            && (timer.is_running() || timer.is_paused());
+
+        let dnd = in_focus && self.config.system.dnd_during_focus;
+        if dnd != self.dnd_active {
+            self.dnd_active = dnd;
+            crate::platform::set_do_not_disturb(dnd);
+        }
+
+        let block = (in_focus && self.config.blocker.is_active()).then_some(&self.config.blocker);
+        if block != self.blocking.as_ref() {
+            self.blocking = block.cloned();
+            let blocker = self.blocker.get_or_insert_with(DistractionBlocker::start);
+            match block {
+                Some(config) => blocker.block(config),
+                None => blocker.unblock(),
+            }
+        }
+
+        let pause_media = in_focus && self.config.system.pause_media_during_focus;
+        if pause_media != self.media_paused {
+            self.media_paused = pause_media;
+            let media = self.media.get_or_insert_with(MediaController::start);
+            if pause_media {
+                media.pause();
+            } else {
+                media.resume();
+            }
+        }
+    }
+
+    /// Add Start Focus / Start Break / Show Stats to the taskbar jump list
+    #[cfg(windows)]
+    pub(super) fn register_jump_list() {
+        let t = crate::i18n::tr();
+        crate::platform::register_jump_list(&[
+            (t.tray.start_focus, "start -s work"),
+            (t.tray.start_break, "start -s short"),
+            (t.tray.show_stats, "show stats"),
+        ]);
+    }
+
     /// Hide the main window to the system tray.
     /// Uses native Win32 API on Windows to avoid corrupting eframe's internal
     /// viewport state (ViewportCommand::Visible(false) blocks all subsequent
@@ -401,6 +991,17 @@ impl PomodoRustApp {
         }
     }
 
+    /// Un-minimize, raise and focus the main window, also when hidden to tray
+    pub(super) fn raise_window(&mut self, ctx: &egui::Context) {
+        #[cfg(windows)]
+        crate::platform::show_pomodorust_window();
+        if self.hidden_to_tray {
+            self.show_from_tray(ctx);
+        }
+        ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+        ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+    }
+
     /// Render close confirmation dialog (minimize to tray or quit)
     pub(super) fn render_close_dialog(&mut self, ctx: &egui::Context) {
         let mut open = true;
@@ -505,3 +1106,67 @@ impl PomodoRustApp {
         }
     }
 }
+
+/// First and last day of a statistics period, both included
+type DateRange = (NaiveDate, NaiveDate);
+
+/// Name and dates of a named statistics period ending `today`;
+/// `today` and `all` have no dates, they come from the loaded statistics
+fn period_range(
+    period: &str,
+    today: NaiveDate,
+) -> Result<(&'static str, Option<DateRange>), String> {
+    Ok(match if period.is_empty() { "today" } else { period } {
+        "today" => ("today", None),
+        "all" => ("all", None),
+        "week" => {
+            let monday =
+                today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
+            ("week", Some((monday, today)))
+        }
+        "month" => ("month", Some((today.with_day(1).unwrap_or(today), today))),
+        "year" => (
+            "year",
+            Some((today.with_ordinal(1).unwrap_or(today), today)),
+        ),
+        other => return Err(format!("Unknown period: {}", other)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_period_ranges() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let range = |period, today| period_range(period, today).unwrap();
+
+        // Periods end today and start on their first day, today included
+        assert_eq!(
+            range("month", date(2024, 2, 29)),
+            ("month", Some((date(2024, 2, 1), date(2024, 2, 29))))
+        );
+        assert_eq!(
+            range("month", date(2024, 3, 1)),
+            ("month", Some((date(2024, 3, 1), date(2024, 3, 1))))
+        );
+        assert_eq!(
+            range("year", date(2024, 12, 31)),
+            ("year", Some((date(2024, 1, 1), date(2024, 12, 31))))
+        );
+        assert_eq!(
+            range("year", date(2025, 1, 1)),
+            ("year", Some((date(2025, 1, 1), date(2025, 1, 1))))
+        );
+        // 2025-01-01 is a Wednesday, its week started the year before
+        assert_eq!(
+            range("week", date(2025, 1, 1)),
+            ("week", Some((date(2024, 12, 30), date(2025, 1, 1))))
+        );
+
+        assert_eq!(range("", date(2024, 1, 1)), ("today", None));
+        assert_eq!(range("all", date(2024, 1, 1)), ("all", None));
+        assert!(period_range("decade", date(2024, 1, 1)).is_err());
+    }
+}
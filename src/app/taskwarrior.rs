@@ -0,0 +1,128 @@
+//! Taskwarrior bridge: imports pending tasks into the current workspace,
+//! starts and stops the task being focused on and annotates it with the
+//! pomodoros done
+
+use super::PomodoRustApp;
+use crate::core::SessionType;
+use crate::integrations::{Taskwarrior, TaskwarriorReply, TaskwarriorTask};
+use crate::ipc::IpcEventKind;
+
+impl PomodoRustApp {
+    /// Start or stop the bridge with the config, sync when it's time and
+    /// take in the replies
+    pub(super) fn update_taskwarrior(&mut self, ctx: &egui::Context) {
+        if !self.config.taskwarrior.is_active() {
+            self.taskwarrior = None;
+            self.taskwarrior_active = None;
+            return;
+        }
+        let bridge = self.taskwarrior.get_or_insert_with(|| {
+            Taskwarrior::start(self.config.taskwarrior.clone(), ctx.clone())
+        });
+        bridge.sync_if_due();
+
+        let mut imported = false;
+        while let Some(reply) = self.taskwarrior.as_ref().and_then(Taskwarrior::poll) {
+            match reply {
+                TaskwarriorReply::Tasks(tasks) => imported |= self.import_taskwarrior_tasks(&tasks),
+                TaskwarriorReply::Failed(e) => {
+                    self.show_error(format!(
+                        "{}: {}",
+                        crate::i18n::tr().notif.taskwarrior_failed,
+                        e
+                    ));
+                }
+            }
+        }
+        if imported {
+            self.refresh_todo_data();
+        }
+    }
+
+    /// Add the tasks not seen before, returns whether there were any
+    fn import_taskwarrior_tasks(&mut self, tasks: &[TaskwarriorTask]) -> bool {
+        let Some(db) = &self.database else {
+            return false;
+        };
+        let workspace_id = self
+            .shared_todo
+            .data
+            .read()
+            .map(|s| s.current_workspace_id)
+            .unwrap_or(0);
+        if workspace_id == 0 {
+            return false;
+        }
+
+        let mut imported = 0;
+        for task in tasks {
+            match db.import_taskwarrior_task(
+                workspace_id,
+                &task.uuid,
+                &task.description,
+                task.local_priority(),
+            ) {
+                Ok(Some(_)) => imported += 1,
+                Ok(None) => {}
+                Err(e) => tracing::warn!("DB import_taskwarrior_task: {e}"),
+            }
+        }
+        if imported > 0 {
+            tracing::info!("Imported {} tasks from Taskwarrior", imported);
+        }
+        imported > 0
+    }
+
+    /// Start the task at the head of the queue with a focus session, and
+    /// stop it when the session pauses or ends
+    pub(super) fn taskwarrior_event(&mut self, kind: IpcEventKind, session_type: SessionType) {
+        if !self.config.taskwarrior.track_time {
+            return;
+        }
+        let Some(bridge) = &self.taskwarrior else {
+            return;
+        };
+
+        let focusing = session_type == SessionType::Work
+            && matches!(kind, IpcEventKind::Started | IpcEventKind::Resumed);
+        let uuid = match &self.database {
+            Some(db) if focusing => db
+                .get_current_queue_task()
+                .ok()
+                .flatten()
+                .and_then(|current| db.taskwarrior_uuid(current.todo_id).ok().flatten()),
+            _ => None,
+        };
+        if uuid == self.taskwarrior_active {
+            return;
+        }
+
+        if let Some(previous) = &self.taskwarrior_active {
+            bridge.stop_task(previous);
+        }
+        if let Some(uuid) = &uuid {
+            bridge.start_task(uuid);
+        }
+        self.taskwarrior_active = uuid;
+    }
+
+    /// Annotate the Taskwarrior task of a todo after a focus session on it
+    pub(super) fn taskwarrior_session_done(&self, todo_id: i64, count: u32, minutes: u32) {
+        if !self.config.taskwarrior.annotate {
+            return;
+        }
+        let (Some(bridge), Some(db)) = (&self.taskwarrior, &self.database) else {
+            return;
+        };
+        if let Ok(Some(uuid)) = db.taskwarrior_uuid(todo_id) {
+            let t = crate::i18n::tr();
+            bridge.annotate(
+                &uuid,
+                &format!(
+                    "🍅 {} {} ({} {})",
+                    t.notif.taskwarrior_annotation, count, minutes, t.common.min
+                ),
+            );
+        }
+    }
+}
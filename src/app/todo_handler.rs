@@ -2,10 +2,12 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use crate::data::todo::{Priority, Project, QueuedTask, TodoItem};
+use crate::ipc::{IpcResponse, IpcTask};
+use crate::ui::tasks::TasksAction;
 use crate::ui::todo_view::TodoAction;
 use crate::ui::todo_window::render_todo_viewport;
 
-use super::PomodoRustApp;
+use super::{PomodoRustApp, View};
 
 impl PomodoRustApp {
     /// Refresh todo data from database into shared state
@@ -40,6 +42,7 @@ impl PomodoRustApp {
             state.needs_refresh = false;
             state.bump_generation();
         }
+        self.close_done_todoist_tasks();
     }
 
     /// Handle todo action from the todo viewport.
@@ -410,6 +413,176 @@ impl PomodoRustApp {
         result
     }
 
+    /// Create a task from the CLI, optionally queueing it
+    pub(super) fn ipc_add_task(&mut self, title: &str, pomodoros: Option<u32>) -> IpcResponse {
+        let Some(db) = &self.database else {
+            return IpcResponse::error("Database not available");
+        };
+        let workspace_id = self
+            .shared_todo
+            .data
+            .read()
+            .map(|s| s.current_workspace_id)
+            .unwrap_or(0);
+        if workspace_id == 0 {
+            return IpcResponse::error("No workspace to add the task to");
+        }
+
+        let id = match db.create_todo(workspace_id, None, title) {
+            Ok(id) => id,
+            Err(e) => return IpcResponse::error(format!("Failed to create task: {}", e)),
+        };
+        if let Some(planned) = pomodoros {
+            if let Err(e) = db.add_to_queue(id, planned.max(1)) {
+                tracing::warn!("DB add_to_queue: {e}");
+            }
+        }
+
+        self.refresh_todo_data();
+        IpcResponse::ok_with_message(format!("Added task #{}", id))
+    }
+
+    /// List the tasks of the current workspace
+    pub(super) fn ipc_list_tasks(&self, include_completed: bool) -> IpcResponse {
+        let Ok(state) = self.shared_todo.data.read() else {
+            return IpcResponse::error("Task list unavailable");
+        };
+
+        let tasks = state
+            .todos
+            .iter()
+            .filter(|t| include_completed || !t.completed)
+            .map(|todo| {
+                let queued = state
+                    .queue
+                    .iter()
+                    .enumerate()
+                    .find(|(_, q)| q.todo_id == todo.id);
+                IpcTask {
+                    id: todo.id,
+                    title: todo.title.clone(),
+                    completed: todo.completed,
+                    priority: match todo.priority {
+                        Priority::None => "none",
+                        Priority::Low => "low",
+                        Priority::Medium => "medium",
+                        Priority::High => "high",
+                        Priority::Urgent => "urgent",
+                    }
+                    .to_string(),
+                    queue_position: queued.map(|(i, _)| i),
+                    completed_pomodoros: queued.map_or(0, |(_, q)| q.completed_pomodoros),
+                    planned_pomodoros: queued.map_or(0, |(_, q)| q.planned_pomodoros),
+                }
+            })
+            .collect();
+
+        IpcResponse::Tasks { tasks }
+    }
+
+    /// Move a task to the front of the queue so the next focus session counts
+    /// towards it. Returns the task title.
+    pub(super) fn ipc_focus_task(&mut self, id: i64) -> Result<String, String> {
+        let Some(db) = &self.database else {
+            return Err("Database not available".to_string());
+        };
+        let todo = match db.get_todo(id) {
+            Ok(Some(todo)) => todo,
+            Ok(None) => return Err(format!("No task #{}", id)),
+            Err(e) => return Err(format!("Failed to load task: {}", e)),
+        };
+        if todo.completed {
+            return Err(format!("Task #{} is already done", id));
+        }
+
+        let result = db.add_to_queue(id, 1).and_then(|_| {
+            let queue = db.get_queue()?;
+            let ids: Vec<i64> = queue
+                .iter()
+                .filter(|q| q.todo_id == id)
+                .chain(queue.iter().filter(|q| q.todo_id != id))
+                .map(|q| q.id)
+                .collect();
+            db.reorder_queue(&ids)
+        });
+        if let Err(e) = result {
+            return Err(format!("Failed to queue task: {}", e));
+        }
+
+        self.refresh_todo_data();
+        Ok(todo.title)
+    }
+
+    /// Handle an action from the tasks view
+    pub(super) fn handle_tasks_action(&mut self, action: TasksAction) {
+        let Some(db) = &self.database else { return };
+        let result = match action {
+            TasksAction::GoBack => {
+                self.current_view = View::Timer;
+                return;
+            }
+            TasksAction::Add { title, estimate } => {
+                let workspace_id = self
+                    .shared_todo
+                    .data
+                    .read()
+                    .map(|s| s.current_workspace_id)
+                    .unwrap_or(0);
+                if workspace_id == 0 {
+                    tracing::warn!("No workspace to add the task to");
+                    return;
+                }
+                db.create_todo(workspace_id, None, &title)
+                    .and_then(|id| db.add_to_queue(id, estimate.max(1)))
+                    .map(|_| ())
+            }
+            TasksAction::Complete { id, todo_id } => db.complete_queue_task(id, todo_id),
+            TasksAction::Select(id) => db.get_queue().and_then(|queue| {
+                let ids: Vec<i64> = std::iter::once(id)
+                    .chain(queue.iter().map(|q| q.id).filter(|&q| q != id))
+                    .collect();
+                db.reorder_queue(&ids)
+            }),
+            TasksAction::SetEstimate { id, estimate } => db.update_queue_planned(id, estimate),
+            TasksAction::Reorder(ids) => db.reorder_queue(&ids),
+        };
+        if let Err(e) = result {
+            tracing::warn!("Task update failed: {e}");
+        }
+        self.refresh_todo_data();
+    }
+
+    /// Mark a task as done from the CLI
+    pub(super) fn ipc_complete_task(&mut self, id: i64) -> IpcResponse {
+        let Some(db) = &self.database else {
+            return IpcResponse::error("Database not available");
+        };
+        match db.get_todo(id) {
+            Ok(Some(todo)) if todo.completed => {
+                return IpcResponse::ok_with_message(format!("Task #{} is already done", id));
+            }
+            Ok(Some(_)) => {}
+            Ok(None) => return IpcResponse::error(format!("No task #{}", id)),
+            Err(e) => return IpcResponse::error(format!("Failed to load task: {}", e)),
+        }
+
+        let queued = db
+            .get_queue()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|q| q.todo_id == id);
+        let result = match queued {
+            Some(q) => db.complete_queue_task(q.id, id),
+            None => db.toggle_todo(id).map(|_| ()),
+        };
+        if let Err(e) = result {
+            return IpcResponse::error(format!("Failed to complete task: {}", e));
+        }
+
+        self.refresh_todo_data();
+        IpcResponse::ok_with_message(format!("Completed task #{}", id))
+    }
+
     /// Show the todo as a separate OS window using show_viewport_deferred.
     pub(super) fn show_todo_viewport(&mut self, ctx: &egui::Context) {
         // ── 0. Ensure parent context is set for wakeup ────────────
@@ -468,7 +641,7 @@ impl PomodoRustApp {
             if let Ok(mut data) = self.shared_todo.data.write() {
                 data.is_always_on_top = new_value;
             }
-            let _ = self.config.save();
+            self.save_config();
             ctx.request_repaint();
         }
 
@@ -0,0 +1,113 @@
+//! Todoist sync: imports tasks into the current workspace, comments on them
+//! after focus sessions and closes them once they're done
+
+use super::PomodoRustApp;
+use crate::integrations::{TodoistClient, TodoistReply};
+
+impl PomodoRustApp {
+    /// Start or stop the client with the config, sync when it's time and
+    /// take in the replies
+    pub(super) fn update_todoist(&mut self, ctx: &egui::Context) {
+        if !self.config.todoist.is_active() {
+            self.todoist = None;
+            return;
+        }
+        let client = self
+            .todoist
+            .get_or_insert_with(|| TodoistClient::start(self.config.todoist.clone(), ctx.clone()));
+        client.sync_if_due();
+
+        let mut imported = false;
+        while let Some(reply) = self.todoist.as_mut().and_then(TodoistClient::poll) {
+            match reply {
+                TodoistReply::Tasks(tasks) => imported |= self.import_todoist_tasks(&tasks),
+                TodoistReply::Closed(todo_id) => {
+                    if let Some(db) = &self.database {
+                        if let Err(e) = db.set_todoist_closed(todo_id) {
+                            tracing::warn!("DB set_todoist_closed: {e}");
+                        }
+                    }
+                }
+                TodoistReply::Failed(e) => {
+                    self.show_error(format!("{}: {}", crate::i18n::tr().notif.todoist_failed, e));
+                }
+            }
+        }
+        if imported {
+            self.refresh_todo_data();
+        }
+    }
+
+    /// Add the tasks not seen before, returns whether there were any
+    fn import_todoist_tasks(&mut self, tasks: &[crate::integrations::TodoistTask]) -> bool {
+        let Some(db) = &self.database else {
+            return false;
+        };
+        let workspace_id = self
+            .shared_todo
+            .data
+            .read()
+            .map(|s| s.current_workspace_id)
+            .unwrap_or(0);
+        if workspace_id == 0 {
+            return false;
+        }
+
+        let mut imported = 0;
+        for task in tasks {
+            match db.import_todoist_task(
+                workspace_id,
+                &task.id,
+                &task.content,
+                &task.description,
+                task.local_priority(),
+            ) {
+                Ok(Some(_)) => imported += 1,
+                Ok(None) => {}
+                Err(e) => tracing::warn!("DB import_todoist_task: {e}"),
+            }
+        }
+        if imported > 0 {
+            tracing::info!("Imported {} tasks from Todoist", imported);
+        }
+        imported > 0
+    }
+
+    /// Comment on the Todoist task of a todo after a focus session on it
+    pub(super) fn todoist_session_done(&self, todo_id: i64, minutes: u32) {
+        if !self.config.todoist.comment_on_session {
+            return;
+        }
+        let (Some(client), Some(db)) = (&self.todoist, &self.database) else {
+            return;
+        };
+        if let Ok(Some(task_id)) = db.todoist_task_id(todo_id) {
+            let t = crate::i18n::tr();
+            client.comment(
+                &task_id,
+                format!(
+                    "🍅 {} ({} {})",
+                    t.notif.todoist_comment, minutes, t.common.min
+                ),
+            );
+        }
+    }
+
+    /// Close the Todoist tasks of the imported todos done in the app
+    pub(super) fn close_done_todoist_tasks(&mut self) {
+        if !self.config.todoist.close_when_done {
+            return;
+        }
+        let (Some(client), Some(db)) = (&mut self.todoist, &self.database) else {
+            return;
+        };
+        match db.todoist_tasks_to_close() {
+            Ok(done) => {
+                for (todo_id, task_id) in done {
+                    client.close(todo_id, &task_id);
+                }
+            }
+            Err(e) => tracing::warn!("DB todoist_tasks_to_close: {e}"),
+        }
+    }
+}
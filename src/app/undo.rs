@@ -0,0 +1,102 @@
+//! Short window to take back a stats reset or a session undo
+//!
+//! The database keeps copies of the changed rows until the window runs out,
+//! meanwhile a small toast with a countdown offers to put them back.
+
+use std::time::{Duration, Instant};
+
+use egui::RichText;
+
+use super::PomodoRustApp;
+use crate::data::Statistics;
+
+/// How long the undo stays on offer
+const UNDO_WINDOW: Duration = Duration::from_secs(5);
+
+/// Destructive action that can still be taken back
+pub(super) struct PendingUndo {
+    message: &'static str,
+    expires: Instant,
+}
+
+impl PomodoRustApp {
+    /// Offer to take back the action just done, replacing any older offer
+    pub(super) fn offer_undo(&mut self, message: &'static str) {
+        self.pending_undo = Some(PendingUndo {
+            message,
+            expires: Instant::now() + UNDO_WINDOW,
+        });
+    }
+
+    /// Toast at the bottom of the window with an "Undo (Ns)" button
+    pub(super) fn render_undo_toast(&mut self, ctx: &egui::Context) {
+        let Some(pending) = &self.pending_undo else {
+            return;
+        };
+
+        let remaining = pending.expires.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            self.pending_undo = None;
+            if let Some(db) = &self.database {
+                if let Err(e) = db.discard_deleted() {
+                    tracing::warn!("Failed to discard undo data: {}", e);
+                }
+            }
+            return;
+        }
+
+        let t = crate::i18n::tr();
+        let theme = &self.theme;
+        let message = pending.message;
+        // Whole seconds left, rounded up so the label starts at the full window
+        let seconds = remaining.as_millis().div_ceil(1000);
+        let mut undo = false;
+        egui::Area::new(egui::Id::new("undo_toast"))
+            .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -16.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                egui::Frame::none()
+                    .fill(theme.bg_elevated)
+                    .stroke(egui::Stroke::new(1.0, theme.border_subtle))
+                    .rounding(theme.rounding_md)
+                    .inner_margin(egui::Margin::symmetric(12.0, 8.0))
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(message).color(theme.text_primary));
+                            ui.add_space(theme.spacing_sm);
+                            let button = egui::Button::new(
+                                RichText::new(format!("{} ({}s)", t.common.undo, seconds))
+                                    .strong()
+                                    .color(theme.accent.solid()),
+                            )
+                            .frame(false);
+                            if ui.add(button).clicked() {
+                                undo = true;
+                            }
+                        });
+                    });
+            });
+
+        if !undo {
+            // Wake up for the next tick of the countdown
+            let to_next_second = remaining.as_millis() % 1000;
+            ctx.request_repaint_after(Duration::from_millis(to_next_second.max(1) as u64));
+            return;
+        }
+
+        self.pending_undo = None;
+        let Some(db) = &self.database else {
+            return;
+        };
+        match db.restore_deleted() {
+            Ok(()) => {
+                self.statistics = Statistics::load_within(db, &self.config.working_hours);
+                self.reload_stats_tab();
+            }
+            Err(e) => {
+                tracing::error!("Failed to restore statistics: {}", e);
+                self.show_error(e.to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Weekly report email: last week's statistics go out on Monday morning,
+//! or as soon as the app runs after that
+
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime};
+
+use super::PomodoRustApp;
+use crate::data::WeeklyReport;
+use crate::integrations::Mailer;
+
+impl PomodoRustApp {
+    /// Start or stop the mailer with the config, take in its replies and
+    /// send last week's report once it's due
+    pub(super) fn update_weekly_report(&mut self, ctx: &egui::Context) {
+        if !self.config.email.is_active() {
+            self.mailer = None;
+            return;
+        }
+        let mailer = self
+            .mailer
+            .get_or_insert_with(|| Mailer::start(self.config.email.clone(), ctx.clone()));
+        let Some(db) = &self.database else {
+            return;
+        };
+
+        let mut failed = None;
+        while let Some(reply) = mailer.poll() {
+            match reply {
+                Ok(()) => {
+                    if let Some(week) = self.report_week {
+                        if let Err(e) = db.mark_weekly_report_sent(week) {
+                            tracing::warn!("DB mark_weekly_report_sent: {e}");
+                        }
+                    }
+                }
+                Err(e) => {
+                    // Retried once the mailer is ready again
+                    self.report_week = None;
+                    failed = Some(e);
+                }
+            }
+        }
+
+        let now = Local::now().naive_local();
+        let send_at = self.config.email.send_time();
+        match due_week(now, send_at) {
+            Some(week) if self.report_week != Some(week) => {
+                if mailer.ready() {
+                    match db.weekly_report_sent(week) {
+                        Ok(true) => self.report_week = Some(week),
+                        Ok(false) => {
+                            let report = WeeklyReport::load(db, week);
+                            mailer.send(report.title(), report.to_html());
+                            self.report_week = Some(week);
+                        }
+                        Err(e) => tracing::warn!("DB weekly_report_sent: {e}"),
+                    }
+                }
+            }
+            _ => {
+                let wait = next_due(now, send_at) - now;
+                ctx.request_repaint_after(wait.to_std().unwrap_or_default());
+            }
+        }
+
+        if let Some(e) = failed {
+            self.show_error(format!(
+                "{}: {}",
+                crate::i18n::tr().notif.weekly_report_failed,
+                e
+            ));
+        }
+    }
+}
+
+/// Monday of this week at `send_at`
+fn this_monday(now: NaiveDateTime, send_at: NaiveTime) -> NaiveDateTime {
+    let days = now.weekday().num_days_from_monday() as i64;
+    (now.date() - Duration::days(days)).and_time(send_at)
+}
+
+/// Monday of the week whose report is due: last week's, once this Monday's
+/// send time has passed
+fn due_week(now: NaiveDateTime, send_at: NaiveTime) -> Option<NaiveDate> {
+    let monday = this_monday(now, send_at);
+    (now >= monday).then(|| monday.date() - Duration::weeks(1))
+}
+
+/// When the next report is due
+fn next_due(now: NaiveDateTime, send_at: NaiveTime) -> NaiveDateTime {
+    let monday = this_monday(now, send_at);
+    if now < monday {
+        monday
+    } else {
+        monday + Duration::weeks(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_due_week() {
+        let eight = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+        let at = |d, h| day(d).and_hms_opt(h, 0, 0).unwrap();
+
+        // 2024-03-11 is a Monday
+        assert_eq!(due_week(at(11, 7), eight), None);
+        assert_eq!(next_due(at(11, 7), eight), at(11, 8));
+        assert_eq!(due_week(at(11, 8), eight), Some(day(4)));
+        assert_eq!(due_week(at(17, 23), eight), Some(day(4)));
+        assert_eq!(next_due(at(17, 23), eight), at(18, 8));
+    }
+}
@@ -0,0 +1,22 @@
+//! Working hours: auto-start holds outside them, and a warning for focus
+//! sessions started late at night
+
+use chrono::Local;
+
+use super::PomodoRustApp;
+
+impl PomodoRustApp {
+    /// Whether the next session may start by itself now
+    pub(super) fn auto_start_allowed(&self) -> bool {
+        let hours = &self.config.working_hours;
+        !hours.hold_auto_start || hours.is_working_time(Local::now().naive_local())
+    }
+
+    /// Warn about a focus session started outside working hours
+    pub(super) fn warn_if_late(&mut self) {
+        let hours = &self.config.working_hours;
+        if hours.warn_late && hours.is_late(Local::now().time()) {
+            self.show_status(crate::i18n::tr().notif.late_focus);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Time source of the timer
+//!
+//! [`Timer`](super::Timer) reads the time through a [`Clock`] instead of
+//! calling `Instant::now()` itself, so tests can move time forward by hand
+//! with a [`ManualClock`], and development builds can run sessions faster
+//! with a [`ScaledClock`].
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Speeds up time by this factor when set, e.g. `60` for a minute a second
+const TIME_SCALE: &str = "POMODORUST_TIME_SCALE";
+
+/// A source of the current time
+pub trait Clock: Debug + Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+/// Shared handle to a clock, as held by timers
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The system's monotonic clock
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when told to
+#[derive(Debug)]
+pub struct ManualClock {
+    origin: Instant,
+    offset: Mutex<Duration>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            offset: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the time forward
+    pub fn advance(&self, by: Duration) {
+        if let Ok(mut offset) = self.offset.lock() {
+            *offset += by;
+        }
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + self.offset.lock().map(|o| *o).unwrap_or_default()
+    }
+}
+
+/// The system clock running `scale` times faster, for trying out long
+/// sessions and auto-start chains during development
+#[derive(Debug)]
+pub struct ScaledClock {
+    origin: Instant,
+    scale: f64,
+}
+
+impl ScaledClock {
+    pub fn new(scale: f64) -> Self {
+        Self {
+            origin: Instant::now(),
+            scale: scale.max(0.0),
+        }
+    }
+}
+
+impl Clock for ScaledClock {
+    fn now(&self) -> Instant {
+        self.origin + self.origin.elapsed().mul_f64(self.scale)
+    }
+}
+
+/// The system clock, or a faster one when `POMODORUST_TIME_SCALE` is set
+pub fn clock_from_env() -> SharedClock {
+    let scale = std::env::var(TIME_SCALE)
+        .ok()
+        .and_then(|scale| scale.trim().parse::<f64>().ok())
+        .filter(|scale| scale.is_finite() && *scale > 0.0 && *scale != 1.0);
+    match scale {
+        Some(scale) => {
+            tracing::warn!("Simulating time {}x faster ({})", scale, TIME_SCALE);
+            Arc::new(ScaledClock::new(scale))
+        }
+        None => Arc::new(SystemClock),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(clock.now() - start, Duration::from_secs(90));
+    }
+}
@@ -3,6 +3,7 @@
 //! This module contains the core domain logic, independent of UI:
 //!
 //! - [`Timer`]: Countdown timer with precise timing using `Instant`
+//! - [`Clock`]: Where the timer reads the time, replaceable in tests
 //! - [`Session`]: Manages the Pomodoro workflow (work/break cycles)
 //! - [`Preset`]: Timer configuration presets (work/break durations)
 //!
@@ -30,10 +31,12 @@
 //! }
 //! ```
 
+mod clock;
 mod preset;
 mod session;
 mod timer;
 
-pub use preset::{Preset, PresetManager};
+pub use clock::{clock_from_env, Clock, ManualClock, ScaledClock, SharedClock, SystemClock};
+pub use preset::{Preset, PresetBehavior, PresetManager};
 pub use session::{Session, SessionState, SessionType};
 pub use timer::{Timer, TimerEvent, TimerState};
@@ -18,6 +18,22 @@ pub struct Preset {
     /// Whether this is a built-in preset
     #[serde(default)]
     pub is_builtin: bool,
+    /// Settings switched along with the durations
+    #[serde(default)]
+    pub behavior: PresetBehavior,
+}
+
+/// What choosing a preset changes besides the durations. `None` leaves the
+/// current setting as it is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetBehavior {
+    pub auto_start_breaks: Option<bool>,
+    pub auto_start_work: Option<bool>,
+    /// No pausing, skipping or resetting a running focus session
+    pub strict: Option<bool>,
+    pub sounds_enabled: Option<bool>,
+    pub tick_enabled: Option<bool>,
 }
 
 impl Preset {
@@ -36,9 +52,16 @@ impl Preset {
             long_break,
             sessions_before_long_break: sessions,
             is_builtin: false,
+            behavior: PresetBehavior::default(),
         }
     }
 
+    /// The same preset, switching these settings along
+    pub fn with_behavior(mut self, behavior: PresetBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
     /// Classic Pomodoro (25/5/15)
     pub fn classic() -> Self {
         Self {
@@ -48,6 +71,7 @@ impl Preset {
             long_break: 15,
             sessions_before_long_break: 4,
             is_builtin: true,
+            behavior: PresetBehavior::default(),
         }
     }
 
@@ -60,6 +84,7 @@ impl Preset {
             long_break: 10,
             sessions_before_long_break: 4,
             is_builtin: true,
+            behavior: PresetBehavior::default(),
         }
     }
 
@@ -72,6 +97,7 @@ impl Preset {
             long_break: 30,
             sessions_before_long_break: 2,
             is_builtin: true,
+            behavior: PresetBehavior::default(),
         }
     }
 
@@ -84,6 +110,7 @@ impl Preset {
             long_break: 30,
             sessions_before_long_break: 2,
             is_builtin: true,
+            behavior: PresetBehavior::default(),
         }
     }
 }
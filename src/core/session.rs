@@ -1,6 +1,9 @@
 //! Session management for Pomodoro workflow
 
-use super::{Preset, Timer, TimerEvent};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{Preset, SharedClock, SystemClock, Timer, TimerEvent};
 use serde::{Deserialize, Serialize};
 
 /// Type of Pomodoro session
@@ -21,6 +24,16 @@ impl SessionType {
         }
     }
 
+    /// Parse a database storage string
+    pub fn from_db_str(s: &str) -> Option<Self> {
+        match s {
+            "work" => Some(SessionType::Work),
+            "short_break" => Some(SessionType::ShortBreak),
+            "long_break" => Some(SessionType::LongBreak),
+            _ => None,
+        }
+    }
+
     /// Get the display label for UI
     pub fn label(&self) -> &'static str {
         match self {
@@ -63,31 +76,31 @@ pub struct Session {
     /// Whether to auto-start next session
     auto_start_breaks: bool,
     auto_start_work: bool,
+    /// Clock of every timer of the session
+    clock: SharedClock,
 }
 
 impl Session {
     /// Create a new session with default preset
     pub fn new() -> Self {
-        let preset = Preset::default();
-        Self {
-            timer: Timer::from_minutes(preset.work_duration),
-            session_type: SessionType::Work,
-            completed_work_sessions: 0,
-            preset,
-            auto_start_breaks: false,
-            auto_start_work: false,
-        }
+        Self::with_preset(Preset::default())
     }
 
     /// Create a session with a specific preset
     pub fn with_preset(preset: Preset) -> Self {
+        Self::with_clock(preset, Arc::new(SystemClock))
+    }
+
+    /// Create a session whose timers read the time from `clock`
+    pub fn with_clock(preset: Preset, clock: SharedClock) -> Self {
         Self {
-            timer: Timer::from_minutes(preset.work_duration),
+            timer: Timer::with_clock(preset.work_duration as u64 * 60, clock.clone()),
             session_type: SessionType::Work,
             completed_work_sessions: 0,
             preset,
             auto_start_breaks: false,
             auto_start_work: false,
+            clock,
         }
     }
 
@@ -96,7 +109,12 @@ impl Session {
         self.preset = preset;
         // Reset to work session with new duration
         self.session_type = SessionType::Work;
-        self.timer = Timer::from_minutes(self.preset.work_duration);
+        self.timer = self.new_timer(self.preset.work_duration);
+    }
+
+    /// A timer of `minutes` on the session's clock
+    fn new_timer(&self, minutes: u32) -> Timer {
+        Timer::with_clock(minutes as u64 * 60, self.clock.clone())
     }
 
     /// Set auto-start preferences
@@ -130,6 +148,21 @@ impl Session {
         }
     }
 
+    /// Focus time done so far in a work session that was started but hasn't
+    /// finished, `None` otherwise
+    pub fn unfinished_focus(&self) -> Option<Duration> {
+        let unfinished = self.session_type == SessionType::Work
+            && matches!(
+                self.timer.state(),
+                super::TimerState::Running | super::TimerState::Paused
+            );
+        unfinished.then(|| {
+            self.timer
+                .total_duration()
+                .saturating_sub(self.timer.remaining())
+        })
+    }
+
     /// Get completed work sessions count
     pub fn completed_work_sessions(&self) -> u32 {
         self.completed_work_sessions
@@ -223,7 +256,7 @@ impl Session {
             SessionType::ShortBreak => self.preset.short_break,
             SessionType::LongBreak => self.preset.long_break,
         };
-        self.timer = Timer::from_minutes(duration);
+        self.timer = self.new_timer(duration);
     }
 
     /// Skip to next session
@@ -270,3 +303,62 @@ impl Default for Session {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::core::{ManualClock, SessionState};
+
+    fn session(clock: &Arc<ManualClock>) -> Session {
+        let preset = Preset::custom("Test", 25, 5, 15, 2);
+        Session::with_clock(preset, clock.clone())
+    }
+
+    #[test]
+    fn test_auto_start_chain() {
+        let clock = Arc::new(ManualClock::new());
+        let mut session = session(&clock);
+        session.set_auto_start(true, true);
+        session.start();
+
+        let mut types = Vec::new();
+        for minutes in [25, 5, 25, 15] {
+            clock.advance(Duration::from_secs(minutes * 60));
+            let (event, auto_start) = session.update();
+            assert_eq!(event, Some(TimerEvent::Completed));
+            assert!(auto_start);
+            types.push(session.session_type());
+            session.start();
+        }
+        assert_eq!(
+            types,
+            [
+                SessionType::ShortBreak,
+                SessionType::Work,
+                SessionType::LongBreak,
+                SessionType::Work
+            ]
+        );
+        assert_eq!(session.completed_work_sessions(), 2);
+    }
+
+    #[test]
+    fn test_sleep_gap() {
+        let clock = Arc::new(ManualClock::new());
+        let mut session = session(&clock);
+        session.start();
+        clock.advance(Duration::from_secs(10 * 60));
+        session.update();
+        assert_eq!(session.timer().remaining_secs(), 15 * 60);
+
+        // A machine asleep for hours finishes the session once, without
+        // auto-starting the break
+        clock.advance(Duration::from_secs(8 * 3600));
+        assert_eq!(session.update(), (Some(TimerEvent::Completed), false));
+        assert_eq!(session.session_type(), SessionType::ShortBreak);
+        assert_eq!(session.state(), SessionState::Ready);
+        assert_eq!(session.update(), (None, false));
+    }
+}
@@ -1,7 +1,10 @@
 //! Timer logic with state management
 
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use super::clock::{SharedClock, SystemClock};
+
 /// Timer state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimerState {
@@ -35,11 +38,18 @@ pub struct Timer {
     last_tick: Option<Instant>,
     /// Accumulated elapsed time (for pause/resume)
     elapsed_while_running: Duration,
+    /// Where the time is read from
+    clock: SharedClock,
 }
 
 impl Timer {
     /// Create a new timer with the given duration in seconds
     pub fn new(duration_secs: u64) -> Self {
+        Self::with_clock(duration_secs, Arc::new(SystemClock))
+    }
+
+    /// Create a timer that reads the time from `clock`
+    pub fn with_clock(duration_secs: u64, clock: SharedClock) -> Self {
         let duration = Duration::from_secs(duration_secs);
         Self {
             total_duration: duration,
@@ -47,6 +57,7 @@ impl Timer {
             state: TimerState::Idle,
             last_tick: None,
             elapsed_while_running: Duration::ZERO,
+            clock,
         }
     }
 
@@ -60,13 +71,13 @@ impl Timer {
         match self.state {
             TimerState::Idle | TimerState::Completed => {
                 self.state = TimerState::Running;
-                self.last_tick = Some(Instant::now());
+                self.last_tick = Some(self.clock.now());
                 self.elapsed_while_running = Duration::ZERO;
                 TimerEvent::Started
             }
             TimerState::Paused => {
                 self.state = TimerState::Running;
-                self.last_tick = Some(Instant::now());
+                self.last_tick = Some(self.clock.now());
                 TimerEvent::Resumed
             }
             TimerState::Running => TimerEvent::Tick,
@@ -77,7 +88,7 @@ impl Timer {
     pub fn pause(&mut self) -> TimerEvent {
         if self.state == TimerState::Running {
             if let Some(last) = self.last_tick {
-                self.elapsed_while_running += last.elapsed();
+                self.elapsed_while_running += self.clock.now().duration_since(last);
             }
             self.state = TimerState::Paused;
             self.last_tick = None;
@@ -110,6 +121,29 @@ impl Timer {
         self.reset();
     }
 
+    /// Shift the elapsed time by `delta_secs` (negative gives time back),
+    /// e.g. to count or discard time spent away from the computer
+    pub fn adjust_elapsed(&mut self, delta_secs: i64) {
+        let delta = Duration::from_secs(delta_secs.unsigned_abs());
+        self.elapsed_while_running = if delta_secs >= 0 {
+            (self.elapsed_while_running + delta).min(self.total_duration)
+        } else {
+            self.elapsed_while_running.saturating_sub(delta)
+        };
+        if self.state == TimerState::Paused {
+            self.remaining = self.total_duration - self.elapsed_while_running;
+        }
+    }
+
+    /// End the timer in `remaining_secs` instead, if that's sooner
+    pub fn cut_to(&mut self, remaining_secs: u64) {
+        let remaining = Duration::from_secs(remaining_secs);
+        if remaining < self.remaining {
+            self.total_duration -= self.remaining - remaining;
+            self.remaining = remaining;
+        }
+    }
+
     /// Update the timer (call this every frame)
     /// Returns Some(event) if an event occurred
     pub fn update(&mut self) -> Option<TimerEvent> {
@@ -117,7 +151,7 @@ impl Timer {
             return None;
         }
 
-        let now = Instant::now();
+        let now = self.clock.now();
         let elapsed = if let Some(last) = self.last_tick {
             self.elapsed_while_running + now.duration_since(last)
         } else {
@@ -135,6 +169,11 @@ impl Timer {
         }
     }
 
+    /// The clock the timer reads
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
     /// Get the current state
     pub fn state(&self) -> TimerState {
         self.state
@@ -184,6 +223,15 @@ impl Timer {
         self.remaining.as_millis() as u64
     }
 
+    /// Time until the countdown shown by [`Self::remaining_formatted`]
+    /// changes, for waking up just in time instead of every frame
+    pub fn until_next_second(&self) -> Duration {
+        match self.remaining.as_millis() % 1000 {
+            0 => Duration::from_secs(1),
+            millis => Duration::from_millis(millis as u64),
+        }
+    }
+
     /// Get precise progress with sub-second accuracy (for smooth animations)
     pub fn progress_precise(&self) -> f32 {
         if self.total_duration.as_millis() == 0 {
@@ -226,6 +274,7 @@ impl Default for Timer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::ManualClock;
 
     #[test]
     fn test_timer_creation() {
@@ -240,9 +289,62 @@ mod tests {
         assert_eq!(timer.remaining_formatted(), "01:30");
     }
 
+    #[test]
+    fn test_until_next_second() {
+        let mut timer = Timer::new(90);
+        assert_eq!(timer.until_next_second(), Duration::from_secs(1));
+        timer.remaining = Duration::from_millis(89_250);
+        assert_eq!(timer.remaining_formatted(), "01:30");
+        assert_eq!(timer.until_next_second(), Duration::from_millis(250));
+    }
+
     #[test]
     fn test_timer_progress() {
         let timer = Timer::from_minutes(25);
         assert_eq!(timer.progress(), 0.0);
     }
+
+    #[test]
+    fn test_adjust_elapsed() {
+        let mut timer = Timer::new(600);
+        timer.start();
+        timer.pause();
+        timer.adjust_elapsed(120);
+        assert!((timer.remaining().as_secs_f64() - 480.0).abs() < 1.0);
+        timer.adjust_elapsed(-300);
+        assert_eq!(timer.remaining_secs(), 600);
+        timer.adjust_elapsed(1000);
+        assert_eq!(timer.remaining_secs(), 0);
+    }
+
+    #[test]
+    fn test_pause_resume() {
+        let clock = Arc::new(ManualClock::new());
+        let mut timer = Timer::with_clock(1500, clock.clone());
+        timer.start();
+        clock.advance(Duration::from_secs(600));
+        timer.update();
+        assert_eq!(timer.remaining_secs(), 900);
+
+        // Time spent paused doesn't count
+        timer.pause();
+        clock.advance(Duration::from_secs(3600));
+        timer.start();
+        timer.update();
+        assert_eq!(timer.remaining_secs(), 900);
+
+        clock.advance(Duration::from_secs(900));
+        assert_eq!(timer.update(), Some(TimerEvent::Completed));
+        assert_eq!(timer.remaining_secs(), 0);
+    }
+
+    #[test]
+    fn test_cut_to() {
+        let mut timer = Timer::new(1500);
+        timer.cut_to(600);
+        assert_eq!(timer.remaining_secs(), 600);
+        assert_eq!(timer.total_duration().as_secs(), 600);
+        timer.cut_to(900);
+        assert_eq!(timer.remaining_secs(), 600);
+    }
 }
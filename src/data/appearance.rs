@@ -0,0 +1,163 @@
+//! Appearance choices stored in the config: the theme and accent color.
+//! How they look is up to the UI (`ui::theme`).
+
+use serde::{Deserialize, Serialize};
+
+/// Theme mode options
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ThemeMode {
+    #[default]
+    System,
+    Light,
+    Dark,
+    /// Light during the day and dark at night, see `ThemeScheduleConfig`
+    Schedule,
+    // Catppuccin flavors
+    CatppuccinLatte,
+    CatppuccinFrappe,
+    CatppuccinMacchiato,
+    CatppuccinMocha,
+}
+
+impl ThemeMode {
+    pub fn all() -> &'static [ThemeMode] {
+        &[
+            ThemeMode::System,
+            ThemeMode::Light,
+            ThemeMode::Dark,
+            ThemeMode::Schedule,
+            ThemeMode::CatppuccinLatte,
+            ThemeMode::CatppuccinFrappe,
+            ThemeMode::CatppuccinMacchiato,
+            ThemeMode::CatppuccinMocha,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        crate::i18n::tr().theme_name(*self)
+    }
+}
+
+/// Accent color options
+///
+/// Serialized by name, or as `"#rrggbb"` for a custom color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccentColor {
+    #[default]
+    Blue,
+    Purple,
+    Rose,
+    Emerald,
+    Amber,
+    Cyan,
+    // Retro terminal colors
+    Matrix,     // Green phosphor
+    RetroAmber, // Amber CRT
+    Synthwave,  // Pink/cyan retro
+    /// Any RGB color, the gradient end is derived from it
+    Custom(u8, u8, u8),
+}
+
+impl AccentColor {
+    pub fn all() -> &'static [AccentColor] {
+        &[
+            AccentColor::Blue,
+            AccentColor::Purple,
+            AccentColor::Rose,
+            AccentColor::Emerald,
+            AccentColor::Amber,
+            AccentColor::Cyan,
+            AccentColor::Matrix,
+            AccentColor::RetroAmber,
+            AccentColor::Synthwave,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        crate::i18n::tr().accent_name(*self)
+    }
+
+    /// Stable name used in the config file (None for custom colors)
+    fn config_name(&self) -> Option<&'static str> {
+        Some(match self {
+            AccentColor::Blue => "Blue",
+            AccentColor::Purple => "Purple",
+            AccentColor::Rose => "Rose",
+            AccentColor::Emerald => "Emerald",
+            AccentColor::Amber => "Amber",
+            AccentColor::Cyan => "Cyan",
+            AccentColor::Matrix => "Matrix",
+            AccentColor::RetroAmber => "RetroAmber",
+            AccentColor::Synthwave => "Synthwave",
+            AccentColor::Custom(..) => return None,
+        })
+    }
+
+    /// Parse a config value: a preset name or a `#rrggbb` color
+    pub fn parse(value: &str) -> Option<Self> {
+        if let Some(hex) = value.strip_prefix('#') {
+            if hex.len() != 6 || !hex.is_ascii() {
+                return None;
+            }
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+            return Some(AccentColor::Custom(channel(0)?, channel(2)?, channel(4)?));
+        }
+        Self::all()
+            .iter()
+            .copied()
+            .find(|accent| accent.config_name() == Some(value))
+    }
+
+    /// The chosen color of a custom accent
+    pub fn custom_rgb(&self) -> Option<[u8; 3]> {
+        match *self {
+            AccentColor::Custom(r, g, b) => Some([r, g, b]),
+            _ => None,
+        }
+    }
+
+    /// Check if this is a retro/TUI style
+    pub fn is_retro(&self) -> bool {
+        matches!(
+            self,
+            AccentColor::Matrix | AccentColor::RetroAmber | AccentColor::Synthwave
+        )
+    }
+}
+
+impl Serialize for AccentColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self.config_name(), self.custom_rgb()) {
+            (Some(name), _) => serializer.serialize_str(name),
+            (None, Some([r, g, b])) => {
+                serializer.serialize_str(&format!("#{:02x}{:02x}{:02x}", r, g, b))
+            }
+            (None, None) => unreachable!("only custom accents have no name"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AccentColor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown accent color: {}", value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accent_parse() {
+        assert_eq!(AccentColor::parse("Emerald"), Some(AccentColor::Emerald));
+        assert_eq!(
+            AccentColor::parse("#3b82F6"),
+            Some(AccentColor::Custom(0x3b, 0x82, 0xf6))
+        );
+        assert_eq!(AccentColor::parse("#3b82f"), None);
+        assert_eq!(AccentColor::parse("Teal"), None);
+        assert!(!AccentColor::Custom(1, 2, 3).is_retro());
+    }
+}
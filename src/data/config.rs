@@ -5,12 +5,20 @@
 
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
+use super::appearance::{AccentColor, ThemeMode};
+use super::config_check::{clamp_reported, ConfigIssue};
+use super::config_env::EnvOverrides;
+use super::config_migrate::CONFIG_VERSION;
+use crate::core::{PresetBehavior, SessionType};
 use crate::error::ConfigError;
 use crate::i18n::Language;
-use crate::ui::theme::{AccentColor, ThemeMode};
 
 /// Available notification sounds
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -41,8 +49,255 @@ impl NotificationSound {
     }
 }
 
+/// Background sounds that can play during focus sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AmbientSound {
+    Rain,
+    Cafe,
+    BrownNoise,
+}
+
+impl AmbientSound {
+    /// Get all available ambient sounds
+    pub fn all() -> &'static [AmbientSound] {
+        &[
+            AmbientSound::Rain,
+            AmbientSound::Cafe,
+            AmbientSound::BrownNoise,
+        ]
+    }
+
+    /// Get localized display name
+    pub fn name(&self) -> &'static str {
+        let t = crate::i18n::tr();
+        match self {
+            AmbientSound::Rain => t.settings.ambient_rain,
+            AmbientSound::Cafe => t.settings.ambient_cafe,
+            AmbientSound::BrownNoise => t.settings.ambient_brown_noise,
+        }
+    }
+}
+
+/// Volume of each ambient sound, 0 to 100 (0 is off)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct AmbientConfig {
+    pub rain: u32,
+    pub cafe: u32,
+    pub brown_noise: u32,
+}
+
+impl AmbientConfig {
+    pub fn volume(&self, sound: AmbientSound) -> u32 {
+        match sound {
+            AmbientSound::Rain => self.rain,
+            AmbientSound::Cafe => self.cafe,
+            AmbientSound::BrownNoise => self.brown_noise,
+        }
+    }
+
+    pub fn volume_mut(&mut self, sound: AmbientSound) -> &mut u32 {
+        match sound {
+            AmbientSound::Rain => &mut self.rain,
+            AmbientSound::Cafe => &mut self.cafe,
+            AmbientSound::BrownNoise => &mut self.brown_noise,
+        }
+    }
+}
+
+/// Soft chimes during a focus session, to pace it without a look at the clock
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct ChimeConfig {
+    /// Chime halfway through the session
+    pub halfway: bool,
+    /// Chime every this many minutes into the session (0 is off)
+    pub interval_minutes: u32,
+}
+
+/// Reminders repeated while a finished session waits to be acted on
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ReminderConfig {
+    pub enabled: bool,
+    /// Minutes after the session ended at which each reminder comes, in
+    /// increasing order. Each one is more insistent than the one before.
+    pub steps_minutes: Vec<u32>,
+    /// Play the notification sound from the second reminder on
+    pub sound: bool,
+}
+
+impl Default for ReminderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            steps_minutes: vec![2, 5, 10],
+            sound: true,
+        }
+    }
+}
+
+impl ReminderConfig {
+    /// Steps as typed in the settings, e.g. "2, 5, 10"
+    pub fn steps_text(&self) -> String {
+        let steps: Vec<String> = self.steps_minutes.iter().map(u32::to_string).collect();
+        steps.join(", ")
+    }
+
+    /// Steps from the settings text, sorted, skipping what isn't a number
+    /// of minutes
+    pub fn parse_steps(text: &str) -> Vec<u32> {
+        let mut steps: Vec<u32> = text
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter_map(|step| step.parse().ok())
+            .filter(|&minutes| minutes > 0)
+            .collect();
+        steps.sort_unstable();
+        steps.dedup();
+        steps
+    }
+}
+
+/// Built-in preset a weekday can switch to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DayPreset {
+    /// Keep the durations from the timer settings
+    #[default]
+    Usual,
+    Classic,
+    Short,
+    Long,
+}
+
+impl DayPreset {
+    pub fn all() -> &'static [DayPreset] {
+        &[
+            DayPreset::Usual,
+            DayPreset::Classic,
+            DayPreset::Short,
+            DayPreset::Long,
+        ]
+    }
+
+    /// Get localized display name
+    pub fn name(&self) -> &'static str {
+        let t = crate::i18n::tr();
+        match self {
+            DayPreset::Usual => t.settings.day_usual,
+            DayPreset::Classic => t.settings.preset_classic,
+            DayPreset::Short => t.settings.preset_short,
+            DayPreset::Long => t.settings.preset_long,
+        }
+    }
+
+    /// The preset itself, `None` for the usual durations
+    pub fn preset(&self) -> Option<crate::core::Preset> {
+        match self {
+            DayPreset::Usual => None,
+            DayPreset::Classic => Some(crate::core::Preset::classic()),
+            DayPreset::Short => Some(crate::core::Preset::short()),
+            DayPreset::Long => Some(crate::core::Preset::long()),
+        }
+    }
+}
+
+/// Preset and daily goal of one weekday
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct DayPlan {
+    pub preset: DayPreset,
+    /// Daily goal in pomodoros (0 keeps the usual goal)
+    pub daily_target: u32,
+}
+
+/// A plan for each weekday, Monday first, taken up at the first session of the day
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct WeekdayConfig {
+    pub enabled: bool,
+    pub days: [DayPlan; 7],
+}
+
+impl WeekdayConfig {
+    /// Plan of the given weekday, `None` while plans are off
+    pub fn plan_for(&self, weekday: chrono::Weekday) -> Option<DayPlan> {
+        self.enabled
+            .then(|| self.days[weekday.num_days_from_monday() as usize])
+    }
+}
+
+/// Working hours, Monday first, used for today's statistics, auto-start
+/// and late-night warnings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct WorkingHoursConfig {
+    pub enabled: bool,
+    /// Which weekdays are working days
+    pub days: [bool; 7],
+    /// Local time the working day starts, "HH:MM"
+    pub start: String,
+    /// Local time the working day ends, "HH:MM", after `start`
+    pub end: String,
+    /// Don't auto-start the next session outside working hours
+    pub hold_auto_start: bool,
+    /// Warn when a focus session starts before or after working hours
+    pub warn_late: bool,
+}
+
+impl Default for WorkingHoursConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            days: [true, true, true, true, true, false, false],
+            start: "09:00".to_string(),
+            end: "17:00".to_string(),
+            hold_auto_start: true,
+            warn_late: false,
+        }
+    }
+}
+
+impl WorkingHoursConfig {
+    /// Start and end of the working day, `None` while off or when the times
+    /// don't parse
+    pub fn hours(&self) -> Option<(chrono::NaiveTime, chrono::NaiveTime)> {
+        let parse = |text: &str| chrono::NaiveTime::parse_from_str(text.trim(), "%H:%M").ok();
+        let (start, end) = (parse(&self.start)?, parse(&self.end)?);
+        (self.enabled && start < end).then_some((start, end))
+    }
+
+    /// Working hours of a day, `None` on days off
+    pub fn hours_on(
+        &self,
+        date: chrono::NaiveDate,
+    ) -> Option<(chrono::NaiveTime, chrono::NaiveTime)> {
+        use chrono::Datelike;
+        let working_day = self.days[date.weekday().num_days_from_monday() as usize];
+        self.hours().filter(|_| working_day)
+    }
+
+    /// Whether `at` is within working hours (always while they're off)
+    pub fn is_working_time(&self, at: chrono::NaiveDateTime) -> bool {
+        if self.hours().is_none() {
+            return true;
+        }
+        self.hours_on(at.date())
+            .is_some_and(|(start, end)| (start..end).contains(&at.time()))
+    }
+
+    /// Whether `time` is before the start or after the end of the working
+    /// day, whatever the weekday
+    pub fn is_late(&self, time: chrono::NaiveTime) -> bool {
+        self.hours()
+            .is_some_and(|(start, end)| time < start || time >= end)
+    }
+}
+
 /// Timer configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct TimerConfig {
     pub work_duration: u32,
     pub short_break: u32,
@@ -50,6 +305,19 @@ pub struct TimerConfig {
     pub sessions_before_long: u32,
     pub auto_start_breaks: bool,
     pub auto_start_work: bool,
+    /// Ask for a one-line intent before a work session started by hand
+    #[serde(default)]
+    pub require_intent: bool,
+    /// No pausing, skipping or resetting a running focus session
+    #[serde(default)]
+    pub strict: bool,
+    /// Focus sessions cut short before this many seconds aren't recorded
+    #[serde(default = "default_min_record_secs")]
+    pub min_record_secs: u32,
+}
+
+fn default_min_record_secs() -> u32 {
+    60
 }
 
 impl Default for TimerConfig {
@@ -61,17 +329,59 @@ impl Default for TimerConfig {
             sessions_before_long: 4,
             auto_start_breaks: false,
             auto_start_work: false,
+            require_intent: false,
+            strict: false,
+            min_record_secs: default_min_record_secs(),
         }
     }
 }
 
+/// Settings switched along with each built-in preset
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct PresetsConfig {
+    pub classic: PresetBehavior,
+    pub short: PresetBehavior,
+    pub long: PresetBehavior,
+}
+
+impl PresetsConfig {
+    /// The built-in preset at `index`, in the order they're listed, with its
+    /// behavior
+    pub fn preset(&self, index: usize) -> Option<crate::core::Preset> {
+        use crate::core::Preset;
+        let preset = match index {
+            0 => Preset::classic().with_behavior(self.classic),
+            1 => Preset::short().with_behavior(self.short),
+            2 => Preset::long().with_behavior(self.long),
+            _ => return None,
+        };
+        Some(preset)
+    }
+
+    /// Behaviors in the order the presets are listed
+    pub fn behaviors_mut(&mut self) -> [&mut PresetBehavior; 3] {
+        [&mut self.classic, &mut self.short, &mut self.long]
+    }
+}
+
 /// Sound configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct SoundConfig {
     pub enabled: bool,
     pub volume: u32,
     pub notification_sound: NotificationSound,
     pub tick_enabled: bool,
+    /// Speed the tick up over the last seconds of a session
+    #[serde(default)]
+    pub tick_speed_up: bool,
+    /// Sound file played instead of `notification_sound`
+    #[serde(default)]
+    pub custom_notification: Option<PathBuf>,
+    /// Sound file looped instead of the built-in tick
+    #[serde(default)]
+    pub custom_tick: Option<PathBuf>,
 }
 
 impl Default for SoundConfig {
@@ -81,12 +391,16 @@ impl Default for SoundConfig {
             volume: 80,
             notification_sound: NotificationSound::SoftBell,
             tick_enabled: false,
+            tick_speed_up: false,
+            custom_notification: None,
+            custom_tick: None,
         }
     }
 }
 
 /// Appearance configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct AppearanceConfig {
     #[serde(default)]
     pub theme_mode: ThemeMode,
@@ -95,6 +409,18 @@ pub struct AppearanceConfig {
     pub window_opacity: u32,
     #[serde(default)]
     pub language: Language,
+    /// PNG picture drawn behind the timer view
+    #[serde(default)]
+    pub background_image: Option<PathBuf>,
+    /// Blur applied to the background image, in pixels
+    #[serde(default)]
+    pub background_blur: u32,
+    /// How much the background image is darkened, 0 to 100
+    #[serde(default = "default_background_dim")]
+    pub background_dim: u32,
+    /// When the "By time of day" theme mode turns light and dark
+    #[serde(default)]
+    pub theme_schedule: ThemeScheduleConfig,
 }
 
 impl Default for AppearanceConfig {
@@ -105,17 +431,87 @@ impl Default for AppearanceConfig {
             compact_mode: false,
             window_opacity: 100,
             language: Language::Auto,
+            background_image: None,
+            background_blur: 0,
+            background_dim: default_background_dim(),
+            theme_schedule: ThemeScheduleConfig::default(),
+        }
+    }
+}
+
+fn default_background_dim() -> u32 {
+    40
+}
+
+/// Light and dark hours of the "By time of day" theme mode
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ThemeScheduleConfig {
+    /// Follow an estimate of sunrise and sunset instead of the fixed times
+    pub follow_sun: bool,
+    /// Local time the light theme starts, "HH:MM"
+    pub light_at: String,
+    /// Local time the dark theme starts, "HH:MM"
+    pub dark_at: String,
+    /// Rough latitude for the sun estimate, in degrees north (negative for
+    /// south). The location is never looked up.
+    pub latitude: f32,
+}
+
+impl Default for ThemeScheduleConfig {
+    fn default() -> Self {
+        Self {
+            follow_sun: false,
+            light_at: "07:00".to_string(),
+            dark_at: "19:00".to_string(),
+            latitude: 45.0,
         }
     }
 }
 
+/// What the system tray icon shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TrayIconStyle {
+    /// Always the app logo
+    #[default]
+    Logo,
+    /// Remaining minutes while a session is active
+    Countdown,
+    /// Pie chart of the remaining time while a session is active
+    Progress,
+}
+
 /// System configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct SystemConfig {
     pub start_with_windows: bool,
     pub minimize_to_tray: bool,
     pub show_in_taskbar: bool,
     pub notifications_enabled: bool,
+    #[serde(default)]
+    pub tray_icon_style: TrayIconStyle,
+    /// Turn on Focus Assist / Do Not Disturb while a focus session runs
+    #[serde(default)]
+    pub dnd_during_focus: bool,
+    /// Pause playing media when focus starts, resume it when the break begins
+    #[serde(default)]
+    pub pause_media_during_focus: bool,
+    /// The close button hides the window to the tray instead of asking
+    #[serde(default)]
+    pub close_to_tray: bool,
+    /// Record which application is in the foreground during focus sessions,
+    /// for the focus time per application in the stats (kept on this computer)
+    #[serde(default)]
+    pub track_apps: bool,
+}
+
+impl SystemConfig {
+    /// Whether a tray icon is needed to hide the window into
+    pub fn wants_tray(&self) -> bool {
+        self.minimize_to_tray || self.close_to_tray
+    }
 }
 
 impl Default for SystemConfig {
@@ -125,12 +521,108 @@ impl Default for SystemConfig {
             minimize_to_tray: true,
             show_in_taskbar: true,
             notifications_enabled: true,
+            tray_icon_style: TrayIconStyle::Logo,
+            dnd_during_focus: false,
+            pause_media_during_focus: false,
+            close_to_tray: false,
+            track_apps: false,
+        }
+    }
+}
+
+/// Auto-pause when the computer is left idle
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct IdleConfig {
+    /// Pause running focus sessions after no keyboard/mouse input
+    pub enabled: bool,
+    /// Minutes without input before pausing
+    pub threshold_minutes: u32,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold_minutes: 5,
+        }
+    }
+}
+
+/// What happens to the timer when the screen is locked
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct ScreenLockConfig {
+    /// Pause a running focus session while locked, resume on unlock
+    pub pause_work: bool,
+    /// Start a pending or paused break when the screen is locked
+    pub count_as_break: bool,
+}
+
+impl ScreenLockConfig {
+    /// Whether any lock policy is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.pause_work || self.count_as_break
+    }
+}
+
+/// Distraction blocker applied during focus sessions
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct BlockerConfig {
+    pub enabled: bool,
+    /// Process names to close, e.g. `"steam"` or `"Discord.exe"`
+    pub apps: Vec<String>,
+    /// Domains to block through the hosts file, e.g. `"reddit.com"`
+    pub domains: Vec<String>,
+}
+
+impl BlockerConfig {
+    /// Whether there is anything to block
+    pub fn is_active(&self) -> bool {
+        self.enabled && !(self.apps.is_empty() && self.domains.is_empty())
+    }
+}
+
+/// Full-screen reminder covering every display during breaks
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct BreakOverlayConfig {
+    pub enabled: bool,
+    /// Show a button to skip the break
+    pub allow_skip: bool,
+}
+
+impl Default for BreakOverlayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            allow_skip: true,
         }
     }
 }
 
+/// What happens to a running session when the computer goes to sleep
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SleepPolicy {
+    /// Pause the session; it stays paused after waking up
+    #[default]
+    Pause,
+    /// Count the time asleep towards the session and keep it running
+    Continue,
+}
+
+/// System sleep handling
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct SleepConfig {
+    pub policy: SleepPolicy,
+}
+
 /// Window position configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct WindowConfig {
     pub width: f32,
     pub height: f32,
@@ -138,6 +630,23 @@ pub struct WindowConfig {
     pub y: Option<f32>,
     pub always_on_top: bool,
     pub maximized: bool,
+    /// Restore, raise and focus the window when a session completes
+    #[serde(default)]
+    pub raise_on_complete: bool,
+    /// Show the compact ring-and-time widget instead of the full window
+    #[serde(default)]
+    pub mini_mode: bool,
+    /// Show the remaining time in the window title
+    #[serde(default = "default_title_countdown")]
+    pub title_countdown: bool,
+    /// Message shown under the time in kiosk mode
+    #[serde(default)]
+    pub kiosk_message: String,
+    /// Last position of the floating timer bubble
+    #[serde(default)]
+    pub bubble_x: Option<f32>,
+    #[serde(default)]
+    pub bubble_y: Option<f32>,
 }
 
 impl Default for WindowConfig {
@@ -149,16 +658,30 @@ impl Default for WindowConfig {
             y: None,
             always_on_top: false,
             maximized: false,
+            raise_on_complete: false,
+            mini_mode: false,
+            title_countdown: true,
+            kiosk_message: String::new(),
+            bubble_x: None,
+            bubble_y: None,
         }
     }
 }
 
+fn default_title_countdown() -> bool {
+    true
+}
+
 /// Goals configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct GoalsConfig {
     pub daily_target: u32,
     pub weekly_target: u32,
     pub notify_on_goal: bool,
+    /// Weekdays, Monday first, without a daily goal and left out of the streak
+    #[serde(default)]
+    pub rest_days: [bool; 7],
 }
 
 impl Default for GoalsConfig {
@@ -167,12 +690,22 @@ impl Default for GoalsConfig {
             daily_target: 8,
             weekly_target: 40,
             notify_on_goal: true,
+            rest_days: [false; 7],
         }
     }
 }
 
+impl GoalsConfig {
+    /// Whether `date` falls on a rest day
+    pub fn is_rest_day(&self, date: chrono::NaiveDate) -> bool {
+        use chrono::Datelike;
+        self.rest_days[date.weekday().num_days_from_monday() as usize]
+    }
+}
+
 /// Hotkey configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct HotkeysConfig {
     pub enabled: bool,
     /// Toggle timer (start/pause) - default: Ctrl+Alt+Space
@@ -181,6 +714,15 @@ pub struct HotkeysConfig {
     pub skip: String,
     /// Reset timer - default: Ctrl+Alt+R
     pub reset: String,
+    /// Mute or unmute all sounds - default: Ctrl+Alt+M
+    #[serde(default = "default_mute_hotkey")]
+    pub mute: String,
+    /// Play/Pause media key toggles the timer, Next skips the session
+    #[serde(default)]
+    pub media_keys: bool,
+    /// Leave the media keys to players while media is playing
+    #[serde(default)]
+    pub media_keys_unless_playing: bool,
 }
 
 impl Default for HotkeysConfig {
@@ -190,21 +732,42 @@ impl Default for HotkeysConfig {
             toggle: "Ctrl+Alt+Space".to_string(),
             skip: "Ctrl+Alt+S".to_string(),
             reset: "Ctrl+Alt+R".to_string(),
+            mute: default_mute_hotkey(),
+            media_keys: false,
+            media_keys_unless_playing: false,
         }
     }
 }
 
+fn default_mute_hotkey() -> String {
+    "Ctrl+Alt+M".to_string()
+}
+
 /// Accessibility configuration
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct AccessibilityConfig {
     /// High contrast mode for better visibility
     pub high_contrast: bool,
     /// Reduce or disable animations
     pub reduced_motion: bool,
+    /// Reduce motion and repaint less often on battery or in battery saver
+    pub power_saver: bool,
+}
+
+impl Default for AccessibilityConfig {
+    fn default() -> Self {
+        Self {
+            high_contrast: false,
+            reduced_motion: false,
+            power_saver: true,
+        }
+    }
 }
 
 /// Todo window configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
 pub struct TodoConfig {
     pub auto_open: bool,
     pub window_width: f32,
@@ -229,86 +792,959 @@ impl Default for TodoConfig {
     }
 }
 
-/// Main configuration struct
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-pub struct Config {
-    pub timer: TimerConfig,
-    pub sounds: SoundConfig,
-    pub appearance: AppearanceConfig,
-    pub system: SystemConfig,
-    pub window: WindowConfig,
-    #[serde(default)]
-    pub goals: GoalsConfig,
-    #[serde(default)]
-    pub hotkeys: HotkeysConfig,
-    #[serde(default)]
-    pub accessibility: AccessibilityConfig,
-    #[serde(default)]
-    pub todo: TodoConfig,
+/// MQTT publishing configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MqttConfig {
+    /// Publish timer events to an MQTT broker (opt-in)
+    pub enabled: bool,
+    /// Broker host name or IP address
+    pub host: String,
+    /// Broker port
+    pub port: u16,
+    /// Client identifier sent on connect
+    pub client_id: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// Prefix for all published topics (e.g. `pomodorust/state`)
+    pub base_topic: String,
+    /// Publish the state topic as a retained message
+    pub retain: bool,
+    /// Announce the timer to Home Assistant and take commands from its buttons
+    pub discovery: bool,
+    /// Topic prefix Home Assistant watches for discovery configs
+    pub discovery_prefix: String,
 }
 
-impl Config {
-    /// Get the configuration directory path
-    pub fn config_dir() -> Option<PathBuf> {
-        ProjectDirs::from("com", "pomodorust", "PomodoRust")
-            .map(|dirs| dirs.config_dir().to_path_buf())
+impl Default for MqttConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "localhost".to_string(),
+            port: 1883,
+            client_id: "pomodorust".to_string(),
+            username: None,
+            password: None,
+            base_topic: "pomodorust".to_string(),
+            retain: true,
+            discovery: false,
+            discovery_prefix: "homeassistant".to_string(),
+        }
     }
+}
 
-    /// Get the configuration file path
-    pub fn config_path() -> Option<PathBuf> {
-        Self::config_dir().map(|dir| dir.join("config.toml"))
-    }
+/// User script hooks run on timer events
+///
+/// Each hook is a shell command line. Session details are passed in
+/// `POMODORUST_*` environment variables.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// A work session started
+    pub on_work_start: Option<String>,
+    /// A work session completed, was skipped or was reset
+    pub on_work_end: Option<String>,
+    /// A break started
+    pub on_break_start: Option<String>,
+    /// A break completed, was skipped or was reset
+    pub on_break_end: Option<String>,
+    /// The timer was paused
+    pub on_pause: Option<String>,
+    /// The timer was resumed
+    pub on_resume: Option<String>,
+    /// Runs for every event, after the specific hook
+    pub on_event: Option<String>,
+}
 
-    /// Load configuration from file or create default
-    pub fn load() -> Self {
-        let Some(path) = Self::config_path() else {
-            tracing::warn!("Could not determine config path, using defaults");
-            return Self::default();
-        };
+/// A single outgoing webhook
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct WebhookEndpoint {
+    /// URL to POST to (`http://` or `https://`)
+    pub url: String,
+    /// Event names that trigger the webhook (`started`, `completed`, ...); empty means all
+    pub events: Vec<String>,
+    /// Body template with `{{placeholder}}` substitution; empty sends the event as JSON
+    pub template: String,
+    /// Content-Type header of the request
+    pub content_type: String,
+}
 
-        if !path.exists() {
-            tracing::info!("No config file found, creating default at {:?}", path);
-            let config = Self::default();
-            let _ = config.save();
-            return config;
+impl Default for WebhookEndpoint {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            events: Vec::new(),
+            template: String::new(),
+            content_type: "application/json".to_string(),
         }
+    }
+}
 
-        match fs::read_to_string(&path) {
-            Ok(content) => match toml::from_str(&content) {
-                Ok(mut config) => {
-                    tracing::info!("Loaded config from {:?}", path);
-                    Self::validate(&mut config);
-                    config
-                }
-                Err(e) => {
-                    tracing::error!("Failed to parse config: {}, using defaults", e);
-                    Self::default()
-                }
-            },
-            Err(e) => {
-                tracing::error!("Failed to read config file: {}, using defaults", e);
-                Self::default()
-            }
+/// Outgoing webhook configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct WebhooksConfig {
+    /// Deliver timer events to the configured endpoints (opt-in)
+    pub enabled: bool,
+    /// Retries after a failed delivery, with exponential backoff
+    pub max_retries: u32,
+    pub endpoints: Vec<WebhookEndpoint>,
+}
+
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_retries: 3,
+            endpoints: Vec::new(),
         }
     }
+}
 
-    /// Save configuration to file
-    pub fn save(&self) -> Result<(), ConfigError> {
-        let dir = Self::config_dir().ok_or(ConfigError::DirectoryNotFound)?;
-
-        // Create directory if it doesn't exist
+/// Philips Hue light that shows the session type
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HueConfig {
+    /// Color a light while the timer runs (opt-in)
+    pub enabled: bool,
+    /// Bridge address on the local network
+    pub bridge_ip: String,
+    /// Application key ("username") registered with the bridge
+    pub app_key: String,
+    /// Light id, or `group:<id>` for a room or zone
+    pub target: String,
+    /// Hue (0-65535) during focus sessions, red by default
+    pub focus_hue: u16,
+    /// Hue (0-65535) during breaks, green by default
+    pub break_hue: u16,
+    /// Brightness (1-254)
+    pub brightness: u8,
+    /// Turn the light off when a session ends or is reset
+    pub off_when_idle: bool,
+}
+
+impl HueConfig {
+    /// Whether enough is configured to talk to the bridge
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.bridge_ip.trim().is_empty() && !self.app_key.trim().is_empty()
+    }
+}
+
+impl Default for HueConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bridge_ip: String::new(),
+            app_key: String::new(),
+            target: "1".to_string(),
+            focus_hue: 0,
+            break_hue: 25500,
+            brightness: 254,
+            off_when_idle: false,
+        }
+    }
+}
+
+/// What to do with a focus session that runs into a meeting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingAction {
+    /// Only say so when the session starts
+    #[default]
+    Warn,
+    /// End the session before the meeting
+    Shorten,
+    /// Pause the session when the meeting starts
+    Pause,
+}
+
+impl MeetingAction {
+    pub fn all() -> &'static [MeetingAction] {
+        &[
+            MeetingAction::Warn,
+            MeetingAction::Shorten,
+            MeetingAction::Pause,
+        ]
+    }
+
+    /// Get localized display name
+    pub fn name(&self) -> &'static str {
+        let t = crate::i18n::tr();
+        match self {
+            MeetingAction::Warn => t.settings.meeting_warn,
+            MeetingAction::Shorten => t.settings.meeting_shorten,
+            MeetingAction::Pause => t.settings.meeting_pause,
+        }
+    }
+}
+
+/// Calendar subscription checked for meetings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct CalendarConfig {
+    /// Watch a calendar for meetings (opt-in)
+    pub enabled: bool,
+    /// ICS feed (`https://` or `webcal://`)
+    pub ics_url: String,
+    /// Minutes between downloads of the feed
+    pub refresh_minutes: u32,
+    /// What to do with a focus session that would run into a meeting
+    pub on_overlap: MeetingAction,
+    /// Minutes left free before a meeting when shortening a session
+    pub margin_minutes: u32,
+}
+
+impl CalendarConfig {
+    /// Whether there's a feed to watch
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.ics_url.trim().is_empty()
+    }
+}
+
+impl Default for CalendarConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ics_url: String::new(),
+            refresh_minutes: 15,
+            on_overlap: MeetingAction::Warn,
+            margin_minutes: 2,
+        }
+    }
+}
+
+/// Completed focus sessions added to a Notion database
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct NotionConfig {
+    /// Add sessions to Notion (opt-in)
+    pub enabled: bool,
+    /// Internal integration secret
+    pub token: String,
+    /// Id of the database, from its URL
+    pub database_id: String,
+    /// Title property, filled with the task
+    pub title_property: String,
+    /// Date property, filled with the start of the session
+    pub date_property: String,
+    /// Number property, filled with the minutes; empty to leave out
+    pub duration_property: String,
+    /// Select property, filled with the tag; empty to leave out
+    pub tag_property: String,
+    /// Title of sessions without a task
+    pub default_title: String,
+    /// Minutes to wait before retrying after a failure
+    pub retry_minutes: u32,
+}
+
+impl NotionConfig {
+    /// Whether enough is configured to talk to Notion
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.token.trim().is_empty() && !self.database_id.trim().is_empty()
+    }
+}
+
+impl Default for NotionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token: String::new(),
+            database_id: String::new(),
+            title_property: "Name".to_string(),
+            date_property: "Date".to_string(),
+            duration_property: "Duration".to_string(),
+            tag_property: "Tag".to_string(),
+            default_title: "Focus session".to_string(),
+            retry_minutes: 5,
+        }
+    }
+}
+
+/// Telegram bot for session messages and timer commands
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TelegramConfig {
+    /// Run the bot (opt-in)
+    pub enabled: bool,
+    /// Bot token from @BotFather
+    pub token: String,
+    /// Chat the bot writes to and takes commands from
+    pub chat_id: String,
+    /// Send a message when a session completes
+    pub notify_complete: bool,
+}
+
+impl TelegramConfig {
+    /// Whether enough is configured to talk to the chat
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.token.trim().is_empty() && !self.chat_id.trim().is_empty()
+    }
+}
+
+impl Default for TelegramConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token: String::new(),
+            chat_id: String::new(),
+            notify_complete: true,
+        }
+    }
+}
+
+/// Part an app plays in a group session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupRole {
+    /// Run the timer the others follow
+    #[default]
+    Host,
+    /// Follow the timer of a host
+    Follow,
+}
+
+impl GroupRole {
+    pub fn all() -> &'static [GroupRole] {
+        &[GroupRole::Host, GroupRole::Follow]
+    }
+
+    /// Get localized display name
+    pub fn name(&self) -> &'static str {
+        let t = crate::i18n::tr();
+        match self {
+            GroupRole::Host => t.settings.group_host,
+            GroupRole::Follow => t.settings.group_follow,
+        }
+    }
+}
+
+/// Group session over the local network: one app hosts and the others
+/// follow its work/break cycle
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct GroupConfig {
+    /// Take part in a group session (opt-in)
+    pub enabled: bool,
+    pub role: GroupRole,
+    /// Address of the host to follow, with `:port` when it isn't `port`
+    pub host: String,
+    /// Port the host listens on
+    pub port: u16,
+    /// Name the others see
+    pub name: String,
+    /// Code members need to join, as the host listens on the network
+    pub code: String,
+}
+
+impl GroupConfig {
+    /// Whether enough is configured to host or follow
+    pub fn is_active(&self) -> bool {
+        self.enabled
+            && !self.code.trim().is_empty()
+            && (self.role == GroupRole::Host || !self.host.trim().is_empty())
+    }
+}
+
+impl Default for GroupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            role: GroupRole::Host,
+            host: String::new(),
+            port: 19849,
+            name: String::new(),
+            code: String::new(),
+        }
+    }
+}
+
+/// Weekly report emailed on Monday mornings over SMTP
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct EmailConfig {
+    /// Send the weekly report (opt-in)
+    pub enabled: bool,
+    /// SMTP server URL, `smtps://host:465` or `smtp://host:587` for STARTTLS
+    pub server: String,
+    pub username: String,
+    pub password: String,
+    /// Sender address
+    pub from: String,
+    /// Recipient addresses, comma-separated
+    pub to: String,
+    /// Local time on Monday the report goes out, "HH:MM"
+    pub send_at: String,
+}
+
+impl EmailConfig {
+    /// Whether enough is configured to send mail
+    pub fn is_active(&self) -> bool {
+        self.enabled
+            && !self.server.trim().is_empty()
+            && !self.from.trim().is_empty()
+            && !self.to.trim().is_empty()
+    }
+
+    /// Time the report goes out, 08:00 when `send_at` doesn't parse
+    pub fn send_time(&self) -> chrono::NaiveTime {
+        chrono::NaiveTime::parse_from_str(self.send_at.trim(), "%H:%M")
+            .unwrap_or_else(|_| chrono::NaiveTime::from_hms_opt(8, 0, 0).unwrap_or_default())
+    }
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            server: String::new(),
+            username: String::new(),
+            password: String::new(),
+            from: String::new(),
+            to: String::new(),
+            send_at: "08:00".to_string(),
+        }
+    }
+}
+
+/// Plugins from the `plugins` directory next to config.toml
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct PluginsConfig {
+    /// Run plugins (opt-in, they're programs of their own)
+    pub enabled: bool,
+    /// Plugins not to run, by directory name
+    pub disabled: Vec<String>,
+    /// Settings of each plugin by directory name, over the defaults of its
+    /// manifest. A plugin only gets its own table.
+    pub settings: BTreeMap<String, toml::Table>,
+}
+
+impl PluginsConfig {
+    /// Directory the plugins are installed in
+    pub fn plugins_dir() -> Option<PathBuf> {
+        Config::config_dir().map(|dir| dir.join("plugins"))
+    }
+
+    /// Whether the plugin in directory `id` should run
+    pub fn runs(&self, id: &str) -> bool {
+        self.enabled && !self.disabled.iter().any(|d| d == id)
+    }
+}
+
+/// Completed sessions sent to a local ActivityWatch server
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ActivityWatchConfig {
+    /// Send sessions to ActivityWatch (opt-in)
+    pub enabled: bool,
+    /// Address of the ActivityWatch server
+    pub server_url: String,
+    /// Bucket for the events, empty for `aw-watcher-pomodorust_<hostname>`
+    pub bucket: String,
+    /// Send breaks too, not just focus sessions
+    pub include_breaks: bool,
+}
+
+impl ActivityWatchConfig {
+    /// Whether enough is configured to reach the server
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.server_url.trim().is_empty()
+    }
+}
+
+impl Default for ActivityWatchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            server_url: "http://localhost:5600".to_string(),
+            bucket: String::new(),
+            include_breaks: false,
+        }
+    }
+}
+
+/// Completed sessions logged to the daily note of an Obsidian vault
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ObsidianConfig {
+    /// Log sessions to the daily note (opt-in)
+    pub enabled: bool,
+    /// Folder of the vault
+    pub vault_path: String,
+    /// Folder of the daily notes inside the vault, empty for its root
+    pub folder: String,
+    /// strftime format of the daily note names
+    pub date_format: String,
+    /// Line appended per session, see the placeholders in `integrations::obsidian`
+    pub template: String,
+    /// Log breaks too, not just focus sessions
+    pub log_breaks: bool,
+}
+
+impl ObsidianConfig {
+    /// Whether enough is configured to find the daily notes
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.vault_path.trim().is_empty()
+    }
+}
+
+impl Default for ObsidianConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            vault_path: String::new(),
+            folder: String::new(),
+            date_format: "%Y-%m-%d".to_string(),
+            template: "- {start}–{end} {icon} {duration} min {task} {note}".to_string(),
+            log_breaks: false,
+        }
+    }
+}
+
+/// Slack status and Do Not Disturb during focus sessions
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SlackConfig {
+    /// Show focus sessions in Slack (opt-in)
+    pub enabled: bool,
+    /// User token (`xoxp-...`) with the `users.profile:write` and `dnd:write` scopes
+    pub token: String,
+    /// Status text, `{until}` is replaced by the end time of the session
+    pub status_text: String,
+    /// Status emoji code
+    pub status_emoji: String,
+    /// Snooze Slack notifications during the session
+    pub dnd: bool,
+}
+
+impl SlackConfig {
+    /// Whether enough is configured to talk to Slack
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.token.trim().is_empty()
+    }
+}
+
+impl Default for SlackConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token: String::new(),
+            status_text: "Focusing until {until}".to_string(),
+            status_emoji: ":tomato:".to_string(),
+            dnd: true,
+        }
+    }
+}
+
+/// Taskwarrior tasks pulled into the task list
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TaskwarriorConfig {
+    /// Sync tasks with Taskwarrior (opt-in)
+    pub enabled: bool,
+    /// The `task` binary, found on PATH unless it's a full path
+    pub command: String,
+    /// Filter words picking the pending tasks to pull, e.g. `project:work`
+    pub filter: String,
+    /// Minutes between syncs
+    pub sync_minutes: u32,
+    /// Start and stop the task with the focus sessions on it
+    pub track_time: bool,
+    /// Annotate the task when a focus session on it completes
+    pub annotate: bool,
+}
+
+impl TaskwarriorConfig {
+    /// Whether enough is configured to run Taskwarrior
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.command.trim().is_empty()
+    }
+}
+
+impl Default for TaskwarriorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            command: "task".to_string(),
+            filter: String::new(),
+            sync_minutes: 5,
+            track_time: true,
+            annotate: true,
+        }
+    }
+}
+
+/// Todoist tasks pulled into the task list
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TodoistConfig {
+    /// Sync tasks with Todoist (opt-in)
+    pub enabled: bool,
+    /// API token (Settings > Integrations > Developer) or OAuth access token
+    pub token: String,
+    /// Todoist filter picking the tasks to pull, all open tasks when empty
+    pub filter: String,
+    /// Minutes between syncs
+    pub sync_minutes: u32,
+    /// Comment on a task when a focus session on it ends
+    pub comment_on_session: bool,
+    /// Close the task in Todoist once it's done in the app
+    pub close_when_done: bool,
+}
+
+impl TodoistConfig {
+    /// Whether enough is configured to talk to Todoist
+    pub fn is_active(&self) -> bool {
+        self.enabled && !self.token.trim().is_empty()
+    }
+}
+
+impl Default for TodoistConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token: String::new(),
+            filter: "today | overdue".to_string(),
+            sync_minutes: 10,
+            comment_on_session: true,
+            close_when_done: true,
+        }
+    }
+}
+
+/// A button of the Quick Start list in the stats view
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QuickStartEntry {
+    /// Text of the button, made from the length and type when empty
+    #[serde(default)]
+    pub label: String,
+    pub session_type: SessionType,
+    pub minutes: u32,
+    /// Tag recorded with the session, none when empty
+    #[serde(default)]
+    pub tag: String,
+}
+
+impl QuickStartEntry {
+    fn new(session_type: SessionType, minutes: u32) -> Self {
+        Self {
+            label: String::new(),
+            session_type,
+            minutes,
+            tag: String::new(),
+        }
+    }
+}
+
+/// One-click sessions offered in the stats view
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct QuickStartConfig {
+    pub entries: Vec<QuickStartEntry>,
+}
+
+impl Default for QuickStartConfig {
+    fn default() -> Self {
+        Self {
+            entries: vec![
+                QuickStartEntry::new(SessionType::ShortBreak, 5),
+                QuickStartEntry::new(SessionType::Work, 25),
+                QuickStartEntry::new(SessionType::Work, 50),
+            ],
+        }
+    }
+}
+
+/// Title and body of a notification, either left empty keeps the built-in text
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct NotificationText {
+    pub title: String,
+    pub body: String,
+}
+
+/// Custom text of the notifications shown when a session ends
+///
+/// `{session_type}` (the session that ended), `{next_duration}` (minutes of
+/// the one coming up) and `{today_count}` (pomodoros done today) are filled
+/// in.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct NotificationTextConfig {
+    pub focus_complete: NotificationText,
+    pub break_complete: NotificationText,
+    pub long_break_complete: NotificationText,
+    pub goal_reached: NotificationText,
+}
+
+/// Embedded HTTP API configuration
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HttpConfig {
+    /// Serve the REST API (opt-in)
+    pub enabled: bool,
+    /// Address to listen on. Anything other than loopback exposes the API to
+    /// the network and requires `token` to be set.
+    pub bind_address: String,
+    /// Port to listen on
+    pub port: u16,
+    /// Shared secret required on every API request when set, sent as
+    /// `Authorization: Bearer <token>` or a `token` query parameter
+    pub token: Option<String>,
+    /// Origins allowed to call the API from a browser (`*` allows any).
+    /// Requests from other origins are rejected.
+    pub allowed_origins: Vec<String>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind_address: "127.0.0.1".to_string(),
+            port: 19848,
+            token: None,
+            allowed_origins: Vec::new(),
+        }
+    }
+}
+
+/// A named set of durations, sounds, goals and hotkeys, e.g. "Office" or "Exam prep"
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    #[serde(default)]
+    pub timer: TimerConfig,
+    #[serde(default)]
+    pub sounds: SoundConfig,
+    #[serde(default)]
+    pub goals: GoalsConfig,
+    #[serde(default)]
+    pub hotkeys: HotkeysConfig,
+}
+
+/// Saved profiles and the one the current settings belong to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct ProfilesConfig {
+    pub active: Option<String>,
+    pub saved: Vec<Profile>,
+}
+
+/// Main configuration struct
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Config {
+    #[serde(default)]
+    pub timer: TimerConfig,
+    #[serde(default)]
+    pub sounds: SoundConfig,
+    #[serde(default)]
+    pub appearance: AppearanceConfig,
+    #[serde(default)]
+    pub system: SystemConfig,
+    #[serde(default)]
+    pub window: WindowConfig,
+    #[serde(default)]
+    pub goals: GoalsConfig,
+    #[serde(default)]
+    pub hotkeys: HotkeysConfig,
+    #[serde(default)]
+    pub accessibility: AccessibilityConfig,
+    #[serde(default)]
+    pub todo: TodoConfig,
+    #[serde(default)]
+    pub mqtt: MqttConfig,
+    #[serde(default)]
+    pub http: HttpConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    #[serde(default)]
+    pub webhooks: WebhooksConfig,
+    #[serde(default)]
+    pub idle: IdleConfig,
+    #[serde(default)]
+    pub screen_lock: ScreenLockConfig,
+    #[serde(default)]
+    pub blocker: BlockerConfig,
+    #[serde(default)]
+    pub break_overlay: BreakOverlayConfig,
+    #[serde(default)]
+    pub sleep: SleepConfig,
+    #[serde(default)]
+    pub hue: HueConfig,
+    #[serde(default)]
+    pub calendar: CalendarConfig,
+    #[serde(default)]
+    pub slack: SlackConfig,
+    #[serde(default)]
+    pub todoist: TodoistConfig,
+    #[serde(default)]
+    pub taskwarrior: TaskwarriorConfig,
+    #[serde(default)]
+    pub obsidian: ObsidianConfig,
+    #[serde(default)]
+    pub activitywatch: ActivityWatchConfig,
+    #[serde(default)]
+    pub notion: NotionConfig,
+    #[serde(default)]
+    pub telegram: TelegramConfig,
+    #[serde(default)]
+    pub email: EmailConfig,
+    #[serde(default)]
+    pub group: GroupConfig,
+    #[serde(default)]
+    pub plugins: PluginsConfig,
+    #[serde(default)]
+    pub quick_start: QuickStartConfig,
+    #[serde(default)]
+    pub notification_text: NotificationTextConfig,
+    #[serde(default)]
+    pub ambient: AmbientConfig,
+    #[serde(default)]
+    pub chimes: ChimeConfig,
+    #[serde(default)]
+    pub reminders: ReminderConfig,
+    #[serde(default)]
+    pub profiles: ProfilesConfig,
+    #[serde(default)]
+    pub weekdays: WeekdayConfig,
+    #[serde(default)]
+    pub working_hours: WorkingHoursConfig,
+    #[serde(default)]
+    pub presets: PresetsConfig,
+    /// Settings replaced by `POMODORUST_*` variables, kept out of the file
+    #[serde(skip)]
+    pub(crate) env_overrides: EnvOverrides,
+}
+
+impl Config {
+    /// Get the configuration directory path
+    pub fn config_dir() -> Option<PathBuf> {
+        ProjectDirs::from("com", "pomodorust", "PomodoRust")
+            .map(|dirs| super::instance::profile_dir(dirs.config_dir().to_path_buf()))
+    }
+
+    /// Get the configuration file path
+    pub fn config_path() -> Option<PathBuf> {
+        Self::config_dir().map(|dir| dir.join("config.toml"))
+    }
+
+    /// Load configuration from file or create default
+    pub fn load() -> Self {
+        Self::load_checked().0
+    }
+
+    /// Load configuration, with the problems found in the file and the
+    /// environment overrides
+    pub fn load_checked() -> (Self, Vec<ConfigIssue>) {
+        let (mut config, mut issues) = Self::load_file();
+        issues.extend(config.apply_env_overrides());
+        (config, issues)
+    }
+
+    fn load_file() -> (Self, Vec<ConfigIssue>) {
+        let Some(path) = Self::config_path() else {
+            tracing::warn!("Could not determine config path, using defaults");
+            return (Self::default(), Vec::new());
+        };
+        Self::load_from(&path)
+    }
+
+    /// Load the config file at `path`, falling back on its backup when it's
+    /// broken, or create it with the defaults
+    pub(super) fn load_from(path: &Path) -> (Self, Vec<ConfigIssue>) {
+        if !path.exists() {
+            tracing::info!("No config file found, creating default at {:?}", path);
+            let config = Self::default();
+            let _ = config.save_to(path);
+            return (config, Vec::new());
+        }
+
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                let (mut config, mut issues) = Self::parse_checked(&content);
+                if parse_failed(&issues) {
+                    // Keep the file before saving settings replaces it
+                    let broken = path.with_extension("toml.broken");
+                    if fs::copy(path, &broken).is_ok() {
+                        issues.push(ConfigIssue::BackedUp(broken));
+                    }
+                    // and carry on with the settings as of the save before
+                    let backup = path.with_extension("toml.bak");
+                    let restored = fs::read_to_string(&backup)
+                        .map(|content| Self::parse_checked(&content))
+                        .ok()
+                        .filter(|(_, issues)| !parse_failed(issues));
+                    if let Some((restored, _)) = restored {
+                        config = restored;
+                        issues.push(ConfigIssue::Restored(backup));
+                    }
+                }
+                if is_read_only(path) {
+                    issues.push(ConfigIssue::ReadOnly);
+                }
+                for issue in &issues {
+                    tracing::warn!("Config {:?}: {}", path, issue);
+                }
+                tracing::info!("Loaded config from {:?}", path);
+                (config, issues)
+            }
+            Err(e) => {
+                tracing::error!("Failed to read config file: {}, using defaults", e);
+                let error = ConfigError::ReadFile {
+                    path: path.to_path_buf(),
+                    source: e,
+                };
+                (Self::default(), vec![ConfigIssue::Parse(error.to_string())])
+            }
+        }
+    }
+
+    /// Save configuration to file
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let dir = Self::config_dir().ok_or(ConfigError::DirectoryNotFound)?;
+
+        // Create directory if it doesn't exist
         fs::create_dir_all(&dir).map_err(|e| ConfigError::DirectoryCreation {
             path: dir.clone(),
             source: e,
         })?;
 
-        let path = dir.join("config.toml");
-        let content = toml::to_string_pretty(self).map_err(|e| ConfigError::Serialize {
+        self.save_to(&dir.join("config.toml"))
+    }
+
+    /// Save configuration to the file at `path`, keeping the previous one as
+    /// a backup
+    pub(super) fn save_to(&self, path: &Path) -> Result<(), ConfigError> {
+        let serialize_error = |e: &dyn std::fmt::Display| ConfigError::Serialize {
             message: e.to_string(),
-        })?;
+        };
+        let mut table =
+            toml::Table::try_from(self.without_env_overrides()).map_err(|e| serialize_error(&e))?;
+        table.insert("version".to_string(), CONFIG_VERSION.into());
+        let content = toml::to_string_pretty(&table).map_err(|e| serialize_error(&e))?;
 
-        fs::write(&path, &content).map_err(|e| ConfigError::WriteFile {
-            path: path.clone(),
+        // Nothing to write, which also spares a read-only file
+        let current = fs::read_to_string(path).ok();
+        if current.as_deref() == Some(content.as_str()) {
+            return Ok(());
+        }
+        if is_read_only(path) {
+            return Err(ConfigError::ReadOnly {
+                path: path.to_path_buf(),
+            });
+        }
+
+        // Keep the file as of the last save, as long as it loads
+        if let Some(current) = current.filter(|c| !parse_failed(&Self::parse_checked(c).1)) {
+            let backup = path.with_extension("toml.bak");
+            if let Err(e) = write_synced(&backup, &current) {
+                tracing::warn!("Failed to back up config to {:?}: {}", backup, e);
+            }
+        }
+
+        write_atomic(path, &content).map_err(|e| ConfigError::WriteFile {
+            path: path.to_path_buf(),
             source: e,
         })?;
 
@@ -317,15 +1753,112 @@ impl Config {
     }
 
     /// Clamp all numeric fields to valid ranges.
-    /// Called after loading or applying settings to prevent invalid values.
-    pub fn validate(&mut self) {
-        self.timer.work_duration = self.timer.work_duration.clamp(1, 90);
-        self.timer.short_break = self.timer.short_break.clamp(1, 30);
-        self.timer.long_break = self.timer.long_break.clamp(5, 60);
-        self.timer.sessions_before_long = self.timer.sessions_before_long.clamp(2, 8);
-        self.sounds.volume = self.sounds.volume.clamp(0, 100);
-        self.appearance.window_opacity = self.appearance.window_opacity.clamp(30, 100);
-        self.goals.daily_target = self.goals.daily_target.clamp(1, 16);
+    /// Called after loading or applying settings to prevent invalid values,
+    /// returns the ones that were out of range.
+    pub fn validate(&mut self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        let timer = &mut self.timer;
+        clamp_reported(
+            &mut issues,
+            "timer.work_duration",
+            &mut timer.work_duration,
+            1,
+            90,
+        );
+        clamp_reported(
+            &mut issues,
+            "timer.short_break",
+            &mut timer.short_break,
+            1,
+            30,
+        );
+        clamp_reported(
+            &mut issues,
+            "timer.long_break",
+            &mut timer.long_break,
+            5,
+            60,
+        );
+        clamp_reported(
+            &mut issues,
+            "timer.sessions_before_long",
+            &mut timer.sessions_before_long,
+            2,
+            8,
+        );
+        clamp_reported(
+            &mut issues,
+            "timer.min_record_secs",
+            &mut timer.min_record_secs,
+            0,
+            600,
+        );
+        clamp_reported(
+            &mut issues,
+            "sounds.volume",
+            &mut self.sounds.volume,
+            0,
+            100,
+        );
+        clamp_reported(
+            &mut issues,
+            "appearance.window_opacity",
+            &mut self.appearance.window_opacity,
+            30,
+            100,
+        );
+        clamp_reported(
+            &mut issues,
+            "goals.daily_target",
+            &mut self.goals.daily_target,
+            1,
+            16,
+        );
+        for (i, day) in self.weekdays.days.iter_mut().enumerate() {
+            clamp_reported(
+                &mut issues,
+                format!("weekdays.days[{}].daily_target", i),
+                &mut day.daily_target,
+                0,
+                16,
+            );
+        }
+        clamp_reported(
+            &mut issues,
+            "calendar.refresh_minutes",
+            &mut self.calendar.refresh_minutes,
+            1,
+            1440,
+        );
+        clamp_reported(
+            &mut issues,
+            "calendar.margin_minutes",
+            &mut self.calendar.margin_minutes,
+            0,
+            30,
+        );
+        clamp_reported(
+            &mut issues,
+            "todoist.sync_minutes",
+            &mut self.todoist.sync_minutes,
+            1,
+            1440,
+        );
+        clamp_reported(
+            &mut issues,
+            "taskwarrior.sync_minutes",
+            &mut self.taskwarrior.sync_minutes,
+            1,
+            1440,
+        );
+        clamp_reported(
+            &mut issues,
+            "notion.retry_minutes",
+            &mut self.notion.retry_minutes,
+            1,
+            1440,
+        );
+        issues
     }
 
     /// Reset to defaults
@@ -344,11 +1877,377 @@ impl Config {
         )
     }
 
-    /// Apply preset to timer config
+    /// Apply preset to timer config, with the settings it switches along
     pub fn apply_preset(&mut self, preset: &crate::core::Preset) {
         self.timer.work_duration = preset.work_duration;
         self.timer.short_break = preset.short_break;
         self.timer.long_break = preset.long_break;
         self.timer.sessions_before_long = preset.sessions_before_long_break;
+
+        let behavior = &preset.behavior;
+        let switches = [
+            (
+                behavior.auto_start_breaks,
+                &mut self.timer.auto_start_breaks,
+            ),
+            (behavior.auto_start_work, &mut self.timer.auto_start_work),
+            (behavior.strict, &mut self.timer.strict),
+            (behavior.sounds_enabled, &mut self.sounds.enabled),
+            (behavior.tick_enabled, &mut self.sounds.tick_enabled),
+        ];
+        for (value, setting) in switches {
+            if let Some(value) = value {
+                *setting = value;
+            }
+        }
+    }
+
+    /// Index of the saved profile with this name, ignoring case
+    fn profile_index(&self, name: &str) -> Option<usize> {
+        let name = name.trim();
+        self.profiles
+            .saved
+            .iter()
+            .position(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Current settings as a profile called `name`
+    fn snapshot_profile(&self, name: String) -> Profile {
+        Profile {
+            name,
+            timer: self.timer.clone(),
+            sounds: self.sounds.clone(),
+            goals: self.goals.clone(),
+            hotkeys: self.hotkeys.clone(),
+        }
+    }
+
+    /// Keep the current settings in the active profile
+    fn store_active_profile(&mut self) {
+        let Some(index) = self
+            .profiles
+            .active
+            .clone()
+            .and_then(|name| self.profile_index(&name))
+        else {
+            return;
+        };
+        let name = self.profiles.saved[index].name.clone();
+        self.profiles.saved[index] = self.snapshot_profile(name);
+    }
+
+    /// Save the current settings as a profile (replacing one of the same
+    /// name) and make it the active one
+    pub fn save_profile(&mut self, name: &str) {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let profile = self.snapshot_profile(name.clone());
+        match self.profile_index(&name) {
+            Some(index) => self.profiles.saved[index] = profile,
+            None => self.profiles.saved.push(profile),
+        }
+        self.profiles.active = Some(name);
+    }
+
+    /// Switch to a saved profile. The settings of the profile being left are
+    /// kept in it first. Returns the profile's name, or `None` when there's
+    /// no profile of that name.
+    pub fn use_profile(&mut self, name: &str) -> Option<String> {
+        let index = self.profile_index(name)?;
+        self.store_active_profile();
+
+        let profile = self.profiles.saved[index].clone();
+        self.timer = profile.timer;
+        self.sounds = profile.sounds;
+        self.goals = profile.goals;
+        self.hotkeys = profile.hotkeys;
+        self.profiles.active = Some(profile.name.clone());
+        self.validate();
+        Some(profile.name)
+    }
+
+    /// Delete a saved profile, the current settings stay as they are
+    pub fn delete_profile(&mut self, name: &str) -> bool {
+        let Some(index) = self.profile_index(name) else {
+            return false;
+        };
+        let removed = self.profiles.saved.remove(index);
+        if self
+            .profiles
+            .active
+            .as_ref()
+            .is_some_and(|active| active.eq_ignore_ascii_case(&removed.name))
+        {
+            self.profiles.active = None;
+        }
+        true
+    }
+}
+
+/// Times a rename is tried while the file is held open by another program
+const RENAME_ATTEMPTS: u32 = 5;
+
+/// Whether the problems of a config file mean it couldn't be loaded at all
+fn parse_failed(issues: &[ConfigIssue]) -> bool {
+    matches!(issues.first(), Some(ConfigIssue::Parse(_)))
+}
+
+/// Whether the file at `path` exists and is marked read-only
+fn is_read_only(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.permissions().readonly())
+}
+
+/// Write `content` to a temporary file next to `path`, then rename it over
+/// `path`, so a crash leaves either the old file or the new one and never a
+/// truncated mix
+fn write_atomic(path: &Path, content: &str) -> io::Result<()> {
+    let temp = path.with_extension("toml.tmp");
+    let result = write_synced(&temp, content).and_then(|()| rename_retrying(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Write a file and wait until it's on disk. The config holds passwords and
+/// tokens, so on unix only the user may read it.
+fn write_synced(path: &Path, content: &str) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    // A file left over from before keeps its mode when opened
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(content.as_bytes())?;
+    file.sync_all()
+}
+
+/// Rename, trying again for a moment while an editor, sync client or
+/// virus scanner has the target open (which Windows doesn't allow)
+fn rename_retrying(from: &Path, to: &Path) -> io::Result<()> {
+    let mut attempts = 1;
+    loop {
+        match fs::rename(from, to) {
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied && attempts < RENAME_ATTEMPTS => {
+                attempts += 1;
+                thread::sleep(Duration::from_millis(100));
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty directory for one test, removed again by `remove_dir`
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pomodorust-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_truncated_config_is_restored_from_backup() {
+        let dir = scratch_dir("config-restore");
+        let path = dir.join("config.toml");
+        let mut config = Config::default();
+        config.timer.work_duration = 50;
+        config.save_to(&path).unwrap();
+        // The second save keeps the first as the backup
+        config.timer.work_duration = 45;
+        config.save_to(&path).unwrap();
+        assert!(!path.with_extension("toml.tmp").exists());
+
+        // Cut off mid-write
+        let content = fs::read_to_string(&path).unwrap();
+        let cut = content.find("work_duration").unwrap() + "work_duration =".len();
+        fs::write(&path, &content[..cut]).unwrap();
+
+        let (loaded, issues) = Config::load_from(&path);
+        assert_eq!(loaded.timer.work_duration, 50);
+        assert!(parse_failed(&issues));
+        assert!(issues.contains(&ConfigIssue::Restored(path.with_extension("toml.bak"))));
+        assert!(issues.contains(&ConfigIssue::BackedUp(path.with_extension("toml.broken"))));
+        assert_eq!(
+            fs::read_to_string(path.with_extension("toml.broken")).unwrap(),
+            &content[..cut]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_saved_config_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = scratch_dir("config-private");
+        let path = dir.join("config.toml");
+        let backup = path.with_extension("toml.bak");
+        fs::write(&backup, "").unwrap();
+        fs::set_permissions(&backup, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let mut config = Config::default();
+        config.save_to(&path).unwrap();
+        config.timer.work_duration = 50;
+        config.save_to(&path).unwrap();
+        for file in [&path, &backup] {
+            let mode = fs::metadata(file).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600, "{:?}", file);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_only_config_is_not_overwritten() {
+        let dir = scratch_dir("config-read-only");
+        let path = dir.join("config.toml");
+        let mut config = Config::default();
+        config.save_to(&path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+
+        let mut permissions = fs::metadata(&path).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&path, permissions.clone()).unwrap();
+
+        config.timer.work_duration = 50;
+        let result = config.save_to(&path);
+        assert!(matches!(result, Err(ConfigError::ReadOnly { .. })));
+        assert_eq!(fs::read_to_string(&path).unwrap(), content);
+        assert!(!path.with_extension("toml.bak").exists());
+        let (_, issues) = Config::load_from(&path);
+        assert!(issues.contains(&ConfigIssue::ReadOnly));
+
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        fs::set_permissions(&path, permissions).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reminder_steps_text() {
+        assert_eq!(
+            ReminderConfig::parse_steps("10, 2 5,x, 0,5"),
+            vec![2, 5, 10]
+        );
+        assert_eq!(ReminderConfig::default().steps_text(), "2, 5, 10");
+        assert!(ReminderConfig::parse_steps("").is_empty());
+    }
+
+    #[test]
+    fn test_switching_profiles_keeps_their_settings() {
+        let mut config = Config::default();
+        config.timer.work_duration = 50;
+        config.save_profile("Office");
+        config.timer.work_duration = 25;
+        config.goals.daily_target = 4;
+        config.save_profile("Home");
+
+        assert_eq!(config.use_profile("office").as_deref(), Some("Office"));
+        assert_eq!(config.timer.work_duration, 50);
+        assert_eq!(config.profiles.active.as_deref(), Some("Office"));
+
+        // Changes made while a profile is in use stay with it
+        config.timer.work_duration = 45;
+        config.use_profile("Home");
+        assert_eq!(config.timer.work_duration, 25);
+        assert_eq!(config.goals.daily_target, 4);
+        config.use_profile("Office");
+        assert_eq!(config.timer.work_duration, 45);
+
+        assert_eq!(config.use_profile("Exam prep"), None);
+        assert_eq!(config.timer.work_duration, 45);
+    }
+
+    #[test]
+    fn test_deleting_the_active_profile() {
+        let mut config = Config::default();
+        config.save_profile("Office");
+        config.save_profile(" office ");
+        assert_eq!(config.profiles.saved.len(), 1);
+
+        assert!(config.delete_profile("Office"));
+        assert!(config.profiles.active.is_none());
+        assert!(!config.delete_profile("Office"));
+    }
+
+    #[test]
+    fn test_weekday_plan() {
+        let mut weekdays = WeekdayConfig::default();
+        weekdays.days[2] = DayPlan {
+            preset: DayPreset::Long,
+            daily_target: 6,
+        };
+        assert_eq!(weekdays.plan_for(chrono::Weekday::Wed), None);
+
+        weekdays.enabled = true;
+        assert_eq!(
+            weekdays.plan_for(chrono::Weekday::Wed),
+            Some(weekdays.days[2])
+        );
+        assert_eq!(
+            weekdays.plan_for(chrono::Weekday::Sun),
+            Some(DayPlan::default())
+        );
+    }
+
+    #[test]
+    fn test_working_hours() {
+        let at = |day: u32, hour: u32| {
+            chrono::NaiveDate::from_ymd_opt(2024, 1, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        let mut hours = WorkingHoursConfig::default();
+        // Off: always working time, never late
+        assert!(hours.is_working_time(at(6, 23)));
+        assert!(!hours.is_late(at(1, 23).time()));
+
+        hours.enabled = true;
+        // 2024-01-01 is a Monday, the 6th a Saturday
+        assert!(hours.is_working_time(at(1, 9)));
+        assert!(!hours.is_working_time(at(1, 17)));
+        assert!(!hours.is_working_time(at(6, 12)));
+        assert!(hours.is_late(at(6, 23).time()));
+        assert!(!hours.is_late(at(6, 12).time()));
+
+        hours.end = "08:00".to_string();
+        assert!(hours.hours().is_none());
+    }
+
+    #[test]
+    fn test_preset_switches_its_behavior() {
+        let mut config = Config::default();
+        config.presets.long = PresetBehavior {
+            auto_start_breaks: Some(true),
+            strict: Some(true),
+            tick_enabled: Some(false),
+            ..Default::default()
+        };
+        config.sounds.tick_enabled = true;
+
+        config.apply_preset(&config.presets.preset(2).unwrap());
+        assert_eq!(config.timer.work_duration, 50);
+        assert!(config.timer.auto_start_breaks && config.timer.strict);
+        assert!(!config.sounds.tick_enabled);
+        assert!(!config.timer.auto_start_work);
+
+        // Presets without a behavior keep the settings
+        config.apply_preset(&config.presets.preset(0).unwrap());
+        assert_eq!(config.timer.work_duration, 25);
+        assert!(config.timer.strict);
     }
 }
@@ -0,0 +1,236 @@
+//! Problems found in the config file
+//!
+//! Loading never fails: unreadable files fall back to the defaults, unknown
+//! keys are ignored and out-of-range values are clamped. The problems are
+//! collected here so the app can show them and `pomodorust config check`
+//! can list them.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use super::config_migrate::migrate;
+use super::Config;
+use crate::error::ConfigError;
+
+/// Something wrong in the config file, and what was done about it
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigIssue {
+    /// The file couldn't be read, isn't valid TOML or has a value of the
+    /// wrong type; the defaults are used instead
+    Parse(String),
+    /// A key the app doesn't know, ignored
+    UnknownKey(String),
+    /// Written by a newer version of the app, whose new settings are ignored
+    NewerVersion(u32),
+    /// Copy of a file that couldn't be loaded, kept before it's overwritten
+    BackedUp(PathBuf),
+    /// The settings of the previous save, used instead of a broken file
+    Restored(PathBuf),
+    /// The file is read-only, so settings changed in the app aren't saved
+    ReadOnly,
+    /// A `POMODORUST_*` variable that couldn't be understood, ignored
+    InvalidEnv { name: String, value: String },
+    /// A number outside its range, clamped to the nearest bound
+    OutOfRange {
+        key: String,
+        value: u32,
+        min: u32,
+        max: u32,
+    },
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigIssue::Parse(message) => {
+                write!(f, "{}", message.trim_end())
+            }
+            ConfigIssue::UnknownKey(key) => write!(f, "unknown key `{}`", key),
+            ConfigIssue::NewerVersion(version) => write!(
+                f,
+                "written by a newer version of the app (layout {}), unknown settings are ignored",
+                version
+            ),
+            ConfigIssue::BackedUp(path) => write!(f, "the file was copied to {:?}", path),
+            ConfigIssue::Restored(path) => {
+                write!(f, "the settings were restored from {:?}", path)
+            }
+            ConfigIssue::ReadOnly => {
+                write!(f, "the file is read-only, changed settings won't be saved")
+            }
+            ConfigIssue::InvalidEnv { name, value } => {
+                write!(f, "invalid value {:?} in {}, ignored", value, name)
+            }
+            ConfigIssue::OutOfRange {
+                key,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "`{}` = {} is out of range ({}-{}), using {}",
+                key,
+                value,
+                min,
+                max,
+                (*value).clamp(*min, *max)
+            ),
+        }
+    }
+}
+
+/// Clamp `value` to `min..=max`, noting it when it was outside
+pub(super) fn clamp_reported(
+    issues: &mut Vec<ConfigIssue>,
+    key: impl Into<String>,
+    value: &mut u32,
+    min: u32,
+    max: u32,
+) {
+    if !(min..=max).contains(value) {
+        issues.push(ConfigIssue::OutOfRange {
+            key: key.into(),
+            value: *value,
+            min,
+            max,
+        });
+        *value = (*value).clamp(min, max);
+    }
+}
+
+impl Config {
+    /// Parse a config file, with everything that had to be fixed on the way
+    pub fn parse_checked(content: &str) -> (Self, Vec<ConfigIssue>) {
+        let parse_error = |e: &dyn fmt::Display| vec![ConfigIssue::Parse(e.to_string())];
+        let mut file = match content.parse::<toml::Table>() {
+            Ok(file) => file,
+            Err(e) => return (Self::default(), parse_error(&e)),
+        };
+        let mut issues: Vec<_> = migrate(&mut file).into_iter().collect();
+        let mut config: Config = match toml::Value::Table(file.clone()).try_into() {
+            Ok(config) => config,
+            Err(e) => return (Self::default(), parse_error(&e)),
+        };
+
+        // Keys that don't survive a round trip aren't fields of the config
+        if let Ok(known) = toml::Table::try_from(&config) {
+            unknown_keys(&file, &known, "", &mut issues);
+        }
+
+        issues.extend(config.validate());
+        (config, issues)
+    }
+
+    /// Problems in the config file on disk and in the environment
+    /// overrides, for `pomodorust config check`
+    pub fn check_file() -> Result<Vec<ConfigIssue>, ConfigError> {
+        let path = Self::config_path().ok_or(ConfigError::DirectoryNotFound)?;
+        let (mut config, mut issues) = if path.exists() {
+            let content = fs::read_to_string(&path).map_err(|e| ConfigError::ReadFile {
+                path: path.clone(),
+                source: e,
+            })?;
+            Self::parse_checked(&content)
+        } else {
+            (Self::default(), Vec::new())
+        };
+        issues.extend(config.apply_env_overrides());
+        Ok(issues)
+    }
+}
+
+/// Collect the keys of `file` that `known` doesn't have
+fn unknown_keys(
+    file: &toml::Table,
+    known: &toml::Table,
+    prefix: &str,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    for (key, value) in file {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match known.get(key) {
+            None => issues.push(ConfigIssue::UnknownKey(path)),
+            Some(known) => unknown_keys_in(value, known, &path, issues),
+        }
+    }
+}
+
+/// Descend into tables and arrays of tables
+fn unknown_keys_in(
+    file: &toml::Value,
+    known: &toml::Value,
+    path: &str,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    match (file, known) {
+        (toml::Value::Table(file), toml::Value::Table(known)) => {
+            unknown_keys(file, known, path, issues)
+        }
+        (toml::Value::Array(file), toml::Value::Array(known)) => {
+            for (i, (file, known)) in file.iter().zip(known).enumerate() {
+                unknown_keys_in(file, known, &format!("{}[{}]", path, i), issues);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_toml() -> String {
+        toml::to_string_pretty(&Config::default()).unwrap()
+    }
+
+    #[test]
+    fn test_clean_config_has_no_issues() {
+        let (config, issues) = Config::parse_checked(&default_toml());
+        assert!(issues.is_empty(), "{:?}", issues);
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn test_unknown_keys_are_reported() {
+        let content = format!("colour = \"red\"\n{}", default_toml())
+            .replace("[timer]\n", "[timer]\nwork_minutes = 30\n");
+        let (config, issues) = Config::parse_checked(&content);
+        assert_eq!(config, Config::default());
+        assert_eq!(
+            issues,
+            vec![
+                ConfigIssue::UnknownKey("colour".into()),
+                ConfigIssue::UnknownKey("timer.work_minutes".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_values_are_clamped() {
+        let content = default_toml().replace("work_duration = 25", "work_duration = 120");
+        let (config, issues) = Config::parse_checked(&content);
+        assert_eq!(config.timer.work_duration, 90);
+        assert_eq!(
+            issues,
+            vec![ConfigIssue::OutOfRange {
+                key: "timer.work_duration".into(),
+                value: 120,
+                min: 1,
+                max: 90,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_fall_back_to_defaults() {
+        let content = default_toml().replace("work_duration = 25", "work_duration = \"long\"");
+        let (config, issues) = Config::parse_checked(&content);
+        assert_eq!(config, Config::default());
+        assert!(matches!(issues.as_slice(), [ConfigIssue::Parse(_)]));
+    }
+}
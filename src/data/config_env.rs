@@ -0,0 +1,195 @@
+//! `POMODORUST_*` environment variables overriding the config file
+//!
+//! Meant for containers, daemons and CI, where editing config.toml isn't
+//! practical. The overrides are applied on top of the file at startup and
+//! stay out of it: saving from the app writes back the file's own value
+//! for any setting the environment still decides. The data directory
+//! (`POMODORUST_DATA_DIR`) and IPC port (`POMODORUST_PORT`) aren't config
+//! values and are read where they're used.
+
+use super::config_check::{clamp_reported, ConfigIssue};
+use super::{Config, ThemeMode};
+
+/// Work duration in minutes
+const WORK_DURATION: &str = "POMODORUST_WORK_DURATION";
+/// Theme, by name (`dark`, `light`, `catppuccin-mocha`, ...)
+const THEME: &str = "POMODORUST_THEME";
+/// Port of the HTTP API
+const HTTP_PORT: &str = "POMODORUST_HTTP_PORT";
+
+/// The settings taken from the environment, with the values they replaced
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnvOverrides {
+    work_duration: Option<Overridden<u32>>,
+    theme_mode: Option<Overridden<ThemeMode>>,
+    http_port: Option<Overridden<u16>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Overridden<T> {
+    file: T,
+    env: T,
+}
+
+impl<T: Copy + PartialEq> Overridden<T> {
+    fn new(file: T, env: T) -> Option<Self> {
+        (file != env).then_some(Self { file, env })
+    }
+
+    /// Put the file's value back, unless the setting was changed since
+    fn restore(this: Option<Self>, value: &mut T) {
+        if let Some(this) = this.filter(|this| *value == this.env) {
+            *value = this.file;
+        }
+    }
+}
+
+impl Config {
+    /// Apply the overrides set in the environment
+    pub fn apply_env_overrides(&mut self) -> Vec<ConfigIssue> {
+        self.apply_overrides(|name| std::env::var(name).ok())
+    }
+
+    fn apply_overrides(&mut self, var: impl Fn(&str) -> Option<String>) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        let mut invalid = |name: &str, value: &str| {
+            issues.push(ConfigIssue::InvalidEnv {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+        };
+
+        let file = (
+            self.timer.work_duration,
+            self.appearance.theme_mode,
+            self.http.port,
+        );
+        let set = |name| var(name).filter(|v| !v.trim().is_empty());
+        if let Some(value) = set(WORK_DURATION) {
+            match value.trim().parse() {
+                Ok(minutes) => self.timer.work_duration = minutes,
+                Err(_) => invalid(WORK_DURATION, &value),
+            }
+        }
+        if let Some(value) = set(THEME) {
+            match theme_named(&value) {
+                Some(theme) => self.appearance.theme_mode = theme,
+                None => invalid(THEME, &value),
+            }
+        }
+        if let Some(value) = set(HTTP_PORT) {
+            match value.trim().parse() {
+                Ok(port) => self.http.port = port,
+                Err(_) => invalid(HTTP_PORT, &value),
+            }
+        }
+
+        clamp_reported(
+            &mut issues,
+            WORK_DURATION,
+            &mut self.timer.work_duration,
+            1,
+            90,
+        );
+
+        self.env_overrides = EnvOverrides {
+            work_duration: Overridden::new(file.0, self.timer.work_duration),
+            theme_mode: Overridden::new(file.1, self.appearance.theme_mode),
+            http_port: Overridden::new(file.2, self.http.port),
+        };
+        issues
+    }
+
+    /// The config as it's written to the file, with the file's values in
+    /// place of the ones still set by the environment
+    pub(super) fn without_env_overrides(&self) -> Self {
+        let mut config = self.clone();
+        let overrides = std::mem::take(&mut config.env_overrides);
+        Overridden::restore(overrides.work_duration, &mut config.timer.work_duration);
+        Overridden::restore(overrides.theme_mode, &mut config.appearance.theme_mode);
+        Overridden::restore(overrides.http_port, &mut config.http.port);
+        config
+    }
+}
+
+/// Theme mode by name, ignoring case, dashes and underscores
+fn theme_named(name: &str) -> Option<ThemeMode> {
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let name = normalize(name);
+    ThemeMode::all()
+        .iter()
+        .copied()
+        .find(|mode| normalize(&format!("{:?}", mode)) == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn overridden(vars: &[(&str, &str)]) -> (Config, Vec<ConfigIssue>) {
+        let vars: HashMap<_, _> = vars.iter().copied().collect();
+        let mut config = Config::default();
+        let issues = config.apply_overrides(|name| vars.get(name).map(|v| v.to_string()));
+        (config, issues)
+    }
+
+    #[test]
+    fn test_overrides_replace_config_values() {
+        let (config, issues) = overridden(&[
+            (WORK_DURATION, "50"),
+            (THEME, "catppuccin-mocha"),
+            (HTTP_PORT, "8080"),
+        ]);
+        assert!(issues.is_empty(), "{:?}", issues);
+        assert_eq!(config.timer.work_duration, 50);
+        assert_eq!(config.appearance.theme_mode, ThemeMode::CatppuccinMocha);
+        assert_eq!(config.http.port, 8080);
+    }
+
+    #[test]
+    fn test_saving_keeps_the_file_values() {
+        let dir = std::env::temp_dir().join(format!("pomodorust-env-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        let mut file = Config::default();
+        file.timer.work_duration = 40;
+        file.save_to(&path).unwrap();
+
+        let (mut config, issues) = Config::load_from(&path);
+        assert!(issues.is_empty(), "{:?}", issues);
+        config.apply_overrides(|name| match name {
+            WORK_DURATION => Some("50".to_string()),
+            HTTP_PORT => Some("8080".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.timer.work_duration, 50);
+        // A setting changed in the app is saved, the overridden ones aren't
+        config.http.port = 9000;
+        config.timer.short_break = 10;
+        config.save_to(&path).unwrap();
+
+        let (saved, _) = Config::load_from(&path);
+        assert_eq!(saved.timer.work_duration, 40);
+        assert_eq!(saved.http.port, 9000);
+        assert_eq!(saved.timer.short_break, 10);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_overrides_are_reported() {
+        let (config, issues) =
+            overridden(&[(WORK_DURATION, "long"), (THEME, "neon"), (HTTP_PORT, "")]);
+        assert_eq!(config, Config::default());
+        assert_eq!(issues.len(), 2);
+
+        let (config, issues) = overridden(&[(WORK_DURATION, "500")]);
+        assert_eq!(config.timer.work_duration, 90);
+        assert_eq!(issues.len(), 1);
+    }
+}
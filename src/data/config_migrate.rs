@@ -0,0 +1,98 @@
+//! Versioning of the config file layout
+//!
+//! config.toml is stamped with the `version` of its layout. When a key is
+//! renamed or moved, it's listed in `MOVED_KEYS` with the version that moved
+//! it, and files written before that version are updated on load instead of
+//! losing the setting.
+
+use super::config_check::ConfigIssue;
+
+/// Current layout of config.toml, stamped as `version` on save
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Keys renamed or moved: version that moved it, old and new dotted path
+const MOVED_KEYS: &[(u32, &str, &str)] = &[];
+
+/// Bring a parsed config file up to the current layout, taking its
+/// `version` out. Files from a newer version are left as they are.
+pub(super) fn migrate(table: &mut toml::Table) -> Option<ConfigIssue> {
+    migrate_with(table, MOVED_KEYS)
+}
+
+fn migrate_with(table: &mut toml::Table, moves: &[(u32, &str, &str)]) -> Option<ConfigIssue> {
+    let version = table
+        .remove("version")
+        .and_then(|v| v.as_integer())
+        .and_then(|v| u32::try_from(v).ok())
+        .unwrap_or(0);
+    if version > CONFIG_VERSION {
+        return Some(ConfigIssue::NewerVersion(version));
+    }
+
+    for &(_, from, to) in moves.iter().filter(|(moved_in, ..)| *moved_in > version) {
+        if let Some(value) = take_key(table, from) {
+            tracing::info!("Config: moving `{}` to `{}`", from, to);
+            put_key(table, to, value);
+        }
+    }
+    None
+}
+
+/// Remove the value at a dotted path
+fn take_key(table: &mut toml::Table, path: &str) -> Option<toml::Value> {
+    match path.split_once('.') {
+        None => table.remove(path),
+        Some((head, rest)) => take_key(table.get_mut(head)?.as_table_mut()?, rest),
+    }
+}
+
+/// Set the value at a dotted path unless it's already there, creating the
+/// tables on the way
+fn put_key(table: &mut toml::Table, path: &str, value: toml::Value) {
+    match path.split_once('.') {
+        None => {
+            table.entry(path).or_insert(value);
+        }
+        Some((head, rest)) => {
+            let inner = table
+                .entry(head)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if let Some(inner) = inner.as_table_mut() {
+                put_key(inner, rest, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOVES: &[(u32, &str, &str)] = &[(1, "timer.focus", "timer.work_duration")];
+
+    fn table(content: &str) -> toml::Table {
+        content.parse().unwrap()
+    }
+
+    #[test]
+    fn test_old_files_are_moved_to_the_new_keys() {
+        let mut old = table("[timer]\nfocus = 50\n");
+        assert_eq!(migrate_with(&mut old, MOVES), None);
+        assert_eq!(old, table("[timer]\nwork_duration = 50\n"));
+
+        // Already written with the new layout
+        let mut current = table("version = 1\n[timer]\nfocus = 50\nwork_duration = 25\n");
+        migrate_with(&mut current, MOVES);
+        assert_eq!(current, table("[timer]\nfocus = 50\nwork_duration = 25\n"));
+    }
+
+    #[test]
+    fn test_newer_files_are_reported() {
+        let mut newer = table("version = 99\n[timer]\nfocus = 50\n");
+        assert_eq!(
+            migrate_with(&mut newer, MOVES),
+            Some(ConfigIssue::NewerVersion(99))
+        );
+        assert_eq!(newer, table("[timer]\nfocus = 50\n"));
+    }
+}
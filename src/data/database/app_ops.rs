@@ -0,0 +1,37 @@
+use chrono::NaiveDate;
+use rusqlite::params;
+
+use super::{Database, DATE_FORMAT};
+
+impl Database {
+    // Focus time per application
+
+    /// Add focus seconds spent in `app` on `date`
+    pub fn add_app_focus(&self, date: NaiveDate, app: &str, seconds: u64) -> rusqlite::Result<()> {
+        self.conn.execute(
+            r#"INSERT INTO app_focus (date, app, seconds) VALUES (?1, ?2, ?3)
+               ON CONFLICT(date, app) DO UPDATE SET seconds = seconds + excluded.seconds"#,
+            params![date.format(DATE_FORMAT).to_string(), app, seconds as i64],
+        )?;
+        Ok(())
+    }
+
+    /// Focus seconds per application since a local calendar day (all time if
+    /// `None`), largest first
+    pub fn get_app_focus_since(
+        &self,
+        since: Option<NaiveDate>,
+    ) -> rusqlite::Result<Vec<(String, i64)>> {
+        let from = since
+            .map(|day| day.format(DATE_FORMAT).to_string())
+            .unwrap_or_default();
+        let mut stmt = self.conn.prepare(
+            r#"SELECT app, SUM(seconds) AS total FROM app_focus
+               WHERE date >= ?1
+               GROUP BY app
+               ORDER BY total DESC, app ASC"#,
+        )?;
+        let rows = stmt.query_map(params![from], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect()
+    }
+}
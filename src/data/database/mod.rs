@@ -4,18 +4,33 @@
 //! - Individual session records
 //! - Daily aggregated statistics
 //! - Streak tracking
-
+//! - Pomodoros planned per task for a day
+//! - Focus time per application, when tracked
+//!
+//! Times are stored in UTC. A session counts towards the local calendar day
+//! it started on, in the timezone the computer was in at the time; that day
+//! is stored with the session (`sessions.local_date`), so moving to another
+//! timezone or a DST change later doesn't move it to another day.
+
+mod app_ops;
+mod notion_ops;
+mod plan_ops;
 mod queue_ops;
+mod report_ops;
+mod taskwarrior_ops;
 mod todo_ops;
+mod todoist_ops;
 
+pub use notion_ops::NotionRow;
+pub use plan_ops::PlannedTask;
 pub use queue_ops::TaskTimeStats;
 
-use chrono::{DateTime, Datelike, Local, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use directories::ProjectDirs;
 use rusqlite::{params, Connection, OptionalExtension, Result as SqliteResult};
 use std::path::PathBuf;
 
-use crate::core::SessionType;
+use crate::core::{Session, SessionType};
 
 /// Date format used in the database (ISO 8601 date only)
 const DATE_FORMAT: &str = "%Y-%m-%d";
@@ -26,21 +41,34 @@ const SECONDS_PER_HOUR: f32 = 3600.0;
 /// Number of days in a week
 const DAYS_IN_WEEK: usize = 7;
 
+/// Steps, and their length, tried to get past a local time skipped by a DST
+/// change (gaps are an hour at most, half an hour in some places)
+const DST_GAP_STEPS: i64 = 8;
+const DST_GAP_STEP_MINUTES: i64 = 15;
+
 /// Database connection manager
 pub struct Database {
     conn: Connection,
+    /// Weekdays, Monday first, that neither extend nor break the streak
+    rest_days: [bool; 7],
 }
 
 impl Database {
-    /// Get the database directory path
-    fn db_dir() -> Option<PathBuf> {
-        ProjectDirs::from("com", "pomodorust", "PomodoRust")
-            .map(|dirs| dirs.data_dir().to_path_buf())
+    /// Get the data directory path (database, logs), `POMODORUST_DATA_DIR`
+    /// when set, of the profile in use
+    pub fn data_dir() -> Option<PathBuf> {
+        let dir = match std::env::var_os("POMODORUST_DATA_DIR").filter(|d| !d.is_empty()) {
+            Some(dir) => PathBuf::from(dir),
+            None => ProjectDirs::from("com", "pomodorust", "PomodoRust")?
+                .data_dir()
+                .to_path_buf(),
+        };
+        Some(super::instance::profile_dir(dir))
     }
 
     /// Get the database file path
     fn db_path() -> Option<PathBuf> {
-        Self::db_dir().map(|dir| dir.join("pomodorust.db"))
+        Self::data_dir().map(|dir| dir.join("pomodorust.db"))
     }
 
     /// Open or create the database with performance optimizations
@@ -67,7 +95,10 @@ impl Database {
             "#,
         )?;
 
-        let db = Self { conn };
+        let db = Self {
+            conn,
+            rest_days: [false; 7],
+        };
         db.initialize()?;
         Ok(db)
     }
@@ -75,7 +106,10 @@ impl Database {
     /// Open an in-memory database (for testing)
     pub fn open_in_memory() -> SqliteResult<Self> {
         let conn = Connection::open_in_memory()?;
-        let db = Self { conn };
+        let db = Self {
+            conn,
+            rest_days: [false; 7],
+        };
         db.initialize()?;
         Ok(db)
     }
@@ -177,6 +211,59 @@ impl Database {
                 FOREIGN KEY (todo_id) REFERENCES todo_items(id) ON DELETE CASCADE
             );
 
+            -- Todo: Tasks imported from Todoist
+            CREATE TABLE IF NOT EXISTS todoist_tasks (
+                todo_id INTEGER PRIMARY KEY,
+                todoist_id TEXT NOT NULL UNIQUE,
+                closed INTEGER NOT NULL DEFAULT 0,
+                FOREIGN KEY (todo_id) REFERENCES todo_items(id) ON DELETE CASCADE
+            );
+
+            -- Sessions waiting to be added to Notion
+            CREATE TABLE IF NOT EXISTS notion_outbox (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                started_at TEXT NOT NULL,
+                minutes INTEGER NOT NULL,
+                task TEXT,
+                tag TEXT
+            );
+
+            -- Todo: Tasks imported from Taskwarrior
+            CREATE TABLE IF NOT EXISTS taskwarrior_tasks (
+                todo_id INTEGER PRIMARY KEY,
+                uuid TEXT NOT NULL UNIQUE,
+                FOREIGN KEY (todo_id) REFERENCES todo_items(id) ON DELETE CASCADE
+            );
+
+            -- Weeks whose report was emailed, by their Monday
+            CREATE TABLE IF NOT EXISTS weekly_reports (
+                week TEXT PRIMARY KEY,
+                sent_at TEXT NOT NULL
+            );
+
+            -- Pomodoros planned per task for a day
+            CREATE TABLE IF NOT EXISTS day_plans (
+                date TEXT NOT NULL,
+                todo_id INTEGER NOT NULL,
+                planned_pomodoros INTEGER NOT NULL,
+                PRIMARY KEY (date, todo_id),
+                FOREIGN KEY (todo_id) REFERENCES todo_items(id) ON DELETE CASCADE
+            );
+
+            -- Days whose planned tasks were put in the queue
+            CREATE TABLE IF NOT EXISTS day_plans_queued (
+                date TEXT PRIMARY KEY,
+                queued_at TEXT NOT NULL
+            );
+
+            -- Focus seconds per application and day
+            CREATE TABLE IF NOT EXISTS app_focus (
+                date TEXT NOT NULL,
+                app TEXT NOT NULL,
+                seconds INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (date, app)
+            );
+
             -- Todo indexes
             CREATE INDEX IF NOT EXISTS idx_todo_workspace ON todo_items(workspace_id);
             CREATE INDEX IF NOT EXISTS idx_todo_project ON todo_items(project_id);
@@ -202,6 +289,9 @@ impl Database {
         // Migrations for existing databases
         self.migrate_sessions_todo_id()?;
         self.migrate_todo_priority()?;
+        self.migrate_sessions_tag()?;
+        self.migrate_sessions_intent()?;
+        self.migrate_sessions_local_date()?;
 
         Ok(())
     }
@@ -236,7 +326,74 @@ impl Database {
         Ok(())
     }
 
+    /// Add tag column to sessions table (migration for existing databases)
+    fn migrate_sessions_tag(&self) -> SqliteResult<()> {
+        let has_column: bool = self
+            .conn
+            .prepare("SELECT tag FROM sessions LIMIT 0")
+            .is_ok();
+        if !has_column {
+            self.conn
+                .execute_batch("ALTER TABLE sessions ADD COLUMN tag TEXT;")?;
+            tracing::info!("Migrated sessions table: added tag column");
+        }
+        Ok(())
+    }
+
+    /// Add intent column to sessions table (migration for existing databases)
+    fn migrate_sessions_intent(&self) -> SqliteResult<()> {
+        let has_column: bool = self
+            .conn
+            .prepare("SELECT intent FROM sessions LIMIT 0")
+            .is_ok();
+        if !has_column {
+            self.conn
+                .execute_batch("ALTER TABLE sessions ADD COLUMN intent TEXT;")?;
+            tracing::info!("Migrated sessions table: added intent column");
+        }
+        Ok(())
+    }
+
+    /// Add local_date column to sessions table (migration for existing
+    /// databases), filled in with the day each session started on in the
+    /// current timezone, the best guess there is for older sessions
+    fn migrate_sessions_local_date(&self) -> SqliteResult<()> {
+        let has_column: bool = self
+            .conn
+            .prepare("SELECT local_date FROM sessions LIMIT 0")
+            .is_ok();
+        if has_column {
+            return Ok(());
+        }
+        // One transaction, so the column is never there without the dates
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute_batch("ALTER TABLE sessions ADD COLUMN local_date TEXT;")?;
+
+        let sessions = tx
+            .prepare("SELECT id, started_at FROM sessions")?
+            .query_map([], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<SqliteResult<Vec<_>>>()?;
+        for (id, started_at) in &sessions {
+            let Ok(started_at) = DateTime::parse_from_rfc3339(started_at) else {
+                continue;
+            };
+            tx.execute(
+                "UPDATE sessions SET local_date = ?1 WHERE id = ?2",
+                params![local_date(&started_at.with_timezone(&Utc)), id],
+            )?;
+        }
+        tx.commit()?;
+        tracing::info!(
+            "Migrated sessions table: added local_date column, filled in for {} sessions",
+            sessions.len()
+        );
+        Ok(())
+    }
+
     /// Record a completed session
+    #[allow(clippy::too_many_arguments)]
     pub fn record_session(
         &self,
         session_type: SessionType,
@@ -245,9 +402,11 @@ impl Database {
         completed: bool,
         started_at: DateTime<Utc>,
         todo_id: Option<i64>,
+        tag: Option<&str>,
+        intent: Option<&str>,
     ) -> SqliteResult<()> {
         let ended_at = Utc::now();
-        let today = Self::today_string();
+        let date = local_date(&started_at);
 
         // Insert session record
         self.insert_session_record(
@@ -258,22 +417,58 @@ impl Database {
             &started_at,
             &ended_at,
             todo_id,
+            tag,
+            intent,
+            &date,
         )?;
 
         // Ensure daily stats row exists
-        self.ensure_daily_stats(&today)?;
+        self.ensure_daily_stats(&date)?;
 
         // Update daily stats based on session type
-        self.update_daily_stats(session_type, duration_secs, completed, &today)?;
+        self.update_daily_stats(session_type, duration_secs, completed, &date)?;
 
         // Update streak if completed work session
         if session_type == SessionType::Work && completed {
-            self.update_streak()?;
+            self.update_streak(local_day(&started_at))?;
         }
 
         Ok(())
     }
 
+    /// Record the focus time done so far in an unfinished work session, e.g.
+    /// one cut short by quitting, as an interrupted session on the current
+    /// queue task. Returns the seconds recorded, `None` when there is no
+    /// unfinished focus session or it is shorter than `min_secs`.
+    pub fn record_unfinished_session(
+        &self,
+        session: &Session,
+        started_at: DateTime<Utc>,
+        min_secs: u64,
+        tag: Option<&str>,
+        intent: Option<&str>,
+    ) -> SqliteResult<Option<u64>> {
+        let Some(elapsed) = session.unfinished_focus().map(|d| d.as_secs()) else {
+            return Ok(None);
+        };
+        if elapsed == 0 || elapsed < min_secs {
+            return Ok(None);
+        }
+
+        let todo_id = self.get_current_queue_task()?.map(|t| t.todo_id);
+        self.record_session(
+            SessionType::Work,
+            elapsed,
+            session.timer().total_duration().as_secs(),
+            false,
+            started_at,
+            todo_id,
+            tag,
+            intent,
+        )?;
+        Ok(Some(elapsed))
+    }
+
     /// Insert a session record into the sessions table
     #[allow(clippy::too_many_arguments)]
     fn insert_session_record(
@@ -285,11 +480,14 @@ impl Database {
         started_at: &DateTime<Utc>,
         ended_at: &DateTime<Utc>,
         todo_id: Option<i64>,
+        tag: Option<&str>,
+        intent: Option<&str>,
+        date: &str,
     ) -> SqliteResult<()> {
         self.conn.execute(
             r#"
-            INSERT INTO sessions (session_type, duration_seconds, planned_duration, completed, started_at, ended_at, todo_id)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            INSERT INTO sessions (session_type, duration_seconds, planned_duration, completed, started_at, ended_at, todo_id, tag, intent, local_date)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
             "#,
             params![
                 session_type.as_str(),
@@ -299,6 +497,9 @@ impl Database {
                 started_at.to_rfc3339(),
                 ended_at.to_rfc3339(),
                 todo_id,
+                tag,
+                intent,
+                date,
             ],
         )?;
         Ok(())
@@ -368,41 +569,36 @@ impl Database {
         Local::now().format(DATE_FORMAT).to_string()
     }
 
-    /// Get yesterday's date as a formatted string
-    fn yesterday_string() -> String {
-        (Local::now() - chrono::Duration::days(1))
-            .format(DATE_FORMAT)
-            .to_string()
+    /// Set the weekdays (Monday first) left out of the streak
+    pub fn set_rest_days(&mut self, rest_days: [bool; 7]) {
+        self.rest_days = rest_days;
     }
 
-    /// Update streak tracking
-    fn update_streak(&self) -> SqliteResult<()> {
-        let today = Self::today_string();
-        let yesterday = Self::yesterday_string();
-
+    /// Update streak tracking for a work session on `today`
+    fn update_streak(&self, today: NaiveDate) -> SqliteResult<()> {
         // Get current streak info
         let (current_streak, last_date): (i32, Option<String>) = self.conn.query_row(
             "SELECT current_streak, last_active_date FROM streaks WHERE id = 1",
             [],
             |row| Ok((row.get(0)?, row.get(1)?)),
         )?;
+        let last_date = last_date.and_then(|d| NaiveDate::parse_from_str(&d, DATE_FORMAT).ok());
 
-        let new_streak = match last_date.as_deref() {
-            Some(last) if last == today => current_streak, // Already counted today
-            Some(last) if last == yesterday => current_streak + 1, // Continuing streak
-            _ => 1,                                        // New streak
+        let Some(new_streak) = next_streak(current_streak, last_date, today, &self.rest_days)
+        else {
+            return Ok(());
         };
 
         self.conn.execute(
             r#"
             UPDATE streaks
-            SET current_streak = MAX(current_streak, ?1),
+            SET current_streak = ?1,
                 longest_streak = MAX(longest_streak, ?1),
                 last_active_date = ?2,
                 updated_at = CURRENT_TIMESTAMP
             WHERE id = 1
             "#,
-            params![new_streak, today],
+            params![new_streak, today.format(DATE_FORMAT).to_string()],
         )?;
 
         Ok(())
@@ -471,6 +667,117 @@ impl Database {
         Ok(result)
     }
 
+    /// Get daily work hours for the calendar month containing `reference_date`
+    pub fn get_month_stats_for_date(&self, reference_date: NaiveDate) -> SqliteResult<Vec<f32>> {
+        let start_of_month = reference_date.with_day(1).unwrap_or(reference_date);
+        let start_of_next = start_of_month
+            .checked_add_months(chrono::Months::new(1))
+            .unwrap_or(start_of_month);
+        let days_in_month = (start_of_next - start_of_month).num_days() as usize;
+
+        let mut result = vec![0.0f32; days_in_month];
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT date, total_work_seconds
+            FROM daily_stats
+            WHERE date >= ?1 AND date < ?2
+            ORDER BY date
+            "#,
+        )?;
+
+        let rows = stmt.query_map(
+            params![
+                start_of_month.format(DATE_FORMAT).to_string(),
+                start_of_next.format(DATE_FORMAT).to_string()
+            ],
+            |row| {
+                let date_str: String = row.get(0)?;
+                let seconds: i64 = row.get(1)?;
+                Ok((date_str, seconds))
+            },
+        )?;
+
+        for (date_str, seconds) in rows.flatten() {
+            if let Ok(date) = NaiveDate::parse_from_str(&date_str, DATE_FORMAT) {
+                let day_index = (date - start_of_month).num_days() as usize;
+                if day_index < days_in_month {
+                    result[day_index] = seconds as f32 / SECONDS_PER_HOUR;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Get statistics for an inclusive date range (total work seconds, completed pomodoros)
+    pub fn get_range_stats(&self, from: NaiveDate, to: NaiveDate) -> SqliteResult<(i64, i32)> {
+        self.conn
+            .query_row(
+                r#"
+            SELECT COALESCE(SUM(total_work_seconds), 0), COALESCE(SUM(completed_pomodoros), 0)
+            FROM daily_stats
+            WHERE date >= ?1 AND date <= ?2
+            "#,
+                params![
+                    from.format(DATE_FORMAT).to_string(),
+                    to.format(DATE_FORMAT).to_string()
+                ],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .or(Ok((0, 0)))
+    }
+
+    /// Focus statistics of the work sessions started between two local times
+    /// of a day (total work seconds, completed pomodoros)
+    pub fn get_stats_within(
+        &self,
+        date: NaiveDate,
+        from: NaiveTime,
+        to: NaiveTime,
+    ) -> SqliteResult<(i64, i32)> {
+        self.conn
+            .query_row(
+                r#"
+            SELECT COALESCE(SUM(duration_seconds), 0), COALESCE(SUM(completed), 0)
+            FROM sessions
+            WHERE session_type = ?1 AND started_at >= ?2 AND started_at < ?3
+            "#,
+                params![
+                    SessionType::Work.as_str(),
+                    local_time_utc(date.and_time(from)),
+                    local_time_utc(date.and_time(to))
+                ],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .or(Ok((0, 0)))
+    }
+
+    /// Get focus statistics for an inclusive date range
+    /// (total work seconds, completed pomodoros, interrupted pomodoros)
+    pub fn get_range_summary(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> SqliteResult<(i64, i32, i32)> {
+        self.conn
+            .query_row(
+                r#"
+            SELECT COALESCE(SUM(total_work_seconds), 0),
+                   COALESCE(SUM(completed_pomodoros), 0),
+                   COALESCE(SUM(interrupted_pomodoros), 0)
+            FROM daily_stats
+            WHERE date >= ?1 AND date <= ?2
+            "#,
+                params![
+                    from.format(DATE_FORMAT).to_string(),
+                    to.format(DATE_FORMAT).to_string()
+                ],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .or(Ok((0, 0, 0)))
+    }
+
     /// Get the earliest date with recorded stats (for navigation bounds)
     pub fn get_earliest_stats_date(&self) -> SqliteResult<Option<NaiveDate>> {
         self.conn
@@ -507,28 +814,106 @@ impl Database {
     pub fn get_all_sessions(&self) -> SqliteResult<Vec<super::export::SessionRecord>> {
         let mut stmt = self.conn.prepare(
             r#"
-            SELECT id, session_type, duration_seconds, planned_duration, completed, started_at, ended_at, todo_id
+            SELECT id, session_type, duration_seconds, planned_duration, completed, started_at, ended_at, todo_id, tag, intent
             FROM sessions
             ORDER BY started_at DESC
             "#,
         )?;
 
-        let rows = stmt.query_map([], |row| {
-            Ok(super::export::SessionRecord {
-                id: row.get(0)?,
-                session_type: row.get(1)?,
-                duration_seconds: row.get(2)?,
-                planned_duration: row.get(3)?,
-                completed: row.get::<_, i32>(4)? != 0,
-                started_at: row.get(5)?,
-                ended_at: row.get::<_, Option<String>>(6)?.unwrap_or_default(),
-                todo_id: row.get(7)?,
-            })
+        let rows = stmt.query_map([], Self::row_to_session_record)?;
+        rows.collect()
+    }
+
+    /// Get the sessions started on a local calendar day, latest first
+    pub fn get_sessions_for_day(
+        &self,
+        date: NaiveDate,
+    ) -> SqliteResult<Vec<super::export::SessionRecord>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, session_type, duration_seconds, planned_duration, completed, started_at, ended_at, todo_id, tag, intent
+            FROM sessions
+            WHERE local_date = ?1
+            ORDER BY started_at DESC
+            "#,
+        )?;
+
+        let rows = stmt.query_map(
+            params![date.format(DATE_FORMAT).to_string()],
+            Self::row_to_session_record,
+        )?;
+        rows.collect()
+    }
+
+    /// Focus seconds per tag since a local calendar day (all time if `None`),
+    /// largest first. Untagged focus time is listed without a tag.
+    pub fn get_tag_focus_since(
+        &self,
+        since: Option<NaiveDate>,
+    ) -> SqliteResult<Vec<(Option<String>, i64)>> {
+        let from = since
+            .map(|day| day.format(DATE_FORMAT).to_string())
+            .unwrap_or_default();
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT NULLIF(tag, ''), SUM(duration_seconds)
+            FROM sessions
+            WHERE session_type = ?1 AND local_date >= ?2
+            GROUP BY NULLIF(tag, '')
+            ORDER BY SUM(duration_seconds) DESC
+            "#,
+        )?;
+
+        let rows = stmt.query_map(params![SessionType::Work.as_str(), from], |row| {
+            Ok((row.get(0)?, row.get(1)?))
         })?;
+        rows.collect()
+    }
+
+    /// Focus seconds per tag over an inclusive range of local calendar
+    /// days, largest first. Untagged focus time is listed without a tag.
+    pub fn get_tag_focus_between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> SqliteResult<Vec<(Option<String>, i64)>> {
+        let (from, to) = (
+            from.format(DATE_FORMAT).to_string(),
+            to.format(DATE_FORMAT).to_string(),
+        );
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT NULLIF(tag, ''), SUM(duration_seconds)
+            FROM sessions
+            WHERE session_type = ?1 AND local_date >= ?2 AND local_date <= ?3
+            GROUP BY NULLIF(tag, '')
+            ORDER BY SUM(duration_seconds) DESC
+            "#,
+        )?;
 
+        let rows = stmt.query_map(params![SessionType::Work.as_str(), from, to], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })?;
         rows.collect()
     }
 
+    fn row_to_session_record(row: &rusqlite::Row) -> SqliteResult<super::export::SessionRecord> {
+        Ok(super::export::SessionRecord {
+            id: row.get(0)?,
+            session_type: row.get(1)?,
+            duration_seconds: row.get(2)?,
+            planned_duration: row.get(3)?,
+            completed: row.get::<_, i32>(4)? != 0,
+            started_at: row.get(5)?,
+            ended_at: row.get::<_, Option<String>>(6)?.unwrap_or_default(),
+            todo_id: row.get(7)?,
+            tag: row.get(8)?,
+            intent: row.get(9)?,
+        })
+    }
+
     /// Get all daily statistics for export
     pub fn get_all_daily_stats(&self) -> SqliteResult<Vec<super::export::DailyStatsRecord>> {
         let mut stmt = self.conn.prepare(
@@ -559,7 +944,7 @@ impl Database {
         self.conn
             .query_row(
                 r#"
-                SELECT id, session_type, duration_seconds, completed, started_at
+                SELECT id, session_type, duration_seconds, completed, started_at, local_date
                 FROM sessions
                 WHERE session_type = 'work'
                 ORDER BY id DESC
@@ -573,6 +958,7 @@ impl Database {
                         duration_seconds: row.get(2)?,
                         completed: row.get::<_, i32>(3)? != 0,
                         started_at: row.get(4)?,
+                        local_date: row.get(5)?,
                     })
                 },
             )
@@ -585,13 +971,23 @@ impl Database {
         let last_session = self.get_last_work_session()?;
 
         if let Some(ref session) = last_session {
-            // Parse date from started_at to update correct daily_stats
-            let date = session
-                .started_at
-                .split('T')
-                .next()
-                .unwrap_or(&Self::today_string())
-                .to_string();
+            // The daily_stats row the session was counted in
+            let date = session.local_date.clone().unwrap_or_else(|| {
+                DateTime::parse_from_rfc3339(&session.started_at)
+                    .map(|t| local_date(&t.with_timezone(&Utc)))
+                    .unwrap_or_else(|_| Self::today_string())
+            });
+
+            // Keep the rows as they are now, so the undo can be taken back
+            self.stash_for_undo()?;
+            self.conn.execute(
+                "INSERT INTO undo_sessions SELECT * FROM sessions WHERE id = ?1",
+                params![session.id],
+            )?;
+            self.conn.execute(
+                "INSERT INTO undo_daily_stats SELECT * FROM daily_stats WHERE date = ?1",
+                params![date],
+            )?;
 
             // Update daily stats
             if session.completed {
@@ -626,12 +1022,19 @@ impl Database {
         Ok(last_session)
     }
 
-    /// Reset all statistics (delete all sessions, daily stats, and reset streaks)
+    /// Reset all statistics (delete all sessions, daily stats and focus time per
+    /// application, and reset streaks)
     pub fn reset_all_stats(&self) -> SqliteResult<()> {
+        self.stash_for_undo()?;
         self.conn.execute_batch(
             r#"
+            INSERT INTO undo_sessions SELECT * FROM sessions;
+            INSERT INTO undo_daily_stats SELECT * FROM daily_stats;
+            INSERT INTO undo_streaks SELECT * FROM streaks;
+            INSERT INTO undo_app_focus SELECT * FROM app_focus;
             DELETE FROM sessions;
             DELETE FROM daily_stats;
+            DELETE FROM app_focus;
             UPDATE streaks SET current_streak = 0, longest_streak = 0, last_active_date = NULL WHERE id = 1;
             "#,
         )?;
@@ -639,6 +1042,59 @@ impl Database {
         tracing::info!("All statistics have been reset");
         Ok(())
     }
+
+    /// Copies of the rows changed by the last destructive action. They are
+    /// TEMP tables, so whatever they hold is gone once the app closes.
+    fn create_undo_tables(&self) -> SqliteResult<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TEMP TABLE IF NOT EXISTS undo_sessions AS SELECT * FROM sessions WHERE 0;
+            CREATE TEMP TABLE IF NOT EXISTS undo_daily_stats AS SELECT * FROM daily_stats WHERE 0;
+            CREATE TEMP TABLE IF NOT EXISTS undo_streaks AS SELECT * FROM streaks WHERE 0;
+            CREATE TEMP TABLE IF NOT EXISTS undo_app_focus AS SELECT * FROM app_focus WHERE 0;
+            "#,
+        )
+    }
+
+    /// Empty the undo tables before a new action fills them
+    fn stash_for_undo(&self) -> SqliteResult<()> {
+        self.create_undo_tables()?;
+        self.conn.execute_batch(
+            r#"
+            DELETE FROM undo_sessions;
+            DELETE FROM undo_daily_stats;
+            DELETE FROM undo_streaks;
+            DELETE FROM undo_app_focus;
+            "#,
+        )
+    }
+
+    /// Put back the rows changed by the last `reset_all_stats` or
+    /// `undo_last_session`
+    pub fn restore_deleted(&self) -> SqliteResult<()> {
+        self.create_undo_tables()?;
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute_batch(
+            r#"
+            UPDATE undo_sessions SET todo_id = NULL
+                WHERE todo_id IS NOT NULL AND todo_id NOT IN (SELECT id FROM todo_items);
+            INSERT OR REPLACE INTO sessions SELECT * FROM undo_sessions;
+            INSERT OR REPLACE INTO daily_stats SELECT * FROM undo_daily_stats;
+            INSERT OR REPLACE INTO streaks SELECT * FROM undo_streaks;
+            INSERT OR REPLACE INTO app_focus SELECT * FROM undo_app_focus;
+            "#,
+        )?;
+        tx.commit()?;
+        self.stash_for_undo()?;
+
+        tracing::info!("Restored deleted statistics");
+        Ok(())
+    }
+
+    /// Drop the rows kept for `restore_deleted`
+    pub fn discard_deleted(&self) -> SqliteResult<()> {
+        self.stash_for_undo()
+    }
 }
 
 /// Information about the last session (for undo functionality)
@@ -649,4 +1105,423 @@ pub struct LastSession {
     pub duration_seconds: i64,
     pub completed: bool,
     pub started_at: String,
+    /// Local calendar day the session counts towards
+    pub local_date: Option<String>,
+}
+
+/// Streak after working on `today`, given the streak and the last day that
+/// counted. Rest days in between don't break it, and working on one leaves
+/// it as it is (`None`).
+fn next_streak(
+    current: i32,
+    last: Option<NaiveDate>,
+    today: NaiveDate,
+    rest_days: &[bool; 7],
+) -> Option<i32> {
+    let is_rest = |day: NaiveDate| rest_days[day.weekday().num_days_from_monday() as usize];
+    if is_rest(today) {
+        return None;
+    }
+    let Some(last) = last.filter(|&last| last <= today) else {
+        return Some(1);
+    };
+    if last == today {
+        return Some(current);
+    }
+    let continues = last
+        .iter_days()
+        .skip(1)
+        .take_while(|&day| day < today)
+        .all(is_rest);
+    Some(if continues { current + 1 } else { 1 })
+}
+
+/// Local calendar day of a moment, in the timezone the computer is in
+fn local_day(at: &DateTime<Utc>) -> NaiveDate {
+    at.with_timezone(&Local).date_naive()
+}
+
+/// Local calendar day of a moment as stored in `local_date`
+fn local_date(at: &DateTime<Utc>) -> String {
+    local_day(at).format(DATE_FORMAT).to_string()
+}
+
+/// A local time as stored in `started_at` (UTC, RFC 3339). A time skipped
+/// by a DST change, such as midnight where clocks go forward at midnight,
+/// stands for the first time after the change, and a time that happens
+/// twice for the first of the two.
+fn local_time_utc(at: NaiveDateTime) -> String {
+    (0..=DST_GAP_STEPS)
+        .find_map(|step| {
+            (at + chrono::Duration::minutes(step * DST_GAP_STEP_MINUTES))
+                .and_local_timezone(Local)
+                .earliest()
+        })
+        .map(|t| t.with_timezone(&Utc).to_rfc3339())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::todo::Priority;
+
+    #[test]
+    fn test_next_streak_skips_rest_days() {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+        let weekends = [false, false, false, false, false, true, true];
+
+        // 2024-01-05 is a Friday, the 8th a Monday
+        assert_eq!(next_streak(3, Some(day(5)), day(8), &[false; 7]), Some(1));
+        assert_eq!(next_streak(3, Some(day(5)), day(8), &weekends), Some(4));
+        assert_eq!(next_streak(3, Some(day(4)), day(8), &weekends), Some(1));
+        assert_eq!(next_streak(3, Some(day(8)), day(8), &weekends), Some(3));
+        assert_eq!(next_streak(3, Some(day(5)), day(6), &weekends), None);
+        assert_eq!(next_streak(0, None, day(8), &weekends), Some(1));
+    }
+
+    #[test]
+    fn test_quitting_records_the_focus_time_done() {
+        use crate::core::{ManualClock, Preset};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let db = Database::open_in_memory().unwrap();
+        let clock = Arc::new(ManualClock::new());
+        let mut session = Session::with_clock(Preset::custom("Test", 25, 5, 15, 4), clock.clone());
+        let started_at = Utc::now();
+        session.start();
+        clock.advance(Duration::from_secs(20 * 60));
+        session.update();
+
+        // Quitting 20 minutes in keeps them as an interrupted session
+        let recorded = db
+            .record_unfinished_session(&session, started_at, 60, Some("deep"), None)
+            .unwrap();
+        assert_eq!(recorded, Some(20 * 60));
+        let sessions = db.get_all_sessions().unwrap();
+        assert_eq!(sessions.len(), 1);
+        let quit = &sessions[0];
+        assert_eq!(quit.session_type, "work");
+        assert_eq!(quit.duration_seconds, 20 * 60);
+        assert_eq!(quit.planned_duration, 25 * 60);
+        assert!(!quit.completed);
+        assert_eq!(quit.tag.as_deref(), Some("deep"));
+
+        // Below the minimum, or on a break, nothing is recorded
+        assert_eq!(
+            db.record_unfinished_session(&session, started_at, 30 * 60, None, None)
+                .unwrap(),
+            None
+        );
+        session.skip();
+        session.start();
+        clock.advance(Duration::from_secs(60));
+        session.update();
+        assert_eq!(
+            db.record_unfinished_session(&session, started_at, 0, None, None)
+                .unwrap(),
+            None
+        );
+        assert_eq!(db.get_all_sessions().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_sessions_count_towards_the_day_they_started() {
+        use chrono::TimeZone;
+
+        let db = Database::open_in_memory().unwrap();
+        // Saturday 23:50, running past midnight
+        let saturday = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+        let started_at = Local
+            .from_local_datetime(&saturday.and_hms_opt(23, 50, 0).unwrap())
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc);
+        db.record_session(
+            SessionType::Work,
+            1800,
+            1800,
+            true,
+            started_at,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let week = db.get_week_stats_for_date(saturday).unwrap();
+        assert_eq!(week[5], 0.5);
+        assert_eq!(week[6], 0.0);
+        assert_eq!(db.get_sessions_for_day(saturday).unwrap().len(), 1);
+        assert!(db
+            .get_sessions_for_day(saturday.succ_opt().unwrap())
+            .unwrap()
+            .is_empty());
+
+        // Sessions recorded before the column existed get their day filled in
+        db.conn
+            .execute_batch("ALTER TABLE sessions DROP COLUMN local_date")
+            .unwrap();
+        db.migrate_sessions_local_date().unwrap();
+        assert_eq!(db.get_sessions_for_day(saturday).unwrap().len(), 1);
+        // Once the column is there, the sessions aren't looked at again
+        db.conn
+            .execute("UPDATE sessions SET local_date = NULL", [])
+            .unwrap();
+        db.migrate_sessions_local_date().unwrap();
+        assert!(db.get_sessions_for_day(saturday).unwrap().is_empty());
+        db.conn
+            .execute(
+                "UPDATE sessions SET local_date = ?1",
+                [local_date(&started_at)],
+            )
+            .unwrap();
+
+        db.undo_last_session().unwrap();
+        assert_eq!(db.get_week_stats_for_date(saturday).unwrap()[5], 0.0);
+    }
+
+    #[test]
+    fn test_todoist_tasks_are_imported_once() {
+        let db = Database::open_in_memory().unwrap();
+        let workspace = db.get_workspaces().unwrap()[0].id;
+        let import = || db.import_todoist_task(workspace, "6X7", "Report", "", Priority::High);
+
+        let id = import().unwrap().unwrap();
+        assert_eq!(import().unwrap(), None);
+        assert_eq!(db.todoist_task_id(id).unwrap().as_deref(), Some("6X7"));
+        assert!(db.todoist_tasks_to_close().unwrap().is_empty());
+
+        db.toggle_todo(id).unwrap();
+        assert_eq!(
+            db.todoist_tasks_to_close().unwrap(),
+            vec![(id, "6X7".to_string())]
+        );
+        db.set_todoist_closed(id).unwrap();
+        assert!(db.todoist_tasks_to_close().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_taskwarrior_tasks_are_imported_once() {
+        let db = Database::open_in_memory().unwrap();
+        let workspace = db.get_workspaces().unwrap()[0].id;
+        let import = || db.import_taskwarrior_task(workspace, "a1b2", "Report", Priority::Low);
+
+        let id = import().unwrap().unwrap();
+        assert_eq!(import().unwrap(), None);
+        assert_eq!(db.taskwarrior_uuid(id).unwrap().as_deref(), Some("a1b2"));
+
+        db.delete_todo(id).unwrap();
+        assert_eq!(db.taskwarrior_uuid(id).unwrap(), None);
+    }
+
+    #[test]
+    fn test_notion_outbox() {
+        let db = Database::open_in_memory().unwrap();
+        let start = Local::now();
+        db.queue_notion_row(&start, 25, Some("Report"), None)
+            .unwrap();
+        db.queue_notion_row(&start, 50, None, Some("writing"))
+            .unwrap();
+
+        let rows = db.notion_rows(1).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].minutes, 25);
+        assert_eq!(rows[0].task.as_deref(), Some("Report"));
+
+        db.remove_notion_rows(&[rows[0].id]).unwrap();
+        let rows = db.notion_rows(10).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].tag.as_deref(), Some("writing"));
+    }
+
+    #[test]
+    fn test_weekly_report_sent_once() {
+        let db = Database::open_in_memory().unwrap();
+        let monday = NaiveDate::from_ymd_opt(2024, 3, 4).unwrap();
+        assert!(!db.weekly_report_sent(monday).unwrap());
+
+        db.mark_weekly_report_sent(monday).unwrap();
+        db.mark_weekly_report_sent(monday).unwrap();
+        assert!(db.weekly_report_sent(monday).unwrap());
+        assert!(!db
+            .weekly_report_sent(monday + chrono::Duration::weeks(1))
+            .unwrap());
+    }
+
+    #[test]
+    fn test_day_plan_is_queued_once() {
+        let db = Database::open_in_memory().unwrap();
+        let workspace = db.get_workspaces().unwrap()[0].id;
+        let report = db.create_todo(workspace, None, "Report").unwrap();
+        let day = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+        db.set_planned_pomodoros(day, report, 2).unwrap();
+
+        assert_eq!(db.queue_day_plan(day).unwrap(), 1);
+        assert_eq!(db.get_queue().unwrap().len(), 1);
+        // Done with it and taken off the queue, as after a restart
+        db.clear_queue().unwrap();
+        assert_eq!(db.queue_day_plan(day).unwrap(), 0);
+        assert!(db.get_queue().unwrap().is_empty());
+
+        // A day without a plan is only looked at once too
+        let next = day.succ_opt().unwrap();
+        assert_eq!(db.queue_day_plan(next).unwrap(), 0);
+        db.set_planned_pomodoros(next, report, 1).unwrap();
+        assert_eq!(db.queue_day_plan(next).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_day_plan_counts_sessions_on_planned_tasks() {
+        use chrono::TimeZone;
+
+        let db = Database::open_in_memory().unwrap();
+        let workspace = db.get_workspaces().unwrap()[0].id;
+        let report = db.create_todo(workspace, None, "Report").unwrap();
+        let slides = db.create_todo(workspace, None, "Slides").unwrap();
+        let day = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+
+        db.set_planned_pomodoros(day, report, 2).unwrap();
+        db.set_planned_pomodoros(day, slides, 1).unwrap();
+        db.set_planned_pomodoros(day, slides, 3).unwrap();
+
+        let noon = Local
+            .from_local_datetime(&day.and_hms_opt(12, 0, 0).unwrap())
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc);
+        for completed in [true, true, true, false] {
+            db.record_session(
+                SessionType::Work,
+                1500,
+                1500,
+                completed,
+                noon,
+                Some(report),
+                None,
+                None,
+            )
+            .unwrap();
+        }
+
+        let plan = db.get_day_plan(day).unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(
+            (plan[0].title.as_str(), plan[0].planned, plan[0].done),
+            ("Report", 2, 3)
+        );
+        assert_eq!(
+            (plan[1].title.as_str(), plan[1].planned, plan[1].done),
+            ("Slides", 3, 0)
+        );
+        assert!(db.get_day_plan(day.succ_opt().unwrap()).unwrap().is_empty());
+
+        // The extra pomodoro on the report doesn't count for the slides
+        assert_eq!(db.get_plan_adherence(day, day).unwrap(), (1, 5, 2));
+
+        db.set_planned_pomodoros(day, slides, 0).unwrap();
+        assert_eq!(db.get_plan_adherence(day, day).unwrap(), (1, 2, 2));
+    }
+
+    #[test]
+    fn test_app_focus_adds_up_per_day() {
+        let db = Database::open_in_memory().unwrap();
+        let monday = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap();
+        let tuesday = monday.succ_opt().unwrap();
+        db.add_app_focus(monday, "Code", 600).unwrap();
+        db.add_app_focus(monday, "Firefox", 300).unwrap();
+        db.add_app_focus(tuesday, "Code", 60).unwrap();
+
+        assert_eq!(
+            db.get_app_focus_since(None).unwrap(),
+            vec![("Code".to_string(), 660), ("Firefox".to_string(), 300)]
+        );
+        assert_eq!(
+            db.get_app_focus_since(Some(tuesday)).unwrap(),
+            vec![("Code".to_string(), 60)]
+        );
+
+        db.reset_all_stats().unwrap();
+        assert!(db.get_app_focus_since(None).unwrap().is_empty());
+        db.restore_deleted().unwrap();
+        assert_eq!(db.get_app_focus_since(None).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_range_stats_across_month_and_year_ends() {
+        use chrono::TimeZone;
+
+        let db = Database::open_in_memory().unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let days = [
+            date(2023, 12, 31),
+            date(2024, 1, 1),
+            date(2024, 1, 31),
+            date(2024, 2, 1),
+            date(2024, 2, 29),
+            date(2024, 3, 1),
+        ];
+        // One pomodoro a day, of a different length so the sums tell them apart
+        for (i, day) in days.iter().enumerate() {
+            let noon = Local
+                .from_local_datetime(&day.and_hms_opt(12, 0, 0).unwrap())
+                .earliest()
+                .unwrap()
+                .with_timezone(&Utc);
+            let seconds = 60 << i;
+            db.record_session(
+                SessionType::Work,
+                seconds,
+                seconds,
+                true,
+                noon,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        }
+
+        // Both ends of a range count
+        assert_eq!(
+            db.get_range_stats(date(2024, 1, 1), date(2024, 1, 31))
+                .unwrap(),
+            (120 + 240, 2)
+        );
+        assert_eq!(
+            db.get_range_stats(date(2024, 2, 1), date(2024, 2, 29))
+                .unwrap(),
+            (480 + 960, 2)
+        );
+        assert_eq!(
+            db.get_range_stats(date(2024, 2, 29), date(2024, 2, 29))
+                .unwrap(),
+            (960, 1)
+        );
+
+        // The year starts on the first of January
+        assert_eq!(
+            db.get_range_stats(date(2024, 1, 1), date(2024, 12, 31))
+                .unwrap(),
+            (120 + 240 + 480 + 960 + 1920, 5)
+        );
+        assert_eq!(
+            db.get_range_stats(date(2023, 12, 31), date(2024, 1, 1))
+                .unwrap(),
+            (60 + 120, 2)
+        );
+
+        // Nothing outside the range, and nothing in an empty one
+        assert_eq!(
+            db.get_range_stats(date(2024, 3, 2), date(2024, 12, 31))
+                .unwrap(),
+            (0, 0)
+        );
+        assert_eq!(
+            db.get_range_stats(date(2024, 2, 2), date(2024, 2, 28))
+                .unwrap(),
+            (0, 0)
+        );
+    }
 }
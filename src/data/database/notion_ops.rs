@@ -0,0 +1,78 @@
+use chrono::{DateTime, Local};
+use rusqlite::params;
+
+use super::Database;
+
+/// A completed session waiting to be added to the Notion database
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotionRow {
+    pub id: i64,
+    pub started_at: DateTime<Local>,
+    pub minutes: u64,
+    pub task: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl Database {
+    // Notion outbox
+
+    /// Keep a session until it's in Notion
+    pub fn queue_notion_row(
+        &self,
+        started_at: &DateTime<Local>,
+        minutes: u64,
+        task: Option<&str>,
+        tag: Option<&str>,
+    ) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT INTO notion_outbox (started_at, minutes, task, tag) VALUES (?1, ?2, ?3, ?4)",
+            params![started_at.to_rfc3339(), minutes as i64, task, tag],
+        )?;
+        Ok(())
+    }
+
+    /// Oldest sessions not yet in Notion
+    pub fn notion_rows(&self, limit: usize) -> rusqlite::Result<Vec<NotionRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, started_at, minutes, task, tag FROM notion_outbox ORDER BY id LIMIT ?1",
+        )?;
+        let rows = stmt.query_map(params![limit as i64], |row| {
+            let started_at: String = row.get(1)?;
+            Ok((
+                row.get(0)?,
+                started_at,
+                row.get::<_, i64>(2)?,
+                row.get(3)?,
+                row.get(4)?,
+            ))
+        })?;
+
+        let mut result = Vec::new();
+        for row in rows {
+            let (id, started_at, minutes, task, tag) = row?;
+            // A row that can't be read would block the ones behind it
+            let Ok(started_at) = DateTime::parse_from_rfc3339(&started_at) else {
+                tracing::warn!("Dropping unreadable Notion row {}", id);
+                self.remove_notion_rows(&[id])?;
+                continue;
+            };
+            result.push(NotionRow {
+                id,
+                started_at: started_at.with_timezone(&Local),
+                minutes: minutes.max(0) as u64,
+                task,
+                tag,
+            });
+        }
+        Ok(result)
+    }
+
+    /// Forget the sessions that made it to Notion
+    pub fn remove_notion_rows(&self, ids: &[i64]) -> rusqlite::Result<()> {
+        for id in ids {
+            self.conn
+                .execute("DELETE FROM notion_outbox WHERE id = ?1", params![id])?;
+        }
+        Ok(())
+    }
+}
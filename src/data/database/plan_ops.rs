@@ -0,0 +1,113 @@
+use chrono::{NaiveDate, Utc};
+use rusqlite::params;
+
+use super::{Database, DATE_FORMAT};
+
+/// A task planned for a day, with the pomodoros done on it that day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedTask {
+    pub todo_id: i64,
+    pub title: String,
+    pub completed: bool,
+    pub planned: u32,
+    pub done: u32,
+}
+
+impl Database {
+    // Day plans
+
+    /// Plan `planned` pomodoros on a task for `date`, 0 to take it off the plan
+    pub fn set_planned_pomodoros(
+        &self,
+        date: NaiveDate,
+        todo_id: i64,
+        planned: u32,
+    ) -> rusqlite::Result<()> {
+        let date = date.format(DATE_FORMAT).to_string();
+        if planned == 0 {
+            self.conn.execute(
+                "DELETE FROM day_plans WHERE date = ?1 AND todo_id = ?2",
+                params![date, todo_id],
+            )?;
+        } else {
+            self.conn.execute(
+                r#"INSERT INTO day_plans (date, todo_id, planned_pomodoros) VALUES (?1, ?2, ?3)
+                   ON CONFLICT(date, todo_id) DO UPDATE SET planned_pomodoros = excluded.planned_pomodoros"#,
+                params![date, todo_id, planned],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Tasks planned for `date`, in the order they were planned, with the
+    /// pomodoros completed on each that day
+    pub fn get_day_plan(&self, date: NaiveDate) -> rusqlite::Result<Vec<PlannedTask>> {
+        let mut stmt = self.conn.prepare(
+            r#"SELECT p.todo_id, t.title, t.completed, p.planned_pomodoros,
+                      (SELECT COUNT(*) FROM sessions s
+                       WHERE s.todo_id = p.todo_id AND s.local_date = p.date
+                         AND s.session_type = 'work' AND s.completed = 1)
+               FROM day_plans p JOIN todo_items t ON p.todo_id = t.id
+               WHERE p.date = ?1
+               ORDER BY p.rowid ASC"#,
+        )?;
+        let rows = stmt.query_map(params![date.format(DATE_FORMAT).to_string()], |row| {
+            Ok(PlannedTask {
+                todo_id: row.get(0)?,
+                title: row.get(1)?,
+                completed: row.get(2)?,
+                planned: row.get::<_, u32>(3)?,
+                done: row.get::<_, u32>(4)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    /// Put the unfinished tasks planned for `date` in the queue, unless that
+    /// was done already for the day, returning how many tasks are planned.
+    /// Remembered in the database, so restarting the app on the day doesn't
+    /// bring back tasks taken off the queue since.
+    pub fn queue_day_plan(&self, date: NaiveDate) -> rusqlite::Result<usize> {
+        let tx = self.conn.unchecked_transaction()?;
+        let first = tx.execute(
+            "INSERT OR IGNORE INTO day_plans_queued (date, queued_at) VALUES (?1, ?2)",
+            params![
+                date.format(DATE_FORMAT).to_string(),
+                Utc::now().to_rfc3339()
+            ],
+        )? > 0;
+        if !first {
+            return Ok(0);
+        }
+        let plan = self.get_day_plan(date)?;
+        for task in plan.iter().filter(|task| !task.completed) {
+            self.add_to_queue(task.todo_id, task.planned)?;
+        }
+        tx.commit()?;
+        Ok(plan.len())
+    }
+
+    /// Plan adherence between two days (inclusive): the days with a plan,
+    /// the pomodoros planned and how many of them were done. Pomodoros
+    /// beyond a task's plan don't make up for another task's.
+    pub fn get_plan_adherence(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> rusqlite::Result<(i32, i32, i32)> {
+        self.conn.query_row(
+            r#"SELECT COUNT(DISTINCT date), COALESCE(SUM(planned), 0), COALESCE(SUM(MIN(done, planned)), 0)
+               FROM (SELECT p.date, p.planned_pomodoros AS planned,
+                            (SELECT COUNT(*) FROM sessions s
+                             WHERE s.todo_id = p.todo_id AND s.local_date = p.date
+                               AND s.session_type = 'work' AND s.completed = 1) AS done
+                     FROM day_plans p
+                     WHERE p.date >= ?1 AND p.date <= ?2)"#,
+            params![
+                from.format(DATE_FORMAT).to_string(),
+                to.format(DATE_FORMAT).to_string()
+            ],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+    }
+}
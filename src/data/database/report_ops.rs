@@ -0,0 +1,32 @@
+use chrono::{NaiveDate, Utc};
+use rusqlite::{params, OptionalExtension};
+
+use super::{Database, DATE_FORMAT};
+
+impl Database {
+    // Weekly report emails
+
+    /// Whether the report of the week starting on `monday` was sent
+    pub fn weekly_report_sent(&self, monday: NaiveDate) -> rusqlite::Result<bool> {
+        self.conn
+            .query_row(
+                "SELECT 1 FROM weekly_reports WHERE week = ?1",
+                params![monday.format(DATE_FORMAT).to_string()],
+                |_| Ok(()),
+            )
+            .optional()
+            .map(|row| row.is_some())
+    }
+
+    /// Remember that the report of the week starting on `monday` was sent
+    pub fn mark_weekly_report_sent(&self, monday: NaiveDate) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO weekly_reports (week, sent_at) VALUES (?1, ?2)",
+            params![
+                monday.format(DATE_FORMAT).to_string(),
+                Utc::now().to_rfc3339()
+            ],
+        )?;
+        Ok(())
+    }
+}
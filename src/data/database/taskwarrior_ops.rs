@@ -0,0 +1,47 @@
+use rusqlite::{params, OptionalExtension};
+
+use crate::data::todo::Priority;
+
+use super::Database;
+
+impl Database {
+    // Taskwarrior links
+
+    /// Add a Taskwarrior task to the task list, unless it was imported
+    /// before. Returns the id of the new todo.
+    pub fn import_taskwarrior_task(
+        &self,
+        workspace_id: i64,
+        uuid: &str,
+        title: &str,
+        priority: Priority,
+    ) -> rusqlite::Result<Option<i64>> {
+        let known: bool = self.conn.query_row(
+            "SELECT COUNT(*) > 0 FROM taskwarrior_tasks WHERE uuid = ?1",
+            params![uuid],
+            |row| row.get(0),
+        )?;
+        if known || title.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let id = self.create_todo_with_body(workspace_id, None, title.trim(), "")?;
+        self.set_todo_priority(id, priority)?;
+        self.conn.execute(
+            "INSERT INTO taskwarrior_tasks (todo_id, uuid) VALUES (?1, ?2)",
+            params![id, uuid],
+        )?;
+        Ok(Some(id))
+    }
+
+    /// Taskwarrior task a todo was imported from
+    pub fn taskwarrior_uuid(&self, todo_id: i64) -> rusqlite::Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT uuid FROM taskwarrior_tasks WHERE todo_id = ?1",
+                params![todo_id],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+}
@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use rusqlite::params;
+use rusqlite::{params, OptionalExtension};
 
 use crate::data::todo::{Priority, Project, TodoItem, Workspace};
 
@@ -223,6 +223,17 @@ impl Database {
         rows.collect()
     }
 
+    pub fn get_todo(&self, id: i64) -> rusqlite::Result<Option<TodoItem>> {
+        self.conn
+            .query_row(
+                r#"SELECT id, project_id, workspace_id, title, body, completed, collapsed, priority, position, created_at, completed_at
+                   FROM todo_items WHERE id = ?1"#,
+                params![id],
+                Self::row_to_todo,
+            )
+            .optional()
+    }
+
     pub fn get_todos_by_project(&self, project_id: i64) -> rusqlite::Result<Vec<TodoItem>> {
         let mut stmt = self.conn.prepare(
             r#"SELECT id, project_id, workspace_id, title, body, completed, collapsed, priority, position, created_at, completed_at
@@ -0,0 +1,67 @@
+use rusqlite::{params, OptionalExtension};
+
+use crate::data::todo::Priority;
+
+use super::Database;
+
+impl Database {
+    // Todoist links
+
+    /// Add a Todoist task to the task list, unless it was imported before.
+    /// Returns the id of the new todo.
+    pub fn import_todoist_task(
+        &self,
+        workspace_id: i64,
+        todoist_id: &str,
+        title: &str,
+        body: &str,
+        priority: Priority,
+    ) -> rusqlite::Result<Option<i64>> {
+        let known: bool = self.conn.query_row(
+            "SELECT COUNT(*) > 0 FROM todoist_tasks WHERE todoist_id = ?1",
+            params![todoist_id],
+            |row| row.get(0),
+        )?;
+        if known || title.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let id = self.create_todo_with_body(workspace_id, None, title.trim(), body.trim())?;
+        self.set_todo_priority(id, priority)?;
+        self.conn.execute(
+            "INSERT INTO todoist_tasks (todo_id, todoist_id) VALUES (?1, ?2)",
+            params![id, todoist_id],
+        )?;
+        Ok(Some(id))
+    }
+
+    /// Todoist task a todo was imported from
+    pub fn todoist_task_id(&self, todo_id: i64) -> rusqlite::Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT todoist_id FROM todoist_tasks WHERE todo_id = ?1",
+                params![todo_id],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    /// Imported todos done in the app whose Todoist task is still open
+    pub fn todoist_tasks_to_close(&self) -> rusqlite::Result<Vec<(i64, String)>> {
+        let mut stmt = self.conn.prepare(
+            r#"SELECT l.todo_id, l.todoist_id
+               FROM todoist_tasks l JOIN todo_items t ON l.todo_id = t.id
+               WHERE t.completed = 1 AND l.closed = 0"#,
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect()
+    }
+
+    pub fn set_todoist_closed(&self, todo_id: i64) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "UPDATE todoist_tasks SET closed = 1 WHERE todo_id = ?1",
+            params![todo_id],
+        )?;
+        Ok(())
+    }
+}
@@ -44,6 +44,9 @@ pub struct SessionRecord {
     pub started_at: String,
     pub ended_at: String,
     pub todo_id: Option<i64>,
+    pub tag: Option<String>,
+    /// What the work session was for, when asked up front
+    pub intent: Option<String>,
 }
 
 /// Daily statistics record for export
@@ -93,7 +96,7 @@ impl Exporter {
     }
 
     /// Gather all export data from the database
-    fn gather_data(db: &Database) -> Result<ExportData, ExportError> {
+    pub fn gather_data(db: &Database) -> Result<ExportData, ExportError> {
         let sessions = db.get_all_sessions().map_err(ExportError::Database)?;
         let daily_stats = db.get_all_daily_stats().map_err(ExportError::Database)?;
         let (current_streak, longest_streak) = db.get_streak().unwrap_or((0, 0));
@@ -187,12 +190,20 @@ impl Exporter {
         // Sessions section
         content.push_str("# Sessions\n");
         content.push_str(
-            "ID,Type,Duration (s),Planned Duration (s),Completed,Started At,Ended At,Todo ID\n",
+            "ID,Type,Duration (s),Planned Duration (s),Completed,Started At,Ended At,Todo ID,Tag,Intent\n",
         );
         for session in &data.sessions {
             let todo_id_str = session.todo_id.map(|id| id.to_string()).unwrap_or_default();
+            // Tags and intents are free text, so quote them
+            let quote = |text: &Option<String>| {
+                text.as_ref()
+                    .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+                    .unwrap_or_default()
+            };
+            let tag_str = quote(&session.tag);
+            let intent_str = quote(&session.intent);
             content.push_str(&format!(
-                "{},{},{},{},{},{},{},{}\n",
+                "{},{},{},{},{},{},{},{},{},{}\n",
                 session.id,
                 session.session_type,
                 session.duration_seconds,
@@ -200,7 +211,9 @@ impl Exporter {
                 session.completed,
                 session.started_at,
                 session.ended_at,
-                todo_id_str
+                todo_id_str,
+                tag_str,
+                intent_str
             ));
         }
 
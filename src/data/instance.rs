@@ -0,0 +1,74 @@
+//! Separate instances of the app, started with `--profile <name>`
+//!
+//! A named profile keeps its own config, database, logs and IPC port, in a
+//! `profiles/<name>` directory under the usual ones, so personal and client
+//! work never mix. Each profile runs its own app, and the CLI talks to the
+//! app of the profile it's given. Not to be confused with the settings
+//! profiles of [`ProfilesConfig`](super::ProfilesConfig), which switch
+//! settings within one instance.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Profile used when `--profile` isn't given
+const PROFILE_VAR: &str = "POMODORUST_PROFILE";
+
+/// Longest profile name accepted
+const MAX_NAME_LEN: usize = 64;
+
+static PROFILE: OnceLock<Option<String>> = OnceLock::new();
+
+/// Whether `name` can name a profile: letters, digits, `-` and `_`, as it
+/// becomes a directory name
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Use the profile `name` for the rest of the run. Must come before
+/// anything reads the config or the database; returns `false` for a name
+/// that can't be used or when a profile was already chosen.
+pub fn set_profile(name: &str) -> bool {
+    is_valid_profile_name(name) && PROFILE.set(Some(name.to_string())).is_ok()
+}
+
+/// The profile in use, `POMODORUST_PROFILE` unless one was set; `None` for
+/// the default one
+pub fn profile() -> Option<&'static str> {
+    PROFILE
+        .get_or_init(|| {
+            let name = std::env::var(PROFILE_VAR).ok()?;
+            if is_valid_profile_name(&name) {
+                Some(name)
+            } else {
+                tracing::warn!("Ignoring invalid {}: {:?}", PROFILE_VAR, name);
+                None
+            }
+        })
+        .as_deref()
+}
+
+/// `dir` for the profile in use
+pub(crate) fn profile_dir(dir: PathBuf) -> PathBuf {
+    match profile() {
+        Some(name) => dir.join("profiles").join(name),
+        None => dir,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_names() {
+        assert!(is_valid_profile_name("client-acme_2"));
+        assert!(!is_valid_profile_name(""));
+        assert!(!is_valid_profile_name("../work"));
+        assert!(!is_valid_profile_name("a b"));
+        assert!(!is_valid_profile_name(&"x".repeat(MAX_NAME_LEN + 1)));
+    }
+}
@@ -5,6 +5,7 @@
 //! - [`Config`]: Application configuration stored in TOML format
 //! - [`Database`]: SQLite database for session history and statistics
 //! - [`Statistics`]: Aggregated statistics loaded from the database
+//! - [`WeeklyReport`]: A week's statistics as an HTML report
 //!
 //! ## Storage Locations
 //!
@@ -14,6 +15,9 @@
 //! - **macOS**: `~/Library/Application Support/com.pomodorust.PomodoRust/`
 //! - **Linux**: `~/.config/pomodorust/`
 //!
+//! A profile started with `--profile <name>` keeps its own in a
+//! `profiles/<name>` directory under these, see [`instance`].
+//!
 //! ## Database Schema
 //!
 //! The SQLite database contains three tables:
@@ -22,14 +26,31 @@
 //! - `daily_stats`: Aggregated daily statistics
 //! - `streaks`: Current and longest streak tracking
 
+mod appearance;
 mod config;
+mod config_check;
+mod config_env;
+mod config_migrate;
 mod database;
 pub mod export;
+pub mod instance;
+mod report;
 mod statistics;
 pub mod todo;
 
-pub use config::{Config, GoalsConfig, NotificationSound, TodoConfig};
-pub use database::{Database, LastSession, TaskTimeStats};
-pub use export::{ExportFormat, Exporter};
+pub use appearance::{AccentColor, ThemeMode};
+pub use config::{
+    ActivityWatchConfig, AmbientConfig, AmbientSound, BlockerConfig, CalendarConfig, ChimeConfig,
+    Config, DayPlan, DayPreset, EmailConfig, GoalsConfig, GroupConfig, GroupRole, HooksConfig,
+    HttpConfig, HueConfig, IdleConfig, MeetingAction, MqttConfig, NotificationSound,
+    NotificationTextConfig, NotionConfig, ObsidianConfig, PluginsConfig, PresetsConfig, Profile,
+    ProfilesConfig, QuickStartEntry, ReminderConfig, SlackConfig, SleepPolicy, TaskwarriorConfig,
+    TelegramConfig, ThemeScheduleConfig, TodoConfig, TodoistConfig, TrayIconStyle, WebhookEndpoint,
+    WebhooksConfig, WeekdayConfig, WorkingHoursConfig,
+};
+pub use config_check::ConfigIssue;
+pub use database::{Database, LastSession, NotionRow, PlannedTask, TaskTimeStats};
+pub use export::{ExportData, ExportError, ExportFormat, Exporter, SessionRecord};
+pub use report::WeeklyReport;
 pub use statistics::Statistics;
 pub use todo::{Priority, Project, QueuedTask, TodoItem, Workspace};
@@ -0,0 +1,192 @@
+//! Weekly report
+//!
+//! Focus totals of a week against the week before, hours per day and
+//! focus per tag, rendered as a self-contained HTML page for email.
+
+use std::fmt::Write;
+
+use chrono::{Duration, NaiveDate};
+
+use super::statistics::PeriodSummary;
+use super::Database;
+
+/// Longest bar of the per-day chart, in pixels
+const BAR_WIDTH: f32 = 240.0;
+
+/// Color of the bars, the tomato red of the app icon
+const BAR_COLOR: &str = "#e5533d";
+
+/// Focus statistics of one week
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyReport {
+    /// First day of the week
+    pub monday: NaiveDate,
+    pub week: PeriodSummary,
+    pub previous_week: PeriodSummary,
+    /// Focus hours per day, Monday first
+    pub daily_hours: Vec<f32>,
+    /// Focus seconds per tag, largest first
+    pub tags: Vec<(Option<String>, i64)>,
+}
+
+impl WeeklyReport {
+    /// Load the report of the week starting on `monday`
+    pub fn load(db: &Database, monday: NaiveDate) -> Self {
+        let sunday = monday + Duration::days(6);
+        let week = Duration::weeks(1);
+        Self {
+            monday,
+            week: PeriodSummary::load(db, monday, sunday),
+            previous_week: PeriodSummary::load(db, monday - week, sunday - week),
+            daily_hours: db
+                .get_week_stats_for_date(monday)
+                .unwrap_or_else(|_| vec![0.0; 7]),
+            tags: db.get_tag_focus_between(monday, sunday).unwrap_or_default(),
+        }
+    }
+
+    /// Title of the report, with the dates it covers
+    pub fn title(&self) -> String {
+        format!(
+            "{} {} – {}",
+            crate::i18n::tr().notif.weekly_report,
+            self.monday.format("%Y-%m-%d"),
+            (self.monday + Duration::days(6)).format("%Y-%m-%d")
+        )
+    }
+
+    /// The report as an HTML page, styled inline as mail clients expect
+    pub fn to_html(&self) -> String {
+        let t = crate::i18n::tr();
+        let rate = |summary: &PeriodSummary| {
+            summary
+                .completion_rate()
+                .map(|rate| format!("{:.0}%", rate * 100.0))
+                .unwrap_or_else(|| "–".to_string())
+        };
+
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{title}</title></head>\n\
+             <body style=\"font-family:sans-serif;color:#222\">\n<h2>{title}</h2>\n",
+            title = escape(&self.title())
+        );
+
+        html.push_str("<table cellpadding=\"6\" style=\"border-collapse:collapse\">\n");
+        let _ = writeln!(
+            html,
+            "<tr><th></th><th align=\"right\">{}</th><th align=\"right\">{}</th></tr>",
+            escape(t.stats.this_week),
+            escape(t.stats.last_week)
+        );
+        let rows = [
+            (
+                t.stats.compare_focus,
+                hours(self.week.hours()),
+                hours(self.previous_week.hours()),
+            ),
+            (
+                t.stats.compare_pomodoros,
+                self.week.pomodoros.to_string(),
+                self.previous_week.pomodoros.to_string(),
+            ),
+            (
+                t.stats.interrupted,
+                self.week.interrupted.to_string(),
+                self.previous_week.interrupted.to_string(),
+            ),
+            (
+                t.stats.completion_rate,
+                rate(&self.week),
+                rate(&self.previous_week),
+            ),
+        ];
+        for (label, this, last) in rows {
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td align=\"right\"><b>{}</b></td><td align=\"right\">{}</td></tr>",
+                escape(label),
+                this,
+                last
+            );
+        }
+        html.push_str("</table>\n");
+
+        let _ = writeln!(html, "<h3>{}</h3>", escape(t.stats.week_activity));
+        html.push_str("<table cellpadding=\"4\">\n");
+        let most = self.daily_hours.iter().copied().fold(0.0f32, f32::max);
+        for (day, hours_on_day) in t.days_of_week().iter().zip(&self.daily_hours) {
+            let width = if most > 0.0 {
+                (hours_on_day / most * BAR_WIDTH).round()
+            } else {
+                0.0
+            };
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td><div style=\"background:{};width:{}px;height:12px\"></div></td>\
+                 <td align=\"right\">{}</td></tr>",
+                escape(day),
+                BAR_COLOR,
+                width,
+                hours(*hours_on_day)
+            );
+        }
+        html.push_str("</table>\n");
+
+        if !self.tags.is_empty() {
+            let _ = writeln!(html, "<h3>{}</h3>", escape(t.stats.by_tag));
+            html.push_str("<table cellpadding=\"4\">\n");
+            for (tag, seconds) in &self.tags {
+                let _ = writeln!(
+                    html,
+                    "<tr><td>{}</td><td align=\"right\">{}</td></tr>",
+                    escape(tag.as_deref().unwrap_or(t.stats.untagged)),
+                    hours(*seconds as f32 / 3600.0)
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        html.push_str("</body></html>\n");
+        html
+    }
+}
+
+fn hours(hours: f32) -> String {
+    format!("{:.1} h", hours)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_html() {
+        let report = WeeklyReport {
+            monday: NaiveDate::from_ymd_opt(2024, 3, 4).unwrap(),
+            week: PeriodSummary {
+                work_seconds: 9000,
+                pomodoros: 6,
+                interrupted: 2,
+            },
+            previous_week: PeriodSummary::default(),
+            daily_hours: vec![2.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            tags: vec![(Some("<b>ugs".to_string()), 5400), (None, 3600)],
+        };
+        let html = report.to_html();
+        assert!(html.contains("2024-03-04 – 2024-03-10"));
+        assert!(html.contains("<b>2.5 h</b>"));
+        assert!(html.contains("<b>75%</b>"));
+        assert!(html.contains("width:240px"));
+        assert!(html.contains("&lt;b&gt;ugs"));
+        assert!(!html.contains("<b>ugs"));
+    }
+}
@@ -1,6 +1,98 @@
 //! Statistics aggregation
 
-use super::Database;
+use chrono::{Datelike, Local, NaiveDate};
+
+use super::{Database, WorkingHoursConfig};
+
+/// Focus totals of a period
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PeriodSummary {
+    pub work_seconds: i64,
+    pub pomodoros: i32,
+    pub interrupted: i32,
+}
+
+impl PeriodSummary {
+    pub(super) fn load(db: &Database, from: NaiveDate, to: NaiveDate) -> Self {
+        let (work_seconds, pomodoros, interrupted) =
+            db.get_range_summary(from, to).unwrap_or((0, 0, 0));
+        Self {
+            work_seconds,
+            pomodoros,
+            interrupted,
+        }
+    }
+
+    pub fn hours(&self) -> f32 {
+        self.work_seconds as f32 / 3600.0
+    }
+
+    /// Share of started pomodoros that were completed, `None` if none started
+    pub fn completion_rate(&self) -> Option<f32> {
+        let started = self.pomodoros + self.interrupted;
+        (started > 0).then(|| self.pomodoros as f32 / started as f32)
+    }
+}
+
+/// This week so far against the same days of last week
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WeekComparison {
+    pub this_week: PeriodSummary,
+    pub last_week: PeriodSummary,
+}
+
+impl WeekComparison {
+    /// Compare Monday to `today` with the same weekdays a week earlier
+    pub fn load(db: &Database, today: NaiveDate) -> Self {
+        let monday = today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
+        let week = chrono::Duration::weeks(1);
+        Self {
+            this_week: PeriodSummary::load(db, monday, today),
+            last_week: PeriodSummary::load(db, monday - week, today - week),
+        }
+    }
+
+    pub fn hours_delta(&self) -> f32 {
+        self.this_week.hours() - self.last_week.hours()
+    }
+
+    pub fn pomodoros_delta(&self) -> i32 {
+        self.this_week.pomodoros - self.last_week.pomodoros
+    }
+
+    /// Difference in completion rate, in percentage points
+    pub fn completion_rate_delta(&self) -> Option<f32> {
+        let this = self.this_week.completion_rate()?;
+        let last = self.last_week.completion_rate()?;
+        Some((this - last) * 100.0)
+    }
+}
+
+/// How closely the day plans of a period were kept
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PlanAdherence {
+    /// Days with a plan
+    pub days: i32,
+    pub planned: i32,
+    /// Planned pomodoros done, not counting any beyond a task's plan
+    pub done: i32,
+}
+
+impl PlanAdherence {
+    pub(super) fn load(db: &Database, from: NaiveDate, to: NaiveDate) -> Self {
+        let (days, planned, done) = db.get_plan_adherence(from, to).unwrap_or((0, 0, 0));
+        Self {
+            days,
+            planned,
+            done,
+        }
+    }
+
+    /// Share of the planned pomodoros that were done, `None` without a plan
+    pub fn rate(&self) -> Option<f32> {
+        (self.planned > 0).then(|| self.done as f32 / self.planned as f32)
+    }
+}
 
 /// Aggregated statistics for display
 #[derive(Debug, Clone)]
@@ -21,16 +113,36 @@ pub struct Statistics {
     pub total_work_seconds: i64,
     /// Total pomodoros (all time)
     pub total_pomodoros: i32,
+    /// This week compared with last week
+    pub week_comparison: WeekComparison,
+    /// Today's plan kept so far
+    pub today_plan: PlanAdherence,
+    /// This week's plans kept, Monday to today
+    pub week_plan: PlanAdherence,
 }
 
 impl Statistics {
     /// Load statistics from database
     pub fn load(db: &Database) -> Self {
-        let (today_work_seconds, today_pomodoros) = db.get_today_stats().unwrap_or((0, 0));
+        Self::load_within(db, &WorkingHoursConfig::default())
+    }
+
+    /// Load statistics, counting today only within today's working hours
+    pub fn load_within(db: &Database, working_hours: &WorkingHoursConfig) -> Self {
+        let today = Local::now().date_naive();
+        let (today_work_seconds, today_pomodoros) = match working_hours.hours_on(today) {
+            Some((from, to)) => db.get_stats_within(today, from, to),
+            None => db.get_today_stats(),
+        }
+        .unwrap_or((0, 0));
         let week_daily_hours = db.get_week_stats().unwrap_or_else(|_| vec![0.0; 7]);
         let week_work_seconds = (week_daily_hours.iter().sum::<f32>() * 3600.0) as i64;
         let (current_streak, longest_streak) = db.get_streak().unwrap_or((0, 0));
         let (total_work_seconds, total_pomodoros) = db.get_total_stats().unwrap_or((0, 0));
+        let week_comparison = WeekComparison::load(db, today);
+        let monday = today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
+        let today_plan = PlanAdherence::load(db, today, today);
+        let week_plan = PlanAdherence::load(db, monday, today);
 
         Self {
             today_work_seconds,
@@ -41,6 +153,9 @@ impl Statistics {
             longest_streak,
             total_work_seconds,
             total_pomodoros,
+            week_comparison,
+            today_plan,
+            week_plan,
         }
     }
 
@@ -55,6 +170,9 @@ impl Statistics {
             longest_streak: 0,
             total_work_seconds: 0,
             total_pomodoros: 0,
+            week_comparison: WeekComparison::default(),
+            today_plan: PlanAdherence::default(),
+            week_plan: PlanAdherence::default(),
         }
     }
 
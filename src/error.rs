@@ -81,6 +81,8 @@ pub enum ConfigError {
     ReadFile { path: PathBuf, source: io::Error },
     /// Failed to write config file
     WriteFile { path: PathBuf, source: io::Error },
+    /// Config file is read-only, so it was left as it is
+    ReadOnly { path: PathBuf },
     /// Failed to parse config file
     Parse { path: PathBuf, message: String },
     /// Failed to serialize config
@@ -102,6 +104,9 @@ impl fmt::Display for ConfigError {
             ConfigError::WriteFile { path, source } => {
                 write!(f, "failed to write {:?}: {}", path, source)
             }
+            ConfigError::ReadOnly { path } => {
+                write!(f, "{:?} is read-only", path)
+            }
             ConfigError::Parse { path, message } => {
                 write!(f, "failed to parse {:?}: {}", path, message)
             }
@@ -193,6 +198,8 @@ pub enum PlatformError {
     },
     /// Notification error
     Notification { message: String },
+    /// Start on login could not be set up or removed
+    Autostart { message: String },
     /// Executable path not found
     ExecutablePath { source: io::Error },
     /// Feature not supported on this platform
@@ -211,6 +218,9 @@ impl fmt::Display for PlatformError {
             PlatformError::Notification { message } => {
                 write!(f, "notification failed: {}", message)
             }
+            PlatformError::Autostart { message } => {
+                write!(f, "autostart failed: {}", message)
+            }
             PlatformError::ExecutablePath { source } => {
                 write!(f, "failed to get executable path: {}", source)
             }
@@ -242,6 +252,12 @@ pub enum AudioError {
     },
     /// Audio device not available
     DeviceNotAvailable,
+    /// Failed to read a sound file
+    ReadFile { path: PathBuf, source: io::Error },
+    /// Sound file is too large to be a notification or tick
+    FileTooLarge { path: PathBuf },
+    /// Sound file is in a format that can't be decoded
+    Decode { path: PathBuf, message: String },
 }
 
 impl fmt::Display for AudioError {
@@ -256,11 +272,37 @@ impl fmt::Display for AudioError {
             AudioError::DeviceNotAvailable => {
                 write!(f, "no audio device available")
             }
+            AudioError::ReadFile { path, source } => {
+                write!(
+                    f,
+                    "failed to read sound file {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+            AudioError::FileTooLarge { path } => {
+                write!(f, "sound file {} is too large", path.display())
+            }
+            AudioError::Decode { path, message } => {
+                write!(
+                    f,
+                    "failed to decode sound file {}: {}",
+                    path.display(),
+                    message
+                )
+            }
         }
     }
 }
 
-impl std::error::Error for AudioError {}
+impl std::error::Error for AudioError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AudioError::ReadFile { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
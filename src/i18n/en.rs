@@ -0,0 +1,553 @@
+//! English translations
+
+use super::*;
+
+pub(super) static EN: Tr = Tr {
+    nav: NavTr {
+        settings: "Settings",
+        statistics: "Statistics",
+        tasks: "Tasks",
+        queue: "Queue",
+    },
+    timer: TimerTr {
+        focus: "FOCUS",
+        short_break: "SHORT BREAK",
+        long_break: "LONG BREAK",
+        pause: "PAUSE",
+        start: "START",
+        skip: "SKIP",
+        session: "Session",
+        break_overlay_title: "Time for a break",
+        break_overlay_hint: "Step away from the screen",
+        custom: "Custom…",
+        custom_hint: "Start a one-off session of any length",
+        drag_hint: "Drag around the ring to set the time",
+        intent_hint: "What will you work on?",
+    },
+    settings: SettingsTr {
+        title: "Settings",
+        timer: "Timer",
+        focus_duration: "Focus Duration",
+        short_break: "Short Break",
+        long_break: "Long Break",
+        sessions_before_long: "Sessions before long break",
+        auto_start_breaks: "Auto-start breaks",
+        auto_start_pomodoros: "Auto-start pomodoros",
+        require_intent: "Ask for an intent before focusing",
+        strict_focus: "Strict focus: no pausing or skipping",
+        min_recorded: "Don't record focus cut short before",
+        sounds: "Sounds",
+        volume: "Volume",
+        sound: "Sound",
+        tick_sound: "Tick sound",
+        sound_file: "Own sound file",
+        tick_file: "Own tick file",
+        tick_speed_up: "Speed up the last 10 seconds",
+        chime_halfway: "Chime halfway through focus",
+        chime_every: "Chime every",
+        sound_files_hint: "A .wav, .ogg or .mp3 file played instead of the built-in sound",
+        audio_files: "Audio files",
+        sound_file_unreadable: "Can't read this file",
+        sound_file_too_large: "The file is over 10 MB",
+        sound_file_unsupported: "This file can't be played",
+        appearance: "Appearance",
+        theme: "Theme",
+        accent_color: "Accent Color",
+        retro_themes: "Retro Themes",
+        window_opacity: "Window Opacity",
+        background_image: "Background Image",
+        choose_image: "Choose…",
+        remove_image: "Remove",
+        png_images: "PNG images",
+        background_blur: "Blur",
+        background_dim: "Dim",
+        quick_start_label_hint: "Label (optional)",
+        quick_start_tag_hint: "Tag",
+        add_quick_start: "Add button",
+        remove_quick_start: "Remove button",
+        accessibility: "Accessibility",
+        high_contrast: "High contrast mode",
+        reduced_motion: "Reduced motion",
+        power_saver: "Save power on battery",
+        system: "System",
+        start_with_windows: "Start with Windows",
+        dnd_during_focus: "Do Not Disturb during focus",
+        pause_media_during_focus: "Pause media during focus",
+        close_to_tray: "Close button hides to tray",
+        always_on_top: "Always on top",
+        raise_on_complete: "Bring window to front when done",
+        title_countdown: "Countdown in window title",
+        kiosk_message: "Kiosk message",
+        track_apps: "Track focus time per app (stays on this computer)",
+        reminders: "Remind again until the next session starts",
+        reminder_steps: "Remind after (minutes)",
+        reminder_sound: "Sound with later reminders",
+        hue: "Philips Hue",
+        hue_enabled: "Light shows the session",
+        hue_bridge_ip: "Bridge IP",
+        hue_app_key: "App key",
+        hue_target: "Light ID (group:N for a room)",
+        calendar: "Calendar",
+        calendar_enabled: "Watch my calendar for meetings",
+        calendar_url: "ICS feed URL",
+        meeting_overlap: "Focus runs into a meeting",
+        meeting_warn: "Warn",
+        meeting_shorten: "End before it",
+        meeting_pause: "Pause for it",
+        slack: "Slack",
+        slack_enabled: "Show focus sessions in Slack",
+        slack_token: "User token (xoxp-...)",
+        slack_status_text: "Status ({until} is the end time)",
+        slack_status_emoji: "Status emoji",
+        slack_dnd: "Pause Slack notifications",
+        todoist: "Todoist",
+        todoist_enabled: "Pull tasks from Todoist",
+        todoist_token: "API token",
+        todoist_filter: "Filter (empty for all tasks)",
+        todoist_comment: "Comment after focus sessions",
+        todoist_close: "Close tasks done here",
+        taskwarrior: "Taskwarrior",
+        taskwarrior_enabled: "Pull tasks from Taskwarrior",
+        taskwarrior_command: "Command",
+        taskwarrior_filter: "Filter (empty for all pending tasks)",
+        taskwarrior_track_time: "Start and stop tasks with focus sessions",
+        taskwarrior_annotate: "Annotate tasks with pomodoros",
+        obsidian: "Obsidian",
+        obsidian_enabled: "Log sessions to the daily note",
+        obsidian_vault: "Vault folder",
+        obsidian_folder: "Daily notes folder (empty for the vault root)",
+        obsidian_date_format: "Note name format",
+        obsidian_template: "Line template",
+        obsidian_log_breaks: "Log breaks too",
+        activitywatch: "ActivityWatch",
+        activitywatch_enabled: "Send sessions to ActivityWatch",
+        activitywatch_url: "Server address",
+        activitywatch_bucket: "Bucket (empty for one per machine)",
+        activitywatch_breaks: "Send breaks too",
+        notion: "Notion",
+        notion_enabled: "Add focus sessions to Notion",
+        notion_token: "Integration secret",
+        notion_database: "Database id",
+        notion_title_property: "Title property",
+        notion_date_property: "Date property",
+        notion_duration_property: "Minutes property",
+        notion_tag_property: "Tag property",
+        telegram: "Telegram",
+        telegram_enabled: "Control the timer from Telegram",
+        telegram_token: "Bot token",
+        telegram_chat: "Chat id",
+        telegram_notify: "Message when a session completes",
+        group: "Group Session",
+        group_enabled: "Share the timer over the local network",
+        group_role: "Role",
+        group_host: "Host",
+        group_follow: "Follow a host",
+        group_address: "Host address",
+        group_name: "Your name",
+        group_code: "Group code",
+        email: "Weekly Email",
+        email_enabled: "Email a weekly report on Mondays",
+        email_server: "SMTP server (smtps://host:465)",
+        email_username: "Username",
+        email_password: "Password",
+        email_from: "From",
+        email_to: "To",
+        email_send_at: "Send at",
+        plugins: "Plugins",
+        plugins_enabled: "Run plugins",
+        plugins_hint: "Plugins are loaded from",
+        no_plugins: "No plugins installed",
+        goals: "Goals",
+        daily_goal: "Daily goal",
+        pomodoros: "pomodoros",
+        notify_goal_reached: "Notify when goal reached",
+        global_hotkeys: "Global Hotkeys",
+        enable_hotkeys: "Enable global hotkeys",
+        toggle_start_pause: "Toggle (start/pause)",
+        skip_session: "Skip session",
+        reset_timer: "Reset timer",
+        mute_hotkey: "Mute/unmute sounds",
+        press_keys: "Press keys…",
+        hotkey_record_hint: "Click, then press the new combination (Esc to cancel)",
+        hotkey_in_use: "Already used by another action",
+        hotkey_unsupported: "Use Ctrl, Alt or Shift with a letter, digit, F1–F12, Space or Enter",
+        media_keys: "Media keys (Play/Pause, Next)",
+        media_keys_unless_playing: "Only while no media is playing",
+        command_line: "Command Line",
+        control_from_terminal: "Control timer from terminal:",
+        copy_path_command: "Copy PATH command",
+        copy_path_tooltip: "Copy PowerShell command to add pomodorust to PATH",
+        run_copied_command: "Run copied command in PowerShell, then restart terminal",
+        presets: "Presets",
+        profiles: "Profiles",
+        profiles_hint: "Each profile keeps its own durations, sounds, goal and hotkeys",
+        no_profile: "No profile",
+        profile_name_hint: "Profile name",
+        save_profile: "Save current settings",
+        use_profile: "Use",
+        delete_profile: "Delete profile",
+        weekday_plans: "Weekday Plans",
+        weekday_plans_enabled: "Plan by weekday",
+        weekday_plans_hint: "Taken up before the first session of the day. A goal of — keeps the daily goal.",
+        day_usual: "Usual",
+        working_hours: "Working Hours",
+        working_hours_enabled: "Set working hours",
+        working_hours_hint: "Today's statistics count only sessions within them on working days.",
+        work_starts: "Day starts",
+        work_ends: "Day ends",
+        hold_auto_start: "No auto-start outside them",
+        warn_late_focus: "Warn about late focus sessions",
+        rest_days: "Rest days",
+        rest_days_hint: "No goal notifications, and the streak carries over them.",
+        reset_to_defaults: "Reset to Defaults",
+        search_hint: "Search settings…",
+        no_results: "No settings match",
+        notification_text: "Notification text",
+        notification_placeholders: "Leave empty for the default. Placeholders: {session_type}, {next_duration} (minutes), {today_count}",
+        integrations: "Integrations",
+        advanced: "Advanced",
+        language: "Language",
+        language_restart_hint: "",
+        test_sound: "Test sound",
+        ambient: "Ambient sounds",
+        ambient_hint: "Mixed in while a focus session runs, off on breaks",
+        ambient_rain: "Rain",
+        ambient_cafe: "Café",
+        ambient_brown_noise: "Brown noise",
+        theme_system: "Auto",
+        theme_light: "Light",
+        theme_dark: "Dark",
+        theme_schedule: "By time of day",
+        follow_sun: "Follow sunrise and sunset",
+        light_from: "Light from",
+        dark_from: "Dark from",
+        time_format_hint: "Use HH:MM",
+        latitude: "Latitude",
+        color_blue: "Blue",
+        color_purple: "Purple",
+        color_rose: "Rose",
+        color_emerald: "Emerald",
+        color_amber: "Amber",
+        color_cyan: "Cyan",
+        color_retro_amber: "Retro Amber",
+        color_custom: "Custom Color",
+        preset_classic: "Classic",
+        preset_short: "Short",
+        preset_long: "Long",
+        preset_applied: "preset",
+        preset_behavior_hint: "Settings each preset switches along with the durations",
+        behavior_keep: "Keep",
+        behavior_on: "On",
+        behavior_off: "Off",
+    },
+    stats: StatsTr {
+        title: "Statistics",
+        current_session: "Current Session",
+        statistics: "Statistics",
+        week_activity: "Week Activity",
+        quick_start: "Quick Start",
+        this_week: "This Week",
+        today: "Today",
+        daily_goal: "Daily Goal",
+        current_streak: "Current Streak",
+        total: "Total",
+        all_time: "All Time",
+        best_streak: "Best Streak",
+        total_sessions: "Total Sessions",
+        running: "Running",
+        completed: "Completed",
+        paused: "Paused",
+        done: "Done!",
+        days: "days",
+        hours: "hours",
+        sessions: "sessions",
+        focus_time: "focus time",
+        todays_focus: "Today's Focus",
+        goal_reached: "Goal reached!",
+        best: "Best",
+        export_as: "Export as",
+        total_label: "total",
+        reset_title: "Reset Statistics?",
+        reset_confirm: "This will permanently delete all\nsession history and statistics.",
+        completed_label: "completed",
+        reset_all_hover: "Reset all statistics",
+        undo_last_hover: "Undo last session",
+        export_hover: "Export statistics",
+        overview: "Overview",
+        month: "Month",
+        this_month: "This Month",
+        history: "History",
+        yesterday: "Yesterday",
+        no_sessions: "No sessions on this day",
+        interrupted: "Interrupted",
+        by_tag: "Focus by Tag",
+        by_app: "Focus by App",
+        today_timeline: "Today's Timeline",
+        vs_last_week: "This Week vs Last Week",
+        last_week: "Last Week",
+        compare_focus: "Focus",
+        compare_pomodoros: "Pomodoros",
+        completion_rate: "Completed",
+        day_plan: "Day Plan",
+        plan_kept: "Kept",
+        plan_days: "Days Planned",
+        untagged: "Untagged",
+        other_tags: "Other",
+        no_focus_time: "No focus time yet",
+        mon: "Mon",
+        tue: "Tue",
+        wed: "Wed",
+        thu: "Thu",
+        fri: "Fri",
+        sat: "Sat",
+        sun: "Sun",
+    },
+    todo: TodoTr {
+        completed_label: "Completed",
+        miscellaneous: "Miscellaneous",
+        no_tasks: "No tasks",
+        create_first_task: "Create your first task below",
+        new_task_hint: "New task...",
+        name_hint: "Name...",
+        new_workspace: "New workspace",
+        project: "Project",
+        project_name_hint: "Project name...",
+        rename: "Rename",
+        delete: "Delete",
+        delete_project: "Delete project",
+        delete_project_n: "Delete project ({} tasks)",
+        edit: "Edit",
+        add_to_queue: "Add to queue",
+        move_to: "Move to...",
+        no_project: "No project",
+        priority: "Priority",
+        title_hint: "Title...",
+        description_hint: "Description (markdown)...",
+        save: "Save",
+        priority_none: "\u{2014}",
+        priority_low: "Low",
+        priority_medium: "Medium",
+        priority_high: "High",
+        priority_urgent: "Urgent",
+        tasks_default_workspace: "Tasks",
+    },
+    queue: QueueTr {
+        title: "Queue",
+        empty: "Queue is empty",
+        empty_hint: "Add tasks via \u{22EE} menu in the task list",
+        clear: "Clear queue",
+    },
+    tasks: TasksTr {
+        title: "Tasks",
+        add_hint: "New task, Enter to add",
+        empty: "No tasks yet",
+        empty_hint: "Type a task above and pick how many pomodoros it needs",
+        select_hint: "Click to work on this task next",
+        estimate_hint: "Completed / estimated pomodoros",
+        open: "Manage tasks",
+    },
+    plan: PlanTr {
+        title: "Day Plan",
+        today: "Today",
+        tomorrow: "Tomorrow",
+        empty: "Nothing to plan",
+        empty_hint: "Add tasks in the task list, then give them pomodoros here",
+        add: "Add to the plan",
+        done_hint: "Completed / planned pomodoros",
+        open: "Plan the day",
+        progress: "Plan {done}/{planned}",
+    },
+    tray: TrayTr {
+        focus: "Focus",
+        short_break: "Short Break",
+        long_break: "Long Break",
+        pause: "Pause",
+        continue_: "Continue",
+        start: "Start",
+        ready: "Ready",
+        close_app: "Close application?",
+        what_to_do: "What would you like to do?",
+        minimize_to_tray: "  Minimize to tray  ",
+        quit: "  Quit  ",
+        show_window: "Show",
+        start_focus: "Start Focus",
+        start_break: "Start Break",
+        show_stats: "Show Stats",
+    },
+    notif: NotifTr {
+        focus_complete: "Focus Complete!",
+        time_for_break: "Time for a break.",
+        break_over: "Break Over",
+        ready_to_focus: "Ready to focus again?",
+        long_break_over: "Long Break Over",
+        back_to_work: "Let's get back to work!",
+        daily_goal_reached: "Daily Goal Reached!",
+        stats_cleared: "All statistics have been cleared.",
+        session_removed: "Last pomodoro session has been removed from statistics.",
+        export_complete: "Export Complete",
+        export_failed: "Export Failed",
+        export_statistics: "Export Statistics",
+        defaults_restored: "Defaults restored",
+        settings_saved: "Settings saved",
+        sounds_muted: "Sounds muted",
+        sounds_unmuted: "Sounds on",
+        profile_switched: "Profile",
+        profile_saved: "Profile saved",
+        late_focus: "Focusing outside working hours",
+        strict_focus: "Strict focus: finish the session first",
+        todoist_failed: "Todoist sync failed",
+        taskwarrior_failed: "Taskwarrior command failed",
+        daily_note_failed: "Could not write the daily note",
+        notion_failed: "Notion sync failed, will retry",
+        weekly_report: "Weekly report",
+        weekly_report_failed: "Could not email the weekly report",
+        meeting_at: "Meeting at",
+        meeting_shortened: "Session shortened for",
+        meeting_paused: "Paused for meeting",
+        todoist_comment: "Pomodoro completed",
+        taskwarrior_annotation: "Pomodoro",
+        action_start_break: "Start break now",
+        action_start_focus: "Start focus now",
+        action_snooze: "Snooze 5 min",
+        action_skip: "Skip",
+    },
+    away: AwayTr {
+        title: "Welcome back",
+        away_for: "The timer was paused while you were away for",
+        question: "Count that time as focus?",
+        count_it: "Count it",
+        discard: "Don't count",
+    },
+    common: CommonTr {
+        config_problems: "Problems in config.toml",
+        dismiss: "Dismiss",
+        back: "Back",
+        cancel: "Cancel",
+        reset: "Reset",
+        min: "min",
+        pin_window: "Pin window (always on top)",
+        unpin_window: "Unpin window (disable always on top)",
+        mute_sounds: "Mute sounds (M)",
+        unmute_sounds: "Unmute sounds (M)",
+        mini_mode: "Mini mode",
+        exit_mini_mode: "Double-click to restore",
+        pop_out_timer: "Pop out timer",
+        kiosk_mode: "Full-screen kiosk",
+        dock_timer: "Dock timer",
+        group_hosting: "Hosting a group session on port {port}",
+        group_following: "Following {host}",
+        group_connecting: "Connecting to {address}",
+        group_participants: "{count} taking part",
+        bubble_hint: "Double-click to dock",
+        undo: "Undo",
+        logs: "Logs",
+        refresh: "Refresh",
+        copy: "Copy",
+    },
+    errors: ErrorsTr {
+        retry: "Retry",
+        database: "Sessions aren't being saved",
+        record_session: "A session wasn't saved",
+        config_save: "Settings weren't saved",
+        autostart: "Couldn't change starting with the system",
+        hotkeys: "Some global hotkeys don't work",
+        export: "Export failed",
+        ipc: "The command line can't reach the app",
+        ipc_fallback: "port {port} is used by another program, listening on {fallback} instead",
+        group: "Can't host the group session",
+    },
+    cli: CliTr {
+        tagline: "A modern Pomodoro timer",
+        help: "\
+USAGE: pomodorust [--profile <name>] [COMMAND]
+
+OPTIONS:
+  --profile <name>    Use a separate config, database and app (letters, digits, - and _)
+
+COMMANDS:
+  start [-s <type>]   Start timer (type: work, short, long)
+        [-d <minutes>] [-t <tag>]  Custom duration and session tag
+        [--at <HH:MM>]             Start later instead of now
+  pause [--for <duration>]         Pause (and resume after e.g. 10m, 1h30m)
+  resume              Resume the timer
+  toggle              Toggle start/pause
+  stop                Stop and reset the timer
+  skip                Skip to next session
+  status              Get current timer status
+  stats [-p <period>] Get statistics (period: today, week, month, year, all)
+        [--from <date>] [--to <date>]  Custom range (YYYY-MM-DD, inclusive)
+  mute [on|off|toggle]           Mute sounds (default: on)
+  unmute                         Unmute sounds
+  dnd [on|off|toggle]            Do not disturb: silence notifications (default: toggle)
+  always-on-top [on|off|toggle]  Keep the window above others (default: toggle)
+  kiosk [on|off|toggle] [-m <text>]  Full-screen timer for a projector, with a message (default: on)
+  unschedule          Cancel pending --at/--for actions
+  task add <title> [-n <pomodoros>]  Add a task (and queue it)
+  task list [--all]                  List open (or all) tasks
+  task start <id>                    Focus on a task now
+  task done <id>                     Mark a task as done
+  profile list                       List the settings profiles
+  profile use <name>                 Switch to a settings profile
+  config check                       List problems in config.toml
+  logs [-n <lines>] [--path]         Print the latest log lines (or the log file's path)
+  show [<view>]       Bring the window to front (view: timer, queue, tasks, plan, stats, settings)
+  quit                Quit the running app
+  ping                Check if GUI is running
+  watch               Print timer events as JSON lines until interrupted
+  tui                 Full-screen terminal view of the timer
+
+Run without arguments to start the GUI.
+",
+        error: "Error",
+        not_running: "PomodoRust GUI is not running. Start the app first.",
+        port_taken: "Port {port} is used by another program, not PomodoRust. Set POMODORUST_PORT to a free port.",
+        invalid_profile: "Invalid profile name (use letters, digits, - and _)",
+        running: "PomodoRust is running",
+        focus: "Focus",
+        short_break: "Short Break",
+        long_break: "Long Break",
+        session: "Session",
+        progress: "Progress",
+        no_tasks: "No tasks",
+        current: "current",
+        queued: "queued",
+        today: "Today",
+        this_week: "This Week",
+        this_month: "This Month",
+        this_year: "This Year",
+        all_time: "All Time",
+        range_to: "to",
+        focus_time: "Focus Time",
+        pomodoros: "Pomodoros",
+        daily_goal: "Daily Goal",
+        streak: "Streak",
+        days: "days",
+        best: "best",
+        unknown_command: "Unknown command",
+        see_help: "Run 'pomodorust --help' for usage.",
+        no_logs: "Nothing has been logged yet",
+        invalid_duration: "Invalid duration",
+        expected_minutes: "expected minutes",
+        expected_span: "expected e.g. 10m, 90s, 1h30m",
+        invalid_time: "Invalid time",
+        expected_clock: "expected HH:MM",
+        invalid_value: "Invalid value",
+        expected_switch: "expected on, off or toggle",
+        invalid_count: "Invalid pomodoro count",
+        expected_task_id: "Expected a task id (see 'pomodorust task list')",
+        task_add_usage: "Usage: pomodorust task add <title> [-n <pomodoros>]",
+        task_commands: "Expected one of: add, list, start, done",
+        no_profiles: "No profiles (save one in Settings > Timer)",
+        profile_use_usage: "Usage: pomodorust profile use <name>",
+        profile_commands: "Expected one of: list, use",
+        config_ok: "no problems found",
+        config_commands: "Expected: check",
+        paused: "Paused",
+        tui_keys: "space start/pause · s skip · x stop · q quit",
+    },
+    editor: EditorTr {
+        focus: "Focus",
+        short_break: "Short break",
+        long_break: "Long break",
+    },
+};
@@ -0,0 +1,724 @@
+//! Internationalization (i18n) support
+//!
+//! Provides zero-cost localization using static translation structs.
+//! All UI strings are accessed via `tr()` which returns `&'static Tr`.
+//! This covers the GUI, notifications, the tray menu and the CLI output.
+//!
+//! Each language lives in its own file (`en.rs`, `ru.rs`) holding one
+//! static `Tr`. To add a language:
+//!
+//! 1. Copy `en.rs` to `<code>.rs` and translate every string
+//! 2. Add a variant to `Language` and `ResolvedLang`, and list it in
+//!    `Language::all()`, `display_name()` and `resolve()`
+//! 3. Map it in `tr()` and its locale prefix in `detect_system_language()`
+//!
+//! A missing string is a compile error, so a translation is never partial.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+mod en;
+mod ru;
+
+use en::EN;
+use ru::RU;
+
+// ── Language enum ─────────────────────────────────────────────────
+
+/// User-facing language preference (stored in config)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Language {
+    #[default]
+    Auto,
+    English,
+    Russian,
+}
+
+impl Language {
+    pub fn all() -> &'static [Language] {
+        &[Language::Auto, Language::English, Language::Russian]
+    }
+
+    /// Display name (always in the target language for recognition)
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Language::Auto => "System",
+            Language::English => "English",
+            Language::Russian => "Русский",
+        }
+    }
+
+    /// Resolve Auto to a concrete language
+    pub fn resolve(&self) -> ResolvedLang {
+        match self {
+            Language::English => ResolvedLang::En,
+            Language::Russian => ResolvedLang::Ru,
+            Language::Auto => detect_system_language(),
+        }
+    }
+}
+
+/// Concrete resolved language (no Auto)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolvedLang {
+    En = 0,
+    Ru = 1,
+}
+
+// ── Global state ──────────────────────────────────────────────────
+
+static CURRENT_LANG: AtomicU8 = AtomicU8::new(0);
+
+/// Get current translations
+pub fn tr() -> &'static Tr {
+    match CURRENT_LANG.load(Ordering::Relaxed) {
+        1 => &RU,
+        _ => &EN,
+    }
+}
+
+/// Set the active language
+pub fn set_language(lang: Language) {
+    let resolved = lang.resolve();
+    CURRENT_LANG.store(resolved as u8, Ordering::Relaxed);
+}
+
+/// Detect system language from OS locale
+pub fn detect_system_language() -> ResolvedLang {
+    let locale = sys_locale::get_locale().unwrap_or_default();
+    if locale.starts_with("ru") {
+        ResolvedLang::Ru
+    } else {
+        ResolvedLang::En
+    }
+}
+
+// ── Translation structs ───────────────────────────────────────────
+
+pub struct Tr {
+    pub nav: NavTr,
+    pub timer: TimerTr,
+    pub settings: SettingsTr,
+    pub stats: StatsTr,
+    pub todo: TodoTr,
+    pub queue: QueueTr,
+    pub tasks: TasksTr,
+    pub plan: PlanTr,
+    pub tray: TrayTr,
+    pub notif: NotifTr,
+    pub away: AwayTr,
+    pub common: CommonTr,
+    pub errors: ErrorsTr,
+    pub cli: CliTr,
+    pub editor: EditorTr,
+}
+
+pub struct NavTr {
+    pub settings: &'static str,
+    pub statistics: &'static str,
+    pub tasks: &'static str,
+    pub queue: &'static str,
+}
+
+pub struct TimerTr {
+    pub focus: &'static str,
+    pub short_break: &'static str,
+    pub long_break: &'static str,
+    pub pause: &'static str,
+    pub start: &'static str,
+    pub skip: &'static str,
+    pub session: &'static str,
+    pub break_overlay_title: &'static str,
+    pub break_overlay_hint: &'static str,
+    pub custom: &'static str,
+    pub custom_hint: &'static str,
+    pub drag_hint: &'static str,
+    pub intent_hint: &'static str,
+}
+
+pub struct SettingsTr {
+    pub title: &'static str,
+    pub timer: &'static str,
+    pub focus_duration: &'static str,
+    pub short_break: &'static str,
+    pub long_break: &'static str,
+    pub sessions_before_long: &'static str,
+    pub auto_start_breaks: &'static str,
+    pub auto_start_pomodoros: &'static str,
+    pub require_intent: &'static str,
+    pub strict_focus: &'static str,
+    pub min_recorded: &'static str,
+    pub sounds: &'static str,
+    pub volume: &'static str,
+    pub sound: &'static str,
+    pub tick_sound: &'static str,
+    pub sound_file: &'static str,
+    pub tick_file: &'static str,
+    pub tick_speed_up: &'static str,
+    pub chime_halfway: &'static str,
+    pub chime_every: &'static str,
+    pub sound_files_hint: &'static str,
+    pub audio_files: &'static str,
+    pub sound_file_unreadable: &'static str,
+    pub sound_file_too_large: &'static str,
+    pub sound_file_unsupported: &'static str,
+    pub appearance: &'static str,
+    pub theme: &'static str,
+    pub accent_color: &'static str,
+    pub retro_themes: &'static str,
+    pub window_opacity: &'static str,
+    pub background_image: &'static str,
+    pub choose_image: &'static str,
+    pub remove_image: &'static str,
+    pub png_images: &'static str,
+    pub background_blur: &'static str,
+    pub background_dim: &'static str,
+    pub quick_start_label_hint: &'static str,
+    pub quick_start_tag_hint: &'static str,
+    pub add_quick_start: &'static str,
+    pub remove_quick_start: &'static str,
+    pub accessibility: &'static str,
+    pub high_contrast: &'static str,
+    pub reduced_motion: &'static str,
+    pub power_saver: &'static str,
+    pub system: &'static str,
+    pub start_with_windows: &'static str,
+    pub dnd_during_focus: &'static str,
+    pub pause_media_during_focus: &'static str,
+    pub close_to_tray: &'static str,
+    pub always_on_top: &'static str,
+    pub raise_on_complete: &'static str,
+    pub title_countdown: &'static str,
+    pub kiosk_message: &'static str,
+    pub track_apps: &'static str,
+    pub reminders: &'static str,
+    pub reminder_steps: &'static str,
+    pub reminder_sound: &'static str,
+    pub hue: &'static str,
+    pub hue_enabled: &'static str,
+    pub hue_bridge_ip: &'static str,
+    pub hue_app_key: &'static str,
+    pub hue_target: &'static str,
+    pub calendar: &'static str,
+    pub calendar_enabled: &'static str,
+    pub calendar_url: &'static str,
+    pub meeting_overlap: &'static str,
+    pub meeting_warn: &'static str,
+    pub meeting_shorten: &'static str,
+    pub meeting_pause: &'static str,
+    pub slack: &'static str,
+    pub slack_enabled: &'static str,
+    pub slack_token: &'static str,
+    pub slack_status_text: &'static str,
+    pub slack_status_emoji: &'static str,
+    pub slack_dnd: &'static str,
+    pub todoist: &'static str,
+    pub todoist_enabled: &'static str,
+    pub todoist_token: &'static str,
+    pub todoist_filter: &'static str,
+    pub todoist_comment: &'static str,
+    pub todoist_close: &'static str,
+    pub taskwarrior: &'static str,
+    pub taskwarrior_enabled: &'static str,
+    pub taskwarrior_command: &'static str,
+    pub taskwarrior_filter: &'static str,
+    pub taskwarrior_track_time: &'static str,
+    pub taskwarrior_annotate: &'static str,
+    pub obsidian: &'static str,
+    pub obsidian_enabled: &'static str,
+    pub obsidian_vault: &'static str,
+    pub obsidian_folder: &'static str,
+    pub obsidian_date_format: &'static str,
+    pub obsidian_template: &'static str,
+    pub obsidian_log_breaks: &'static str,
+    pub activitywatch: &'static str,
+    pub activitywatch_enabled: &'static str,
+    pub activitywatch_url: &'static str,
+    pub activitywatch_bucket: &'static str,
+    pub activitywatch_breaks: &'static str,
+    pub notion: &'static str,
+    pub notion_enabled: &'static str,
+    pub notion_token: &'static str,
+    pub notion_database: &'static str,
+    pub notion_title_property: &'static str,
+    pub notion_date_property: &'static str,
+    pub notion_duration_property: &'static str,
+    pub notion_tag_property: &'static str,
+    pub telegram: &'static str,
+    pub telegram_enabled: &'static str,
+    pub telegram_token: &'static str,
+    pub telegram_chat: &'static str,
+    pub telegram_notify: &'static str,
+    pub group: &'static str,
+    pub group_enabled: &'static str,
+    pub group_role: &'static str,
+    pub group_host: &'static str,
+    pub group_follow: &'static str,
+    pub group_address: &'static str,
+    pub group_name: &'static str,
+    pub group_code: &'static str,
+    pub email: &'static str,
+    pub email_enabled: &'static str,
+    pub email_server: &'static str,
+    pub email_username: &'static str,
+    pub email_password: &'static str,
+    pub email_from: &'static str,
+    pub email_to: &'static str,
+    pub email_send_at: &'static str,
+    pub plugins: &'static str,
+    pub plugins_enabled: &'static str,
+    pub plugins_hint: &'static str,
+    pub no_plugins: &'static str,
+    pub goals: &'static str,
+    pub daily_goal: &'static str,
+    pub pomodoros: &'static str,
+    pub notify_goal_reached: &'static str,
+    pub global_hotkeys: &'static str,
+    pub enable_hotkeys: &'static str,
+    pub toggle_start_pause: &'static str,
+    pub skip_session: &'static str,
+    pub reset_timer: &'static str,
+    pub mute_hotkey: &'static str,
+    pub press_keys: &'static str,
+    pub hotkey_record_hint: &'static str,
+    pub hotkey_in_use: &'static str,
+    pub hotkey_unsupported: &'static str,
+    pub media_keys: &'static str,
+    pub media_keys_unless_playing: &'static str,
+    pub command_line: &'static str,
+    pub control_from_terminal: &'static str,
+    pub copy_path_command: &'static str,
+    pub copy_path_tooltip: &'static str,
+    pub run_copied_command: &'static str,
+    pub presets: &'static str,
+    pub profiles: &'static str,
+    pub profiles_hint: &'static str,
+    pub no_profile: &'static str,
+    pub profile_name_hint: &'static str,
+    pub save_profile: &'static str,
+    pub use_profile: &'static str,
+    pub delete_profile: &'static str,
+    pub weekday_plans: &'static str,
+    pub weekday_plans_enabled: &'static str,
+    pub weekday_plans_hint: &'static str,
+    pub day_usual: &'static str,
+    pub working_hours: &'static str,
+    pub working_hours_enabled: &'static str,
+    pub working_hours_hint: &'static str,
+    pub work_starts: &'static str,
+    pub work_ends: &'static str,
+    pub hold_auto_start: &'static str,
+    pub warn_late_focus: &'static str,
+    pub rest_days: &'static str,
+    pub rest_days_hint: &'static str,
+    pub reset_to_defaults: &'static str,
+    pub search_hint: &'static str,
+    pub no_results: &'static str,
+    pub notification_text: &'static str,
+    pub notification_placeholders: &'static str,
+    pub integrations: &'static str,
+    pub advanced: &'static str,
+    pub language: &'static str,
+    pub language_restart_hint: &'static str,
+    pub test_sound: &'static str,
+    pub ambient: &'static str,
+    pub ambient_hint: &'static str,
+    pub ambient_rain: &'static str,
+    pub ambient_cafe: &'static str,
+    pub ambient_brown_noise: &'static str,
+    // Theme mode names
+    pub theme_system: &'static str,
+    pub theme_light: &'static str,
+    pub theme_dark: &'static str,
+    pub theme_schedule: &'static str,
+    pub follow_sun: &'static str,
+    pub light_from: &'static str,
+    pub dark_from: &'static str,
+    pub time_format_hint: &'static str,
+    pub latitude: &'static str,
+    // Color names
+    pub color_blue: &'static str,
+    pub color_purple: &'static str,
+    pub color_rose: &'static str,
+    pub color_emerald: &'static str,
+    pub color_amber: &'static str,
+    pub color_cyan: &'static str,
+    pub color_retro_amber: &'static str,
+    pub color_custom: &'static str,
+    // Preset names
+    pub preset_classic: &'static str,
+    pub preset_short: &'static str,
+    pub preset_long: &'static str,
+    pub preset_applied: &'static str, // "{} preset" / "Пресет {}"
+    pub preset_behavior_hint: &'static str,
+    pub behavior_keep: &'static str,
+    pub behavior_on: &'static str,
+    pub behavior_off: &'static str,
+}
+
+pub struct StatsTr {
+    pub title: &'static str,
+    pub current_session: &'static str,
+    pub statistics: &'static str,
+    pub week_activity: &'static str,
+    pub quick_start: &'static str,
+    pub this_week: &'static str,
+    pub today: &'static str,
+    pub daily_goal: &'static str,
+    pub current_streak: &'static str,
+    pub total: &'static str,
+    pub all_time: &'static str,
+    pub best_streak: &'static str,
+    pub total_sessions: &'static str,
+    pub running: &'static str,
+    pub completed: &'static str,
+    pub paused: &'static str,
+    pub done: &'static str,
+    pub days: &'static str,
+    pub hours: &'static str,
+    pub sessions: &'static str,
+    pub focus_time: &'static str,
+    pub todays_focus: &'static str,
+    pub goal_reached: &'static str,
+    pub best: &'static str,
+    pub export_as: &'static str,
+    pub total_label: &'static str,
+    pub reset_title: &'static str,
+    pub reset_confirm: &'static str,
+    pub completed_label: &'static str,
+    pub reset_all_hover: &'static str,
+    pub undo_last_hover: &'static str,
+    pub export_hover: &'static str,
+    pub overview: &'static str,
+    pub month: &'static str,
+    pub this_month: &'static str,
+    pub history: &'static str,
+    pub yesterday: &'static str,
+    pub no_sessions: &'static str,
+    pub interrupted: &'static str,
+    pub by_tag: &'static str,
+    pub by_app: &'static str,
+    pub today_timeline: &'static str,
+    pub vs_last_week: &'static str,
+    pub last_week: &'static str,
+    pub compare_focus: &'static str,
+    pub compare_pomodoros: &'static str,
+    pub completion_rate: &'static str,
+    pub day_plan: &'static str,
+    pub plan_kept: &'static str,
+    pub plan_days: &'static str,
+    pub untagged: &'static str,
+    pub other_tags: &'static str,
+    pub no_focus_time: &'static str,
+    // Days of week
+    pub mon: &'static str,
+    pub tue: &'static str,
+    pub wed: &'static str,
+    pub thu: &'static str,
+    pub fri: &'static str,
+    pub sat: &'static str,
+    pub sun: &'static str,
+}
+
+pub struct TodoTr {
+    pub completed_label: &'static str,
+    pub miscellaneous: &'static str,
+    pub no_tasks: &'static str,
+    pub create_first_task: &'static str,
+    pub new_task_hint: &'static str,
+    pub name_hint: &'static str,
+    pub new_workspace: &'static str,
+    pub project: &'static str,
+    pub project_name_hint: &'static str,
+    pub rename: &'static str,
+    pub delete: &'static str,
+    pub delete_project: &'static str,
+    pub delete_project_n: &'static str, // "Delete project ({} tasks)"
+    pub edit: &'static str,
+    pub add_to_queue: &'static str,
+    pub move_to: &'static str,
+    pub no_project: &'static str,
+    pub priority: &'static str,
+    pub title_hint: &'static str,
+    pub description_hint: &'static str,
+    pub save: &'static str,
+    // Priority labels
+    pub priority_none: &'static str,
+    pub priority_low: &'static str,
+    pub priority_medium: &'static str,
+    pub priority_high: &'static str,
+    pub priority_urgent: &'static str,
+    pub tasks_default_workspace: &'static str,
+}
+
+pub struct QueueTr {
+    pub title: &'static str,
+    pub empty: &'static str,
+    pub empty_hint: &'static str,
+    pub clear: &'static str,
+}
+
+pub struct TasksTr {
+    pub title: &'static str,
+    pub add_hint: &'static str,
+    pub empty: &'static str,
+    pub empty_hint: &'static str,
+    pub select_hint: &'static str,
+    pub estimate_hint: &'static str,
+    pub open: &'static str,
+}
+
+pub struct PlanTr {
+    pub title: &'static str,
+    pub today: &'static str,
+    pub tomorrow: &'static str,
+    pub empty: &'static str,
+    pub empty_hint: &'static str,
+    pub add: &'static str,
+    pub done_hint: &'static str,
+    pub open: &'static str,
+    /// Under the timer, `{done}` of the `{planned}` pomodoros planned today
+    pub progress: &'static str,
+}
+
+/// Command line output
+pub struct CliTr {
+    pub tagline: &'static str,
+    /// Usage and command list printed by `--help`
+    pub help: &'static str,
+    pub error: &'static str,
+    pub not_running: &'static str,
+    /// `{port}` is the port the other program holds
+    pub port_taken: &'static str,
+    pub invalid_profile: &'static str,
+    pub running: &'static str,
+    pub focus: &'static str,
+    pub short_break: &'static str,
+    pub long_break: &'static str,
+    pub session: &'static str,
+    pub progress: &'static str,
+    pub no_tasks: &'static str,
+    pub current: &'static str,
+    pub queued: &'static str,
+    pub today: &'static str,
+    pub this_week: &'static str,
+    pub this_month: &'static str,
+    pub this_year: &'static str,
+    pub all_time: &'static str,
+    pub range_to: &'static str,
+    pub focus_time: &'static str,
+    pub pomodoros: &'static str,
+    pub daily_goal: &'static str,
+    pub streak: &'static str,
+    pub days: &'static str,
+    pub best: &'static str,
+    pub unknown_command: &'static str,
+    pub see_help: &'static str,
+    pub no_logs: &'static str,
+    pub invalid_duration: &'static str,
+    pub expected_minutes: &'static str,
+    pub expected_span: &'static str,
+    pub invalid_time: &'static str,
+    pub expected_clock: &'static str,
+    pub invalid_value: &'static str,
+    pub expected_switch: &'static str,
+    pub invalid_count: &'static str,
+    pub expected_task_id: &'static str,
+    pub task_add_usage: &'static str,
+    pub task_commands: &'static str,
+    pub no_profiles: &'static str,
+    pub profile_use_usage: &'static str,
+    pub profile_commands: &'static str,
+    pub config_ok: &'static str,
+    pub config_commands: &'static str,
+    pub paused: &'static str,
+    pub tui_keys: &'static str,
+}
+
+/// Editor status lines (see `ipc::editor`)
+pub struct EditorTr {
+    pub focus: &'static str,
+    pub short_break: &'static str,
+    pub long_break: &'static str,
+}
+
+pub struct TrayTr {
+    pub focus: &'static str,
+    pub short_break: &'static str,
+    pub long_break: &'static str,
+    pub pause: &'static str,
+    pub continue_: &'static str,
+    pub start: &'static str,
+    pub ready: &'static str,
+    pub close_app: &'static str,
+    pub what_to_do: &'static str,
+    pub minimize_to_tray: &'static str,
+    pub quit: &'static str,
+    pub show_window: &'static str,
+    pub start_focus: &'static str,
+    pub start_break: &'static str,
+    pub show_stats: &'static str,
+}
+
+pub struct NotifTr {
+    pub focus_complete: &'static str,
+    pub time_for_break: &'static str,
+    pub break_over: &'static str,
+    pub ready_to_focus: &'static str,
+    pub long_break_over: &'static str,
+    pub back_to_work: &'static str,
+    pub daily_goal_reached: &'static str,
+    pub stats_cleared: &'static str,
+    pub session_removed: &'static str,
+    pub export_complete: &'static str,
+    pub export_failed: &'static str,
+    pub export_statistics: &'static str,
+    pub defaults_restored: &'static str,
+    pub settings_saved: &'static str,
+    pub sounds_muted: &'static str,
+    pub sounds_unmuted: &'static str,
+    pub profile_switched: &'static str,
+    pub profile_saved: &'static str,
+    pub late_focus: &'static str,
+    pub strict_focus: &'static str,
+    pub todoist_failed: &'static str,
+    pub taskwarrior_failed: &'static str,
+    pub daily_note_failed: &'static str,
+    pub notion_failed: &'static str,
+    pub weekly_report: &'static str,
+    pub weekly_report_failed: &'static str,
+    pub meeting_at: &'static str,
+    pub meeting_shortened: &'static str,
+    pub meeting_paused: &'static str,
+    pub todoist_comment: &'static str,
+    pub taskwarrior_annotation: &'static str,
+    pub action_start_break: &'static str,
+    pub action_start_focus: &'static str,
+    pub action_snooze: &'static str,
+    pub action_skip: &'static str,
+}
+
+pub struct AwayTr {
+    pub title: &'static str,
+    pub away_for: &'static str,
+    pub question: &'static str,
+    pub count_it: &'static str,
+    pub discard: &'static str,
+}
+
+/// Problems shown in the banner while the app runs
+pub struct ErrorsTr {
+    pub retry: &'static str,
+    pub database: &'static str,
+    pub record_session: &'static str,
+    pub config_save: &'static str,
+    pub autostart: &'static str,
+    pub hotkeys: &'static str,
+    pub export: &'static str,
+    pub ipc: &'static str,
+    /// `{port}` is the configured port, `{fallback}` the one used instead
+    pub ipc_fallback: &'static str,
+    pub group: &'static str,
+}
+
+pub struct CommonTr {
+    pub config_problems: &'static str,
+    pub dismiss: &'static str,
+    pub back: &'static str,
+    pub cancel: &'static str,
+    pub reset: &'static str,
+    pub min: &'static str,
+    pub pin_window: &'static str,
+    pub unpin_window: &'static str,
+    pub mute_sounds: &'static str,
+    pub unmute_sounds: &'static str,
+    pub mini_mode: &'static str,
+    pub exit_mini_mode: &'static str,
+    pub pop_out_timer: &'static str,
+    pub kiosk_mode: &'static str,
+    pub dock_timer: &'static str,
+    /// `{port}` is the port members connect to
+    pub group_hosting: &'static str,
+    /// `{host}` is the host's name
+    pub group_following: &'static str,
+    /// `{address}` is the host's address
+    pub group_connecting: &'static str,
+    /// `{count}` is the number of people in the group
+    pub group_participants: &'static str,
+    pub bubble_hint: &'static str,
+    pub undo: &'static str,
+    pub logs: &'static str,
+    pub refresh: &'static str,
+    pub copy: &'static str,
+}
+
+// ── Helper methods ────────────────────────────────────────────────
+
+impl Tr {
+    /// Get session type label
+    pub fn session_label(&self, st: crate::core::SessionType) -> &'static str {
+        match st {
+            crate::core::SessionType::Work => self.timer.focus,
+            crate::core::SessionType::ShortBreak => self.timer.short_break,
+            crate::core::SessionType::LongBreak => self.timer.long_break,
+        }
+    }
+
+    /// Get priority label
+    pub fn priority_label(&self, p: crate::data::todo::Priority) -> &'static str {
+        match p {
+            crate::data::todo::Priority::None => self.todo.priority_none,
+            crate::data::todo::Priority::Low => self.todo.priority_low,
+            crate::data::todo::Priority::Medium => self.todo.priority_medium,
+            crate::data::todo::Priority::High => self.todo.priority_high,
+            crate::data::todo::Priority::Urgent => self.todo.priority_urgent,
+        }
+    }
+
+    /// Get localized theme mode name
+    pub fn theme_name(&self, mode: crate::data::ThemeMode) -> &'static str {
+        use crate::data::ThemeMode;
+        match mode {
+            ThemeMode::System => self.settings.theme_system,
+            ThemeMode::Light => self.settings.theme_light,
+            ThemeMode::Dark => self.settings.theme_dark,
+            ThemeMode::Schedule => self.settings.theme_schedule,
+            // Brand names stay the same in all languages
+            ThemeMode::CatppuccinLatte => "Catppuccin Latte",
+            ThemeMode::CatppuccinFrappe => "Catppuccin Frappé",
+            ThemeMode::CatppuccinMacchiato => "Catppuccin Macchiato",
+            ThemeMode::CatppuccinMocha => "Catppuccin Mocha",
+        }
+    }
+
+    /// Get localized accent color name
+    pub fn accent_name(&self, color: crate::data::AccentColor) -> &'static str {
+        use crate::data::AccentColor;
+        match color {
+            AccentColor::Blue => self.settings.color_blue,
+            AccentColor::Purple => self.settings.color_purple,
+            AccentColor::Rose => self.settings.color_rose,
+            AccentColor::Emerald => self.settings.color_emerald,
+            AccentColor::Amber => self.settings.color_amber,
+            AccentColor::Cyan => self.settings.color_cyan,
+            // Brand/proper names
+            AccentColor::Matrix => "Matrix",
+            AccentColor::RetroAmber => self.settings.color_retro_amber,
+            AccentColor::Synthwave => "Synthwave",
+            AccentColor::Custom(..) => self.settings.color_custom,
+        }
+    }
+
+    /// Get days of week array
+    pub fn days_of_week(&self) -> [&'static str; 7] {
+        [
+            self.stats.mon,
+            self.stats.tue,
+            self.stats.wed,
+            self.stats.thu,
+            self.stats.fri,
+            self.stats.sat,
+            self.stats.sun,
+        ]
+    }
+}
@@ -0,0 +1,554 @@
+//! Russian translations
+
+use super::*;
+
+pub(super) static RU: Tr = Tr {
+    nav: NavTr {
+        settings: "Настройки",
+        statistics: "Статистика",
+        tasks: "Задачи",
+        queue: "Очередь",
+    },
+    timer: TimerTr {
+        focus: "ФОКУС",
+        short_break: "ПЕРЕРЫВ",
+        long_break: "ДЛИННЫЙ ПЕРЕРЫВ",
+        pause: "ПАУЗА",
+        start: "СТАРТ",
+        skip: "ДАЛЕЕ",
+        session: "Сессия",
+        break_overlay_title: "Время отдохнуть",
+        break_overlay_hint: "Отойдите от экрана",
+        custom: "Своё время…",
+        custom_hint: "Запустить разовую сессию любой длины",
+        drag_hint: "Потяните по кольцу, чтобы задать время",
+        intent_hint: "Над чем будете работать?",
+    },
+    settings: SettingsTr {
+        title: "Настройки",
+        timer: "Таймер",
+        focus_duration: "Длительность фокуса",
+        short_break: "Короткий перерыв",
+        long_break: "Длинный перерыв",
+        sessions_before_long: "Сессий до длинного перерыва",
+        auto_start_breaks: "Автозапуск перерывов",
+        auto_start_pomodoros: "Автозапуск помодоро",
+        require_intent: "Спрашивать цель перед фокусом",
+        strict_focus: "Строгий фокус: без пауз и пропусков",
+        min_recorded: "Не записывать фокус короче",
+        sounds: "Звуки",
+        volume: "Громкость",
+        sound: "Звук",
+        tick_sound: "Звук тиканья",
+        sound_file: "Свой звуковой файл",
+        tick_file: "Свой файл тиканья",
+        tick_speed_up: "Ускорять последние 10 секунд",
+        chime_halfway: "Сигнал на середине фокуса",
+        chime_every: "Сигнал каждые",
+        sound_files_hint: "Файл .wav, .ogg или .mp3 вместо встроенного звука",
+        audio_files: "Аудиофайлы",
+        sound_file_unreadable: "Не удаётся прочитать файл",
+        sound_file_too_large: "Файл больше 10 МБ",
+        sound_file_unsupported: "Этот файл не воспроизводится",
+        appearance: "Внешний вид",
+        theme: "Тема",
+        accent_color: "Акцентный цвет",
+        retro_themes: "Ретро темы",
+        window_opacity: "Прозрачность окна",
+        background_image: "Фоновое изображение",
+        choose_image: "Выбрать…",
+        remove_image: "Убрать",
+        png_images: "Изображения PNG",
+        background_blur: "Размытие",
+        background_dim: "Затемнение",
+        quick_start_label_hint: "Название (необязательно)",
+        quick_start_tag_hint: "Тег",
+        add_quick_start: "Добавить кнопку",
+        remove_quick_start: "Удалить кнопку",
+        accessibility: "Доступность",
+        high_contrast: "Высокий контраст",
+        reduced_motion: "Уменьшить анимации",
+        power_saver: "Экономить заряд батареи",
+        system: "Система",
+        start_with_windows: "Запуск с Windows",
+        dnd_during_focus: "«Не беспокоить» во время фокуса",
+        pause_media_during_focus: "Пауза медиа во время фокуса",
+        close_to_tray: "Закрытие сворачивает в трей",
+        always_on_top: "Поверх всех окон",
+        raise_on_complete: "Показывать окно по завершении",
+        title_countdown: "Отсчёт в заголовке окна",
+        kiosk_message: "Сообщение в полноэкранном режиме",
+        track_apps: "Учитывать фокус по приложениям (только на этом компьютере)",
+        reminders: "Напоминать, пока не начнётся следующая сессия",
+        reminder_steps: "Напомнить через (минуты)",
+        reminder_sound: "Звук при повторных напоминаниях",
+        hue: "Philips Hue",
+        hue_enabled: "Свет показывает сессию",
+        hue_bridge_ip: "IP моста",
+        hue_app_key: "Ключ приложения",
+        hue_target: "ID лампы (group:N для комнаты)",
+        calendar: "Календарь",
+        calendar_enabled: "Следить за встречами в календаре",
+        calendar_url: "Ссылка на ICS",
+        meeting_overlap: "Фокус пересекается со встречей",
+        meeting_warn: "Предупредить",
+        meeting_shorten: "Закончить раньше",
+        meeting_pause: "Поставить на паузу",
+        slack: "Slack",
+        slack_enabled: "Показывать фокус в Slack",
+        slack_token: "Токен пользователя (xoxp-...)",
+        slack_status_text: "Статус ({until} — время окончания)",
+        slack_status_emoji: "Эмодзи статуса",
+        slack_dnd: "Приостанавливать уведомления Slack",
+        todoist: "Todoist",
+        todoist_enabled: "Загружать задачи из Todoist",
+        todoist_token: "API-токен",
+        todoist_filter: "Фильтр (пусто — все задачи)",
+        todoist_comment: "Комментировать после фокуса",
+        todoist_close: "Закрывать выполненные здесь задачи",
+        taskwarrior: "Taskwarrior",
+        taskwarrior_enabled: "Загружать задачи из Taskwarrior",
+        taskwarrior_command: "Команда",
+        taskwarrior_filter: "Фильтр (пусто — все задачи)",
+        taskwarrior_track_time: "Запускать и останавливать задачи вместе с фокусом",
+        taskwarrior_annotate: "Добавлять аннотации о помидорах",
+        obsidian: "Obsidian",
+        obsidian_enabled: "Записывать сессии в ежедневную заметку",
+        obsidian_vault: "Папка хранилища",
+        obsidian_folder: "Папка ежедневных заметок (пусто — корень)",
+        obsidian_date_format: "Формат имени заметки",
+        obsidian_template: "Шаблон строки",
+        obsidian_log_breaks: "Записывать и перерывы",
+        activitywatch: "ActivityWatch",
+        activitywatch_enabled: "Отправлять сессии в ActivityWatch",
+        activitywatch_url: "Адрес сервера",
+        activitywatch_bucket: "Корзина (пусто — своя для каждого компьютера)",
+        activitywatch_breaks: "Отправлять и перерывы",
+        notion: "Notion",
+        notion_enabled: "Добавлять сессии фокуса в Notion",
+        notion_token: "Секрет интеграции",
+        notion_database: "Id базы данных",
+        notion_title_property: "Свойство заголовка",
+        notion_date_property: "Свойство даты",
+        notion_duration_property: "Свойство минут",
+        notion_tag_property: "Свойство тега",
+        telegram: "Telegram",
+        telegram_enabled: "Управлять таймером из Telegram",
+        telegram_token: "Токен бота",
+        telegram_chat: "Id чата",
+        telegram_notify: "Сообщать о завершении сессии",
+        group: "Групповая сессия",
+        group_enabled: "Общий таймер в локальной сети",
+        group_role: "Роль",
+        group_host: "Ведущий",
+        group_follow: "Следовать за ведущим",
+        group_address: "Адрес ведущего",
+        group_name: "Ваше имя",
+        group_code: "Код группы",
+        email: "Письмо за неделю",
+        email_enabled: "Присылать отчёт за неделю по понедельникам",
+        email_server: "SMTP-сервер (smtps://host:465)",
+        email_username: "Имя пользователя",
+        email_password: "Пароль",
+        email_from: "От",
+        email_to: "Кому",
+        email_send_at: "Отправлять в",
+        plugins: "Плагины",
+        plugins_enabled: "Запускать плагины",
+        plugins_hint: "Плагины загружаются из",
+        no_plugins: "Плагины не установлены",
+        goals: "Цели",
+        daily_goal: "Дневная цель",
+        pomodoros: "помодоро",
+        notify_goal_reached: "Уведомлять о достижении цели",
+        global_hotkeys: "Горячие клавиши",
+        enable_hotkeys: "Включить горячие клавиши",
+        toggle_start_pause: "Старт/пауза",
+        skip_session: "Пропустить сессию",
+        reset_timer: "Сбросить таймер",
+        mute_hotkey: "Выключить/включить звуки",
+        press_keys: "Нажмите клавиши…",
+        hotkey_record_hint: "Нажмите, затем новое сочетание (Esc — отмена)",
+        hotkey_in_use: "Уже используется другим действием",
+        hotkey_unsupported:
+            "Используйте Ctrl, Alt или Shift с буквой, цифрой, F1–F12, Space или Enter",
+        media_keys: "Медиаклавиши (Пуск/Пауза, Далее)",
+        media_keys_unless_playing: "Только когда медиа не играет",
+        command_line: "Командная строка",
+        control_from_terminal: "Управление таймером из терминала:",
+        copy_path_command: "Копировать команду PATH",
+        copy_path_tooltip: "Копировать команду для добавления в PATH",
+        run_copied_command: "Выполните команду в PowerShell, затем перезапустите терминал",
+        presets: "Пресеты",
+        profiles: "Профили",
+        profiles_hint: "У каждого профиля свои длительности, звуки, цель и горячие клавиши",
+        no_profile: "Без профиля",
+        profile_name_hint: "Название профиля",
+        save_profile: "Сохранить текущие настройки",
+        use_profile: "Выбрать",
+        delete_profile: "Удалить профиль",
+        weekday_plans: "План по дням недели",
+        weekday_plans_enabled: "Планировать по дням",
+        weekday_plans_hint: "Применяется перед первой сессией дня. Цель «—» оставляет обычную дневную цель.",
+        day_usual: "Обычный",
+        working_hours: "Рабочие часы",
+        working_hours_enabled: "Задать рабочие часы",
+        working_hours_hint: "В рабочие дни статистика за сегодня учитывает только сессии в эти часы.",
+        work_starts: "Начало дня",
+        work_ends: "Конец дня",
+        hold_auto_start: "Без автозапуска вне их",
+        warn_late_focus: "Предупреждать о поздних сессиях",
+        rest_days: "Дни отдыха",
+        rest_days_hint: "Без уведомлений о цели, серия через них не прерывается.",
+        reset_to_defaults: "Сбросить по умолчанию",
+        search_hint: "Поиск настроек…",
+        no_results: "Ничего не найдено",
+        notification_text: "Текст уведомлений",
+        notification_placeholders: "Оставьте пустым для текста по умолчанию. Подстановки: {session_type}, {next_duration} (минуты), {today_count}",
+        integrations: "Интеграции",
+        advanced: "Дополнительно",
+        language: "Язык",
+        language_restart_hint: "",
+        test_sound: "Тест звука",
+        ambient: "Фоновые звуки",
+        ambient_hint: "Играют во время фокуса, на перерывах выключаются",
+        ambient_rain: "Дождь",
+        ambient_cafe: "Кафе",
+        ambient_brown_noise: "Коричневый шум",
+        theme_system: "Авто",
+        theme_light: "Светлая",
+        theme_dark: "Тёмная",
+        theme_schedule: "По времени суток",
+        follow_sun: "По восходу и закату",
+        light_from: "Светлая с",
+        dark_from: "Тёмная с",
+        time_format_hint: "Формат ЧЧ:ММ",
+        latitude: "Широта",
+        color_blue: "Синий",
+        color_purple: "Фиолетовый",
+        color_rose: "Розовый",
+        color_emerald: "Изумрудный",
+        color_amber: "Янтарный",
+        color_cyan: "Голубой",
+        color_retro_amber: "Ретро янтарный",
+        color_custom: "Свой цвет",
+        preset_classic: "Классический",
+        preset_short: "Короткий",
+        preset_long: "Длинный",
+        preset_applied: "пресет",
+        preset_behavior_hint: "Что пресет переключает вместе с длительностями",
+        behavior_keep: "Не менять",
+        behavior_on: "Вкл",
+        behavior_off: "Выкл",
+    },
+    stats: StatsTr {
+        title: "Статистика",
+        current_session: "Текущая сессия",
+        statistics: "Статистика",
+        week_activity: "Активность за неделю",
+        quick_start: "Быстрый старт",
+        this_week: "Эта неделя",
+        today: "Сегодня",
+        daily_goal: "Дневная цель",
+        current_streak: "Текущая серия",
+        total: "Всего",
+        all_time: "За всё время",
+        best_streak: "Лучшая серия",
+        total_sessions: "Всего сессий",
+        running: "Активно",
+        completed: "Завершено",
+        paused: "Пауза",
+        done: "Готово!",
+        days: "дн.",
+        hours: "часов",
+        sessions: "сессий",
+        focus_time: "время фокуса",
+        todays_focus: "Фокус сегодня",
+        goal_reached: "Цель достигнута!",
+        best: "Лучший",
+        export_as: "Экспорт в",
+        total_label: "всего",
+        reset_title: "Сбросить статистику?",
+        reset_confirm: "Это безвозвратно удалит всю\nисторию сессий и статистику.",
+        completed_label: "завершено",
+        reset_all_hover: "Сбросить всю статистику",
+        undo_last_hover: "Отменить последнюю сессию",
+        export_hover: "Экспорт статистики",
+        overview: "Обзор",
+        month: "Месяц",
+        this_month: "Этот месяц",
+        history: "История",
+        yesterday: "Вчера",
+        no_sessions: "В этот день сессий не было",
+        interrupted: "Прервана",
+        by_tag: "Фокус по тегам",
+        by_app: "Фокус по приложениям",
+        today_timeline: "Сегодня по часам",
+        vs_last_week: "Эта неделя и прошлая",
+        last_week: "Прошлая неделя",
+        compare_focus: "Фокус",
+        compare_pomodoros: "Помидоры",
+        completion_rate: "Завершено",
+        day_plan: "План дня",
+        plan_kept: "Выполнено",
+        plan_days: "Дней с планом",
+        untagged: "Без тега",
+        other_tags: "Другие",
+        no_focus_time: "Времени фокуса пока нет",
+        mon: "Пн",
+        tue: "Вт",
+        wed: "Ср",
+        thu: "Чт",
+        fri: "Пт",
+        sat: "Сб",
+        sun: "Вс",
+    },
+    todo: TodoTr {
+        completed_label: "Завершённые",
+        miscellaneous: "Разное",
+        no_tasks: "Нет задач",
+        create_first_task: "Создайте первую задачу ниже",
+        new_task_hint: "Новая задача...",
+        name_hint: "Название...",
+        new_workspace: "Новый workspace",
+        project: "Проект",
+        project_name_hint: "Название проекта...",
+        rename: "Переименовать",
+        delete: "Удалить",
+        delete_project: "Удалить проект",
+        delete_project_n: "Удалить проект ({} задач)",
+        edit: "Редактировать",
+        add_to_queue: "В очередь",
+        move_to: "Переместить в...",
+        no_project: "Без проекта",
+        priority: "Приоритет",
+        title_hint: "Заголовок...",
+        description_hint: "Описание (markdown)...",
+        save: "Сохранить",
+        priority_none: "\u{2014}",
+        priority_low: "Низкий",
+        priority_medium: "Средний",
+        priority_high: "Высокий",
+        priority_urgent: "Срочный",
+        tasks_default_workspace: "Задачи",
+    },
+    queue: QueueTr {
+        title: "Очередь",
+        empty: "Очередь пуста",
+        empty_hint: "Добавляйте задачи через меню \u{22EE} в списке задач",
+        clear: "Очистить очередь",
+    },
+    tasks: TasksTr {
+        title: "Задачи",
+        add_hint: "Новая задача, Enter — добавить",
+        empty: "Задач пока нет",
+        empty_hint: "Введите задачу выше и укажите, сколько помидоров она займёт",
+        select_hint: "Нажмите, чтобы работать над этой задачей",
+        estimate_hint: "Выполнено / запланировано помидоров",
+        open: "Управление задачами",
+    },
+    plan: PlanTr {
+        title: "План дня",
+        today: "Сегодня",
+        tomorrow: "Завтра",
+        empty: "Планировать нечего",
+        empty_hint: "Добавьте задачи в список задач и распределите здесь помидоры",
+        add: "Добавить в план",
+        done_hint: "Выполнено / запланировано помидоров",
+        open: "Спланировать день",
+        progress: "План {done}/{planned}",
+    },
+    tray: TrayTr {
+        focus: "Фокус",
+        short_break: "Короткий перерыв",
+        long_break: "Длинный перерыв",
+        pause: "Пауза",
+        continue_: "Продолжить",
+        start: "Старт",
+        ready: "Готов",
+        close_app: "Закрыть приложение?",
+        what_to_do: "Что вы хотите сделать?",
+        minimize_to_tray: "  Свернуть в трей  ",
+        quit: "  Выход  ",
+        show_window: "Показать окно",
+        start_focus: "Начать фокус",
+        start_break: "Начать перерыв",
+        show_stats: "Показать статистику",
+    },
+    notif: NotifTr {
+        focus_complete: "Фокус завершён!",
+        time_for_break: "Время для перерыва.",
+        break_over: "Перерыв окончен",
+        ready_to_focus: "Готовы снова сфокусироваться?",
+        long_break_over: "Длинный перерыв окончен",
+        back_to_work: "Пора вернуться к работе!",
+        daily_goal_reached: "Дневная цель достигнута!",
+        stats_cleared: "Вся статистика была очищена.",
+        session_removed: "Последняя сессия удалена из статистики.",
+        export_complete: "Экспорт завершён",
+        export_failed: "Ошибка экспорта",
+        export_statistics: "Экспорт статистики",
+        defaults_restored: "Настройки по умолчанию восстановлены",
+        settings_saved: "Настройки сохранены",
+        sounds_muted: "Звуки выключены",
+        sounds_unmuted: "Звуки включены",
+        profile_switched: "Профиль",
+        profile_saved: "Профиль сохранён",
+        late_focus: "Фокус вне рабочих часов",
+        strict_focus: "Строгий фокус: сначала завершите сессию",
+        todoist_failed: "Ошибка синхронизации Todoist",
+        taskwarrior_failed: "Ошибка команды Taskwarrior",
+        daily_note_failed: "Не удалось записать ежедневную заметку",
+        notion_failed: "Ошибка синхронизации Notion, повтор позже",
+        weekly_report: "Отчёт за неделю",
+        weekly_report_failed: "Не удалось отправить отчёт за неделю",
+        meeting_at: "Встреча в",
+        meeting_shortened: "Сессия сокращена из-за встречи",
+        meeting_paused: "Пауза на встречу",
+        todoist_comment: "Помидор завершён",
+        taskwarrior_annotation: "Помидор",
+        action_start_break: "Начать перерыв",
+        action_start_focus: "Начать фокус",
+        action_snooze: "Отложить на 5 мин",
+        action_skip: "Пропустить",
+    },
+    away: AwayTr {
+        title: "С возвращением",
+        away_for: "Таймер был приостановлен, пока вас не было",
+        question: "Засчитать это время как фокус?",
+        count_it: "Засчитать",
+        discard: "Не засчитывать",
+    },
+    common: CommonTr {
+        config_problems: "Проблемы в config.toml",
+        dismiss: "Скрыть",
+        back: "Назад",
+        cancel: "Отмена",
+        reset: "Сбросить",
+        min: "мин",
+        pin_window: "Закрепить окно (поверх всех)",
+        unpin_window: "Открепить окно (снять поверх всех)",
+        mute_sounds: "Выключить звуки (M)",
+        unmute_sounds: "Включить звуки (M)",
+        mini_mode: "Мини-режим",
+        exit_mini_mode: "Двойной щелчок — обычный режим",
+        pop_out_timer: "Открепить таймер",
+        kiosk_mode: "Полноэкранный режим",
+        dock_timer: "Вернуть таймер",
+        group_hosting: "Групповая сессия на порту {port}",
+        group_following: "Ведущий: {host}",
+        group_connecting: "Подключение к {address}",
+        group_participants: "Участников: {count}",
+        bubble_hint: "Двойной щелчок — вернуть в окно",
+        undo: "Отменить",
+        logs: "Журнал",
+        refresh: "Обновить",
+        copy: "Копировать",
+    },
+    errors: ErrorsTr {
+        retry: "Повторить",
+        database: "Сессии не сохраняются",
+        record_session: "Сессия не сохранилась",
+        config_save: "Настройки не сохранились",
+        autostart: "Не удалось изменить автозапуск",
+        hotkeys: "Часть глобальных горячих клавиш не работает",
+        export: "Ошибка экспорта",
+        ipc: "Командная строка не может связаться с приложением",
+        ipc_fallback: "порт {port} занят другой программой, используется {fallback}",
+        group: "Не удалось начать групповую сессию",
+    },
+    cli: CliTr {
+        tagline: "Современный помодоро-таймер",
+        help: "\
+ИСПОЛЬЗОВАНИЕ: pomodorust [--profile <имя>] [КОМАНДА]
+
+ПАРАМЕТРЫ:
+  --profile <имя>     Отдельные настройки, база и приложение (буквы, цифры, - и _)
+
+КОМАНДЫ:
+  start [-s <type>]   Запустить таймер (type: work, short, long)
+        [-d <minutes>] [-t <tag>]  Своя длительность и метка сессии
+        [--at <HH:MM>]             Запустить позже
+  pause [--for <duration>]         Пауза (и продолжить через, например, 10m, 1h30m)
+  resume              Продолжить таймер
+  toggle              Старт/пауза
+  stop                Остановить и сбросить таймер
+  skip                Перейти к следующей сессии
+  status              Текущее состояние таймера
+  stats [-p <period>] Статистика (period: today, week, month, year, all)
+        [--from <date>] [--to <date>]  Свой период (YYYY-MM-DD, включительно)
+  mute [on|off|toggle]           Выключить звуки (по умолчанию: on)
+  unmute                         Включить звуки
+  dnd [on|off|toggle]            Не беспокоить: без уведомлений (по умолчанию: toggle)
+  always-on-top [on|off|toggle]  Поверх других окон (по умолчанию: toggle)
+  kiosk [on|off|toggle] [-m <text>]  Таймер на весь экран для проектора, с сообщением (по умолчанию: on)
+  unschedule          Отменить запланированные --at/--for действия
+  task add <title> [-n <pomodoros>]  Добавить задачу (и поставить в очередь)
+  task list [--all]                  Открытые (или все) задачи
+  task start <id>                    Начать работу над задачей
+  task done <id>                     Отметить задачу выполненной
+  profile list                       Список профилей настроек
+  profile use <name>                 Переключиться на профиль настроек
+  config check                       Показать проблемы в config.toml
+  logs [-n <lines>] [--path]         Последние строки журнала (или путь к файлу)
+  show [<view>]       Показать окно (view: timer, queue, tasks, plan, stats, settings)
+  quit                Закрыть запущенное приложение
+  ping                Проверить, запущен ли GUI
+  watch               Выводить события таймера как JSON-строки
+  tui                 Полноэкранный таймер в терминале
+
+Запустите без аргументов, чтобы открыть GUI.
+",
+        error: "Ошибка",
+        not_running: "PomodoRust не запущен. Сначала откройте приложение.",
+        port_taken: "Порт {port} занят другой программой, а не PomodoRust. Укажите свободный порт в POMODORUST_PORT.",
+        invalid_profile: "Неверное имя профиля (буквы, цифры, - и _)",
+        running: "PomodoRust запущен",
+        focus: "Фокус",
+        short_break: "Перерыв",
+        long_break: "Длинный перерыв",
+        session: "Сессия",
+        progress: "Прогресс",
+        no_tasks: "Задач нет",
+        current: "текущая",
+        queued: "в очереди",
+        today: "Сегодня",
+        this_week: "Эта неделя",
+        this_month: "Этот месяц",
+        this_year: "Этот год",
+        all_time: "За всё время",
+        range_to: "по",
+        focus_time: "Время фокуса",
+        pomodoros: "Помидоры",
+        daily_goal: "Цель на день",
+        streak: "Серия",
+        days: "дн.",
+        best: "лучшая",
+        unknown_command: "Неизвестная команда",
+        see_help: "Справка: 'pomodorust --help'.",
+        no_logs: "Журнал пока пуст",
+        invalid_duration: "Неверная длительность",
+        expected_minutes: "ожидаются минуты",
+        expected_span: "например, 10m, 90s, 1h30m",
+        invalid_time: "Неверное время",
+        expected_clock: "ожидается ЧЧ:ММ",
+        invalid_value: "Неверное значение",
+        expected_switch: "ожидается on, off или toggle",
+        invalid_count: "Неверное число помидоров",
+        expected_task_id: "Ожидается номер задачи (см. 'pomodorust task list')",
+        task_add_usage: "Использование: pomodorust task add <title> [-n <pomodoros>]",
+        task_commands: "Ожидается одно из: add, list, start, done",
+        no_profiles: "Профилей нет (сохраните профиль в Настройки > Таймер)",
+        profile_use_usage: "Использование: pomodorust profile use <name>",
+        profile_commands: "Ожидается одно из: list, use",
+        config_ok: "проблем не найдено",
+        config_commands: "Ожидается: check",
+        paused: "Пауза",
+        tui_keys: "пробел старт/пауза · s пропустить · x стоп · q выход",
+    },
+    editor: EditorTr {
+        focus: "Фокус",
+        short_break: "Перерыв",
+        long_break: "Длинный перерыв",
+    },
+};
@@ -0,0 +1,158 @@
+//! ActivityWatch reporting
+//!
+//! Sends every completed session as an event to a bucket on the local
+//! ActivityWatch server, next to the window and AFK watchers' data. The
+//! bucket is created on first use; events carry the session type, task,
+//! note and tag.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use super::{http_client, CompletedSession};
+use crate::core::SessionType;
+use crate::data::ActivityWatchConfig;
+
+/// Bucket type of the events, after the `app.*` types of other watchers
+const BUCKET_TYPE: &str = "app.pomodorust.session";
+
+/// Background ActivityWatch client
+pub struct ActivityWatch {
+    tx: Sender<CompletedSession>,
+    include_breaks: bool,
+}
+
+impl ActivityWatch {
+    /// Spawn the request thread for the given configuration
+    pub fn start(config: ActivityWatchConfig) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let include_breaks = config.include_breaks;
+
+        let spawned = thread::Builder::new()
+            .name("activitywatch".into())
+            .spawn(move || worker_loop(rx, config));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start ActivityWatch integration: {}", e);
+        }
+
+        Self { tx, include_breaks }
+    }
+
+    /// Send a completed session
+    pub fn record(&self, session: &CompletedSession) {
+        if session.session_type == SessionType::Work || self.include_breaks {
+            let _ = self.tx.send(session.clone());
+        }
+    }
+}
+
+/// Worker thread: creates the bucket once, then posts the events
+fn worker_loop(rx: Receiver<CompletedSession>, config: ActivityWatchConfig) {
+    let hostname = hostname();
+    let bucket = bucket_id(&config, &hostname);
+    let api = format!(
+        "{}/api/0/buckets/{}",
+        config.server_url.trim_end_matches('/'),
+        bucket
+    );
+    let headers = [("Content-Type", "application/json")];
+
+    let mut bucket_ready = false;
+    for session in rx {
+        if !bucket_ready {
+            let body = serde_json::json!({
+                "client": "pomodorust",
+                "type": BUCKET_TYPE,
+                "hostname": hostname,
+            });
+            // 304 Not Modified when the bucket already exists
+            match http_client::request("POST", &api, &headers, Some(&body.to_string())) {
+                Ok(reply) if reply.is_success() || reply.status == 304 => bucket_ready = true,
+                Ok(reply) => {
+                    tracing::warn!("ActivityWatch rejected the bucket: {}", reply.body.trim());
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!("ActivityWatch unreachable: {}", e);
+                    continue;
+                }
+            }
+        }
+
+        let url = format!("{}/events", api);
+        match http_client::request("POST", &url, &headers, Some(&event_body(&session))) {
+            Ok(reply) if reply.is_success() => tracing::debug!("Session sent to ActivityWatch"),
+            Ok(reply) => tracing::warn!("ActivityWatch rejected the event: {}", reply.body.trim()),
+            Err(e) => tracing::warn!("ActivityWatch unreachable: {}", e),
+        }
+    }
+}
+
+/// Configured bucket, or one per machine named like the other watchers'
+fn bucket_id(config: &ActivityWatchConfig, hostname: &str) -> String {
+    match config.bucket.trim() {
+        "" => format!("aw-watcher-pomodorust_{}", hostname),
+        bucket => bucket.to_string(),
+    }
+}
+
+/// Events to insert for a session, an array of one
+fn event_body(session: &CompletedSession) -> String {
+    let mut data = serde_json::json!({
+        "title": session.task.as_deref().unwrap_or(session.session_type.as_str()),
+        "type": session.session_type.as_str(),
+    });
+    for (key, value) in [
+        ("task", &session.task),
+        ("note", &session.note),
+        ("tag", &session.tag),
+    ] {
+        if let Some(value) = value {
+            data[key] = value.as_str().into();
+        }
+    }
+    let duration = (session.end - session.start).num_milliseconds() as f64 / 1000.0;
+    serde_json::json!([{
+        "timestamp": session.start.to_rfc3339(),
+        "duration": duration.max(0.0),
+        "data": data,
+    }])
+    .to_string()
+}
+
+/// Name of this machine, for the bucket
+fn hostname() -> String {
+    ["HOSTNAME", "COMPUTERNAME"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .chain(std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .find(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone};
+
+    #[test]
+    fn test_event_body() {
+        let session = CompletedSession {
+            session_type: SessionType::Work,
+            start: Local.with_ymd_and_hms(2024, 3, 1, 14, 0, 0).unwrap(),
+            end: Local.with_ymd_and_hms(2024, 3, 1, 14, 25, 0).unwrap(),
+            minutes: 25,
+            task: Some("Write report".to_string()),
+            note: None,
+            tag: Some("writing".to_string()),
+        };
+        let body: serde_json::Value = serde_json::from_str(&event_body(&session)).unwrap();
+        assert_eq!(body[0]["duration"], 1500.0);
+        assert_eq!(body[0]["data"]["title"], "Write report");
+        assert_eq!(body[0]["data"]["tag"], "writing");
+        assert!(body[0]["data"].get("note").is_none());
+
+        let config = ActivityWatchConfig::default();
+        assert_eq!(bucket_id(&config, "desk"), "aw-watcher-pomodorust_desk");
+    }
+}
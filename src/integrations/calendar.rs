@@ -0,0 +1,465 @@
+//! Meetings from an ICS calendar subscription
+//!
+//! The feed is downloaded on a background thread every few minutes and the
+//! meetings around now are kept for the app to check focus sessions
+//! against. Times with a `TZID` are read as local time; all-day, cancelled
+//! and free ("transparent") events aren't meetings and are left out.
+//! Daily, weekly, monthly and yearly `RRULE`s are expanded, with their
+//! `EXDATE`s and moved occurrences.
+
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime, TimeDelta, Weekday};
+
+use super::http_client;
+use crate::data::CalendarConfig;
+
+/// Days of recurrences walked at most per event
+const MAX_RECURRENCE_DAYS: i64 = 20_000;
+
+/// An occurrence of a calendar event, in local time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Meeting {
+    pub summary: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+/// Background calendar subscription
+pub struct CalendarFeed {
+    meetings: Arc<Mutex<Vec<Meeting>>>,
+    /// Dropping it stops the thread
+    _stop: Sender<()>,
+}
+
+impl CalendarFeed {
+    /// Spawn the thread downloading the feed
+    pub fn start(config: CalendarConfig) -> Self {
+        let meetings = Arc::new(Mutex::new(Vec::new()));
+        let (stop, stopped) = mpsc::channel::<()>();
+        let url = feed_url(&config.ics_url);
+        let interval = Duration::from_secs(u64::from(config.refresh_minutes.max(1)) * 60);
+
+        let shared = Arc::clone(&meetings);
+        let spawned = thread::Builder::new()
+            .name("calendar".into())
+            .spawn(move || loop {
+                match fetch(&url) {
+                    Ok(found) => {
+                        tracing::debug!("Calendar: {} meetings around now", found.len());
+                        if let Ok(mut meetings) = shared.lock() {
+                            *meetings = found;
+                        }
+                    }
+                    Err(e) => tracing::warn!("Calendar feed unavailable: {}", e),
+                }
+                if stopped.recv_timeout(interval) != Err(RecvTimeoutError::Timeout) {
+                    break;
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start calendar integration: {}", e);
+        }
+
+        Self {
+            meetings,
+            _stop: stop,
+        }
+    }
+
+    /// First meeting still going on or starting after `now` whose start is
+    /// before `until`
+    pub fn next_meeting(&self, now: NaiveDateTime, until: NaiveDateTime) -> Option<Meeting> {
+        let meetings = self.meetings.lock().ok()?;
+        meetings
+            .iter()
+            .find(|m| m.end > now && m.start < until)
+            .cloned()
+    }
+}
+
+/// `webcal://` links are plain HTTPS feeds
+fn feed_url(url: &str) -> String {
+    let url = url.trim();
+    match url.strip_prefix("webcal://") {
+        Some(rest) => format!("https://{}", rest),
+        None => url.to_string(),
+    }
+}
+
+/// Download the feed and keep the meetings from yesterday to two days ahead
+fn fetch(url: &str) -> Result<Vec<Meeting>, String> {
+    let reply = http_client::request("GET", url, &[], None)?;
+    if !reply.is_success() {
+        return Err(format!("HTTP {}", reply.status));
+    }
+    let now = Local::now().naive_local();
+    Ok(parse_ics(
+        &reply.body,
+        now - TimeDelta::days(1),
+        now + TimeDelta::days(2),
+    ))
+}
+
+/// A VEVENT as written in the file
+#[derive(Default)]
+struct Event {
+    uid: String,
+    summary: String,
+    start: Option<NaiveDateTime>,
+    end: Option<NaiveDateTime>,
+    duration: Option<TimeDelta>,
+    all_day: bool,
+    skipped: bool,
+    rrule: Option<String>,
+    exdates: Vec<NaiveDateTime>,
+    recurrence_id: Option<NaiveDateTime>,
+}
+
+/// Meeting occurrences overlapping `from..to`, sorted by start
+pub fn parse_ics(text: &str, from: NaiveDateTime, to: NaiveDateTime) -> Vec<Meeting> {
+    let events = read_events(text);
+
+    // Moved or changed occurrences replace the ones of their series
+    let moved: Vec<(String, NaiveDateTime)> = events
+        .iter()
+        .filter_map(|e| Some((e.uid.clone(), e.recurrence_id?)))
+        .collect();
+
+    let mut meetings = Vec::new();
+    for event in &events {
+        let Some(start) = event.start else { continue };
+        if event.all_day || event.skipped {
+            continue;
+        }
+        let length = match (event.end, event.duration) {
+            (Some(end), _) => end - start,
+            (None, Some(duration)) => duration,
+            (None, None) => TimeDelta::zero(),
+        };
+
+        let starts = match (&event.rrule, event.recurrence_id) {
+            (Some(rule), None) => occurrences(start, rule, to),
+            _ => vec![start],
+        };
+        for occurrence in starts {
+            let excluded = event.exdates.contains(&occurrence)
+                || (event.recurrence_id.is_none()
+                    && moved.contains(&(event.uid.clone(), occurrence)));
+            let end = occurrence + length;
+            if !excluded && end > from && occurrence < to {
+                meetings.push(Meeting {
+                    summary: event.summary.clone(),
+                    start: occurrence,
+                    end,
+                });
+            }
+        }
+    }
+    meetings.sort_by_key(|m| m.start);
+    meetings
+}
+
+fn read_events(text: &str) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut current: Option<Event> = None;
+    let mut nested = 0usize;
+
+    for line in unfold(text) {
+        let Some((name_and_params, value)) = line.split_once(':') else {
+            continue;
+        };
+        let mut parts = name_and_params.split(';');
+        let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+        let params: Vec<&str> = parts.collect();
+
+        match (name.as_str(), value) {
+            ("BEGIN", "VEVENT") => current = Some(Event::default()),
+            ("END", "VEVENT") => events.extend(current.take()),
+            // Alarms inside an event have properties of their own
+            ("BEGIN", _) if current.is_some() => nested += 1,
+            ("END", _) if current.is_some() => nested = nested.saturating_sub(1),
+            _ => {}
+        }
+        let Some(event) = current.as_mut().filter(|_| nested == 0) else {
+            continue;
+        };
+        match name.as_str() {
+            "UID" => event.uid = value.to_string(),
+            "SUMMARY" => event.summary = unescape(value),
+            "DTSTART" => {
+                event.all_day = is_date(&params, value);
+                event.start = parse_time(value);
+            }
+            "DTEND" => event.end = parse_time(value),
+            "DURATION" => event.duration = parse_duration(value),
+            "STATUS" => event.skipped |= value.eq_ignore_ascii_case("CANCELLED"),
+            "TRANSP" => event.skipped |= value.eq_ignore_ascii_case("TRANSPARENT"),
+            "RRULE" => event.rrule = Some(value.to_string()),
+            "EXDATE" => event
+                .exdates
+                .extend(value.split(',').filter_map(parse_time)),
+            "RECURRENCE-ID" => event.recurrence_id = parse_time(value),
+            _ => {}
+        }
+    }
+    events
+}
+
+/// Join folded lines (continuations start with a space or tab)
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_end_matches('\r');
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+fn is_date(params: &[&str], value: &str) -> bool {
+    params.iter().any(|p| p.eq_ignore_ascii_case("VALUE=DATE")) || value.trim().len() == 8
+}
+
+/// `20240301T140000` (local), `20240301T130000Z` (UTC) or `20240301`
+fn parse_time(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix('Z') {
+        let time = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(time.and_utc().with_timezone(&Local).naive_local());
+    }
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y%m%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)
+        })
+}
+
+/// `PT1H30M`, `P1D`, `PT45M`, ...
+fn parse_duration(value: &str) -> Option<TimeDelta> {
+    let value = value.trim().strip_prefix('+').unwrap_or(value.trim());
+    let rest = value.strip_prefix('P')?;
+    let mut total = TimeDelta::zero();
+    let mut number = String::new();
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match unit {
+                    'W' => TimeDelta::weeks(n),
+                    'D' => TimeDelta::days(n),
+                    'H' => TimeDelta::hours(n),
+                    'M' => TimeDelta::minutes(n),
+                    'S' => TimeDelta::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+    Some(total)
+}
+
+/// Starts of a recurring event up to `to`
+fn occurrences(start: NaiveDateTime, rule: &str, to: NaiveDateTime) -> Vec<NaiveDateTime> {
+    let mut freq = "";
+    let mut interval = 1i64;
+    let mut count: Option<usize> = None;
+    let mut until: Option<NaiveDateTime> = None;
+    let mut by_day: Vec<Weekday> = Vec::new();
+    for part in rule.split(';') {
+        let Some((key, value)) = part.split_once('=') else {
+            continue;
+        };
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => freq = value,
+            "INTERVAL" => interval = value.parse().unwrap_or(1).max(1),
+            "COUNT" => count = value.parse().ok(),
+            "UNTIL" => until = parse_time(value),
+            "BYDAY" => by_day = value.split(',').filter_map(weekday).collect(),
+            _ => {}
+        }
+    }
+
+    let first = start.date();
+    let week_start =
+        |date: NaiveDate| date - TimeDelta::days(date.weekday().num_days_from_monday().into());
+    let matches = |date: NaiveDate| {
+        let days = (date - first).num_days();
+        match freq.to_ascii_uppercase().as_str() {
+            "DAILY" => days % interval == 0,
+            "WEEKLY" => {
+                let weeks = (week_start(date) - week_start(first)).num_days() / 7;
+                let day_matches = if by_day.is_empty() {
+                    date.weekday() == first.weekday()
+                } else {
+                    by_day.contains(&date.weekday())
+                };
+                weeks % interval == 0 && day_matches
+            }
+            "MONTHLY" => {
+                let months = (date.year() - first.year()) as i64 * 12 + date.month() as i64
+                    - first.month() as i64;
+                date.day() == first.day() && months % interval == 0
+            }
+            "YEARLY" => {
+                date.month() == first.month()
+                    && date.day() == first.day()
+                    && (date.year() - first.year()) as i64 % interval == 0
+            }
+            _ => days == 0,
+        }
+    };
+
+    let mut starts = Vec::new();
+    let mut date = first;
+    for _ in 0..MAX_RECURRENCE_DAYS {
+        let occurrence = date.and_time(start.time());
+        if occurrence >= to || until.is_some_and(|until| occurrence > until) {
+            break;
+        }
+        if count.is_some_and(|count| starts.len() >= count) {
+            break;
+        }
+        if matches(date) {
+            starts.push(occurrence);
+        }
+        date = date.succ_opt().unwrap_or(date);
+    }
+    starts
+}
+
+fn weekday(code: &str) -> Option<Weekday> {
+    // Ordinals like `1MO` only make sense for monthly rules, which match by
+    // day of month here
+    let code = code.trim_start_matches(|c: char| c.is_ascii_digit() || c == '-' || c == '+');
+    match code.to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(date: &str, time: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    const FEED: &str = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:standup\r
+SUMMARY:Stand\r
+ up\r
+DTSTART;TZID=Europe/Berlin:20240304T100000\r
+DURATION:PT15M\r
+RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR\r
+BEGIN:VALARM\r
+TRIGGER:-PT10M\r
+DURATION:PT1H\r
+END:VALARM\r
+EXDATE;TZID=Europe/Berlin:20240306T100000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:standup\r
+RECURRENCE-ID;TZID=Europe/Berlin:20240308T100000\r
+SUMMARY:Stand up (moved)\r
+DTSTART;TZID=Europe/Berlin:20240308T113000\r
+DTEND;TZID=Europe/Berlin:20240308T114500\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:review\r
+SUMMARY:Review\\, Q1\r
+DTSTART:20240305T140000\r
+DTEND:20240305T150000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:cancelled\r
+SUMMARY:Cancelled\r
+STATUS:CANCELLED\r
+DTSTART:20240305T090000\r
+DTEND:20240305T093000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:holiday\r
+SUMMARY:Holiday\r
+DTSTART;VALUE=DATE:20240305\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[test]
+    fn test_parse_ics() {
+        let meetings = parse_ics(FEED, at("2024-03-04", "00:00"), at("2024-03-09", "00:00"));
+        let summary: Vec<(&str, NaiveDateTime, NaiveDateTime)> = meetings
+            .iter()
+            .map(|m| (m.summary.as_str(), m.start, m.end))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "Standup",
+                    at("2024-03-04", "10:00"),
+                    at("2024-03-04", "10:15")
+                ),
+                (
+                    "Review, Q1",
+                    at("2024-03-05", "14:00"),
+                    at("2024-03-05", "15:00")
+                ),
+                (
+                    "Stand up (moved)",
+                    at("2024-03-08", "11:30"),
+                    at("2024-03-08", "11:45")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recurrence_limits() {
+        let start = at("2024-03-01", "09:00");
+        let to = at("2024-04-01", "00:00");
+        assert_eq!(occurrences(start, "FREQ=DAILY;COUNT=3", to).len(), 3);
+        assert_eq!(
+            occurrences(start, "FREQ=DAILY;INTERVAL=2;UNTIL=20240307T090000", to),
+            vec![
+                at("2024-03-01", "09:00"),
+                at("2024-03-03", "09:00"),
+                at("2024-03-05", "09:00"),
+                at("2024-03-07", "09:00"),
+            ]
+        );
+        assert_eq!(parse_duration("PT1H30M"), Some(TimeDelta::minutes(90)));
+        assert_eq!(
+            feed_url("webcal://example.com/a.ics"),
+            "https://example.com/a.ics"
+        );
+    }
+}
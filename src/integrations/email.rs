@@ -0,0 +1,299 @@
+//! Email over SMTP
+//!
+//! Sends HTML mail through the system `curl` binary, which speaks SMTP with
+//! TLS (`smtps://`, or STARTTLS on `smtp://`) just like it does HTTPS for
+//! the other integrations, so no mail library is bundled. Used for the
+//! weekly report.
+//!
+//! The login goes to curl in a config file only this user can read, not on
+//! its command line, where every user could see it in the process list.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+
+use super::http_client::curl_quote;
+use crate::data::EmailConfig;
+
+/// Network timeout for sending one message
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Delay before trying again after a failure
+const RETRY_DELAY: Duration = Duration::from_secs(60 * 60);
+
+/// A message to send
+struct Mail {
+    subject: String,
+    html: String,
+}
+
+/// Background SMTP client
+pub struct Mailer {
+    tx: Sender<Mail>,
+    rx: Receiver<Result<(), String>>,
+    /// A message is on its way
+    sending: bool,
+    /// When the last message failed
+    failed_at: Option<Instant>,
+}
+
+impl Mailer {
+    /// Spawn the sending thread, waking the UI through `ctx` on replies
+    pub fn start(config: EmailConfig, ctx: egui::Context) -> Self {
+        let (tx, requests) = mpsc::channel();
+        let (replies, rx) = mpsc::channel();
+
+        let spawned = thread::Builder::new()
+            .name("email".into())
+            .spawn(move || worker_loop(requests, replies, config, ctx));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start email integration: {}", e);
+        }
+
+        Self {
+            tx,
+            rx,
+            sending: false,
+            failed_at: None,
+        }
+    }
+
+    /// Whether a message can go now: none is on its way and the last one
+    /// didn't fail too recently
+    pub fn ready(&self) -> bool {
+        !self.sending && self.failed_at.is_none_or(|at| at.elapsed() >= RETRY_DELAY)
+    }
+
+    /// Send an HTML message to the configured recipients
+    pub fn send(&mut self, subject: String, html: String) {
+        if self.tx.send(Mail { subject, html }).is_ok() {
+            self.sending = true;
+        }
+    }
+
+    /// Outcome of the message on its way, once it's known
+    pub fn poll(&mut self) -> Option<Result<(), String>> {
+        let reply = self.rx.try_recv().ok()?;
+        self.sending = false;
+        self.failed_at = reply.is_err().then(Instant::now);
+        Some(reply)
+    }
+}
+
+/// Worker thread: sends the messages one at a time
+fn worker_loop(
+    requests: Receiver<Mail>,
+    replies: Sender<Result<(), String>>,
+    config: EmailConfig,
+    ctx: egui::Context,
+) {
+    for mail in requests {
+        let reply = send_curl(&config, &message(&config, &mail));
+        match reply {
+            Ok(()) => tracing::info!("Email sent: {}", mail.subject),
+            Err(ref e) => tracing::warn!("Email failed: {}", e),
+        }
+        if replies.send(reply).is_err() {
+            break;
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// Recipients of the `to` setting
+fn recipients(config: &EmailConfig) -> Vec<&str> {
+    config
+        .to
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .collect()
+}
+
+/// The message as sent over SMTP: headers, a blank line and the HTML body
+fn message(config: &EmailConfig, mail: &Mail) -> String {
+    let headers = [
+        ("From", config.from.trim().to_string()),
+        ("To", recipients(config).join(", ")),
+        ("Subject", encode_header(&mail.subject)),
+        ("Date", Local::now().to_rfc2822()),
+        ("MIME-Version", "1.0".to_string()),
+        ("Content-Type", "text/html; charset=utf-8".to_string()),
+        ("Content-Transfer-Encoding", "8bit".to_string()),
+    ];
+    let mut message = String::new();
+    for (name, value) in headers {
+        message.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    message.push_str("\r\n");
+    // SMTP lines end in CRLF; curl takes care of escaping leading dots
+    for line in mail.html.lines() {
+        message.push_str(line);
+        message.push_str("\r\n");
+    }
+    message
+}
+
+/// Header value as an RFC 2047 encoded word when it isn't plain ASCII
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_string();
+    }
+    let mut encoded = String::from("=?UTF-8?Q?");
+    for byte in value.bytes() {
+        match byte {
+            b' ' => encoded.push('_'),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("={:02X}", byte)),
+        }
+    }
+    encoded.push_str("?=");
+    encoded
+}
+
+/// Curl config with the SMTP login, readable by this user only and removed
+/// when dropped
+struct LoginConfig {
+    path: PathBuf,
+}
+
+impl LoginConfig {
+    fn create(username: &str, password: &str) -> std::io::Result<Self> {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        let path = std::env::temp_dir().join(format!(
+            "pomodorust-smtp-{}-{}.conf",
+            std::process::id(),
+            nanos
+        ));
+
+        // The temp directory on Windows is already private to the user
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&path)?;
+        let login = Self { path };
+        let user = curl_quote(&format!("{}:{}", username, password));
+        file.write_all(format!("user = {}\n", user).as_bytes())?;
+        Ok(login)
+    }
+}
+
+impl Drop for LoginConfig {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Send the message through the `curl` binary
+fn send_curl(config: &EmailConfig, message: &str) -> Result<(), String> {
+    // Kept until curl is done with it
+    let login = if config.username.trim().is_empty() {
+        None
+    } else {
+        let login = LoginConfig::create(config.username.trim(), &config.password)
+            .map_err(|e| format!("Failed to write the SMTP login: {}", e))?;
+        Some(login)
+    };
+
+    let mut command = Command::new("curl");
+    command
+        .arg("--silent")
+        .arg("--show-error")
+        .arg("--max-time")
+        .arg(TIMEOUT.as_secs().to_string())
+        .arg("--ssl-reqd")
+        .arg("--mail-from")
+        .arg(config.from.trim());
+    for address in recipients(config) {
+        command.arg("--mail-rcpt").arg(address);
+    }
+    if let Some(login) = &login {
+        command.arg("--config").arg(&login.path);
+    }
+    command
+        .arg("--upload-file")
+        .arg("-")
+        .arg("--url")
+        .arg(config.server.trim())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to run curl: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(message.as_bytes());
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message() {
+        let config = EmailConfig {
+            from: "me@example.com".to_string(),
+            to: " me@example.com; boss@example.com ,".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            recipients(&config),
+            vec!["me@example.com", "boss@example.com"]
+        );
+
+        let mail = Mail {
+            subject: "Отчёт".to_string(),
+            html: "<p>Hi</p>\n<p>Bye</p>".to_string(),
+        };
+        let message = message(&config, &mail);
+        assert!(message.contains("To: me@example.com, boss@example.com\r\n"));
+        assert!(message.contains("Subject: =?UTF-8?Q?=D0=9E=D1=82=D1=87=D1=91=D1=82?=\r\n"));
+        assert!(message.ends_with("\r\n\r\n<p>Hi</p>\r\n<p>Bye</p>\r\n"));
+    }
+
+    #[test]
+    fn test_login_config_is_private_and_removed() {
+        let login = LoginConfig::create("me", "pa\"ss").unwrap();
+        let path = login.path.clone();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "user = \"me:pa\\\"ss\"\n"
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        drop(login);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,650 @@
+//! Group sessions over the local network
+//!
+//! One app hosts: it listens on `group.port` and sends its timer to the
+//! members whenever it changes, and every few seconds besides so they can
+//! tell when the host is gone. The others follow: they connect to the host,
+//! join with the group code and mirror the timer they're sent, each
+//! recording its own sessions. The host names everyone taking part in each
+//! message, so every app can show who's there.
+//!
+//! Messages are JSON lines, one [`GroupMessage`] each: `join` from a
+//! member, then `state` from the host, or `refused` when it won't take the
+//! member.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{IpAddr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::{GroupConfig, GroupRole};
+use crate::ipc::{IpcStatus, APP_NAME};
+
+/// How often the host sends its timer when nothing changes
+const HEARTBEAT: Duration = Duration::from_secs(5);
+
+/// How long a member waits to hear from the host before connecting again
+const HOST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Delay before a member connects again after losing the host
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Network timeout for connecting, joining and writes
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the host looks for new members and for being stopped
+const HOST_POLL: Duration = Duration::from_millis(200);
+
+/// Most members a host takes
+const MAX_MEMBERS: usize = 32;
+
+/// Most connections a host waits on to join at once
+const MAX_JOINING: usize = 8;
+
+/// Longest name shown to the others
+const MAX_NAME_CHARS: usize = 40;
+
+/// Seconds a member's timer may be off the host's before it's corrected
+pub const SYNC_TOLERANCE_SECS: u64 = 2;
+
+/// A message between the host and a member
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum GroupMessage {
+    /// A member asking to follow the host
+    Join {
+        app: String,
+        name: String,
+        code: String,
+    },
+    /// The host's timer, with everyone taking part, the host first
+    State {
+        status: IpcStatus,
+        participants: Vec<String>,
+    },
+    /// The host won't take the member
+    Refused { reason: String },
+}
+
+impl GroupMessage {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// The host's timer as a member last heard of it
+#[derive(Debug, Clone)]
+pub struct GroupTimer {
+    pub status: IpcStatus,
+    received: Instant,
+}
+
+impl GroupTimer {
+    /// Seconds left on the host's timer now
+    pub fn remaining_secs(&self) -> u64 {
+        remaining_after(&self.status, self.received.elapsed())
+    }
+}
+
+/// Seconds left on the timer of `status`, `elapsed` after it was taken
+fn remaining_after(status: &IpcStatus, elapsed: Duration) -> u64 {
+    if status.state == "running" {
+        status.remaining_secs.saturating_sub(elapsed.as_secs())
+    } else {
+        status.remaining_secs
+    }
+}
+
+/// Whether `now` is the timer of `sent`, `elapsed` later
+fn in_sync(sent: &IpcStatus, elapsed: Duration, now: &IpcStatus) -> bool {
+    sent.state == now.state
+        && sent.session_type == now.session_type
+        && sent.total_duration_secs == now.total_duration_secs
+        && remaining_after(sent, elapsed).abs_diff(now.remaining_secs) <= SYNC_TOLERANCE_SECS
+}
+
+/// Name to show for `configured`, the user's login when it's empty
+fn display_name(configured: &str) -> String {
+    let name = configured.trim();
+    let name = if name.is_empty() {
+        std::env::var("USERNAME")
+            .or_else(|_| std::env::var("USER"))
+            .unwrap_or_else(|_| "?".to_string())
+    } else {
+        name.to_string()
+    };
+    name.chars().take(MAX_NAME_CHARS).collect()
+}
+
+/// Address of the host to follow, on `port` unless `host` names one
+fn host_address(host: &str, port: u16) -> String {
+    let host = host.trim();
+    if host.parse::<SocketAddr>().is_ok() {
+        return host.to_string();
+    }
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V6(ip)) => format!("[{}]:{}", ip, port),
+        Ok(IpAddr::V4(ip)) => format!("{}:{}", ip, port),
+        // A host name, with or without a port
+        Err(_) if host.contains(':') => host.to_string(),
+        Err(_) => format!("{}:{}", host, port),
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A group session this app takes part in
+pub enum GroupSession {
+    Host(GroupHost),
+    Member(GroupMember),
+}
+
+impl GroupSession {
+    /// Host or follow as configured, waking the UI through `ctx` when the
+    /// host's timer comes in
+    pub fn start(config: &GroupConfig, ctx: Option<egui::Context>) -> Result<Self, String> {
+        match config.role {
+            GroupRole::Host => GroupHost::start(config).map(GroupSession::Host),
+            GroupRole::Follow => Ok(GroupSession::Member(GroupMember::start(config, ctx))),
+        }
+    }
+
+    /// Everyone taking part, the host first
+    pub fn participants(&self) -> Vec<String> {
+        match self {
+            GroupSession::Host(host) => host.shared.participants(),
+            GroupSession::Member(member) => lock(&member.shared).participants.clone(),
+        }
+    }
+}
+
+/// A member as the host sees it
+struct Member {
+    /// Tells members apart while they're written to without the lock
+    id: u64,
+    name: String,
+    stream: TcpStream,
+}
+
+/// What the host's threads share
+struct HostShared {
+    name: String,
+    code: String,
+    members: Mutex<Vec<Member>>,
+    /// Id of the next member to join
+    next_id: AtomicU64,
+    /// Connections still being asked for the group code
+    joining: AtomicUsize,
+    /// Timer last published, and when
+    last: Mutex<Option<(IpcStatus, Instant)>>,
+    /// Set when the timer or the members changed since the last message
+    dirty: AtomicBool,
+}
+
+impl HostShared {
+    fn participants(&self) -> Vec<String> {
+        std::iter::once(self.name.clone())
+            .chain(lock(&self.members).iter().map(|m| m.name.clone()))
+            .collect()
+    }
+
+    /// Send the timer to every member, dropping those who left
+    fn broadcast(&self) {
+        let Some((status, at)) = lock(&self.last).clone() else {
+            return;
+        };
+        let mut status = status;
+        status.remaining_secs = remaining_after(&status, at.elapsed());
+        let message = GroupMessage::State {
+            status,
+            participants: self.participants(),
+        }
+        .to_json();
+
+        // Written without holding the lock, which the UI takes for the
+        // participants, so a stalled member can't hold it up
+        let streams: Vec<(u64, TcpStream)> = lock(&self.members)
+            .iter()
+            .filter_map(|member| Some((member.id, member.stream.try_clone().ok()?)))
+            .collect();
+        let gone: Vec<u64> = streams
+            .into_iter()
+            .filter_map(|(id, mut stream)| writeln!(stream, "{}", message).is_err().then_some(id))
+            .collect();
+        if !gone.is_empty() {
+            lock(&self.members).retain(|member| !gone.contains(&member.id));
+            // Tell the others next time round
+            self.dirty.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Take a member in when it joins with the right code
+    fn welcome(&self, mut stream: TcpStream) {
+        let _ = stream.set_nonblocking(false);
+        let _ = stream.set_read_timeout(Some(IO_TIMEOUT));
+        let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+        let Ok(reader) = stream.try_clone() else {
+            return;
+        };
+        let mut line = String::new();
+        let _ = BufReader::new(reader).read_line(&mut line);
+
+        let reason = match serde_json::from_str::<GroupMessage>(line.trim()) {
+            Ok(GroupMessage::Join { app, name, code }) if app == APP_NAME => {
+                let mut members = lock(&self.members);
+                if code.trim() != self.code {
+                    "Wrong group code"
+                } else if members.len() >= MAX_MEMBERS {
+                    "The group is full"
+                } else {
+                    let name = display_name(&name);
+                    tracing::info!("{} joined the group session", name);
+                    let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+                    members.push(Member { id, name, stream });
+                    self.dirty.store(true, Ordering::Relaxed);
+                    return;
+                }
+            }
+            _ => "Not a group member",
+        };
+        let refused = GroupMessage::Refused {
+            reason: reason.to_string(),
+        };
+        let _ = writeln!(stream, "{}", refused.to_json());
+    }
+}
+
+/// Hosting a group session
+pub struct GroupHost {
+    shared: Arc<HostShared>,
+    stop: Arc<AtomicBool>,
+    port: u16,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl GroupHost {
+    /// Listen for members on `config.port`, on every network interface
+    pub fn start(config: &GroupConfig) -> Result<Self, String> {
+        let listener = TcpListener::bind(("0.0.0.0", config.port))
+            .map_err(|e| format!("port {}: {}", config.port, e))?;
+        listener.set_nonblocking(true).map_err(|e| e.to_string())?;
+        let port = listener.local_addr().map_or(config.port, |a| a.port());
+
+        let shared = Arc::new(HostShared {
+            name: display_name(&config.name),
+            code: config.code.trim().to_string(),
+            members: Mutex::new(Vec::new()),
+            next_id: AtomicU64::new(0),
+            joining: AtomicUsize::new(0),
+            last: Mutex::new(None),
+            dirty: AtomicBool::new(false),
+        });
+        let stop = Arc::new(AtomicBool::new(false));
+        let (thread_shared, thread_stop) = (shared.clone(), stop.clone());
+        let thread = thread::Builder::new()
+            .name("group-host".into())
+            .spawn(move || host_loop(listener, thread_shared, thread_stop))
+            .map_err(|e| e.to_string())?;
+
+        tracing::info!("Hosting a group session on port {}", port);
+        Ok(Self {
+            shared,
+            stop,
+            port,
+            thread: Some(thread),
+        })
+    }
+
+    /// Port the host listens on
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Pass the timer on to the members, once it's no longer what they were
+    /// last sent
+    pub fn publish(&self, status: IpcStatus) {
+        let mut last = lock(&self.shared.last);
+        let changed = match &*last {
+            Some((sent, at)) => !in_sync(sent, at.elapsed(), &status),
+            None => true,
+        };
+        if changed {
+            *last = Some((status, Instant::now()));
+            self.shared.dirty.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Drop for GroupHost {
+    /// Wait for the listener to close, so the port can be taken again
+    /// right away
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Take in members and send them the timer until stopped. Writing happens
+/// here rather than in `publish`, so a stalled member never holds up the UI.
+fn host_loop(listener: TcpListener, shared: Arc<HostShared>, stop: Arc<AtomicBool>) {
+    let mut last_sent = Instant::now();
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let joining =
+                    shared
+                        .joining
+                        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                            (n < MAX_JOINING).then_some(n + 1)
+                        });
+                if joining.is_err() {
+                    // Closed right away, the member tries again later
+                    tracing::warn!("Group join refused: too many connections joining");
+                    continue;
+                }
+                let thread_shared = shared.clone();
+                let spawned = thread::Builder::new()
+                    .name("group-join".into())
+                    .spawn(move || {
+                        thread_shared.welcome(stream);
+                        thread_shared.joining.fetch_sub(1, Ordering::SeqCst);
+                    });
+                if let Err(e) = spawned {
+                    shared.joining.fetch_sub(1, Ordering::SeqCst);
+                    tracing::warn!("Failed to take a group member: {}", e);
+                }
+                continue;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(e) => tracing::warn!("Group session accept failed: {}", e),
+        }
+        if shared.dirty.swap(false, Ordering::Relaxed) || last_sent.elapsed() >= HEARTBEAT {
+            shared.broadcast();
+            last_sent = Instant::now();
+        }
+        thread::sleep(HOST_POLL);
+    }
+    // Closing the connections tells the members the host is gone
+    lock(&shared.members).clear();
+}
+
+/// What a member knows of the group
+#[derive(Default)]
+struct Following {
+    timer: Option<GroupTimer>,
+    participants: Vec<String>,
+    /// Why the member isn't following right now
+    error: Option<String>,
+    /// Connection to the host, shut down to stop
+    stream: Option<TcpStream>,
+}
+
+/// Following the timer of a host
+pub struct GroupMember {
+    shared: Arc<Mutex<Following>>,
+    stop: Arc<AtomicBool>,
+    address: String,
+}
+
+impl GroupMember {
+    /// Connect to the host in the background, and again whenever it's lost
+    pub fn start(config: &GroupConfig, ctx: Option<egui::Context>) -> Self {
+        let address = host_address(&config.host, config.port);
+        let join = GroupMessage::Join {
+            app: APP_NAME.to_string(),
+            name: display_name(&config.name),
+            code: config.code.trim().to_string(),
+        }
+        .to_json();
+        let shared = Arc::new(Mutex::new(Following::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let (thread_shared, thread_stop, thread_address) =
+            (shared.clone(), stop.clone(), address.clone());
+        let spawned = thread::Builder::new()
+            .name("group-member".into())
+            .spawn(move || member_loop(&thread_address, &join, &thread_shared, &thread_stop, ctx));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to join the group session: {}", e);
+        }
+
+        Self {
+            shared,
+            stop,
+            address,
+        }
+    }
+
+    /// The host's timer, while connected
+    pub fn timer(&self) -> Option<GroupTimer> {
+        lock(&self.shared).timer.clone()
+    }
+
+    /// Why the host's timer isn't coming in, if it isn't
+    pub fn error(&self) -> Option<String> {
+        lock(&self.shared).error.clone()
+    }
+
+    /// Address of the host
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+}
+
+impl Drop for GroupMember {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(stream) = lock(&self.shared).stream.take() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+fn member_loop(
+    address: &str,
+    join: &str,
+    shared: &Mutex<Following>,
+    stop: &AtomicBool,
+    ctx: Option<egui::Context>,
+) {
+    while !stop.load(Ordering::Relaxed) {
+        let result = follow(address, join, shared, stop, ctx.as_ref());
+        if stop.load(Ordering::Relaxed) {
+            break;
+        }
+        if let Err(e) = result {
+            tracing::debug!("Group session with {}: {}", address, e);
+            let mut following = lock(shared);
+            following.timer = None;
+            following.participants.clear();
+            following.error = Some(e);
+        }
+        if let Some(ctx) = &ctx {
+            ctx.request_repaint();
+        }
+
+        let retry_at = Instant::now() + RECONNECT_BACKOFF;
+        while Instant::now() < retry_at && !stop.load(Ordering::Relaxed) {
+            thread::sleep(HOST_POLL);
+        }
+    }
+}
+
+/// Join the host and take in its timer until it's lost or the member stops
+fn follow(
+    address: &str,
+    join: &str,
+    shared: &Mutex<Following>,
+    stop: &AtomicBool,
+    ctx: Option<&egui::Context>,
+) -> Result<(), String> {
+    let socket = address
+        .to_socket_addrs()
+        .map_err(|e| format!("{}: {}", address, e))?
+        .next()
+        .ok_or_else(|| format!("{}: no address", address))?;
+    let mut stream = TcpStream::connect_timeout(&socket, IO_TIMEOUT)
+        .map_err(|e| format!("{}: {}", address, e))?;
+    let _ = stream.set_read_timeout(Some(HOST_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+    writeln!(stream, "{}", join).map_err(|e| e.to_string())?;
+    let reader = stream.try_clone().map_err(|e| e.to_string())?;
+    lock(shared).stream = Some(stream);
+
+    for line in BufReader::new(reader).lines() {
+        if stop.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let line = line.map_err(|e| e.to_string())?;
+        match serde_json::from_str::<GroupMessage>(&line) {
+            Ok(GroupMessage::State {
+                status,
+                participants,
+            }) => {
+                let mut following = lock(shared);
+                following.timer = Some(GroupTimer {
+                    status,
+                    received: Instant::now(),
+                });
+                following.participants = participants;
+                following.error = None;
+            }
+            Ok(GroupMessage::Refused { reason }) => return Err(reason),
+            _ => continue,
+        }
+        if let Some(ctx) = ctx {
+            ctx.request_repaint();
+        }
+    }
+    Err("The host left".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(state: &str, remaining_secs: u64) -> IpcStatus {
+        IpcStatus {
+            state: state.to_string(),
+            session_type: "work".to_string(),
+            remaining_secs,
+            remaining_formatted: String::new(),
+            progress: 0.0,
+            current_session: 1,
+            total_sessions: 4,
+            total_duration_secs: 1500,
+            tag: None,
+        }
+    }
+
+    /// Wait up to a few seconds for `done`
+    fn eventually(done: impl Fn() -> bool) -> bool {
+        let until = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < until {
+            if done() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        false
+    }
+
+    #[test]
+    fn test_timer_sync() {
+        let running = status("running", 600);
+        assert_eq!(remaining_after(&running, Duration::from_secs(10)), 590);
+        assert_eq!(
+            remaining_after(&status("paused", 600), Duration::from_secs(10)),
+            600
+        );
+        assert!(in_sync(
+            &running,
+            Duration::from_secs(10),
+            &status("running", 591)
+        ));
+        assert!(!in_sync(
+            &running,
+            Duration::from_secs(10),
+            &status("running", 580)
+        ));
+        assert!(!in_sync(&running, Duration::ZERO, &status("paused", 600)));
+    }
+
+    #[test]
+    fn test_host_addresses() {
+        assert_eq!(host_address("192.168.1.5", 19849), "192.168.1.5:19849");
+        assert_eq!(
+            host_address(" 192.168.1.5:4000 ", 19849),
+            "192.168.1.5:4000"
+        );
+        assert_eq!(host_address("fe80::1", 19849), "[fe80::1]:19849");
+        assert_eq!(host_address("desk.local", 19849), "desk.local:19849");
+        assert_eq!(host_address("desk.local:4000", 19849), "desk.local:4000");
+    }
+
+    #[test]
+    fn test_members_follow_the_host() {
+        let config = GroupConfig {
+            enabled: true,
+            port: 0,
+            name: "Host".to_string(),
+            code: "focus".to_string(),
+            ..GroupConfig::default()
+        };
+        let host = GroupHost::start(&config).unwrap();
+        host.publish(status("running", 600));
+
+        let member_config = GroupConfig {
+            role: GroupRole::Follow,
+            host: format!("127.0.0.1:{}", host.port()),
+            name: "Member".to_string(),
+            ..config
+        };
+        let member = GroupMember::start(&member_config, None);
+        assert!(eventually(|| member.timer().is_some()));
+        assert!(member.timer().unwrap().remaining_secs() >= 590);
+        assert!(eventually(|| {
+            lock(&member.shared).participants == ["Host", "Member"]
+        }));
+
+        let intruder = GroupMember::start(
+            &GroupConfig {
+                code: "guess".to_string(),
+                ..member_config
+            },
+            None,
+        );
+        assert!(eventually(|| intruder.error().is_some()));
+        assert!(intruder.timer().is_none());
+        assert_eq!(host.shared.participants().len(), 2);
+    }
+
+    #[test]
+    fn test_host_limits_connections_joining() {
+        let config = GroupConfig {
+            enabled: true,
+            port: 0,
+            code: "focus".to_string(),
+            ..GroupConfig::default()
+        };
+        let host = GroupHost::start(&config).unwrap();
+        let connect = || TcpStream::connect(("127.0.0.1", host.port())).unwrap();
+
+        // Connections that never say who they are
+        let _silent: Vec<TcpStream> = (0..MAX_JOINING).map(|_| connect()).collect();
+        assert!(eventually(|| {
+            host.shared.joining.load(Ordering::SeqCst) == MAX_JOINING
+        }));
+        let extra = connect();
+        extra.set_read_timeout(Some(IO_TIMEOUT)).unwrap();
+        let mut line = String::new();
+        assert_eq!(BufReader::new(extra).read_line(&mut line).unwrap(), 0);
+    }
+}
@@ -0,0 +1,195 @@
+//! User script hooks
+//!
+//! Runs the shell commands configured in `[hooks]` when timer events happen,
+//! e.g. to mute chat apps during focus time or log sessions elsewhere.
+//! Commands run detached; session details are passed as environment
+//! variables:
+//!
+//! | Variable | Example |
+//! |----------|---------|
+//! | `POMODORUST_EVENT` | `started`, `completed`, `skipped`, ... |
+//! | `POMODORUST_SESSION_TYPE` | `work`, `short_break`, `long_break` |
+//! | `POMODORUST_STATE` | `running`, `paused`, `idle` |
+//! | `POMODORUST_REMAINING_SECS` | `1500` |
+//! | `POMODORUST_DURATION_SECS` | `1500` |
+//! | `POMODORUST_SESSION_NUMBER` / `POMODORUST_TOTAL_SESSIONS` | `2` / `4` |
+//! | `POMODORUST_TAG` | `writing` (empty when untagged) |
+//! | `POMODORUST_TIMESTAMP` | RFC 3339 time of the event |
+
+use std::process::{Command, Stdio};
+use std::thread;
+
+use crate::data::HooksConfig;
+use crate::ipc::{IpcEvent, IpcEventKind};
+
+/// Pick the hook for an event, if one applies
+fn hook_for<'a>(hooks: &'a HooksConfig, event: &IpcEvent) -> Option<&'a str> {
+    let is_work = event.session_type == "work";
+    let hook = match event.event {
+        IpcEventKind::Started if is_work => &hooks.on_work_start,
+        IpcEventKind::Started => &hooks.on_break_start,
+        IpcEventKind::Completed | IpcEventKind::Skipped | IpcEventKind::Reset if is_work => {
+            &hooks.on_work_end
+        }
+        IpcEventKind::Completed | IpcEventKind::Skipped | IpcEventKind::Reset => {
+            &hooks.on_break_end
+        }
+        IpcEventKind::Paused => &hooks.on_pause,
+        IpcEventKind::Resumed => &hooks.on_resume,
+    };
+    hook.as_deref().filter(|cmd| !cmd.trim().is_empty())
+}
+
+/// Run the hooks configured for an event
+pub fn run_hooks(hooks: &HooksConfig, event: &IpcEvent) {
+    if let Some(cmd) = hook_for(hooks, event) {
+        spawn_hook(cmd, event);
+    }
+    if let Some(cmd) = hooks.on_event.as_deref().filter(|c| !c.trim().is_empty()) {
+        spawn_hook(cmd, event);
+    }
+}
+
+/// Build a shell command for the current platform
+pub(super) fn shell_command(cmd: &str) -> Command {
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+        let mut command = Command::new("cmd");
+        command.arg("/C").arg(cmd).creation_flags(CREATE_NO_WINDOW);
+        command
+    }
+    #[cfg(not(windows))]
+    {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg(cmd);
+        command
+    }
+}
+
+/// Environment variables describing an event to a hook
+fn hook_env(event: &IpcEvent) -> Vec<(&'static str, String)> {
+    let status = &event.status;
+    vec![
+        ("POMODORUST_EVENT", event.event.as_str().to_string()),
+        ("POMODORUST_SESSION_TYPE", event.session_type.clone()),
+        ("POMODORUST_STATE", status.state.clone()),
+        (
+            "POMODORUST_REMAINING_SECS",
+            status.remaining_secs.to_string(),
+        ),
+        ("POMODORUST_DURATION_SECS", event.session_secs().to_string()),
+        (
+            "POMODORUST_SESSION_NUMBER",
+            status.current_session.to_string(),
+        ),
+        (
+            "POMODORUST_TOTAL_SESSIONS",
+            status.total_sessions.to_string(),
+        ),
+        ("POMODORUST_TAG", status.tag.clone().unwrap_or_default()),
+        ("POMODORUST_TIMESTAMP", event.timestamp.clone()),
+    ]
+}
+
+/// Start a hook command without blocking the UI
+fn spawn_hook(cmd: &str, event: &IpcEvent) {
+    let mut command = shell_command(cmd);
+    command
+        .envs(hook_env(event))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    match command.spawn() {
+        Ok(mut child) => {
+            let cmd = cmd.to_string();
+            // Reap the child so it doesn't linger as a zombie
+            let _ = thread::Builder::new()
+                .name("hook".into())
+                .spawn(move || match child.wait() {
+                    Ok(status) if !status.success() => {
+                        tracing::warn!("Hook `{}` exited with {}", cmd, status);
+                    }
+                    Err(e) => tracing::warn!("Hook `{}` failed: {}", cmd, e),
+                    _ => {}
+                });
+        }
+        Err(e) => tracing::warn!("Failed to run hook `{}`: {}", cmd, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc::IpcStatus;
+
+    fn event(kind: IpcEventKind, session_type: &str) -> IpcEvent {
+        IpcEvent {
+            event: kind,
+            session_type: session_type.to_string(),
+            status: IpcStatus {
+                state: "running".to_string(),
+                session_type: session_type.to_string(),
+                remaining_secs: 0,
+                remaining_formatted: "00:00".to_string(),
+                progress: 0.0,
+                current_session: 1,
+                total_sessions: 4,
+                total_duration_secs: 0,
+                tag: None,
+            },
+            duration_secs: None,
+            timestamp: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_hook_selection() {
+        let hooks = HooksConfig {
+            on_work_start: Some("focus".to_string()),
+            on_break_end: Some("back".to_string()),
+            on_pause: Some("  ".to_string()),
+            ..Default::default()
+        };
+
+        let start = event(IpcEventKind::Started, "work");
+        assert_eq!(hook_for(&hooks, &start), Some("focus"));
+        let skipped = event(IpcEventKind::Skipped, "short_break");
+        assert_eq!(hook_for(&hooks, &skipped), Some("back"));
+        // Blank commands are ignored
+        assert_eq!(hook_for(&hooks, &event(IpcEventKind::Paused, "work")), None);
+        assert_eq!(
+            hook_for(&hooks, &event(IpcEventKind::Completed, "work")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_completed_work_reports_its_own_duration() {
+        // By the time a pomodoro completes, the status shows the idle break
+        let mut completed = event(IpcEventKind::Completed, "work");
+        completed.status.state = "idle".to_string();
+        completed.status.session_type = "short_break".to_string();
+        completed.status.remaining_secs = 300;
+        completed.status.total_duration_secs = 300;
+        completed.duration_secs = Some(1500);
+
+        let env = hook_env(&completed);
+        let var = |name: &str| {
+            env.iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(var("POMODORUST_DURATION_SECS"), Some("1500"));
+        assert_eq!(var("POMODORUST_SESSION_TYPE"), Some("work"));
+
+        // Other events describe the session in the status
+        let mut started = event(IpcEventKind::Started, "work");
+        started.status.total_duration_secs = 1500;
+        let env = hook_env(&started);
+        assert!(env.contains(&("POMODORUST_DURATION_SECS", "1500".to_string())));
+    }
+}
@@ -0,0 +1,216 @@
+//! Minimal blocking HTTP client for integrations
+//!
+//! Plain `http://` requests are sent directly over a TCP socket. `https://`
+//! requests are delegated to the system `curl` binary (shipped with Windows
+//! 10+, macOS and virtually every Linux distribution) so the app doesn't need
+//! to bundle a TLS stack. Always call this from a background thread.
+//!
+//! The URL, headers and body go to curl as a config on its stdin rather
+//! than as arguments, so tokens in them don't show up in the process list.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Network timeout for a single request
+const TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Response of a completed request
+#[derive(Debug, Clone)]
+pub struct HttpReply {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpReply {
+    /// Whether the status code is 2xx
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Send a request and wait for the reply
+pub fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<HttpReply, String> {
+    if url.starts_with("https://") {
+        request_curl(method, url, headers, body)
+    } else if let Some(rest) = url.strip_prefix("http://") {
+        request_plain(method, rest, headers, body)
+    } else {
+        Err(format!("Unsupported URL: {}", url))
+    }
+}
+
+/// Send a request over a plain TCP connection
+fn request_plain(
+    method: &str,
+    url_without_scheme: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<HttpReply, String> {
+    let (authority, path) = match url_without_scheme.find('/') {
+        Some(i) => url_without_scheme.split_at(i),
+        None => (url_without_scheme, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    let mut stream = TcpStream::connect(&address).map_err(|e| e.to_string())?;
+    let _ = stream.set_read_timeout(Some(TIMEOUT));
+    let _ = stream.set_write_timeout(Some(TIMEOUT));
+
+    // HTTP/1.0 keeps the response simple: no chunked encoding, no keep-alive
+    let body = body.unwrap_or("");
+    let mut head = format!(
+        "{} {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: PomodoRust\r\nContent-Length: {}\r\n",
+        method,
+        path,
+        authority,
+        body.len()
+    );
+    for (name, value) in headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+
+    stream
+        .write_all(head.as_bytes())
+        .and_then(|_| stream.write_all(body.as_bytes()))
+        .map_err(|e| e.to_string())?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw).map_err(|e| e.to_string())?;
+    let raw = String::from_utf8_lossy(&raw);
+
+    let status = raw
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or("Malformed HTTP response")?;
+    let body = raw
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body.to_string())
+        .unwrap_or_default();
+
+    Ok(HttpReply { status, body })
+}
+
+/// Quote a value for a curl config file
+pub(super) fn curl_quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Curl config with the parts of a request that may hold secrets
+fn curl_config(url: &str, headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let mut config = format!("url = {}\n", curl_quote(url));
+    for (name, value) in headers {
+        config.push_str(&format!(
+            "header = {}\n",
+            curl_quote(&format!("{}: {}", name, value))
+        ));
+    }
+    // `data-raw` doesn't treat a leading `@` as a file name
+    if let Some(body) = body {
+        config.push_str(&format!("data-raw = {}\n", curl_quote(body)));
+    }
+    config
+}
+
+/// Send a request through the `curl` binary
+fn request_curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<HttpReply, String> {
+    let mut command = Command::new("curl");
+    command
+        .arg("--silent")
+        .arg("--show-error")
+        .arg("--request")
+        .arg(method)
+        .arg("--max-time")
+        .arg(TIMEOUT.as_secs().to_string())
+        .arg("--write-out")
+        .arg("\n%{http_code}")
+        .arg("--config")
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to run curl: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(curl_config(url, headers, body).as_bytes());
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.trim().to_string());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').ok_or("Malformed curl output")?;
+    let status = status
+        .trim()
+        .parse()
+        .map_err(|_| "Malformed curl output".to_string())?;
+
+    Ok(HttpReply {
+        status,
+        body: body.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curl_config() {
+        let config = curl_config(
+            "https://api.example.com/bot123:abc/send",
+            &[("Authorization", "Bearer s3cret")],
+            Some("@{\"text\": \"a\\b\nc\"}"),
+        );
+        assert_eq!(
+            config,
+            "url = \"https://api.example.com/bot123:abc/send\"\n\
+             header = \"Authorization: Bearer s3cret\"\n\
+             data-raw = \"@{\\\"text\\\": \\\"a\\\\b\\nc\\\"}\"\n"
+        );
+        assert!(!curl_config("https://example.com", &[], None).contains("data"));
+    }
+}
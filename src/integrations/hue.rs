@@ -0,0 +1,123 @@
+//! Philips Hue light
+//!
+//! Colors a light (or a room/zone group) through the bridge's local REST API:
+//! red while focusing and green during breaks by default. Requests go to
+//! `http://<bridge>/api/<app key>/lights/<id>/state`, or
+//! `.../groups/<id>/action` when the target is `group:<id>`.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use super::http_client;
+use crate::data::HueConfig;
+use crate::ipc::{IpcEvent, IpcEventKind};
+
+/// Background Hue bridge client
+pub struct HueLight {
+    tx: Sender<String>,
+    config: HueConfig,
+}
+
+impl HueLight {
+    /// Spawn the request thread for the given configuration
+    pub fn start(config: HueConfig) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let url = state_url(&config);
+
+        let spawned = thread::Builder::new()
+            .name("hue".into())
+            .spawn(move || worker_loop(rx, url));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start Hue integration: {}", e);
+        }
+
+        Self { tx, config }
+    }
+
+    /// Update the light for a timer event
+    pub fn dispatch(&self, event: &IpcEvent) {
+        let body = match event.event {
+            IpcEventKind::Started | IpcEventKind::Resumed => {
+                let hue = if event.session_type == "work" {
+                    self.config.focus_hue
+                } else {
+                    self.config.break_hue
+                };
+                color_body(hue, self.config.brightness)
+            }
+            IpcEventKind::Completed | IpcEventKind::Reset | IpcEventKind::Skipped
+                if self.config.off_when_idle =>
+            {
+                r#"{"on":false}"#.to_string()
+            }
+            _ => return,
+        };
+        let _ = self.tx.send(body);
+    }
+}
+
+/// Worker thread: sends queued light states one at a time
+fn worker_loop(rx: Receiver<String>, url: String) {
+    let headers = [("Content-Type", "application/json")];
+    for body in rx {
+        match http_client::request("PUT", &url, &headers, Some(&body)) {
+            // The bridge answers 200 with a list of per-attribute errors
+            Ok(reply) if reply.is_success() && !reply.body.contains("\"error\"") => {
+                tracing::debug!("Hue light updated");
+            }
+            Ok(reply) => tracing::warn!("Hue bridge rejected the update: {}", reply.body),
+            Err(e) => tracing::warn!("Hue bridge unreachable: {}", e),
+        }
+    }
+}
+
+/// Endpoint that sets the state of the configured light or group
+fn state_url(config: &HueConfig) -> String {
+    let target = config.target.trim();
+    let path = match target.strip_prefix("group:") {
+        Some(group) => format!("groups/{}/action", group.trim()),
+        None => format!("lights/{}/state", target),
+    };
+    format!(
+        "http://{}/api/{}/{}",
+        config.bridge_ip.trim(),
+        config.app_key.trim(),
+        path
+    )
+}
+
+/// JSON body turning the light on in a saturated color
+fn color_body(hue: u16, brightness: u8) -> String {
+    serde_json::json!({
+        "on": true,
+        "hue": hue,
+        "sat": 254,
+        "bri": brightness.clamp(1, 254),
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_url() {
+        let mut config = HueConfig {
+            bridge_ip: "192.168.1.2".to_string(),
+            app_key: "key".to_string(),
+            ..HueConfig::default()
+        };
+        assert_eq!(
+            state_url(&config),
+            "http://192.168.1.2/api/key/lights/1/state"
+        );
+
+        config.target = "group:3".to_string();
+        assert_eq!(
+            state_url(&config),
+            "http://192.168.1.2/api/key/groups/3/action"
+        );
+        assert!(color_body(0, 0).contains("\"bri\":1"));
+    }
+}
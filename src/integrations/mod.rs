@@ -0,0 +1,224 @@
+//! Integrations with external services
+//!
+//! The app publishes an [`IpcEvent`] whenever the timer changes state
+//! (started, paused, completed, ...). [`Integrations`] fans these events out
+//! to every enabled integration. Integrations do their I/O on background
+//! threads so a slow or unreachable service never blocks the UI.
+//! Completed sessions, with their task and note, go to the time trackers
+//! ([`ActivityWatch`]) as a [`CompletedSession`]; the app queues them for
+//! [`NotionClient`] itself, so they survive being offline.
+//! [`TelegramBot`] messages a chat when a session completes, and
+//! [`Mailer`] sends the weekly report. A [`GroupSession`] shares the timer
+//! with other apps on the local network.
+//!
+//! [`TodoistClient`], [`Taskwarrior`] and [`CalendarFeed`] go the other way,
+//! bringing tasks and meetings into the app; the app drives them directly,
+//! as it does [`obsidian`] daily notes.
+//!
+//! User [`plugins`] get the events too, and add panels to the stats view
+//! and formats to the export menu.
+
+mod activitywatch;
+mod calendar;
+mod email;
+mod group;
+mod hooks;
+pub mod http_client;
+mod hue;
+mod mqtt;
+mod notion;
+pub mod obsidian;
+pub mod plugins;
+mod slack;
+mod taskwarrior;
+mod telegram;
+mod todoist;
+mod webhooks;
+
+pub use activitywatch::ActivityWatch;
+pub use calendar::{CalendarFeed, Meeting};
+pub use email::Mailer;
+pub use group::{GroupHost, GroupMember, GroupSession, GroupTimer, SYNC_TOLERANCE_SECS};
+pub use hue::HueLight;
+pub use mqtt::MqttPublisher;
+pub use notion::{NotionClient, NotionReply};
+pub use plugins::{PluginExport, PluginHost, PluginPanel};
+pub use slack::SlackStatus;
+pub use taskwarrior::{Taskwarrior, TaskwarriorReply, TaskwarriorTask};
+pub use telegram::{status_text, TelegramBot};
+pub use todoist::{TodoistClient, TodoistReply, TodoistTask};
+pub use webhooks::WebhookDispatcher;
+
+use chrono::{DateTime, Local};
+
+use crate::core::SessionType;
+use crate::data::{
+    ActivityWatchConfig, Config, HooksConfig, HueConfig, MqttConfig, PluginsConfig, SlackConfig,
+    TelegramConfig, WebhooksConfig,
+};
+use crate::ipc::{IpcCommand, IpcEvent};
+
+/// A completed session, as logged to notes and time trackers
+#[derive(Debug, Clone)]
+pub struct CompletedSession {
+    pub session_type: SessionType,
+    pub start: DateTime<Local>,
+    pub end: DateTime<Local>,
+    pub minutes: u64,
+    /// Title of the task at the head of the queue
+    pub task: Option<String>,
+    /// What the session was for, from the intent prompt
+    pub note: Option<String>,
+    pub tag: Option<String>,
+}
+
+/// Owns all enabled integrations and dispatches events to them
+#[derive(Default)]
+pub struct Integrations {
+    mqtt: Option<MqttPublisher>,
+    mqtt_config: MqttConfig,
+    /// Woken when a command comes in over MQTT or Telegram
+    ctx: Option<egui::Context>,
+    hooks: HooksConfig,
+    webhooks: Option<WebhookDispatcher>,
+    webhooks_config: WebhooksConfig,
+    hue: Option<HueLight>,
+    hue_config: HueConfig,
+    slack: Option<SlackStatus>,
+    slack_config: SlackConfig,
+    activitywatch: Option<ActivityWatch>,
+    activitywatch_config: ActivityWatchConfig,
+    telegram: Option<TelegramBot>,
+    telegram_config: TelegramConfig,
+    plugins: Option<PluginHost>,
+    plugins_config: PluginsConfig,
+}
+
+impl Integrations {
+    /// Start all integrations enabled in the config
+    pub fn new(config: &Config, ctx: &egui::Context) -> Self {
+        let mut integrations = Self {
+            ctx: Some(ctx.clone()),
+            ..Self::default()
+        };
+        integrations.reconfigure(config);
+        integrations
+    }
+
+    /// Restart integrations whose configuration changed
+    pub fn reconfigure(&mut self, config: &Config) {
+        if self.mqtt.is_none() || config.mqtt != self.mqtt_config {
+            self.mqtt_config = config.mqtt.clone();
+            self.mqtt = config.mqtt.enabled.then(|| {
+                tracing::info!(
+                    "MQTT publishing enabled ({}:{})",
+                    config.mqtt.host,
+                    config.mqtt.port
+                );
+                MqttPublisher::start(config.mqtt.clone(), self.ctx.clone())
+            });
+        }
+
+        if self.webhooks.is_none() || config.webhooks != self.webhooks_config {
+            self.webhooks_config = config.webhooks.clone();
+            self.webhooks = (config.webhooks.enabled && !config.webhooks.endpoints.is_empty())
+                .then(|| WebhookDispatcher::start(config.webhooks.clone()));
+        }
+
+        if self.hue.is_none() || config.hue != self.hue_config {
+            self.hue_config = config.hue.clone();
+            self.hue = config
+                .hue
+                .is_active()
+                .then(|| HueLight::start(config.hue.clone()));
+        }
+
+        if self.slack.is_none() || config.slack != self.slack_config {
+            self.slack_config = config.slack.clone();
+            self.slack = config
+                .slack
+                .is_active()
+                .then(|| SlackStatus::start(config.slack.clone()));
+        }
+
+        if self.activitywatch.is_none() || config.activitywatch != self.activitywatch_config {
+            self.activitywatch_config = config.activitywatch.clone();
+            self.activitywatch = config
+                .activitywatch
+                .is_active()
+                .then(|| ActivityWatch::start(config.activitywatch.clone()));
+        }
+
+        if self.telegram.is_none() || config.telegram != self.telegram_config {
+            self.telegram_config = config.telegram.clone();
+            self.telegram = config
+                .telegram
+                .is_active()
+                .then(|| TelegramBot::start(config.telegram.clone(), self.ctx.clone()));
+        }
+
+        if self.plugins.is_none() || config.plugins != self.plugins_config {
+            self.plugins_config = config.plugins.clone();
+            self.plugins = config
+                .plugins
+                .enabled
+                .then(|| PluginHost::start(&config.plugins, self.ctx.clone()));
+        }
+
+        self.hooks = config.hooks.clone();
+    }
+
+    /// Forward a timer event to every enabled integration
+    pub fn dispatch(&self, event: &IpcEvent) {
+        if let Some(ref mqtt) = self.mqtt {
+            mqtt.publish_event(event);
+        }
+        if let Some(ref webhooks) = self.webhooks {
+            webhooks.dispatch(event);
+        }
+        if let Some(ref hue) = self.hue {
+            hue.dispatch(event);
+        }
+        if let Some(ref slack) = self.slack {
+            slack.dispatch(event);
+        }
+        if let Some(ref plugins) = self.plugins {
+            plugins.dispatch(event);
+        }
+        hooks::run_hooks(&self.hooks, event);
+    }
+
+    /// Send a completed session to the time trackers
+    pub fn record_session(&self, session: &CompletedSession) {
+        if let Some(ref activitywatch) = self.activitywatch {
+            activitywatch.record(session);
+        }
+    }
+
+    /// Next timer command received over MQTT
+    pub fn poll_command(&self) -> Option<IpcCommand> {
+        self.mqtt.as_ref().and_then(MqttPublisher::poll_command)
+    }
+
+    /// The running plugins, if enabled
+    pub fn plugins(&self) -> Option<&PluginHost> {
+        self.plugins.as_ref()
+    }
+
+    /// Whether completed sessions should be messaged to Telegram
+    pub fn notifies_complete(&self) -> bool {
+        self.telegram.is_some() && self.telegram_config.notify_complete
+    }
+
+    /// Message the Telegram chat
+    pub fn telegram_send(&self, text: String) {
+        if let Some(ref telegram) = self.telegram {
+            telegram.send(text);
+        }
+    }
+
+    /// Next timer command received from the Telegram chat
+    pub fn poll_telegram(&self) -> Option<IpcCommand> {
+        self.telegram.as_ref().and_then(TelegramBot::poll_command)
+    }
+}
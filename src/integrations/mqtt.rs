@@ -0,0 +1,575 @@
+//! Minimal MQTT 3.1.1 client
+//!
+//! Publishes timer events to a broker so home-automation setups can react
+//! to focus sessions. Only the small subset of the protocol needed for
+//! QoS 0 is implemented: CONNECT, PUBLISH, SUBSCRIBE, PINGREQ and
+//! DISCONNECT.
+//!
+//! Topics (relative to the configured base topic):
+//! - `<base>/state`: retained JSON [`IpcStatus`] snapshot after every change
+//! - `<base>/event`: JSON [`IpcEvent`] for every lifecycle event
+//! - `<base>/availability`: `online` / `offline` (offline is the last will)
+//! - `<base>/command`: `start`, `pause`, `resume`, `toggle`, `skip` or
+//!   `stop`, subscribed to with Home Assistant discovery
+//!
+//! With discovery on, the timer announces itself to Home Assistant as a
+//! device with state, session and remaining time sensors and start, pause
+//! and skip buttons. The retained announcements are sent on start and again
+//! whenever Home Assistant comes back online.
+//!
+//! [`IpcStatus`]: crate::ipc::IpcStatus
+
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::data::MqttConfig;
+use crate::ipc::{IpcCommand, IpcEvent};
+
+/// Keep-alive interval announced to the broker
+const KEEP_ALIVE_SECS: u16 = 60;
+
+/// Delay before reconnecting after a failed connection attempt
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Network timeout for connect, reads and writes
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A message queued for publishing
+struct MqttMessage {
+    topic: String,
+    payload: Vec<u8>,
+    retain: bool,
+}
+
+/// Background MQTT publisher
+///
+/// Messages are queued on a channel and sent by a worker thread, which
+/// (re)connects lazily so a missing broker never blocks the UI.
+pub struct MqttPublisher {
+    tx: Sender<MqttMessage>,
+    commands: Receiver<IpcCommand>,
+    base_topic: String,
+    retain: bool,
+}
+
+impl MqttPublisher {
+    /// Spawn the publisher thread for the given configuration, waking the
+    /// UI through `ctx` when a command comes in
+    pub fn start(config: MqttConfig, ctx: Option<egui::Context>) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let (command_tx, commands) = mpsc::channel();
+        let base_topic = config.base_topic.trim_end_matches('/').to_string();
+        let retain = config.retain;
+
+        // Announcing the device also opens the connection, so the buttons
+        // work before the first timer event
+        if config.discovery {
+            for message in discovery_messages(&config) {
+                let _ = tx.send(message);
+            }
+        }
+
+        let outbox = tx.clone();
+        let spawned = thread::Builder::new().name("mqtt".into()).spawn(move || {
+            worker_loop(
+                config,
+                rx,
+                Inbox {
+                    outbox,
+                    commands: command_tx,
+                    ctx,
+                },
+            )
+        });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start MQTT publisher: {}", e);
+        }
+
+        Self {
+            tx,
+            commands,
+            base_topic,
+            retain,
+        }
+    }
+
+    /// Next command received on the command topic
+    pub fn poll_command(&self) -> Option<IpcCommand> {
+        self.commands.try_recv().ok()
+    }
+
+    /// Build a topic name below the base topic
+    pub fn topic(&self, suffix: &str) -> String {
+        format!("{}/{}", self.base_topic, suffix)
+    }
+
+    /// Queue a raw message for publishing
+    pub fn publish(&self, topic: String, payload: impl Into<Vec<u8>>, retain: bool) {
+        let _ = self.tx.send(MqttMessage {
+            topic,
+            payload: payload.into(),
+            retain,
+        });
+    }
+
+    /// Publish a timer event and the resulting state snapshot
+    pub fn publish_event(&self, event: &IpcEvent) {
+        let state = serde_json::to_string(&event.status).unwrap_or_default();
+        self.publish(self.topic("state"), state, self.retain);
+        self.publish(self.topic("event"), event.to_json(), false);
+    }
+}
+
+/// Where the reader thread delivers what comes in from the broker
+#[derive(Clone)]
+struct Inbox {
+    /// The worker's own queue, for announcing the device again
+    outbox: Sender<MqttMessage>,
+    commands: Sender<IpcCommand>,
+    ctx: Option<egui::Context>,
+}
+
+/// An open broker connection, read by its own thread
+struct Connection {
+    stream: TcpStream,
+    /// Cleared by the reader thread once the broker goes away
+    alive: Arc<AtomicBool>,
+}
+
+impl Connection {
+    fn close(self) {
+        let _ = self.stream.shutdown(Shutdown::Both);
+    }
+}
+
+/// Worker thread: owns the connection and drains the message queue
+fn worker_loop(config: MqttConfig, rx: Receiver<MqttMessage>, inbox: Inbox) {
+    let base_topic = config.base_topic.trim_end_matches('/');
+    let availability = format!("{}/availability", base_topic);
+    let mut connection: Option<Connection> = None;
+    let mut last_attempt: Option<Instant> = None;
+    let ping_interval = Duration::from_secs(KEEP_ALIVE_SECS as u64 / 2);
+
+    loop {
+        if connection
+            .as_ref()
+            .is_some_and(|c| !c.alive.load(Ordering::Relaxed))
+        {
+            tracing::warn!("MQTT broker closed the connection");
+            if let Some(c) = connection.take() {
+                c.close();
+            }
+        }
+
+        match rx.recv_timeout(ping_interval) {
+            Ok(message) => {
+                if connection.is_none() {
+                    let backing_off = last_attempt.is_some_and(|t| t.elapsed() < RECONNECT_BACKOFF);
+                    if backing_off {
+                        tracing::debug!("MQTT broker unavailable, dropping message");
+                        continue;
+                    }
+                    last_attempt = Some(Instant::now());
+                    connection = connect(&config, &availability, &inbox);
+                }
+
+                if let Some(ref mut c) = connection {
+                    let packet = encode_publish(&message.topic, &message.payload, message.retain);
+                    if let Err(e) = c.stream.write_all(&packet) {
+                        tracing::warn!("MQTT publish failed: {}", e);
+                        if let Some(c) = connection.take() {
+                            c.close();
+                        }
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                // Keep the connection alive between sparse timer events;
+                // the reader thread takes the PINGRESP
+                if let Some(ref mut c) = connection {
+                    if let Err(e) = c.stream.write_all(&[0xC0, 0x00]) {
+                        tracing::warn!("MQTT keep-alive failed: {}", e);
+                        if let Some(c) = connection.take() {
+                            c.close();
+                        }
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    if let Some(mut c) = connection {
+        let _ = c
+            .stream
+            .write_all(&encode_publish(&availability, b"offline", true));
+        let _ = c.stream.write_all(&[0xE0, 0x00]); // DISCONNECT
+        c.close();
+    }
+    tracing::info!("MQTT publisher stopped");
+}
+
+/// Open a TCP connection, perform the MQTT handshake and start reading
+fn connect(config: &MqttConfig, availability: &str, inbox: &Inbox) -> Option<Connection> {
+    let addr = format!("{}:{}", config.host, config.port);
+    let result = (|| -> std::io::Result<Connection> {
+        let mut stream = TcpStream::connect(&addr)?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+        stream.write_all(&encode_connect(config, availability))?;
+
+        let mut connack = [0u8; 4];
+        stream.read_exact(&mut connack)?;
+        if connack[0] != 0x20 || connack[3] != 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionRefused,
+                format!("broker refused connection (code {})", connack[3]),
+            ));
+        }
+
+        stream.write_all(&encode_publish(availability, b"online", true))?;
+        if config.discovery {
+            let topics = [
+                command_topic(config),
+                format!("{}/status", config.discovery_prefix.trim_end_matches('/')),
+            ];
+            stream.write_all(&encode_subscribe(1, &topics))?;
+        }
+
+        // From here on the reader thread blocks on the socket until it closes
+        stream.set_read_timeout(None)?;
+        let alive = Arc::new(AtomicBool::new(true));
+        let reader = stream.try_clone()?;
+        let (config, inbox, flag) = (config.clone(), inbox.clone(), alive.clone());
+        thread::Builder::new()
+            .name("mqtt-reader".into())
+            .spawn(move || reader_loop(reader, &config, &inbox, &flag))?;
+        Ok(Connection { stream, alive })
+    })();
+
+    match result {
+        Ok(connection) => {
+            tracing::info!("Connected to MQTT broker at {}", addr);
+            Some(connection)
+        }
+        Err(e) => {
+            tracing::warn!("Failed to connect to MQTT broker at {}: {}", addr, e);
+            None
+        }
+    }
+}
+
+/// Reader thread: takes in the packets from the broker until it goes away
+fn reader_loop(mut stream: TcpStream, config: &MqttConfig, inbox: &Inbox, alive: &AtomicBool) {
+    let commands = command_topic(config);
+    let ha_status = format!("{}/status", config.discovery_prefix.trim_end_matches('/'));
+
+    while let Ok((header, body)) = read_packet(&mut stream) {
+        // Anything but PUBLISH (PINGRESP, SUBACK) needs no answer
+        if header >> 4 != 3 {
+            continue;
+        }
+        let Some((topic, payload)) = parse_publish(header, &body) else {
+            continue;
+        };
+        if topic == commands {
+            match parse_command(&payload) {
+                Some(command) => {
+                    tracing::info!("MQTT command: {}", payload.trim());
+                    let _ = inbox.commands.send(command);
+                    if let Some(ref ctx) = inbox.ctx {
+                        ctx.request_repaint();
+                    }
+                }
+                None => tracing::warn!("Unknown MQTT command: {}", payload.trim()),
+            }
+        } else if topic == ha_status && payload.trim() == "online" {
+            tracing::debug!("Home Assistant is back, announcing the device again");
+            for message in discovery_messages(config) {
+                let _ = inbox.outbox.send(message);
+            }
+        }
+    }
+    alive.store(false, Ordering::Relaxed);
+}
+
+/// Topic the buttons publish to
+fn command_topic(config: &MqttConfig) -> String {
+    format!("{}/command", config.base_topic.trim_end_matches('/'))
+}
+
+/// Timer command for a command topic payload
+fn parse_command(payload: &str) -> Option<IpcCommand> {
+    let command = match payload.trim().to_ascii_lowercase().as_str() {
+        "start" => IpcCommand::Start {
+            session_type: None,
+            minutes: None,
+            tag: None,
+        },
+        "pause" => IpcCommand::Pause,
+        "resume" => IpcCommand::Resume,
+        "toggle" => IpcCommand::Toggle,
+        "skip" => IpcCommand::Skip,
+        "stop" | "reset" => IpcCommand::Stop,
+        _ => return None,
+    };
+    Some(command)
+}
+
+/// Retained Home Assistant discovery configs for the timer's entities
+fn discovery_messages(config: &MqttConfig) -> Vec<MqttMessage> {
+    let base = config.base_topic.trim_end_matches('/');
+    let prefix = config.discovery_prefix.trim_end_matches('/');
+    let node: String = config
+        .client_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let device = serde_json::json!({
+        "identifiers": [node],
+        "name": "PomodoRust",
+        "manufacturer": "PomodoRust",
+        "model": "Pomodoro timer",
+        "sw_version": env!("CARGO_PKG_VERSION"),
+    });
+    let availability = format!("{}/availability", base);
+    let state = format!("{}/state", base);
+    let command = command_topic(config);
+
+    let sensors = [
+        (
+            "state",
+            "State",
+            "{{ value_json.state }}",
+            "mdi:timer-outline",
+        ),
+        (
+            "session",
+            "Session",
+            "{{ value_json.session_type }}",
+            "mdi:tag-outline",
+        ),
+        (
+            "remaining",
+            "Remaining",
+            "{{ value_json.remaining_secs }}",
+            "mdi:timer-sand",
+        ),
+    ];
+    let buttons = [
+        ("start", "Start", "mdi:play"),
+        ("pause", "Pause", "mdi:pause"),
+        ("skip", "Skip", "mdi:skip-next"),
+    ];
+
+    let mut messages = Vec::new();
+    for (object, name, template, icon) in sensors {
+        let mut payload = serde_json::json!({
+            "name": name,
+            "unique_id": format!("{}_{}", node, object),
+            "state_topic": state,
+            "value_template": template,
+            "availability_topic": availability,
+            "icon": icon,
+            "device": device,
+        });
+        if object == "remaining" {
+            payload["unit_of_measurement"] = "s".into();
+            payload["device_class"] = "duration".into();
+        }
+        messages.push(MqttMessage {
+            topic: format!("{}/sensor/{}/{}/config", prefix, node, object),
+            payload: payload.to_string().into_bytes(),
+            retain: true,
+        });
+    }
+    for (object, name, icon) in buttons {
+        let payload = serde_json::json!({
+            "name": name,
+            "unique_id": format!("{}_{}", node, object),
+            "command_topic": command,
+            "payload_press": object,
+            "availability_topic": availability,
+            "icon": icon,
+            "device": device,
+        });
+        messages.push(MqttMessage {
+            topic: format!("{}/button/{}/{}/config", prefix, node, object),
+            payload: payload.to_string().into_bytes(),
+            retain: true,
+        });
+    }
+    messages
+}
+
+/// Read one packet: its first header byte and its body
+fn read_packet(stream: &mut impl Read) -> std::io::Result<(u8, Vec<u8>)> {
+    let mut byte = [0u8; 1];
+    stream.read_exact(&mut byte)?;
+    let header = byte[0];
+
+    let mut len = 0usize;
+    for shift in [0, 7, 14, 21] {
+        stream.read_exact(&mut byte)?;
+        len |= usize::from(byte[0] & 0x7F) << shift;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+    }
+    let mut body = vec![0u8; len];
+    stream.read_exact(&mut body)?;
+    Ok((header, body))
+}
+
+/// Topic and payload of a PUBLISH packet body
+fn parse_publish(header: u8, body: &[u8]) -> Option<(String, String)> {
+    let topic_len = usize::from(u16::from_be_bytes([*body.first()?, *body.get(1)?]));
+    let topic = std::str::from_utf8(body.get(2..2 + topic_len)?).ok()?;
+    // QoS 1 and 2 carry a packet id before the payload
+    let mut payload_start = 2 + topic_len;
+    if (header >> 1) & 0x03 > 0 {
+        payload_start += 2;
+    }
+    let payload = String::from_utf8_lossy(body.get(payload_start..)?);
+    Some((topic.to_string(), payload.into_owned()))
+}
+
+/// Append an MQTT "remaining length" variable-length integer
+fn encode_remaining_length(buf: &mut Vec<u8>, mut len: usize) {
+    loop {
+        let mut byte = (len % 128) as u8;
+        len /= 128;
+        if len > 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if len == 0 {
+            break;
+        }
+    }
+}
+
+/// Append a length-prefixed UTF-8 string / binary field
+fn encode_field(buf: &mut Vec<u8>, data: &[u8]) {
+    buf.extend_from_slice(&(data.len() as u16).to_be_bytes());
+    buf.extend_from_slice(data);
+}
+
+/// Prefix a packet body with its fixed header
+fn with_fixed_header(first_byte: u8, body: Vec<u8>) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(body.len() + 5);
+    packet.push(first_byte);
+    encode_remaining_length(&mut packet, body.len());
+    packet.extend(body);
+    packet
+}
+
+/// Build a CONNECT packet with an `offline` last will on the availability topic
+fn encode_connect(config: &MqttConfig, will_topic: &str) -> Vec<u8> {
+    let mut flags: u8 = 0x02 | 0x04 | 0x20; // clean session, will flag, will retain
+    if config.username.is_some() {
+        flags |= 0x80;
+    }
+    if config.password.is_some() {
+        flags |= 0x40;
+    }
+
+    let mut body = Vec::new();
+    encode_field(&mut body, b"MQTT");
+    body.push(4); // protocol level 3.1.1
+    body.push(flags);
+    body.extend_from_slice(&KEEP_ALIVE_SECS.to_be_bytes());
+
+    encode_field(&mut body, config.client_id.as_bytes());
+    encode_field(&mut body, will_topic.as_bytes());
+    encode_field(&mut body, b"offline");
+    if let Some(ref username) = config.username {
+        encode_field(&mut body, username.as_bytes());
+    }
+    if let Some(ref password) = config.password {
+        encode_field(&mut body, password.as_bytes());
+    }
+
+    with_fixed_header(0x10, body)
+}
+
+/// Build a SUBSCRIBE packet asking for QoS 0 on each topic
+fn encode_subscribe(packet_id: u16, topics: &[String]) -> Vec<u8> {
+    let mut body = Vec::new();
+    body.extend_from_slice(&packet_id.to_be_bytes());
+    for topic in topics {
+        encode_field(&mut body, topic.as_bytes());
+        body.push(0);
+    }
+    with_fixed_header(0x82, body)
+}
+
+/// Build a QoS 0 PUBLISH packet
+fn encode_publish(topic: &str, payload: &[u8], retain: bool) -> Vec<u8> {
+    let mut body = Vec::with_capacity(topic.len() + payload.len() + 2);
+    encode_field(&mut body, topic.as_bytes());
+    body.extend_from_slice(payload);
+
+    let first_byte = if retain { 0x31 } else { 0x30 };
+    with_fixed_header(first_byte, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining_length_encoding() {
+        let mut buf = Vec::new();
+        encode_remaining_length(&mut buf, 127);
+        assert_eq!(buf, vec![0x7F]);
+
+        let mut buf = Vec::new();
+        encode_remaining_length(&mut buf, 321);
+        assert_eq!(buf, vec![0xC1, 0x02]);
+    }
+
+    #[test]
+    fn test_publish_packet() {
+        let packet = encode_publish("a/b", b"hi", true);
+        assert_eq!(packet, vec![0x31, 7, 0, 3, b'a', b'/', b'b', b'h', b'i']);
+
+        let (header, body) = read_packet(&mut packet.as_slice()).unwrap();
+        assert_eq!(
+            parse_publish(header, &body),
+            Some(("a/b".to_string(), "hi".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_subscribe_packet() {
+        let packet = encode_subscribe(1, &["a/b".to_string()]);
+        assert_eq!(packet, vec![0x82, 8, 0, 1, 0, 3, b'a', b'/', b'b', 0]);
+    }
+
+    #[test]
+    fn test_discovery() {
+        let config = MqttConfig::default();
+        let messages = discovery_messages(&config);
+        assert_eq!(messages.len(), 6);
+        assert!(messages.iter().all(|m| m.retain));
+
+        let skip = messages
+            .iter()
+            .find(|m| m.topic == "homeassistant/button/pomodorust/skip/config")
+            .unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&skip.payload).unwrap();
+        assert_eq!(payload["command_topic"], "pomodorust/command");
+        assert!(matches!(
+            parse_command(payload["payload_press"].as_str().unwrap()),
+            Some(IpcCommand::Skip)
+        ));
+        assert!(parse_command("reboot").is_none());
+    }
+}
@@ -0,0 +1,216 @@
+//! Notion database sync
+//!
+//! Adds each completed focus session as a page (row) to a Notion database.
+//! Sessions wait in the local database until Notion has them, so they're
+//! sent in batches and survive being offline or restarting the app. The
+//! database needs a title property plus a date, a number (minutes) and a
+//! select (tag) property, named as in the config; the integration needs
+//! to be connected to it in Notion.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::http_client;
+use crate::data::{NotionConfig, NotionRow};
+
+const API: &str = "https://api.notion.com/v1";
+
+/// API version the requests are written against
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// Result of sending a batch, delivered to the app
+#[derive(Debug)]
+pub struct NotionReply {
+    /// Rows now in Notion
+    pub sent: Vec<i64>,
+    /// Why the rest of the batch wasn't sent
+    pub error: Option<String>,
+}
+
+/// Background Notion client
+pub struct NotionClient {
+    tx: Sender<Vec<NotionRow>>,
+    rx: Receiver<NotionReply>,
+    retry_interval: Duration,
+    /// When the last batch failed
+    failed_at: Option<Instant>,
+    /// A batch is on its way
+    sending: bool,
+    /// Rows may be waiting in the outbox
+    pending: bool,
+}
+
+impl NotionClient {
+    /// Spawn the request thread, waking the UI through `ctx` on replies
+    pub fn start(config: NotionConfig, ctx: egui::Context) -> Self {
+        let (tx, requests) = mpsc::channel();
+        let (replies, rx) = mpsc::channel();
+        let retry_interval = Duration::from_secs(u64::from(config.retry_minutes.max(1)) * 60);
+
+        let spawned = thread::Builder::new()
+            .name("notion".into())
+            .spawn(move || worker_loop(requests, replies, config, ctx));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start Notion integration: {}", e);
+        }
+
+        Self {
+            tx,
+            rx,
+            retry_interval,
+            failed_at: None,
+            sending: false,
+            pending: true,
+        }
+    }
+
+    /// Note that a row was added to the outbox
+    pub fn wake(&mut self) {
+        self.pending = true;
+    }
+
+    /// Whether the last batch failed
+    pub fn failing(&self) -> bool {
+        self.failed_at.is_some()
+    }
+
+    /// Whether a batch can go now: rows may be waiting, none is on its way
+    /// and the last one didn't fail too recently
+    pub fn ready(&self) -> bool {
+        self.pending
+            && !self.sending
+            && self
+                .failed_at
+                .is_none_or(|at| at.elapsed() >= self.retry_interval)
+    }
+
+    /// Send a batch of rows, an empty one meaning the outbox is empty
+    pub fn send(&mut self, rows: Vec<NotionRow>) {
+        if rows.is_empty() {
+            self.pending = false;
+        } else if self.tx.send(rows).is_ok() {
+            self.sending = true;
+        }
+    }
+
+    /// Next reply from the request thread
+    pub fn poll(&mut self) -> Option<NotionReply> {
+        let reply = self.rx.try_recv().ok()?;
+        self.sending = false;
+        self.failed_at = reply.error.is_some().then(Instant::now);
+        Some(reply)
+    }
+}
+
+/// Worker thread: adds the rows of each batch in order, stopping at the
+/// first failure so the rest is retried later
+fn worker_loop(
+    requests: Receiver<Vec<NotionRow>>,
+    replies: Sender<NotionReply>,
+    config: NotionConfig,
+    ctx: egui::Context,
+) {
+    let auth = format!("Bearer {}", config.token.trim());
+    let headers = [
+        ("Authorization", auth.as_str()),
+        ("Notion-Version", NOTION_VERSION),
+        ("Content-Type", "application/json"),
+    ];
+    let url = format!("{}/pages", API);
+
+    for rows in requests {
+        let mut reply = NotionReply {
+            sent: Vec::new(),
+            error: None,
+        };
+        for row in rows {
+            let body = page_body(&config, &row);
+            match http_client::request("POST", &url, &headers, Some(&body)) {
+                Ok(response) if response.is_success() => reply.sent.push(row.id),
+                Ok(response) => {
+                    reply.error = Some(format!(
+                        "HTTP {}: {}",
+                        response.status,
+                        response.body.trim()
+                    ));
+                    break;
+                }
+                Err(e) => {
+                    reply.error = Some(e);
+                    break;
+                }
+            }
+        }
+        if let Some(ref e) = reply.error {
+            tracing::warn!("Notion sync failed: {}", e);
+        }
+        if replies.send(reply).is_err() {
+            break;
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// Body of the request creating the page for a row
+fn page_body(config: &NotionConfig, row: &NotionRow) -> String {
+    let title = row.task.as_deref().unwrap_or(&config.default_title);
+    let mut properties = serde_json::Map::new();
+    properties.insert(
+        config.title_property.clone(),
+        serde_json::json!({ "title": [{ "text": { "content": title } }] }),
+    );
+    properties.insert(
+        config.date_property.clone(),
+        serde_json::json!({ "date": { "start": row.started_at.to_rfc3339() } }),
+    );
+    properties.insert(
+        config.duration_property.clone(),
+        serde_json::json!({ "number": row.minutes }),
+    );
+    if let Some(tag) = &row.tag {
+        // Commas aren't allowed in select options
+        properties.insert(
+            config.tag_property.clone(),
+            serde_json::json!({ "select": { "name": tag.replace(',', " ") } }),
+        );
+    }
+    // Properties left empty in the config aren't filled in
+    properties.retain(|name, _| !name.trim().is_empty());
+
+    serde_json::json!({
+        "parent": { "database_id": config.database_id.trim() },
+        "properties": properties,
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone};
+
+    #[test]
+    fn test_page_body() {
+        let config = NotionConfig {
+            database_id: "abc123".to_string(),
+            tag_property: String::new(),
+            ..Default::default()
+        };
+        let row = NotionRow {
+            id: 1,
+            started_at: Local.with_ymd_and_hms(2024, 3, 1, 14, 0, 0).unwrap(),
+            minutes: 25,
+            task: None,
+            tag: Some("writing".to_string()),
+        };
+        let body: serde_json::Value = serde_json::from_str(&page_body(&config, &row)).unwrap();
+        assert_eq!(body["parent"]["database_id"], "abc123");
+        assert_eq!(
+            body["properties"]["Name"]["title"][0]["text"]["content"],
+            "Focus session"
+        );
+        assert_eq!(body["properties"]["Duration"]["number"], 25);
+        assert_eq!(body["properties"].as_object().unwrap().len(), 3);
+    }
+}
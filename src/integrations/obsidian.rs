@@ -0,0 +1,161 @@
+//! Obsidian daily notes
+//!
+//! Appends a line for each completed session to today's daily note in an
+//! Obsidian vault, `<vault>/<folder>/<date>.md`, creating the note when it
+//! doesn't exist yet. The line comes from a template with these
+//! placeholders:
+//!
+//! | Placeholder | Example |
+//! |-------------|---------|
+//! | `{start}` / `{end}` | `14:00` / `14:25` |
+//! | `{duration}` | `25` (minutes) |
+//! | `{type}` | `work`, `short_break`, `long_break` |
+//! | `{icon}` | `🍅` |
+//! | `{task}` | title of the task at the head of the queue |
+//! | `{note}` | what the session was for, from the intent prompt |
+//! | `{tag}` | `writing` |
+//!
+//! Placeholders without a value are left out along with the space before
+//! them.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+use chrono::format::StrftimeItems;
+use chrono::{DateTime, Local, NaiveDate};
+
+use super::CompletedSession;
+use crate::data::ObsidianConfig;
+
+/// Date format of Obsidian's daily notes plugin
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Append the entry to the daily note of the day it ended on, returning
+/// the path of the note
+pub fn append_entry(config: &ObsidianConfig, entry: &CompletedSession) -> io::Result<PathBuf> {
+    let path = daily_note_path(config, entry.end.date_naive());
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(&path)?;
+    // Start on a line of its own when the note doesn't end with a newline
+    let mut line = String::new();
+    if file.seek(SeekFrom::End(0))? > 0 {
+        let mut last = [0u8; 1];
+        file.seek(SeekFrom::End(-1))?;
+        file.read_exact(&mut last)?;
+        if last[0] != b'\n' {
+            line.push('\n');
+        }
+    }
+    line.push_str(&render(&config.template, entry));
+    line.push('\n');
+    file.write_all(line.as_bytes())?;
+    Ok(path)
+}
+
+/// Path of the daily note for `date`
+fn daily_note_path(config: &ObsidianConfig, date: NaiveDate) -> PathBuf {
+    let format = config.date_format.trim();
+    let format = if StrftimeItems::new(format).parse().is_ok() && !format.is_empty() {
+        format
+    } else {
+        DEFAULT_DATE_FORMAT
+    };
+
+    let mut path = PathBuf::from(config.vault_path.trim());
+    let folder = config.folder.trim().trim_matches(['/', '\\']);
+    if !folder.is_empty() {
+        path.push(folder);
+    }
+    path.push(format!("{}.md", date.format(format)));
+    path
+}
+
+/// Fill in the template for an entry
+fn render(template: &str, entry: &CompletedSession) -> String {
+    let time = |at: &DateTime<Local>| at.format("%H:%M").to_string();
+    let values = [
+        ("{start}", Some(time(&entry.start))),
+        ("{end}", Some(time(&entry.end))),
+        ("{duration}", Some(entry.minutes.to_string())),
+        ("{type}", Some(entry.session_type.as_str().to_string())),
+        ("{icon}", Some(entry.session_type.icon().to_string())),
+        ("{task}", entry.task.clone()),
+        ("{note}", entry.note.clone()),
+        ("{tag}", entry.tag.clone()),
+    ];
+
+    // Notes and task titles go on one line
+    let mut line = template.lines().next().unwrap_or_default().to_string();
+    for (placeholder, value) in values {
+        match value.map(|v| v.split_whitespace().collect::<Vec<_>>().join(" ")) {
+            Some(value) if !value.is_empty() => line = line.replace(placeholder, &value),
+            _ => {
+                line = line.replace(&format!(" {}", placeholder), "");
+                line = line.replace(placeholder, "");
+            }
+        }
+    }
+    line.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    use crate::core::SessionType;
+
+    fn entry() -> CompletedSession {
+        CompletedSession {
+            session_type: SessionType::Work,
+            start: Local.with_ymd_and_hms(2024, 3, 1, 14, 0, 0).unwrap(),
+            end: Local.with_ymd_and_hms(2024, 3, 1, 14, 25, 0).unwrap(),
+            minutes: 25,
+            task: Some("Write report".to_string()),
+            note: None,
+            tag: Some("writing".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let config = ObsidianConfig::default();
+        assert_eq!(
+            render(&config.template, &entry()),
+            "- 14:00–14:25 🍅 25 min Write report"
+        );
+        assert_eq!(
+            render("- {start} #{tag} {note}", &entry()),
+            "- 14:00 #writing"
+        );
+    }
+
+    #[test]
+    fn test_daily_note_path() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let mut config = ObsidianConfig {
+            vault_path: "vault".to_string(),
+            folder: "Daily/".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            daily_note_path(&config, date),
+            PathBuf::from("vault").join("Daily").join("2024-03-01.md")
+        );
+
+        config.folder.clear();
+        config.date_format = "%Q".to_string();
+        assert_eq!(
+            daily_note_path(&config, date),
+            PathBuf::from("vault").join("2024-03-01.md")
+        );
+    }
+}
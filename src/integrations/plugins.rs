@@ -0,0 +1,532 @@
+//! Plugins
+//!
+//! A plugin is a directory in `plugins/` next to config.toml with a
+//! `plugin.toml` manifest and a program in any language:
+//!
+//! ```toml
+//! name = "Streak badges"
+//! command = "python3 badges.py"  # run in the plugin's directory
+//! events = ["completed"]         # timer events it gets, "*" for all
+//!
+//! [[panels]]                     # cards added to the stats view
+//! id = "badges"
+//! title = "Badges"
+//!
+//! [[exports]]                    # formats added to the export menu
+//! id = "ics"
+//! title = "iCalendar"
+//! extension = "ics"
+//!
+//! [settings]                     # defaults, changed in config.toml
+//! goal = 4
+//! ```
+//!
+//! The command runs once per call and reads one JSON request from stdin,
+//! which always carries the plugin's `settings`:
+//!
+//! - `{"hook": "event", "event": {...}}`: a timer event, as printed by
+//!   `pomodorust watch`; the output is ignored
+//! - `{"hook": "panel", "panel": "badges", "stats": {...}}`: answer with
+//!   `{"rows": [{"label": "...", "value": "..."}]}` on stdout
+//! - `{"hook": "export", "format": "ics", "stats": {...}}`: stdout becomes
+//!   the exported file
+//!
+//! `stats` is the same data as the JSON export. Plugins run as separate
+//! processes with a clean environment and only see their own settings: the
+//! manifest defaults with `[plugins.settings.<directory>]` on top. One that
+//! takes longer than [`TIMEOUT`] is killed.
+//!
+//! That is all the separation there is: plugins aren't sandboxed and run
+//! with the user's full rights, so only install ones you trust.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use crate::data::{ExportData, PluginsConfig};
+use crate::ipc::IpcEvent;
+
+/// Manifest file of a plugin directory
+const MANIFEST: &str = "plugin.toml";
+
+/// Longest a plugin may run for one call
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Variables kept in the plugin's environment, so interpreters can be
+/// found and run
+const PASSED_ENV: &[&str] = &[
+    "PATH",
+    "HOME",
+    "USERPROFILE",
+    "SYSTEMROOT",
+    "TEMP",
+    "TMP",
+    "TMPDIR",
+    "LANG",
+];
+
+/// Contents of `plugin.toml`
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PluginManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Command line run in the plugin's directory
+    pub command: String,
+    /// Timer events sent to the plugin, `*` for all
+    #[serde(default)]
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub panels: Vec<PanelSpec>,
+    #[serde(default)]
+    pub exports: Vec<ExportSpec>,
+    /// Default settings
+    #[serde(default)]
+    pub settings: toml::Table,
+}
+
+/// A stats panel declared by a plugin
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PanelSpec {
+    pub id: String,
+    pub title: String,
+}
+
+/// An export format declared by a plugin
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ExportSpec {
+    pub id: String,
+    pub title: String,
+    /// File extension, without the dot
+    pub extension: String,
+}
+
+/// An installed plugin
+#[derive(Debug, Clone)]
+pub struct Plugin {
+    /// Name of its directory, which identifies it in the config
+    pub id: String,
+    pub dir: PathBuf,
+    pub manifest: PluginManifest,
+}
+
+impl Plugin {
+    /// Read the plugin in `dir`
+    fn load(dir: &Path) -> Result<Self, String> {
+        let id = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or("Not a directory name")?;
+        let content = fs::read_to_string(dir.join(MANIFEST)).map_err(|e| e.to_string())?;
+        let manifest = toml::from_str(&content).map_err(|e| e.to_string())?;
+        Ok(Self {
+            id,
+            dir: dir.to_path_buf(),
+            manifest,
+        })
+    }
+
+    /// Whether the plugin asked for this event
+    fn wants(&self, event: &IpcEvent) -> bool {
+        self.manifest
+            .events
+            .iter()
+            .any(|name| name == "*" || name == event.event.as_str())
+    }
+
+    /// Settings as the plugin sees them: the manifest's defaults with the
+    /// user's on top
+    fn settings(&self, config: &PluginsConfig) -> toml::Table {
+        let mut settings = self.manifest.settings.clone();
+        if let Some(user) = config.settings.get(&self.id) {
+            settings.extend(user.clone());
+        }
+        settings
+    }
+
+    /// Run the plugin with one request, returning its output
+    fn call(&self, request: &serde_json::Value) -> Result<Vec<u8>, String> {
+        self.call_within(request, TIMEOUT)
+    }
+
+    fn call_within(
+        &self,
+        request: &serde_json::Value,
+        timeout: Duration,
+    ) -> Result<Vec<u8>, String> {
+        let mut command = super::hooks::shell_command(&self.manifest.command);
+        command.env_clear();
+        for name in PASSED_ENV {
+            if let Some(value) = std::env::var_os(name) {
+                command.env(name, value);
+            }
+        }
+        command
+            .env("POMODORUST_PLUGIN_DIR", &self.dir)
+            .current_dir(&self.dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = command
+            .spawn()
+            .map_err(|e| format!("Failed to run `{}`: {}", self.manifest.command, e))?;
+        // Written on its own thread, so a plugin that never reads its input
+        // still runs into the timeout instead of blocking the write
+        if let Some(mut stdin) = child.stdin.take() {
+            let request = request.to_string();
+            thread::spawn(move || {
+                let _ = writeln!(stdin, "{}", request);
+            });
+        }
+        wait_with_timeout(child, timeout)
+    }
+}
+
+/// Wait for the plugin's output, killing it once `timeout` has passed
+fn wait_with_timeout(mut child: Child, timeout: Duration) -> Result<Vec<u8>, String> {
+    // Read the pipes on their own threads so a chatty plugin can't block
+    let mut stdout = child.stdout.take();
+    let mut stderr = child.stderr.take();
+    let out = thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(ref mut pipe) = stdout {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    });
+    let err = thread::spawn(move || {
+        let mut buf = String::new();
+        if let Some(ref mut pipe) = stderr {
+            let _ = pipe.read_to_string(&mut buf);
+        }
+        buf
+    });
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("Timed out after {}s", timeout.as_secs()));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(20)),
+            Err(e) => return Err(e.to_string()),
+        }
+    };
+
+    let output = out.join().unwrap_or_default();
+    let errors = err.join().unwrap_or_default();
+    if status.success() {
+        Ok(output)
+    } else {
+        Err(format!("Exited with {}: {}", status, errors.trim()))
+    }
+}
+
+/// Plugins installed in `dir`, by directory name. Broken ones are logged
+/// and left out.
+pub fn discover(dir: &Path) -> Vec<Plugin> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut plugins: Vec<Plugin> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.join(MANIFEST).is_file())
+        .filter_map(|path| match Plugin::load(&path) {
+            Ok(plugin) => Some(plugin),
+            Err(e) => {
+                tracing::warn!("Skipping plugin {:?}: {}", path, e);
+                None
+            }
+        })
+        .collect();
+    plugins.sort_by(|a, b| a.id.cmp(&b.id));
+    plugins
+}
+
+/// A line of a plugin's stats panel
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PanelRow {
+    pub label: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+#[derive(Deserialize)]
+struct PanelReply {
+    rows: Vec<PanelRow>,
+}
+
+/// A stats panel with the plugin's latest answer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginPanel {
+    pub title: String,
+    pub rows: Result<Vec<PanelRow>, String>,
+}
+
+/// An export format offered by a plugin
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginExport {
+    pub plugin: String,
+    pub format: String,
+    pub title: String,
+    pub extension: String,
+}
+
+/// A plugin with the settings it runs with
+struct Loaded {
+    plugin: Plugin,
+    settings: serde_json::Value,
+}
+
+/// Runs the enabled plugins
+pub struct PluginHost {
+    plugins: Arc<Vec<Loaded>>,
+    /// Panels as of the last refresh
+    panels: Arc<Mutex<Vec<PluginPanel>>>,
+    /// Woken when the panels are refreshed
+    ctx: Option<egui::Context>,
+}
+
+impl PluginHost {
+    /// Load the plugins the config enables
+    pub fn start(config: &PluginsConfig, ctx: Option<egui::Context>) -> Self {
+        let plugins = PluginsConfig::plugins_dir()
+            .map(|dir| discover(&dir))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|plugin| config.runs(&plugin.id))
+            .map(|plugin| {
+                tracing::info!("Plugin loaded: {} ({})", plugin.manifest.name, plugin.id);
+                let settings = serde_json::to_value(plugin.settings(config))
+                    .unwrap_or(serde_json::Value::Null);
+                Loaded { plugin, settings }
+            })
+            .collect();
+        Self {
+            plugins: Arc::new(plugins),
+            panels: Arc::new(Mutex::new(Vec::new())),
+            ctx,
+        }
+    }
+
+    /// Send a timer event to the plugins that asked for it
+    pub fn dispatch(&self, event: &IpcEvent) {
+        for (i, loaded) in self.plugins.iter().enumerate() {
+            if !loaded.plugin.wants(event) {
+                continue;
+            }
+            let request = serde_json::json!({
+                "hook": "event",
+                "event": event,
+                "settings": loaded.settings,
+            });
+            let plugins = self.plugins.clone();
+            let spawned = thread::Builder::new().name("plugin".into()).spawn(move || {
+                let plugin = &plugins[i].plugin;
+                if let Err(e) = plugin.call(&request) {
+                    tracing::warn!("Plugin {} failed on an event: {}", plugin.id, e);
+                }
+            });
+            if let Err(e) = spawned {
+                tracing::error!("Failed to run plugin: {}", e);
+            }
+        }
+    }
+
+    /// Ask the plugins for their panels again, in the background
+    pub fn refresh_panels(&self, stats: &ExportData) {
+        if self
+            .plugins
+            .iter()
+            .all(|l| l.plugin.manifest.panels.is_empty())
+        {
+            return;
+        }
+        let stats = serde_json::to_value(stats).unwrap_or(serde_json::Value::Null);
+        let (plugins, panels, ctx) = (self.plugins.clone(), self.panels.clone(), self.ctx.clone());
+        let spawned = thread::Builder::new()
+            .name("plugin-panels".into())
+            .spawn(move || {
+                let mut fresh = Vec::new();
+                for loaded in plugins.iter() {
+                    for spec in &loaded.plugin.manifest.panels {
+                        let request = serde_json::json!({
+                            "hook": "panel",
+                            "panel": spec.id,
+                            "settings": loaded.settings,
+                            "stats": stats,
+                        });
+                        let rows = loaded.plugin.call(&request).and_then(|output| {
+                            serde_json::from_slice::<PanelReply>(&output)
+                                .map(|reply| reply.rows)
+                                .map_err(|e| format!("Unexpected reply: {}", e))
+                        });
+                        if let Err(ref e) = rows {
+                            tracing::warn!("Plugin {} panel {}: {}", loaded.plugin.id, spec.id, e);
+                        }
+                        fresh.push(PluginPanel {
+                            title: spec.title.clone(),
+                            rows,
+                        });
+                    }
+                }
+                if let Ok(mut panels) = panels.lock() {
+                    *panels = fresh;
+                }
+                if let Some(ctx) = ctx {
+                    ctx.request_repaint();
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to run plugins: {}", e);
+        }
+    }
+
+    /// Panels as of the last refresh
+    pub fn panels(&self) -> Vec<PluginPanel> {
+        self.panels.lock().map(|p| p.clone()).unwrap_or_default()
+    }
+
+    /// Export formats the plugins offer
+    pub fn exports(&self) -> Vec<PluginExport> {
+        self.plugins
+            .iter()
+            .flat_map(|loaded| {
+                loaded
+                    .plugin
+                    .manifest
+                    .exports
+                    .iter()
+                    .map(|spec| PluginExport {
+                        plugin: loaded.plugin.id.clone(),
+                        format: spec.id.clone(),
+                        title: spec.title.clone(),
+                        extension: spec.extension.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Have a plugin export the statistics to `path`, in the background.
+    /// The result arrives on the returned channel.
+    pub fn export(
+        &self,
+        export: &PluginExport,
+        stats: &ExportData,
+        path: PathBuf,
+    ) -> Receiver<Result<(), String>> {
+        let (tx, rx) = mpsc::channel();
+        let Some(i) = self
+            .plugins
+            .iter()
+            .position(|l| l.plugin.id == export.plugin)
+        else {
+            let _ = tx.send(Err(format!("Plugin {} is not loaded", export.plugin)));
+            return rx;
+        };
+        let request = serde_json::json!({
+            "hook": "export",
+            "format": export.format,
+            "settings": self.plugins[i].settings,
+            "stats": stats,
+        });
+        let (plugins, ctx) = (self.plugins.clone(), self.ctx.clone());
+        let spawned = thread::Builder::new()
+            .name("plugin-export".into())
+            .spawn(move || {
+                let result = plugins[i]
+                    .plugin
+                    .call(&request)
+                    .and_then(|content| fs::write(&path, content).map_err(|e| e.to_string()));
+                let _ = tx.send(result);
+                if let Some(ctx) = ctx {
+                    ctx.request_repaint();
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to run plugin: {}", e);
+        }
+        rx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest() {
+        let manifest: PluginManifest = toml::from_str(
+            r#"
+            name = "Badges"
+            command = "python3 badges.py"
+            events = ["completed"]
+
+            [[exports]]
+            id = "ics"
+            title = "iCalendar"
+            extension = "ics"
+
+            [settings]
+            goal = 4
+            color = "red"
+            "#,
+        )
+        .unwrap();
+        assert!(manifest.panels.is_empty());
+        assert_eq!(manifest.exports[0].extension, "ics");
+
+        let plugin = Plugin {
+            id: "badges".to_string(),
+            dir: PathBuf::from("badges"),
+            manifest,
+        };
+        let mut config = PluginsConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let mut user = toml::Table::new();
+        user.insert("goal".to_string(), toml::Value::Integer(8));
+        config.settings.insert("badges".to_string(), user.clone());
+        config.settings.insert("other".to_string(), user);
+
+        let settings = plugin.settings(&config);
+        assert_eq!(settings["goal"].as_integer(), Some(8));
+        assert_eq!(settings["color"].as_str(), Some("red"));
+        assert!(config.runs("badges"));
+        config.disabled.push("badges".to_string());
+        assert!(!config.runs("badges"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_plugin_ignoring_its_input_times_out() {
+        let plugin = Plugin {
+            id: "stuck".to_string(),
+            dir: std::env::temp_dir(),
+            manifest: toml::from_str(
+                r#"name = "Stuck"
+command = "sleep 10""#,
+            )
+            .unwrap(),
+        };
+        // Far more than a pipe holds
+        let request = serde_json::json!({ "stats": "x".repeat(1 << 20) });
+        let start = Instant::now();
+        let result = plugin.call_within(&request, Duration::from_millis(300));
+        assert!(result.is_err_and(|e| e.starts_with("Timed out")));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,168 @@
+//! Slack status
+//!
+//! Sets the user's status to "Focusing until HH:MM" and snoozes Slack
+//! notifications while a focus session runs, then clears both when it
+//! pauses or ends. Uses the Web API (`users.profile.set`, `dnd.setSnooze`,
+//! `dnd.endSnooze`) with a user token that has the `users.profile:write`
+//! and `dnd:write` scopes.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use chrono::{DateTime, Duration, Local};
+
+use super::http_client;
+use crate::data::SlackConfig;
+use crate::ipc::{IpcEvent, IpcEventKind};
+
+const API: &str = "https://slack.com/api";
+
+enum SlackUpdate {
+    /// A focus session runs until then
+    Focus {
+        until: DateTime<Local>,
+    },
+    Clear,
+}
+
+/// Background Slack client
+pub struct SlackStatus {
+    tx: Sender<SlackUpdate>,
+}
+
+impl SlackStatus {
+    /// Spawn the request thread for the given configuration
+    pub fn start(config: SlackConfig) -> Self {
+        let (tx, rx) = mpsc::channel();
+
+        let spawned = thread::Builder::new()
+            .name("slack".into())
+            .spawn(move || worker_loop(rx, config));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start Slack integration: {}", e);
+        }
+
+        Self { tx }
+    }
+
+    /// Update the status for a timer event
+    pub fn dispatch(&self, event: &IpcEvent) {
+        let update = match event.event {
+            IpcEventKind::Started | IpcEventKind::Resumed if event.session_type == "work" => {
+                let remaining = i64::try_from(event.status.remaining_secs).unwrap_or(0);
+                SlackUpdate::Focus {
+                    until: Local::now() + Duration::seconds(remaining),
+                }
+            }
+            _ => SlackUpdate::Clear,
+        };
+        let _ = self.tx.send(update);
+    }
+}
+
+/// Worker thread: applies the updates one at a time, only clearing what it
+/// set itself so a status set by hand survives breaks
+fn worker_loop(rx: Receiver<SlackUpdate>, config: SlackConfig) {
+    let auth = format!("Bearer {}", config.token.trim());
+    let call = |method: &str, content_type: &str, body: &str| {
+        let url = format!("{}/{}", API, method);
+        let headers = [
+            ("Authorization", auth.as_str()),
+            ("Content-Type", content_type),
+        ];
+        match http_client::request("POST", &url, &headers, Some(body)) {
+            Ok(reply) if reply.is_success() && slack_ok(&reply.body) => {
+                tracing::debug!("Slack {} done", method);
+                true
+            }
+            Ok(reply) => {
+                tracing::warn!("Slack rejected {}: {}", method, reply.body);
+                false
+            }
+            Err(e) => {
+                tracing::warn!("Slack unreachable: {}", e);
+                false
+            }
+        }
+    };
+    let json = "application/json; charset=utf-8";
+    let form = "application/x-www-form-urlencoded";
+
+    let mut status_set = false;
+    let mut snoozed = false;
+    for update in rx {
+        match update {
+            SlackUpdate::Focus { until } => {
+                status_set = call(
+                    "users.profile.set",
+                    json,
+                    &status_body(&config, Some(until)),
+                );
+                if config.dnd {
+                    let minutes = (until - Local::now()).num_minutes().max(1);
+                    snoozed = call("dnd.setSnooze", form, &format!("num_minutes={}", minutes));
+                }
+            }
+            SlackUpdate::Clear => {
+                if status_set {
+                    call("users.profile.set", json, &status_body(&config, None));
+                    status_set = false;
+                }
+                if snoozed {
+                    call("dnd.endSnooze", form, "");
+                    snoozed = false;
+                }
+            }
+        }
+    }
+}
+
+/// Body of `users.profile.set`: the focus status, or an empty one to clear
+/// it. The status expires on its own in case the app isn't there to clear it.
+fn status_body(config: &SlackConfig, until: Option<DateTime<Local>>) -> String {
+    let profile = match until {
+        Some(until) => serde_json::json!({
+            "status_text": config
+                .status_text
+                .replace("{until}", &until.format("%H:%M").to_string()),
+            "status_emoji": config.status_emoji.trim(),
+            "status_expiration": until.timestamp(),
+        }),
+        None => serde_json::json!({
+            "status_text": "",
+            "status_emoji": "",
+            "status_expiration": 0,
+        }),
+    };
+    serde_json::json!({ "profile": profile }).to_string()
+}
+
+/// Slack answers 200 with `"ok": false` for failed calls
+fn slack_ok(body: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|reply| reply.get("ok")?.as_bool())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_status_body() {
+        let config = SlackConfig::default();
+        let until = Local.with_ymd_and_hms(2024, 3, 1, 14, 25, 0).unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(&status_body(&config, Some(until))).unwrap();
+        assert_eq!(body["profile"]["status_text"], "Focusing until 14:25");
+        assert_eq!(body["profile"]["status_emoji"], ":tomato:");
+        assert_eq!(body["profile"]["status_expiration"], until.timestamp());
+
+        let body: serde_json::Value = serde_json::from_str(&status_body(&config, None)).unwrap();
+        assert_eq!(body["profile"]["status_text"], "");
+        assert!(slack_ok(r#"{"ok":true}"#));
+        assert!(!slack_ok(r#"{"ok":false,"error":"invalid_auth"}"#));
+    }
+}
@@ -0,0 +1,223 @@
+//! Taskwarrior tasks
+//!
+//! Pulls the pending tasks from `task export` into the task list, runs
+//! `task <uuid> start` / `stop` while a focus session on one of them runs,
+//! and annotates it with the pomodoro count when a session completes.
+//! Commands run one at a time on a background thread.
+
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use crate::data::todo::Priority;
+use crate::data::TaskwarriorConfig;
+
+/// A pending Taskwarrior task
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TaskwarriorTask {
+    pub uuid: String,
+    pub description: String,
+    /// `H`, `M` or `L`
+    #[serde(default)]
+    pub priority: Option<String>,
+}
+
+impl TaskwarriorTask {
+    /// Priority of the task in the app's task list
+    pub fn local_priority(&self) -> Priority {
+        match self.priority.as_deref() {
+            Some("H") => Priority::High,
+            Some("M") => Priority::Medium,
+            Some("L") => Priority::Low,
+            _ => Priority::None,
+        }
+    }
+}
+
+/// Result of a command, delivered to the app
+#[derive(Debug)]
+pub enum TaskwarriorReply {
+    /// The pending tasks matching the filter
+    Tasks(Vec<TaskwarriorTask>),
+    Failed(String),
+}
+
+enum Request {
+    Export,
+    /// Run `task <uuid> <args...>`
+    Modify {
+        uuid: String,
+        args: Vec<String>,
+    },
+}
+
+/// Background Taskwarrior bridge
+pub struct Taskwarrior {
+    tx: Sender<Request>,
+    rx: Receiver<TaskwarriorReply>,
+    sync_interval: Duration,
+    synced_at: Option<Instant>,
+}
+
+impl Taskwarrior {
+    /// Spawn the command thread, waking the UI through `ctx` on replies
+    pub fn start(config: TaskwarriorConfig, ctx: egui::Context) -> Self {
+        let (tx, requests) = mpsc::channel();
+        let (replies, rx) = mpsc::channel();
+        let sync_interval = Duration::from_secs(u64::from(config.sync_minutes.max(1)) * 60);
+
+        let spawned = thread::Builder::new()
+            .name("taskwarrior".into())
+            .spawn(move || worker_loop(requests, replies, config, ctx));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start Taskwarrior integration: {}", e);
+        }
+
+        Self {
+            tx,
+            rx,
+            sync_interval,
+            synced_at: None,
+        }
+    }
+
+    /// Export the tasks if the last sync is older than the sync interval
+    pub fn sync_if_due(&mut self) {
+        if self
+            .synced_at
+            .is_some_and(|at| at.elapsed() < self.sync_interval)
+        {
+            return;
+        }
+        self.synced_at = Some(Instant::now());
+        let _ = self.tx.send(Request::Export);
+    }
+
+    /// Mark the task as being worked on
+    pub fn start_task(&self, uuid: &str) {
+        self.modify(uuid, &["start"]);
+    }
+
+    /// Mark the task as no longer being worked on
+    pub fn stop_task(&self, uuid: &str) {
+        self.modify(uuid, &["stop"]);
+    }
+
+    /// Add an annotation to the task
+    pub fn annotate(&self, uuid: &str, text: &str) {
+        self.modify(uuid, &["annotate", text]);
+    }
+
+    fn modify(&self, uuid: &str, args: &[&str]) {
+        let _ = self.tx.send(Request::Modify {
+            uuid: uuid.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+        });
+    }
+
+    /// Next reply from the command thread
+    pub fn poll(&self) -> Option<TaskwarriorReply> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// Worker thread: runs the commands one at a time
+fn worker_loop(
+    requests: Receiver<Request>,
+    replies: Sender<TaskwarriorReply>,
+    config: TaskwarriorConfig,
+    ctx: egui::Context,
+) {
+    for request in requests {
+        let reply = match request {
+            Request::Export => {
+                let mut args = export_args(&config.filter);
+                args.insert(0, "rc.verbose=nothing".to_string());
+                match run(&config.command, &args).and_then(|out| parse_export(&out)) {
+                    Ok(tasks) => TaskwarriorReply::Tasks(tasks),
+                    Err(e) => TaskwarriorReply::Failed(e),
+                }
+            }
+            Request::Modify { uuid, args } => {
+                let args: Vec<String> = ["rc.confirmation=off".to_string(), uuid]
+                    .into_iter()
+                    .chain(args)
+                    .collect();
+                match run(&config.command, &args) {
+                    Ok(_) => continue,
+                    Err(e) => TaskwarriorReply::Failed(e),
+                }
+            }
+        };
+        if let TaskwarriorReply::Failed(ref e) = reply {
+            tracing::warn!("Taskwarrior command failed: {}", e);
+        }
+        if replies.send(reply).is_err() {
+            break;
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// Arguments of `task ... export` for the pending tasks matching `filter`
+fn export_args(filter: &str) -> Vec<String> {
+    filter
+        .split_whitespace()
+        .map(str::to_string)
+        .chain(["status:pending".to_string(), "export".to_string()])
+        .collect()
+}
+
+/// Run the `task` binary and return what it printed
+fn run(program: &str, args: &[String]) -> Result<String, String> {
+    let mut command = Command::new(program.trim());
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", program, e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn parse_export(output: &str) -> Result<Vec<TaskwarriorTask>, String> {
+    serde_json::from_str(output.trim()).map_err(|e| format!("Unexpected export: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export() {
+        assert_eq!(
+            export_args(" project:work  +next "),
+            vec!["project:work", "+next", "status:pending", "export"]
+        );
+
+        let tasks = parse_export(
+            r#"[{"id":1,"description":"Write report","entry":"20240301T090000Z","priority":"H","status":"pending","uuid":"a1b2","urgency":9.8},
+               {"id":2,"description":"Tidy desk","status":"pending","uuid":"c3d4","urgency":0}]"#,
+        )
+        .unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].local_priority(), Priority::High);
+        assert_eq!(tasks[1].local_priority(), Priority::None);
+    }
+}
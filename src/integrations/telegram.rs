@@ -0,0 +1,265 @@
+//! Telegram bot
+//!
+//! Sends session-complete messages to a chat and takes timer commands from
+//! it: `/status`, `/start`, `/pause`, `/resume`, `/toggle`, `/skip` and
+//! `/stop`. Commands become [`IpcCommand`]s and go through the same handler
+//! as the CLI; the bot answers with the resulting status. Only the
+//! configured chat is listened to.
+//!
+//! Talks to the Bot API with long polling (`getUpdates`), so no webhook or
+//! public address is needed. Create the bot with @BotFather; the chat id of
+//! a private chat is the user's id, which bots like @userinfobot tell.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::http_client;
+use crate::data::TelegramConfig;
+use crate::ipc::{IpcCommand, IpcStatus};
+
+const API: &str = "https://api.telegram.org";
+
+/// Seconds `getUpdates` waits for a message, below the HTTP client's timeout
+const POLL_SECS: u32 = 10;
+
+/// Delay before polling again after a failure
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+
+const HELP: &str = "/status, /start, /pause, /resume, /toggle, /skip, /stop";
+
+/// Background Telegram bot
+pub struct TelegramBot {
+    outbox: Sender<String>,
+    commands: Receiver<IpcCommand>,
+    /// Tells the polling thread to stop once the bot is dropped
+    stop: Arc<AtomicBool>,
+}
+
+impl TelegramBot {
+    /// Spawn the polling and sending threads, waking the UI through `ctx`
+    /// when a command comes in
+    pub fn start(config: TelegramConfig, ctx: Option<egui::Context>) -> Self {
+        let (outbox, messages) = mpsc::channel();
+        let (command_tx, commands) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let sender_config = config.clone();
+        let spawned = thread::Builder::new()
+            .name("telegram-send".into())
+            .spawn(move || send_loop(messages, sender_config));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start Telegram integration: {}", e);
+        }
+
+        let (help, flag) = (outbox.clone(), stop.clone());
+        let spawned = thread::Builder::new()
+            .name("telegram-poll".into())
+            .spawn(move || poll_loop(config, command_tx, help, ctx, flag));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start Telegram integration: {}", e);
+        }
+
+        Self {
+            outbox,
+            commands,
+            stop,
+        }
+    }
+
+    /// Send a message to the chat
+    pub fn send(&self, text: String) {
+        let _ = self.outbox.send(text);
+    }
+
+    /// Next command received from the chat
+    pub fn poll_command(&self) -> Option<IpcCommand> {
+        self.commands.try_recv().ok()
+    }
+}
+
+impl Drop for TelegramBot {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Sending thread: posts the messages one at a time
+fn send_loop(messages: Receiver<String>, config: TelegramConfig) {
+    let url = format!("{}/bot{}/sendMessage", API, config.token.trim());
+    let headers = [("Content-Type", "application/json")];
+    for text in messages {
+        let body = serde_json::json!({ "chat_id": config.chat_id.trim(), "text": text });
+        match http_client::request("POST", &url, &headers, Some(&body.to_string())) {
+            Ok(reply) if reply.is_success() => tracing::debug!("Telegram message sent"),
+            Ok(reply) => tracing::warn!("Telegram rejected the message: {}", reply.body.trim()),
+            Err(e) => tracing::warn!("Telegram unreachable: {}", e),
+        }
+    }
+}
+
+/// Polling thread: long-polls for messages and turns the ones from the
+/// configured chat into commands
+fn poll_loop(
+    config: TelegramConfig,
+    commands: Sender<IpcCommand>,
+    outbox: Sender<String>,
+    ctx: Option<egui::Context>,
+    stop: Arc<AtomicBool>,
+) {
+    let base = format!("{}/bot{}/getUpdates", API, config.token.trim());
+    let chat_id = config.chat_id.trim();
+    let mut offset: Option<i64> = None;
+
+    while !stop.load(Ordering::Relaxed) {
+        let mut url = format!("{}?timeout={}", base, POLL_SECS);
+        if let Some(offset) = offset {
+            url.push_str(&format!("&offset={}", offset));
+        }
+        let updates = match http_client::request("GET", &url, &[], None)
+            .and_then(|reply| parse_updates(&reply.body))
+        {
+            Ok(updates) => updates,
+            Err(e) => {
+                tracing::warn!("Telegram polling failed: {}", e);
+                thread::sleep(RETRY_DELAY);
+                continue;
+            }
+        };
+
+        for update in updates {
+            offset = Some(update.update_id + 1);
+            let Some(message) = update.message else {
+                continue;
+            };
+            if message.chat.id.to_string() != chat_id {
+                tracing::debug!("Ignoring Telegram message from chat {}", message.chat.id);
+                continue;
+            }
+            match parse_command(message.text.as_deref().unwrap_or_default()) {
+                Some(command) => {
+                    if commands.send(command).is_err() {
+                        return;
+                    }
+                    if let Some(ref ctx) = ctx {
+                        ctx.request_repaint();
+                    }
+                }
+                None => {
+                    let _ = outbox.send(HELP.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Updates {
+    ok: bool,
+    #[serde(default)]
+    result: Vec<Update>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Update {
+    update_id: i64,
+    #[serde(default)]
+    message: Option<Message>,
+}
+
+#[derive(Deserialize)]
+struct Message {
+    chat: Chat,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Chat {
+    id: i64,
+}
+
+fn parse_updates(body: &str) -> Result<Vec<Update>, String> {
+    let updates: Updates =
+        serde_json::from_str(body).map_err(|e| format!("Unexpected reply: {}", e))?;
+    if updates.ok {
+        Ok(updates.result)
+    } else {
+        Err(updates
+            .description
+            .unwrap_or_else(|| body.trim().to_string()))
+    }
+}
+
+/// Timer command for a chat message like `/pause` or `/pause@SomeBot`
+fn parse_command(text: &str) -> Option<IpcCommand> {
+    let word = text.split_whitespace().next()?.strip_prefix('/')?;
+    let name = word.split('@').next().unwrap_or(word);
+    let command = match name.to_ascii_lowercase().as_str() {
+        "status" => IpcCommand::Status,
+        "start" => IpcCommand::Start {
+            session_type: None,
+            minutes: None,
+            tag: None,
+        },
+        "pause" => IpcCommand::Pause,
+        "resume" => IpcCommand::Resume,
+        "toggle" => IpcCommand::Toggle,
+        "skip" => IpcCommand::Skip,
+        "stop" => IpcCommand::Stop,
+        _ => return None,
+    };
+    Some(command)
+}
+
+/// Status line sent back after a command
+pub fn status_text(status: &IpcStatus) -> String {
+    let icon = match status.session_type.as_str() {
+        "short_break" => "☕",
+        "long_break" => "🌴",
+        _ => "🍅",
+    };
+    format!(
+        "{} {} ({}, {}/{})",
+        icon,
+        status.remaining_formatted,
+        status.state,
+        status.current_session,
+        status.total_sessions
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert!(matches!(parse_command("/status"), Some(IpcCommand::Status)));
+        assert!(matches!(
+            parse_command("/Pause@PomodoRustBot now"),
+            Some(IpcCommand::Pause)
+        ));
+        assert!(parse_command("pause").is_none());
+        assert!(parse_command("/reboot").is_none());
+    }
+
+    #[test]
+    fn test_parse_updates() {
+        let updates = parse_updates(
+            r#"{"ok":true,"result":[{"update_id":7,"message":{"message_id":1,"chat":{"id":42,"type":"private"},"text":"/start"}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(updates[0].update_id, 7);
+        assert_eq!(updates[0].message.as_ref().unwrap().chat.id, 42);
+
+        let err = parse_updates(r#"{"ok":false,"error_code":401,"description":"Unauthorized"}"#);
+        assert_eq!(err.err().as_deref(), Some("Unauthorized"));
+    }
+}
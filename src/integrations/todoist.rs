@@ -0,0 +1,297 @@
+//! Todoist tasks
+//!
+//! Pulls the tasks matching a filter into the task list, comments on a task
+//! when a focus session on it ends and closes it once it's done in the app.
+//! Talks to the REST API at `https://api.todoist.com/api/v1` with the token
+//! from the config; requests run on a background thread and the replies are
+//! picked up by the app on its next frame.
+
+use std::collections::HashSet;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use super::http_client;
+use crate::data::todo::Priority;
+use crate::data::TodoistConfig;
+
+const API: &str = "https://api.todoist.com/api/v1";
+
+/// Pages of tasks fetched at most per sync
+const MAX_PAGES: usize = 10;
+
+/// An open Todoist task
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TodoistTask {
+    pub id: String,
+    pub content: String,
+    #[serde(default)]
+    pub description: String,
+    /// 1 (normal) to 4 (urgent)
+    #[serde(default = "default_priority")]
+    pub priority: u8,
+}
+
+fn default_priority() -> u8 {
+    1
+}
+
+impl TodoistTask {
+    /// Priority of the task in the app's task list
+    pub fn local_priority(&self) -> Priority {
+        match self.priority {
+            4 => Priority::Urgent,
+            3 => Priority::High,
+            2 => Priority::Medium,
+            _ => Priority::None,
+        }
+    }
+}
+
+/// Result of a request, delivered to the app
+#[derive(Debug)]
+pub enum TodoistReply {
+    /// The tasks matching the filter
+    Tasks(Vec<TodoistTask>),
+    /// The task of this local todo was closed
+    Closed(i64),
+    Failed(String),
+}
+
+enum Request {
+    Fetch,
+    Comment { task_id: String, content: String },
+    Close { todo_id: i64, task_id: String },
+}
+
+/// Background Todoist client
+pub struct TodoistClient {
+    tx: Sender<Request>,
+    rx: Receiver<TodoistReply>,
+    sync_interval: Duration,
+    synced_at: Option<Instant>,
+    /// Local todos whose task is being closed
+    closing: HashSet<i64>,
+}
+
+impl TodoistClient {
+    /// Spawn the request thread, waking the UI through `ctx` on replies
+    pub fn start(config: TodoistConfig, ctx: egui::Context) -> Self {
+        let (tx, requests) = mpsc::channel();
+        let (replies, rx) = mpsc::channel();
+        let sync_interval = Duration::from_secs(u64::from(config.sync_minutes.max(1)) * 60);
+
+        let spawned = thread::Builder::new()
+            .name("todoist".into())
+            .spawn(move || worker_loop(requests, replies, config, ctx));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start Todoist integration: {}", e);
+        }
+
+        Self {
+            tx,
+            rx,
+            sync_interval,
+            synced_at: None,
+            closing: HashSet::new(),
+        }
+    }
+
+    /// Fetch the tasks if the last sync is older than the sync interval
+    pub fn sync_if_due(&mut self) {
+        if self
+            .synced_at
+            .is_some_and(|at| at.elapsed() < self.sync_interval)
+        {
+            return;
+        }
+        self.sync();
+    }
+
+    /// Fetch the tasks now
+    pub fn sync(&mut self) {
+        self.synced_at = Some(Instant::now());
+        let _ = self.tx.send(Request::Fetch);
+    }
+
+    /// Add a comment to a task
+    pub fn comment(&self, task_id: &str, content: String) {
+        let _ = self.tx.send(Request::Comment {
+            task_id: task_id.to_string(),
+            content,
+        });
+    }
+
+    /// Close the task of a local todo, unless that's already under way
+    pub fn close(&mut self, todo_id: i64, task_id: &str) {
+        if self.closing.insert(todo_id) {
+            let _ = self.tx.send(Request::Close {
+                todo_id,
+                task_id: task_id.to_string(),
+            });
+        }
+    }
+
+    /// Next reply from the request thread
+    pub fn poll(&mut self) -> Option<TodoistReply> {
+        let reply = self.rx.try_recv().ok()?;
+        match reply {
+            TodoistReply::Closed(todo_id) => {
+                self.closing.remove(&todo_id);
+            }
+            // Try again on the next refresh
+            TodoistReply::Failed(_) => self.closing.clear(),
+            TodoistReply::Tasks(_) => {}
+        }
+        Some(reply)
+    }
+}
+
+/// Worker thread: runs the requests one at a time
+fn worker_loop(
+    requests: Receiver<Request>,
+    replies: Sender<TodoistReply>,
+    config: TodoistConfig,
+    ctx: egui::Context,
+) {
+    let auth = format!("Bearer {}", config.token.trim());
+    let headers = [
+        ("Authorization", auth.as_str()),
+        ("Content-Type", "application/json"),
+    ];
+
+    for request in requests {
+        let reply = match request {
+            Request::Fetch => match fetch_tasks(&headers, &config.filter) {
+                Ok(tasks) => TodoistReply::Tasks(tasks),
+                Err(e) => TodoistReply::Failed(e),
+            },
+            Request::Comment { task_id, content } => {
+                let body = serde_json::json!({ "task_id": task_id, "content": content });
+                let url = format!("{}/comments", API);
+                match send(&url, &headers, Some(&body.to_string())) {
+                    Ok(_) => continue,
+                    Err(e) => TodoistReply::Failed(e),
+                }
+            }
+            Request::Close { todo_id, task_id } => {
+                let url = format!("{}/tasks/{}/close", API, task_id);
+                match send(&url, &headers, None) {
+                    Ok(_) => TodoistReply::Closed(todo_id),
+                    Err(e) => TodoistReply::Failed(e),
+                }
+            }
+        };
+        if let TodoistReply::Failed(ref e) = reply {
+            tracing::warn!("Todoist request failed: {}", e);
+        }
+        if replies.send(reply).is_err() {
+            break;
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// All pages of the tasks matching `filter`, or every open task when it's
+/// empty
+fn fetch_tasks(headers: &[(&str, &str)], filter: &str) -> Result<Vec<TodoistTask>, String> {
+    let mut tasks = Vec::new();
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_PAGES {
+        let reply =
+            http_client::request("GET", &tasks_url(filter, cursor.as_deref()), headers, None)?;
+        if !reply.is_success() {
+            return Err(format!("HTTP {}: {}", reply.status, reply.body.trim()));
+        }
+        let page = parse_page(&reply.body)?;
+        tasks.extend(page.results);
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    Ok(tasks)
+}
+
+fn send(url: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<(), String> {
+    let reply = http_client::request("POST", url, headers, body)?;
+    if reply.is_success() {
+        Ok(())
+    } else {
+        Err(format!("HTTP {}: {}", reply.status, reply.body.trim()))
+    }
+}
+
+#[derive(Deserialize)]
+struct Page {
+    results: Vec<TodoistTask>,
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+fn parse_page(body: &str) -> Result<Page, String> {
+    serde_json::from_str(body).map_err(|e| format!("Unexpected reply: {}", e))
+}
+
+/// Endpoint listing the tasks, through the filter endpoint when there's one
+fn tasks_url(filter: &str, cursor: Option<&str>) -> String {
+    let filter = filter.trim();
+    let mut url = if filter.is_empty() {
+        format!("{}/tasks?limit=200", API)
+    } else {
+        format!(
+            "{}/tasks/filter?limit=200&query={}",
+            API,
+            url_encode(filter)
+        )
+    };
+    if let Some(cursor) = cursor {
+        url.push_str("&cursor=");
+        url.push_str(&url_encode(cursor));
+    }
+    url
+}
+
+/// Percent-encode a query parameter value
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tasks_url() {
+        assert_eq!(tasks_url("", None), format!("{}/tasks?limit=200", API));
+        assert_eq!(
+            tasks_url("today | overdue", Some("abc=")),
+            format!(
+                "{}/tasks/filter?limit=200&query=today%20%7C%20overdue&cursor=abc%3D",
+                API
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_page() {
+        let page = parse_page(
+            r#"{"results":[{"id":"6X7","content":"Write report","description":"","priority":4,"labels":[]}],"next_cursor":null}"#,
+        )
+        .unwrap();
+        assert!(page.next_cursor.is_none());
+        assert_eq!(page.results[0].id, "6X7");
+        assert_eq!(page.results[0].local_priority(), Priority::Urgent);
+        assert!(parse_page("[]").is_err());
+    }
+}
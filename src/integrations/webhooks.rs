@@ -0,0 +1,252 @@
+//! Outgoing webhooks
+//!
+//! POSTs a templated body to each configured endpoint when a matching timer
+//! event happens, for Zapier/IFTTT/n8n style automations. Failed deliveries
+//! are retried with exponential backoff and every outcome is appended to a
+//! delivery log (`webhooks.log` in the data directory, one JSON object per
+//! line).
+//!
+//! Template placeholders: `{{event}}`, `{{session_type}}`, `{{state}}`,
+//! `{{remaining_secs}}`, `{{remaining}}` (MM:SS), `{{duration_secs}}`,
+//! `{{duration_minutes}}`, `{{session_number}}`, `{{total_sessions}}`,
+//! `{{tag}}`, `{{timestamp}}` and `{{json}}` (the whole event). Values are
+//! JSON-escaped when the content type is JSON.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use super::http_client;
+use crate::data::{Database, WebhookEndpoint, WebhooksConfig};
+use crate::ipc::IpcEvent;
+
+/// Delivery log is rotated once it grows past this size
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// A rendered request waiting to be delivered
+struct Delivery {
+    url: String,
+    content_type: String,
+    body: String,
+    event: &'static str,
+}
+
+/// Background webhook sender
+pub struct WebhookDispatcher {
+    tx: Sender<Delivery>,
+    endpoints: Vec<WebhookEndpoint>,
+}
+
+impl WebhookDispatcher {
+    /// Spawn the delivery thread for the given configuration
+    pub fn start(config: WebhooksConfig) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let max_retries = config.max_retries;
+
+        let spawned = thread::Builder::new()
+            .name("webhooks".into())
+            .spawn(move || worker_loop(rx, max_retries));
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start webhook dispatcher: {}", e);
+        }
+
+        Self {
+            tx,
+            endpoints: config.endpoints,
+        }
+    }
+
+    /// Queue deliveries for every endpoint subscribed to this event
+    pub fn dispatch(&self, event: &IpcEvent) {
+        let name = event.event.as_str();
+        for endpoint in &self.endpoints {
+            let subscribed =
+                endpoint.events.is_empty() || endpoint.events.iter().any(|e| e == name);
+            if !subscribed || endpoint.url.is_empty() {
+                continue;
+            }
+
+            let body = if endpoint.template.trim().is_empty() {
+                event.to_json()
+            } else {
+                let json = endpoint.content_type.contains("json");
+                render_template(&endpoint.template, event, json)
+            };
+
+            let _ = self.tx.send(Delivery {
+                url: endpoint.url.clone(),
+                content_type: endpoint.content_type.clone(),
+                body,
+                event: name,
+            });
+        }
+    }
+}
+
+/// Worker thread: delivers queued webhooks one at a time
+fn worker_loop(rx: Receiver<Delivery>, max_retries: u32) {
+    for delivery in rx {
+        let headers = [("Content-Type", delivery.content_type.as_str())];
+        let mut attempt = 0;
+
+        let outcome = loop {
+            attempt += 1;
+            let result =
+                http_client::request("POST", &delivery.url, &headers, Some(&delivery.body));
+            match result {
+                Ok(reply) if reply.is_success() => break Ok(reply.status),
+                Ok(reply) if attempt > max_retries => break Err(format!("HTTP {}", reply.status)),
+                Err(e) if attempt > max_retries => break Err(e),
+                Ok(_) | Err(_) => {
+                    // 2s, 4s, 8s, ... capped at a minute
+                    let backoff = 2u64.saturating_pow(attempt).min(60);
+                    thread::sleep(Duration::from_secs(backoff));
+                }
+            }
+        };
+
+        match &outcome {
+            Ok(status) => tracing::debug!("Webhook {} delivered ({})", delivery.url, status),
+            Err(e) => tracing::warn!(
+                "Webhook {} failed after {} attempts: {}",
+                delivery.url,
+                attempt,
+                e
+            ),
+        }
+        log_delivery(&delivery, attempt, &outcome);
+    }
+}
+
+/// Append a delivery outcome to the log file
+fn log_delivery(delivery: &Delivery, attempts: u32, outcome: &Result<u16, String>) {
+    let Some(path) = log_path() else {
+        return;
+    };
+
+    // Keep the log bounded by rotating it to `webhooks.log.old`
+    if std::fs::metadata(&path).is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+        let _ = std::fs::rename(&path, path.with_extension("log.old"));
+    }
+
+    let entry = serde_json::json!({
+        "timestamp": Utc::now().to_rfc3339(),
+        "url": delivery.url,
+        "event": delivery.event,
+        "attempts": attempts,
+        "status": outcome.as_ref().ok(),
+        "error": outcome.as_ref().err(),
+    });
+
+    let file = OpenOptions::new().create(true).append(true).open(&path);
+    if let Ok(mut file) = file {
+        let _ = writeln!(file, "{}", entry);
+    }
+}
+
+/// Path of the delivery log
+fn log_path() -> Option<PathBuf> {
+    Database::data_dir().map(|dir| dir.join("webhooks.log"))
+}
+
+/// Substitute `{{placeholder}}`s in a template
+fn render_template(template: &str, event: &IpcEvent, json: bool) -> String {
+    let status = &event.status;
+    let escape = |value: &str| -> String {
+        if json {
+            // serde_json quotes the string; strip the quotes for embedding
+            let quoted = serde_json::to_string(value).unwrap_or_default();
+            quoted[1..quoted.len() - 1].to_string()
+        } else {
+            value.to_string()
+        }
+    };
+
+    let values: [(&str, String); 12] = [
+        ("event", event.event.as_str().to_string()),
+        ("session_type", event.session_type.clone()),
+        ("state", status.state.clone()),
+        ("remaining_secs", status.remaining_secs.to_string()),
+        ("remaining", status.remaining_formatted.clone()),
+        ("duration_secs", event.session_secs().to_string()),
+        ("duration_minutes", (event.session_secs() / 60).to_string()),
+        ("session_number", status.current_session.to_string()),
+        ("total_sessions", status.total_sessions.to_string()),
+        ("tag", status.tag.clone().unwrap_or_default()),
+        ("timestamp", event.timestamp.clone()),
+        ("json", event.to_json()),
+    ];
+
+    let mut rendered = template.to_string();
+    for (name, value) in values {
+        let placeholder = format!("{{{{{}}}}}", name);
+        if rendered.contains(&placeholder) {
+            // The whole-event JSON is inserted verbatim
+            let value = if name == "json" {
+                value
+            } else {
+                escape(&value)
+            };
+            rendered = rendered.replace(&placeholder, &value);
+        }
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc::{IpcEventKind, IpcStatus};
+
+    /// A completed pomodoro, the status already showing the break after it
+    fn event() -> IpcEvent {
+        IpcEvent {
+            event: IpcEventKind::Completed,
+            session_type: "work".to_string(),
+            status: IpcStatus {
+                state: "idle".to_string(),
+                session_type: "short_break".to_string(),
+                remaining_secs: 300,
+                remaining_formatted: "05:00".to_string(),
+                progress: 0.0,
+                current_session: 2,
+                total_sessions: 4,
+                total_duration_secs: 300,
+                tag: Some("say \"hi\"".to_string()),
+            },
+            duration_secs: Some(1500),
+            timestamp: "2024-01-01T10:00:00+00:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let rendered = render_template(
+            r#"{"text": "{{event}} {{session_type}} ({{duration_minutes}} min) {{tag}}"}"#,
+            &event(),
+            true,
+        );
+        assert_eq!(
+            rendered,
+            r#"{"text": "completed work (25 min) say \"hi\""}"#
+        );
+
+        let plain = render_template("{{tag}} {{unknown}}", &event(), false);
+        assert_eq!(plain, "say \"hi\" {{unknown}}");
+    }
+
+    #[test]
+    fn test_completed_duration_is_the_finished_session() {
+        let rendered = render_template(
+            "{{session_type}} {{duration_secs}} then {{state}} {{remaining_secs}}",
+            &event(),
+            false,
+        );
+        assert_eq!(rendered, "work 1500 then idle 300");
+    }
+}
@@ -0,0 +1,173 @@
+//! Finding the running app
+//!
+//! The app listens on [`IPC_PORT`](super::IPC_PORT), or `POMODORUST_PORT`
+//! when set. When it can't have that port, say because another program
+//! holds it, it listens on one the system picks instead. Either way it
+//! writes its port to `ipc-port` in the data directory. Clients try that
+//! port, then the configured one, and only take a port for the app's when
+//! it answers `ping` with [`APP_NAME`] and the profile in use, so neither
+//! another program nor the app of another profile is mistaken for it.
+
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use super::protocol::{IpcCommand, IpcResponse};
+use super::{ipc_address, ipc_port};
+use crate::data::{instance, Database};
+
+/// Name the app gives in its answer to `ping`
+pub const APP_NAME: &str = "pomodorust";
+
+/// File in the data directory holding the port the app listens on
+const PORT_FILE: &str = "ipc-port";
+
+/// How long a port gets to answer `ping`
+const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// What was found looking for the running app
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lookup {
+    /// The app answers on this address
+    Running(String),
+    /// Another program holds the configured port, and the app wasn't found
+    PortTaken(u16),
+    /// Nothing answers
+    NotRunning,
+}
+
+impl fmt::Display for Lookup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lookup::Running(address) => write!(f, "Pomodorust is running on {}", address),
+            Lookup::PortTaken(port) => write!(
+                f,
+                "Port {} is used by another program, not Pomodorust. \
+                 Set POMODORUST_PORT to a free port.",
+                port
+            ),
+            Lookup::NotRunning => write!(f, "Cannot connect to Pomodorust. Is it running?"),
+        }
+    }
+}
+
+/// What answers on an address
+#[derive(Debug, PartialEq, Eq)]
+enum Probe {
+    App,
+    Other,
+    Nothing,
+}
+
+/// Look for the running app, on the port it wrote down first
+pub fn lookup() -> Lookup {
+    let configured = ipc_address();
+    let published = published_port()
+        .map(|port| format!("127.0.0.1:{}", port))
+        .filter(|address| *address != configured);
+    if let Some(address) = published {
+        if probe(&address) == Probe::App {
+            return Lookup::Running(address);
+        }
+    }
+    match probe(&configured) {
+        Probe::App => Lookup::Running(configured),
+        Probe::Other => Lookup::PortTaken(ipc_port()),
+        Probe::Nothing => Lookup::NotRunning,
+    }
+}
+
+/// Ask `address` for a pong naming the app
+fn probe(address: &str) -> Probe {
+    let Ok(socket) = address.parse::<SocketAddr>() else {
+        return Probe::Nothing;
+    };
+    let Ok(mut stream) = TcpStream::connect_timeout(&socket, PROBE_TIMEOUT) else {
+        return Probe::Nothing;
+    };
+    let _ = stream.set_read_timeout(Some(PROBE_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(PROBE_TIMEOUT));
+    if writeln!(stream, "{}", IpcCommand::Ping.to_json()).is_err() {
+        return Probe::Other;
+    }
+
+    let mut line = String::new();
+    let _ = BufReader::new(stream).read_line(&mut line);
+    match IpcResponse::from_json(line.trim()) {
+        Ok(IpcResponse::Pong { app, profile, .. })
+            if app == APP_NAME && profile.as_deref() == instance::profile() =>
+        {
+            Probe::App
+        }
+        _ => Probe::Other,
+    }
+}
+
+fn port_file() -> Option<PathBuf> {
+    Database::data_dir().map(|dir| dir.join(PORT_FILE))
+}
+
+/// Port the app wrote down, if any
+fn published_port() -> Option<u16> {
+    std::fs::read_to_string(port_file()?)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Write down the port the app listens on
+pub(super) fn publish_port(port: u16) {
+    let Some(path) = port_file() else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Err(e) = std::fs::write(&path, port.to_string()) {
+        tracing::warn!("Failed to write IPC port to {:?}: {}", path, e);
+    }
+}
+
+/// Forget the port once the app stops listening
+pub(super) fn unpublish_port() {
+    if let Some(path) = port_file() {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Address of a listener answering one connection with `reply`
+    fn answering(reply: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let reply = reply.to_string();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut line = String::new();
+            let _ = BufReader::new(stream.try_clone().unwrap()).read_line(&mut line);
+            let _ = writeln!(stream, "{}", reply);
+        });
+        address
+    }
+
+    #[test]
+    fn test_probe_tells_the_app_from_other_programs() {
+        let pong = IpcResponse::pong().to_json();
+        assert_eq!(probe(&answering(&pong)), Probe::App);
+        assert_eq!(probe(&answering(r#"{"type":"pong"}"#)), Probe::Other);
+        assert_eq!(probe(&answering("HTTP/1.1 400 Bad Request")), Probe::Other);
+
+        let closed = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = closed.local_addr().unwrap().to_string();
+        drop(closed);
+        assert_eq!(probe(&address), Probe::Nothing);
+    }
+}
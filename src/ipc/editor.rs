@@ -0,0 +1,111 @@
+//! Editor status lines
+//!
+//! Editor plugins (VS Code, Neovim, ...) poll `GET /editor` every few
+//! seconds as a heartbeat and put its `text` in the status line. Clicking
+//! it can `POST /editor?action=toggle` or `POST /editor?action=skip`, which
+//! answer with the new status. The endpoint needs `http.token` to be set
+//! and presented even on loopback, as any local process could reach it.
+//!
+//! Payload:
+//!
+//! ```json
+//! {
+//!   "text": "🍅 12:34",
+//!   "tooltip": "Focus 2/4 · writing",
+//!   "state": "running",
+//!   "session_type": "work",
+//!   "remaining_secs": 754,
+//!   "progress": 0.5
+//! }
+//! ```
+//!
+//! `text` is the session icon (⏸ while paused) and the remaining time, and
+//! `tooltip` is in the app's language. `state` is `idle`, `running`,
+//! `paused` or `completed`, `session_type` is `work`, `short_break` or
+//! `long_break`, and `progress` goes from 0 to 1.
+
+use serde::Serialize;
+
+use super::protocol::IpcStatus;
+use crate::core::SessionType;
+use crate::i18n::tr;
+
+/// Timer status for an editor status line
+#[derive(Debug, Clone, Serialize)]
+pub struct EditorStatus {
+    /// Short text for the status line
+    pub text: String,
+    /// Longer text for the hover
+    pub tooltip: String,
+    pub state: String,
+    pub session_type: String,
+    pub remaining_secs: u64,
+    pub progress: f32,
+}
+
+impl EditorStatus {
+    /// Build the status line for the current timer status
+    pub fn from_status(status: &IpcStatus) -> Self {
+        let session = SessionType::from_db_str(&status.session_type).unwrap_or(SessionType::Work);
+        let icon = if status.state == "paused" {
+            "⏸"
+        } else {
+            session.icon()
+        };
+        let tr = &tr().editor;
+        let label = match session {
+            SessionType::Work => tr.focus,
+            SessionType::ShortBreak => tr.short_break,
+            SessionType::LongBreak => tr.long_break,
+        };
+
+        let mut tooltip = format!(
+            "{} {}/{}",
+            label, status.current_session, status.total_sessions
+        );
+        if let Some(tag) = &status.tag {
+            tooltip.push_str(" · ");
+            tooltip.push_str(tag);
+        }
+
+        Self {
+            text: format!("{} {}", icon, status.remaining_formatted),
+            tooltip,
+            state: status.state.clone(),
+            session_type: status.session_type.clone(),
+            remaining_secs: status.remaining_secs,
+            progress: status.progress,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_status() {
+        let mut status = IpcStatus {
+            state: "running".to_string(),
+            session_type: "work".to_string(),
+            remaining_secs: 754,
+            remaining_formatted: "12:34".to_string(),
+            progress: 0.5,
+            current_session: 2,
+            total_sessions: 4,
+            total_duration_secs: 1500,
+            tag: Some("writing".to_string()),
+        };
+
+        let editor = EditorStatus::from_status(&status);
+        assert_eq!(editor.text, "🍅 12:34");
+        assert_eq!(editor.tooltip, "Focus 2/4 · writing");
+
+        status.state = "paused".to_string();
+        status.session_type = "short_break".to_string();
+        status.tag = None;
+        let editor = EditorStatus::from_status(&status);
+        assert_eq!(editor.text, "⏸ 12:34");
+        assert_eq!(editor.tooltip, "Short break 2/4");
+    }
+}
@@ -0,0 +1,667 @@
+//! Embedded HTTP API
+//!
+//! A small HTTP/1.1 server that exposes the IPC commands as REST endpoints
+//! so browser extensions, mobile shortcuts and scripts can control the timer
+//! without the CLI. Requests are translated into [`IpcCommand`]s and go
+//! through the same command pipeline as the CLI socket.
+//!
+//! Endpoints:
+//! - `GET /status`, `GET /stats?period=today|week|month|year|all`,
+//!   `GET /stats?from=YYYY-MM-DD&to=YYYY-MM-DD`, `GET /ping`
+//! - `POST /start[?type=work|short|long&minutes=N&tag=T]`, `POST /pause`, `POST /resume`,
+//!   `POST /toggle`, `POST /stop`, `POST /skip`
+//! - `GET /`: a small web dashboard (timer + today's stats) built on the API
+//! - `GET /key`, `GET /icon.svg`: key state and images for Stream Deck
+//!   plugins (see [`super::streamdeck`])
+//! - `GET /editor`, `POST /editor?action=toggle|skip`: status line for
+//!   editor plugins, token required (see [`super::editor`])
+//!
+//! Browser requests carrying an `Origin` header are only accepted when they
+//! come from the dashboard itself or from an origin listed in
+//! `allowed_origins`, which keeps arbitrary web pages from controlling the
+//! timer. Without a token, the `Host` header must also name the server by
+//! its loopback address (`127.0.0.1`, `localhost` or `[::1]` with the port),
+//! which defeats DNS rebinding.
+//!
+//! The server binds to loopback by default. Binding to a LAN address (for
+//! remote control from another machine) is only allowed together with a
+//! `token`, which every API request must then present.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, TcpListener, TcpStream};
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use super::editor::EditorStatus;
+use super::protocol::{IpcCommand, IpcResponse};
+use super::server::{CommandChannel, ConnectionSlot};
+use super::streamdeck::{self, KeyState};
+use crate::data::HttpConfig;
+
+/// Largest request body we are willing to drain
+const MAX_BODY_LEN: usize = 64 * 1024;
+
+/// Web dashboard served at `/`
+const DASHBOARD_HTML: &str = include_str!("../../assets/web/index.html");
+
+/// A parsed HTTP request (only the parts the API needs)
+#[derive(Debug, Default)]
+struct HttpRequest {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    origin: Option<String>,
+    host: Option<String>,
+    /// Bearer token from the `Authorization` header
+    bearer: Option<String>,
+}
+
+impl HttpRequest {
+    /// Whether the request presents the configured token
+    fn is_authorized(&self, token: Option<&str>) -> bool {
+        let Some(expected) = token.filter(|t| !t.is_empty()) else {
+            return true;
+        };
+        let presented = self.bearer.as_deref().or_else(|| self.param("token"));
+        presented.is_some_and(|p| constant_time_eq(p.as_bytes(), expected.as_bytes()))
+    }
+
+    /// Whether the `Host` header names this server. Without a token, only
+    /// the loopback names are accepted, so a page whose domain was rebound
+    /// to 127.0.0.1 can't pass itself off as the dashboard. With a token the
+    /// API may be reached under any LAN name, and the token guards it.
+    fn is_allowed_host(&self, config: &HttpConfig) -> bool {
+        if config.token.as_deref().is_some_and(|t| !t.is_empty()) {
+            return true;
+        }
+        let Some(host) = &self.host else {
+            return false;
+        };
+        [
+            "127.0.0.1",
+            "localhost",
+            "[::1]",
+            config.bind_address.as_str(),
+        ]
+        .iter()
+        .any(|name| host.eq_ignore_ascii_case(&format!("{}:{}", name, config.port)))
+    }
+
+    /// Whether the request comes from a page served by this server
+    fn is_same_origin(&self) -> bool {
+        match (&self.origin, &self.host) {
+            (Some(origin), Some(host)) => origin
+                .strip_prefix("http://")
+                .is_some_and(|o| o.eq_ignore_ascii_case(host)),
+            _ => false,
+        }
+    }
+
+    /// Get a query parameter by name
+    fn param(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A response ready to be written to the socket
+struct HttpResponse {
+    status: u16,
+    reason: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+impl HttpResponse {
+    fn json(status: u16, reason: &'static str, body: String) -> Self {
+        Self {
+            status,
+            reason,
+            content_type: "application/json",
+            body,
+        }
+    }
+
+    fn from_ipc(response: &IpcResponse) -> Self {
+        match response {
+            IpcResponse::Error { .. } => Self::json(400, "Bad Request", response.to_json()),
+            _ => Self::json(200, "OK", response.to_json()),
+        }
+    }
+
+    fn error(status: u16, reason: &'static str, message: &str) -> Self {
+        Self::json(status, reason, IpcResponse::error(message).to_json())
+    }
+
+    fn svg(body: String) -> Self {
+        Self {
+            status: 200,
+            reason: "OK",
+            content_type: "image/svg+xml",
+            body,
+        }
+    }
+
+    fn html(body: &str) -> Self {
+        Self {
+            status: 200,
+            reason: "OK",
+            content_type: "text/html; charset=utf-8",
+            body: body.to_string(),
+        }
+    }
+
+    fn no_content() -> Self {
+        Self {
+            status: 204,
+            reason: "No Content",
+            content_type: "text/plain",
+            body: String::new(),
+        }
+    }
+}
+
+/// Start the HTTP server thread
+pub(super) fn start(
+    config: HttpConfig,
+    channel: CommandChannel,
+    running: Arc<Mutex<bool>>,
+) -> Option<thread::JoinHandle<()>> {
+    let loopback = config
+        .bind_address
+        .parse::<IpAddr>()
+        .is_ok_and(|ip| ip.is_loopback())
+        || config.bind_address.eq_ignore_ascii_case("localhost");
+    let has_token = config.token.as_deref().is_some_and(|t| !t.is_empty());
+    if !loopback && !has_token {
+        tracing::error!(
+            "Refusing to expose the HTTP API on {} without a token; set http.token",
+            config.bind_address
+        );
+        return None;
+    }
+
+    let address = format!("{}:{}", config.bind_address, config.port);
+    let listener = match TcpListener::bind(&address) {
+        Ok(l) => l,
+        Err(e) => {
+            tracing::warn!("Failed to bind HTTP API on {}: {}", address, e);
+            return None;
+        }
+    };
+
+    if let Err(e) = listener.set_nonblocking(true) {
+        tracing::warn!("Failed to set non-blocking: {}", e);
+    }
+
+    let config = Arc::new(config);
+    let open = Arc::new(AtomicUsize::new(0));
+    let spawned = thread::Builder::new()
+        .name("http-api".into())
+        .spawn(move || loop {
+            if let Ok(r) = running.lock() {
+                if !*r {
+                    break;
+                }
+            }
+
+            match listener.accept() {
+                Ok((stream, _addr)) => {
+                    let Some(slot) = ConnectionSlot::acquire(&open) else {
+                        tracing::warn!("HTTP connection refused: too many clients");
+                        continue;
+                    };
+                    let channel = channel.clone();
+                    let config = config.clone();
+                    thread::spawn(move || {
+                        handle_connection(stream, &config, &channel);
+                        drop(slot);
+                    });
+                }
+                Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Err(e) => {
+                    tracing::error!("HTTP accept error: {}", e);
+                    thread::sleep(Duration::from_millis(100));
+                }
+            }
+        });
+
+    match spawned {
+        Ok(handle) => {
+            tracing::info!("HTTP API listening on http://{}", address);
+            Some(handle)
+        }
+        Err(e) => {
+            tracing::error!("Failed to start HTTP API: {}", e);
+            None
+        }
+    }
+}
+
+/// Handle a single HTTP request and close the connection
+fn handle_connection(mut stream: TcpStream, config: &HttpConfig, channel: &CommandChannel) {
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+    let _ = stream.set_write_timeout(Some(Duration::from_secs(5)));
+
+    let request = match stream.try_clone().map(read_request) {
+        Ok(Some(request)) => request,
+        Ok(None) => {
+            let response = HttpResponse::error(400, "Bad Request", "Malformed request");
+            write_response(&mut stream, &response, None);
+            return;
+        }
+        Err(e) => {
+            tracing::debug!("HTTP read error: {}", e);
+            return;
+        }
+    };
+    tracing::debug!("HTTP {} {}", request.method, request.path);
+
+    if !request.is_allowed_host(config) {
+        let response = HttpResponse::error(403, "Forbidden", "Host not allowed");
+        write_response(&mut stream, &response, None);
+        return;
+    }
+
+    // Reject browser requests from origins that were not explicitly allowed
+    let allowed_origin = match request.origin {
+        Some(_) if request.is_same_origin() => None,
+        Some(ref origin) if origin_allowed(&config.allowed_origins, origin) => Some(origin.clone()),
+        Some(_) => {
+            let response = HttpResponse::error(403, "Forbidden", "Origin not allowed");
+            write_response(&mut stream, &response, None);
+            return;
+        }
+        None => None,
+    };
+
+    let is_dashboard =
+        request.method == "GET" && matches!(request.path.as_str(), "/" | "/index.html");
+
+    let response = if request.method == "OPTIONS" {
+        // CORS preflight
+        HttpResponse::no_content()
+    } else if is_dashboard {
+        // The page itself is static; its API calls carry the token
+        HttpResponse::html(DASHBOARD_HTML)
+    } else if !request.is_authorized(config.token.as_deref()) {
+        HttpResponse::error(401, "Unauthorized", "Missing or invalid token")
+    } else if request.method == "GET" && matches!(request.path.as_str(), "/key" | "/icon.svg") {
+        stream_deck_response(&request, channel)
+    } else if request.path.trim_end_matches('/') == "/editor" {
+        editor_response(&request, config, channel)
+    } else {
+        match route(&request) {
+            Ok(cmd) => HttpResponse::from_ipc(&channel.dispatch(cmd)),
+            Err(response) => response,
+        }
+    };
+
+    write_response(&mut stream, &response, allowed_origin.as_deref());
+}
+
+/// Render the Stream Deck key state or image for the current status
+fn stream_deck_response(request: &HttpRequest, channel: &CommandChannel) -> HttpResponse {
+    let status = match channel.dispatch(IpcCommand::Status) {
+        IpcResponse::Status(status) => status,
+        other => return HttpResponse::from_ipc(&other),
+    };
+
+    if request.path == "/key" {
+        let key = KeyState::from_status(&status);
+        HttpResponse::json(200, "OK", serde_json::to_string(&key).unwrap_or_default())
+    } else {
+        let size = request
+            .param("size")
+            .and_then(|s| s.parse().ok())
+            .filter(|s| (16..=512).contains(s))
+            .unwrap_or(streamdeck::DEFAULT_ICON_SIZE);
+        HttpResponse::svg(streamdeck::render_icon(&status, size))
+    }
+}
+
+/// Run the editor's action, if any, and answer with its status line
+fn editor_response(
+    request: &HttpRequest,
+    config: &HttpConfig,
+    channel: &CommandChannel,
+) -> HttpResponse {
+    // The token was checked already; here it also has to exist
+    if config.token.as_deref().is_none_or(str::is_empty) {
+        return HttpResponse::error(403, "Forbidden", "Set http.token to use /editor");
+    }
+    let action = match editor_action(request) {
+        Ok(action) => action,
+        Err(response) => return response,
+    };
+    if let Some(cmd) = action {
+        let response = channel.dispatch(cmd);
+        if let IpcResponse::Error { .. } = response {
+            return HttpResponse::from_ipc(&response);
+        }
+    }
+
+    match channel.dispatch(IpcCommand::Status) {
+        IpcResponse::Status(status) => {
+            let editor = EditorStatus::from_status(&status);
+            HttpResponse::json(
+                200,
+                "OK",
+                serde_json::to_string(&editor).unwrap_or_default(),
+            )
+        }
+        other => HttpResponse::from_ipc(&other),
+    }
+}
+
+/// Command for an editor request: none for the heartbeat, toggle or skip
+fn editor_action(request: &HttpRequest) -> Result<Option<IpcCommand>, HttpResponse> {
+    match (request.method.as_str(), request.param("action")) {
+        ("GET", None) => Ok(None),
+        ("POST", Some("toggle")) => Ok(Some(IpcCommand::Toggle)),
+        ("POST", Some("skip")) => Ok(Some(IpcCommand::Skip)),
+        ("POST", _) => Err(HttpResponse::error(
+            400,
+            "Bad Request",
+            "Unknown action, use toggle or skip",
+        )),
+        _ => Err(HttpResponse::error(
+            405,
+            "Method Not Allowed",
+            "Method not allowed",
+        )),
+    }
+}
+
+/// Map an HTTP request to an IPC command
+fn route(request: &HttpRequest) -> Result<IpcCommand, HttpResponse> {
+    let path = request.path.trim_end_matches('/');
+    let cmd = match path {
+        "/status" => IpcCommand::Status,
+        "/stats" => IpcCommand::Stats {
+            period: request.param("period").unwrap_or("today").to_string(),
+            from: request.param("from").map(str::to_string),
+            to: request.param("to").map(str::to_string),
+        },
+        "/ping" => IpcCommand::Ping,
+        "/start" => IpcCommand::Start {
+            session_type: request.param("type").map(str::to_string),
+            minutes: request.param("minutes").and_then(|m| m.parse().ok()),
+            tag: request.param("tag").map(str::to_string),
+        },
+        "/pause" => IpcCommand::Pause,
+        "/resume" => IpcCommand::Resume,
+        "/toggle" => IpcCommand::Toggle,
+        "/stop" => IpcCommand::Stop,
+        "/skip" => IpcCommand::Skip,
+        _ => return Err(HttpResponse::error(404, "Not Found", "Unknown endpoint")),
+    };
+
+    // Queries are read-only; everything else changes the timer and needs POST
+    let read_only = matches!(
+        cmd,
+        IpcCommand::Status | IpcCommand::Stats { .. } | IpcCommand::Ping
+    );
+    let method_ok = match request.method.as_str() {
+        "GET" => read_only,
+        "POST" => true,
+        _ => false,
+    };
+    if !method_ok {
+        return Err(HttpResponse::error(
+            405,
+            "Method Not Allowed",
+            "Method not allowed",
+        ));
+    }
+
+    Ok(cmd)
+}
+
+/// Check an `Origin` header against the configured allow list
+fn origin_allowed(allowed: &[String], origin: &str) -> bool {
+    allowed
+        .iter()
+        .any(|a| a == "*" || a.trim_end_matches('/').eq_ignore_ascii_case(origin))
+}
+
+/// Read the request line and headers, draining any body
+fn read_request(stream: TcpStream) -> Option<HttpRequest> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_ascii_uppercase();
+    let target = parts.next()?;
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let mut request = HttpRequest {
+        method,
+        path: path.to_string(),
+        query: parse_query(query),
+        origin: None,
+        host: None,
+        bearer: None,
+    };
+
+    let mut content_length = 0usize;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).ok()? == 0 {
+            break;
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("origin") {
+                request.origin = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case("host") {
+                request.host = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case("authorization") {
+                request.bearer = value.strip_prefix("Bearer ").map(|t| t.trim().to_string());
+            } else if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().unwrap_or(0);
+            }
+        }
+    }
+
+    // The API takes no body, but drain it so the client sees a clean close
+    if content_length > 0 {
+        let mut body = vec![0u8; content_length.min(MAX_BODY_LEN)];
+        let _ = reader.read_exact(&mut body);
+    }
+
+    Some(request)
+}
+
+/// Split a query string into key/value pairs
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|pair| {
+            let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(k), percent_decode(v))
+        })
+        .collect()
+}
+
+/// Decode `%XX` escapes and `+` in a query component
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Compare secrets without leaking the match length through timing
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Write a response, adding CORS headers for an allowed origin
+fn write_response(stream: &mut TcpStream, response: &HttpResponse, origin: Option<&str>) {
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.reason,
+        response.content_type,
+        response.body.len()
+    );
+    if let Some(origin) = origin {
+        head.push_str(&format!(
+            "Access-Control-Allow-Origin: {}\r\n\
+             Access-Control-Allow-Methods: GET, POST, OPTIONS\r\n\
+             Access-Control-Allow-Headers: Content-Type, Authorization\r\n\
+             Vary: Origin\r\n",
+            origin
+        ));
+    }
+    head.push_str("\r\n");
+
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(response.body.as_bytes());
+    let _ = stream.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, target: &str) -> HttpRequest {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        HttpRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: parse_query(query),
+            origin: None,
+            host: None,
+            bearer: None,
+        }
+    }
+
+    #[test]
+    fn test_routes() {
+        assert!(matches!(
+            route(&request("GET", "/status")),
+            Ok(IpcCommand::Status)
+        ));
+        assert!(matches!(
+            route(&request("GET", "/stats?period=week")),
+            Ok(IpcCommand::Stats { period, .. }) if period == "week"
+        ));
+        assert!(matches!(
+            route(&request("POST", "/start?type=long")),
+            Ok(IpcCommand::Start { session_type: Some(t), .. }) if t == "long"
+        ));
+        assert!(route(&request("GET", "/nope")).is_err());
+    }
+
+    #[test]
+    fn test_state_changes_require_post() {
+        let err = route(&request("GET", "/pause")).err().unwrap();
+        assert_eq!(err.status, 405);
+        assert!(route(&request("POST", "/pause")).is_ok());
+    }
+
+    #[test]
+    fn test_editor_actions() {
+        assert!(matches!(
+            editor_action(&request("GET", "/editor")),
+            Ok(None)
+        ));
+        assert!(matches!(
+            editor_action(&request("POST", "/editor?action=skip")),
+            Ok(Some(IpcCommand::Skip))
+        ));
+        let err = editor_action(&request("GET", "/editor?action=toggle"))
+            .err()
+            .unwrap();
+        assert_eq!(err.status, 405);
+        let err = editor_action(&request("POST", "/editor?action=stop"))
+            .err()
+            .unwrap();
+        assert_eq!(err.status, 400);
+    }
+
+    #[test]
+    fn test_same_origin() {
+        let mut req = request("POST", "/toggle");
+        req.host = Some("127.0.0.1:19848".to_string());
+        req.origin = Some("http://127.0.0.1:19848".to_string());
+        assert!(req.is_same_origin());
+        req.origin = Some("http://evil.example".to_string());
+        assert!(!req.is_same_origin());
+    }
+
+    #[test]
+    fn test_host_allow_list() {
+        let mut config = HttpConfig::default();
+        let mut req = request("GET", "/status");
+        assert!(!req.is_allowed_host(&config));
+        for host in ["127.0.0.1", "LOCALHOST", "[::1]"] {
+            req.host = Some(format!("{}:{}", host, config.port));
+            assert!(req.is_allowed_host(&config), "{}", host);
+        }
+        // A rebound domain, or the right name on another port
+        req.host = Some(format!("evil.example:{}", config.port));
+        assert!(!req.is_allowed_host(&config));
+        req.host = Some("127.0.0.1:1".to_string());
+        assert!(!req.is_allowed_host(&config));
+
+        // With a token the API can be reached under its LAN name
+        req.host = Some(format!("pomodoro.lan:{}", config.port));
+        config.token = Some("secret".to_string());
+        assert!(req.is_allowed_host(&config));
+    }
+
+    #[test]
+    fn test_token_auth() {
+        let mut req = request("GET", "/status?token=s%3Dcret");
+        assert!(req.is_authorized(None));
+        assert!(req.is_authorized(Some("s=cret")));
+        assert!(!req.is_authorized(Some("other")));
+
+        req.query.clear();
+        assert!(!req.is_authorized(Some("s=cret")));
+        req.bearer = Some("s=cret".to_string());
+        assert!(req.is_authorized(Some("s=cret")));
+    }
+
+    #[test]
+    fn test_origin_allowed() {
+        let allowed = vec!["http://localhost:3000/".to_string()];
+        assert!(origin_allowed(&allowed, "http://localhost:3000"));
+        assert!(!origin_allowed(&allowed, "https://evil.example"));
+        assert!(origin_allowed(&["*".to_string()], "https://any.example"));
+        assert!(!origin_allowed(&[], "http://localhost:3000"));
+    }
+}
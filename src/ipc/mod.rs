@@ -1,18 +1,48 @@
 //! IPC (Inter-Process Communication) for CLI integration
 //!
 //! Uses a simple TCP localhost socket for cross-platform compatibility.
-//! The GUI app runs a server, CLI sends commands.
+//! The GUI app runs a server, CLI sends commands. An optional HTTP API
+//! exposes the same commands to browsers and other devices.
 
+mod discovery;
+mod editor;
+mod http;
 mod protocol;
 mod server;
+mod streamdeck;
 
-pub use protocol::{IpcCommand, IpcResponse, IpcStats, IpcStatus};
-pub use server::{is_app_running, send_command, IpcServer};
+pub use discovery::{lookup, Lookup, APP_NAME};
+pub use protocol::{IpcCommand, IpcEvent, IpcEventKind, IpcResponse, IpcStats, IpcStatus, IpcTask};
+pub use server::{is_app_running, send_command, subscribe, IpcRequest, IpcServer};
 
 /// Default IPC port
 pub const IPC_PORT: u16 = 19847;
 
-/// Get the IPC address
+/// Ports after [`IPC_PORT`] that named profiles are spread over
+const PROFILE_PORTS: u32 = 1000;
+
+/// Get the IPC port, `POMODORUST_PORT` when set. Each named profile gets
+/// its own, picked from its name.
+pub fn ipc_port() -> u16 {
+    std::env::var("POMODORUST_PORT")
+        .ok()
+        .and_then(|port| port.trim().parse::<u16>().ok())
+        .unwrap_or_else(|| match crate::data::instance::profile() {
+            Some(name) => profile_port(name),
+            None => IPC_PORT,
+        })
+}
+
+/// Port of a named profile, the same on every run
+fn profile_port(name: &str) -> u16 {
+    // FNV-1a, as std's hasher may change between releases
+    let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    });
+    IPC_PORT + 1 + (hash % PROFILE_PORTS) as u16
+}
+
+/// Get the IPC address, on [`ipc_port`]
 pub fn ipc_address() -> String {
-    format!("127.0.0.1:{}", IPC_PORT)
+    format!("127.0.0.1:{}", ipc_port())
 }
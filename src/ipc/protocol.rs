@@ -8,10 +8,16 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "command", rename_all = "snake_case")]
 pub enum IpcCommand {
-    /// Start the timer (optionally specify session type)
+    /// Start the timer (optionally specify session type, duration and tag)
     Start {
         #[serde(skip_serializing_if = "Option::is_none")]
         session_type: Option<String>,
+        /// Custom duration in minutes (restarts the session like Quick Start)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        minutes: Option<u32>,
+        /// Free-form label stored with the recorded session
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
     },
     /// Pause the timer
     Pause,
@@ -28,10 +34,72 @@ pub enum IpcCommand {
     /// Get statistics
     Stats {
         #[serde(default)]
-        period: String, // today, week, all
+        period: String, // today, week, month, year, all
+        /// Start of a custom date range (YYYY-MM-DD), overrides `period`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        from: Option<String>,
+        /// End of a custom date range (YYYY-MM-DD, inclusive)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        to: Option<String>,
     },
+    /// Turn a quick-toggle setting on or off (mute, dnd, always_on_top)
+    SetOption {
+        option: String,
+        /// New value, or `None` to toggle the current one
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<bool>,
+    },
+    /// Show the full-screen timer for a projector, or leave it
+    Kiosk {
+        /// `None` to toggle
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        enabled: Option<bool>,
+        /// Message shown under the time, empty to clear it
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
+    /// Run a command at a later time
+    Schedule {
+        /// When to run the command (RFC 3339)
+        at: String,
+        /// Command to run
+        action: Box<IpcCommand>,
+    },
+    /// Cancel all scheduled commands
+    CancelScheduled,
+    /// Create a task in the current workspace
+    TaskAdd {
+        title: String,
+        /// Also queue the task with this many planned pomodoros
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pomodoros: Option<u32>,
+    },
+    /// List tasks in the current workspace
+    TaskList {
+        /// Include completed tasks
+        #[serde(default)]
+        all: bool,
+    },
+    /// Make a task the current one and start a focus session on it
+    TaskStart { id: i64 },
+    /// Mark a task as done and drop it from the queue
+    TaskDone { id: i64 },
+    /// Switch to a saved settings profile
+    ProfileUse { name: String },
+    /// List the saved settings profiles
+    ProfileList,
+    /// Show and focus the main window, optionally on a given view
+    /// (timer, queue, stats, settings)
+    Show {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        view: Option<String>,
+    },
+    /// Quit the app, even when closing hides it to the tray
+    Quit,
     /// Ping to check if server is running
     Ping,
+    /// Keep the connection open and stream timer events as JSON lines
+    Subscribe,
 }
 
 /// Response from the GUI
@@ -47,8 +115,25 @@ pub enum IpcResponse {
     Status(IpcStatus),
     /// Statistics data
     Stats(IpcStats),
-    /// Pong response
-    Pong,
+    /// Task list
+    Tasks { tasks: Vec<IpcTask> },
+    /// Saved settings profiles and the one in use
+    Profiles {
+        profiles: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        active: Option<String>,
+    },
+    /// Pong response, naming the app so another program on the port isn't
+    /// taken for it
+    Pong {
+        #[serde(default)]
+        app: String,
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        version: String,
+        /// Profile the app was started with (`--profile`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        profile: Option<String>,
+    },
     /// Error occurred
     Error { message: String },
 }
@@ -72,13 +157,22 @@ pub struct IpcStatus {
     pub total_sessions: u32,
     /// Total duration of current session in seconds
     pub total_duration_secs: u64,
+    /// Tag attached to the current session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
 }
 
 /// Statistics information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IpcStats {
-    /// Period: today, week, all
+    /// Period: today, week, month, year, all, range
     pub period: String,
+    /// First day covered by the stats (YYYY-MM-DD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Last day covered by the stats (YYYY-MM-DD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
     /// Work hours
     pub hours: f32,
     /// Completed pomodoros
@@ -91,6 +185,90 @@ pub struct IpcStats {
     pub daily_goal: u32,
     /// Today's pomodoros (for goal progress)
     pub today_pomodoros: i32,
+    /// Work hours per day, Monday first (week period only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub daily_hours: Vec<f32>,
+}
+
+/// Task information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpcTask {
+    /// Task id, used by `task start` and `task done`
+    pub id: i64,
+    pub title: String,
+    pub completed: bool,
+    /// Priority label: none, low, medium, high, urgent
+    pub priority: String,
+    /// Position in the pomodoro queue (0 = current task)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub queue_position: Option<usize>,
+    /// Completed and planned pomodoros when queued
+    #[serde(default)]
+    pub completed_pomodoros: u32,
+    #[serde(default)]
+    pub planned_pomodoros: u32,
+}
+
+/// Kind of timer lifecycle event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpcEventKind {
+    /// A session was started from idle
+    Started,
+    /// The running timer was paused
+    Paused,
+    /// A paused timer was resumed
+    Resumed,
+    /// The current session was reset
+    Reset,
+    /// The current session was skipped
+    Skipped,
+    /// A session ran to completion
+    Completed,
+}
+
+impl IpcEventKind {
+    /// Get the wire name of this event kind
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IpcEventKind::Started => "started",
+            IpcEventKind::Paused => "paused",
+            IpcEventKind::Resumed => "resumed",
+            IpcEventKind::Reset => "reset",
+            IpcEventKind::Skipped => "skipped",
+            IpcEventKind::Completed => "completed",
+        }
+    }
+}
+
+/// Timer lifecycle event published to integrations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpcEvent {
+    /// What happened
+    pub event: IpcEventKind,
+    /// Session type the event refers to: work, short_break, long_break
+    pub session_type: String,
+    /// Timer status right after the event
+    pub status: IpcStatus,
+    /// Length of the session that ended, on `completed` (the status has
+    /// already moved on to the next session by then)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<u64>,
+    /// When the event happened (RFC 3339)
+    pub timestamp: String,
+}
+
+impl IpcEvent {
+    /// Length in seconds of the session the event refers to
+    pub fn session_secs(&self) -> u64 {
+        self.duration_secs
+            .unwrap_or(self.status.total_duration_secs)
+    }
+
+    /// Convert event to JSON string
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
 }
 
 impl IpcCommand {
@@ -103,6 +281,21 @@ impl IpcCommand {
     pub fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or_default()
     }
+
+    /// Whether the command stops, pauses or replaces the running session,
+    /// which strict mode refuses during a focus session
+    pub fn interrupts_session(&self) -> bool {
+        match self {
+            IpcCommand::Pause | IpcCommand::Toggle | IpcCommand::Stop | IpcCommand::Skip => true,
+            // A type or a duration restarts the timer
+            IpcCommand::Start {
+                session_type,
+                minutes,
+                ..
+            } => session_type.is_some() || minutes.is_some(),
+            _ => false,
+        }
+    }
 }
 
 impl IpcResponse {
@@ -118,6 +311,15 @@ impl IpcResponse {
         }
     }
 
+    /// Create the response to a ping
+    pub fn pong() -> Self {
+        Self::Pong {
+            app: super::APP_NAME.to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            profile: crate::data::instance::profile().map(str::to_string),
+        }
+    }
+
     /// Create an error response
     pub fn error(msg: impl Into<String>) -> Self {
         Self::Error {
@@ -140,15 +342,43 @@ impl IpcResponse {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_commands_interrupting_strict_focus() {
+        let start = |session_type: Option<&str>, minutes| IpcCommand::Start {
+            session_type: session_type.map(str::to_string),
+            minutes,
+            tag: Some("x".to_string()),
+        };
+        assert!(IpcCommand::Skip.interrupts_session());
+        assert!(IpcCommand::Toggle.interrupts_session());
+        assert!(start(Some("short"), None).interrupts_session());
+        assert!(start(None, Some(10)).interrupts_session());
+        // Starting what already runs doesn't touch it
+        assert!(!start(None, None).interrupts_session());
+        assert!(!IpcCommand::Resume.interrupts_session());
+        assert!(!IpcCommand::Status.interrupts_session());
+    }
+
     #[test]
     fn test_command_serialization() {
-        let cmd = IpcCommand::Start { session_type: None };
+        let cmd = IpcCommand::Start {
+            session_type: None,
+            minutes: None,
+            tag: None,
+        };
         let json = cmd.to_json();
         assert!(json.contains("start"));
 
         let cmd = IpcCommand::Status;
         let json = cmd.to_json();
         assert!(json.contains("status"));
+
+        let cmd = IpcCommand::Kiosk {
+            enabled: Some(true),
+            message: None,
+        };
+        let json = cmd.to_json();
+        assert_eq!(json, r#"{"command":"kiosk","enabled":true}"#);
     }
 
     #[test]
@@ -161,5 +391,19 @@ mod tests {
         let json = resp.to_json();
         assert!(json.contains("error"));
         assert!(json.contains("test error"));
+
+        let resp = IpcResponse::Tasks {
+            tasks: vec![IpcTask {
+                id: 7,
+                title: "Write docs".to_string(),
+                completed: false,
+                priority: "high".to_string(),
+                queue_position: Some(0),
+                completed_pomodoros: 1,
+                planned_pomodoros: 3,
+            }],
+        };
+        let parsed = IpcResponse::from_json(&resp.to_json()).unwrap();
+        assert!(matches!(parsed, IpcResponse::Tasks { tasks } if tasks[0].id == 7));
     }
 }
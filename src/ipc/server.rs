@@ -1,27 +1,131 @@
 //! IPC Server for handling CLI commands
 //!
 //! Runs in a separate thread and communicates with the main app via channels.
+//! Clients normally send one command and read one response; a client that
+//! sends `subscribe` instead keeps the connection open and receives every
+//! timer event as a JSON line.
+//!
+//! Each connection gets its own thread, so any number of clients (CLI
+//! calls, status bars, subscribers) can be connected at once. Every command
+//! carries its own reply channel, so concurrent clients never see each
+//! other's responses.
+//!
+//! When the configured port can't be had, the server listens on a port the
+//! system picks; clients find it through [`lookup`](super::lookup).
 
 use std::io::{BufRead, BufReader, Write};
 use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
+use super::discovery::{self, Lookup};
+use super::http;
 use super::ipc_address;
-use super::protocol::{IpcCommand, IpcResponse};
+use super::protocol::{IpcCommand, IpcEvent, IpcResponse};
+use crate::data::HttpConfig;
+
+/// Callback waking the app so it handles a command while idle
+type Waker = Arc<dyn Fn() + Send + Sync>;
+
+/// How long a client waits for the app to handle its command
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Most client connections served at once; more are turned away
+const MAX_CONNECTIONS: usize = 64;
+
+/// A command from a client, answered through its own reply channel
+pub struct IpcRequest {
+    pub command: IpcCommand,
+    reply: Sender<IpcResponse>,
+}
+
+impl IpcRequest {
+    /// Send the response back to the client that asked
+    pub fn respond(self, response: IpcResponse) {
+        // The client may have timed out and gone
+        let _ = self.reply.send(response);
+    }
+}
+
+/// Sending half of the command pipeline, shared by all listener threads
+#[derive(Clone)]
+pub(super) struct CommandChannel {
+    /// Channel to send commands to the app
+    command_tx: Sender<IpcRequest>,
+    /// Wakes the app so commands are handled while idle
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl CommandChannel {
+    /// Forward a command to the app and wait for its response
+    ///
+    /// Shared by the CLI socket and the HTTP API so both go through the same
+    /// command pipeline.
+    pub(super) fn dispatch(&self, cmd: IpcCommand) -> IpcResponse {
+        // Handle ping directly
+        if matches!(cmd, IpcCommand::Ping) {
+            return IpcResponse::pong();
+        }
+
+        // Send command to app
+        let (reply, response) = mpsc::channel();
+        let request = IpcRequest {
+            command: cmd,
+            reply,
+        };
+        if self.command_tx.send(request).is_err() {
+            return IpcResponse::error("App not responding");
+        }
+        if let Ok(waker) = self.waker.lock() {
+            if let Some(ref wake) = *waker {
+                wake();
+            }
+        }
+
+        response
+            .recv_timeout(RESPONSE_TIMEOUT)
+            .unwrap_or_else(|_| IpcResponse::error("Response timeout"))
+    }
+}
+
+/// Counts a connection as open until dropped
+pub(super) struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    /// Take a slot unless `MAX_CONNECTIONS` are already open
+    pub(super) fn acquire(open: &Arc<AtomicUsize>) -> Option<Self> {
+        open.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+            (n < MAX_CONNECTIONS).then_some(n + 1)
+        })
+        .ok()
+        .map(|_| Self(open.clone()))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Event queues of connected subscribers
+type Subscribers = Arc<Mutex<Vec<Sender<String>>>>;
 
 /// IPC Server that listens for CLI commands
 pub struct IpcServer {
-    /// Channel to send commands to the app
-    command_tx: Sender<IpcCommand>,
+    /// Shared command pipeline
+    channel: CommandChannel,
+    /// Clients that asked to receive timer events
+    subscribers: Subscribers,
     /// Channel to receive commands in the app
-    command_rx: Option<Receiver<IpcCommand>>,
-    /// Shared state for responses
-    response_state: Arc<Mutex<Option<IpcResponse>>>,
+    command_rx: Option<Receiver<IpcRequest>>,
     /// Server thread handle
-    _handle: Option<thread::JoinHandle<()>>,
+    handle: Option<thread::JoinHandle<()>>,
+    /// HTTP API thread handle
+    http_handle: Option<thread::JoinHandle<()>>,
     /// Flag to indicate if server is running
     running: Arc<Mutex<bool>>,
 }
@@ -31,30 +135,58 @@ impl IpcServer {
     pub fn new() -> Self {
         let (command_tx, command_rx) = mpsc::channel();
         Self {
-            command_tx,
+            channel: CommandChannel {
+                command_tx,
+                waker: Arc::new(Mutex::new(None)),
+            },
             command_rx: Some(command_rx),
-            response_state: Arc::new(Mutex::new(None)),
-            _handle: None,
+            subscribers: Arc::new(Mutex::new(Vec::new())),
+            handle: None,
+            http_handle: None,
             running: Arc::new(Mutex::new(false)),
         }
     }
 
-    /// Take the command receiver (can only be called once)
-    pub fn take_receiver(&mut self) -> Option<Receiver<IpcCommand>> {
+    /// Take the command receiver (can only be called once); answer each
+    /// request with [`IpcRequest::respond`]
+    pub fn take_receiver(&mut self) -> Option<Receiver<IpcRequest>> {
         self.command_rx.take()
     }
 
-    /// Set the response to send back to the client
-    pub fn set_response(&self, response: IpcResponse) {
-        if let Ok(mut state) = self.response_state.lock() {
-            *state = Some(response);
+    /// Call `wake` whenever a command arrives, for apps that sleep while
+    /// idle
+    pub fn set_waker(&self, wake: impl Fn() + Send + Sync + 'static) {
+        if let Ok(mut waker) = self.channel.waker.lock() {
+            *waker = Some(Arc::new(wake));
         }
     }
 
-    /// Start the IPC server in a background thread
-    pub fn start(&mut self) {
-        let command_tx = self.command_tx.clone();
-        let response_state = self.response_state.clone();
+    /// Wake the UI through this context whenever a command arrives
+    #[cfg(feature = "gui")]
+    pub fn set_repaint_context(&self, ctx: egui::Context) {
+        self.set_waker(move || ctx.request_repaint());
+    }
+
+    /// Push a timer event to every subscribed client
+    pub fn broadcast(&self, event: &IpcEvent) {
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            let line = event.to_json();
+            // Drop subscribers whose connection thread has exited
+            subscribers.retain(|tx| tx.send(line.clone()).is_ok());
+        }
+    }
+
+    /// Start the IPC server in a background thread, returning the port it
+    /// listens on
+    pub fn start(&mut self) -> Result<u16, String> {
+        let (listener, port) = Self::bind().map_err(|e| {
+            tracing::warn!("{}. CLI will not be available.", e);
+            e
+        })?;
+        discovery::publish_port(port);
+
+        let channel = self.channel.clone();
+        let subscribers = self.subscribers.clone();
         let running = self.running.clone();
 
         // Mark as running
@@ -63,35 +195,43 @@ impl IpcServer {
         }
 
         let handle = thread::spawn(move || {
-            Self::server_loop(command_tx, response_state, running);
+            Self::server_loop(listener, channel, subscribers, running);
         });
 
-        self._handle = Some(handle);
-        tracing::info!("IPC server started on {}", ipc_address());
+        self.handle = Some(handle);
+        tracing::info!("IPC server started on port {}", port);
+        Ok(port)
+    }
+
+    /// Listen on the configured port, or on one the system picks when
+    /// that can't be had
+    fn bind() -> Result<(TcpListener, u16), String> {
+        let address = ipc_address();
+        let listener = TcpListener::bind(&address).or_else(|e| {
+            tracing::warn!("Failed to bind IPC server to {}: {}", address, e);
+            TcpListener::bind("127.0.0.1:0")
+        });
+        let listener = listener.map_err(|e| format!("Failed to bind IPC server: {}", e))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| format!("Failed to bind IPC server: {}", e))?
+            .port();
+        Ok((listener, port))
     }
 
     /// Server loop that accepts connections
     fn server_loop(
-        command_tx: Sender<IpcCommand>,
-        response_state: Arc<Mutex<Option<IpcResponse>>>,
+        listener: TcpListener,
+        channel: CommandChannel,
+        subscribers: Subscribers,
         running: Arc<Mutex<bool>>,
     ) {
-        let listener = match TcpListener::bind(ipc_address()) {
-            Ok(l) => l,
-            Err(e) => {
-                tracing::warn!(
-                    "Failed to bind IPC server: {}. CLI will not be available.",
-                    e
-                );
-                return;
-            }
-        };
-
         // Set non-blocking to allow checking the running flag
         if let Err(e) = listener.set_nonblocking(true) {
             tracing::warn!("Failed to set non-blocking: {}", e);
         }
 
+        let open = Arc::new(AtomicUsize::new(0));
         loop {
             // Check if we should stop
             if let Ok(r) = running.lock() {
@@ -101,13 +241,20 @@ impl IpcServer {
             }
 
             match listener.accept() {
-                Ok((stream, _addr)) => {
-                    let tx = command_tx.clone();
-                    let state = response_state.clone();
+                Ok((mut stream, _addr)) => {
+                    let Some(slot) = ConnectionSlot::acquire(&open) else {
+                        tracing::warn!("IPC connection refused: too many clients");
+                        let response = IpcResponse::error("Too many connections");
+                        let _ = writeln!(stream, "{}", response.to_json());
+                        continue;
+                    };
+                    let channel = channel.clone();
+                    let subscribers = subscribers.clone();
 
                     // Handle connection in a separate thread
                     thread::spawn(move || {
-                        Self::handle_connection(stream, tx, state);
+                        Self::handle_connection(stream, channel, subscribers);
+                        drop(slot);
                     });
                 }
                 Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
@@ -125,11 +272,10 @@ impl IpcServer {
     }
 
     /// Handle a single client connection
-    fn handle_connection(
-        mut stream: TcpStream,
-        command_tx: Sender<IpcCommand>,
-        response_state: Arc<Mutex<Option<IpcResponse>>>,
-    ) {
+    fn handle_connection(mut stream: TcpStream, channel: CommandChannel, subscribers: Subscribers) {
+        // Accepted sockets inherit non-blocking mode from the listener on
+        // some platforms
+        let _ = stream.set_nonblocking(false);
         // Set read timeout
         let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
         let _ = stream.set_write_timeout(Some(Duration::from_secs(5)));
@@ -146,54 +292,15 @@ impl IpcServer {
                 let line = line.trim();
                 tracing::debug!("IPC received: {}", line);
 
-                // Parse command
-                match IpcCommand::from_json(line) {
-                    Ok(cmd) => {
-                        // Handle ping directly
-                        if matches!(cmd, IpcCommand::Ping) {
-                            let response = IpcResponse::Pong;
-                            let _ = writeln!(stream, "{}", response.to_json());
-                            return;
-                        }
-
-                        // Clear previous response
-                        if let Ok(mut state) = response_state.lock() {
-                            *state = None;
-                        }
-
-                        // Send command to app
-                        if command_tx.send(cmd).is_err() {
-                            let response = IpcResponse::error("App not responding");
-                            let _ = writeln!(stream, "{}", response.to_json());
-                            return;
-                        }
-
-                        // Wait for response (with timeout)
-                        let start = std::time::Instant::now();
-                        let timeout = Duration::from_secs(2);
-
-                        loop {
-                            if start.elapsed() > timeout {
-                                let response = IpcResponse::error("Response timeout");
-                                let _ = writeln!(stream, "{}", response.to_json());
-                                return;
-                            }
-
-                            if let Ok(state) = response_state.lock() {
-                                if let Some(response) = state.as_ref() {
-                                    let _ = writeln!(stream, "{}", response.to_json());
-                                    return;
-                                }
-                            }
-
-                            thread::sleep(Duration::from_millis(10));
-                        }
-                    }
-                    Err(e) => {
-                        let response = IpcResponse::error(format!("Invalid command: {}", e));
-                        let _ = writeln!(stream, "{}", response.to_json());
+                let response = match IpcCommand::from_json(line) {
+                    Ok(IpcCommand::Subscribe) => {
+                        Self::stream_events(stream, subscribers);
+                        return;
                     }
-                }
+                    Ok(cmd) => channel.dispatch(cmd),
+                    Err(e) => IpcResponse::error(format!("Invalid command: {}", e)),
+                };
+                let _ = writeln!(stream, "{}", response.to_json());
             }
             Err(e) => {
                 tracing::debug!("IPC read error: {}", e);
@@ -201,11 +308,58 @@ impl IpcServer {
         }
     }
 
+    /// Keep a subscriber connection open, writing each event as a line
+    fn stream_events(mut stream: TcpStream, subscribers: Subscribers) {
+        let (tx, rx) = mpsc::channel();
+        if let Ok(mut subscribers) = subscribers.lock() {
+            subscribers.push(tx);
+        }
+
+        let response = IpcResponse::ok_with_message("Subscribed");
+        if writeln!(stream, "{}", response.to_json()).is_err() {
+            return;
+        }
+        tracing::debug!("IPC subscriber connected");
+
+        for line in rx {
+            if writeln!(stream, "{}", line).is_err() {
+                break;
+            }
+        }
+        tracing::debug!("IPC subscriber disconnected");
+    }
+
+    /// Start the HTTP API in a background thread
+    pub fn start_http(&mut self, config: &HttpConfig) {
+        self.http_handle = http::start(config.clone(), self.channel.clone(), self.running.clone());
+    }
+
+    /// Stop the listeners and bind them again, e.g. after the system wakes
+    /// up with a changed network stack, returning the port of the CLI
+    /// socket
+    pub fn restart(&mut self, http: Option<&HttpConfig>) -> Result<u16, String> {
+        self.stop();
+        // The accept loops notice the flag within 100ms and release the ports
+        for handle in [self.handle.take(), self.http_handle.take()]
+            .into_iter()
+            .flatten()
+        {
+            let _ = handle.join();
+        }
+
+        let started = self.start();
+        if let Some(config) = http {
+            self.start_http(config);
+        }
+        started
+    }
+
     /// Stop the IPC server
     pub fn stop(&self) {
         if let Ok(mut r) = self.running.lock() {
             *r = false;
         }
+        discovery::unpublish_port();
     }
 }
 
@@ -215,11 +369,20 @@ impl Default for IpcServer {
     }
 }
 
+/// Address of the running app, or why it can't be reached
+fn app_address() -> Result<String, String> {
+    match discovery::lookup() {
+        Lookup::Running(address) => Ok(address),
+        other => Err(other.to_string()),
+    }
+}
+
 /// Helper function to send a command to the running app
 pub fn send_command(command: &IpcCommand) -> Result<IpcResponse, String> {
     use std::io::BufRead;
 
-    let mut stream = TcpStream::connect(ipc_address())
+    let address = app_address()?;
+    let mut stream = TcpStream::connect(&address)
         .map_err(|e| format!("Cannot connect to Pomodorust. Is it running? ({})", e))?;
 
     stream
@@ -243,7 +406,102 @@ pub fn send_command(command: &IpcCommand) -> Result<IpcResponse, String> {
     IpcResponse::from_json(line.trim()).map_err(|e| format!("Invalid response: {}", e))
 }
 
+/// Subscribe to timer events, calling `on_event` for each one until the
+/// connection closes or `on_event` returns `false`
+pub fn subscribe(mut on_event: impl FnMut(IpcEvent) -> bool) -> Result<(), String> {
+    let address = app_address()?;
+    let mut stream = TcpStream::connect(&address)
+        .map_err(|e| format!("Cannot connect to Pomodorust. Is it running? ({})", e))?;
+
+    writeln!(stream, "{}", IpcCommand::Subscribe.to_json())
+        .map_err(|e| format!("Failed to send command: {}", e))?;
+
+    let reader = BufReader::new(stream);
+    let mut lines = reader.lines();
+
+    // First line acknowledges the subscription
+    match lines.next() {
+        Some(Ok(line)) => match IpcResponse::from_json(line.trim()) {
+            Ok(IpcResponse::Error { message }) => return Err(message),
+            Ok(_) => {}
+            Err(e) => return Err(format!("Invalid response: {}", e)),
+        },
+        Some(Err(e)) => return Err(format!("Failed to read response: {}", e)),
+        None => return Err("Connection closed".to_string()),
+    }
+
+    for line in lines {
+        let line = line.map_err(|e| format!("Connection lost: {}", e))?;
+        match serde_json::from_str::<IpcEvent>(line.trim()) {
+            Ok(event) => {
+                if !on_event(event) {
+                    break;
+                }
+            }
+            Err(e) => tracing::debug!("Ignoring invalid event: {}", e),
+        }
+    }
+
+    Ok(())
+}
+
 /// Check if the app is running
 pub fn is_app_running() -> bool {
-    send_command(&IpcCommand::Ping).is_ok()
+    matches!(discovery::lookup(), Lookup::Running(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concurrent_dispatch() {
+        let mut server = IpcServer::new();
+        let requests = server.take_receiver().unwrap();
+        // Answer in reverse order of arrival, as an app busy with a frame
+        // might handle a batch
+        thread::spawn(move || {
+            let mut batch = Vec::new();
+            for request in requests {
+                batch.push(request);
+                if batch.len() == 8 {
+                    for request in batch.drain(..).rev() {
+                        let view = match &request.command {
+                            IpcCommand::Show { view } => view.clone(),
+                            _ => None,
+                        };
+                        request.respond(IpcResponse::Ok { message: view });
+                    }
+                }
+            }
+        });
+
+        let clients: Vec<_> = (0..8)
+            .map(|n| {
+                let channel = server.channel.clone();
+                thread::spawn(move || {
+                    let view = Some(n.to_string());
+                    match channel.dispatch(IpcCommand::Show { view: view.clone() }) {
+                        IpcResponse::Ok { message } => message == view,
+                        _ => false,
+                    }
+                })
+            })
+            .collect();
+        for client in clients {
+            assert!(client.join().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_connection_slots() {
+        let open = Arc::new(AtomicUsize::new(0));
+        let slots: Vec<_> = (0..MAX_CONNECTIONS)
+            .map(|_| ConnectionSlot::acquire(&open).unwrap())
+            .collect();
+        assert!(ConnectionSlot::acquire(&open).is_none());
+        drop(slots);
+        assert_eq!(open.load(Ordering::SeqCst), 0);
+        assert!(ConnectionSlot::acquire(&open).is_some());
+    }
 }
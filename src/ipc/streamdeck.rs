@@ -0,0 +1,123 @@
+//! Stream Deck support
+//!
+//! Stream Deck plugins poll the HTTP API for the state of a key:
+//! - `GET /key` returns `{"title", "state", "image"}`, ready to pass to the
+//!   SDK's `setTitle`, `setState` (0 = stopped/paused, 1 = running) and
+//!   `setImage` (an SVG data URL) calls
+//! - `GET /icon.svg?size=N` returns the key image itself
+//!
+//! Key presses map to the existing `POST /toggle` and `POST /skip` endpoints.
+
+use serde::Serialize;
+
+use super::protocol::IpcStatus;
+
+/// Default key image size in pixels (Stream Deck XL / @2x keys)
+pub const DEFAULT_ICON_SIZE: u32 = 144;
+
+/// Key state for the Stream Deck SDK
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyState {
+    /// Text shown on the key
+    pub title: String,
+    /// Multi-state action index: 0 = stopped or paused, 1 = running
+    pub state: u8,
+    /// Key image as an SVG data URL
+    pub image: String,
+}
+
+impl KeyState {
+    /// Build the key state for the current timer status
+    pub fn from_status(status: &IpcStatus) -> Self {
+        let svg = render_icon(status, DEFAULT_ICON_SIZE);
+        Self {
+            title: status.remaining_formatted.clone(),
+            state: u8::from(status.state == "running"),
+            image: format!("data:image/svg+xml;charset=utf8,{}", percent_encode(&svg)),
+        }
+    }
+}
+
+/// Ring color for a session type
+fn session_color(session_type: &str) -> &'static str {
+    match session_type {
+        "short_break" => "#22c55e",
+        "long_break" => "#a855f7",
+        _ => "#3b82f6",
+    }
+}
+
+/// Render a key image: a progress ring with the remaining time inside
+pub fn render_icon(status: &IpcStatus, size: u32) -> String {
+    const RADIUS: f32 = 60.0;
+    let circumference = 2.0 * std::f32::consts::PI * RADIUS;
+    let offset = circumference * status.progress.clamp(0.0, 1.0);
+    let running = status.state == "running";
+
+    let color = session_color(&status.session_type);
+    let opacity = if running { "1" } else { "0.45" };
+    let label = match status.session_type.as_str() {
+        "short_break" => "BREAK",
+        "long_break" => "LONG",
+        _ => "FOCUS",
+    };
+    let state_mark = if status.state == "paused" {
+        r##"<rect x="62" y="98" width="6" height="16" fill="#888"/><rect x="76" y="98" width="6" height="16" fill="#888"/>"##
+    } else {
+        ""
+    };
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 144 144"><rect width="144" height="144" fill="#000"/><circle cx="72" cy="72" r="{r}" fill="none" stroke="#1f1f1f" stroke-width="10"/><circle cx="72" cy="72" r="{r}" fill="none" stroke="{color}" stroke-opacity="{opacity}" stroke-width="10" stroke-linecap="round" stroke-dasharray="{c:.1}" stroke-dashoffset="{off:.1}" transform="rotate(-90 72 72)"/><text x="72" y="50" fill="#888" font-family="sans-serif" font-size="14" text-anchor="middle">{label}</text><text x="72" y="84" fill="#ededed" font-family="sans-serif" font-size="30" font-weight="bold" text-anchor="middle">{time}</text>{state_mark}</svg>"##,
+        size = size,
+        r = RADIUS,
+        color = color,
+        opacity = opacity,
+        c = circumference,
+        off = offset,
+        label = label,
+        time = status.remaining_formatted,
+        state_mark = state_mark,
+    )
+}
+
+/// Percent-encode the characters that are unsafe in a data URL
+fn percent_encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len() * 2);
+    for c in input.chars() {
+        match c {
+            '%' | '#' | '<' | '>' | '"' | '\n' | '\r' | ' ' => {
+                out.push_str(&format!("%{:02X}", c as u32));
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_state() {
+        let status = IpcStatus {
+            state: "running".to_string(),
+            session_type: "work".to_string(),
+            remaining_secs: 754,
+            remaining_formatted: "12:34".to_string(),
+            progress: 0.5,
+            current_session: 1,
+            total_sessions: 4,
+            total_duration_secs: 1500,
+            tag: None,
+        };
+
+        let key = KeyState::from_status(&status);
+        assert_eq!(key.title, "12:34");
+        assert_eq!(key.state, 1);
+        assert!(key.image.starts_with("data:image/svg+xml"));
+        assert!(!key.image.contains('<'));
+        assert!(render_icon(&status, 72).contains("12:34"));
+    }
+}
@@ -1,17 +1,32 @@
 //! PomodoRust - A modern, lightweight Pomodoro timer
 //!
 //! This library provides all the core functionality for the PomodoRust application.
+//!
+//! The timer ([`core`]), statistics and config ([`data`]), translations
+//! ([`i18n`]) and the CLI/HTTP API ([`ipc`]) don't depend on the GUI. Build
+//! with `default-features = false` to embed them without egui, eframe and
+//! the platform integrations, which come with the default `gui` feature.
+//! The terminal client (`tui`) is behind the default `tui` feature.
 
+#[cfg(feature = "gui")]
 pub mod app;
 pub mod core;
 pub mod data;
 pub mod error;
 pub mod i18n;
+#[cfg(feature = "gui")]
+pub mod integrations;
 pub mod ipc;
+pub mod logging;
+#[cfg(feature = "gui")]
 pub mod platform;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "gui")]
 pub mod ui;
 pub mod utils;
 
+#[cfg(feature = "gui")]
 pub use app::PomodoRustApp;
 pub use error::{Error, Result};
 pub use ipc::{is_app_running, send_command, IpcCommand, IpcResponse, IpcServer};
@@ -0,0 +1,183 @@
+//! Log file
+//!
+//! What the app logs through `tracing` goes to `pomodorust.log` in the data
+//! directory, so a bug report can say what the app was doing. The file is
+//! rotated once it passes [`MAX_LOG_BYTES`], keeping [`KEPT_LOGS`] older ones
+//! (`pomodorust.log.1` being the newest). `pomodorust logs` prints the
+//! latest lines, and Ctrl+Shift+L shows them in the app.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tracing::Level;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+use crate::data::Database;
+
+/// Name of the log file in the data directory
+const LOG_FILE: &str = "pomodorust.log";
+
+/// Size a log file grows to before it's rotated
+const MAX_LOG_BYTES: u64 = 2 * 1024 * 1024;
+
+/// Rotated log files kept besides the current one
+const KEPT_LOGS: usize = 3;
+
+/// Path of the current log file
+pub fn log_path() -> Option<PathBuf> {
+    Database::data_dir().map(|dir| dir.join(LOG_FILE))
+}
+
+/// Path of the `n`th most recent rotated log file
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    path.with_extension(format!("log.{}", n))
+}
+
+/// Send the app's log to the log file, and to stderr as well when `echo`
+/// is set. Panics are logged too.
+pub fn init(echo: bool) {
+    let file = log_path().and_then(|path| match RotatingFile::open(path.clone()) {
+        Ok(file) => Some(file),
+        Err(e) => {
+            eprintln!("Failed to open log file {:?}: {}", path, e);
+            None
+        }
+    });
+    let file_layer = file.map(|file| {
+        tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(Mutex::new(file))
+    });
+    let stderr_layer = echo.then(|| tracing_subscriber::fmt::layer().with_writer(io::stderr));
+    let filter = Targets::new()
+        .with_target(env!("CARGO_CRATE_NAME"), Level::INFO)
+        .with_default(Level::WARN);
+
+    let installed = tracing_subscriber::registry()
+        .with(file_layer)
+        .with(stderr_layer)
+        .with(filter)
+        .try_init();
+    if installed.is_err() {
+        return;
+    }
+
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        tracing::error!("{}", info);
+        default_hook(info);
+    }));
+}
+
+/// Log file writer that rotates the file as it grows
+struct RotatingFile {
+    path: PathBuf,
+    /// Closed while rotating, as Windows can't rename an open file
+    file: Option<File>,
+    written: u64,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf) -> io::Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = Self::append(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file: Some(file),
+            written,
+        })
+    }
+
+    fn append(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    /// Shift the old files up by one, dropping the oldest, and start over
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file = None;
+        for n in (1..KEPT_LOGS).rev() {
+            let _ = fs::rename(rotated_path(&self.path, n), rotated_path(&self.path, n + 1));
+        }
+        fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        self.file = Some(Self::append(&self.path)?);
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written >= MAX_LOG_BYTES {
+            if let Err(e) = self.rotate() {
+                // Keep writing to the file there is rather than losing lines
+                eprintln!("Failed to rotate log file: {}", e);
+                self.written = 0;
+            }
+        }
+        if self.file.is_none() {
+            self.file = Some(Self::append(&self.path)?);
+        }
+        let written = self.file.as_mut().map_or(Ok(0), |file| file.write(buf))?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.as_mut().map_or(Ok(()), File::flush)
+    }
+}
+
+/// The last `count` lines logged, oldest first, reading back through the
+/// rotated files as needed
+pub fn recent_lines(count: usize) -> Vec<String> {
+    let Some(path) = log_path() else {
+        return Vec::new();
+    };
+    let files = (1..=KEPT_LOGS)
+        .rev()
+        .map(|n| rotated_path(&path, n))
+        .chain([path.clone()])
+        .filter_map(|file| fs::read(file).ok())
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+        .collect::<Vec<_>>();
+    last_lines(&files, count)
+}
+
+/// The last `count` lines of `texts` taken in order
+fn last_lines(texts: &[String], count: usize) -> Vec<String> {
+    let mut lines = VecDeque::with_capacity(count);
+    for line in texts.iter().flat_map(|text| text.lines()) {
+        if lines.len() == count {
+            lines.pop_front();
+        }
+        if count > 0 {
+            lines.push_back(line.to_string());
+        }
+    }
+    lines.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_lines() {
+        let texts = ["a\nb\n".to_string(), "c\nd".to_string()];
+        assert_eq!(last_lines(&texts, 3), ["b", "c", "d"]);
+        assert_eq!(last_lines(&texts, 10).len(), 4);
+        assert!(last_lines(&texts, 0).is_empty());
+        assert_eq!(
+            rotated_path(Path::new("/data/pomodorust.log"), 2),
+            Path::new("/data/pomodorust.log.2")
+        );
+    }
+}
@@ -5,9 +5,11 @@
 //! Run without arguments to start the GUI.
 //! Run with a command (e.g., `pomodorust status`) to use CLI mode.
 
+use chrono::{DateTime, Local, NaiveTime};
 use eframe::egui;
-use pomodorust::data::Config;
-use pomodorust::ipc::{IpcCommand, IpcResponse, IpcStats, IpcStatus};
+use pomodorust::data::{Config, ConfigIssue};
+use pomodorust::i18n::tr;
+use pomodorust::ipc::{IpcCommand, IpcResponse, IpcStats, IpcStatus, IpcTask, Lookup};
 use pomodorust::{is_app_running, send_command, PomodoRustApp};
 use std::env;
 
@@ -19,39 +21,72 @@ use windows::Win32::UI::WindowsAndMessaging::FindWindowW;
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 enum Command {
-    Start { session: Option<String> },
-    Pause,
+    Start {
+        session: Option<String>,
+        minutes: Option<u32>,
+        tag: Option<String>,
+        at: Option<DateTime<Local>>,
+    },
+    Pause {
+        resume_at: Option<DateTime<Local>>,
+    },
     Resume,
     Toggle,
     Stop,
     Skip,
     Status,
-    Stats { period: String },
+    Stats {
+        period: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    SetOption {
+        option: String,
+        value: Option<bool>,
+    },
+    Kiosk {
+        enabled: Option<bool>,
+        message: Option<String>,
+    },
+    Unschedule,
+    Task(IpcCommand),
+    Profile(IpcCommand),
+    ConfigCheck,
+    Logs {
+        lines: usize,
+        path: bool,
+    },
+    Show {
+        view: Option<String>,
+    },
+    Quit,
     Ping,
+    Watch,
+    #[cfg(feature = "tui")]
+    Tui,
 }
 
 fn print_help() {
-    println!("PomodoRust v{} - A modern Pomodoro timer", VERSION);
+    let t = &tr().cli;
+    println!("PomodoRust v{} - {}", VERSION, t.tagline);
     println!();
-    println!("USAGE: pomodorust [COMMAND]");
-    println!();
-    println!("COMMANDS:");
-    println!("  start [-s <type>]   Start timer (type: work, short, long)");
-    println!("  pause               Pause the timer");
-    println!("  resume              Resume the timer");
-    println!("  toggle              Toggle start/pause");
-    println!("  stop                Stop and reset the timer");
-    println!("  skip                Skip to next session");
-    println!("  status              Get current timer status");
-    println!("  stats [-p <period>] Get statistics (period: today, week, all)");
-    println!("  ping                Check if GUI is running");
-    println!();
-    println!("Run without arguments to start the GUI.");
+    print!("{}", t.help);
 }
 
-fn parse_args() -> Option<Command> {
-    let args: Vec<String> = env::args().collect();
+/// Take `--profile <name>` (or `--profile=<name>`) out of the arguments
+fn take_profile_arg(args: &mut Vec<String>) -> Option<String> {
+    let i = args
+        .iter()
+        .position(|a| a == "--profile" || a.starts_with("--profile="))?;
+    let arg = args.remove(i);
+    match arg.strip_prefix("--profile=") {
+        Some(name) => Some(name.to_string()),
+        None if i < args.len() => Some(args.remove(i)),
+        None => Some(String::new()),
+    }
+}
 
+fn parse_args(args: &[String]) -> Option<Command> {
     if args.len() < 2 {
         return None;
     }
@@ -69,9 +104,53 @@ fn parse_args() -> Option<Command> {
         }
         "start" => {
             let session = parse_option(&args[2..], &["-s", "--session"]);
-            Some(Command::Start { session })
+            let minutes = parse_option(&args[2..], &["-d", "--duration"]).map(|m| {
+                m.parse().unwrap_or_else(|_| {
+                    eprintln!(
+                        "{}: {} ({})",
+                        tr().cli.invalid_duration,
+                        m,
+                        tr().cli.expected_minutes
+                    );
+                    std::process::exit(1);
+                })
+            });
+            let tag = parse_option(&args[2..], &["-t", "--tag"]);
+            let at = parse_option(&args[2..], &["--at"]).map(|t| {
+                parse_clock_time(&t).unwrap_or_else(|| {
+                    eprintln!(
+                        "{}: {} ({})",
+                        tr().cli.invalid_time,
+                        t,
+                        tr().cli.expected_clock
+                    );
+                    std::process::exit(1);
+                })
+            });
+            Some(Command::Start {
+                session,
+                minutes,
+                tag,
+                at,
+            })
+        }
+        "pause" => {
+            let resume_at = parse_option(&args[2..], &["--for"]).map(|d| {
+                pomodorust::utils::parse_duration(&d)
+                    .filter(|&secs| secs > 0)
+                    .and_then(seconds_from_now)
+                    .unwrap_or_else(|| {
+                        eprintln!(
+                            "{}: {} ({})",
+                            tr().cli.invalid_duration,
+                            d,
+                            tr().cli.expected_span
+                        );
+                        std::process::exit(1);
+                    })
+            });
+            Some(Command::Pause { resume_at })
         }
-        "pause" => Some(Command::Pause),
         "resume" => Some(Command::Resume),
         "toggle" => Some(Command::Toggle),
         "stop" => Some(Command::Stop),
@@ -80,12 +159,175 @@ fn parse_args() -> Option<Command> {
         "stats" => {
             let period = parse_option(&args[2..], &["-p", "--period"])
                 .unwrap_or_else(|| "today".to_string());
-            Some(Command::Stats { period })
+            let from = parse_option(&args[2..], &["--from"]);
+            let to = parse_option(&args[2..], &["--to"]);
+            Some(Command::Stats { period, from, to })
+        }
+        "mute" => Some(Command::SetOption {
+            option: "mute".to_string(),
+            value: parse_switch(args.get(2), Some(true)),
+        }),
+        "unmute" => Some(Command::SetOption {
+            option: "mute".to_string(),
+            value: Some(false),
+        }),
+        "dnd" | "always-on-top" => Some(Command::SetOption {
+            option: cmd.clone(),
+            value: parse_switch(args.get(2), None),
+        }),
+        "kiosk" => Some(Command::Kiosk {
+            // The switch is optional, so don't take the message flag for it
+            enabled: parse_switch(args.get(2).filter(|a| !a.starts_with('-')), Some(true)),
+            message: parse_option(&args[2..], &["-m", "--message"]),
+        }),
+        "unschedule" => Some(Command::Unschedule),
+        "task" | "tasks" => Some(Command::Task(parse_task_args(&args[2..]))),
+        "profile" | "profiles" => Some(Command::Profile(parse_profile_args(&args[2..]))),
+        "config" => match args.get(2).map(|a| a.to_lowercase()).as_deref() {
+            None | Some("check") => Some(Command::ConfigCheck),
+            Some(other) => {
+                eprintln!("{}: {}", tr().cli.unknown_command, other);
+                eprintln!("{}", tr().cli.config_commands);
+                std::process::exit(1);
+            }
+        },
+        "logs" => {
+            let lines = parse_option(&args[2..], &["-n", "--lines"]).map(|n| {
+                n.parse().unwrap_or_else(|_| {
+                    eprintln!("{}: {}", tr().cli.invalid_value, n);
+                    std::process::exit(1);
+                })
+            });
+            Some(Command::Logs {
+                lines: lines.unwrap_or(100),
+                path: args.iter().any(|a| a == "--path"),
+            })
         }
+        "show" => Some(Command::Show {
+            view: args.get(2).map(|v| v.to_lowercase()),
+        }),
+        "quit" | "exit" => Some(Command::Quit),
         "ping" => Some(Command::Ping),
+        "watch" => Some(Command::Watch),
+        #[cfg(feature = "tui")]
+        "tui" => Some(Command::Tui),
         _ => {
-            eprintln!("Unknown command: {}", cmd);
-            eprintln!("Run 'pomodorust --help' for usage.");
+            eprintln!("{}: {}", tr().cli.unknown_command, cmd);
+            eprintln!("{}", tr().cli.see_help);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Parse the arguments of a `task` subcommand
+fn parse_task_args(args: &[String]) -> IpcCommand {
+    let task_id = |arg: Option<&String>| -> i64 {
+        arg.and_then(|a| a.trim_start_matches('#').parse().ok())
+            .unwrap_or_else(|| {
+                eprintln!("{}", tr().cli.expected_task_id);
+                std::process::exit(1);
+            })
+    };
+
+    match args.first().map(|a| a.to_lowercase()).as_deref() {
+        None | Some("list" | "ls") => IpcCommand::TaskList {
+            all: args.iter().any(|a| a == "-a" || a == "--all"),
+        },
+        Some("add") => {
+            let pomodoros = parse_option(&args[1..], &["-n", "--pomodoros"]).map(|n| {
+                n.parse().unwrap_or_else(|_| {
+                    eprintln!("{}: {}", tr().cli.invalid_count, n);
+                    std::process::exit(1);
+                })
+            });
+            // Everything except the -n flag and its value forms the title
+            let mut words = Vec::new();
+            let mut rest = args[1..].iter();
+            while let Some(arg) = rest.next() {
+                if arg == "-n" || arg == "--pomodoros" {
+                    rest.next();
+                } else {
+                    words.push(arg.as_str());
+                }
+            }
+            let title = words.join(" ");
+            if title.trim().is_empty() {
+                eprintln!("{}", tr().cli.task_add_usage);
+                std::process::exit(1);
+            }
+            IpcCommand::TaskAdd { title, pomodoros }
+        }
+        Some("start") => IpcCommand::TaskStart {
+            id: task_id(args.get(1)),
+        },
+        Some("done" | "complete") => IpcCommand::TaskDone {
+            id: task_id(args.get(1)),
+        },
+        Some(other) => {
+            eprintln!("{}: {}", tr().cli.unknown_command, other);
+            eprintln!("{}", tr().cli.task_commands);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Parse the arguments of a `profile` subcommand
+fn parse_profile_args(args: &[String]) -> IpcCommand {
+    match args.first().map(|a| a.to_lowercase()).as_deref() {
+        None | Some("list" | "ls") => IpcCommand::ProfileList,
+        Some("use" | "switch") => {
+            let name = args[1..].join(" ");
+            if name.trim().is_empty() {
+                eprintln!("{}", tr().cli.profile_use_usage);
+                std::process::exit(1);
+            }
+            IpcCommand::ProfileUse { name }
+        }
+        Some(other) => {
+            eprintln!("{}: {}", tr().cli.unknown_command, other);
+            eprintln!("{}", tr().cli.profile_commands);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// The time `secs` seconds from now, unless that's out of range
+fn seconds_from_now(secs: u64) -> Option<DateTime<Local>> {
+    let secs = i64::try_from(secs).ok()?;
+    Local::now().checked_add_signed(chrono::TimeDelta::try_seconds(secs)?)
+}
+
+/// Parse a wall-clock time (`HH:MM`) as its next occurrence
+fn parse_clock_time(input: &str) -> Option<DateTime<Local>> {
+    let time = NaiveTime::parse_from_str(input.trim(), "%H:%M").ok()?;
+    let now = Local::now();
+    let today = now.date_naive().and_time(time).and_local_timezone(Local);
+    let at = today.earliest()?;
+    if at > now {
+        Some(at)
+    } else {
+        // Already past today: run tomorrow
+        (now.date_naive().succ_opt()?)
+            .and_time(time)
+            .and_local_timezone(Local)
+            .earliest()
+    }
+}
+
+/// Parse an `on`/`off`/`toggle` argument (`None` means toggle)
+fn parse_switch(arg: Option<&String>, default: Option<bool>) -> Option<bool> {
+    match arg.map(|a| a.to_lowercase()).as_deref() {
+        None => default,
+        Some("on" | "true" | "1") => Some(true),
+        Some("off" | "false" | "0") => Some(false),
+        Some("toggle") => None,
+        Some(other) => {
+            eprintln!(
+                "{}: {} ({})",
+                tr().cli.invalid_value,
+                other,
+                tr().cli.expected_switch
+            );
             std::process::exit(1);
         }
     }
@@ -101,14 +343,27 @@ fn parse_option(args: &[String], flags: &[&str]) -> Option<String> {
 }
 
 fn main() {
+    // A separate profile has to be chosen before anything reads the config
+    let mut args: Vec<String> = env::args().collect();
+    if let Some(name) = take_profile_arg(&mut args) {
+        if !pomodorust::data::instance::set_profile(&name) {
+            eprintln!("{}: {:?}", tr().cli.invalid_profile, name);
+            std::process::exit(1);
+        }
+    }
+
+    // CLI output and the GUI follow the language chosen in the settings
+    let (config, config_issues) = Config::load_checked();
+    pomodorust::i18n::set_language(config.appearance.language);
+
     // Parse CLI arguments
-    if let Some(command) = parse_args() {
+    if let Some(command) = parse_args(&args) {
         run_cli(command);
         return;
     }
 
     // Otherwise, run GUI
-    run_gui();
+    run_gui(config, config_issues);
 }
 
 /// Run the CLI mode
@@ -123,35 +378,165 @@ fn run_cli(command: Command) {
         }
     }
 
+    // Checking the config works on the file, with or without the app
+    if matches!(command, Command::ConfigCheck) {
+        run_config_check();
+        return;
+    }
+    if let Command::Logs { lines, path } = command {
+        run_logs(lines, path);
+        return;
+    }
+
     // Check if app is running for non-ping commands
-    if !matches!(command, Command::Ping) && !is_app_running() {
-        eprintln!("Error: PomodoRust GUI is not running. Start the app first.");
-        std::process::exit(1);
+    if !matches!(command, Command::Ping) {
+        let problem = match pomodorust::ipc::lookup() {
+            Lookup::Running(_) => None,
+            Lookup::PortTaken(port) => {
+                Some(tr().cli.port_taken.replace("{port}", &port.to_string()))
+            }
+            Lookup::NotRunning => Some(tr().cli.not_running.to_string()),
+        };
+        if let Some(problem) = problem {
+            eprintln!("{}: {}", tr().cli.error, problem);
+            std::process::exit(1);
+        }
+    }
+
+    if matches!(command, Command::Watch) {
+        run_watch();
+        return;
     }
 
+    #[cfg(feature = "tui")]
+    if matches!(command, Command::Tui) {
+        if let Err(e) = pomodorust::tui::run() {
+            eprintln!("{}: {}", tr().cli.error, e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Follow-up command sent after the main one succeeds (`pause --for`)
+    let mut follow_up = None;
+
     let ipc_command = match command {
-        Command::Start { session } => IpcCommand::Start {
-            session_type: session,
-        },
-        Command::Pause => IpcCommand::Pause,
+        Command::Start {
+            session,
+            minutes,
+            tag,
+            at,
+        } => {
+            let start = IpcCommand::Start {
+                session_type: session,
+                minutes,
+                tag,
+            };
+            match at {
+                Some(at) => IpcCommand::Schedule {
+                    at: at.to_rfc3339(),
+                    action: Box::new(start),
+                },
+                None => start,
+            }
+        }
+        Command::Pause { resume_at } => {
+            if let Some(at) = resume_at {
+                follow_up = Some(IpcCommand::Schedule {
+                    at: at.to_rfc3339(),
+                    action: Box::new(IpcCommand::Resume),
+                });
+            }
+            IpcCommand::Pause
+        }
         Command::Resume => IpcCommand::Resume,
         Command::Toggle => IpcCommand::Toggle,
         Command::Stop => IpcCommand::Stop,
         Command::Skip => IpcCommand::Skip,
         Command::Status => IpcCommand::Status,
-        Command::Stats { period } => IpcCommand::Stats { period },
+        Command::Stats { period, from, to } => IpcCommand::Stats { period, from, to },
+        Command::SetOption { option, value } => IpcCommand::SetOption { option, value },
+        Command::Kiosk { enabled, message } => IpcCommand::Kiosk { enabled, message },
+        Command::Unschedule => IpcCommand::CancelScheduled,
+        Command::Task(command) | Command::Profile(command) => command,
+        Command::Show { view } => IpcCommand::Show { view },
+        Command::Quit => IpcCommand::Quit,
         Command::Ping => IpcCommand::Ping,
+        Command::Watch | Command::ConfigCheck | Command::Logs { .. } => {
+            unreachable!("handled above")
+        }
+        #[cfg(feature = "tui")]
+        Command::Tui => unreachable!("handled above"),
     };
 
-    match send_command(&ipc_command) {
-        Ok(response) => handle_cli_response(response),
+    for command in std::iter::once(ipc_command).chain(follow_up) {
+        match send_command(&command) {
+            Ok(response) => handle_cli_response(response),
+            Err(e) => {
+                eprintln!("{}: {}", tr().cli.error, e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// List the problems in config.toml, exiting with 1 when there are any
+fn run_config_check() {
+    let t = &tr().cli;
+    let path = Config::config_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "config.toml".to_string());
+    match Config::check_file() {
+        Ok(issues) if issues.is_empty() => println!("{}: {}", path, t.config_ok),
+        Ok(issues) => {
+            println!("{} ({}):", tr().common.config_problems, path);
+            for issue in &issues {
+                println!("  {}", issue);
+            }
+            std::process::exit(1);
+        }
         Err(e) => {
-            eprintln!("Error: {}", e);
+            eprintln!("{}: {}", t.error, e);
             std::process::exit(1);
         }
     }
 }
 
+/// Print the latest lines of the app's log, or where it is
+fn run_logs(lines: usize, path: bool) {
+    let Some(log_path) = pomodorust::logging::log_path() else {
+        eprintln!("{}: {}", tr().cli.error, tr().cli.no_logs);
+        std::process::exit(1);
+    };
+    if path {
+        println!("{}", log_path.display());
+        return;
+    }
+    let recent = pomodorust::logging::recent_lines(lines);
+    if recent.is_empty() {
+        eprintln!("{} ({})", tr().cli.no_logs, log_path.display());
+        std::process::exit(1);
+    }
+    for line in recent {
+        println!("{}", line);
+    }
+}
+
+/// Stream timer events to stdout, one JSON object per line
+fn run_watch() {
+    use std::io::Write;
+
+    let result = pomodorust::ipc::subscribe(|event| {
+        let mut stdout = std::io::stdout().lock();
+        writeln!(stdout, "{}", event.to_json()).is_ok() && stdout.flush().is_ok()
+    });
+
+    if let Err(e) = result {
+        eprintln!("{}: {}", tr().cli.error, e);
+        std::process::exit(1);
+    }
+}
+
 fn handle_cli_response(response: IpcResponse) {
     match response {
         IpcResponse::Ok { message } => {
@@ -167,11 +552,17 @@ fn handle_cli_response(response: IpcResponse) {
         IpcResponse::Stats(stats) => {
             print_stats(&stats);
         }
-        IpcResponse::Pong => {
-            println!("PomodoRust is running");
+        IpcResponse::Tasks { tasks } => {
+            print_tasks(&tasks);
+        }
+        IpcResponse::Profiles { profiles, active } => {
+            print_profiles(&profiles, active.as_deref());
+        }
+        IpcResponse::Pong { .. } => {
+            println!("{}", tr().cli.running);
         }
         IpcResponse::Error { message } => {
-            eprintln!("Error: {}", message);
+            eprintln!("{}: {}", tr().cli.error, message);
             std::process::exit(1);
         }
     }
@@ -185,10 +576,11 @@ fn print_status(status: &IpcStatus) {
         _ => "--",
     };
 
+    let t = &tr().cli;
     let session_label = match status.session_type.as_str() {
-        "work" => "Focus",
-        "short_break" => "Short Break",
-        "long_break" => "Long Break",
+        "work" => t.focus,
+        "short_break" => t.short_break,
+        "long_break" => t.long_break,
         _ => &status.session_type,
     };
 
@@ -197,24 +589,87 @@ fn print_status(status: &IpcStatus) {
         state_icon, session_label, status.remaining_formatted
     );
     println!(
-        "   Session {}/{} | Progress: {:.0}%",
+        "   {} {}/{} | {}: {:.0}%",
+        t.session,
         status.current_session,
         status.total_sessions,
+        t.progress,
         status.progress * 100.0
     );
 }
 
+fn print_tasks(tasks: &[IpcTask]) {
+    let t = &tr().cli;
+    if tasks.is_empty() {
+        println!("{}", t.no_tasks);
+        return;
+    }
+
+    for task in tasks {
+        let check = if task.completed { "[x]" } else { "[ ]" };
+        let priority = match task.priority.as_str() {
+            "none" => String::new(),
+            p => format!(" ({})", p),
+        };
+        let queue = match task.queue_position {
+            Some(0) => format!(
+                "  >> {} {}/{}",
+                t.current, task.completed_pomodoros, task.planned_pomodoros
+            ),
+            Some(_) => format!(
+                "  {} {}/{}",
+                t.queued, task.completed_pomodoros, task.planned_pomodoros
+            ),
+            None => String::new(),
+        };
+        println!(
+            "{:>5} {} {}{}{}",
+            format!("#{}", task.id),
+            check,
+            task.title,
+            priority,
+            queue
+        );
+    }
+}
+
+fn print_profiles(profiles: &[String], active: Option<&str>) {
+    if profiles.is_empty() {
+        println!("{}", tr().cli.no_profiles);
+        return;
+    }
+
+    for name in profiles {
+        let marker = if Some(name.as_str()) == active {
+            ">>"
+        } else {
+            "  "
+        };
+        println!("{} {}", marker, name);
+    }
+}
+
 fn print_stats(stats: &IpcStats) {
+    let t = &tr().cli;
+    let range_label;
     let period_label = match stats.period.as_str() {
-        "today" => "Today",
-        "week" => "This Week",
-        "all" => "All Time",
-        _ => &stats.period,
+        "today" => t.today,
+        "week" => t.this_week,
+        "month" => t.this_month,
+        "year" => t.this_year,
+        "all" => t.all_time,
+        _ => match (&stats.from, &stats.to) {
+            (Some(from), Some(to)) => {
+                range_label = format!("{} {} {}", from, t.range_to, to);
+                &range_label
+            }
+            _ => &stats.period,
+        },
     };
 
     println!("=== {} ===", period_label);
-    println!("Focus Time: {:.1}h", stats.hours);
-    println!("Pomodoros:  {}", stats.pomodoros);
+    println!("{}: {:.1}h", t.focus_time, stats.hours);
+    println!("{}: {}", t.pomodoros, stats.pomodoros);
 
     if stats.period == "today" {
         let progress = if stats.daily_goal > 0 {
@@ -223,34 +678,41 @@ fn print_stats(stats: &IpcStats) {
             100.0
         };
         println!(
-            "Daily Goal: {}/{} ({:.0}%)",
-            stats.today_pomodoros, stats.daily_goal, progress
+            "{}: {}/{} ({:.0}%)",
+            t.daily_goal, stats.today_pomodoros, stats.daily_goal, progress
         );
     }
 
     println!(
-        "Streak:     {} days (best: {})",
-        stats.current_streak, stats.longest_streak
+        "{}: {} {} ({}: {})",
+        t.streak, stats.current_streak, t.days, t.best, stats.longest_streak
     );
 }
 
 /// Run the GUI mode
-fn run_gui() {
+fn run_gui(config: Config, config_issues: Vec<ConfigIssue>) {
     // Check if another instance is already running
     if is_app_running() {
-        // Try to show existing window and exit
+        // Ask the running instance to show its window, then exit
         #[cfg(windows)]
-        {
+        pomodorust::platform::allow_set_foreground_window();
+        let shown = matches!(
+            send_command(&IpcCommand::Show { view: None }),
+            Ok(IpcResponse::Ok { .. })
+        );
+        if !shown {
+            // Instances from older versions don't know the Show command
+            #[cfg(any(windows, target_os = "macos"))]
             pomodorust::platform::show_pomodorust_window();
         }
         return;
     }
 
-    // Load config early to apply window settings
-    let config = Config::load();
-
-    // Initialize language from config
-    pomodorust::i18n::set_language(config.appearance.language);
+    pomodorust::logging::init(cfg!(debug_assertions));
+    tracing::info!("PomodoRust {} starting", VERSION);
+    for issue in &config_issues {
+        tracing::warn!("Config: {}", issue);
+    }
 
     let mut viewport = egui::ViewportBuilder::default()
         .with_inner_size([config.window.width, config.window.height])
@@ -283,7 +745,7 @@ fn run_gui() {
     };
 
     // Create system tray (must be on main thread before event loop)
-    let system_tray = if config.system.minimize_to_tray {
+    let system_tray = if config.system.wants_tray() {
         match pomodorust::platform::SystemTray::new() {
             Ok(tray) => {
                 tracing::info!("System tray created");
@@ -316,11 +778,10 @@ fn run_gui() {
         "PomodoRust",
         options,
         Box::new(move |cc| {
-            Ok(Box::new(PomodoRustApp::with_config(
-                cc,
-                config,
-                system_tray,
-            )))
+            Ok(Box::new(
+                PomodoRustApp::with_config(cc, config, system_tray)
+                    .with_config_issues(config_issues),
+            ))
         }),
     );
 }
@@ -0,0 +1,137 @@
+//! The application in the foreground
+//!
+//! - Windows: the executable of the process owning the foreground window
+//! - Linux: the `WM_CLASS` of the active window, read with `xprop` (X11 and
+//!   XWayland windows only)
+//! - macOS: the display name of the front application, from `lsappinfo`
+//!
+//! The Linux and macOS backends shell out, so callers should poll every few
+//! seconds rather than every frame.
+
+/// Name of the application in the foreground, if it can be determined
+#[cfg(windows)]
+pub fn focused_app() -> Option<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd.is_invalid() {
+            return None;
+        }
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == 0 {
+            return None;
+        }
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut path = [0u16; 1024];
+        let mut len = path.len() as u32;
+        let queried = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(path.as_mut_ptr()),
+            &mut len,
+        );
+        let _ = CloseHandle(process);
+        queried.ok()?;
+
+        // `C:\...\Code.exe` is "Code"
+        let path = String::from_utf16_lossy(&path[..len as usize]);
+        let name = std::path::Path::new(&path).file_stem()?.to_string_lossy();
+        (!name.is_empty()).then(|| name.into_owned())
+    }
+}
+
+/// Name of the application in the foreground, if it can be determined
+#[cfg(target_os = "linux")]
+pub fn focused_app() -> Option<String> {
+    // `_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007`
+    let root = xprop(&["-root", "_NET_ACTIVE_WINDOW"])?;
+    let window = parse_window_id(&root)?;
+    // `WM_CLASS(STRING) = "code", "Code"`, the class comes last
+    let class = xprop(&["-id", &window, "WM_CLASS"])?;
+    last_quoted(&class)
+}
+
+/// Run `xprop`, returning its output
+#[cfg(target_os = "linux")]
+fn xprop(args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new("xprop")
+        .args(args)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Window id from `xprop -root _NET_ACTIVE_WINDOW`, `None` without one
+#[cfg(target_os = "linux")]
+fn parse_window_id(output: &str) -> Option<String> {
+    let id = output.rsplit("# ").next()?.trim();
+    (id.starts_with("0x") && id != "0x0").then(|| id.to_string())
+}
+
+/// Name of the application in the foreground, if it can be determined
+#[cfg(target_os = "macos")]
+pub fn focused_app() -> Option<String> {
+    let lsappinfo = |args: &[&str]| {
+        let output = std::process::Command::new("lsappinfo")
+            .args(args)
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    // `ASN:0x0-0x1d01d:`, then `"LSDisplayName"="Safari"`
+    let front = lsappinfo(&["front"])?;
+    let info = lsappinfo(&["info", "-only", "name", &front])?;
+    last_quoted(&info)
+}
+
+/// The last double-quoted string in `text`
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn last_quoted(text: &str) -> Option<String> {
+    let end = text.rfind('"')?;
+    let start = text[..end].rfind('"')? + 1;
+    let value = &text[start..end];
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Name of the application in the foreground (unsupported platform)
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
+pub fn focused_app() -> Option<String> {
+    None
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_xprop_output() {
+        assert_eq!(
+            parse_window_id("_NET_ACTIVE_WINDOW(WINDOW): window id # 0x3a00007\n").as_deref(),
+            Some("0x3a00007")
+        );
+        assert_eq!(
+            parse_window_id("_NET_ACTIVE_WINDOW(WINDOW): window id # 0x0"),
+            None
+        );
+        assert_eq!(
+            last_quoted("WM_CLASS(STRING) = \"code\", \"Code\"\n").as_deref(),
+            Some("Code")
+        );
+        assert_eq!(last_quoted("WM_CLASS:  not found."), None);
+    }
+}
@@ -0,0 +1,167 @@
+//! Ambient sounds synthesized on the fly
+//!
+//! Rather than shipping recordings, each sound is generated from noise:
+//! brown noise is integrated white noise, rain is pink noise with random
+//! drops on top, and the café is a slowly swelling low murmur with the odd
+//! clink of a cup.
+
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use rodio::Source;
+
+use crate::data::AmbientSound;
+
+const SAMPLE_RATE: u32 = 44_100;
+
+/// Small xorshift generator, plenty for noise
+struct Noise(u32);
+
+impl Noise {
+    /// Uniform in -1..1
+    fn next(&mut self) -> f32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+    }
+
+    /// Uniform in 0..1
+    fn unit(&mut self) -> f32 {
+        (self.next() + 1.0) * 0.5
+    }
+}
+
+/// Endless mono stream of one ambient sound
+pub(super) struct AmbientSource {
+    sound: AmbientSound,
+    noise: Noise,
+    /// Integrator of the brown noise
+    brown: f32,
+    /// Filter taps of the pink noise
+    pink: [f32; 3],
+    /// One-pole lowpass of the murmur
+    low: f32,
+    /// Envelope of the current drop or clink and its decay per sample
+    burst: f32,
+    burst_decay: f32,
+    /// Phase of the clink tone and its step per sample
+    phase: f32,
+    phase_step: f32,
+    /// Samples played so far, drives the swell of the murmur
+    position: u64,
+}
+
+impl AmbientSource {
+    pub(super) fn new(sound: AmbientSound) -> Self {
+        Self {
+            sound,
+            // Any nonzero seed, different per sound so they don't correlate
+            noise: Noise(0x9E37_79B9 ^ (sound as u32 + 1).wrapping_mul(0x85EB_CA6B)),
+            brown: 0.0,
+            pink: [0.0; 3],
+            low: 0.0,
+            burst: 0.0,
+            burst_decay: 0.0,
+            phase: 0.0,
+            phase_step: 0.0,
+            position: 0,
+        }
+    }
+
+    fn brown_noise(&mut self, white: f32) -> f32 {
+        self.brown = (self.brown + 0.02 * white) / 1.02;
+        self.brown * 3.5
+    }
+
+    /// Paul Kellet's economy pink filter
+    fn pink_noise(&mut self, white: f32) -> f32 {
+        let [b0, b1, b2] = &mut self.pink;
+        *b0 = 0.99765 * *b0 + white * 0.099_046;
+        *b1 = 0.96300 * *b1 + white * 0.296_516_4;
+        *b2 = 0.57000 * *b2 + white * 1.052_691_3;
+        (*b0 + *b1 + *b2 + white * 0.1848) * 0.25
+    }
+
+    fn rain(&mut self, white: f32) -> f32 {
+        let hiss = self.pink_noise(white) * 0.5;
+        // About 25 drops a second, each a few milliseconds of noise
+        if self.burst < 0.01 && self.noise.unit() < 0.000_6 {
+            self.burst = 0.3 + 0.7 * self.noise.unit();
+            self.burst_decay = 0.990 + 0.008 * self.noise.unit();
+        }
+        let drop = self.burst * self.noise.next() * 0.6;
+        self.burst *= self.burst_decay;
+        hiss + drop
+    }
+
+    fn cafe(&mut self, white: f32) -> f32 {
+        self.low += 0.02 * (white - self.low);
+        let t = self.position as f32 / SAMPLE_RATE as f32;
+        let swell = 0.6 + 0.4 * (TAU * 0.23 * t).sin() * (TAU * 0.07 * t + 1.3).sin();
+        let murmur = self.low * swell * 3.0;
+
+        // Roughly one clink a second, a short high tone
+        if self.burst < 0.001 && self.noise.unit() < 0.000_02 {
+            self.burst = 0.08 + 0.08 * self.noise.unit();
+            self.burst_decay = 0.9997;
+            self.phase_step = TAU * (2500.0 + 1500.0 * self.noise.unit()) / SAMPLE_RATE as f32;
+        }
+        self.phase = (self.phase + self.phase_step) % TAU;
+        let clink = self.burst * self.phase.sin();
+        self.burst *= self.burst_decay;
+        murmur + clink
+    }
+}
+
+impl Iterator for AmbientSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let white = self.noise.next();
+        let sample = match self.sound {
+            AmbientSound::Rain => self.rain(white),
+            AmbientSound::Cafe => self.cafe(white),
+            AmbientSound::BrownNoise => self.brown_noise(white),
+        };
+        self.position = self.position.wrapping_add(1);
+        Some(sample.clamp(-1.0, 1.0))
+    }
+}
+
+impl Source for AmbientSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sources_stay_in_range_and_make_sound() {
+        for sound in AmbientSound::all() {
+            let samples: Vec<f32> = AmbientSource::new(*sound)
+                .take(SAMPLE_RATE as usize)
+                .collect();
+            assert!(samples.iter().all(|s| (-1.0..=1.0).contains(s)));
+            let energy = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+            assert!(energy > 1e-5, "{:?} is silent", sound);
+        }
+    }
+}
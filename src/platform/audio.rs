@@ -2,11 +2,22 @@
 //!
 //! Audio is lazily initialized on first use to improve startup performance.
 //! This is especially important on Windows 10 where audio initialization can be slow.
+//!
+//! The notification and tick sounds can be replaced by the user's own files,
+//! which are read and checked once when chosen and fall back to the built-in
+//! sound when they can't be played.
 
+use rodio::source::SineWave;
 use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
 use std::io::Cursor;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::data::NotificationSound;
+use super::ambient::AmbientSource;
+use crate::data::{AmbientSound, NotificationSound};
+use crate::error::AudioError;
 
 // Embed sound files at compile time
 const SOUND_SOFT_BELL: &[u8] = include_bytes!("../../assets/soft_bell.mp3");
@@ -14,19 +25,95 @@ const SOUND_LEVEL_UP: &[u8] = include_bytes!("../../assets/level_up.mp3");
 const SOUND_DIGITAL_ALERT: &[u8] = include_bytes!("../../assets/digital_alert.mp3");
 const SOUND_TICK: &[u8] = include_bytes!("../../assets/tick.mp3");
 
+/// How long the tick takes to swell in when it starts and to die away
+const TICK_FADE: Duration = Duration::from_millis(800);
+
+/// How long a chime rings out
+const CHIME_LENGTH: Duration = Duration::from_millis(1200);
+
+/// Largest sound file accepted, a notification or tick is only seconds long
+const MAX_SOUND_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
 /// Inner audio state that is lazily initialized
 struct AudioInner {
     _stream: OutputStream,
     stream_handle: OutputStreamHandle,
 }
 
+/// Source that fades in from silence and ends with a fade out once asked to,
+/// so the tick doesn't start or stop mid-click
+struct Fade<S> {
+    inner: S,
+    gain: f32,
+    /// Gain change per sample
+    step: f32,
+    fade_out: Arc<AtomicBool>,
+}
+
+impl<S: Source<Item = f32>> Fade<S> {
+    fn new(inner: S, length: Duration, fade_out: Arc<AtomicBool>) -> Self {
+        let samples = inner.sample_rate() as f32 * inner.channels() as f32 * length.as_secs_f32();
+        Self {
+            inner,
+            gain: 0.0,
+            step: 1.0 / samples.max(1.0),
+            fade_out,
+        }
+    }
+}
+
+impl<S: Source<Item = f32>> Iterator for Fade<S> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.fade_out.load(Ordering::Relaxed) {
+            self.gain -= self.step;
+            if self.gain <= 0.0 {
+                return None;
+            }
+        } else if self.gain < 1.0 {
+            self.gain = (self.gain + self.step).min(1.0);
+        }
+        self.inner.next().map(|sample| sample * self.gain)
+    }
+}
+
+impl<S: Source<Item = f32>> Source for Fade<S> {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// The looping tick and the switch that fades it out
+struct TickLoop {
+    sink: Sink,
+    fade_out: Arc<AtomicBool>,
+}
+
 /// Audio player for playing notification sounds.
 /// Lazily initializes the audio stream on first use to speed up app startup.
 pub struct AudioPlayer {
     inner: Option<AudioInner>,
     init_attempted: bool,
     volume: f32,
-    tick_sink: Option<Sink>,
+    tick: Option<TickLoop>,
+    /// Ambient sounds playing, with their own level before the volume
+    ambient: Vec<(AmbientSound, f32, Sink)>,
+    /// User files played instead of the built-in notification and tick
+    custom_notification: Option<Vec<u8>>,
+    custom_tick: Option<Vec<u8>>,
 }
 
 impl AudioPlayer {
@@ -36,10 +123,47 @@ impl AudioPlayer {
             inner: None,
             init_attempted: false,
             volume: 0.8,
-            tick_sink: None,
+            tick: None,
+            ambient: Vec::new(),
+            custom_notification: None,
+            custom_tick: None,
         })
     }
 
+    /// Read a sound file and check that it can be decoded
+    pub fn load_sound_file(path: &Path) -> Result<Vec<u8>, AudioError> {
+        let read_error = |source| AudioError::ReadFile {
+            path: path.to_path_buf(),
+            source,
+        };
+        let size = std::fs::metadata(path).map_err(read_error)?.len();
+        if size > MAX_SOUND_FILE_BYTES {
+            return Err(AudioError::FileTooLarge {
+                path: path.to_path_buf(),
+            });
+        }
+        let data = std::fs::read(path).map_err(read_error)?;
+        Decoder::new(Cursor::new(data.clone())).map_err(|e| AudioError::Decode {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        Ok(data)
+    }
+
+    /// Play these files instead of the built-in notification and tick,
+    /// `None` or a file that doesn't load keeps the built-in sound
+    pub fn set_custom_sounds(&mut self, notification: Option<&Path>, tick: Option<&Path>) {
+        let load = |path: Option<&Path>| {
+            Self::load_sound_file(path?)
+                .inspect_err(|e| tracing::warn!("{}, using the built-in sound", e))
+                .ok()
+        };
+        self.custom_notification = load(notification);
+        self.custom_tick = load(tick);
+        // Restarted with the new sound by the caller's next tick update
+        self.stop_tick();
+    }
+
     /// Ensure audio is initialized. Returns true if audio is available.
     fn ensure_initialized(&mut self) -> bool {
         if self.inner.is_some() {
@@ -82,13 +206,27 @@ impl AudioPlayer {
     pub fn set_volume(&mut self, volume: f32) {
         self.volume = volume.clamp(0.0, 1.0);
         // Update tick volume if playing
-        if let Some(ref sink) = self.tick_sink {
-            sink.set_volume(self.volume);
+        if let Some(ref tick) = self.tick {
+            tick.sink.set_volume(self.volume);
+        }
+        for (_, level, sink) in &self.ambient {
+            sink.set_volume(level * self.volume);
         }
     }
 
-    /// Play the selected notification sound
+    /// Play the custom notification file if there is one, otherwise the
+    /// selected built-in sound
     pub fn play_notification(&mut self, sound: NotificationSound) {
+        if let Some(data) = self.custom_notification.clone() {
+            self.play_sound_data(&data);
+            return;
+        }
+
+        self.play_built_in(sound);
+    }
+
+    /// Play one of the built-in notification sounds
+    pub fn play_built_in(&mut self, sound: NotificationSound) {
         let sound_data = match sound {
             NotificationSound::SoftBell => SOUND_SOFT_BELL,
             NotificationSound::LevelUp => SOUND_LEVEL_UP,
@@ -98,7 +236,34 @@ impl AudioPlayer {
         self.play_sound_data(sound_data);
     }
 
-    /// Play raw sound data (mp3)
+    /// Soft two-tone chime marking a point within a session
+    pub fn play_chime(&mut self) {
+        let Some(handle) = self.stream_handle() else {
+            return;
+        };
+
+        let tone = |frequency| {
+            let mut tone = SineWave::new(frequency).take_duration(CHIME_LENGTH);
+            tone.set_filter_fadeout();
+            tone.amplify(0.15)
+        };
+        if let Ok(sink) = Sink::try_new(handle) {
+            sink.set_volume(self.volume);
+            // C6 and G6
+            sink.append(tone(1046.5).mix(tone(1568.0)));
+            sink.detach();
+        }
+    }
+
+    /// Play a sound file once, to try it out
+    pub fn play_file(&mut self, path: &Path) {
+        match Self::load_sound_file(path) {
+            Ok(data) => self.play_sound_data(&data),
+            Err(e) => tracing::warn!("{}", e),
+        }
+    }
+
+    /// Play raw sound data in any format the decoder knows
     fn play_sound_data(&mut self, data: &[u8]) {
         let Some(handle) = self.stream_handle() else {
             return;
@@ -120,24 +285,33 @@ impl AudioPlayer {
         }
     }
 
-    /// Start playing tick-tock sound in a loop
+    /// Start playing tick-tock sound in a loop, fading in
     pub fn start_tick(&mut self) {
         // Already playing
-        if self.tick_sink.is_some() {
+        if self.tick.is_some() {
             return;
         }
 
+        let data = self
+            .custom_tick
+            .clone()
+            .unwrap_or_else(|| SOUND_TICK.to_vec());
         let Some(handle) = self.stream_handle() else {
             return;
         };
 
-        let cursor = Cursor::new(SOUND_TICK.to_vec());
+        let cursor = Cursor::new(data);
         match Decoder::new(cursor) {
             Ok(source) => {
                 if let Ok(sink) = Sink::try_new(handle) {
+                    let fade_out = Arc::new(AtomicBool::new(false));
                     sink.set_volume(self.volume);
-                    sink.append(source.repeat_infinite());
-                    self.tick_sink = Some(sink);
+                    sink.append(Fade::new(
+                        source.convert_samples().repeat_infinite(),
+                        TICK_FADE,
+                        fade_out.clone(),
+                    ));
+                    self.tick = Some(TickLoop { sink, fade_out });
                 }
             }
             Err(e) => {
@@ -146,15 +320,86 @@ impl AudioPlayer {
         }
     }
 
-    /// Stop playing tick-tock sound
+    /// Stop playing tick-tock sound, letting it fade out
     pub fn stop_tick(&mut self) {
-        if let Some(sink) = self.tick_sink.take() {
-            sink.stop();
+        if let Some(tick) = self.tick.take() {
+            tick.fade_out.store(true, Ordering::Relaxed);
+            // Plays on by itself until the fade ends the source
+            tick.sink.detach();
         }
     }
 
     /// Check if tick sound is currently playing
     pub fn is_tick_playing(&self) -> bool {
-        self.tick_sink.is_some()
+        self.tick.is_some()
+    }
+
+    /// Play the tick faster (1.0 is normal speed)
+    pub fn set_tick_speed(&mut self, speed: f32) {
+        if let Some(ref tick) = self.tick {
+            if tick.sink.speed() != speed {
+                tick.sink.set_speed(speed);
+            }
+        }
+    }
+
+    /// Play each ambient sound at its level (0.0 to 1.0), stopping those at 0
+    pub fn set_ambient(&mut self, levels: &[(AmbientSound, f32)]) {
+        self.ambient.retain(|(sound, _, sink)| {
+            let keep = levels
+                .iter()
+                .any(|(other, level)| other == sound && *level > 0.0);
+            if !keep {
+                sink.stop();
+            }
+            keep
+        });
+
+        for &(sound, level) in levels {
+            if level <= 0.0 {
+                continue;
+            }
+            let volume = self.volume;
+            if let Some(playing) = self.ambient.iter_mut().find(|(s, _, _)| *s == sound) {
+                if playing.1 != level {
+                    playing.1 = level;
+                    playing.2.set_volume(level * volume);
+                }
+                continue;
+            }
+
+            let Some(handle) = self.stream_handle() else {
+                return;
+            };
+            if let Ok(sink) = Sink::try_new(handle) {
+                sink.set_volume(level * volume);
+                sink.append(AmbientSource::new(sound));
+                self.ambient.push((sound, level, sink));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fade_swells_in_and_ends_after_fading_out() {
+        let fade_out = Arc::new(AtomicBool::new(false));
+        let mut tick = Fade::new(SineWave::new(440.0), TICK_FADE, fade_out.clone());
+        // Starts from silence
+        tick.next();
+        assert!(tick.gain < 0.001);
+        let fade_samples = (48_000.0 * TICK_FADE.as_secs_f32()) as usize;
+        for _ in 0..fade_samples {
+            tick.next();
+        }
+        assert_eq!(tick.gain, 1.0);
+
+        fade_out.store(true, Ordering::Relaxed);
+        let tail = tick.by_ref().take(fade_samples * 2).count();
+        assert!(tail <= fade_samples + 1);
+        assert_eq!(tick.next(), None);
     }
 }
@@ -0,0 +1,319 @@
+//! Distraction blocker for focus sessions
+//!
+//! While active:
+//! - apps from the block list are closed whenever they are found running
+//!   (checked every few seconds, so relaunching them doesn't help)
+//! - blocked domains (and their `www.` variants) are pointed at `0.0.0.0` in a
+//!   marked section of the hosts file, which is removed again on unblock
+//!
+//! Editing the hosts file needs administrator/root rights; without them only
+//! app blocking works and a warning is logged.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::data::BlockerConfig;
+
+/// How often blocked apps are looked for
+const KILL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Markers around our section of the hosts file
+const HOSTS_BEGIN: &str = "# >>> PomodoRust focus block";
+const HOSTS_END: &str = "# <<< PomodoRust focus block";
+
+enum BlockerCommand {
+    Block(BlockerConfig),
+    Unblock,
+}
+
+/// Background worker applying and lifting blocks
+pub struct DistractionBlocker {
+    tx: Sender<BlockerCommand>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl DistractionBlocker {
+    /// Spawn the worker thread, clearing any block left over from a crash
+    pub fn start() -> Self {
+        let (tx, rx) = mpsc::channel();
+
+        let worker = thread::Builder::new()
+            .name("blocker".into())
+            .spawn(move || {
+                update_hosts(&[]);
+                let mut blocked_apps: Vec<String> = Vec::new();
+                loop {
+                    match rx.recv_timeout(KILL_INTERVAL) {
+                        Ok(BlockerCommand::Block(config)) => {
+                            update_hosts(&config.domains);
+                            blocked_apps = config.apps;
+                        }
+                        Ok(BlockerCommand::Unblock) => {
+                            update_hosts(&[]);
+                            blocked_apps.clear();
+                        }
+                        Err(RecvTimeoutError::Timeout) => {}
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    }
+                    for app in &blocked_apps {
+                        kill_app(app);
+                    }
+                }
+            })
+            .map_err(|e| tracing::error!("Failed to start distraction blocker: {}", e))
+            .ok();
+
+        Self { tx, worker }
+    }
+
+    /// Start blocking the configured apps and domains
+    pub fn block(&self, config: &BlockerConfig) {
+        let _ = self.tx.send(BlockerCommand::Block(config.clone()));
+    }
+
+    /// Lift all blocks
+    pub fn unblock(&self) {
+        let _ = self.tx.send(BlockerCommand::Unblock);
+    }
+
+    /// Lift all blocks and wait for the hosts file to be restored
+    pub fn shutdown(self) {
+        self.unblock();
+        // Closing the channel ends the worker loop
+        let Self { tx, worker } = self;
+        drop(tx);
+        if let Some(worker) = worker {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Close every running instance of an app
+fn kill_app(name: &str) {
+    #[cfg(windows)]
+    let mut command = {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+        let image = if name.contains('.') {
+            name.to_string()
+        } else {
+            format!("{}.exe", name)
+        };
+        let mut command = Command::new("taskkill");
+        command
+            .args(["/F", "/IM", &image])
+            .creation_flags(CREATE_NO_WINDOW);
+        command
+    };
+
+    #[cfg(not(windows))]
+    let mut command = {
+        let mut command = Command::new("pkill");
+        command.args(["-x", name]);
+        command
+    };
+
+    let killed = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+    if killed {
+        tracing::info!("Closed blocked app: {}", name);
+    }
+}
+
+/// Path of the system hosts file
+fn hosts_path() -> PathBuf {
+    #[cfg(windows)]
+    {
+        let root = std::env::var("SystemRoot").unwrap_or_else(|_| r"C:\Windows".to_string());
+        PathBuf::from(root).join(r"System32\drivers\etc\hosts")
+    }
+    #[cfg(not(windows))]
+    {
+        PathBuf::from("/etc/hosts")
+    }
+}
+
+/// Rewrite our hosts file section to block `domains` (none lifts the block)
+fn update_hosts(domains: &[String]) {
+    let path = hosts_path();
+    if let Err(e) = update_hosts_file(&path, domains) {
+        tracing::warn!(
+            "Failed to update {} (needs administrator rights): {}",
+            path.display(),
+            e
+        );
+    }
+}
+
+/// Replace the hosts file at `path` with one blocking `domains`. The new
+/// file is written next to it and renamed over it, so a failed write never
+/// leaves a truncated hosts file behind.
+fn update_hosts_file(path: &Path, domains: &[String]) -> io::Result<()> {
+    let contents = fs::read_to_string(path)?;
+    let updated = with_hosts_block(&contents, domains);
+    if updated == contents {
+        return Ok(());
+    }
+
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".pomodorust.tmp");
+    let temp = path.with_file_name(name);
+    let written = (|| {
+        let mut file = File::create(&temp)?;
+        file.write_all(updated.as_bytes())?;
+        file.sync_all()?;
+        fs::set_permissions(&temp, fs::metadata(path)?.permissions())?;
+        fs::rename(&temp, path)
+    })();
+    if written.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    written
+}
+
+/// Whether `domain` is a plain host name, which keeps anything else (spaces,
+/// line breaks, `#`) from ending up as extra hosts file entries
+fn is_valid_domain(domain: &str) -> bool {
+    !domain.is_empty()
+        && domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+}
+
+/// Replace the PomodoRust section of a hosts file
+fn with_hosts_block(contents: &str, domains: &[String]) -> String {
+    // The rest of the file is kept byte for byte, line endings included
+    let mut out = String::with_capacity(contents.len());
+    let mut in_block = false;
+    for line in contents.split_inclusive('\n') {
+        match line.trim() {
+            HOSTS_BEGIN => in_block = true,
+            HOSTS_END => in_block = false,
+            _ if !in_block => out.push_str(line),
+            _ => {}
+        }
+    }
+
+    let domains: Vec<&str> = domains
+        .iter()
+        .map(|d| d.trim().trim_start_matches("www."))
+        .filter(|d| !d.is_empty())
+        .filter(|d| {
+            let valid = is_valid_domain(d);
+            if !valid {
+                tracing::warn!("Not blocking invalid domain: {:?}", d);
+            }
+            valid
+        })
+        .collect();
+    if !domains.is_empty() {
+        let newline = if contents.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        };
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push_str(newline);
+        }
+        let mut push_line = |line: &str| {
+            out.push_str(line);
+            out.push_str(newline);
+        };
+        push_line(HOSTS_BEGIN);
+        for domain in domains {
+            push_line(&format!("0.0.0.0 {}", domain));
+            push_line(&format!("0.0.0.0 www.{}", domain));
+        }
+        push_line(HOSTS_END);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hosts_block_round_trip() {
+        let original = "127.0.0.1 localhost\n";
+        let blocked = with_hosts_block(original, &["www.reddit.com".to_string()]);
+        assert!(blocked.contains("0.0.0.0 reddit.com\n"));
+        assert!(blocked.contains("0.0.0.0 www.reddit.com\n"));
+        assert!(blocked.starts_with(original));
+
+        // Re-blocking replaces the section instead of appending another one
+        let reblocked = with_hosts_block(&blocked, &["youtube.com".to_string()]);
+        assert_eq!(reblocked.matches(HOSTS_BEGIN).count(), 1);
+        assert!(!reblocked.contains("reddit"));
+
+        assert_eq!(with_hosts_block(&reblocked, &[]), original);
+    }
+
+    #[test]
+    fn test_hosts_file_format_is_kept() {
+        // Nothing to remove or add leaves the file as it is
+        let windows = "# hosts\r\n127.0.0.1 localhost";
+        assert_eq!(with_hosts_block(windows, &[]), windows);
+
+        let blocked = with_hosts_block(windows, &["reddit.com".to_string()]);
+        assert_eq!(
+            blocked,
+            format!(
+                "# hosts\r\n127.0.0.1 localhost\r\n{}\r\n0.0.0.0 reddit.com\r\n0.0.0.0 www.reddit.com\r\n{}\r\n",
+                HOSTS_BEGIN, HOSTS_END
+            )
+        );
+        assert_eq!(
+            with_hosts_block(&blocked, &[]),
+            "# hosts\r\n127.0.0.1 localhost\r\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_domains_are_not_blocked() {
+        let domains = [
+            "news.ycombinator.com".to_string(),
+            "evil.com\n1.2.3.4 bank.com".to_string(),
+            "a.com # note".to_string(),
+            "bad_host.com".to_string(),
+        ];
+        let blocked = with_hosts_block("", &domains);
+        assert_eq!(
+            blocked,
+            format!(
+                "{}\n0.0.0.0 news.ycombinator.com\n0.0.0.0 www.news.ycombinator.com\n{}\n",
+                HOSTS_BEGIN, HOSTS_END
+            )
+        );
+        assert!(with_hosts_block("", &domains[1..]).trim().is_empty());
+    }
+
+    #[test]
+    fn test_hosts_file_is_replaced_whole() {
+        let dir = std::env::temp_dir().join(format!("pomodorust-hosts-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("hosts");
+        fs::write(&path, "127.0.0.1 localhost\n").unwrap();
+
+        update_hosts_file(&path, &["reddit.com".to_string()]).unwrap();
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("0.0.0.0 reddit.com\n"));
+        assert!(!dir.join("hosts.pomodorust.tmp").exists());
+
+        update_hosts_file(&path, &[]).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "127.0.0.1 localhost\n");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
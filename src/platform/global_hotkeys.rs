@@ -1,9 +1,16 @@
-//! Global hotkeys support for Linux
+//! Global hotkeys support for Linux and macOS
 //!
-//! Registers system-wide hotkeys using the global-hotkey crate
-//! which supports both X11 and Wayland (via xdg-desktop-portal).
+//! Registers system-wide hotkeys using the global-hotkey crate, which
+//! supports X11, Wayland (via xdg-desktop-portal) and macOS (Carbon hotkeys).
+//!
+//! On macOS the manager has to be created on the main thread and its events
+//! are delivered through the app's run loop, so it is owned by
+//! `HotkeyManager` and only the event polling runs in the background thread.
+//!
+//! The Play/Pause and Next Track media keys can optionally be captured too.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -17,6 +24,29 @@ pub enum HotkeyAction {
     Toggle,
     Skip,
     Reset,
+    Mute,
+}
+
+/// Media key bindings: Play/Pause toggles the timer, Next skips the session
+fn media_hotkeys() -> [(HotKey, HotkeyAction); 2] {
+    [
+        (
+            HotKey::new(None, Code::MediaPlayPause),
+            HotkeyAction::Toggle,
+        ),
+        (HotKey::new(None, Code::MediaTrackNext), HotkeyAction::Skip),
+    ]
+}
+
+/// Register or unregister the media key bindings
+fn register_media_keys(manager: &GlobalHotKeyManager, register: bool) {
+    for (hotkey, _) in media_hotkeys() {
+        if !register {
+            let _ = manager.unregister(hotkey);
+        } else if let Err(e) = manager.register(hotkey) {
+            tracing::warn!("Failed to register media key {:?}: {}", hotkey.key, e);
+        }
+    }
 }
 
 /// Parse a hotkey string like "Ctrl+Alt+Space" into a HotKey
@@ -32,14 +62,20 @@ fn parse_hotkey(hotkey_str: &str) -> Option<HotKey> {
     for part in parts {
         match part.to_uppercase().as_str() {
             "CTRL" | "CONTROL" => modifiers |= Modifiers::CONTROL,
-            "ALT" => modifiers |= Modifiers::ALT,
+            "ALT" | "OPTION" => modifiers |= Modifiers::ALT,
             "SHIFT" => modifiers |= Modifiers::SHIFT,
-            "WIN" | "SUPER" | "META" => modifiers |= Modifiers::SUPER,
+            "WIN" | "SUPER" | "META" | "CMD" | "COMMAND" => modifiers |= Modifiers::SUPER,
             "SPACE" => key_code = Some(Code::Space),
             "ENTER" | "RETURN" => key_code = Some(Code::Enter),
             "ESC" | "ESCAPE" => key_code = Some(Code::Escape),
             "TAB" => key_code = Some(Code::Tab),
             "BACKSPACE" => key_code = Some(Code::Backspace),
+            "MEDIAPLAYPAUSE" | "PLAYPAUSE" => key_code = Some(Code::MediaPlayPause),
+            "MEDIANEXT" | "MEDIATRACKNEXT" | "NEXTTRACK" => key_code = Some(Code::MediaTrackNext),
+            "MEDIAPREV" | "MEDIATRACKPREVIOUS" | "PREVTRACK" => {
+                key_code = Some(Code::MediaTrackPrevious)
+            }
+            "MEDIASTOP" => key_code = Some(Code::MediaStop),
             // Single letter keys (A-Z)
             s if s.len() == 1 && s.chars().next().unwrap().is_ascii_alphabetic() => {
                 let c = s.chars().next().unwrap().to_ascii_uppercase();
@@ -119,6 +155,13 @@ fn parse_hotkey(hotkey_str: &str) -> Option<HotKey> {
     key_code.map(|code| HotKey::new(Some(modifiers), code))
 }
 
+/// Note a hotkey that couldn't be registered, for the app to report
+fn record_failure(failures: &Mutex<Vec<String>>, failure: String) {
+    if let Ok(mut failures) = failures.lock() {
+        failures.push(failure);
+    }
+}
+
 /// Global hotkey manager
 pub struct HotkeyManager {
     /// Channel to receive hotkey events
@@ -131,6 +174,16 @@ pub struct HotkeyManager {
     running: Arc<Mutex<bool>>,
     /// Hotkey configuration to register
     hotkey_config: Arc<Mutex<Vec<(HotkeyAction, String)>>>,
+    /// Whether the media keys should be captured
+    media_keys: Arc<AtomicBool>,
+    /// Hotkeys that couldn't be registered since the last start
+    failures: Arc<Mutex<Vec<String>>>,
+    /// Main-thread manager that owns the registrations (macOS only)
+    #[cfg(target_os = "macos")]
+    manager: Option<GlobalHotKeyManager>,
+    /// Whether the media keys are registered with `manager` (macOS only)
+    #[cfg(target_os = "macos")]
+    media_registered: AtomicBool,
 }
 
 impl HotkeyManager {
@@ -143,6 +196,26 @@ impl HotkeyManager {
             thread_handle: None,
             running: Arc::new(Mutex::new(false)),
             hotkey_config: Arc::new(Mutex::new(Vec::new())),
+            media_keys: Arc::new(AtomicBool::new(false)),
+            failures: Arc::new(Mutex::new(Vec::new())),
+            #[cfg(target_os = "macos")]
+            manager: None,
+            #[cfg(target_os = "macos")]
+            media_registered: AtomicBool::new(false),
+        }
+    }
+
+    /// Capture Play/Pause (toggles the timer) and Next Track (skips the
+    /// session). Takes effect while the manager is running.
+    pub fn set_media_keys(&self, enabled: bool) {
+        self.media_keys.store(enabled, Ordering::Relaxed);
+
+        // The background thread can't touch the main-thread manager
+        #[cfg(target_os = "macos")]
+        if let Some(ref manager) = self.manager {
+            if self.media_registered.swap(enabled, Ordering::Relaxed) != enabled {
+                register_media_keys(manager, enabled);
+            }
         }
     }
 
@@ -151,8 +224,16 @@ impl HotkeyManager {
         self.event_rx.take()
     }
 
+    /// Hotkeys that failed to register since this was last asked
+    pub fn take_failures(&self) -> Vec<String> {
+        self.failures
+            .lock()
+            .map(|mut failures| std::mem::take(&mut *failures))
+            .unwrap_or_default()
+    }
+
     /// Register hotkeys and start listening
-    pub fn start(&mut self, toggle: &str, skip: &str, reset: &str) {
+    pub fn start(&mut self, toggle: &str, skip: &str, reset: &str, mute: &str) {
         // Store hotkey configuration
         {
             let mut config = self.hotkey_config.lock().unwrap();
@@ -160,11 +241,18 @@ impl HotkeyManager {
             config.push((HotkeyAction::Toggle, toggle.to_string()));
             config.push((HotkeyAction::Skip, skip.to_string()));
             config.push((HotkeyAction::Reset, reset.to_string()));
+            config.push((HotkeyAction::Mute, mute.to_string()));
+        }
+        if let Ok(mut failures) = self.failures.lock() {
+            failures.clear();
         }
 
         let event_tx = self.event_tx.clone();
         let running = self.running.clone();
         let hotkey_config = self.hotkey_config.clone();
+        let failures = self.failures.clone();
+        #[cfg(not(target_os = "macos"))]
+        let media_keys = self.media_keys.clone();
 
         // Mark as running
         {
@@ -173,10 +261,29 @@ impl HotkeyManager {
         }
 
         // Start hotkey listener thread
+        #[cfg(not(target_os = "macos"))]
         let handle = thread::spawn(move || {
-            Self::hotkey_loop(event_tx, running, hotkey_config);
+            Self::hotkey_loop(event_tx, running, hotkey_config, media_keys, failures);
         });
 
+        #[cfg(target_os = "macos")]
+        let handle = {
+            let manager = match GlobalHotKeyManager::new() {
+                Ok(m) => m,
+                Err(e) => {
+                    tracing::error!("Failed to create GlobalHotKeyManager: {}", e);
+                    *self.running.lock().unwrap() = false;
+                    record_failure(&failures, e.to_string());
+                    return;
+                }
+            };
+            let hotkey_map = Self::register_hotkeys(&manager, &hotkey_config, &failures);
+            self.manager = Some(manager);
+            self.media_registered.store(false, Ordering::Relaxed);
+            self.set_media_keys(self.media_keys.load(Ordering::Relaxed));
+            thread::spawn(move || Self::poll_events(event_tx, running, hotkey_map, || {}))
+        };
+
         self.thread_handle = Some(handle);
         tracing::info!("Hotkey manager started");
     }
@@ -192,49 +299,95 @@ impl HotkeyManager {
             let _ = handle.join();
         }
 
+        // Dropping the manager unregisters its hotkeys
+        #[cfg(target_os = "macos")]
+        {
+            self.manager = None;
+        }
+
         tracing::info!("Hotkey manager stopped");
     }
 
     /// The main hotkey listening loop (runs in a separate thread)
+    #[cfg(not(target_os = "macos"))]
     fn hotkey_loop(
         event_tx: Sender<HotkeyAction>,
         running: Arc<Mutex<bool>>,
         hotkey_config: Arc<Mutex<Vec<(HotkeyAction, String)>>>,
+        media_keys: Arc<AtomicBool>,
+        failures: Arc<Mutex<Vec<String>>>,
     ) {
         // Create the hotkey manager (must be done in the thread that will process events)
         let manager = match GlobalHotKeyManager::new() {
             Ok(m) => m,
             Err(e) => {
                 tracing::error!("Failed to create GlobalHotKeyManager: {}", e);
+                record_failure(&failures, e.to_string());
                 return;
             }
         };
 
-        // Parse and register hotkeys
+        let hotkey_map = Self::register_hotkeys(&manager, &hotkey_config, &failures);
+        let mut media_registered = false;
+        Self::poll_events(event_tx, running, hotkey_map, || {
+            let want_media = media_keys.load(Ordering::Relaxed);
+            if want_media != media_registered {
+                register_media_keys(&manager, want_media);
+                media_registered = want_media;
+            }
+        });
+
+        // Hotkeys are unregistered when the manager is dropped
+        drop(manager);
+    }
+
+    /// Parse and register the configured hotkeys
+    fn register_hotkeys(
+        manager: &GlobalHotKeyManager,
+        hotkey_config: &Mutex<Vec<(HotkeyAction, String)>>,
+        failures: &Mutex<Vec<String>>,
+    ) -> HashMap<u32, HotkeyAction> {
         let mut hotkey_map: HashMap<u32, HotkeyAction> = HashMap::new();
-        {
-            let config = hotkey_config.lock().unwrap();
-            for (action, hotkey_str) in config.iter() {
-                if let Some(hotkey) = parse_hotkey(hotkey_str) {
-                    match manager.register(hotkey) {
-                        Ok(()) => {
-                            hotkey_map.insert(hotkey.id(), *action);
-                            tracing::info!("Registered hotkey for {:?}: {}", action, hotkey_str);
-                        }
-                        Err(e) => {
-                            tracing::warn!(
-                                "Failed to register hotkey for {:?} ({}): {}",
-                                action,
-                                hotkey_str,
-                                e
-                            );
-                        }
+        let config = hotkey_config.lock().unwrap();
+        for (action, hotkey_str) in config.iter() {
+            if let Some(hotkey) = parse_hotkey(hotkey_str) {
+                match manager.register(hotkey) {
+                    Ok(()) => {
+                        hotkey_map.insert(hotkey.id(), *action);
+                        tracing::info!("Registered hotkey for {:?}: {}", action, hotkey_str);
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to register hotkey for {:?} ({}): {}",
+                            action,
+                            hotkey_str,
+                            e
+                        );
+                        record_failure(failures, format!("{}: {}", hotkey_str, e));
                     }
-                } else {
-                    tracing::warn!("Failed to parse hotkey: {}", hotkey_str);
+                }
+            } else {
+                tracing::warn!("Failed to parse hotkey: {}", hotkey_str);
+                if !hotkey_str.trim().is_empty() {
+                    record_failure(failures, format!("{} (not a valid hotkey)", hotkey_str));
                 }
             }
         }
+        hotkey_map
+    }
+
+    /// Forward hotkey events to the app until stopped, calling `tick`
+    /// between polls
+    fn poll_events(
+        event_tx: Sender<HotkeyAction>,
+        running: Arc<Mutex<bool>>,
+        mut hotkey_map: HashMap<u32, HotkeyAction>,
+        mut tick: impl FnMut(),
+    ) {
+        // Media key events only arrive while they are registered
+        for (hotkey, action) in media_hotkeys() {
+            hotkey_map.entry(hotkey.id()).or_insert(action);
+        }
 
         // Event receiver from global-hotkey crate
         let receiver = GlobalHotKeyEvent::receiver();
@@ -250,6 +403,8 @@ impl HotkeyManager {
                 }
             }
 
+            tick();
+
             // Check for hotkey events with timeout
             if let Ok(event) = receiver.try_recv() {
                 if let Some(action) = hotkey_map.get(&event.id) {
@@ -262,13 +417,6 @@ impl HotkeyManager {
             thread::sleep(std::time::Duration::from_millis(50));
         }
 
-        // Unregister hotkeys
-        for (id, _) in hotkey_map.iter() {
-            // We need to reconstruct the HotKey to unregister, but since we don't store them,
-            // just let them be cleaned up when the manager is dropped
-            let _ = id;
-        }
-
         tracing::info!("Hotkey loop ended");
     }
 
@@ -1,8 +1,10 @@
 //! Global hotkeys support for Windows
 //!
 //! Registers system-wide hotkeys and sends events to the app via a channel.
+//! Optionally also captures the Play/Pause and Next Track media keys.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -10,7 +12,8 @@ use std::thread;
 use windows::Win32::Foundation::HWND;
 use windows::Win32::UI::Input::KeyboardAndMouse::{
     RegisterHotKey, UnregisterHotKey, HOT_KEY_MODIFIERS, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT,
-    MOD_SHIFT, MOD_WIN, VIRTUAL_KEY, VK_ESCAPE, VK_RETURN, VK_SPACE,
+    MOD_SHIFT, MOD_WIN, VIRTUAL_KEY, VK_ESCAPE, VK_MEDIA_NEXT_TRACK, VK_MEDIA_PLAY_PAUSE,
+    VK_MEDIA_PREV_TRACK, VK_MEDIA_STOP, VK_RETURN, VK_SPACE,
 };
 use windows::Win32::UI::WindowsAndMessaging::{DispatchMessageW, TranslateMessage, MSG, WM_HOTKEY};
 
@@ -20,6 +23,7 @@ pub enum HotkeyAction {
     Toggle,
     Skip,
     Reset,
+    Mute,
 }
 
 impl HotkeyAction {
@@ -29,6 +33,7 @@ impl HotkeyAction {
             HotkeyAction::Toggle => 1,
             HotkeyAction::Skip => 2,
             HotkeyAction::Reset => 3,
+            HotkeyAction::Mute => 4,
         }
     }
 
@@ -38,11 +43,24 @@ impl HotkeyAction {
             1 => Some(HotkeyAction::Toggle),
             2 => Some(HotkeyAction::Skip),
             3 => Some(HotkeyAction::Reset),
+            4 => Some(HotkeyAction::Mute),
+            MEDIA_PLAY_PAUSE_ID => Some(HotkeyAction::Toggle),
+            MEDIA_NEXT_ID => Some(HotkeyAction::Skip),
             _ => None,
         }
     }
 }
 
+/// Hotkey IDs of the media key bindings
+const MEDIA_PLAY_PAUSE_ID: i32 = 10;
+const MEDIA_NEXT_ID: i32 = 11;
+
+/// Media keys and the IDs they are registered under
+const MEDIA_KEYS: [(i32, VIRTUAL_KEY); 2] = [
+    (MEDIA_PLAY_PAUSE_ID, VK_MEDIA_PLAY_PAUSE),
+    (MEDIA_NEXT_ID, VK_MEDIA_NEXT_TRACK),
+];
+
 /// Parse a hotkey string like "Ctrl+Alt+Space" into modifiers and key
 fn parse_hotkey(hotkey: &str) -> Option<(HOT_KEY_MODIFIERS, VIRTUAL_KEY)> {
     let parts: Vec<&str> = hotkey.split('+').map(|s| s.trim()).collect();
@@ -62,6 +80,10 @@ fn parse_hotkey(hotkey: &str) -> Option<(HOT_KEY_MODIFIERS, VIRTUAL_KEY)> {
             "SPACE" => key = Some(VK_SPACE),
             "ENTER" | "RETURN" => key = Some(VK_RETURN),
             "ESC" | "ESCAPE" => key = Some(VK_ESCAPE),
+            "MEDIAPLAYPAUSE" | "PLAYPAUSE" => key = Some(VK_MEDIA_PLAY_PAUSE),
+            "MEDIANEXT" | "MEDIATRACKNEXT" | "NEXTTRACK" => key = Some(VK_MEDIA_NEXT_TRACK),
+            "MEDIAPREV" | "MEDIATRACKPREVIOUS" | "PREVTRACK" => key = Some(VK_MEDIA_PREV_TRACK),
+            "MEDIASTOP" => key = Some(VK_MEDIA_STOP),
             // Single letter keys (A-Z)
             s if s.len() == 1 && s.chars().next().unwrap().is_ascii_alphabetic() => {
                 let c = s.chars().next().unwrap().to_ascii_uppercase();
@@ -102,6 +124,10 @@ pub struct HotkeyManager {
     running: Arc<Mutex<bool>>,
     /// Registered hotkeys
     registered: Arc<Mutex<HashMap<HotkeyAction, (HOT_KEY_MODIFIERS, VIRTUAL_KEY)>>>,
+    /// Whether the media keys should be captured
+    media_keys: Arc<AtomicBool>,
+    /// Hotkeys that couldn't be registered since the last start
+    failures: Arc<Mutex<Vec<String>>>,
 }
 
 impl HotkeyManager {
@@ -114,29 +140,56 @@ impl HotkeyManager {
             thread_handle: None,
             running: Arc::new(Mutex::new(false)),
             registered: Arc::new(Mutex::new(HashMap::new())),
+            media_keys: Arc::new(AtomicBool::new(false)),
+            failures: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
+    /// Capture Play/Pause (toggles the timer) and Next Track (skips the
+    /// session). Takes effect while the manager is running.
+    pub fn set_media_keys(&self, enabled: bool) {
+        self.media_keys.store(enabled, Ordering::Relaxed);
+    }
+
     /// Take the event receiver (can only be called once)
     pub fn take_receiver(&mut self) -> Option<Receiver<HotkeyAction>> {
         self.event_rx.take()
     }
 
+    /// Hotkeys that failed to register since this was last asked
+    pub fn take_failures(&self) -> Vec<String> {
+        self.failures
+            .lock()
+            .map(|mut failures| std::mem::take(&mut *failures))
+            .unwrap_or_default()
+    }
+
     /// Register hotkeys and start listening
-    pub fn start(&mut self, toggle: &str, skip: &str, reset: &str) {
+    pub fn start(&mut self, toggle: &str, skip: &str, reset: &str, mute: &str) {
         // Parse hotkeys
         let hotkeys: Vec<(HotkeyAction, Option<(HOT_KEY_MODIFIERS, VIRTUAL_KEY)>)> = vec![
             (HotkeyAction::Toggle, parse_hotkey(toggle)),
             (HotkeyAction::Skip, parse_hotkey(skip)),
             (HotkeyAction::Reset, parse_hotkey(reset)),
+            (HotkeyAction::Mute, parse_hotkey(mute)),
         ];
 
-        // Store valid hotkeys
+        // Store valid hotkeys, replacing those of a previous start
         {
             let mut registered = self.registered.lock().unwrap();
-            for (action, parsed) in &hotkeys {
-                if let Some((mods, key)) = parsed {
-                    registered.insert(*action, (*mods, *key));
+            let mut failures = self.failures.lock().unwrap();
+            registered.clear();
+            failures.clear();
+            let texts = [toggle, skip, reset, mute];
+            for ((action, parsed), text) in hotkeys.iter().zip(texts) {
+                match parsed {
+                    Some((mods, key)) => {
+                        registered.insert(*action, (*mods, *key));
+                    }
+                    None if !text.trim().is_empty() => {
+                        failures.push(format!("{} (not a valid hotkey)", text));
+                    }
+                    None => {}
                 }
             }
         }
@@ -144,6 +197,8 @@ impl HotkeyManager {
         let event_tx = self.event_tx.clone();
         let running = self.running.clone();
         let registered = self.registered.clone();
+        let media_keys = self.media_keys.clone();
+        let failures = self.failures.clone();
 
         // Mark as running
         {
@@ -153,7 +208,7 @@ impl HotkeyManager {
 
         // Start hotkey listener thread
         let handle = thread::spawn(move || {
-            Self::hotkey_loop(event_tx, running, registered);
+            Self::hotkey_loop(event_tx, running, registered, media_keys, failures);
         });
 
         self.thread_handle = Some(handle);
@@ -183,6 +238,8 @@ impl HotkeyManager {
         event_tx: Sender<HotkeyAction>,
         running: Arc<Mutex<bool>>,
         registered: Arc<Mutex<HashMap<HotkeyAction, (HOT_KEY_MODIFIERS, VIRTUAL_KEY)>>>,
+        media_keys: Arc<AtomicBool>,
+        failures: Arc<Mutex<Vec<String>>>,
     ) {
         // Register all hotkeys
         {
@@ -190,12 +247,11 @@ impl HotkeyManager {
             for (action, (mods, key)) in reg.iter() {
                 unsafe {
                     let result = RegisterHotKey(HWND::default(), action.id(), *mods, key.0 as u32);
-                    if result.is_err() {
-                        tracing::warn!(
-                            "Failed to register hotkey for {:?}: {:?}",
-                            action,
-                            result.err()
-                        );
+                    if let Err(e) = result {
+                        tracing::warn!("Failed to register hotkey for {:?}: {:?}", action, e);
+                        if let Ok(mut failures) = failures.lock() {
+                            failures.push(format!("{:?}: {}", action, e));
+                        }
                     } else {
                         tracing::info!("Registered hotkey for {:?}", action);
                     }
@@ -205,6 +261,7 @@ impl HotkeyManager {
 
         // Message loop
         let mut msg = MSG::default();
+        let mut media_registered = false;
         loop {
             // Check if we should stop
             {
@@ -215,6 +272,14 @@ impl HotkeyManager {
                 }
             }
 
+            // Hotkeys belong to the thread that registered them, so media
+            // keys are (un)registered here when the setting changes
+            let want_media = media_keys.load(Ordering::Relaxed);
+            if want_media != media_registered {
+                Self::register_media_keys(want_media);
+                media_registered = want_media;
+            }
+
             unsafe {
                 // GetMessageW blocks until a message is available
                 // We use a timeout approach by checking running flag periodically
@@ -240,6 +305,9 @@ impl HotkeyManager {
         }
 
         // Unregister all hotkeys
+        if media_registered {
+            Self::register_media_keys(false);
+        }
         {
             let reg = registered.lock().unwrap();
             for (action, _) in reg.iter() {
@@ -252,6 +320,21 @@ impl HotkeyManager {
         tracing::info!("Hotkey loop ended");
     }
 
+    /// Register or unregister the media key bindings on the current thread
+    fn register_media_keys(register: bool) {
+        for (id, key) in MEDIA_KEYS {
+            unsafe {
+                if !register {
+                    let _ = UnregisterHotKey(HWND::default(), id);
+                } else if let Err(e) =
+                    RegisterHotKey(HWND::default(), id, MOD_NOREPEAT, key.0 as u32)
+                {
+                    tracing::warn!("Failed to register media key {:?}: {:?}", key, e);
+                }
+            }
+        }
+    }
+
     /// Check if manager is running
     pub fn is_running(&self) -> bool {
         self.running.lock().map(|r| *r).unwrap_or(false)
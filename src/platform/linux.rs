@@ -1,15 +1,21 @@
 //! Linux-specific functionality
 //!
 //! Provides Linux-specific features including:
-//! - Desktop notifications via D-Bus (notify-rust)
+//! - Desktop notifications via D-Bus (notify-rust), optionally with buttons
 //! - Autostart via XDG Desktop Entry specification
 //! - Window effects (no-op on Linux)
+//! - Do Not Disturb during focus sessions (GNOME banners setting, or a
+//!   notification inhibition on KDE Plasma and other servers supporting it)
 
+use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Mutex;
 
+use super::NotificationAction;
 use crate::error::PlatformError;
 
 /// Show a desktop notification using D-Bus
@@ -25,6 +31,41 @@ pub fn show_notification(title: &str, body: &str) {
     }
 }
 
+/// Show a desktop notification with buttons.
+/// `on_action` is called from a background thread when a button is clicked.
+pub fn show_actionable_notification(
+    title: &str,
+    body: &str,
+    actions: &[(NotificationAction, &str)],
+    on_action: impl Fn(NotificationAction) + Send + 'static,
+) {
+    let mut notification = notify_rust::Notification::new();
+    notification
+        .summary(title)
+        .body(body)
+        .appname("PomodoRust")
+        .timeout(notify_rust::Timeout::Default);
+    for (action, label) in actions {
+        notification.action(action.id(), label);
+    }
+
+    match notification.show() {
+        Ok(handle) => {
+            // Blocks until the notification is clicked or dismissed
+            let _ = std::thread::Builder::new()
+                .name("notification".into())
+                .spawn(move || {
+                    handle.wait_for_action(|id| {
+                        if let Some(action) = NotificationAction::from_id(id) {
+                            on_action(action);
+                        }
+                    });
+                });
+        }
+        Err(e) => tracing::error!("Failed to show notification: {}", e),
+    }
+}
+
 /// Get the XDG autostart directory path
 fn get_autostart_dir() -> Option<PathBuf> {
     env::var_os("XDG_CONFIG_HOME")
@@ -66,7 +107,7 @@ pub fn set_autostart(enabled: bool) -> Result<(), PlatformError> {
 
         // Create autostart directory if it doesn't exist
         if !autostart_dir.exists() {
-            fs::create_dir_all(&autostart_dir).map_err(|e| PlatformError::Notification {
+            fs::create_dir_all(&autostart_dir).map_err(|e| PlatformError::Autostart {
                 message: format!("Failed to create autostart directory: {}", e),
             })?;
         }
@@ -81,12 +122,12 @@ pub fn set_autostart(enabled: bool) -> Result<(), PlatformError> {
         })?;
 
         let mut file =
-            fs::File::create(&desktop_file_path).map_err(|e| PlatformError::Notification {
+            fs::File::create(&desktop_file_path).map_err(|e| PlatformError::Autostart {
                 message: format!("Failed to create desktop file: {}", e),
             })?;
 
         file.write_all(desktop_content.as_bytes())
-            .map_err(|e| PlatformError::Notification {
+            .map_err(|e| PlatformError::Autostart {
                 message: format!("Failed to write desktop file: {}", e),
             })?;
 
@@ -102,7 +143,7 @@ pub fn set_autostart(enabled: bool) -> Result<(), PlatformError> {
 pub fn remove_autostart() -> Result<(), PlatformError> {
     if let Some(desktop_file_path) = get_desktop_file_path() {
         if desktop_file_path.exists() {
-            fs::remove_file(&desktop_file_path).map_err(|e| PlatformError::Notification {
+            fs::remove_file(&desktop_file_path).map_err(|e| PlatformError::Autostart {
                 message: format!("Failed to remove desktop file: {}", e),
             })?;
             tracing::info!("Disabled autostart: {:?}", desktop_file_path);
@@ -145,12 +186,47 @@ pub fn show_pomodorust_window() -> bool {
     false
 }
 
+/// Read the freedesktop `color-scheme` setting through the settings portal
+/// Returns 0 (no preference), 1 (prefer dark) or 2 (prefer light)
+fn portal_color_scheme() -> Option<u32> {
+    use zbus::zvariant::{OwnedValue, Value};
+
+    let connection = zbus::blocking::Connection::session().ok()?;
+    let reply = connection
+        .call_method(
+            Some("org.freedesktop.portal.Desktop"),
+            "/org/freedesktop/portal/desktop",
+            Some("org.freedesktop.portal.Settings"),
+            "Read",
+            &("org.freedesktop.appearance", "color-scheme"),
+        )
+        .ok()?;
+    let value: OwnedValue = reply.body().deserialize().ok()?;
+
+    // `Read` wraps the setting in an extra variant
+    match &*value {
+        Value::Value(inner) => match **inner {
+            Value::U32(scheme) => Some(scheme),
+            _ => None,
+        },
+        Value::U32(scheme) => Some(*scheme),
+        _ => None,
+    }
+}
+
 /// Check if the system is using light theme
-/// Detects via gsettings for GNOME/GTK-based environments
+/// Uses the desktop portal's `prefers-color-scheme` setting, falling back to
+/// gsettings for GNOME/GTK-based environments without the portal
 /// Returns true if light theme is detected, false otherwise (defaults to dark)
 pub fn system_uses_light_theme() -> bool {
     use std::process::Command;
 
+    match portal_color_scheme() {
+        Some(1) => return false,
+        Some(2) => return true,
+        _ => {}
+    }
+
     // Try GNOME color-scheme (GNOME 42+)
     if let Ok(output) = Command::new("gsettings")
         .args(["get", "org.gnome.desktop.interface", "color-scheme"])
@@ -184,3 +260,101 @@ pub fn system_uses_light_theme() -> bool {
     // Default to dark theme if detection fails
     false
 }
+
+/// GNOME schema holding the "Do Not Disturb" switch (`show-banners`)
+const GNOME_NOTIFICATIONS_SCHEMA: &str = "org.gnome.desktop.notifications";
+
+/// How to undo turning Do Not Disturb on
+enum DndRestore {
+    /// GNOME: the previous `show-banners` value
+    Banners(bool),
+    /// Notification server inhibition, dropped along with the connection
+    Inhibition(zbus::blocking::Connection, u32),
+}
+
+/// Set while we have Do Not Disturb turned on
+static DND_RESTORE: Mutex<Option<DndRestore>> = Mutex::new(None);
+
+/// Turn Do Not Disturb on, or restore the state from before it was turned on
+pub fn set_do_not_disturb(active: bool) {
+    let mut restore = DND_RESTORE.lock().unwrap_or_else(|e| e.into_inner());
+
+    if active {
+        if restore.is_none() {
+            *restore = enable_do_not_disturb();
+        }
+    } else if let Some(previous) = restore.take() {
+        match previous {
+            DndRestore::Banners(true) => set_gnome_banners(true),
+            DndRestore::Banners(false) => {}
+            DndRestore::Inhibition(connection, cookie) => {
+                if let Err(e) = call_notifications(&connection, "UnInhibit", &(cookie,)) {
+                    tracing::warn!("Failed to release notification inhibition: {}", e);
+                }
+            }
+        }
+    }
+}
+
+fn enable_do_not_disturb() -> Option<DndRestore> {
+    let desktop = env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
+    if desktop.to_uppercase().contains("GNOME") {
+        let output = Command::new("gsettings")
+            .args(["get", GNOME_NOTIFICATIONS_SCHEMA, "show-banners"])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())?;
+        let show_banners = String::from_utf8_lossy(&output.stdout).trim() == "true";
+        if show_banners {
+            set_gnome_banners(false);
+        }
+        return Some(DndRestore::Banners(show_banners));
+    }
+
+    // KDE Plasma: inhibitions are tied to the D-Bus connection that made them
+    let inhibit = || -> zbus::Result<DndRestore> {
+        let connection = zbus::blocking::Connection::session()?;
+        let hints: HashMap<&str, zbus::zvariant::Value> = HashMap::new();
+        let reply = call_notifications(
+            &connection,
+            "Inhibit",
+            &("pomodorust", "Focus session", hints),
+        )?;
+        let cookie: u32 = reply.body().deserialize()?;
+        Ok(DndRestore::Inhibition(connection, cookie))
+    };
+    match inhibit() {
+        Ok(restore) => Some(restore),
+        Err(e) => {
+            tracing::warn!("Failed to enable Do Not Disturb: {}", e);
+            None
+        }
+    }
+}
+
+fn set_gnome_banners(show: bool) {
+    let value = if show { "true" } else { "false" };
+    let status = Command::new("gsettings")
+        .args(["set", GNOME_NOTIFICATIONS_SCHEMA, "show-banners", value])
+        .status();
+    if !status.is_ok_and(|s| s.success()) {
+        tracing::warn!("Failed to set GNOME notification banners");
+    }
+}
+
+fn call_notifications<B>(
+    connection: &zbus::blocking::Connection,
+    method: &str,
+    body: &B,
+) -> zbus::Result<zbus::Message>
+where
+    B: serde::Serialize + zbus::zvariant::DynamicType,
+{
+    connection.call_method(
+        Some("org.freedesktop.Notifications"),
+        "/org/freedesktop/Notifications",
+        Some("org.freedesktop.Notifications"),
+        method,
+        body,
+    )
+}
@@ -0,0 +1,191 @@
+//! macOS-specific functionality
+//!
+//! Provides macOS-specific features including:
+//! - Notifications via Notification Center (`osascript`)
+//! - Autostart via a LaunchAgent in `~/Library/LaunchAgents`
+//! - Bringing the window to the front when a second instance starts
+//! - Light/dark appearance detection
+//!
+//! Dock attention (bouncing the icon) is requested through the egui viewport,
+//! see `PomodoRustApp::on_timer_completed`.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::error::PlatformError;
+
+/// LaunchAgent label, also used as the plist file name
+const LAUNCH_AGENT_LABEL: &str = "com.gerrux.pomodorust";
+
+/// Quote a string for use inside an AppleScript string literal
+fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Run an AppleScript snippet, returning whether it succeeded
+fn run_osascript(script: &str) -> bool {
+    Command::new("osascript")
+        .arg("-e")
+        .arg(script)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Show a notification in Notification Center
+pub fn show_notification(title: &str, body: &str) {
+    let script = format!(
+        "display notification {} with title {}",
+        applescript_string(body),
+        applescript_string(title)
+    );
+    // osascript takes a moment to start; don't block the UI on it
+    std::thread::spawn(move || {
+        if !run_osascript(&script) {
+            tracing::error!("Failed to show notification");
+        }
+    });
+}
+
+/// Get the path to the LaunchAgent plist
+fn get_launch_agent_path() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| {
+        PathBuf::from(home)
+            .join("Library")
+            .join("LaunchAgents")
+            .join(format!("{}.plist", LAUNCH_AGENT_LABEL))
+    })
+}
+
+/// Escape a string for a plist XML value
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Create the LaunchAgent plist content
+fn create_launch_agent(exec_path: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{}</string>
+    </array>
+    <key>RunAtLoad</key>
+    <true/>
+    <key>ProcessType</key>
+    <string>Interactive</string>
+</dict>
+</plist>
+"#,
+        LAUNCH_AGENT_LABEL,
+        xml_escape(exec_path)
+    )
+}
+
+/// Set application to start on login
+pub fn set_autostart(enabled: bool) -> Result<(), PlatformError> {
+    if !enabled {
+        return remove_autostart();
+    }
+
+    let plist_path = get_launch_agent_path().ok_or(PlatformError::Unsupported {
+        feature: "autostart (HOME not set)",
+    })?;
+
+    if let Some(dir) = plist_path.parent() {
+        fs::create_dir_all(dir).map_err(|e| PlatformError::Autostart {
+            message: format!("Failed to create LaunchAgents directory: {}", e),
+        })?;
+    }
+
+    let exe_path = env::current_exe().map_err(|e| PlatformError::ExecutablePath { source: e })?;
+
+    fs::write(
+        &plist_path,
+        create_launch_agent(&exe_path.to_string_lossy()),
+    )
+    .map_err(|e| PlatformError::Autostart {
+        message: format!("Failed to write LaunchAgent: {}", e),
+    })?;
+
+    tracing::info!("Enabled autostart: {:?}", plist_path);
+    Ok(())
+}
+
+/// Remove application from login startup
+pub fn remove_autostart() -> Result<(), PlatformError> {
+    if let Some(plist_path) = get_launch_agent_path() {
+        if plist_path.exists() {
+            fs::remove_file(&plist_path).map_err(|e| PlatformError::Autostart {
+                message: format!("Failed to remove LaunchAgent: {}", e),
+            })?;
+            tracing::info!("Disabled autostart: {:?}", plist_path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply window effects (no-op on macOS)
+/// The window server already draws rounded corners and shadows
+pub fn apply_window_effects(_hwnd: isize) {
+    // No-op on macOS
+}
+
+/// Flash the window in taskbar (no-op on macOS)
+pub fn flash_window(_hwnd: isize, _count: u32) {
+    // No-op on macOS, the dock icon bounce is requested via the viewport
+}
+
+/// Stop flashing the window (no-op on macOS)
+pub fn stop_flash_window(_hwnd: isize) {
+    // No-op on macOS
+}
+
+/// Flash the PomodoRust window by finding it by title (no-op on macOS)
+/// Returns false; the app bounces the dock icon through the viewport instead
+pub fn flash_pomodorust_window(_count: u32) -> bool {
+    false
+}
+
+/// Show and bring the PomodoRust window to foreground
+/// Activates the running instance through System Events
+pub fn show_pomodorust_window() -> bool {
+    let name = env::current_exe()
+        .ok()
+        .and_then(|exe| exe.file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "pomodorust".to_string());
+
+    let script = format!(
+        "tell application \"System Events\" to set frontmost of every process whose name is {} to true",
+        applescript_string(&name)
+    );
+    run_osascript(&script)
+}
+
+/// Check if the system is using light theme
+/// `AppleInterfaceStyle` is only set (to "Dark") in dark mode
+pub fn system_uses_light_theme() -> bool {
+    match Command::new("defaults")
+        .args(["read", "-g", "AppleInterfaceStyle"])
+        .output()
+    {
+        Ok(output) => !String::from_utf8_lossy(&output.stdout)
+            .to_lowercase()
+            .contains("dark"),
+        // Default to dark theme if detection fails
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,264 @@
+//! Pausing and resuming media players, and watching whether any is playing
+//!
+//! - Windows: system media transport controls
+//!   (`GlobalSystemMediaTransportControlsSessionManager`)
+//! - Linux: every MPRIS player on the session bus
+//!
+//! Only players that were playing get paused, and only those are resumed
+//! later. Backends can block (D-Bus round trips, WinRT async calls), so the
+//! work happens on a background thread.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// How often playback is checked while watching
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+enum MediaCommand {
+    Pause,
+    Resume,
+    Watch(bool),
+}
+
+/// Pauses playing media and resumes it later
+pub struct MediaController {
+    tx: Sender<MediaCommand>,
+    playing: Arc<AtomicBool>,
+}
+
+impl MediaController {
+    /// Spawn the worker thread
+    pub fn start() -> Self {
+        let (tx, rx) = mpsc::channel();
+        let playing = Arc::new(AtomicBool::new(false));
+
+        let is_playing = playing.clone();
+        let spawned = thread::Builder::new().name("media".into()).spawn(move || {
+            // Players we paused, by bus name / app id
+            let mut paused: Vec<String> = Vec::new();
+            let mut watching = false;
+            loop {
+                let timeout = if watching {
+                    WATCH_INTERVAL
+                } else {
+                    Duration::MAX
+                };
+                match rx.recv_timeout(timeout) {
+                    Ok(MediaCommand::Pause) => {
+                        paused.extend(pause_playing());
+                        tracing::debug!("Paused media players: {:?}", paused);
+                    }
+                    Ok(MediaCommand::Resume) if !paused.is_empty() => {
+                        resume(&std::mem::take(&mut paused));
+                    }
+                    Ok(MediaCommand::Resume) => {}
+                    Ok(MediaCommand::Watch(watch)) => watching = watch,
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+                if watching {
+                    is_playing.store(any_playing(), Ordering::Relaxed);
+                }
+            }
+        });
+        if let Err(e) = spawned {
+            tracing::error!("Failed to start media controller: {}", e);
+        }
+
+        Self { tx, playing }
+    }
+
+    /// Pause every player that is currently playing
+    pub fn pause(&self) {
+        let _ = self.tx.send(MediaCommand::Pause);
+    }
+
+    /// Resume the players paused by [`MediaController::pause`]
+    pub fn resume(&self) {
+        let _ = self.tx.send(MediaCommand::Resume);
+    }
+
+    /// Start or stop checking whether media is playing
+    pub fn watch(&self, enabled: bool) {
+        let _ = self.tx.send(MediaCommand::Watch(enabled));
+    }
+
+    /// Whether media was playing at the last check (see [`MediaController::watch`])
+    pub fn is_playing(&self) -> bool {
+        self.playing.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(windows)]
+fn pause_playing() -> Vec<String> {
+    use windows::Media::Control::{
+        GlobalSystemMediaTransportControlsSessionManager as SessionManager,
+        GlobalSystemMediaTransportControlsSessionPlaybackStatus as PlaybackStatus,
+    };
+
+    let pause = || -> windows::core::Result<Vec<String>> {
+        let manager = SessionManager::RequestAsync()?.get()?;
+        let mut paused = Vec::new();
+        for session in manager.GetSessions()? {
+            let status = session.GetPlaybackInfo()?.PlaybackStatus()?;
+            if status == PlaybackStatus::Playing && session.TryPauseAsync()?.get()? {
+                paused.push(session.SourceAppUserModelId()?.to_string());
+            }
+        }
+        Ok(paused)
+    };
+    pause().unwrap_or_else(|e| {
+        tracing::warn!("Failed to pause media: {}", e);
+        Vec::new()
+    })
+}
+
+#[cfg(windows)]
+fn any_playing() -> bool {
+    use windows::Media::Control::{
+        GlobalSystemMediaTransportControlsSessionManager as SessionManager,
+        GlobalSystemMediaTransportControlsSessionPlaybackStatus as PlaybackStatus,
+    };
+
+    let check = || -> windows::core::Result<bool> {
+        let manager = SessionManager::RequestAsync()?.get()?;
+        for session in manager.GetSessions()? {
+            if session.GetPlaybackInfo()?.PlaybackStatus()? == PlaybackStatus::Playing {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    };
+    check().unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn resume(players: &[String]) {
+    use windows::Media::Control::GlobalSystemMediaTransportControlsSessionManager as SessionManager;
+
+    let play = || -> windows::core::Result<()> {
+        let manager = SessionManager::RequestAsync()?.get()?;
+        for session in manager.GetSessions()? {
+            let id = session.SourceAppUserModelId()?.to_string();
+            if players.contains(&id) {
+                session.TryPlayAsync()?.get()?;
+            }
+        }
+        Ok(())
+    };
+    if let Err(e) = play() {
+        tracing::warn!("Failed to resume media: {}", e);
+    }
+}
+
+#[cfg(target_os = "linux")]
+const MPRIS_PREFIX: &str = "org.mpris.MediaPlayer2.";
+
+#[cfg(target_os = "linux")]
+const MPRIS_PATH: &str = "/org/mpris/MediaPlayer2";
+
+#[cfg(target_os = "linux")]
+const MPRIS_PLAYER: &str = "org.mpris.MediaPlayer2.Player";
+
+/// Bus names of the MPRIS players that are playing
+#[cfg(target_os = "linux")]
+fn playing_players(connection: &zbus::blocking::Connection) -> zbus::Result<Vec<String>> {
+    use zbus::zvariant::OwnedValue;
+
+    let names: Vec<String> = connection
+        .call_method(
+            Some("org.freedesktop.DBus"),
+            "/org/freedesktop/DBus",
+            Some("org.freedesktop.DBus"),
+            "ListNames",
+            &(),
+        )?
+        .body()
+        .deserialize()?;
+
+    let mut playing = Vec::new();
+    for name in names.into_iter().filter(|n| n.starts_with(MPRIS_PREFIX)) {
+        let status: OwnedValue = connection
+            .call_method(
+                Some(name.as_str()),
+                MPRIS_PATH,
+                Some("org.freedesktop.DBus.Properties"),
+                "Get",
+                &(MPRIS_PLAYER, "PlaybackStatus"),
+            )?
+            .body()
+            .deserialize()?;
+        if String::try_from(status).is_ok_and(|s| s == "Playing") {
+            playing.push(name);
+        }
+    }
+    Ok(playing)
+}
+
+#[cfg(target_os = "linux")]
+fn pause_playing() -> Vec<String> {
+    let pause = || -> zbus::Result<Vec<String>> {
+        let connection = zbus::blocking::Connection::session()?;
+        let mut paused = playing_players(&connection)?;
+        paused.retain(|name| {
+            connection
+                .call_method(
+                    Some(name.as_str()),
+                    MPRIS_PATH,
+                    Some(MPRIS_PLAYER),
+                    "Pause",
+                    &(),
+                )
+                .is_ok()
+        });
+        Ok(paused)
+    };
+    pause().unwrap_or_else(|e| {
+        tracing::warn!("Failed to pause media: {}", e);
+        Vec::new()
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn any_playing() -> bool {
+    zbus::blocking::Connection::session()
+        .and_then(|connection| playing_players(&connection))
+        .is_ok_and(|playing| !playing.is_empty())
+}
+
+#[cfg(target_os = "linux")]
+fn resume(players: &[String]) {
+    let connection = match zbus::blocking::Connection::session() {
+        Ok(connection) => connection,
+        Err(e) => {
+            tracing::warn!("Failed to resume media: {}", e);
+            return;
+        }
+    };
+    for name in players {
+        // The player may have quit in the meantime
+        let _ = connection.call_method(
+            Some(name.as_str()),
+            MPRIS_PATH,
+            Some(MPRIS_PLAYER),
+            "Play",
+            &(),
+        );
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn pause_playing() -> Vec<String> {
+    Vec::new()
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn resume(_players: &[String]) {}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn any_playing() -> bool {
+    false
+}
@@ -6,8 +6,21 @@
 //! - Autostart configuration
 //! - Window effects
 //! - Global hotkeys
-
+//! - Input idle time and screen lock state
+//! - Battery and power saver state, system sleep and wake-up
+//! - Media player control
+//! - Distraction blocking
+//! - Connected displays
+//! - The application in the foreground
+
+mod active_app;
+mod ambient;
 mod audio;
+mod blocker;
+mod media;
+mod monitors;
+mod power;
+mod presence;
 pub mod tray;
 
 #[cfg(windows)]
@@ -19,17 +32,73 @@ mod hotkeys;
 #[cfg(target_os = "linux")]
 mod linux;
 
-#[cfg(target_os = "linux")]
-mod linux_hotkeys;
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod global_hotkeys;
 
+pub use active_app::focused_app;
 pub use audio::AudioPlayer;
-pub use tray::{SystemTray, TrayAction};
+pub use blocker::DistractionBlocker;
+pub use media::MediaController;
+pub use monitors::{monitor_rects, MonitorRect};
+pub use power::{is_power_saving, SleepEvent, SleepMonitor};
+pub use presence::{idle_duration, is_screen_locked};
+
+/// Button attached to a timer notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationAction {
+    /// Start the next session now
+    StartNext,
+    /// Start the next session in a few minutes
+    Snooze,
+    /// Skip the next session
+    Skip,
+}
+
+impl NotificationAction {
+    /// Identifier passed through the OS notification system
+    pub fn id(&self) -> &'static str {
+        match self {
+            NotificationAction::StartNext => "start",
+            NotificationAction::Snooze => "snooze",
+            NotificationAction::Skip => "skip",
+        }
+    }
+
+    /// Parse an identifier returned by the OS notification system
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "start" => Some(NotificationAction::StartNext),
+            "snooze" => Some(NotificationAction::Snooze),
+            "skip" => Some(NotificationAction::Skip),
+            _ => None,
+        }
+    }
+}
+
+/// Timer state shown on the taskbar button
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskbarProgress {
+    /// No progress indicator
+    None,
+    /// Session running, progress in permille (green)
+    Running(u16),
+    /// Session paused, progress in permille (yellow)
+    Paused(u16),
+    /// Session finished and waiting for the user (red)
+    Completed,
+}
+pub use tray::{SystemTray, TrayAction, TrayIconFace};
 
 #[cfg(windows)]
 pub use windows::{
-    apply_window_effects, ensure_notification_shortcut, flash_pomodorust_window, flash_window,
-    hide_pomodorust_window, is_windows_11, remove_autostart, set_autostart, show_notification,
-    show_pomodorust_window, stop_flash_window, system_uses_light_theme,
+    allow_set_foreground_window, apply_window_effects, ensure_notification_shortcut,
+    flash_pomodorust_window, flash_window, hide_pomodorust_window, is_windows_11,
+    register_jump_list, remove_autostart, set_autostart, set_do_not_disturb, set_main_window,
+    set_taskbar_progress, show_actionable_notification, show_notification, show_pomodorust_window,
+    stop_flash_window, system_uses_light_theme,
 };
 
 #[cfg(windows)]
@@ -37,55 +106,86 @@ pub use hotkeys::{HotkeyAction, HotkeyManager};
 
 #[cfg(target_os = "linux")]
 pub use linux::{
+    apply_window_effects, flash_pomodorust_window, flash_window, remove_autostart, set_autostart,
+    set_do_not_disturb, show_actionable_notification, show_notification, show_pomodorust_window,
+    stop_flash_window, system_uses_light_theme,
+};
+
+#[cfg(target_os = "macos")]
+pub use macos::{
     apply_window_effects, flash_pomodorust_window, flash_window, remove_autostart, set_autostart,
     show_notification, show_pomodorust_window, stop_flash_window, system_uses_light_theme,
 };
 
-#[cfg(target_os = "linux")]
-pub use linux_hotkeys::{HotkeyAction, HotkeyManager};
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub use global_hotkeys::{HotkeyAction, HotkeyManager};
 
-// Fallback for other platforms (not Windows, not Linux)
-#[cfg(not(any(windows, target_os = "linux")))]
+// Fallback for other platforms (not Windows, Linux or macOS)
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 use crate::error::PlatformError;
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn show_notification(_title: &str, _body: &str) {
     tracing::info!("Notification: {} - {}", _title, _body);
 }
 
+/// Show a notification with buttons (buttons are only supported on Windows
+/// and Linux; elsewhere this is a plain notification)
 #[cfg(not(any(windows, target_os = "linux")))]
+pub fn show_actionable_notification(
+    title: &str,
+    body: &str,
+    _actions: &[(NotificationAction, &str)],
+    _on_action: impl Fn(NotificationAction) + Send + 'static,
+) {
+    show_notification(title, body);
+}
+
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn set_autostart(_enabled: bool) -> Result<(), PlatformError> {
     // Autostart not implemented for this platform
     Ok(())
 }
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn remove_autostart() -> Result<(), PlatformError> {
     // Autostart not implemented for this platform
     Ok(())
 }
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn apply_window_effects(_hwnd: isize) {
     // Window effects are platform-specific
 }
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn flash_window(_hwnd: isize, _count: u32) {
     // Window flash is platform-specific
 }
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn stop_flash_window(_hwnd: isize) {
     // Window flash is platform-specific
 }
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn flash_pomodorust_window(_count: u32) -> bool {
     // Window flash is platform-specific
     false
 }
 
+/// Show timer progress on the taskbar button (Windows only)
+#[cfg(not(windows))]
+pub fn set_taskbar_progress(_progress: TaskbarProgress) {
+    // Taskbar progress is Windows-specific
+}
+
+/// Turn Do Not Disturb on during focus (Windows and Linux only)
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn set_do_not_disturb(_active: bool) {
+    // macOS Focus modes can't be toggled by apps
+}
+
 /// Check if running on Windows 11 (or modern desktop with rounded corners)
 /// Returns true for Linux/macOS (use rounded corners), false for Windows 10
 #[cfg(not(windows))]
@@ -95,30 +195,31 @@ pub fn is_windows_11() -> bool {
 
 /// Check if the system is using light theme (fallback for unsupported platforms)
 /// Returns false (defaults to dark theme)
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn system_uses_light_theme() -> bool {
     false // Default to dark theme on unsupported platforms
 }
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub fn show_pomodorust_window() -> bool {
     // Window show is platform-specific
     false
 }
 
 // Hotkey fallbacks for other platforms
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HotkeyAction {
     Toggle,
     Skip,
     Reset,
+    Mute,
 }
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 pub struct HotkeyManager;
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 impl HotkeyManager {
     pub fn new() -> Self {
         Self
@@ -128,18 +229,24 @@ impl HotkeyManager {
         None
     }
 
-    pub fn start(&mut self, _toggle: &str, _skip: &str, _reset: &str) {
+    pub fn take_failures(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    pub fn start(&mut self, _toggle: &str, _skip: &str, _reset: &str, _mute: &str) {
         tracing::info!("Global hotkeys not supported on this platform");
     }
 
     pub fn stop(&mut self) {}
 
+    pub fn set_media_keys(&self, _enabled: bool) {}
+
     pub fn is_running(&self) -> bool {
         false
     }
 }
 
-#[cfg(not(any(windows, target_os = "linux")))]
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
 impl Default for HotkeyManager {
     fn default() -> Self {
         Self::new()
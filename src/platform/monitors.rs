@@ -0,0 +1,121 @@
+//! Connected displays
+//!
+//! - Windows: `EnumDisplayMonitors`
+//! - Linux: `xrandr --listmonitors` (X11 and XWayland)
+//!
+//! Elsewhere, or when detection fails, the list is empty and callers should
+//! fall back to the monitor the main window is on.
+
+/// A display's area in physical pixels on the virtual desktop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitorRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// List the connected displays
+#[cfg(windows)]
+pub fn monitor_rects() -> Vec<MonitorRect> {
+    use windows::Win32::Foundation::{BOOL, LPARAM, RECT};
+    use windows::Win32::Graphics::Gdi::{EnumDisplayMonitors, HDC, HMONITOR};
+
+    unsafe extern "system" fn collect(
+        _monitor: HMONITOR,
+        _hdc: HDC,
+        rect: *mut RECT,
+        data: LPARAM,
+    ) -> BOOL {
+        let monitors = &mut *(data.0 as *mut Vec<MonitorRect>);
+        let rect = &*rect;
+        monitors.push(MonitorRect {
+            x: rect.left,
+            y: rect.top,
+            width: (rect.right - rect.left).max(0) as u32,
+            height: (rect.bottom - rect.top).max(0) as u32,
+        });
+        true.into()
+    }
+
+    let mut monitors: Vec<MonitorRect> = Vec::new();
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            HDC::default(),
+            None,
+            Some(collect),
+            LPARAM(&mut monitors as *mut Vec<MonitorRect> as isize),
+        );
+    }
+    monitors
+}
+
+/// List the connected displays
+#[cfg(target_os = "linux")]
+pub fn monitor_rects() -> Vec<MonitorRect> {
+    std::process::Command::new("xrandr")
+        .arg("--listmonitors")
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| parse_xrandr_monitors(&String::from_utf8_lossy(&output.stdout)))
+        .unwrap_or_default()
+}
+
+/// Parse lines like ` 1: +HDMI-1 2560/597x1440/336+1920+0  HDMI-1`
+#[cfg(target_os = "linux")]
+fn parse_xrandr_monitors(output: &str) -> Vec<MonitorRect> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let geometry = line.split_whitespace().nth(2)?;
+            let (width, rest) = geometry.split_once('/')?;
+            let (_, rest) = rest.split_once('x')?;
+            let (height, rest) = rest.split_once('/')?;
+            // Signed offsets follow the physical height, e.g. `336+1920-200`
+            let offsets = &rest[rest.find(['+', '-'])?..];
+            let split = offsets[1..].find(['+', '-'])? + 1;
+            let (x, y) = offsets.split_at(split);
+            Some(MonitorRect {
+                x: x.parse().ok()?,
+                y: y.parse().ok()?,
+                width: width.parse().ok()?,
+                height: height.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// List the connected displays (unsupported platform)
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn monitor_rects() -> Vec<MonitorRect> {
+    Vec::new()
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_xrandr_monitors() {
+        let output = "Monitors: 2\n 0: +*eDP-1 1920/344x1080/194+0+0  eDP-1\n 1: +HDMI-1 2560/597x1440/336+1920-200  HDMI-1\n";
+        assert_eq!(
+            parse_xrandr_monitors(output),
+            vec![
+                MonitorRect {
+                    x: 0,
+                    y: 0,
+                    width: 1920,
+                    height: 1080
+                },
+                MonitorRect {
+                    x: 1920,
+                    y: -200,
+                    width: 2560,
+                    height: 1440
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,367 @@
+//! Power state: running on battery or in battery saver mode
+//!
+//! - Windows: `GetSystemPowerStatus` (AC line status and the battery saver flag)
+//! - Linux: mains adapters in `/sys/class/power_supply`, plus the
+//!   `power-saver` profile of power-profiles-daemon over D-Bus
+//! - macOS: `pmset` (power source and Low Power Mode)
+//!
+//! The Linux and macOS backends shell out or talk to D-Bus, so callers should
+//! poll every few seconds rather than every frame.
+//!
+//! Sleep and wake-up are reported by [`SleepMonitor`]:
+//! - Windows: `PowerRegisterSuspendResumeNotification`
+//! - Linux: logind's `PrepareForSleep` signal, holding a delay inhibitor so
+//!   the app gets a moment to react before the system suspends
+//! - Elsewhere (and without logind): a jump of the wall clock, which only
+//!   reports the wake-up
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How long the system is held back so the UI can react to a suspend
+#[cfg(any(windows, target_os = "linux"))]
+const SUSPEND_GRACE: Duration = Duration::from_millis(500);
+
+/// Wall clock polling interval of the fallback wake-up detection
+const CLOCK_POLL: Duration = Duration::from_secs(5);
+
+/// Extra wall clock time that counts as having been asleep
+const CLOCK_JUMP: Duration = Duration::from_secs(30);
+
+/// A system sleep transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SleepEvent {
+    /// The system is about to suspend
+    Suspending,
+    /// The system woke up after sleeping for about `slept`
+    Resumed { slept: Duration },
+}
+
+/// Forwards sleep transitions from the backend to the app
+struct SleepNotifier {
+    tx: Sender<SleepEvent>,
+    ctx: egui::Context,
+    suspended_at: Mutex<Option<SystemTime>>,
+}
+
+impl SleepNotifier {
+    fn send(&self, event: SleepEvent) {
+        tracing::info!("System power event: {:?}", event);
+        let _ = self.tx.send(event);
+        self.ctx.request_repaint();
+    }
+
+    fn suspending(&self) {
+        if let Ok(mut at) = self.suspended_at.lock() {
+            *at = Some(SystemTime::now());
+        }
+        self.send(SleepEvent::Suspending);
+    }
+
+    fn resumed(&self) {
+        let slept = self
+            .suspended_at
+            .lock()
+            .ok()
+            .and_then(|mut at| at.take())
+            .and_then(|at| at.elapsed().ok())
+            .unwrap_or_default();
+        self.send(SleepEvent::Resumed { slept });
+    }
+}
+
+/// Listener for system suspend and resume
+pub struct SleepMonitor {
+    rx: Receiver<SleepEvent>,
+}
+
+impl SleepMonitor {
+    /// Subscribe to sleep events, waking the UI through `ctx`
+    pub fn start(ctx: egui::Context) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let notifier = Arc::new(SleepNotifier {
+            tx,
+            ctx,
+            suspended_at: Mutex::new(None),
+        });
+        watch(notifier);
+        Self { rx }
+    }
+
+    /// Next pending sleep event
+    pub fn poll(&self) -> Option<SleepEvent> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// Register for suspend/resume callbacks
+#[cfg(windows)]
+fn watch(notifier: Arc<SleepNotifier>) {
+    use std::ffi::c_void;
+    use windows::Win32::Foundation::{ERROR_SUCCESS, HANDLE};
+    use windows::Win32::System::Power::{
+        PowerRegisterSuspendResumeNotification, DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS, HPOWERNOTIFY,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        DEVICE_NOTIFY_CALLBACK, PBT_APMRESUMEAUTOMATIC, PBT_APMSUSPEND,
+    };
+
+    unsafe extern "system" fn callback(
+        context: *const c_void,
+        kind: u32,
+        _setting: *const c_void,
+    ) -> u32 {
+        let notifier = &*(context as *const SleepNotifier);
+        match kind {
+            PBT_APMSUSPEND => {
+                notifier.suspending();
+                // Windows waits for the callback before suspending
+                thread::sleep(SUSPEND_GRACE);
+            }
+            PBT_APMRESUMEAUTOMATIC => notifier.resumed(),
+            _ => {}
+        }
+        ERROR_SUCCESS.0
+    }
+
+    // The registration lasts for the whole process, so the context and
+    // parameters are leaked on purpose
+    let context = Arc::into_raw(notifier.clone()) as *mut c_void;
+    let params = Box::leak(Box::new(DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS {
+        Callback: Some(callback),
+        Context: context,
+    }));
+    let mut registration = HPOWERNOTIFY::default();
+    let result = unsafe {
+        PowerRegisterSuspendResumeNotification(
+            DEVICE_NOTIFY_CALLBACK,
+            HANDLE(params as *mut DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS as *mut c_void),
+            &mut registration,
+        )
+    };
+    if result != ERROR_SUCCESS {
+        tracing::warn!("Failed to register for power notifications: {:?}", result);
+        spawn_watcher(move || watch_clock(&notifier));
+    }
+}
+
+/// Listen to logind, falling back to the wall clock
+#[cfg(target_os = "linux")]
+fn watch(notifier: Arc<SleepNotifier>) {
+    spawn_watcher(move || {
+        if let Err(e) = watch_logind(&notifier) {
+            tracing::debug!("logind sleep signals unavailable: {}", e);
+            watch_clock(&notifier);
+        }
+    });
+}
+
+/// Watch the wall clock for jumps
+#[cfg(not(any(windows, target_os = "linux")))]
+fn watch(notifier: Arc<SleepNotifier>) {
+    spawn_watcher(move || watch_clock(&notifier));
+}
+
+/// Run a sleep backend on its own thread
+fn spawn_watcher(watcher: impl FnOnce() + Send + 'static) {
+    let spawned = thread::Builder::new()
+        .name("sleep-monitor".into())
+        .spawn(watcher);
+    if let Err(e) = spawned {
+        tracing::error!("Failed to start sleep monitor: {}", e);
+    }
+}
+
+/// Follow logind's `PrepareForSleep(bool start)` signal
+#[cfg(target_os = "linux")]
+fn watch_logind(notifier: &SleepNotifier) -> zbus::Result<()> {
+    use zbus::blocking::{Connection, Proxy};
+
+    let connection = Connection::system()?;
+    let manager = Proxy::new(
+        &connection,
+        "org.freedesktop.login1",
+        "/org/freedesktop/login1",
+        "org.freedesktop.login1.Manager",
+    )?;
+    let signals = manager.receive_signal("PrepareForSleep")?;
+
+    // logind delays the suspend until this file descriptor is closed
+    let inhibit = || -> Option<zbus::zvariant::OwnedFd> {
+        manager
+            .call(
+                "Inhibit",
+                &("sleep", "PomodoRust", "Pause the timer", "delay"),
+            )
+            .ok()
+    };
+    let mut inhibitor = inhibit();
+
+    for signal in signals {
+        let Ok(starting) = signal.body().deserialize::<bool>() else {
+            continue;
+        };
+        if starting {
+            notifier.suspending();
+            thread::sleep(SUSPEND_GRACE);
+            drop(inhibitor.take());
+        } else {
+            notifier.resumed();
+            inhibitor = inhibit();
+        }
+    }
+    Ok(())
+}
+
+/// Report a wake-up when the wall clock moves much further than expected
+fn watch_clock(notifier: &SleepNotifier) {
+    let mut last = SystemTime::now();
+    loop {
+        thread::sleep(CLOCK_POLL);
+        let now = SystemTime::now();
+        let gap = now.duration_since(last).unwrap_or_default();
+        if gap > CLOCK_POLL + CLOCK_JUMP {
+            notifier.send(SleepEvent::Resumed {
+                slept: gap - CLOCK_POLL,
+            });
+        }
+        last = now;
+    }
+}
+
+/// Whether the system is saving power, if it can be determined
+#[cfg(windows)]
+pub fn is_power_saving() -> Option<bool> {
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    /// `SystemStatusFlag` value while battery saver is on
+    const BATTERY_SAVER_ON: u8 = 1;
+    /// `ACLineStatus` value while unplugged
+    const AC_OFFLINE: u8 = 0;
+
+    let mut status = SYSTEM_POWER_STATUS::default();
+    unsafe { GetSystemPowerStatus(&mut status) }.ok()?;
+    Some(status.SystemStatusFlag == BATTERY_SAVER_ON || status.ACLineStatus == AC_OFFLINE)
+}
+
+/// Whether the system is saving power, if it can be determined
+#[cfg(target_os = "linux")]
+pub fn is_power_saving() -> Option<bool> {
+    if power_profile().as_deref() == Some("power-saver") {
+        return Some(true);
+    }
+    on_battery()
+}
+
+/// Whether a battery is present and no mains adapter is online
+#[cfg(target_os = "linux")]
+fn on_battery() -> Option<bool> {
+    let entries = std::fs::read_dir("/sys/class/power_supply").ok()?;
+    let supplies: Vec<(String, bool)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let kind = std::fs::read_to_string(path.join("type")).ok()?;
+            let online =
+                std::fs::read_to_string(path.join("online")).is_ok_and(|value| value.trim() == "1");
+            Some((kind.trim().to_string(), online))
+        })
+        .collect();
+    on_battery_from_supplies(&supplies)
+}
+
+/// Decide from `(type, online)` pairs whether the machine runs on battery
+#[cfg(target_os = "linux")]
+fn on_battery_from_supplies(supplies: &[(String, bool)]) -> Option<bool> {
+    if !supplies.iter().any(|(kind, _)| kind == "Battery") {
+        // Desktops without a battery are never on battery
+        return Some(false);
+    }
+    let mut mains = supplies
+        .iter()
+        .filter(|(kind, _)| kind == "Mains")
+        .peekable();
+    mains.peek()?;
+    Some(!mains.any(|(_, online)| *online))
+}
+
+/// Active power-profiles-daemon profile (`power-saver`, `balanced`, ...)
+#[cfg(target_os = "linux")]
+fn power_profile() -> Option<String> {
+    use zbus::zvariant::{OwnedValue, Value};
+
+    let connection = zbus::blocking::Connection::system().ok()?;
+    let reply = connection
+        .call_method(
+            Some("net.hadess.PowerProfiles"),
+            "/net/hadess/PowerProfiles",
+            Some("org.freedesktop.DBus.Properties"),
+            "Get",
+            &("net.hadess.PowerProfiles", "ActiveProfile"),
+        )
+        .ok()?;
+    let value: OwnedValue = reply.body().deserialize().ok()?;
+
+    match &*value {
+        Value::Str(profile) => Some(profile.to_string()),
+        _ => None,
+    }
+}
+
+/// Whether the system is saving power, if it can be determined
+#[cfg(target_os = "macos")]
+pub fn is_power_saving() -> Option<bool> {
+    use std::process::Command;
+
+    let pmset = |args: &[&str]| -> Option<String> {
+        let output = Command::new("pmset").args(args).output().ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let settings = pmset(&["-g"]).unwrap_or_default();
+    let low_power = settings.lines().any(|line| {
+        let mut words = line.split_whitespace();
+        words.next() == Some("lowpowermode") && words.next() == Some("1")
+    });
+    if low_power {
+        return Some(true);
+    }
+
+    // "Now drawing from 'Battery Power'"
+    pmset(&["-g", "batt"]).map(|batt| batt.contains("'Battery Power'"))
+}
+
+/// Whether the system is saving power (not supported on this platform)
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
+pub fn is_power_saving() -> Option<bool> {
+    None
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    fn supplies(list: &[(&str, bool)]) -> Vec<(String, bool)> {
+        list.iter().map(|(k, o)| (k.to_string(), *o)).collect()
+    }
+
+    #[test]
+    fn test_on_battery_from_supplies() {
+        let laptop = supplies(&[("Mains", false), ("Battery", false)]);
+        assert_eq!(on_battery_from_supplies(&laptop), Some(true));
+
+        let plugged = supplies(&[("Mains", true), ("Battery", false), ("USB", false)]);
+        assert_eq!(on_battery_from_supplies(&plugged), Some(false));
+
+        assert_eq!(on_battery_from_supplies(&supplies(&[])), Some(false));
+        assert_eq!(
+            on_battery_from_supplies(&supplies(&[("Battery", false)])),
+            None
+        );
+    }
+}
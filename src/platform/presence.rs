@@ -0,0 +1,212 @@
+//! User presence: system-wide input idle time and screen lock state
+//!
+//! Idle time:
+//! - Windows: `GetLastInputInfo`
+//! - Linux: GNOME Mutter's IdleMonitor or the freedesktop ScreenSaver
+//!   interface over D-Bus (works on Wayland), falling back to `xprintidle`
+//!   on other X11 desktops
+//! - macOS: `HIDIdleTime` from the IOHIDSystem registry entry
+//!
+//! Screen lock:
+//! - Windows: the input desktop can't be opened or switched to while the
+//!   secure lock screen is shown
+//! - Linux: `GetActive` on the GNOME or freedesktop ScreenSaver interface
+//! - macOS: `CGSSessionScreenIsLocked` in the console session registry entry
+//!
+//! The Linux and macOS backends shell out, so callers should poll every few
+//! seconds rather than every frame.
+
+use std::time::Duration;
+
+/// Time since the last keyboard or mouse input, if it can be determined
+#[cfg(windows)]
+pub fn idle_duration() -> Option<Duration> {
+    use windows::Win32::System::SystemInformation::GetTickCount;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    let mut info = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+    unsafe {
+        if !GetLastInputInfo(&mut info).as_bool() {
+            return None;
+        }
+        // Both are milliseconds since boot and wrap after ~49 days
+        let idle_ms = GetTickCount().wrapping_sub(info.dwTime);
+        Some(Duration::from_millis(idle_ms as u64))
+    }
+}
+
+/// Whether the screen is locked, if it can be determined
+#[cfg(windows)]
+pub fn is_screen_locked() -> Option<bool> {
+    use windows::Win32::System::StationsAndDesktops::{
+        CloseDesktop, OpenInputDesktop, SwitchDesktop, DESKTOP_CONTROL_FLAGS, DESKTOP_SWITCHDESKTOP,
+    };
+
+    unsafe {
+        // The lock screen runs on the secure desktop, which we can't open
+        let Ok(desktop) = OpenInputDesktop(DESKTOP_CONTROL_FLAGS(0), false, DESKTOP_SWITCHDESKTOP)
+        else {
+            return Some(true);
+        };
+        let locked = SwitchDesktop(desktop).is_err();
+        let _ = CloseDesktop(desktop);
+        Some(locked)
+    }
+}
+
+/// Time since the last keyboard or mouse input, if it can be determined
+#[cfg(target_os = "linux")]
+pub fn idle_duration() -> Option<Duration> {
+    // GNOME (X11 and Wayland), milliseconds
+    let mutter = gdbus_call(
+        "org.gnome.Mutter.IdleMonitor",
+        "/org/gnome/Mutter/IdleMonitor/Core",
+        "org.gnome.Mutter.IdleMonitor.GetIdletime",
+    );
+    if let Some(ms) = mutter.as_deref().and_then(parse_gvariant_uint) {
+        return Some(Duration::from_millis(ms));
+    }
+
+    // KDE and others implementing the freedesktop interface, seconds
+    let screensaver = gdbus_call(
+        "org.freedesktop.ScreenSaver",
+        "/org/freedesktop/ScreenSaver",
+        "org.freedesktop.ScreenSaver.GetSessionIdleTime",
+    );
+    if let Some(secs) = screensaver.as_deref().and_then(parse_gvariant_uint) {
+        return Some(Duration::from_secs(secs));
+    }
+
+    // Plain X11, milliseconds
+    let output = std::process::Command::new("xprintidle").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let ms = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_millis(ms))
+}
+
+/// Whether the screen is locked, if it can be determined
+#[cfg(target_os = "linux")]
+pub fn is_screen_locked() -> Option<bool> {
+    [
+        ("org.gnome.ScreenSaver", "/org/gnome/ScreenSaver"),
+        (
+            "org.freedesktop.ScreenSaver",
+            "/org/freedesktop/ScreenSaver",
+        ),
+    ]
+    .into_iter()
+    .find_map(|(dest, path)| {
+        let method = format!("{}.GetActive", dest);
+        gdbus_call(dest, path, &method).and_then(|reply| parse_gvariant_bool(&reply))
+    })
+}
+
+/// Call a D-Bus method on the session bus, returning the raw reply
+#[cfg(target_os = "linux")]
+fn gdbus_call(dest: &str, path: &str, method: &str) -> Option<String> {
+    let output = std::process::Command::new("gdbus")
+        .args(["call", "--session", "--dest", dest, "--object-path", path])
+        .args(["--method", method])
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Extract the value from a gdbus reply like `(true,)`
+#[cfg(target_os = "linux")]
+fn parse_gvariant_bool(reply: &str) -> Option<bool> {
+    match reply
+        .trim()
+        .trim_matches(|c| c == '(' || c == ')' || c == ',')
+    {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Extract the number from a gdbus reply like `(uint64 1234,)`
+#[cfg(target_os = "linux")]
+fn parse_gvariant_uint(reply: &str) -> Option<u64> {
+    reply
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .trim_end_matches(',')
+        .split_whitespace()
+        .last()?
+        .parse()
+        .ok()
+}
+
+/// Time since the last keyboard or mouse input, if it can be determined
+#[cfg(target_os = "macos")]
+pub fn idle_duration() -> Option<Duration> {
+    let output = std::process::Command::new("ioreg")
+        .args(["-c", "IOHIDSystem", "-d", "4"])
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // `"HIDIdleTime" = 123456789` (nanoseconds)
+    let line = stdout.lines().find(|l| l.contains("\"HIDIdleTime\""))?;
+    let ns: u64 = line.rsplit('=').next()?.trim().parse().ok()?;
+    Some(Duration::from_nanos(ns))
+}
+
+/// Whether the screen is locked, if it can be determined
+#[cfg(target_os = "macos")]
+pub fn is_screen_locked() -> Option<bool> {
+    let output = std::process::Command::new("ioreg")
+        .args(["-n", "Root", "-d", "1"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Set to Yes in the console session dictionary while locked
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(stdout.contains("\"CGSSessionScreenIsLocked\"=Yes"))
+}
+
+/// Time since the last keyboard or mouse input (unsupported platform)
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
+pub fn idle_duration() -> Option<Duration> {
+    None
+}
+
+/// Whether the screen is locked (unsupported platform)
+#[cfg(not(any(windows, target_os = "linux", target_os = "macos")))]
+pub fn is_screen_locked() -> Option<bool> {
+    None
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gvariant_uint() {
+        assert_eq!(parse_gvariant_uint("(uint64 1234,)\n"), Some(1234));
+        assert_eq!(parse_gvariant_uint("(uint32 5,)"), Some(5));
+        assert_eq!(parse_gvariant_uint("Error"), None);
+    }
+
+    #[test]
+    fn test_parse_gvariant_bool() {
+        assert_eq!(parse_gvariant_bool("(true,)\n"), Some(true));
+        assert_eq!(parse_gvariant_bool("(false,)"), Some(false));
+        assert_eq!(parse_gvariant_bool("(uint32 1,)"), None);
+    }
+}
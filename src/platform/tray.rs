@@ -1,7 +1,8 @@
 //! System tray integration
 //!
 //! Provides a system tray icon with context menu for controlling the timer
-//! when the main window is minimized or hidden.
+//! when the main window is minimized or hidden. The icon itself can show the
+//! remaining time as a countdown or a progress pie (see [`TrayIconFace`]).
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc, Arc};
@@ -18,6 +19,20 @@ pub enum TrayAction {
     Quit,
 }
 
+/// Size of the dynamically rendered tray icon in pixels
+const FACE_SIZE: u32 = 32;
+
+/// What the tray icon currently shows
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrayIconFace {
+    /// The app logo
+    Logo,
+    /// Remaining whole minutes (seconds during the last minute) on a colored disc
+    Countdown { label: String, color: [u8; 3] },
+    /// Pie of the remaining time, in sixtieths of the session
+    Progress { remaining: u8, color: [u8; 3] },
+}
+
 /// System tray icon with context menu
 pub struct SystemTray {
     _tray_icon: TrayIcon,
@@ -27,6 +42,7 @@ pub struct SystemTray {
     show_id: tray_icon::menu::MenuId,
     quit_id: tray_icon::menu::MenuId,
     last_tooltip: String,
+    last_face: TrayIconFace,
     /// Receiver for actions from the background polling thread
     action_rx: Option<mpsc::Receiver<TrayAction>>,
     /// Flag to stop the background polling thread
@@ -74,6 +90,7 @@ impl SystemTray {
             show_id,
             quit_id,
             last_tooltip: String::new(),
+            last_face: TrayIconFace::Logo,
             action_rx: None,
             polling_active: Arc::new(AtomicBool::new(false)),
             periodic_wakeup: Arc::new(AtomicBool::new(false)),
@@ -226,6 +243,35 @@ impl SystemTray {
         }
     }
 
+    /// Update the tray icon image (only re-rendered if the face changed).
+    pub fn update_icon(&mut self, face: TrayIconFace) {
+        if self.last_face == face {
+            return;
+        }
+
+        let icon = match &face {
+            TrayIconFace::Logo => load_tray_icon(),
+            TrayIconFace::Countdown { label, color } => {
+                tray_icon::Icon::from_rgba(render_countdown(label, *color), FACE_SIZE, FACE_SIZE)
+                    .map_err(Into::into)
+            }
+            TrayIconFace::Progress { remaining, color } => tray_icon::Icon::from_rgba(
+                render_progress(*remaining as f32 / 60.0, *color),
+                FACE_SIZE,
+                FACE_SIZE,
+            )
+            .map_err(Into::into),
+        };
+
+        match icon {
+            Ok(icon) => {
+                let _ = self._tray_icon.set_icon(Some(icon));
+                self.last_face = face;
+            }
+            Err(e) => tracing::warn!("Failed to render tray icon: {}", e),
+        }
+    }
+
     /// Update the toggle menu item label (Start/Pause/Resume).
     pub fn update_toggle_label(&self, label: &str) {
         self.toggle_item.set_text(label);
@@ -246,3 +292,111 @@ fn load_tray_icon() -> Result<tray_icon::Icon, Box<dyn std::error::Error>> {
     let icon = tray_icon::Icon::from_rgba(rgba, width, height)?;
     Ok(icon)
 }
+
+/// 3x5 pixel glyphs for the digits 0-9, one row per byte (bit 2 = left column)
+const DIGITS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b010, 0b010, 0b010],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+/// Fill a disc covering the icon, returning the RGBA buffer
+fn disc(color: [u8; 3], inside: impl Fn(f32, f32) -> bool) -> Vec<u8> {
+    let mut rgba = vec![0u8; (FACE_SIZE * FACE_SIZE * 4) as usize];
+    let center = FACE_SIZE as f32 / 2.0;
+    for y in 0..FACE_SIZE {
+        for x in 0..FACE_SIZE {
+            let (dx, dy) = (x as f32 + 0.5 - center, y as f32 + 0.5 - center);
+            if dx * dx + dy * dy <= center * center && inside(dx, dy) {
+                let i = ((y * FACE_SIZE + x) * 4) as usize;
+                rgba[i..i + 4].copy_from_slice(&[color[0], color[1], color[2], 255]);
+            }
+        }
+    }
+    rgba
+}
+
+/// Render one or two digits in white on a colored disc
+fn render_countdown(label: &str, color: [u8; 3]) -> Vec<u8> {
+    const SCALE: u32 = 3;
+    let mut rgba = disc(color, |_, _| true);
+
+    let digits: Vec<usize> = label
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|d| d as usize)
+        .take(2)
+        .collect();
+    let width = digits.len() as u32 * (3 * SCALE + SCALE) - SCALE;
+    let left = (FACE_SIZE - width) / 2;
+    let top = (FACE_SIZE - 5 * SCALE) / 2;
+
+    for (n, digit) in digits.iter().enumerate() {
+        let x0 = left + n as u32 * 4 * SCALE;
+        for (row, bits) in DIGITS[*digit].iter().enumerate() {
+            for col in 0..3 {
+                if bits & (0b100 >> col) == 0 {
+                    continue;
+                }
+                for py in 0..SCALE {
+                    for px in 0..SCALE {
+                        let x = x0 + col * SCALE + px;
+                        let y = top + row as u32 * SCALE + py;
+                        let i = ((y * FACE_SIZE + x) * 4) as usize;
+                        rgba[i..i + 4].copy_from_slice(&[255, 255, 255, 255]);
+                    }
+                }
+            }
+        }
+    }
+    rgba
+}
+
+/// Render a pie of the remaining fraction, clockwise from 12 o'clock
+fn render_progress(remaining: f32, color: [u8; 3]) -> Vec<u8> {
+    let dim = [color[0] / 4, color[1] / 4, color[2] / 4];
+    let mut rgba = disc(dim, |_, _| true);
+    let pie = disc(color, |dx, dy| {
+        // Angle from 12 o'clock, clockwise, in 0..1
+        let angle = dx.atan2(-dy) / std::f32::consts::TAU;
+        angle.rem_euclid(1.0) < remaining
+    });
+    for (dst, src) in rgba.chunks_exact_mut(4).zip(pie.chunks_exact(4)) {
+        if src[3] > 0 {
+            dst.copy_from_slice(src);
+        }
+    }
+    rgba
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(rgba: &[u8], x: u32, y: u32) -> &[u8] {
+        let i = ((y * FACE_SIZE + x) * 4) as usize;
+        &rgba[i..i + 4]
+    }
+
+    #[test]
+    fn test_render_faces() {
+        let blue = [59, 130, 246];
+        let countdown = render_countdown("25", blue);
+        assert_eq!(countdown.len(), (FACE_SIZE * FACE_SIZE * 4) as usize);
+        // Corners are transparent, the top row of the "2" is white
+        assert_eq!(pixel(&countdown, 0, 0)[3], 0);
+        assert_eq!(pixel(&countdown, 10, 9), &[255, 255, 255, 255]);
+
+        // Half remaining: right half lit, left half dimmed
+        let pie = render_progress(0.5, blue);
+        assert_eq!(pixel(&pie, 24, 16), &[59, 130, 246, 255]);
+        assert_eq!(pixel(&pie, 8, 16), &[14, 32, 61, 255]);
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! Provides Windows-specific features including:
 //! - DWM (Desktop Window Manager) effects for dark mode and rounded corners
-//! - Native toast notifications
+//! - Native toast notifications, optionally with buttons
 //! - Autostart via registry
 //! - Window flash for timer completion
+//! - Taskbar button progress (ITaskbarList3)
+//! - Focus Assist during focus sessions
+//! - Taskbar jump list tasks
 //!
 //! ## Windows Version Compatibility
 //!
@@ -26,8 +29,14 @@ use winreg::RegKey;
 
 use crate::error::PlatformError;
 
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicIsize, Ordering};
 use std::sync::{Mutex, OnceLock};
 
+use windows::Win32::UI::Shell::ITaskbarList3;
+
+use super::{NotificationAction, TaskbarProgress};
+
 /// App User Model ID for Windows toast notifications
 const APP_USER_MODEL_ID: &str = "Gerrux.PomodoRust";
 
@@ -205,6 +214,61 @@ pub fn ensure_notification_shortcut() {
     }
 }
 
+/// Register the taskbar jump list tasks as `(title, CLI arguments)` pairs.
+/// Each task launches the exe, which forwards the command to this instance.
+pub fn register_jump_list(tasks: &[(&str, &str)]) {
+    use windows::core::{Interface, HSTRING, PROPVARIANT};
+    use windows::Win32::Storage::EnhancedStorage::PKEY_Title;
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED,
+    };
+    use windows::Win32::UI::Shell::Common::{IObjectArray, IObjectCollection};
+    use windows::Win32::UI::Shell::{
+        DestinationList, EnumerableObjectCollection, ICustomDestinationList, IShellLinkW,
+        PropertiesSystem::IPropertyStore, ShellLink,
+    };
+
+    let exe_path = match env::current_exe() {
+        Ok(p) => HSTRING::from(p.to_string_lossy().as_ref()),
+        Err(_) => return,
+    };
+
+    let register = || -> windows::core::Result<()> {
+        unsafe {
+            let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+            let list: ICustomDestinationList =
+                CoCreateInstance(&DestinationList, None, CLSCTX_INPROC_SERVER)?;
+            let mut max_slots = 0u32;
+            let _removed: IObjectArray = list.BeginList(&mut max_slots)?;
+
+            let collection: IObjectCollection =
+                CoCreateInstance(&EnumerableObjectCollection, None, CLSCTX_INPROC_SERVER)?;
+            for (title, args) in tasks {
+                let link: IShellLinkW = CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER)?;
+                link.SetPath(&exe_path)?;
+                link.SetArguments(&HSTRING::from(*args))?;
+                link.SetIconLocation(&exe_path, 0)?;
+
+                // Jump list entries show the title property, not the description
+                let store: IPropertyStore = link.cast()?;
+                store.SetValue(&PKEY_Title, &PROPVARIANT::from(*title))?;
+                store.Commit()?;
+
+                collection.AddObject(&link)?;
+            }
+
+            list.AddUserTasks(&collection.cast::<IObjectArray>()?)?;
+            list.CommitList()
+        }
+    };
+
+    match register() {
+        Ok(()) => tracing::info!("Registered jump list tasks"),
+        Err(e) => tracing::warn!("Failed to register jump list: {}", e),
+    }
+}
+
 /// Show a Windows toast notification
 pub fn show_notification(title: &str, body: &str) {
     if let Err(e) = notify_rust::Notification::new()
@@ -219,6 +283,83 @@ pub fn show_notification(title: &str, body: &str) {
     }
 }
 
+/// The last actionable toast, kept alive so its `Activated` handler stays registered
+static LAST_TOAST: Mutex<Option<windows::UI::Notifications::ToastNotification>> = Mutex::new(None);
+
+/// Escape text for toast XML
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Show a Windows toast notification with buttons.
+/// `on_action` is called from a WinRT thread when a button is clicked.
+pub fn show_actionable_notification(
+    title: &str,
+    body: &str,
+    actions: &[(NotificationAction, &str)],
+    on_action: impl Fn(NotificationAction) + Send + 'static,
+) {
+    use windows::core::{IInspectable, Interface, HSTRING};
+    use windows::Data::Xml::Dom::XmlDocument;
+    use windows::Foundation::TypedEventHandler;
+    use windows::UI::Notifications::{
+        ToastActivatedEventArgs, ToastNotification, ToastNotificationManager,
+    };
+
+    let buttons: String = actions
+        .iter()
+        .map(|(action, label)| {
+            format!(
+                r#"<action content="{}" arguments="{}" activationType="foreground"/>"#,
+                xml_escape(label),
+                action.id()
+            )
+        })
+        .collect();
+    let xml = format!(
+        r#"<toast><visual><binding template="ToastGeneric"><text>{}</text><text>{}</text></binding></visual><actions>{}</actions></toast>"#,
+        xml_escape(title),
+        xml_escape(body),
+        buttons
+    );
+
+    let result = (|| -> windows::core::Result<()> {
+        let doc = XmlDocument::new()?;
+        doc.LoadXml(&HSTRING::from(xml))?;
+        let toast = ToastNotification::CreateToastNotification(&doc)?;
+
+        toast.Activated(&TypedEventHandler::<ToastNotification, IInspectable>::new(
+            move |_, args| {
+                if let Some(args) = args.as_ref() {
+                    let args: ToastActivatedEventArgs = args.cast()?;
+                    let id = args.Arguments()?.to_string_lossy();
+                    if let Some(action) = NotificationAction::from_id(&id) {
+                        on_action(action);
+                    }
+                }
+                Ok(())
+            },
+        ))?;
+
+        ToastNotificationManager::CreateToastNotifierWithId(&HSTRING::from(APP_USER_MODEL_ID))?
+            .Show(&toast)?;
+        if let Ok(mut last) = LAST_TOAST.lock() {
+            *last = Some(toast);
+        }
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        tracing::error!("Failed to show notification: {}", e);
+        // Fall back to a plain toast
+        show_notification(title, body);
+    }
+}
+
 /// Flash the window in taskbar to get user attention
 /// This is called when timer completes to notify the user
 pub fn flash_window(hwnd: isize, count: u32) {
@@ -255,60 +396,133 @@ pub fn stop_flash_window(hwnd: isize) {
     }
 }
 
-/// Flash the PomodoRust window by finding it by title
-/// Returns true if window was found and flashed
-pub fn flash_pomodorust_window(count: u32) -> bool {
+/// Handle of the main window, 0 until the app has started
+static MAIN_WINDOW: AtomicIsize = AtomicIsize::new(0);
+
+/// Remember the main window for the functions acting on it. Its title shows
+/// the countdown during a session, so it can't be looked up by title.
+pub fn set_main_window(hwnd: isize) {
+    MAIN_WINDOW.store(hwnd, Ordering::Relaxed);
+}
+
+/// The main window. Outside the app's own process (a second instance asking
+/// an old one to show itself) it's found by its idle title instead.
+fn main_window() -> Option<HWND> {
     use windows::core::PCWSTR;
     use windows::Win32::UI::WindowsAndMessaging::FindWindowW;
 
-    unsafe {
-        let title: Vec<u16> = "PomodoRust\0".encode_utf16().collect();
-        if let Ok(hwnd) = FindWindowW(PCWSTR::null(), PCWSTR(title.as_ptr())) {
-            if !hwnd.is_invalid() {
-                flash_window(hwnd.0 as isize, count);
-                return true;
+    match MAIN_WINDOW.load(Ordering::Relaxed) {
+        0 => unsafe {
+            let title: Vec<u16> = "PomodoRust\0".encode_utf16().collect();
+            FindWindowW(PCWSTR::null(), PCWSTR(title.as_ptr()))
+                .ok()
+                .filter(|hwnd| !hwnd.is_invalid())
+        },
+        hwnd => Some(HWND(hwnd as *mut std::ffi::c_void)),
+    }
+}
+
+/// Flash the PomodoRust window
+/// Returns true if window was found and flashed
+pub fn flash_pomodorust_window(count: u32) -> bool {
+    let Some(hwnd) = main_window() else {
+        return false;
+    };
+    flash_window(hwnd.0 as isize, count);
+    true
+}
+
+thread_local! {
+    /// Taskbar COM object, created lazily on the UI thread (apartment-threaded)
+    static TASKBAR_LIST: RefCell<Option<ITaskbarList3>> = const { RefCell::new(None) };
+}
+
+/// Show timer progress on the PomodoRust taskbar button
+pub fn set_taskbar_progress(progress: TaskbarProgress) {
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED,
+    };
+    use windows::Win32::UI::Shell::{
+        TaskbarList, TBPF_ERROR, TBPF_NOPROGRESS, TBPF_NORMAL, TBPF_PAUSED,
+    };
+
+    let Some(hwnd) = main_window() else {
+        return;
+    };
+
+    TASKBAR_LIST.with(|cell| {
+        let mut taskbar = cell.borrow_mut();
+        if taskbar.is_none() {
+            *taskbar = unsafe {
+                let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+                CoCreateInstance::<_, ITaskbarList3>(&TaskbarList, None, CLSCTX_INPROC_SERVER)
+                    .and_then(|list| list.HrInit().map(|_| list))
+                    .map_err(|e| tracing::warn!("Taskbar progress unavailable: {}", e))
+                    .ok()
+            };
+        }
+        let Some(taskbar) = taskbar.as_ref() else {
+            return;
+        };
+
+        let (state, value) = match progress {
+            TaskbarProgress::None => (TBPF_NOPROGRESS, None),
+            TaskbarProgress::Running(permille) => (TBPF_NORMAL, Some(permille)),
+            TaskbarProgress::Paused(permille) => (TBPF_PAUSED, Some(permille)),
+            TaskbarProgress::Completed => (TBPF_ERROR, Some(1000)),
+        };
+
+        unsafe {
+            // Setting a value switches to the normal state, so set the state last
+            if let Some(permille) = value {
+                let _ = taskbar.SetProgressValue(hwnd, permille as u64, 1000);
             }
+            let _ = taskbar.SetProgressState(hwnd, state);
         }
+    });
+}
+
+/// Let the running instance take the foreground when asked to show itself
+/// Windows only lets the process that owns the foreground pass it on
+pub fn allow_set_foreground_window() {
+    use windows::Win32::UI::WindowsAndMessaging::{AllowSetForegroundWindow, ASFW_ANY};
+    unsafe {
+        let _ = AllowSetForegroundWindow(ASFW_ANY);
     }
-    false
 }
 
 /// Show and bring the PomodoRust window to foreground
 /// Returns true if window was found and shown
 pub fn show_pomodorust_window() -> bool {
-    use windows::core::PCWSTR;
     use windows::Win32::UI::WindowsAndMessaging::{
-        FindWindowW, GetWindowLongPtrW, SetForegroundWindow, SetWindowLongPtrW, SetWindowPos,
-        GWL_EXSTYLE, HWND_NOTOPMOST, HWND_TOPMOST, SWP_FRAMECHANGED, SWP_NOSIZE, SWP_SHOWWINDOW,
+        GetWindowLongPtrW, SetForegroundWindow, SetWindowLongPtrW, SetWindowPos, GWL_EXSTYLE,
+        HWND_NOTOPMOST, HWND_TOPMOST, SWP_FRAMECHANGED, SWP_NOSIZE, SWP_SHOWWINDOW,
         WS_EX_TOOLWINDOW,
     };
 
+    let Some(hwnd) = main_window() else {
+        tracing::warn!("Could not find PomodoRust window");
+        return false;
+    };
     unsafe {
-        let title: Vec<u16> = "PomodoRust\0".encode_utf16().collect();
-        if let Ok(hwnd) = FindWindowW(PCWSTR::null(), PCWSTR(title.as_ptr())) {
-            if !hwnd.is_invalid() {
-                // Remove WS_EX_TOOLWINDOW to restore taskbar entry
-                let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
-                SetWindowLongPtrW(hwnd, GWL_EXSTYLE, ex_style & !(WS_EX_TOOLWINDOW.0 as isize));
-                // Restore saved position (or use 100,100 as fallback)
-                let (x, y) = SAVED_WINDOW_POS
-                    .lock()
-                    .ok()
-                    .and_then(|pos| *pos)
-                    .unwrap_or((100, 100));
-                let flags = SWP_NOSIZE | SWP_SHOWWINDOW | SWP_FRAMECHANGED;
-                // Move back to saved position + bring to front
-                let _ = SetWindowPos(hwnd, HWND_TOPMOST, x, y, 0, 0, flags);
-                let _ = SetWindowPos(hwnd, HWND_NOTOPMOST, x, y, 0, 0, flags);
-                let _ = SetForegroundWindow(hwnd);
-
-                tracing::info!("Restored PomodoRust window to ({}, {})", x, y);
-                return true;
-            }
-        }
+        // Remove WS_EX_TOOLWINDOW to restore taskbar entry
+        let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, ex_style & !(WS_EX_TOOLWINDOW.0 as isize));
+        // Restore saved position (or use 100,100 as fallback)
+        let (x, y) = SAVED_WINDOW_POS
+            .lock()
+            .ok()
+            .and_then(|pos| *pos)
+            .unwrap_or((100, 100));
+        let flags = SWP_NOSIZE | SWP_SHOWWINDOW | SWP_FRAMECHANGED;
+        // Move back to saved position + bring to front
+        let _ = SetWindowPos(hwnd, HWND_TOPMOST, x, y, 0, 0, flags);
+        let _ = SetWindowPos(hwnd, HWND_NOTOPMOST, x, y, 0, 0, flags);
+        let _ = SetForegroundWindow(hwnd);
+
+        tracing::info!("Restored PomodoRust window to ({}, {})", x, y);
     }
-    tracing::warn!("Could not find PomodoRust window");
-    false
+    true
 }
 
 /// Saved window position before hiding (to restore on show)
@@ -318,40 +532,37 @@ static SAVED_WINDOW_POS: Mutex<Option<(i32, i32)>> = Mutex::new(None);
 /// Unlike `ShowWindow(SW_HIDE)`, this keeps the window "visible" to Windows
 /// so `WM_PAINT` messages continue and eframe's `update()` keeps running.
 pub fn hide_pomodorust_window() {
-    use windows::core::PCWSTR;
     use windows::Win32::Foundation::RECT;
     use windows::Win32::UI::WindowsAndMessaging::{
-        FindWindowW, GetWindowLongPtrW, GetWindowRect, SetWindowLongPtrW, SetWindowPos,
-        GWL_EXSTYLE, SWP_FRAMECHANGED, SWP_NOACTIVATE, SWP_NOSIZE, SWP_NOZORDER, WS_EX_TOOLWINDOW,
+        GetWindowLongPtrW, GetWindowRect, SetWindowLongPtrW, SetWindowPos, GWL_EXSTYLE,
+        SWP_FRAMECHANGED, SWP_NOACTIVATE, SWP_NOSIZE, SWP_NOZORDER, WS_EX_TOOLWINDOW,
     };
 
+    let Some(hwnd) = main_window() else {
+        return;
+    };
     unsafe {
-        let title: Vec<u16> = "PomodoRust\0".encode_utf16().collect();
-        if let Ok(hwnd) = FindWindowW(PCWSTR::null(), PCWSTR(title.as_ptr())) {
-            if !hwnd.is_invalid() {
-                // Save current position
-                let mut rect = RECT::default();
-                if GetWindowRect(hwnd, &mut rect).is_ok() {
-                    if let Ok(mut pos) = SAVED_WINDOW_POS.lock() {
-                        *pos = Some((rect.left, rect.top));
-                    }
-                }
-                // Add WS_EX_TOOLWINDOW to hide from taskbar
-                let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
-                SetWindowLongPtrW(hwnd, GWL_EXSTYLE, ex_style | WS_EX_TOOLWINDOW.0 as isize);
-                // Move off-screen (window stays "visible" — WM_PAINT keeps working)
-                let _ = SetWindowPos(
-                    hwnd,
-                    HWND(std::ptr::null_mut()),
-                    -32000,
-                    -32000,
-                    0,
-                    0,
-                    SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE | SWP_FRAMECHANGED,
-                );
-                tracing::info!("Hid window off-screen (removed from taskbar)");
+        // Save current position
+        let mut rect = RECT::default();
+        if GetWindowRect(hwnd, &mut rect).is_ok() {
+            if let Ok(mut pos) = SAVED_WINDOW_POS.lock() {
+                *pos = Some((rect.left, rect.top));
             }
         }
+        // Add WS_EX_TOOLWINDOW to hide from taskbar
+        let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+        SetWindowLongPtrW(hwnd, GWL_EXSTYLE, ex_style | WS_EX_TOOLWINDOW.0 as isize);
+        // Move off-screen (window stays "visible" — WM_PAINT keeps working)
+        let _ = SetWindowPos(
+            hwnd,
+            HWND(std::ptr::null_mut()),
+            -32000,
+            -32000,
+            0,
+            0,
+            SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE | SWP_FRAMECHANGED,
+        );
+        tracing::info!("Hid window off-screen (removed from taskbar)");
     }
 }
 
@@ -420,3 +631,97 @@ pub fn remove_autostart() -> Result<(), PlatformError> {
     tracing::info!("Disabled autostart");
     Ok(())
 }
+
+/// WNF state name of the active Focus Assist profile
+/// (`WNF_SHEL_QUIETHOURS_ACTIVE_PROFILE_CHANGED`)
+const WNF_FOCUS_ASSIST: u64 = 0x0D83_063E_A3BF_1C75;
+
+/// Focus Assist profiles: off, priority only, alarms only
+const FOCUS_ASSIST_PRIORITY_ONLY: u32 = 1;
+
+/// Focus Assist profile from before we turned it on
+static FOCUS_ASSIST_PREVIOUS: Mutex<Option<u32>> = Mutex::new(None);
+
+// Focus Assist has no public API; the shell itself toggles it through these
+// undocumented ntdll exports
+#[link(name = "ntdll")]
+extern "system" {
+    fn NtQueryWnfStateData(
+        state_name: *const u64,
+        type_id: *const std::ffi::c_void,
+        explicit_scope: *const std::ffi::c_void,
+        change_stamp: *mut u32,
+        buffer: *mut std::ffi::c_void,
+        buffer_size: *mut u32,
+    ) -> i32;
+    fn NtUpdateWnfStateData(
+        state_name: *const u64,
+        buffer: *const std::ffi::c_void,
+        length: u32,
+        type_id: *const std::ffi::c_void,
+        explicit_scope: *const std::ffi::c_void,
+        matching_change_stamp: u32,
+        check_stamp: u32,
+    ) -> i32;
+}
+
+/// Read the active Focus Assist profile
+fn focus_assist_profile() -> Option<u32> {
+    let mut profile = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    let mut stamp = 0u32;
+    let status = unsafe {
+        NtQueryWnfStateData(
+            &WNF_FOCUS_ASSIST,
+            std::ptr::null(),
+            std::ptr::null(),
+            &mut stamp,
+            &mut profile as *mut u32 as *mut _,
+            &mut size,
+        )
+    };
+    (status >= 0).then_some(profile)
+}
+
+/// Switch the active Focus Assist profile
+fn set_focus_assist_profile(profile: u32) -> bool {
+    let status = unsafe {
+        NtUpdateWnfStateData(
+            &WNF_FOCUS_ASSIST,
+            &profile as *const u32 as *const _,
+            std::mem::size_of::<u32>() as u32,
+            std::ptr::null(),
+            std::ptr::null(),
+            0,
+            0,
+        )
+    };
+    status >= 0
+}
+
+/// Turn Focus Assist on (priority only), or restore the profile that was
+/// active before it was turned on
+pub fn set_do_not_disturb(active: bool) {
+    let mut previous = FOCUS_ASSIST_PREVIOUS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+
+    if active {
+        if previous.is_some() {
+            return;
+        }
+        let Some(profile) = focus_assist_profile() else {
+            tracing::warn!("Failed to read Focus Assist state");
+            return;
+        };
+        if profile == 0 && !set_focus_assist_profile(FOCUS_ASSIST_PRIORITY_ONLY) {
+            tracing::warn!("Failed to enable Focus Assist");
+            return;
+        }
+        *previous = Some(profile);
+    } else if let Some(profile) = previous.take() {
+        if profile == 0 && !set_focus_assist_profile(profile) {
+            tracing::warn!("Failed to restore Focus Assist");
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Terminal client
+//!
+//! `pomodorust tui` shows the running app's timer full-screen in the
+//! terminal: the remaining time in large digits, progress, the sessions of
+//! the cycle and this week's focus hours, with keys to control the timer.
+//! Everything goes through IPC like the other CLI commands, so it works
+//! over SSH and without a desktop.
+
+use std::io;
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Gauge, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::i18n::tr;
+use crate::ipc::{send_command, IpcCommand, IpcResponse, IpcStats, IpcStatus};
+
+/// How often the status is asked for, and keys are checked
+const REFRESH: Duration = Duration::from_millis(250);
+
+/// How often the week chart is refreshed besides session changes
+const STATS_REFRESH: Duration = Duration::from_secs(30);
+
+/// What a key press does
+#[derive(Debug)]
+enum Action {
+    Quit,
+    Send(IpcCommand),
+}
+
+/// Latest replies from the app
+#[derive(Default)]
+struct View {
+    status: Option<Result<IpcStatus, String>>,
+    stats: Option<IpcStats>,
+    stats_at: Option<Instant>,
+}
+
+/// Run the terminal client until the user quits
+pub fn run() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal) -> io::Result<()> {
+    let mut view = View::default();
+    loop {
+        view.refresh();
+        terminal.draw(|frame| view.draw(frame))?;
+
+        if !event::poll(REFRESH)? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            match action(key) {
+                Some(Action::Quit) => return Ok(()),
+                Some(Action::Send(command)) => {
+                    if let Err(e) = send_command(&command) {
+                        view.status = Some(Err(e));
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+fn action(key: KeyEvent) -> Option<Action> {
+    if key.kind != KeyEventKind::Press {
+        return None;
+    }
+    let action = match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::Quit,
+        KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+        KeyCode::Char(' ') | KeyCode::Enter => Action::Send(IpcCommand::Toggle),
+        KeyCode::Char('s') => Action::Send(IpcCommand::Skip),
+        KeyCode::Char('x') => Action::Send(IpcCommand::Stop),
+        _ => return None,
+    };
+    Some(action)
+}
+
+impl View {
+    /// Ask for the status, and for the week's stats when they're stale or a
+    /// session has ended
+    fn refresh(&mut self) {
+        let previous = self.status.as_ref().and_then(|s| s.as_ref().ok());
+        let status = match send_command(&IpcCommand::Status) {
+            Ok(IpcResponse::Status(status)) => Ok(status),
+            Ok(IpcResponse::Error { message }) => Err(message),
+            Ok(_) => Err("Unexpected response".to_string()),
+            Err(e) => Err(e),
+        };
+        let session_changed = match (previous, &status) {
+            (Some(old), Ok(new)) => {
+                old.session_type != new.session_type || old.current_session != new.current_session
+            }
+            _ => false,
+        };
+        if status.is_ok()
+            && (session_changed || self.stats_at.is_none_or(|at| at.elapsed() >= STATS_REFRESH))
+        {
+            let command = IpcCommand::Stats {
+                period: "week".to_string(),
+                from: None,
+                to: None,
+            };
+            if let Ok(IpcResponse::Stats(stats)) = send_command(&command) {
+                self.stats = Some(stats);
+            }
+            self.stats_at = Some(Instant::now());
+        }
+        self.status = Some(status);
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let t = &tr().cli;
+        let [main, week, keys] = Layout::vertical([
+            Constraint::Length(10),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        match &self.status {
+            Some(Ok(status)) => draw_timer(frame, main, status),
+            Some(Err(e)) => frame.render_widget(
+                Paragraph::new(format!("{}: {}", t.error, e))
+                    .alignment(Alignment::Center)
+                    .red()
+                    .block(Block::bordered()),
+                main,
+            ),
+            None => {}
+        }
+        if let Some(stats) = &self.stats {
+            draw_week(frame, week, stats);
+        }
+        frame.render_widget(
+            Paragraph::new(t.tui_keys)
+                .alignment(Alignment::Center)
+                .dark_gray(),
+            keys,
+        );
+    }
+}
+
+/// Color of a session type, as in the app's default theme
+fn session_color(session_type: &str) -> Color {
+    match session_type {
+        "short_break" => Color::Green,
+        "long_break" => Color::Blue,
+        _ => Color::Red,
+    }
+}
+
+fn draw_timer(frame: &mut Frame, area: Rect, status: &IpcStatus) {
+    let t = &tr().cli;
+    let color = session_color(&status.session_type);
+    let block = Block::bordered().border_style(Style::new().fg(color));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [title, _, digits, _, gauge, dots] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(3),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let label = match status.session_type.as_str() {
+        "short_break" => t.short_break,
+        "long_break" => t.long_break,
+        _ => t.focus,
+    };
+    let mut heading = vec![Span::styled(
+        label.to_uppercase(),
+        Style::new().fg(color).add_modifier(Modifier::BOLD),
+    )];
+    if let Some(tag) = &status.tag {
+        heading.push(Span::raw(format!("  #{}", tag)));
+    }
+    if status.state == "paused" {
+        heading.push(Span::raw(format!("  ({})", t.paused)).yellow());
+    }
+    frame.render_widget(Line::from(heading).centered(), title);
+
+    let big = big_text(&status.remaining_formatted);
+    let lines: Vec<Line> = big.into_iter().map(Line::from).collect();
+    let mut digits_style = Style::new().fg(color);
+    if status.state == "paused" {
+        digits_style = digits_style.add_modifier(Modifier::DIM);
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .style(digits_style),
+        digits,
+    );
+
+    let [_, gauge, _] = Layout::horizontal([
+        Constraint::Percentage(15),
+        Constraint::Percentage(70),
+        Constraint::Percentage(15),
+    ])
+    .areas(gauge);
+    frame.render_widget(
+        Gauge::default()
+            .gauge_style(Style::new().fg(color))
+            .ratio(f64::from(status.progress.clamp(0.0, 1.0)))
+            .label(""),
+        gauge,
+    );
+
+    frame.render_widget(
+        Line::from(format!(
+            "{}  {} {}/{}",
+            session_dots(status.current_session, status.total_sessions),
+            t.session,
+            status.current_session,
+            status.total_sessions
+        ))
+        .centered(),
+        dots,
+    );
+}
+
+fn draw_week(frame: &mut Frame, area: Rect, stats: &IpcStats) {
+    if stats.daily_hours.is_empty() || area.height < 4 {
+        return;
+    }
+    let block = Block::bordered().title(format!(
+        " {} · {:.1} h ",
+        tr().stats.week_activity,
+        stats.hours
+    ));
+    let inner = block.inner(area);
+    let gap = 1;
+    let bar_width = (inner.width.saturating_sub(gap * 6) / 7).clamp(1, 9);
+
+    let bars: Vec<Bar> = tr()
+        .days_of_week()
+        .iter()
+        .zip(&stats.daily_hours)
+        .map(|(day, hours)| {
+            Bar::default()
+                // Bars hold integers, so they're sized in minutes
+                .value((hours * 60.0).round() as u64)
+                .text_value(format!("{:.1}", hours))
+                .label(Line::from(*day))
+                .style(Style::new().fg(Color::Red))
+                .value_style(Style::new().fg(Color::Black).bg(Color::Red))
+        })
+        .collect();
+
+    let width = bar_width * 7 + gap * 6;
+    let [_, chart, _] = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(width + 2),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+    frame.render_widget(
+        BarChart::default()
+            .block(block)
+            .bar_width(bar_width)
+            .bar_gap(gap)
+            .data(BarGroup::default().bars(&bars)),
+        chart,
+    );
+}
+
+/// Sessions of the cycle: done, current and to come
+fn session_dots(current: u32, total: u32) -> String {
+    (1..=total.max(current))
+        .map(|n| match n.cmp(&current) {
+            std::cmp::Ordering::Less => "●",
+            std::cmp::Ordering::Equal => "◉",
+            std::cmp::Ordering::Greater => "○",
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Rows of a three-line digit font
+const GLYPHS: [[&str; 3]; 10] = [
+    ["█▀█", "█ █", "▀▀▀"],
+    [" ▀█", "  █", "  ▀"],
+    ["▀▀█", "█▀▀", "▀▀▀"],
+    ["▀▀█", " ▀█", "▀▀▀"],
+    ["█ █", "▀▀█", "  ▀"],
+    ["█▀▀", "▀▀█", "▀▀▀"],
+    ["█▀▀", "█▀█", "▀▀▀"],
+    ["▀▀█", "  █", "  ▀"],
+    ["█▀█", "█▀█", "▀▀▀"],
+    ["█▀█", "▀▀█", "▀▀▀"],
+];
+
+/// A time like `24:59` in large digits, as three rows of text
+fn big_text(text: &str) -> [String; 3] {
+    let mut rows: [String; 3] = Default::default();
+    for (i, c) in text.chars().enumerate() {
+        let glyph = match c {
+            '0'..='9' => GLYPHS[c as usize - '0' as usize],
+            ':' => ["▄", "▄", " "],
+            _ => [" ", " ", " "],
+        };
+        for (row, part) in rows.iter_mut().zip(glyph) {
+            if i > 0 {
+                row.push(' ');
+            }
+            row.push_str(part);
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action() {
+        let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert!(matches!(
+            action(press(KeyCode::Char(' '))),
+            Some(Action::Send(IpcCommand::Toggle))
+        ));
+        assert!(matches!(
+            action(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Action::Quit)
+        ));
+        assert!(action(press(KeyCode::Char('z'))).is_none());
+    }
+
+    #[test]
+    fn test_session_dots() {
+        assert_eq!(session_dots(2, 4), "● ◉ ○ ○");
+        assert_eq!(session_dots(1, 1), "◉");
+    }
+
+    #[test]
+    fn test_big_text() {
+        let rows = big_text("1:05");
+        assert_eq!(rows[0], " ▀█ ▄ █▀█ █▀▀");
+        assert_eq!(rows[2], "  ▀   ▀▀▀ ▀▀▀");
+        assert!(rows.iter().all(|row| row.chars().count() == 13));
+    }
+}
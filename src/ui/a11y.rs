@@ -0,0 +1,45 @@
+//! Accessibility helpers for the custom painted widgets
+//!
+//! Painted widgets don't describe themselves, so they attach a role, a label
+//! and a value to their response here. egui turns this into its AccessKit
+//! tree for screen readers (NVDA, Orca, VoiceOver); the tree reaches the OS
+//! when eframe is built with its `accesskit` feature.
+
+use egui::output::OutputEvent;
+use egui::{Id, Response, WidgetInfo, WidgetType};
+
+/// Describe a painted widget with a role and a label
+pub fn describe(response: &Response, typ: WidgetType, label: &str) {
+    response.widget_info(|| WidgetInfo::labeled(typ, response.enabled(), label));
+}
+
+/// Describe a progress indicator, `progress` goes from 0.0 to 1.0
+pub fn describe_progress(response: &Response, label: &str, progress: f32) {
+    response.widget_info(|| WidgetInfo {
+        value: Some((progress as f64 * 100.0).round()),
+        ..WidgetInfo::labeled(WidgetType::ProgressIndicator, true, label)
+    });
+}
+
+/// Announce the widget's text whenever it changes
+///
+/// Used for the session label so a screen reader says "Short break" when a
+/// focus session ends, without moving the focus.
+pub fn announce_changes(response: &Response, text: &str) {
+    let id = Id::new("a11y_announced").with(response.id);
+    let previous: Option<String> = response.ctx.data(|d| d.get_temp(id));
+    if previous.as_deref() == Some(text) {
+        return;
+    }
+    response
+        .ctx
+        .data_mut(|d| d.insert_temp(id, text.to_string()));
+    // Nothing to announce on the first frame
+    if previous.is_some() {
+        response.output_event(OutputEvent::ValueChanged(WidgetInfo::labeled(
+            WidgetType::Label,
+            true,
+            text,
+        )));
+    }
+}
@@ -283,28 +283,20 @@ impl AnimationState {
         self.last_update = now;
         self.timer_running = timer_running;
 
-        // Timer pulse (1.5 second cycle when running)
+        // Timer pulse (1.5 second cycle when running). Frames can be seconds
+        // apart while the window is in the background, so wrap with fract.
         if timer_running {
-            self.timer_pulse += dt / 1.5;
-            if self.timer_pulse > 1.0 {
-                self.timer_pulse -= 1.0;
-            }
+            self.timer_pulse = (self.timer_pulse + dt / 1.5).fract();
         } else {
             self.timer_pulse = (self.timer_pulse - dt * 3.0).max(0.0);
         }
 
         // Background glow (4 second cycle, always active but subtle)
-        self.glow_phase += dt / 4.0;
-        if self.glow_phase > 1.0 {
-            self.glow_phase -= 1.0;
-        }
+        self.glow_phase = (self.glow_phase + dt / 4.0).fract();
 
         // Breathing animation (3 second cycle, only when idle)
         if !timer_running {
-            self.breathe_phase += dt / 3.0;
-            if self.breathe_phase > 1.0 {
-                self.breathe_phase -= 1.0;
-            }
+            self.breathe_phase = (self.breathe_phase + dt / 3.0).fract();
         } else {
             self.breathe_phase = 0.0;
         }
@@ -336,11 +328,15 @@ impl AnimationState {
         t.sin() * 0.3 + 0.7 // Range: 0.4 to 1.0
     }
 
-    pub fn needs_repaint(&self) -> bool {
-        self.timer_pulse > 0.0
-            || self.breathe_phase > 0.0
-            || self.progress_anim.is_animating()
-            || self.view_transition.is_animating()
+    /// Whether the timer pulse is showing, which needs every frame
+    pub fn is_pulsing(&self) -> bool {
+        self.timer_running
+    }
+
+    /// Whether a transition is under way, which needs every frame until it
+    /// ends
+    pub fn is_transitioning(&self) -> bool {
+        self.progress_anim.is_animating() || self.view_transition.is_animating()
     }
 
     pub fn set_progress(&mut self, progress: f32) {
@@ -247,6 +247,48 @@ impl AsciiBox {
     }
 }
 
+/// Bar chart drawn with braille dots, four dot rows per line
+pub struct AsciiSparkline;
+
+impl AsciiSparkline {
+    /// Braille bits lighting both dot columns of a cell row, top to bottom
+    const ROW_BITS: [u32; 4] = [0x01 | 0x08, 0x02 | 0x10, 0x04 | 0x20, 0x40 | 0x80];
+
+    /// Render one bar per value, `bar_width` cells wide with a space between
+    /// bars. Returns `rows` lines, top first; the tallest value fills them all.
+    pub fn render(values: &[f32], rows: usize, bar_width: usize) -> Vec<String> {
+        let max = values.iter().cloned().fold(0.0_f32, f32::max);
+        let levels: Vec<usize> = values
+            .iter()
+            .map(|&value| {
+                if max > 0.0 && value > 0.0 {
+                    // Anything above zero gets at least one dot
+                    ((value / max * (rows * 4) as f32).round() as usize).max(1)
+                } else {
+                    0
+                }
+            })
+            .collect();
+
+        (0..rows)
+            .map(|row| {
+                // Dots below this line in every column
+                let below = (rows - 1 - row) * 4;
+                levels
+                    .iter()
+                    .map(|&level| {
+                        let lit = level.saturating_sub(below).min(4);
+                        let bits: u32 = Self::ROW_BITS[4 - lit..].iter().sum();
+                        let cell = char::from_u32(0x2800 + bits).unwrap_or(' ');
+                        cell.to_string().repeat(bar_width)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+}
+
 /// ASCII spinner animation frames
 pub struct AsciiSpinner;
 
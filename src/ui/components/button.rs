@@ -1,8 +1,11 @@
 //! Custom button components with gradients and animations
 
-use egui::{vec2, Color32, CursorIcon, Rect, Response, Rounding, Sense, Stroke, Ui, Vec2};
+use egui::{
+    vec2, Color32, CursorIcon, Rect, Response, Rounding, Sense, Stroke, Ui, Vec2, WidgetType,
+};
 
 use super::icons::{draw_icon, Icon};
+use crate::ui::a11y;
 use crate::ui::animations::InteractionState;
 use crate::ui::theme::Theme;
 
@@ -43,6 +46,7 @@ impl GradientButton {
 
     pub fn show(mut self, ui: &mut Ui, theme: &Theme) -> Response {
         let (rect, response) = ui.allocate_exact_size(self.size, Sense::click());
+        a11y::describe(&response, WidgetType::Button, &self.text);
 
         self.state
             .update(response.hovered(), response.is_pointer_button_down_on());
@@ -120,6 +124,8 @@ pub struct IconButton {
     custom_gradient: Option<(Color32, Color32)>,
     is_light_mode: bool,
     opacity: f32,
+    /// Name read by screen readers
+    label: String,
 }
 
 impl IconButton {
@@ -133,6 +139,7 @@ impl IconButton {
             custom_gradient: None,
             is_light_mode: false,
             opacity: 1.0,
+            label: String::new(),
         }
     }
 
@@ -170,8 +177,15 @@ impl IconButton {
         self
     }
 
+    /// Set the name screen readers announce for the button
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
     pub fn show(mut self, ui: &mut Ui, theme: &Theme) -> Response {
         let (rect, response) = ui.allocate_exact_size(vec2(self.size, self.size), Sense::click());
+        a11y::describe(&response, WidgetType::Button, &self.label);
 
         self.state
             .update(response.hovered(), response.is_pointer_button_down_on());
@@ -3,6 +3,7 @@
 use egui::{vec2, Color32, Pos2, Rect, Stroke, Ui};
 use std::f32::consts::{PI, TAU};
 
+use crate::ui::a11y;
 use crate::ui::theme::Theme;
 
 /// A circular progress ring with gradient and animations
@@ -21,6 +22,12 @@ pub struct CircularProgress {
     bg_color: Color32,
     /// Pulse intensity (0.0 to 1.0)
     pulse: f32,
+    /// Name read by screen readers
+    label: String,
+    /// Values a full turn is split into when dragging, 0 when not draggable
+    drag_steps: u32,
+    /// Tooltip shown while the ring can be dragged
+    drag_hint: String,
 }
 
 impl CircularProgress {
@@ -33,6 +40,9 @@ impl CircularProgress {
             end_color: Color32::from_rgb(139, 92, 246),   // violet-500
             bg_color: Color32::from_rgb(39, 39, 42),      // zinc-800
             pulse: 0.0,
+            label: String::new(),
+            drag_steps: 0,
+            drag_hint: String::new(),
         }
     }
 
@@ -62,14 +72,52 @@ impl CircularProgress {
         self
     }
 
-    pub fn show(&self, ui: &mut Ui, center_content: impl FnOnce(&mut Ui)) {
+    /// Set the name screen readers announce with the progress
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Let the ring be dragged around like a clock hand to pick a value from
+    /// 1 to `steps`, a full turn being `steps`. Zero keeps it read-only.
+    pub fn with_drag_steps(mut self, steps: u32, hint: impl Into<String>) -> Self {
+        self.drag_steps = steps;
+        self.drag_hint = hint.into();
+        self
+    }
+
+    /// Draw the ring, returns the picked value while it is being dragged
+    pub fn show(&mut self, ui: &mut Ui, center_content: impl FnOnce(&mut Ui)) -> Option<u32> {
         let size = vec2(
             self.radius * 2.0 + self.thickness,
             self.radius * 2.0 + self.thickness,
         );
-        let (rect, _response) = ui.allocate_exact_size(size, egui::Sense::hover());
+        let sense = if self.drag_steps > 0 {
+            egui::Sense::drag()
+        } else {
+            egui::Sense::hover()
+        };
+        let (rect, response) = ui.allocate_exact_size(size, sense);
+        a11y::describe_progress(&response, &self.label, self.progress);
 
         let center = rect.center();
+        let dragged = self.drag_value(ui, &response, center);
+        if let Some(value) = dragged {
+            // Show the picked value on the ring while dragging
+            self.progress = value as f32 / self.drag_steps as f32;
+        }
+        if self.drag_steps > 0 {
+            let cursor = if response.dragged() {
+                egui::CursorIcon::Grabbing
+            } else {
+                egui::CursorIcon::Grab
+            };
+            let response = response.on_hover_cursor(cursor);
+            if !response.dragged() {
+                response.on_hover_text(&self.drag_hint);
+            }
+        }
+
         let outer_radius = self.radius;
         let inner_radius = self.radius - self.thickness;
 
@@ -137,6 +185,36 @@ impl CircularProgress {
                 },
             );
         });
+
+        dragged
+    }
+
+    /// Value under the pointer while the ring is dragged, snapped to steps
+    fn drag_value(&self, ui: &Ui, response: &egui::Response, center: Pos2) -> Option<u32> {
+        let id = response.id.with("drag_value");
+        if response.drag_stopped() {
+            ui.data_mut(|d| d.remove::<u32>(id));
+        }
+        if self.drag_steps == 0 || !response.dragged() {
+            return None;
+        }
+        let pos = response.interact_pointer_pos()?;
+        let offset = pos - center;
+        // Angle from the top going clockwise, as a fraction of a turn
+        let turn = (offset.y.atan2(offset.x) + PI / 2.0).rem_euclid(TAU) / TAU;
+        let value = drag_step(turn, self.drag_steps);
+
+        // Crossing the top doesn't jump between the ends, it stops there
+        let previous = ui.data(|d| d.get_temp::<u32>(id));
+        let value = match previous {
+            Some(prev) if prev > self.drag_steps * 3 / 4 && value < self.drag_steps / 4 => {
+                self.drag_steps
+            }
+            Some(prev) if prev < self.drag_steps / 4 && value > self.drag_steps * 3 / 4 => 1,
+            _ => value,
+        };
+        ui.data_mut(|d| d.insert_temp(id, value));
+        Some(value)
     }
 
     /// Draw a ring (or arc) using mesh - same method for both background and progress
@@ -286,3 +364,25 @@ impl CircularProgress {
         }
     }
 }
+
+/// Fraction of a turn snapped to one of `steps` values, from 1 to `steps`
+fn drag_step(turn: f32, steps: u32) -> u32 {
+    ((turn * steps as f32).round() as u32).clamp(1, steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_step_snaps_to_nearest() {
+        assert_eq!(drag_step(0.25, 60), 15);
+        assert_eq!(drag_step(17.4 / 60.0, 60), 17);
+    }
+
+    #[test]
+    fn test_drag_step_never_zero() {
+        assert_eq!(drag_step(0.0, 60), 1);
+        assert_eq!(drag_step(0.999, 60), 60);
+    }
+}
@@ -0,0 +1,198 @@
+//! Donut chart showing the share of each slice in a total
+
+use egui::{epaint::Mesh, vec2, Color32, Pos2, Response, Ui};
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::ui::a11y;
+
+/// One slice of a donut chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonutSlice {
+    pub label: String,
+    pub value: f32,
+    pub color: Color32,
+}
+
+/// A ring split into slices proportional to their values, starting at the
+/// top and going clockwise. Hovering a slice shows its label and share.
+pub struct DonutChart<'a> {
+    slices: &'a [DonutSlice],
+    radius: f32,
+    thickness: f32,
+    bg_color: Color32,
+    center_text: Option<(String, Color32)>,
+}
+
+impl<'a> DonutChart<'a> {
+    pub fn new(slices: &'a [DonutSlice]) -> Self {
+        Self {
+            slices,
+            radius: 48.0,
+            thickness: 14.0,
+            bg_color: Color32::from_rgb(39, 39, 42), // zinc-800
+            center_text: None,
+        }
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Ring color when there is nothing to show
+    pub fn with_bg_color(mut self, color: Color32) -> Self {
+        self.bg_color = color;
+        self
+    }
+
+    /// Text drawn in the hole of the donut
+    pub fn with_center_text(mut self, text: impl Into<String>, color: Color32) -> Self {
+        self.center_text = Some((text.into(), color));
+        self
+    }
+
+    /// Share of each slice in the total, 0.0 to 1.0
+    fn shares(&self) -> Vec<f32> {
+        let total: f32 = self.slices.iter().map(|s| s.value.max(0.0)).sum();
+        self.slices
+            .iter()
+            .map(|s| {
+                if total > 0.0 {
+                    s.value.max(0.0) / total
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    pub fn show(&self, ui: &mut Ui) -> Response {
+        let size = vec2(self.radius * 2.0 + 6.0, self.radius * 2.0 + 6.0);
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+        let center = rect.center();
+        let inner = self.radius - self.thickness;
+        let shares = self.shares();
+
+        let summary = self
+            .slices
+            .iter()
+            .zip(&shares)
+            .map(|(slice, share)| format!("{} {:.0}%", slice.label, share * 100.0))
+            .collect::<Vec<_>>()
+            .join(", ");
+        a11y::describe(&response, egui::WidgetType::Label, &summary);
+
+        // Slice under the pointer, by angle from the top going clockwise
+        let hovered = response.hover_pos().and_then(|pos| {
+            let offset = pos - center;
+            let distance = offset.length();
+            if distance < inner || distance > self.radius + 3.0 {
+                return None;
+            }
+            let angle = (offset.y.atan2(offset.x) + FRAC_PI_2).rem_euclid(TAU) / TAU;
+            let mut start = 0.0;
+            shares.iter().position(|&share| {
+                start += share;
+                angle < start
+            })
+        });
+
+        if shares.iter().all(|&share| share <= 0.0) {
+            fill_arc(ui, center, self.radius, inner, 0.0, 1.0, self.bg_color);
+        } else {
+            let mut start = 0.0;
+            for (i, (slice, &share)) in self.slices.iter().zip(&shares).enumerate() {
+                // Hovered slice pops out a little
+                let outer = if hovered == Some(i) {
+                    self.radius + 3.0
+                } else {
+                    self.radius
+                };
+                fill_arc(ui, center, outer, inner, start, start + share, slice.color);
+                start += share;
+            }
+        }
+
+        if let Some((text, color)) = &self.center_text {
+            ui.painter().text(
+                center,
+                egui::Align2::CENTER_CENTER,
+                text,
+                egui::FontId::proportional((inner * 0.45).clamp(10.0, 16.0)),
+                *color,
+            );
+        }
+
+        match hovered {
+            Some(i) => response.on_hover_text(format!(
+                "{}: {:.0}%",
+                self.slices[i].label,
+                shares[i] * 100.0
+            )),
+            None => response,
+        }
+    }
+}
+
+/// Fill the ring section between two fractions of a full turn
+fn fill_arc(
+    ui: &mut Ui,
+    center: Pos2,
+    outer_r: f32,
+    inner_r: f32,
+    from: f32,
+    to: f32,
+    color: Color32,
+) {
+    if to <= from {
+        return;
+    }
+    let segments = (((to - from) * 96.0).ceil() as u32).max(2);
+    let mut mesh = Mesh::default();
+
+    for i in 0..=segments {
+        let t = from + (to - from) * i as f32 / segments as f32;
+        let angle = t * TAU - FRAC_PI_2;
+        let dir = vec2(angle.cos(), angle.sin());
+        mesh.colored_vertex(center + dir * outer_r, color);
+        mesh.colored_vertex(center + dir * inner_r, color);
+
+        if i > 0 {
+            let base = (i - 1) * 2;
+            mesh.add_triangle(base, base + 1, base + 2);
+            mesh.add_triangle(base + 1, base + 3, base + 2);
+        }
+    }
+
+    ui.painter().add(egui::Shape::mesh(mesh));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slice(value: f32) -> DonutSlice {
+        DonutSlice {
+            label: String::new(),
+            value,
+            color: Color32::WHITE,
+        }
+    }
+
+    #[test]
+    fn test_shares_sum_to_one() {
+        let slices = [slice(1.0), slice(3.0)];
+        assert_eq!(DonutChart::new(&slices).shares(), vec![0.25, 0.75]);
+    }
+
+    #[test]
+    fn test_shares_of_empty_chart() {
+        let slices = [slice(0.0), slice(-2.0)];
+        assert_eq!(DonutChart::new(&slices).shares(), vec![0.0, 0.0]);
+    }
+}
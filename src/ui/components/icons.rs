@@ -60,6 +60,7 @@ pub enum Icon {
     Download,
     Pin,
     PinOff,
+    MiniMode,
     Trash,
 
     // Todo
@@ -129,6 +130,7 @@ impl Icon {
             Icon::Download => DOWNLOAD,
             Icon::Pin => PUSH_PIN,
             Icon::PinOff => PUSH_PIN_SLASH,
+            Icon::MiniMode => PICTURE_IN_PICTURE,
             Icon::Trash => TRASH,
 
             // Todo
@@ -4,15 +4,17 @@ mod ascii_art;
 mod button;
 mod card;
 mod circular_progress;
+mod donut;
 pub mod icons;
 mod slider;
 
 pub use ascii_art::{
-    AsciiBox, AsciiProgressBar, AsciiSessionDots, AsciiSpinner, AsciiTime, ASCII_TOMATO,
-    ASCII_TOMATO_SMALL,
+    AsciiBox, AsciiProgressBar, AsciiSessionDots, AsciiSparkline, AsciiSpinner, AsciiTime,
+    ASCII_TOMATO, ASCII_TOMATO_SMALL,
 };
 pub use button::{GradientButton, IconButton};
 pub use card::Card;
 pub use circular_progress::CircularProgress;
+pub use donut::{DonutChart, DonutSlice};
 pub use icons::{draw_icon, draw_icon_at, Icon, IconPainter};
 pub use slider::CustomSlider;
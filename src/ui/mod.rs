@@ -1,20 +1,25 @@
 //! UI components and views
 //!
 //! This module provides all UI-related functionality:
+//! - `a11y`: Screen reader descriptions for painted widgets
 //! - `animations`: Animation state and easing functions
 //! - `components`: Reusable UI components (buttons, progress, etc.)
 //! - `stats`: Statistics view
 //! - `layout`: Layout constants and responsive sizing utilities
 //! - `settings`: Settings panel view
+//! - `tasks`: Task list view
 //! - `theme`: Design system with colors, spacing, and fonts
 //! - `timer_view`: Main timer view
 //! - `titlebar`: Custom window title bar
 
+pub mod a11y;
 pub mod animations;
 pub mod components;
 pub mod layout;
+pub mod plan;
 pub mod settings;
 pub mod stats;
+pub mod tasks;
 pub mod theme;
 pub mod timer_view;
 pub mod titlebar;
@@ -23,8 +28,10 @@ pub mod todo_window;
 
 pub use animations::AnimationState;
 pub use layout::{responsive, ResponsiveSize};
+pub use plan::PlanView;
 pub use settings::SettingsView;
 pub use stats::StatsView;
+pub use tasks::TasksView;
 pub use theme::Theme;
 pub use timer_view::TimerView;
 pub use titlebar::TitleBar;
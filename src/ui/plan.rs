@@ -0,0 +1,242 @@
+//! Plan view: how many pomodoros each task gets tomorrow, or today
+//!
+//! Lists the tasks planned for the day with their pomodoros, then the open
+//! tasks of the current workspace that can be added. On the day, the timer
+//! view shows the plan against what was done and the stats how well plans
+//! are kept.
+
+use chrono::{Local, NaiveDate};
+use egui::{vec2, RichText, Ui};
+
+use super::components::{draw_icon, Icon};
+use super::tasks::{step_button, MAX_ESTIMATE};
+use super::theme::Theme;
+use crate::data::{PlannedTask, TodoItem};
+
+/// Day shown in the plan view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanDay {
+    Today,
+    Tomorrow,
+}
+
+impl PlanDay {
+    pub fn date(self) -> NaiveDate {
+        let today = Local::now().date_naive();
+        match self {
+            PlanDay::Today => today,
+            PlanDay::Tomorrow => today.succ_opt().unwrap_or(today),
+        }
+    }
+}
+
+/// Actions that can be triggered from the plan view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanAction {
+    GoBack,
+    /// Show the plan of another day
+    ChangeDay(PlanDay),
+    /// Plan a number of pomodoros on a task, 0 to take it off the plan
+    SetPlanned {
+        todo_id: i64,
+        planned: u32,
+    },
+}
+
+/// The plan view with the plan of the shown day
+pub struct PlanView {
+    pub day: PlanDay,
+    /// Tasks planned for the day, loaded by the app
+    pub plan: Vec<PlannedTask>,
+}
+
+impl Default for PlanView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlanView {
+    pub fn new() -> Self {
+        Self {
+            day: PlanDay::Tomorrow,
+            plan: Vec::new(),
+        }
+    }
+
+    /// Show the plan view and return the triggered actions
+    pub fn show(&mut self, ui: &mut Ui, theme: &Theme, todos: &[TodoItem]) -> Vec<PlanAction> {
+        let mut actions = Vec::new();
+        let t = crate::i18n::tr();
+
+        // Header
+        ui.horizontal(|ui| {
+            let (arrow_rect, arrow_resp) =
+                ui.allocate_exact_size(vec2(18.0, 18.0), egui::Sense::click());
+            let ir = egui::Rect::from_center_size(arrow_rect.center(), vec2(12.0, 12.0));
+            draw_icon(ui, Icon::ArrowLeft, ir, theme.text_secondary);
+            if arrow_resp.clicked() {
+                actions.push(PlanAction::GoBack);
+            }
+
+            ui.label(
+                RichText::new(t.plan.title)
+                    .size(14.0)
+                    .strong()
+                    .color(theme.text_primary),
+            );
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if !self.plan.is_empty() {
+                    let planned: u32 = self.plan.iter().map(|task| task.planned).sum();
+                    ui.label(
+                        RichText::new(format!("{} pom.", planned))
+                            .size(12.0)
+                            .color(theme.text_muted),
+                    );
+                }
+            });
+        });
+
+        ui.add_space(theme.spacing_sm);
+
+        // Day switch
+        ui.horizontal(|ui| {
+            for (day, label) in [
+                (PlanDay::Today, t.plan.today),
+                (PlanDay::Tomorrow, t.plan.tomorrow),
+            ] {
+                let selected = self.day == day;
+                let text = RichText::new(label).size(13.0).color(if selected {
+                    theme.text_primary
+                } else {
+                    theme.text_muted
+                });
+                if ui.selectable_label(selected, text).clicked() && !selected {
+                    actions.push(PlanAction::ChangeDay(day));
+                }
+            }
+            ui.label(
+                RichText::new(self.day.date().format("%Y-%m-%d").to_string())
+                    .size(12.0)
+                    .color(theme.text_muted),
+            );
+        });
+
+        ui.add_space(theme.spacing_sm);
+
+        let unplanned: Vec<&TodoItem> = todos
+            .iter()
+            .filter(|todo| !todo.completed)
+            .filter(|todo| !self.plan.iter().any(|task| task.todo_id == todo.id))
+            .collect();
+
+        if self.plan.is_empty() && unplanned.is_empty() {
+            ui.add_space(theme.spacing_xl);
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    RichText::new(t.plan.empty)
+                        .size(16.0)
+                        .color(theme.text_muted),
+                );
+                ui.add_space(theme.spacing_sm);
+                ui.label(
+                    RichText::new(t.plan.empty_hint)
+                        .size(13.0)
+                        .color(theme.text_muted),
+                );
+            });
+            return actions;
+        }
+
+        let today = self.day == PlanDay::Today;
+        let available_height = ui.available_height();
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .max_height(available_height)
+            .show(ui, |ui| {
+                for task in &self.plan {
+                    ui.horizontal(|ui| {
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if step_button(ui, theme, Icon::Plus) && task.planned < MAX_ESTIMATE {
+                                actions.push(PlanAction::SetPlanned {
+                                    todo_id: task.todo_id,
+                                    planned: task.planned + 1,
+                                });
+                            }
+                            // Only today has anything done yet
+                            let (count, color) = if today {
+                                let color = if task.done >= task.planned {
+                                    theme.success
+                                } else {
+                                    theme.text_secondary
+                                };
+                                (format!("{}/{}", task.done, task.planned), color)
+                            } else {
+                                (task.planned.to_string(), theme.text_secondary)
+                            };
+                            let count = ui.label(RichText::new(count).size(11.0).color(color));
+                            if today {
+                                count.on_hover_text(t.plan.done_hint);
+                            }
+                            if step_button(ui, theme, Icon::Minus) {
+                                actions.push(PlanAction::SetPlanned {
+                                    todo_id: task.todo_id,
+                                    planned: task.planned - 1,
+                                });
+                            }
+                            ui.with_layout(
+                                egui::Layout::left_to_right(egui::Align::Center),
+                                |ui| {
+                                    let mut title = RichText::new(&task.title).size(13.0);
+                                    title = if task.completed {
+                                        title.strikethrough().color(theme.text_muted)
+                                    } else {
+                                        title.color(theme.text_primary)
+                                    };
+                                    ui.add(egui::Label::new(title).truncate());
+                                },
+                            );
+                        });
+                    });
+                }
+
+                if unplanned.is_empty() {
+                    return;
+                }
+                if !self.plan.is_empty() {
+                    ui.add_space(theme.spacing_sm);
+                    ui.separator();
+                }
+                ui.label(RichText::new(t.plan.add).size(12.0).color(theme.text_muted));
+                ui.add_space(theme.spacing_xs);
+                for todo in unplanned {
+                    ui.horizontal(|ui| {
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if step_button(ui, theme, Icon::Plus) {
+                                actions.push(PlanAction::SetPlanned {
+                                    todo_id: todo.id,
+                                    planned: 1,
+                                });
+                            }
+                            ui.with_layout(
+                                egui::Layout::left_to_right(egui::Align::Center),
+                                |ui| {
+                                    ui.add(
+                                        egui::Label::new(
+                                            RichText::new(&todo.title)
+                                                .size(13.0)
+                                                .color(theme.text_secondary),
+                                        )
+                                        .truncate(),
+                                    );
+                                },
+                            );
+                        });
+                    });
+                }
+            });
+
+        actions
+    }
+}
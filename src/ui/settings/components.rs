@@ -161,6 +161,60 @@ pub(super) fn color_picker_row(
     });
 }
 
+/// Draw a row with an RGB picker for a custom accent color
+pub(super) fn custom_color_row(
+    ui: &mut Ui,
+    theme: &Theme,
+    label: &str,
+    rgb: &mut [u8; 3],
+    selected: &mut AccentColor,
+) {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(label).color(theme.text_secondary));
+
+        ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+            let is_selected = selected.custom_rgb().is_some();
+            let response = egui::color_picker::color_edit_button_srgb(ui, rgb);
+            if response.changed() || (response.clicked() && !is_selected) {
+                *selected = AccentColor::Custom(rgb[0], rgb[1], rgb[2]);
+            }
+            if is_selected {
+                ui.painter().rect_stroke(
+                    response.rect.expand(2.0),
+                    theme.rounding_sm,
+                    egui::Stroke::new(2.0, theme.text_primary),
+                );
+            }
+        });
+    });
+}
+
+/// Draw a slider row with the current value shown as `value_text`
+pub(super) fn slider_row(
+    ui: &mut Ui,
+    theme: &Theme,
+    label: &str,
+    value: &mut f32,
+    range: std::ops::RangeInclusive<f32>,
+    step: f64,
+    value_text: &str,
+) {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(label).color(theme.text_secondary));
+
+        ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+            ui.label(egui::RichText::new(value_text).color(theme.text_muted));
+
+            ui.add_sized(
+                vec2(120.0, 20.0),
+                egui::Slider::new(value, range)
+                    .step_by(step)
+                    .show_value(false),
+            );
+        });
+    });
+}
+
 /// Draw a toggle row with checkbox
 pub(super) fn toggle_row(ui: &mut Ui, theme: &Theme, label: &str, value: &mut bool) {
     ui.horizontal(|ui| {
@@ -175,27 +229,159 @@ pub(super) fn toggle_row(ui: &mut Ui, theme: &Theme, label: &str, value: &mut bo
     ui.add_space(theme.spacing_xs);
 }
 
-/// Draw a hotkey display row (read-only)
-pub(super) fn hotkey_row(ui: &mut Ui, theme: &Theme, label: &str, hotkey: &str) {
+/// Draw a single-line text input row
+pub(super) fn text_row(ui: &mut Ui, theme: &Theme, label: &str, value: &mut String, secret: bool) {
     ui.horizontal(|ui| {
         ui.label(egui::RichText::new(label).color(theme.text_secondary));
 
         ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
-            // Display hotkey in a styled box
-            let hotkey_text = egui::RichText::new(hotkey)
-                .color(theme.text_primary)
-                .strong()
-                .small();
-
-            egui::Frame::none()
-                .fill(theme.bg_tertiary)
-                .rounding(4.0)
-                .inner_margin(egui::Margin::symmetric(8.0, 4.0))
-                .show(ui, |ui| {
-                    ui.label(hotkey_text);
-                });
+            ui.add(
+                egui::TextEdit::singleline(value)
+                    .password(secret)
+                    .desired_width(150.0),
+            );
         });
     });
 
     ui.add_space(theme.spacing_xs);
 }
+
+/// Key press recorded by a hotkey row
+pub(super) enum HotkeyInput {
+    Recorded(String),
+    /// A key without modifier or one that can't be registered globally
+    Unsupported,
+}
+
+/// Hotkey row with a "click to record" button
+///
+/// While recording, the next key press is taken; Escape cancels.
+pub(super) fn hotkey_row(
+    ui: &mut Ui,
+    theme: &Theme,
+    label: &str,
+    hotkey: &str,
+    error: Option<&str>,
+) -> Option<HotkeyInput> {
+    let t = crate::i18n::tr();
+    let mut recorded = None;
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(label).color(theme.text_secondary));
+
+        ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+            let id = ui.id().with(label).with("hotkey_capture");
+            let mut recording = ui.data(|d| d.get_temp::<bool>(id)).unwrap_or(false);
+
+            let (text, fill) = if recording {
+                (
+                    t.settings.press_keys,
+                    Theme::with_alpha(theme.accent.solid(), 60),
+                )
+            } else {
+                (hotkey, theme.bg_tertiary)
+            };
+            let button = ui
+                .add(
+                    egui::Button::new(
+                        egui::RichText::new(text)
+                            .color(theme.text_primary)
+                            .strong()
+                            .small(),
+                    )
+                    .fill(fill)
+                    .rounding(4.0),
+                )
+                .on_hover_text(t.settings.hotkey_record_hint);
+
+            if button.clicked() {
+                recording = !recording;
+            } else if recording {
+                match capture_hotkey(ui) {
+                    Some(Capture::Combo(combo)) => {
+                        recorded = Some(HotkeyInput::Recorded(combo));
+                        recording = false;
+                    }
+                    Some(Capture::Unsupported) => {
+                        recorded = Some(HotkeyInput::Unsupported);
+                        recording = false;
+                    }
+                    Some(Capture::Cancel) => recording = false,
+                    None if button.clicked_elsewhere() => recording = false,
+                    None => {}
+                }
+            }
+            ui.data_mut(|d| d.insert_temp(id, recording));
+        });
+    });
+
+    if let Some(error) = error {
+        ui.label(egui::RichText::new(error).color(theme.error).small());
+    }
+
+    ui.add_space(theme.spacing_xs);
+    recorded
+}
+
+/// Result of listening for a hotkey
+enum Capture {
+    Combo(String),
+    Unsupported,
+    Cancel,
+}
+
+/// Take the first key press of this frame as a hotkey combo
+///
+/// The press is consumed so it doesn't also trigger the in-app shortcuts.
+fn capture_hotkey(ui: &mut Ui) -> Option<Capture> {
+    let (modifiers, key) = ui.input(|i| {
+        i.events.iter().find_map(|event| match event {
+            egui::Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+                ..
+            } => Some((*modifiers, *key)),
+            _ => None,
+        })
+    })?;
+    ui.input_mut(|i| i.consume_key(modifiers, key));
+
+    if key == egui::Key::Escape && modifiers.is_none() {
+        return Some(Capture::Cancel);
+    }
+    Some(format_hotkey(modifiers, key).map_or(Capture::Unsupported, Capture::Combo))
+}
+
+/// Hotkey string like "Ctrl+Alt+Space", as parsed by the hotkey manager
+///
+/// Only keys every platform can register are accepted, and at least one
+/// modifier is required so plain typing is never captured system-wide.
+fn format_hotkey(modifiers: egui::Modifiers, key: egui::Key) -> Option<String> {
+    let name = key.name();
+    let supported = (name.len() == 1 && name.chars().all(|c| c.is_ascii_alphanumeric()))
+        || matches!(key, egui::Key::Space | egui::Key::Enter)
+        || (egui::Key::F1 as u32..=egui::Key::F12 as u32).contains(&(key as u32));
+    if !supported {
+        return None;
+    }
+
+    let mut parts = Vec::new();
+    if modifiers.ctrl {
+        parts.push("Ctrl");
+    }
+    if modifiers.alt {
+        parts.push("Alt");
+    }
+    if modifiers.shift {
+        parts.push("Shift");
+    }
+    if modifiers.mac_cmd {
+        parts.push("Cmd");
+    }
+    if parts.is_empty() {
+        return None;
+    }
+    parts.push(name);
+    Some(parts.join("+"))
+}
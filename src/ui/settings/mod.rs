@@ -7,16 +7,19 @@
 //! - Consistent state management
 
 mod components;
+mod quick_start;
+mod search;
+mod sections;
 mod state;
 
-use egui::{vec2, Layout, Ui};
+use egui::Ui;
 
-use super::components::{draw_icon, Card, Icon, IconButton};
-use super::theme::{AccentColor, Theme, ThemeMode};
+use super::components::{Card, Icon, IconButton};
+use super::theme::Theme;
 use crate::data::{Config, NotificationSound};
-use components::{
-    color_picker_row, duration_row, duration_row_with_unit, hotkey_row, section_header, toggle_row,
-};
+use components::section_header;
+use search::filter_section;
+use sections::{Category, Section};
 pub use state::SettingsState;
 
 /// Actions from settings
@@ -29,18 +32,40 @@ pub enum SettingsAction {
     ResetDefaults,
     SetAlwaysOnTop(bool),
     TestSound(NotificationSound),
+    /// Play one of the user's own sound files
+    TestSoundFile(std::path::PathBuf),
+    /// Save the current settings as a named profile
+    SaveProfile(String),
+    /// Switch to a saved profile
+    UseProfile(String),
+    DeleteProfile(String),
 }
 
 /// Settings view
 pub struct SettingsView {
     /// Local editing state, kept in sync with Config
     state: SettingsState,
+    /// Rejected hotkey recording: binding index and reason
+    hotkey_error: Option<(usize, &'static str)>,
+    /// Text of the search box
+    search: String,
+    /// Tab shown while not searching
+    category: Category,
+    /// Refused sound file: label of its row and reason
+    sound_file_error: Option<(&'static str, &'static str)>,
+    /// Name typed for a new profile
+    profile_name: String,
 }
 
 impl SettingsView {
     pub fn new(config: &Config) -> Self {
         Self {
             state: SettingsState::from_config(config),
+            hotkey_error: None,
+            search: String::new(),
+            category: Category::Timer,
+            sound_file_error: None,
+            profile_name: String::new(),
         }
     }
 
@@ -63,543 +88,144 @@ impl SettingsView {
         egui::ScrollArea::vertical()
             .max_height(scroll_max_h)
             .show(ui, |ui| {
-            // Apply horizontal margins for centering
-            let margin = egui::Margin {
-                left: horizontal_margin,
-                right: horizontal_margin,
-                top: 0.0,
-                bottom: 0.0,
-            };
-            egui::Frame::none().inner_margin(margin).show(ui, |ui| {
-            // Force dark theme styles for all widgets (fixes Windows 10 light theme issues)
-            let visuals = &mut ui.style_mut().visuals;
-            visuals.widgets.inactive.bg_fill = theme.bg_tertiary;
-            visuals.widgets.inactive.weak_bg_fill = theme.bg_tertiary;
-            visuals.widgets.inactive.fg_stroke = egui::Stroke::new(1.0, theme.text_primary);
-            visuals.widgets.hovered.bg_fill = theme.bg_hover;
-            visuals.widgets.hovered.weak_bg_fill = theme.bg_hover;
-            visuals.widgets.hovered.fg_stroke = egui::Stroke::new(1.0, theme.text_primary);
-            visuals.widgets.active.bg_fill = theme.bg_active;
-            visuals.widgets.active.weak_bg_fill = theme.bg_active;
-            visuals.widgets.active.fg_stroke = egui::Stroke::new(1.0, theme.text_primary);
-            visuals.widgets.open.bg_fill = theme.bg_tertiary;
-            visuals.widgets.open.weak_bg_fill = theme.bg_tertiary;
-            visuals.widgets.open.fg_stroke = egui::Stroke::new(1.0, theme.text_primary);
-            visuals.widgets.noninteractive.bg_fill = theme.bg_secondary;
-            visuals.widgets.noninteractive.fg_stroke = egui::Stroke::new(1.0, theme.text_secondary);
-            visuals.selection.bg_fill = Theme::with_alpha(theme.accent.solid(), 100);
-            visuals.selection.stroke = egui::Stroke::new(1.0, theme.accent.solid());
-
-            // Header
-            ui.horizontal(|ui| {
-                if IconButton::new(Icon::ArrowLeft)
-                    .with_size(32.0)
-                    .with_icon_scale(0.5)
-                    .show(ui, theme)
-                    .clicked()
-                {
-                    action = Some(SettingsAction::Back);
-                }
-
-                ui.add_space(12.0);
-
-                ui.label(
-                    egui::RichText::new(t.settings.title)
-                        .font(theme.font_h2())
-                        .color(theme.text_primary),
-                );
-            });
-
-            ui.add_space(theme.spacing_lg);
-
-            // Timer section
-            section_header(ui, theme, t.settings.timer);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                duration_row(
-                    ui,
-                    theme,
-                    t.settings.focus_duration,
-                    &mut self.state.work_duration,
-                    1.0,
-                    90.0,
-                );
-                duration_row(
-                    ui,
-                    theme,
-                    t.settings.short_break,
-                    &mut self.state.short_break,
-                    1.0,
-                    30.0,
-                );
-                duration_row(
-                    ui,
-                    theme,
-                    t.settings.long_break,
-                    &mut self.state.long_break,
-                    5.0,
-                    60.0,
-                );
-                duration_row_with_unit(
-                    ui,
-                    theme,
-                    t.settings.sessions_before_long,
-                    &mut self.state.sessions_before_long,
-                    2.0,
-                    8.0,
-                    "",
-                );
-
-                ui.add_space(theme.spacing_sm);
-
-                toggle_row(
-                    ui,
-                    theme,
-                    t.settings.auto_start_breaks,
-                    &mut self.state.auto_start_breaks,
-                );
-                toggle_row(
-                    ui,
-                    theme,
-                    t.settings.auto_start_pomodoros,
-                    &mut self.state.auto_start_work,
-                );
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // Sounds section
-            section_header(ui, theme, t.settings.sounds);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new(t.settings.volume).color(theme.text_secondary));
-
-                    // Use right-to-left layout for proper alignment
-                    ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
-                        ui.label(
-                            egui::RichText::new(format!("{}%", self.state.volume.round() as u32))
-                                .color(theme.text_muted),
-                        );
-
-                        ui.add_sized(
-                            vec2(120.0, 20.0),
-                            egui::Slider::new(&mut self.state.volume, 0.0..=100.0)
-                                .step_by(1.0)
-                                .show_value(false),
-                        );
-                    });
-                });
-
-                ui.add_space(theme.spacing_sm);
-
-                // Sound selection with test button
-                let mut test_sound = false;
-                ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new(t.settings.sound).color(theme.text_secondary));
-
-                    ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
-                        // Test button with play icon
-                        let (btn_rect, btn_resp) =
-                            ui.allocate_exact_size(vec2(28.0, 22.0), egui::Sense::click());
-                        ui.painter().rect_filled(btn_rect, theme.rounding_sm, theme.bg_tertiary);
-                        ui.painter().rect_stroke(btn_rect, theme.rounding_sm, egui::Stroke::new(1.0, theme.border_subtle));
-                        let icon_rect = egui::Rect::from_center_size(btn_rect.center(), vec2(12.0, 12.0));
-                        draw_icon(ui, Icon::Play, icon_rect, theme.text_primary);
-                        if btn_resp.on_hover_text(t.settings.test_sound).clicked() {
-                            test_sound = true;
+                // Apply horizontal margins for centering
+                let margin = egui::Margin {
+                    left: horizontal_margin,
+                    right: horizontal_margin,
+                    top: 0.0,
+                    bottom: 0.0,
+                };
+                egui::Frame::none().inner_margin(margin).show(ui, |ui| {
+                    // Force dark theme styles for all widgets (fixes Windows 10 light theme issues)
+                    let visuals = &mut ui.style_mut().visuals;
+                    visuals.widgets.inactive.bg_fill = theme.bg_tertiary;
+                    visuals.widgets.inactive.weak_bg_fill = theme.bg_tertiary;
+                    visuals.widgets.inactive.fg_stroke = egui::Stroke::new(1.0, theme.text_primary);
+                    visuals.widgets.hovered.bg_fill = theme.bg_hover;
+                    visuals.widgets.hovered.weak_bg_fill = theme.bg_hover;
+                    visuals.widgets.hovered.fg_stroke = egui::Stroke::new(1.0, theme.text_primary);
+                    visuals.widgets.active.bg_fill = theme.bg_active;
+                    visuals.widgets.active.weak_bg_fill = theme.bg_active;
+                    visuals.widgets.active.fg_stroke = egui::Stroke::new(1.0, theme.text_primary);
+                    visuals.widgets.open.bg_fill = theme.bg_tertiary;
+                    visuals.widgets.open.weak_bg_fill = theme.bg_tertiary;
+                    visuals.widgets.open.fg_stroke = egui::Stroke::new(1.0, theme.text_primary);
+                    visuals.widgets.noninteractive.bg_fill = theme.bg_secondary;
+                    visuals.widgets.noninteractive.fg_stroke =
+                        egui::Stroke::new(1.0, theme.text_secondary);
+                    visuals.selection.bg_fill = Theme::with_alpha(theme.accent.solid(), 100);
+                    visuals.selection.stroke = egui::Stroke::new(1.0, theme.accent.solid());
+
+                    // Header
+                    ui.horizontal(|ui| {
+                        if IconButton::new(Icon::ArrowLeft)
+                            .with_label(crate::i18n::tr().common.back)
+                            .with_size(32.0)
+                            .with_icon_scale(0.5)
+                            .show(ui, theme)
+                            .clicked()
+                        {
+                            action = Some(SettingsAction::Back);
                         }
 
-                        ui.add_space(4.0);
-
-                        // Apply dark theme styles for ComboBox button
-                        ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.inactive.weak_bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
-                        ui.style_mut().visuals.widgets.hovered.weak_bg_fill = theme.bg_hover;
-                        ui.style_mut().visuals.widgets.active.bg_fill = theme.bg_active;
-                        ui.style_mut().visuals.widgets.active.weak_bg_fill = theme.bg_active;
-                        ui.style_mut().visuals.widgets.open.bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.open.weak_bg_fill = theme.bg_tertiary;
-
-                        egui::ComboBox::from_id_salt("notification_sound")
-                            .selected_text(
-                                egui::RichText::new(self.state.notification_sound.name())
-                                    .color(theme.text_primary),
-                            )
-                            .width(120.0)
-                            .show_ui(ui, |ui| {
-                                ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
-                                ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
-                                for sound in NotificationSound::all() {
-                                    ui.selectable_value(
-                                        &mut self.state.notification_sound,
-                                        *sound,
-                                        egui::RichText::new(sound.name()).color(theme.text_primary),
-                                    );
-                                }
-                            });
-                    });
-                });
-                if test_sound {
-                    action = Some(SettingsAction::TestSound(self.state.notification_sound));
-                }
-
-                ui.add_space(theme.spacing_sm);
-
-                toggle_row(
-                    ui,
-                    theme,
-                    t.settings.tick_sound,
-                    &mut self.state.tick_enabled,
-                );
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // Appearance section
-            section_header(ui, theme, t.settings.appearance);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                // Theme mode selector
-                ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new(t.settings.theme).color(theme.text_secondary));
-
-                    ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
-                        // Apply dark theme styles for ComboBox button
-                        ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.inactive.weak_bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
-                        ui.style_mut().visuals.widgets.hovered.weak_bg_fill = theme.bg_hover;
-                        ui.style_mut().visuals.widgets.active.bg_fill = theme.bg_active;
-                        ui.style_mut().visuals.widgets.active.weak_bg_fill = theme.bg_active;
-                        ui.style_mut().visuals.widgets.open.bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.open.weak_bg_fill = theme.bg_tertiary;
-
-                        egui::ComboBox::from_id_salt("theme_mode")
-                            .selected_text(
-                                egui::RichText::new(self.state.theme_mode.name())
-                                    .color(theme.text_primary),
-                            )
-                            .width(150.0)
-                            .show_ui(ui, |ui| {
-                                ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
-                                ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
-                                for mode in ThemeMode::all() {
-                                    ui.selectable_value(
-                                        &mut self.state.theme_mode,
-                                        *mode,
-                                        egui::RichText::new(mode.name()).color(theme.text_primary),
-                                    );
-                                }
-                            });
-                    });
-                });
-
-                ui.add_space(theme.spacing_sm);
-
-                // Standard colors
-                let standard_colors: Vec<_> = AccentColor::all()
-                    .iter()
-                    .filter(|c| !c.is_retro())
-                    .collect();
-                let retro_colors: Vec<_> =
-                    AccentColor::all().iter().filter(|c| c.is_retro()).collect();
-
-                color_picker_row(
-                    ui,
-                    theme,
-                    t.settings.accent_color,
-                    &standard_colors,
-                    &mut self.state.selected_accent,
-                );
+                        ui.add_space(12.0);
 
-                ui.add_space(theme.spacing_sm);
-
-                color_picker_row(
-                    ui,
-                    theme,
-                    t.settings.retro_themes,
-                    &retro_colors,
-                    &mut self.state.selected_accent,
-                );
-
-                ui.add_space(theme.spacing_sm);
-
-                // Window opacity slider
-                ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new(t.settings.window_opacity).color(theme.text_secondary));
-
-                    ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
                         ui.label(
-                            egui::RichText::new(format!("{}%", self.state.window_opacity.round() as u32))
-                                .color(theme.text_muted),
-                        );
-
-                        ui.add_sized(
-                            vec2(120.0, 20.0),
-                            egui::Slider::new(&mut self.state.window_opacity, 30.0..=100.0)
-                                .step_by(5.0)
-                                .show_value(false),
+                            egui::RichText::new(t.settings.title)
+                                .font(theme.font_h2())
+                                .color(theme.text_primary),
                         );
                     });
-                });
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // Language section
-            section_header(ui, theme, t.settings.language);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new(t.settings.language).color(theme.text_secondary));
-
-                    ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
-                        ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.inactive.weak_bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
-                        ui.style_mut().visuals.widgets.hovered.weak_bg_fill = theme.bg_hover;
-                        ui.style_mut().visuals.widgets.active.bg_fill = theme.bg_active;
-                        ui.style_mut().visuals.widgets.active.weak_bg_fill = theme.bg_active;
-                        ui.style_mut().visuals.widgets.open.bg_fill = theme.bg_tertiary;
-                        ui.style_mut().visuals.widgets.open.weak_bg_fill = theme.bg_tertiary;
-
-                        egui::ComboBox::from_id_salt("language")
-                            .selected_text(
-                                egui::RichText::new(self.state.language.display_name())
-                                    .color(theme.text_primary),
-                            )
-                            .width(150.0)
-                            .show_ui(ui, |ui| {
-                                ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
-                                ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
-                                for lang in crate::i18n::Language::all() {
-                                    ui.selectable_value(
-                                        &mut self.state.language,
-                                        *lang,
-                                        egui::RichText::new(lang.display_name()).color(theme.text_primary),
-                                    );
-                                }
-                            });
-                    });
-                });
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // Accessibility section
-            section_header(ui, theme, t.settings.accessibility);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                toggle_row(
-                    ui,
-                    theme,
-                    t.settings.high_contrast,
-                    &mut self.state.high_contrast,
-                );
-                toggle_row(
-                    ui,
-                    theme,
-                    t.settings.reduced_motion,
-                    &mut self.state.reduced_motion,
-                );
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // System section
-            section_header(ui, theme, t.settings.system);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                toggle_row(
-                    ui,
-                    theme,
-                    t.settings.start_with_windows,
-                    &mut self.state.start_with_windows,
-                );
-                toggle_row(ui, theme, t.settings.always_on_top, &mut self.state.always_on_top);
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // Goals section
-            section_header(ui, theme, t.settings.goals);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                duration_row_with_unit(
-                    ui,
-                    theme,
-                    t.settings.daily_goal,
-                    &mut self.state.daily_goal,
-                    1.0,
-                    16.0,
-                    t.settings.pomodoros,
-                );
-
-                toggle_row(
-                    ui,
-                    theme,
-                    t.settings.notify_goal_reached,
-                    &mut self.state.notify_on_goal,
-                );
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // Hotkeys section
-            section_header(ui, theme, t.settings.global_hotkeys);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                toggle_row(
-                    ui,
-                    theme,
-                    t.settings.enable_hotkeys,
-                    &mut self.state.hotkeys_enabled,
-                );
-
-                if self.state.hotkeys_enabled {
-                    ui.add_space(theme.spacing_xs);
 
-                    // Show current hotkey bindings (read-only for now)
-                    hotkey_row(ui, theme, t.settings.toggle_start_pause, &self.state.hotkey_toggle);
-                    hotkey_row(ui, theme, t.settings.skip_session, &self.state.hotkey_skip);
-                    hotkey_row(ui, theme, t.settings.reset_timer, &self.state.hotkey_reset);
+                    ui.add_space(theme.spacing_md);
 
-                    ui.add_space(theme.spacing_xs);
-                    ui.label(
-                        egui::RichText::new(t.settings.restart_for_hotkeys)
-                            .color(theme.text_muted)
-                            .small(),
+                    // Search box
+                    let search = ui.add(
+                        egui::TextEdit::singleline(&mut self.search)
+                            .hint_text(t.settings.search_hint)
+                            .desired_width(f32::INFINITY),
                     );
-                }
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // CLI Setup section
-            section_header(ui, theme, t.settings.command_line);
-            Card::new().show(ui, theme, |ui| {
-                ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
-
-                ui.label(
-                    egui::RichText::new(t.settings.control_from_terminal)
-                        .color(theme.text_secondary),
-                );
-                ui.add_space(theme.spacing_xs);
-
-                ui.horizontal(|ui| {
-                    ui.label(
-                        egui::RichText::new("pomodorust status")
-                            .color(theme.text_muted)
-                            .code(),
-                    );
-                });
-
-                ui.add_space(theme.spacing_sm);
-
-                // Get current exe path for the command
-                let exe_path = std::env::current_exe()
-                    .ok()
-                    .and_then(|p| p.parent().map(|p| p.to_path_buf()))
-                    .map(|p| p.to_string_lossy().to_string())
-                    .unwrap_or_else(|| "C:\\path\\to\\pomodorust".to_string());
-
-                let powershell_cmd = format!(
-                    "$p = [Environment]::GetEnvironmentVariable('Path', 'User'); if ($p -notlike '*{}*') {{ [Environment]::SetEnvironmentVariable('Path', \"$p;{}\", 'User') }}",
-                    exe_path, exe_path
-                );
-
-                let copy_btn = egui::Button::new(
-                    egui::RichText::new(t.settings.copy_path_command).color(theme.text_primary),
-                )
-                .fill(theme.bg_tertiary)
-                .stroke(egui::Stroke::new(1.0, theme.border_subtle));
-
-                if ui
-                    .add_sized(vec2(ui.available_width(), 32.0), copy_btn)
-                    .on_hover_text(t.settings.copy_path_tooltip)
-                    .clicked()
-                {
-                    ui.ctx().copy_text(powershell_cmd);
-                }
-
-                ui.add_space(theme.spacing_xs);
-                ui.label(
-                    egui::RichText::new(t.settings.run_copied_command)
-                        .color(theme.text_muted)
-                        .small(),
-                );
-            });
-
-            ui.add_space(theme.spacing_md);
-
-            // Presets section
-            section_header(ui, theme, t.settings.presets);
-            let mut preset_clicked: Option<usize> = None;
-            Card::new().show(ui, theme, |ui| {
-                let card_width = ui.available_width();
-                ui.set_min_width(card_width - theme.spacing_md * 2.0);
-
-                let presets = [
-                    (t.settings.preset_classic, "25/5/15"),
-                    (t.settings.preset_short, "15/3/10"),
-                    (t.settings.preset_long, "50/10/30"),
-                ];
+                    // Escape clears the query before it leaves the page (the field
+                    // itself drops focus on Escape)
+                    if (search.has_focus() || search.lost_focus())
+                        && !self.search.is_empty()
+                        && ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape))
+                    {
+                        self.search.clear();
+                    }
 
-                let button_width = (card_width - theme.spacing_sm * 2.0) / 3.0;
+                    let query = self.search.trim().to_lowercase();
+
+                    // Tabs, a search looks through all of them
+                    if query.is_empty() {
+                        ui.add_space(theme.spacing_sm);
+                        ui.horizontal_wrapped(|ui| {
+                            for category in Category::ALL {
+                                let selected = self.category == category;
+                                let text = egui::RichText::new(category.title()).size(13.0).color(
+                                    if selected {
+                                        theme.text_primary
+                                    } else {
+                                        theme.text_muted
+                                    },
+                                );
+                                if ui.selectable_label(selected, text).clicked() {
+                                    self.category = category;
+                                }
+                            }
+                        });
+                    }
 
-                ui.horizontal(|ui| {
-                    ui.spacing_mut().item_spacing.x = theme.spacing_sm;
-                    for (i, preset) in presets.iter().enumerate() {
-                        let preset_btn = egui::Button::new(
-                            egui::RichText::new(format!("{}\n{}", preset.0, preset.1))
-                                .color(theme.text_primary),
-                        )
-                        .fill(theme.bg_tertiary)
-                        .stroke(egui::Stroke::new(1.0, theme.border_subtle));
+                    ui.add_space(theme.spacing_lg);
 
-                        if ui
-                            .add_sized(vec2(button_width, 48.0), preset_btn)
-                            .clicked()
-                        {
-                            preset_clicked = Some(i);
+                    let mut found = false;
+                    for section in Section::ALL {
+                        if query.is_empty() && section.category() != self.category {
+                            continue;
                         }
+                        let Some(rows) = filter_section(&query, section.title(), &section.labels())
+                        else {
+                            continue;
+                        };
+                        found = true;
+                        section_header(ui, theme, section.title());
+                        Card::new().show(ui, theme, |ui| {
+                            ui.set_min_width(ui.available_width() - theme.spacing_md * 2.0);
+                            self.show_section(section, ui, theme, rows, &mut action);
+                        });
+                        ui.add_space(theme.spacing_md);
                     }
-                });
-            });
-            if let Some(index) = preset_clicked {
-                action = Some(SettingsAction::SelectPreset(index));
-            }
 
-            ui.add_space(theme.spacing_xl);
+                    if !found {
+                        ui.vertical_centered(|ui| {
+                            ui.label(
+                                egui::RichText::new(t.settings.no_results).color(theme.text_muted),
+                            );
+                        });
+                    }
 
-            // Reset button
-            ui.horizontal(|ui| {
-                ui.add_space((ui.available_width() - 150.0) / 2.0);
+                    // Reset button
+                    if query.is_empty() && self.category == Category::Advanced {
+                        ui.add_space(theme.spacing_xl);
+                        ui.horizontal(|ui| {
+                            ui.add_space((ui.available_width() - 150.0) / 2.0);
 
-                let reset_btn = egui::Button::new(
-                    egui::RichText::new(t.settings.reset_to_defaults).color(theme.text_primary),
-                )
-                .fill(theme.bg_tertiary)
-                .stroke(egui::Stroke::new(1.0, theme.border_subtle));
+                            let reset_btn = egui::Button::new(
+                                egui::RichText::new(t.settings.reset_to_defaults)
+                                    .color(theme.text_primary),
+                            )
+                            .fill(theme.bg_tertiary)
+                            .stroke(egui::Stroke::new(1.0, theme.border_subtle));
 
-                if ui.add(reset_btn).clicked() {
-                    action = Some(SettingsAction::ResetDefaults);
-                }
-            });
+                            if ui.add(reset_btn).clicked() {
+                                action = Some(SettingsAction::ResetDefaults);
+                            }
+                        });
+                    }
 
-            ui.add_space(theme.spacing_lg);
-            }); // Frame
-        }); // ScrollArea
+                    ui.add_space(theme.spacing_lg);
+                }); // Frame
+            }); // ScrollArea
 
         // Check if config changed and emit UpdateConfig action
         if action.is_none() && self.state.differs_from(config) {
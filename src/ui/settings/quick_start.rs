@@ -0,0 +1,98 @@
+//! Editor for the Quick Start buttons of the stats view
+
+use egui::Ui;
+
+use super::super::theme::Theme;
+use crate::core::SessionType;
+use crate::data::QuickStartEntry;
+
+/// Most buttons the stats view has room for
+const MAX_ENTRIES: usize = 8;
+
+fn session_name(session_type: SessionType) -> &'static str {
+    let t = crate::i18n::tr();
+    match session_type {
+        SessionType::Work => t.tray.focus,
+        SessionType::ShortBreak => t.tray.short_break,
+        SessionType::LongBreak => t.tray.long_break,
+    }
+}
+
+/// One row per button (label, session type, minutes, tag) plus an add button
+pub(super) fn quick_start_editor(ui: &mut Ui, theme: &Theme, entries: &mut Vec<QuickStartEntry>) {
+    let t = crate::i18n::tr();
+    let mut remove = None;
+
+    for (i, entry) in entries.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut entry.label)
+                    .hint_text(t.settings.quick_start_label_hint)
+                    .desired_width(110.0),
+            );
+
+            egui::ComboBox::from_id_salt(("quick_start_type", i))
+                .selected_text(session_name(entry.session_type))
+                .width(100.0)
+                .show_ui(ui, |ui| {
+                    for session_type in [
+                        SessionType::Work,
+                        SessionType::ShortBreak,
+                        SessionType::LongBreak,
+                    ] {
+                        ui.selectable_value(
+                            &mut entry.session_type,
+                            session_type,
+                            session_name(session_type),
+                        );
+                    }
+                });
+
+            ui.add(
+                egui::DragValue::new(&mut entry.minutes)
+                    .range(1..=240)
+                    .suffix(format!(" {}", t.common.min)),
+            );
+
+            ui.add(
+                egui::TextEdit::singleline(&mut entry.tag)
+                    .hint_text(t.settings.quick_start_tag_hint)
+                    .desired_width(70.0),
+            );
+
+            let remove_btn = ui
+                .add(
+                    egui::Button::new(egui::RichText::new("×").color(theme.text_muted))
+                        .frame(false),
+                )
+                .on_hover_text(t.settings.remove_quick_start);
+            if remove_btn.clicked() {
+                remove = Some(i);
+            }
+        });
+        ui.add_space(theme.spacing_xs);
+    }
+
+    if let Some(i) = remove {
+        entries.remove(i);
+    }
+
+    if entries.len() < MAX_ENTRIES
+        && ui
+            .add(
+                egui::Button::new(
+                    egui::RichText::new(t.settings.add_quick_start).color(theme.text_primary),
+                )
+                .fill(theme.bg_tertiary)
+                .stroke(egui::Stroke::new(1.0, theme.border_subtle)),
+            )
+            .clicked()
+    {
+        entries.push(QuickStartEntry {
+            label: String::new(),
+            session_type: SessionType::Work,
+            minutes: 25,
+            tag: String::new(),
+        });
+    }
+}
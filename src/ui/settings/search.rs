@@ -0,0 +1,69 @@
+//! Narrowing the settings down to the rows matching a search query
+
+/// The rows of a section left by the search
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Rows<'a> {
+    /// Lowercase query, `None` when the whole section is shown
+    query: Option<&'a str>,
+}
+
+impl Rows<'_> {
+    /// Every row of the section
+    pub(super) const ALL: Rows<'static> = Rows { query: None };
+
+    /// Whether the row labelled `label` is shown
+    pub(super) fn shows(&self, label: &str) -> bool {
+        self.query.is_none_or(|query| matches(label, query))
+    }
+
+    /// Whether any of `labels` is shown, for a toggle that reveals them
+    pub(super) fn shows_any(&self, labels: &[&str]) -> bool {
+        labels.iter().any(|label| self.shows(label))
+    }
+}
+
+fn matches(label: &str, query: &str) -> bool {
+    label.to_lowercase().contains(query)
+}
+
+/// Rows of the section titled `title` that match the lowercase `query`, or
+/// `None` when nothing in it does. A matching title keeps the whole section.
+pub(super) fn filter_section<'a>(query: &'a str, title: &str, labels: &[&str]) -> Option<Rows<'a>> {
+    if query.is_empty() || matches(title, query) {
+        Some(Rows::ALL)
+    } else if labels.iter().any(|label| matches(label, query)) {
+        Some(Rows { query: Some(query) })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_shows_everything() {
+        let rows = filter_section("", "Sounds", &["Volume"]).unwrap();
+        assert!(rows.shows("Tick sound"));
+    }
+
+    #[test]
+    fn test_title_match_keeps_all_rows() {
+        let rows = filter_section("sound", "Sounds", &["Volume"]).unwrap();
+        assert!(rows.shows("Volume"));
+    }
+
+    #[test]
+    fn test_row_match_keeps_only_that_row() {
+        let rows = filter_section("tick", "Sounds", &["Volume", "Tick sound"]).unwrap();
+        assert!(rows.shows("Tick sound"));
+        assert!(!rows.shows("Volume"));
+        assert!(rows.shows_any(&["Volume", "Tick sound"]));
+    }
+
+    #[test]
+    fn test_no_match_hides_section() {
+        assert!(filter_section("hue", "Sounds", &["Volume", "Tick sound"]).is_none());
+    }
+}
@@ -0,0 +1,2078 @@
+//! The settings sections, each drawn inside its own card, and the tabs
+//! they are grouped under
+
+use chrono::NaiveTime;
+use egui::{vec2, Layout, Ui};
+
+use super::super::components::{draw_icon, Icon};
+use super::super::theme::{AccentColor, Theme, ThemeMode};
+use super::components::{
+    color_picker_row, custom_color_row, duration_row, duration_row_with_unit, hotkey_row,
+    slider_row, text_row, toggle_row, HotkeyInput,
+};
+use super::quick_start::quick_start_editor;
+use super::search::Rows;
+use super::{SettingsAction, SettingsView};
+use crate::data::{
+    AmbientSound, DayPreset, GroupRole, MeetingAction, NotificationSound, PluginsConfig,
+};
+use crate::error::AudioError;
+use crate::platform::AudioPlayer;
+
+/// A titled group of settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Section {
+    Timer,
+    Sounds,
+    Ambient,
+    Appearance,
+    Language,
+    Accessibility,
+    System,
+    Hue,
+    Calendar,
+    Slack,
+    Todoist,
+    Taskwarrior,
+    Obsidian,
+    ActivityWatch,
+    Notion,
+    Telegram,
+    Email,
+    Group,
+    Plugins,
+    Goals,
+    WeekdayPlans,
+    WorkingHours,
+    Hotkeys,
+    NotificationText,
+    CommandLine,
+    Presets,
+    Profiles,
+    QuickStart,
+}
+
+impl Section {
+    /// All sections, in page order within their tab
+    pub(super) const ALL: [Section; 28] = [
+        Section::Timer,
+        Section::Goals,
+        Section::WeekdayPlans,
+        Section::WorkingHours,
+        Section::Presets,
+        Section::Profiles,
+        Section::QuickStart,
+        Section::Sounds,
+        Section::Ambient,
+        Section::Appearance,
+        Section::Language,
+        Section::Accessibility,
+        Section::System,
+        Section::Hotkeys,
+        Section::NotificationText,
+        Section::Hue,
+        Section::Calendar,
+        Section::Slack,
+        Section::Todoist,
+        Section::Taskwarrior,
+        Section::Obsidian,
+        Section::ActivityWatch,
+        Section::Notion,
+        Section::Telegram,
+        Section::Email,
+        Section::Group,
+        Section::Plugins,
+        Section::CommandLine,
+    ];
+
+    pub(super) fn title(self) -> &'static str {
+        let t = crate::i18n::tr();
+        match self {
+            Section::Timer => t.settings.timer,
+            Section::Sounds => t.settings.sounds,
+            Section::Ambient => t.settings.ambient,
+            Section::Appearance => t.settings.appearance,
+            Section::Language => t.settings.language,
+            Section::Accessibility => t.settings.accessibility,
+            Section::System => t.settings.system,
+            Section::Hue => t.settings.hue,
+            Section::Calendar => t.settings.calendar,
+            Section::Slack => t.settings.slack,
+            Section::Todoist => t.settings.todoist,
+            Section::Taskwarrior => t.settings.taskwarrior,
+            Section::Obsidian => t.settings.obsidian,
+            Section::ActivityWatch => t.settings.activitywatch,
+            Section::Notion => t.settings.notion,
+            Section::Telegram => t.settings.telegram,
+            Section::Email => t.settings.email,
+            Section::Group => t.settings.group,
+            Section::Plugins => t.settings.plugins,
+            Section::Goals => t.settings.goals,
+            Section::WeekdayPlans => t.settings.weekday_plans,
+            Section::WorkingHours => t.settings.working_hours,
+            Section::Hotkeys => t.settings.global_hotkeys,
+            Section::NotificationText => t.settings.notification_text,
+            Section::CommandLine => t.settings.command_line,
+            Section::Presets => t.settings.presets,
+            Section::Profiles => t.settings.profiles,
+            Section::QuickStart => t.stats.quick_start,
+        }
+    }
+
+    /// Labels of the rows, matched against the search query. Sections
+    /// without any are only found by their title.
+    pub(super) fn labels(self) -> Vec<&'static str> {
+        let t = &crate::i18n::tr().settings;
+        match self {
+            Section::Timer => vec![
+                t.focus_duration,
+                t.short_break,
+                t.long_break,
+                t.sessions_before_long,
+                t.auto_start_breaks,
+                t.auto_start_pomodoros,
+                t.require_intent,
+                t.strict_focus,
+                t.min_recorded,
+            ],
+            Section::Sounds => vec![
+                t.volume,
+                t.sound,
+                t.sound_file,
+                t.tick_sound,
+                t.tick_speed_up,
+                t.tick_file,
+                t.chime_halfway,
+                t.chime_every,
+            ],
+            Section::Ambient => AmbientSound::all().iter().map(|s| s.name()).collect(),
+            Section::Appearance => vec![
+                t.theme,
+                t.follow_sun,
+                t.light_from,
+                t.dark_from,
+                t.latitude,
+                t.accent_color,
+                t.color_custom,
+                t.retro_themes,
+                t.window_opacity,
+                t.background_image,
+                t.background_blur,
+                t.background_dim,
+            ],
+            Section::Accessibility => vec![t.high_contrast, t.reduced_motion, t.power_saver],
+            Section::System => vec![
+                t.start_with_windows,
+                t.dnd_during_focus,
+                t.pause_media_during_focus,
+                t.close_to_tray,
+                t.always_on_top,
+                t.raise_on_complete,
+                t.reminders,
+                t.reminder_steps,
+                t.reminder_sound,
+                t.title_countdown,
+                t.track_apps,
+                t.kiosk_message,
+            ],
+            Section::Hue => vec![t.hue_enabled, t.hue_bridge_ip, t.hue_app_key, t.hue_target],
+            Section::Calendar => vec![t.calendar_enabled, t.calendar_url, t.meeting_overlap],
+            Section::Slack => vec![
+                t.slack_enabled,
+                t.slack_token,
+                t.slack_status_text,
+                t.slack_status_emoji,
+                t.slack_dnd,
+            ],
+            Section::Todoist => vec![
+                t.todoist_enabled,
+                t.todoist_token,
+                t.todoist_filter,
+                t.todoist_comment,
+                t.todoist_close,
+            ],
+            Section::Taskwarrior => vec![
+                t.taskwarrior_enabled,
+                t.taskwarrior_command,
+                t.taskwarrior_filter,
+                t.taskwarrior_track_time,
+                t.taskwarrior_annotate,
+            ],
+            Section::Obsidian => vec![
+                t.obsidian_enabled,
+                t.obsidian_vault,
+                t.obsidian_folder,
+                t.obsidian_date_format,
+                t.obsidian_template,
+                t.obsidian_log_breaks,
+            ],
+            Section::ActivityWatch => vec![
+                t.activitywatch_enabled,
+                t.activitywatch_url,
+                t.activitywatch_bucket,
+                t.activitywatch_breaks,
+            ],
+            Section::Notion => vec![
+                t.notion_enabled,
+                t.notion_token,
+                t.notion_database,
+                t.notion_title_property,
+                t.notion_date_property,
+                t.notion_duration_property,
+                t.notion_tag_property,
+            ],
+            Section::Telegram => vec![
+                t.telegram_enabled,
+                t.telegram_token,
+                t.telegram_chat,
+                t.telegram_notify,
+            ],
+            Section::Group => vec![
+                t.group_enabled,
+                t.group_role,
+                t.group_address,
+                t.group_name,
+                t.group_code,
+            ],
+            Section::Email => vec![
+                t.email_enabled,
+                t.email_server,
+                t.email_username,
+                t.email_password,
+                t.email_from,
+                t.email_to,
+                t.email_send_at,
+            ],
+            Section::Plugins => vec![t.plugins_enabled],
+            Section::Goals => vec![t.daily_goal, t.notify_goal_reached, t.rest_days],
+            Section::WeekdayPlans => vec![t.weekday_plans_enabled],
+            Section::WorkingHours => vec![
+                t.working_hours_enabled,
+                t.work_starts,
+                t.work_ends,
+                t.hold_auto_start,
+                t.warn_late_focus,
+            ],
+            Section::Hotkeys => vec![
+                t.enable_hotkeys,
+                t.toggle_start_pause,
+                t.skip_session,
+                t.reset_timer,
+                t.mute_hotkey,
+                t.media_keys,
+                t.media_keys_unless_playing,
+            ],
+            Section::NotificationText => {
+                let notif = &crate::i18n::tr().notif;
+                vec![
+                    notif.focus_complete,
+                    notif.break_over,
+                    notif.long_break_over,
+                    notif.daily_goal_reached,
+                ]
+            }
+            Section::Presets => vec![t.preset_classic, t.preset_short, t.preset_long],
+            Section::Profiles => vec![t.save_profile],
+            Section::Language | Section::CommandLine | Section::QuickStart => Vec::new(),
+        }
+    }
+}
+
+/// Tab of the settings page, grouping related sections
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Category {
+    Timer,
+    Sounds,
+    Appearance,
+    System,
+    Integrations,
+    Advanced,
+}
+
+impl Category {
+    /// All tabs, in strip order
+    pub(super) const ALL: [Category; 6] = [
+        Category::Timer,
+        Category::Sounds,
+        Category::Appearance,
+        Category::System,
+        Category::Integrations,
+        Category::Advanced,
+    ];
+
+    pub(super) fn title(self) -> &'static str {
+        let t = crate::i18n::tr();
+        match self {
+            Category::Timer => t.settings.timer,
+            Category::Sounds => t.settings.sounds,
+            Category::Appearance => t.settings.appearance,
+            Category::System => t.settings.system,
+            Category::Integrations => t.settings.integrations,
+            Category::Advanced => t.settings.advanced,
+        }
+    }
+}
+
+impl Section {
+    /// Tab the section is listed under
+    pub(super) fn category(self) -> Category {
+        match self {
+            Section::Timer
+            | Section::Goals
+            | Section::WeekdayPlans
+            | Section::WorkingHours
+            | Section::Presets
+            | Section::Profiles
+            | Section::QuickStart => Category::Timer,
+            Section::Sounds | Section::Ambient => Category::Sounds,
+            Section::Appearance | Section::Language | Section::Accessibility => {
+                Category::Appearance
+            }
+            Section::System | Section::Hotkeys | Section::NotificationText => Category::System,
+            Section::Hue
+            | Section::Calendar
+            | Section::Slack
+            | Section::Todoist
+            | Section::Taskwarrior
+            | Section::Obsidian
+            | Section::ActivityWatch
+            | Section::Notion
+            | Section::Telegram
+            | Section::Email
+            | Section::Group
+            | Section::Plugins => Category::Integrations,
+            Section::CommandLine => Category::Advanced,
+        }
+    }
+}
+
+/// Dark widget styles for a ComboBox button
+fn combo_style(ui: &mut Ui, theme: &Theme) {
+    let widgets = &mut ui.style_mut().visuals.widgets;
+    widgets.inactive.bg_fill = theme.bg_tertiary;
+    widgets.inactive.weak_bg_fill = theme.bg_tertiary;
+    widgets.hovered.bg_fill = theme.bg_hover;
+    widgets.hovered.weak_bg_fill = theme.bg_hover;
+    widgets.active.bg_fill = theme.bg_active;
+    widgets.active.weak_bg_fill = theme.bg_active;
+    widgets.open.bg_fill = theme.bg_tertiary;
+    widgets.open.weak_bg_fill = theme.bg_tertiary;
+}
+
+/// Small button with a play icon that plays a sound to try it
+fn test_button(ui: &mut Ui, theme: &Theme) -> bool {
+    let (btn_rect, btn_resp) = ui.allocate_exact_size(vec2(28.0, 22.0), egui::Sense::click());
+    ui.painter()
+        .rect_filled(btn_rect, theme.rounding_sm, theme.bg_tertiary);
+    ui.painter().rect_stroke(
+        btn_rect,
+        theme.rounding_sm,
+        egui::Stroke::new(1.0, theme.border_subtle),
+    );
+    let icon_rect = egui::Rect::from_center_size(btn_rect.center(), vec2(12.0, 12.0));
+    draw_icon(ui, Icon::Play, icon_rect, theme.text_primary);
+    btn_resp
+        .on_hover_text(crate::i18n::tr().settings.test_sound)
+        .clicked()
+}
+
+impl SettingsView {
+    /// Contents of the card of `section`, limited to `rows`
+    pub(super) fn show_section(
+        &mut self,
+        section: Section,
+        ui: &mut Ui,
+        theme: &Theme,
+        rows: Rows,
+        action: &mut Option<SettingsAction>,
+    ) {
+        match section {
+            Section::Timer => self.timer_section(ui, theme, rows),
+            Section::Sounds => self.sounds_section(ui, theme, rows, action),
+            Section::Ambient => self.ambient_section(ui, theme, rows),
+            Section::Appearance => self.appearance_section(ui, theme, rows),
+            Section::Language => self.language_section(ui, theme),
+            Section::Accessibility => self.accessibility_section(ui, theme, rows),
+            Section::System => self.system_section(ui, theme, rows),
+            Section::Hue => self.hue_section(ui, theme, rows),
+            Section::Calendar => self.calendar_section(ui, theme, rows),
+            Section::Slack => self.slack_section(ui, theme, rows),
+            Section::Todoist => self.todoist_section(ui, theme, rows),
+            Section::Taskwarrior => self.taskwarrior_section(ui, theme, rows),
+            Section::Obsidian => self.obsidian_section(ui, theme, rows),
+            Section::ActivityWatch => self.activitywatch_section(ui, theme, rows),
+            Section::Notion => self.notion_section(ui, theme, rows),
+            Section::Telegram => self.telegram_section(ui, theme, rows),
+            Section::Email => self.email_section(ui, theme, rows),
+            Section::Group => self.group_section(ui, theme, rows),
+            Section::Plugins => self.plugins_section(ui, theme, rows),
+            Section::Goals => self.goals_section(ui, theme, rows),
+            Section::WeekdayPlans => self.weekday_plans_section(ui, theme),
+            Section::WorkingHours => self.working_hours_section(ui, theme, rows),
+            Section::Hotkeys => self.hotkeys_section(ui, theme, rows),
+            Section::NotificationText => self.notification_text_section(ui, theme, rows),
+            Section::CommandLine => command_line_section(ui, theme),
+            Section::Presets => self.presets_section(ui, theme, action),
+            Section::Profiles => self.profiles_section(ui, theme, action),
+            Section::QuickStart => quick_start_editor(ui, theme, &mut self.state.quick_start),
+        }
+    }
+
+    fn timer_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        if rows.shows(t.settings.focus_duration) {
+            duration_row(
+                ui,
+                theme,
+                t.settings.focus_duration,
+                &mut self.state.work_duration,
+                1.0,
+                90.0,
+            );
+        }
+        if rows.shows(t.settings.short_break) {
+            duration_row(
+                ui,
+                theme,
+                t.settings.short_break,
+                &mut self.state.short_break,
+                1.0,
+                30.0,
+            );
+        }
+        if rows.shows(t.settings.long_break) {
+            duration_row(
+                ui,
+                theme,
+                t.settings.long_break,
+                &mut self.state.long_break,
+                5.0,
+                60.0,
+            );
+        }
+        if rows.shows(t.settings.sessions_before_long) {
+            duration_row_with_unit(
+                ui,
+                theme,
+                t.settings.sessions_before_long,
+                &mut self.state.sessions_before_long,
+                2.0,
+                8.0,
+                "",
+            );
+        }
+
+        ui.add_space(theme.spacing_sm);
+
+        if rows.shows(t.settings.auto_start_breaks) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.auto_start_breaks,
+                &mut self.state.auto_start_breaks,
+            );
+        }
+        if rows.shows(t.settings.auto_start_pomodoros) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.auto_start_pomodoros,
+                &mut self.state.auto_start_work,
+            );
+        }
+        if rows.shows(t.settings.require_intent) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.require_intent,
+                &mut self.state.require_intent,
+            );
+        }
+        if rows.shows(t.settings.strict_focus) {
+            toggle_row(ui, theme, t.settings.strict_focus, &mut self.state.strict);
+        }
+        if rows.shows(t.settings.min_recorded) {
+            duration_row(
+                ui,
+                theme,
+                t.settings.min_recorded,
+                &mut self.state.min_record_minutes,
+                0.0,
+                10.0,
+            );
+        }
+    }
+
+    fn sounds_section(
+        &mut self,
+        ui: &mut Ui,
+        theme: &Theme,
+        rows: Rows,
+        action: &mut Option<SettingsAction>,
+    ) {
+        let t = crate::i18n::tr();
+        if rows.shows(t.settings.volume) {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(t.settings.volume).color(theme.text_secondary));
+
+                // Use right-to-left layout for proper alignment
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.label(
+                        egui::RichText::new(format!("{}%", self.state.volume.round() as u32))
+                            .color(theme.text_muted),
+                    );
+
+                    ui.add_sized(
+                        vec2(120.0, 20.0),
+                        egui::Slider::new(&mut self.state.volume, 0.0..=100.0)
+                            .step_by(1.0)
+                            .show_value(false),
+                    );
+                });
+            });
+
+            ui.add_space(theme.spacing_sm);
+        }
+
+        // Sound selection with test button
+        if rows.shows(t.settings.sound) {
+            let mut test_sound = false;
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(t.settings.sound).color(theme.text_secondary));
+
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    if test_button(ui, theme) {
+                        test_sound = true;
+                    }
+
+                    ui.add_space(4.0);
+
+                    combo_style(ui, theme);
+                    egui::ComboBox::from_id_salt("notification_sound")
+                        .selected_text(
+                            egui::RichText::new(self.state.notification_sound.name())
+                                .color(theme.text_primary),
+                        )
+                        .width(120.0)
+                        .show_ui(ui, |ui| {
+                            ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
+                            ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
+                            for sound in NotificationSound::all() {
+                                ui.selectable_value(
+                                    &mut self.state.notification_sound,
+                                    *sound,
+                                    egui::RichText::new(sound.name()).color(theme.text_primary),
+                                );
+                            }
+                        });
+                });
+            });
+            if test_sound {
+                *action = Some(SettingsAction::TestSound(self.state.notification_sound));
+            }
+
+            ui.add_space(theme.spacing_sm);
+        }
+
+        if rows.shows(t.settings.sound_file) {
+            self.sound_file_row(ui, theme, t.settings.sound_file, false, action);
+            ui.add_space(theme.spacing_sm);
+        }
+
+        if rows.shows(t.settings.tick_sound) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.tick_sound,
+                &mut self.state.tick_enabled,
+            );
+        }
+        if self.state.tick_enabled && rows.shows(t.settings.tick_speed_up) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.tick_speed_up,
+                &mut self.state.tick_speed_up,
+            );
+        }
+
+        if rows.shows(t.settings.tick_file) {
+            ui.add_space(theme.spacing_sm);
+            self.sound_file_row(ui, theme, t.settings.tick_file, true, action);
+        }
+
+        if rows.shows_any(&[t.settings.chime_halfway, t.settings.chime_every]) {
+            ui.add_space(theme.spacing_sm);
+        }
+        if rows.shows(t.settings.chime_halfway) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.chime_halfway,
+                &mut self.state.chimes.halfway,
+            );
+        }
+        if rows.shows(t.settings.chime_every) {
+            let interval = &mut self.state.chimes.interval_minutes;
+            let mut minutes = *interval as f32;
+            let minutes_text = if *interval == 0 {
+                "—".to_string()
+            } else {
+                format!("{} {}", interval, t.common.min)
+            };
+            slider_row(
+                ui,
+                theme,
+                t.settings.chime_every,
+                &mut minutes,
+                0.0..=30.0,
+                5.0,
+                &minutes_text,
+            );
+            *interval = minutes.round() as u32;
+        }
+    }
+
+    /// Own file replacing the notification or the tick. It is decoded as soon
+    /// as it's picked, so a file that can't be played is refused right here.
+    fn sound_file_row(
+        &mut self,
+        ui: &mut Ui,
+        theme: &Theme,
+        label: &'static str,
+        tick: bool,
+        action: &mut Option<SettingsAction>,
+    ) {
+        let t = crate::i18n::tr();
+        let path = if tick {
+            &mut self.state.custom_tick
+        } else {
+            &mut self.state.custom_notification
+        };
+
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(label).color(theme.text_secondary))
+                .on_hover_text(t.settings.sound_files_hint);
+
+            ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                if path.is_some() && ui.button(t.settings.remove_image).clicked() {
+                    *path = None;
+                    self.sound_file_error = None;
+                }
+                if ui.button(t.settings.choose_image).clicked() {
+                    if let Some(picked) = rfd::FileDialog::new()
+                        .add_filter(t.settings.audio_files, &["wav", "ogg", "mp3"])
+                        .pick_file()
+                    {
+                        match AudioPlayer::load_sound_file(&picked) {
+                            Ok(_) => {
+                                *path = Some(picked);
+                                self.sound_file_error = None;
+                            }
+                            Err(e) => {
+                                tracing::warn!("Refused sound file: {}", e);
+                                let message = match e {
+                                    AudioError::FileTooLarge { .. } => {
+                                        t.settings.sound_file_too_large
+                                    }
+                                    AudioError::Decode { .. } => t.settings.sound_file_unsupported,
+                                    _ => t.settings.sound_file_unreadable,
+                                };
+                                self.sound_file_error = Some((label, message));
+                            }
+                        }
+                    }
+                }
+                if let Some(file) = path.as_deref() {
+                    if test_button(ui, theme) {
+                        *action = Some(SettingsAction::TestSoundFile(file.to_path_buf()));
+                    }
+                    if let Some(name) = file.file_name() {
+                        ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(name.to_string_lossy()).color(theme.text_muted),
+                            )
+                            .truncate(),
+                        );
+                    }
+                }
+            });
+        });
+
+        if let Some((_, message)) = self.sound_file_error.filter(|(row, _)| *row == label) {
+            ui.label(egui::RichText::new(message).color(theme.error).small());
+        }
+    }
+
+    /// Mixer of the ambient sounds
+    fn ambient_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        ui.label(
+            egui::RichText::new(t.settings.ambient_hint)
+                .color(theme.text_muted)
+                .small(),
+        );
+        ui.add_space(theme.spacing_sm);
+
+        for &sound in AmbientSound::all() {
+            if !rows.shows(sound.name()) {
+                continue;
+            }
+            let volume = self.state.ambient.volume_mut(sound);
+            let mut level = *volume as f32;
+            let level_text = if *volume == 0 {
+                "—".to_string()
+            } else {
+                format!("{}%", volume)
+            };
+            slider_row(
+                ui,
+                theme,
+                sound.name(),
+                &mut level,
+                0.0..=100.0,
+                5.0,
+                &level_text,
+            );
+            *volume = level.round() as u32;
+            ui.add_space(theme.spacing_xs);
+        }
+    }
+
+    fn appearance_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+
+        // Theme mode selector, with the schedule of the time of day mode
+        let schedule_rows = [
+            t.settings.theme,
+            t.settings.follow_sun,
+            t.settings.light_from,
+            t.settings.dark_from,
+            t.settings.latitude,
+        ];
+        if rows.shows_any(&schedule_rows) {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(t.settings.theme).color(theme.text_secondary));
+
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    combo_style(ui, theme);
+                    egui::ComboBox::from_id_salt("theme_mode")
+                        .selected_text(
+                            egui::RichText::new(self.state.theme_mode.name())
+                                .color(theme.text_primary),
+                        )
+                        .width(150.0)
+                        .show_ui(ui, |ui| {
+                            ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
+                            ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
+                            for mode in ThemeMode::all() {
+                                ui.selectable_value(
+                                    &mut self.state.theme_mode,
+                                    *mode,
+                                    egui::RichText::new(mode.name()).color(theme.text_primary),
+                                );
+                            }
+                        });
+                });
+            });
+
+            ui.add_space(theme.spacing_sm);
+        }
+        if self.state.theme_mode == ThemeMode::Schedule {
+            self.theme_schedule_rows(ui, theme, rows);
+        }
+
+        // Standard colors
+        let standard_colors: Vec<_> = AccentColor::all()
+            .iter()
+            .filter(|c| !c.is_retro())
+            .collect();
+        let retro_colors: Vec<_> = AccentColor::all().iter().filter(|c| c.is_retro()).collect();
+
+        if rows.shows(t.settings.accent_color) {
+            color_picker_row(
+                ui,
+                theme,
+                t.settings.accent_color,
+                &standard_colors,
+                &mut self.state.selected_accent,
+            );
+
+            ui.add_space(theme.spacing_sm);
+        }
+
+        if rows.shows(t.settings.color_custom) {
+            custom_color_row(
+                ui,
+                theme,
+                t.settings.color_custom,
+                &mut self.state.custom_accent,
+                &mut self.state.selected_accent,
+            );
+
+            ui.add_space(theme.spacing_sm);
+        }
+
+        if rows.shows(t.settings.retro_themes) {
+            color_picker_row(
+                ui,
+                theme,
+                t.settings.retro_themes,
+                &retro_colors,
+                &mut self.state.selected_accent,
+            );
+
+            ui.add_space(theme.spacing_sm);
+        }
+
+        // Window opacity slider
+        if rows.shows(t.settings.window_opacity) {
+            let opacity_text = format!("{}%", self.state.window_opacity.round() as u32);
+            slider_row(
+                ui,
+                theme,
+                t.settings.window_opacity,
+                &mut self.state.window_opacity,
+                30.0..=100.0,
+                5.0,
+                &opacity_text,
+            );
+
+            ui.add_space(theme.spacing_sm);
+        }
+
+        // Background image behind the timer
+        let image_rows = [
+            t.settings.background_image,
+            t.settings.background_blur,
+            t.settings.background_dim,
+        ];
+        if rows.shows_any(&image_rows) {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(t.settings.background_image).color(theme.text_secondary),
+                );
+
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    if self.state.background_image.is_some()
+                        && ui.button(t.settings.remove_image).clicked()
+                    {
+                        self.state.background_image = None;
+                    }
+                    if ui.button(t.settings.choose_image).clicked() {
+                        if let Some(path) = rfd::FileDialog::new()
+                            .add_filter(t.settings.png_images, &["png"])
+                            .pick_file()
+                        {
+                            self.state.background_image = Some(path);
+                        }
+                    }
+                    if let Some(name) = self
+                        .state
+                        .background_image
+                        .as_deref()
+                        .and_then(|p| p.file_name())
+                    {
+                        ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(name.to_string_lossy()).color(theme.text_muted),
+                            )
+                            .truncate(),
+                        );
+                    }
+                });
+            });
+        }
+
+        if self.state.background_image.is_some() {
+            if rows.shows(t.settings.background_blur) {
+                ui.add_space(theme.spacing_xs);
+                let blur_text = format!("{} px", self.state.background_blur.round() as u32);
+                slider_row(
+                    ui,
+                    theme,
+                    t.settings.background_blur,
+                    &mut self.state.background_blur,
+                    0.0..=20.0,
+                    1.0,
+                    &blur_text,
+                );
+            }
+            if rows.shows(t.settings.background_dim) {
+                ui.add_space(theme.spacing_xs);
+                let dim_text = format!("{}%", self.state.background_dim.round() as u32);
+                slider_row(
+                    ui,
+                    theme,
+                    t.settings.background_dim,
+                    &mut self.state.background_dim,
+                    0.0..=90.0,
+                    5.0,
+                    &dim_text,
+                );
+            }
+        }
+    }
+
+    /// When the "By time of day" mode turns light and dark
+    fn theme_schedule_rows(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let schedule = &mut self.state.theme_schedule;
+        if rows.shows(t.settings.follow_sun) {
+            toggle_row(ui, theme, t.settings.follow_sun, &mut schedule.follow_sun);
+        }
+
+        if schedule.follow_sun {
+            if rows.shows(t.settings.latitude) {
+                let latitude_text = format!("{:.0}°", schedule.latitude);
+                slider_row(
+                    ui,
+                    theme,
+                    t.settings.latitude,
+                    &mut schedule.latitude,
+                    -90.0..=90.0,
+                    1.0,
+                    &latitude_text,
+                );
+                ui.add_space(theme.spacing_sm);
+            }
+            return;
+        }
+
+        let times = [
+            (t.settings.light_from, &mut schedule.light_at),
+            (t.settings.dark_from, &mut schedule.dark_at),
+        ];
+        for (label, value) in times {
+            if !rows.shows(label) {
+                continue;
+            }
+            text_row(ui, theme, label, value, false);
+            if NaiveTime::parse_from_str(value.trim(), "%H:%M").is_err() {
+                ui.label(
+                    egui::RichText::new(t.settings.time_format_hint)
+                        .color(theme.error)
+                        .small(),
+                );
+            }
+        }
+        ui.add_space(theme.spacing_sm);
+    }
+
+    fn language_section(&mut self, ui: &mut Ui, theme: &Theme) {
+        let t = crate::i18n::tr();
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(t.settings.language).color(theme.text_secondary));
+
+            ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                combo_style(ui, theme);
+                egui::ComboBox::from_id_salt("language")
+                    .selected_text(
+                        egui::RichText::new(self.state.language.display_name())
+                            .color(theme.text_primary),
+                    )
+                    .width(150.0)
+                    .show_ui(ui, |ui| {
+                        ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
+                        ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
+                        for lang in crate::i18n::Language::all() {
+                            ui.selectable_value(
+                                &mut self.state.language,
+                                *lang,
+                                egui::RichText::new(lang.display_name()).color(theme.text_primary),
+                            );
+                        }
+                    });
+            });
+        });
+    }
+
+    fn accessibility_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let toggles = [
+            (t.settings.high_contrast, &mut self.state.high_contrast),
+            (t.settings.reduced_motion, &mut self.state.reduced_motion),
+            (t.settings.power_saver, &mut self.state.power_saver),
+        ];
+        for (label, value) in toggles {
+            if rows.shows(label) {
+                toggle_row(ui, theme, label, value);
+            }
+        }
+    }
+
+    fn system_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let toggles = [
+            (
+                t.settings.start_with_windows,
+                &mut self.state.start_with_windows,
+            ),
+            (
+                t.settings.dnd_during_focus,
+                &mut self.state.dnd_during_focus,
+            ),
+            (
+                t.settings.pause_media_during_focus,
+                &mut self.state.pause_media_during_focus,
+            ),
+            (t.settings.close_to_tray, &mut self.state.close_to_tray),
+            (t.settings.always_on_top, &mut self.state.always_on_top),
+            (
+                t.settings.raise_on_complete,
+                &mut self.state.raise_on_complete,
+            ),
+            (t.settings.title_countdown, &mut self.state.title_countdown),
+            (t.settings.track_apps, &mut self.state.track_apps),
+        ];
+        for (label, value) in toggles {
+            if rows.shows(label) {
+                toggle_row(ui, theme, label, value);
+            }
+        }
+
+        // The steps only appear once reminders are on, so keep the toggle for them
+        let reminders = &mut self.state.reminders;
+        if rows.shows_any(&[
+            t.settings.reminders,
+            t.settings.reminder_steps,
+            t.settings.reminder_sound,
+        ]) {
+            toggle_row(ui, theme, t.settings.reminders, &mut reminders.enabled);
+        }
+        if reminders.enabled {
+            if rows.shows(t.settings.reminder_steps) {
+                text_row(
+                    ui,
+                    theme,
+                    t.settings.reminder_steps,
+                    &mut self.state.reminder_steps,
+                    false,
+                );
+            }
+            if rows.shows(t.settings.reminder_sound) {
+                toggle_row(ui, theme, t.settings.reminder_sound, &mut reminders.sound);
+            }
+        }
+        if rows.shows(t.settings.kiosk_message) {
+            text_row(
+                ui,
+                theme,
+                t.settings.kiosk_message,
+                &mut self.state.kiosk_message,
+                false,
+            );
+        }
+    }
+
+    fn hue_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let fields = [
+            (
+                t.settings.hue_bridge_ip,
+                &mut self.state.hue_bridge_ip,
+                false,
+            ),
+            (t.settings.hue_app_key, &mut self.state.hue_app_key, true),
+            (t.settings.hue_target, &mut self.state.hue_target, false),
+        ];
+        let field_labels = fields.each_ref().map(|(label, _, _)| *label);
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.hue_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.hue_enabled,
+                &mut self.state.hue_enabled,
+            );
+        }
+        if self.state.hue_enabled {
+            for (label, value, secret) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, secret);
+                }
+            }
+        }
+    }
+
+    fn calendar_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let calendar = &mut self.state.calendar;
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.calendar_enabled)
+            || rows.shows_any(&[t.settings.calendar_url, t.settings.meeting_overlap])
+        {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.calendar_enabled,
+                &mut calendar.enabled,
+            );
+        }
+        if !calendar.enabled {
+            return;
+        }
+        if rows.shows(t.settings.calendar_url) {
+            text_row(
+                ui,
+                theme,
+                t.settings.calendar_url,
+                &mut calendar.ics_url,
+                true,
+            );
+        }
+        if rows.shows(t.settings.meeting_overlap) {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(t.settings.meeting_overlap).color(theme.text_secondary),
+                );
+
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    combo_style(ui, theme);
+                    egui::ComboBox::from_id_salt("meeting_overlap")
+                        .selected_text(
+                            egui::RichText::new(calendar.on_overlap.name())
+                                .color(theme.text_primary),
+                        )
+                        .width(140.0)
+                        .show_ui(ui, |ui| {
+                            ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
+                            ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
+                            for action in MeetingAction::all() {
+                                ui.selectable_value(
+                                    &mut calendar.on_overlap,
+                                    *action,
+                                    egui::RichText::new(action.name()).color(theme.text_primary),
+                                );
+                            }
+                        });
+                });
+            });
+        }
+    }
+
+    fn slack_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let slack = &mut self.state.slack;
+        let fields = [
+            (t.settings.slack_token, &mut slack.token, true),
+            (t.settings.slack_status_text, &mut slack.status_text, false),
+            (
+                t.settings.slack_status_emoji,
+                &mut slack.status_emoji,
+                false,
+            ),
+        ];
+        let field_labels = [
+            t.settings.slack_token,
+            t.settings.slack_status_text,
+            t.settings.slack_status_emoji,
+            t.settings.slack_dnd,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.slack_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(ui, theme, t.settings.slack_enabled, &mut slack.enabled);
+        }
+        if slack.enabled {
+            for (label, value, secret) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, secret);
+                }
+            }
+            if rows.shows(t.settings.slack_dnd) {
+                toggle_row(ui, theme, t.settings.slack_dnd, &mut slack.dnd);
+            }
+        }
+    }
+
+    fn todoist_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let todoist = &mut self.state.todoist;
+        let fields = [
+            (t.settings.todoist_token, &mut todoist.token, true),
+            (t.settings.todoist_filter, &mut todoist.filter, false),
+        ];
+        let toggles = [
+            (t.settings.todoist_comment, &mut todoist.comment_on_session),
+            (t.settings.todoist_close, &mut todoist.close_when_done),
+        ];
+        let field_labels = [
+            t.settings.todoist_token,
+            t.settings.todoist_filter,
+            t.settings.todoist_comment,
+            t.settings.todoist_close,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.todoist_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(ui, theme, t.settings.todoist_enabled, &mut todoist.enabled);
+        }
+        if todoist.enabled {
+            for (label, value, secret) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, secret);
+                }
+            }
+            for (label, value) in toggles {
+                if rows.shows(label) {
+                    toggle_row(ui, theme, label, value);
+                }
+            }
+        }
+    }
+
+    fn taskwarrior_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let taskwarrior = &mut self.state.taskwarrior;
+        let fields = [
+            (t.settings.taskwarrior_command, &mut taskwarrior.command),
+            (t.settings.taskwarrior_filter, &mut taskwarrior.filter),
+        ];
+        let toggles = [
+            (
+                t.settings.taskwarrior_track_time,
+                &mut taskwarrior.track_time,
+            ),
+            (t.settings.taskwarrior_annotate, &mut taskwarrior.annotate),
+        ];
+        let field_labels = [
+            t.settings.taskwarrior_command,
+            t.settings.taskwarrior_filter,
+            t.settings.taskwarrior_track_time,
+            t.settings.taskwarrior_annotate,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.taskwarrior_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.taskwarrior_enabled,
+                &mut taskwarrior.enabled,
+            );
+        }
+        if taskwarrior.enabled {
+            for (label, value) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, false);
+                }
+            }
+            for (label, value) in toggles {
+                if rows.shows(label) {
+                    toggle_row(ui, theme, label, value);
+                }
+            }
+        }
+    }
+
+    fn obsidian_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let obsidian = &mut self.state.obsidian;
+        let fields = [
+            (t.settings.obsidian_vault, &mut obsidian.vault_path),
+            (t.settings.obsidian_folder, &mut obsidian.folder),
+            (t.settings.obsidian_date_format, &mut obsidian.date_format),
+            (t.settings.obsidian_template, &mut obsidian.template),
+        ];
+        let field_labels = [
+            t.settings.obsidian_vault,
+            t.settings.obsidian_folder,
+            t.settings.obsidian_date_format,
+            t.settings.obsidian_template,
+            t.settings.obsidian_log_breaks,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.obsidian_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.obsidian_enabled,
+                &mut obsidian.enabled,
+            );
+        }
+        if obsidian.enabled {
+            for (label, value) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, false);
+                }
+            }
+            if rows.shows(t.settings.obsidian_log_breaks) {
+                toggle_row(
+                    ui,
+                    theme,
+                    t.settings.obsidian_log_breaks,
+                    &mut obsidian.log_breaks,
+                );
+            }
+        }
+    }
+
+    fn activitywatch_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let activitywatch = &mut self.state.activitywatch;
+        let fields = [
+            (t.settings.activitywatch_url, &mut activitywatch.server_url),
+            (t.settings.activitywatch_bucket, &mut activitywatch.bucket),
+        ];
+        let field_labels = [
+            t.settings.activitywatch_url,
+            t.settings.activitywatch_bucket,
+            t.settings.activitywatch_breaks,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.activitywatch_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.activitywatch_enabled,
+                &mut activitywatch.enabled,
+            );
+        }
+        if activitywatch.enabled {
+            for (label, value) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, false);
+                }
+            }
+            if rows.shows(t.settings.activitywatch_breaks) {
+                toggle_row(
+                    ui,
+                    theme,
+                    t.settings.activitywatch_breaks,
+                    &mut activitywatch.include_breaks,
+                );
+            }
+        }
+    }
+
+    fn notion_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let notion = &mut self.state.notion;
+        let fields = [
+            (t.settings.notion_token, &mut notion.token, true),
+            (t.settings.notion_database, &mut notion.database_id, false),
+            (
+                t.settings.notion_title_property,
+                &mut notion.title_property,
+                false,
+            ),
+            (
+                t.settings.notion_date_property,
+                &mut notion.date_property,
+                false,
+            ),
+            (
+                t.settings.notion_duration_property,
+                &mut notion.duration_property,
+                false,
+            ),
+            (
+                t.settings.notion_tag_property,
+                &mut notion.tag_property,
+                false,
+            ),
+        ];
+        let field_labels = [
+            t.settings.notion_token,
+            t.settings.notion_database,
+            t.settings.notion_title_property,
+            t.settings.notion_date_property,
+            t.settings.notion_duration_property,
+            t.settings.notion_tag_property,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.notion_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(ui, theme, t.settings.notion_enabled, &mut notion.enabled);
+        }
+        if notion.enabled {
+            for (label, value, secret) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, secret);
+                }
+            }
+        }
+    }
+
+    fn telegram_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let telegram = &mut self.state.telegram;
+        let fields = [
+            (t.settings.telegram_token, &mut telegram.token, true),
+            (t.settings.telegram_chat, &mut telegram.chat_id, false),
+        ];
+        let field_labels = [
+            t.settings.telegram_token,
+            t.settings.telegram_chat,
+            t.settings.telegram_notify,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.telegram_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.telegram_enabled,
+                &mut telegram.enabled,
+            );
+        }
+        if telegram.enabled {
+            for (label, value, secret) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, secret);
+                }
+            }
+            if rows.shows(t.settings.telegram_notify) {
+                toggle_row(
+                    ui,
+                    theme,
+                    t.settings.telegram_notify,
+                    &mut telegram.notify_complete,
+                );
+            }
+        }
+    }
+
+    fn group_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let group = &mut self.state.group;
+        let field_labels = [
+            t.settings.group_role,
+            t.settings.group_address,
+            t.settings.group_name,
+            t.settings.group_code,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.group_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(ui, theme, t.settings.group_enabled, &mut group.enabled);
+        }
+        if !group.enabled {
+            return;
+        }
+        if rows.shows(t.settings.group_role) {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(t.settings.group_role).color(theme.text_secondary));
+
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    combo_style(ui, theme);
+                    egui::ComboBox::from_id_salt("group_role")
+                        .selected_text(
+                            egui::RichText::new(group.role.name()).color(theme.text_primary),
+                        )
+                        .width(140.0)
+                        .show_ui(ui, |ui| {
+                            ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
+                            ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
+                            for role in GroupRole::all() {
+                                ui.selectable_value(
+                                    &mut group.role,
+                                    *role,
+                                    egui::RichText::new(role.name()).color(theme.text_primary),
+                                );
+                            }
+                        });
+                });
+            });
+        }
+        if group.role == GroupRole::Follow && rows.shows(t.settings.group_address) {
+            text_row(ui, theme, t.settings.group_address, &mut group.host, false);
+        }
+        let fields = [
+            (t.settings.group_name, &mut group.name, false),
+            (t.settings.group_code, &mut group.code, true),
+        ];
+        for (label, value, secret) in fields {
+            if rows.shows(label) {
+                text_row(ui, theme, label, value, secret);
+            }
+        }
+    }
+
+    fn email_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let email = &mut self.state.email;
+        let fields = [
+            (t.settings.email_server, &mut email.server, false),
+            (t.settings.email_username, &mut email.username, false),
+            (t.settings.email_password, &mut email.password, true),
+            (t.settings.email_from, &mut email.from, false),
+            (t.settings.email_to, &mut email.to, false),
+        ];
+        let field_labels = [
+            t.settings.email_server,
+            t.settings.email_username,
+            t.settings.email_password,
+            t.settings.email_from,
+            t.settings.email_to,
+            t.settings.email_send_at,
+        ];
+
+        // The fields only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.email_enabled) || rows.shows_any(&field_labels) {
+            toggle_row(ui, theme, t.settings.email_enabled, &mut email.enabled);
+        }
+        if email.enabled {
+            for (label, value, secret) in fields {
+                if rows.shows(label) {
+                    text_row(ui, theme, label, value, secret);
+                }
+            }
+            if rows.shows(t.settings.email_send_at) {
+                text_row(
+                    ui,
+                    theme,
+                    t.settings.email_send_at,
+                    &mut email.send_at,
+                    false,
+                );
+                if NaiveTime::parse_from_str(email.send_at.trim(), "%H:%M").is_err() {
+                    ui.label(
+                        egui::RichText::new(t.settings.time_format_hint)
+                            .color(theme.error)
+                            .small(),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Master switch, then one switch per installed plugin
+    fn plugins_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let plugins = &mut self.state.plugins;
+        let names: Vec<&str> = self
+            .state
+            .installed_plugins
+            .iter()
+            .map(|(_, name)| name.as_str())
+            .collect();
+
+        // The plugins only appear once the toggle is on, so keep it for them
+        if rows.shows(t.settings.plugins_enabled) || rows.shows_any(&names) {
+            toggle_row(ui, theme, t.settings.plugins_enabled, &mut plugins.enabled);
+        }
+        if !plugins.enabled {
+            return;
+        }
+        if let Some(dir) = PluginsConfig::plugins_dir() {
+            ui.label(
+                egui::RichText::new(format!("{} {}", t.settings.plugins_hint, dir.display()))
+                    .color(theme.text_muted)
+                    .small(),
+            );
+            ui.add_space(theme.spacing_sm);
+        }
+        if self.state.installed_plugins.is_empty() {
+            ui.label(egui::RichText::new(t.settings.no_plugins).color(theme.text_secondary));
+        }
+        for (id, name) in &self.state.installed_plugins {
+            if !rows.shows(name) {
+                continue;
+            }
+            let mut runs = !plugins.disabled.contains(id);
+            toggle_row(ui, theme, name, &mut runs);
+            if runs {
+                plugins.disabled.retain(|d| d != id);
+            } else if !plugins.disabled.contains(id) {
+                plugins.disabled.push(id.clone());
+            }
+        }
+    }
+
+    fn goals_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        if rows.shows(t.settings.daily_goal) {
+            duration_row_with_unit(
+                ui,
+                theme,
+                t.settings.daily_goal,
+                &mut self.state.daily_goal,
+                1.0,
+                16.0,
+                t.settings.pomodoros,
+            );
+        }
+        if rows.shows(t.settings.notify_goal_reached) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.notify_goal_reached,
+                &mut self.state.notify_on_goal,
+            );
+        }
+        if rows.shows(t.settings.rest_days) {
+            ui.add_space(theme.spacing_xs);
+            ui.label(egui::RichText::new(t.settings.rest_days).color(theme.text_secondary));
+            ui.horizontal(|ui| {
+                for (day, rest) in t.days_of_week().into_iter().zip(&mut self.state.rest_days) {
+                    ui.toggle_value(rest, day);
+                }
+            });
+            ui.label(
+                egui::RichText::new(t.settings.rest_days_hint)
+                    .color(theme.text_muted)
+                    .small(),
+            );
+        }
+    }
+
+    /// A preset and daily goal for each weekday
+    fn weekday_plans_section(&mut self, ui: &mut Ui, theme: &Theme) {
+        let t = crate::i18n::tr();
+        toggle_row(
+            ui,
+            theme,
+            t.settings.weekday_plans_enabled,
+            &mut self.state.weekdays.enabled,
+        );
+        if !self.state.weekdays.enabled {
+            return;
+        }
+        ui.label(
+            egui::RichText::new(t.settings.weekday_plans_hint)
+                .color(theme.text_muted)
+                .small(),
+        );
+        ui.add_space(theme.spacing_sm);
+
+        let days = t.days_of_week();
+        for (i, plan) in self.state.weekdays.days.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(days[i]).color(theme.text_secondary));
+
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut plan.daily_target)
+                            .range(0..=16)
+                            .custom_formatter(|n, _| {
+                                if n == 0.0 {
+                                    "—".to_string()
+                                } else {
+                                    format!("{} {}", n, t.settings.pomodoros)
+                                }
+                            }),
+                    );
+                    ui.add_space(4.0);
+
+                    combo_style(ui, theme);
+                    egui::ComboBox::from_id_salt(("weekday_preset", i))
+                        .selected_text(
+                            egui::RichText::new(plan.preset.name()).color(theme.text_primary),
+                        )
+                        .width(110.0)
+                        .show_ui(ui, |ui| {
+                            ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
+                            ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
+                            for preset in DayPreset::all() {
+                                ui.selectable_value(
+                                    &mut plan.preset,
+                                    *preset,
+                                    egui::RichText::new(preset.name()).color(theme.text_primary),
+                                );
+                            }
+                        });
+                });
+            });
+            ui.add_space(theme.spacing_xs);
+        }
+    }
+
+    fn presets_section(&mut self, ui: &mut Ui, theme: &Theme, action: &mut Option<SettingsAction>) {
+        let t = crate::i18n::tr();
+        let presets = [
+            (t.settings.preset_classic, "25/5/15"),
+            (t.settings.preset_short, "15/3/10"),
+            (t.settings.preset_long, "50/10/30"),
+        ];
+
+        let button_width = (ui.available_width() - theme.spacing_sm * 2.0) / 3.0;
+
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = theme.spacing_sm;
+            for (i, preset) in presets.iter().enumerate() {
+                let preset_btn = egui::Button::new(
+                    egui::RichText::new(format!("{}\n{}", preset.0, preset.1))
+                        .color(theme.text_primary),
+                )
+                .fill(theme.bg_tertiary)
+                .stroke(egui::Stroke::new(1.0, theme.border_subtle));
+
+                if ui.add_sized(vec2(button_width, 48.0), preset_btn).clicked() {
+                    *action = Some(SettingsAction::SelectPreset(i));
+                }
+            }
+        });
+
+        ui.add_space(theme.spacing_sm);
+        ui.label(
+            egui::RichText::new(t.settings.preset_behavior_hint)
+                .color(theme.text_muted)
+                .small(),
+        );
+        ui.add_space(theme.spacing_xs);
+
+        for (i, behavior) in self.state.presets.behaviors_mut().into_iter().enumerate() {
+            egui::CollapsingHeader::new(
+                egui::RichText::new(presets[i].0).color(theme.text_secondary),
+            )
+            .id_salt(("preset_behavior", i))
+            .show(ui, |ui| {
+                let rows = [
+                    (
+                        t.settings.auto_start_breaks,
+                        &mut behavior.auto_start_breaks,
+                    ),
+                    (
+                        t.settings.auto_start_pomodoros,
+                        &mut behavior.auto_start_work,
+                    ),
+                    (t.settings.strict_focus, &mut behavior.strict),
+                    (t.settings.sounds, &mut behavior.sounds_enabled),
+                    (t.settings.tick_sound, &mut behavior.tick_enabled),
+                ];
+                for (row, (label, value)) in rows.into_iter().enumerate() {
+                    behavior_row(ui, theme, (i, row), label, value);
+                }
+            });
+        }
+    }
+
+    /// Working days and hours, and what they change
+    fn working_hours_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let hours = &mut self.state.working_hours;
+        toggle_row(
+            ui,
+            theme,
+            t.settings.working_hours_enabled,
+            &mut hours.enabled,
+        );
+        if !hours.enabled {
+            return;
+        }
+        ui.label(
+            egui::RichText::new(t.settings.working_hours_hint)
+                .color(theme.text_muted)
+                .small(),
+        );
+        ui.add_space(theme.spacing_sm);
+
+        ui.horizontal(|ui| {
+            for (day, working) in t.days_of_week().into_iter().zip(&mut hours.days) {
+                ui.toggle_value(working, day);
+            }
+        });
+        ui.add_space(theme.spacing_xs);
+
+        let times = [
+            (t.settings.work_starts, &mut hours.start),
+            (t.settings.work_ends, &mut hours.end),
+        ];
+        for (label, value) in times {
+            if !rows.shows(label) {
+                continue;
+            }
+            text_row(ui, theme, label, value, false);
+            if NaiveTime::parse_from_str(value.trim(), "%H:%M").is_err() {
+                ui.label(
+                    egui::RichText::new(t.settings.time_format_hint)
+                        .color(theme.error)
+                        .small(),
+                );
+            }
+        }
+        ui.add_space(theme.spacing_sm);
+
+        if rows.shows(t.settings.hold_auto_start) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.hold_auto_start,
+                &mut hours.hold_auto_start,
+            );
+        }
+        if rows.shows(t.settings.warn_late_focus) {
+            toggle_row(ui, theme, t.settings.warn_late_focus, &mut hours.warn_late);
+        }
+    }
+
+    fn hotkeys_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        let labels = [
+            t.settings.toggle_start_pause,
+            t.settings.skip_session,
+            t.settings.reset_timer,
+            t.settings.mute_hotkey,
+        ];
+        let media_labels = [t.settings.media_keys, t.settings.media_keys_unless_playing];
+
+        // Everything below the toggle only appears once it is on
+        if rows.shows(t.settings.enable_hotkeys)
+            || rows.shows_any(&labels)
+            || rows.shows_any(&media_labels)
+        {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.enable_hotkeys,
+                &mut self.state.hotkeys_enabled,
+            );
+        }
+
+        if !self.state.hotkeys_enabled {
+            return;
+        }
+        ui.add_space(theme.spacing_xs);
+
+        // Click a binding to record a new combo
+        let bindings = self.state.hotkey_bindings_mut();
+        for (i, label) in labels.into_iter().enumerate() {
+            if !rows.shows(label) {
+                continue;
+            }
+            let error = self
+                .hotkey_error
+                .filter(|(field, _)| *field == i)
+                .map(|(_, error)| error);
+            match hotkey_row(ui, theme, label, bindings[i], error) {
+                Some(HotkeyInput::Recorded(combo)) => {
+                    let taken = bindings
+                        .iter()
+                        .enumerate()
+                        .any(|(j, other)| j != i && other.eq_ignore_ascii_case(&combo));
+                    if taken {
+                        self.hotkey_error = Some((i, t.settings.hotkey_in_use));
+                    } else {
+                        *bindings[i] = combo;
+                        self.hotkey_error = None;
+                    }
+                }
+                Some(HotkeyInput::Unsupported) => {
+                    self.hotkey_error = Some((i, t.settings.hotkey_unsupported));
+                }
+                None => {}
+            }
+        }
+
+        ui.add_space(theme.spacing_xs);
+        if rows.shows_any(&media_labels) {
+            toggle_row(ui, theme, t.settings.media_keys, &mut self.state.media_keys);
+        }
+        if self.state.media_keys && rows.shows(t.settings.media_keys_unless_playing) {
+            toggle_row(
+                ui,
+                theme,
+                t.settings.media_keys_unless_playing,
+                &mut self.state.media_keys_unless_playing,
+            );
+        }
+    }
+
+    /// Saved profiles to switch to or delete, and a row to save a new one
+    fn profiles_section(
+        &mut self,
+        ui: &mut Ui,
+        theme: &Theme,
+        action: &mut Option<SettingsAction>,
+    ) {
+        let t = crate::i18n::tr();
+        ui.label(
+            egui::RichText::new(t.settings.profiles_hint)
+                .color(theme.text_muted)
+                .small(),
+        );
+        ui.add_space(theme.spacing_sm);
+
+        let profiles = &self.state.profiles;
+        for profile in &profiles.saved {
+            let active = profiles
+                .active
+                .as_ref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(&profile.name));
+            ui.horizontal(|ui| {
+                let name = egui::RichText::new(&profile.name);
+                ui.label(if active {
+                    name.color(theme.accent.solid()).strong()
+                } else {
+                    name.color(theme.text_secondary)
+                });
+
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    let delete = ui
+                        .add(
+                            egui::Button::new(egui::RichText::new("×").color(theme.text_muted))
+                                .frame(false),
+                        )
+                        .on_hover_text(t.settings.delete_profile);
+                    if delete.clicked() {
+                        *action = Some(SettingsAction::DeleteProfile(profile.name.clone()));
+                    }
+                    if !active && ui.button(t.settings.use_profile).clicked() {
+                        *action = Some(SettingsAction::UseProfile(profile.name.clone()));
+                    }
+                });
+            });
+            ui.add_space(theme.spacing_xs);
+        }
+
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.profile_name)
+                    .hint_text(t.settings.profile_name_hint)
+                    .desired_width(150.0),
+            );
+            let name = self.profile_name.trim();
+            if ui
+                .add_enabled(!name.is_empty(), egui::Button::new(t.settings.save_profile))
+                .clicked()
+            {
+                *action = Some(SettingsAction::SaveProfile(name.to_string()));
+                self.profile_name.clear();
+            }
+        });
+    }
+
+    fn notification_text_section(&mut self, ui: &mut Ui, theme: &Theme, rows: Rows) {
+        let t = crate::i18n::tr();
+        ui.label(
+            egui::RichText::new(t.settings.notification_placeholders)
+                .color(theme.text_muted)
+                .small(),
+        );
+        ui.add_space(theme.spacing_sm);
+
+        // Each event with its built-in title and body as the hints
+        let texts = &mut self.state.notification_text;
+        let events = [
+            (
+                t.notif.focus_complete,
+                t.notif.time_for_break,
+                &mut texts.focus_complete,
+            ),
+            (
+                t.notif.break_over,
+                t.notif.ready_to_focus,
+                &mut texts.break_complete,
+            ),
+            (
+                t.notif.long_break_over,
+                t.notif.back_to_work,
+                &mut texts.long_break_complete,
+            ),
+            (
+                t.notif.daily_goal_reached,
+                t.settings.pomodoros,
+                &mut texts.goal_reached,
+            ),
+        ];
+        for (title, body, text) in events {
+            if !rows.shows(title) {
+                continue;
+            }
+            ui.label(egui::RichText::new(title).color(theme.text_secondary));
+            ui.add(
+                egui::TextEdit::singleline(&mut text.title)
+                    .hint_text(title)
+                    .desired_width(f32::INFINITY),
+            );
+            ui.add(
+                egui::TextEdit::singleline(&mut text.body)
+                    .hint_text(body)
+                    .desired_width(f32::INFINITY),
+            );
+            ui.add_space(theme.spacing_sm);
+        }
+    }
+}
+
+fn command_line_section(ui: &mut Ui, theme: &Theme) {
+    let t = crate::i18n::tr();
+    ui.label(egui::RichText::new(t.settings.control_from_terminal).color(theme.text_secondary));
+    ui.add_space(theme.spacing_xs);
+
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new("pomodorust status")
+                .color(theme.text_muted)
+                .code(),
+        );
+    });
+
+    ui.add_space(theme.spacing_sm);
+
+    // Get current exe path for the command
+    let exe_path = std::env::current_exe()
+        .ok()
+        .and_then(|p| p.parent().map(|p| p.to_path_buf()))
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|| "C:\\path\\to\\pomodorust".to_string());
+
+    let powershell_cmd = format!(
+        "$p = [Environment]::GetEnvironmentVariable('Path', 'User'); if ($p -notlike '*{}*') {{ [Environment]::SetEnvironmentVariable('Path', \"$p;{}\", 'User') }}",
+        exe_path, exe_path
+    );
+
+    let copy_btn = egui::Button::new(
+        egui::RichText::new(t.settings.copy_path_command).color(theme.text_primary),
+    )
+    .fill(theme.bg_tertiary)
+    .stroke(egui::Stroke::new(1.0, theme.border_subtle));
+
+    if ui
+        .add_sized(vec2(ui.available_width(), 32.0), copy_btn)
+        .on_hover_text(t.settings.copy_path_tooltip)
+        .clicked()
+    {
+        ui.ctx().copy_text(powershell_cmd);
+    }
+
+    ui.add_space(theme.spacing_xs);
+    ui.label(
+        egui::RichText::new(t.settings.run_copied_command)
+            .color(theme.text_muted)
+            .small(),
+    );
+}
+
+/// Keep / On / Off choice for a setting a preset may switch
+fn behavior_row(
+    ui: &mut Ui,
+    theme: &Theme,
+    id: impl std::hash::Hash,
+    label: &str,
+    value: &mut Option<bool>,
+) {
+    let t = crate::i18n::tr();
+    let name = |value: Option<bool>| match value {
+        None => t.settings.behavior_keep,
+        Some(true) => t.settings.behavior_on,
+        Some(false) => t.settings.behavior_off,
+    };
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(label).color(theme.text_secondary));
+
+        ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+            combo_style(ui, theme);
+            egui::ComboBox::from_id_salt(("behavior", id))
+                .selected_text(egui::RichText::new(name(*value)).color(theme.text_primary))
+                .width(100.0)
+                .show_ui(ui, |ui| {
+                    ui.style_mut().visuals.widgets.inactive.bg_fill = theme.bg_secondary;
+                    ui.style_mut().visuals.widgets.hovered.bg_fill = theme.bg_hover;
+                    for choice in [None, Some(true), Some(false)] {
+                        ui.selectable_value(
+                            value,
+                            choice,
+                            egui::RichText::new(name(choice)).color(theme.text_primary),
+                        );
+                    }
+                });
+        });
+    });
+}
@@ -1,5 +1,11 @@
 use super::super::theme::{AccentColor, ThemeMode};
-use crate::data::{Config, NotificationSound};
+use crate::data::{
+    ActivityWatchConfig, AmbientConfig, CalendarConfig, ChimeConfig, Config, EmailConfig,
+    GroupConfig, NotificationSound, NotificationTextConfig, NotionConfig, ObsidianConfig,
+    PluginsConfig, PresetsConfig, ProfilesConfig, QuickStartEntry, ReminderConfig, SlackConfig,
+    TaskwarriorConfig, TelegramConfig, ThemeScheduleConfig, TodoistConfig, WeekdayConfig,
+    WorkingHoursConfig,
+};
 use crate::i18n::Language;
 
 /// Editable settings state - extracted from Config for UI editing
@@ -17,30 +23,87 @@ pub struct SettingsState {
     pub volume: f32,
     pub notification_sound: NotificationSound,
     pub tick_enabled: bool,
+    pub tick_speed_up: bool,
+    pub custom_notification: Option<std::path::PathBuf>,
+    pub custom_tick: Option<std::path::PathBuf>,
+    pub ambient: AmbientConfig,
+    pub chimes: ChimeConfig,
+    // Notifications
+    pub notification_text: NotificationTextConfig,
+    pub reminders: ReminderConfig,
+    /// Reminder steps as typed, parsed when saving
+    pub reminder_steps: String,
     // Auto-start settings
     pub auto_start_breaks: bool,
     pub auto_start_work: bool,
+    pub require_intent: bool,
+    pub strict: bool,
+    /// Minutes, as the config's seconds may not be whole minutes
+    pub min_record_minutes: f32,
+    pub presets: PresetsConfig,
     // System settings
     pub start_with_windows: bool,
+    pub dnd_during_focus: bool,
+    pub pause_media_during_focus: bool,
+    pub close_to_tray: bool,
+    pub track_apps: bool,
     // Window settings
     pub always_on_top: bool,
+    pub raise_on_complete: bool,
+    pub title_countdown: bool,
+    pub kiosk_message: String,
     // Appearance
     pub theme_mode: ThemeMode,
+    pub theme_schedule: ThemeScheduleConfig,
     pub selected_accent: AccentColor,
+    /// Color shown in the custom accent picker
+    pub custom_accent: [u8; 3],
     pub window_opacity: f32,
+    pub background_image: Option<std::path::PathBuf>,
+    pub background_blur: f32,
+    pub background_dim: f32,
+    // Quick start
+    pub quick_start: Vec<QuickStartEntry>,
     // Goals
     pub daily_goal: f32,
     pub notify_on_goal: bool,
+    pub rest_days: [bool; 7],
+    pub weekdays: WeekdayConfig,
+    pub working_hours: WorkingHoursConfig,
     // Hotkeys
     pub hotkeys_enabled: bool,
     pub hotkey_toggle: String,
     pub hotkey_skip: String,
     pub hotkey_reset: String,
+    pub hotkey_mute: String,
+    pub media_keys: bool,
+    pub media_keys_unless_playing: bool,
     // Accessibility
     pub high_contrast: bool,
     pub reduced_motion: bool,
+    pub power_saver: bool,
+    // Philips Hue
+    pub hue_enabled: bool,
+    pub hue_bridge_ip: String,
+    pub hue_app_key: String,
+    pub hue_target: String,
+    pub calendar: CalendarConfig,
+    pub slack: SlackConfig,
+    pub todoist: TodoistConfig,
+    pub taskwarrior: TaskwarriorConfig,
+    pub obsidian: ObsidianConfig,
+    pub activitywatch: ActivityWatchConfig,
+    pub notion: NotionConfig,
+    pub telegram: TelegramConfig,
+    pub group: GroupConfig,
+    pub email: EmailConfig,
+    pub plugins: PluginsConfig,
+    /// Installed plugins as (id, name), found when the settings open
+    pub installed_plugins: Vec<(String, String)>,
     // Language
     pub language: Language,
+    // Saved profiles, only changed through profile actions
+    pub profiles: ProfilesConfig,
 }
 
 impl SettingsState {
@@ -54,22 +117,83 @@ impl SettingsState {
             volume: config.sounds.volume as f32,
             notification_sound: config.sounds.notification_sound,
             tick_enabled: config.sounds.tick_enabled,
+            tick_speed_up: config.sounds.tick_speed_up,
+            custom_notification: config.sounds.custom_notification.clone(),
+            custom_tick: config.sounds.custom_tick.clone(),
+            ambient: config.ambient.clone(),
+            chimes: config.chimes.clone(),
+            notification_text: config.notification_text.clone(),
+            reminders: config.reminders.clone(),
+            reminder_steps: config.reminders.steps_text(),
             auto_start_breaks: config.timer.auto_start_breaks,
             auto_start_work: config.timer.auto_start_work,
+            require_intent: config.timer.require_intent,
+            strict: config.timer.strict,
+            min_record_minutes: config.timer.min_record_secs as f32 / 60.0,
+            presets: config.presets.clone(),
             start_with_windows: config.system.start_with_windows,
+            dnd_during_focus: config.system.dnd_during_focus,
+            pause_media_during_focus: config.system.pause_media_during_focus,
+            close_to_tray: config.system.close_to_tray,
+            track_apps: config.system.track_apps,
             always_on_top: config.window.always_on_top,
+            raise_on_complete: config.window.raise_on_complete,
+            title_countdown: config.window.title_countdown,
+            kiosk_message: config.window.kiosk_message.clone(),
             theme_mode: config.appearance.theme_mode,
+            theme_schedule: config.appearance.theme_schedule.clone(),
             selected_accent: config.appearance.accent_color,
+            custom_accent: config
+                .appearance
+                .accent_color
+                .custom_rgb()
+                .unwrap_or_else(|| {
+                    let color = config.appearance.accent_color.solid();
+                    [color.r(), color.g(), color.b()]
+                }),
             window_opacity: config.appearance.window_opacity as f32,
+            background_image: config.appearance.background_image.clone(),
+            background_blur: config.appearance.background_blur as f32,
+            background_dim: config.appearance.background_dim as f32,
+            quick_start: config.quick_start.entries.clone(),
             daily_goal: config.goals.daily_target as f32,
             notify_on_goal: config.goals.notify_on_goal,
+            rest_days: config.goals.rest_days,
+            weekdays: config.weekdays.clone(),
+            working_hours: config.working_hours.clone(),
             hotkeys_enabled: config.hotkeys.enabled,
             hotkey_toggle: config.hotkeys.toggle.clone(),
             hotkey_skip: config.hotkeys.skip.clone(),
             hotkey_reset: config.hotkeys.reset.clone(),
+            hotkey_mute: config.hotkeys.mute.clone(),
+            media_keys: config.hotkeys.media_keys,
+            media_keys_unless_playing: config.hotkeys.media_keys_unless_playing,
             high_contrast: config.accessibility.high_contrast,
             reduced_motion: config.accessibility.reduced_motion,
+            power_saver: config.accessibility.power_saver,
+            hue_enabled: config.hue.enabled,
+            hue_bridge_ip: config.hue.bridge_ip.clone(),
+            hue_app_key: config.hue.app_key.clone(),
+            hue_target: config.hue.target.clone(),
+            calendar: config.calendar.clone(),
+            slack: config.slack.clone(),
+            todoist: config.todoist.clone(),
+            taskwarrior: config.taskwarrior.clone(),
+            obsidian: config.obsidian.clone(),
+            activitywatch: config.activitywatch.clone(),
+            notion: config.notion.clone(),
+            telegram: config.telegram.clone(),
+            group: config.group.clone(),
+            email: config.email.clone(),
+            plugins: config.plugins.clone(),
+            installed_plugins: PluginsConfig::plugins_dir()
+                .map(|dir| crate::integrations::plugins::discover(&dir))
+                .unwrap_or_default()
+                .into_iter()
+                .map(|plugin| (plugin.id, plugin.manifest.name))
+                .collect(),
             language: config.appearance.language,
+            profiles: config.profiles.clone(),
         }
     }
 
@@ -78,6 +202,16 @@ impl SettingsState {
         self.apply_to(config) != *config
     }
 
+    /// Hotkey bindings in display order: toggle, skip, reset, mute
+    pub fn hotkey_bindings_mut(&mut self) -> [&mut String; 4] {
+        [
+            &mut self.hotkey_toggle,
+            &mut self.hotkey_skip,
+            &mut self.hotkey_reset,
+            &mut self.hotkey_mute,
+        ]
+    }
+
     /// Apply the editing state to a Config, returning a new Config
     pub fn apply_to(&self, original: &Config) -> Config {
         let mut config = original.clone();
@@ -87,22 +221,118 @@ impl SettingsState {
         config.timer.sessions_before_long = self.sessions_before_long.round() as u32;
         config.timer.auto_start_breaks = self.auto_start_breaks;
         config.timer.auto_start_work = self.auto_start_work;
+        config.timer.strict = self.strict;
+        config.timer.min_record_secs = (self.min_record_minutes * 60.0).round() as u32;
+        config.presets = self.presets.clone();
+        config.timer.require_intent = self.require_intent;
         config.sounds.volume = self.volume.round() as u32;
         config.sounds.notification_sound = self.notification_sound;
         config.sounds.tick_enabled = self.tick_enabled;
+        config.sounds.tick_speed_up = self.tick_speed_up;
+        config.sounds.custom_notification = self.custom_notification.clone();
+        config.sounds.custom_tick = self.custom_tick.clone();
+        config.ambient = self.ambient.clone();
+        config.chimes = self.chimes.clone();
+        config.notification_text = self.notification_text.clone();
+        config.reminders = ReminderConfig {
+            steps_minutes: ReminderConfig::parse_steps(&self.reminder_steps),
+            ..self.reminders.clone()
+        };
         config.system.start_with_windows = self.start_with_windows;
+        config.system.dnd_during_focus = self.dnd_during_focus;
+        config.system.pause_media_during_focus = self.pause_media_during_focus;
+        config.system.close_to_tray = self.close_to_tray;
+        config.system.track_apps = self.track_apps;
         config.window.always_on_top = self.always_on_top;
+        config.window.raise_on_complete = self.raise_on_complete;
+        config.window.title_countdown = self.title_countdown;
+        config.window.kiosk_message = self.kiosk_message.trim().to_string();
         config.appearance.theme_mode = self.theme_mode;
+        config.appearance.theme_schedule = self.theme_schedule.clone();
         config.appearance.accent_color = self.selected_accent;
         config.appearance.window_opacity = self.window_opacity.round() as u32;
+        config.appearance.background_image = self.background_image.clone();
+        config.appearance.background_blur = self.background_blur.round() as u32;
+        config.appearance.background_dim = self.background_dim.round() as u32;
+        config.quick_start.entries = self.quick_start.clone();
         config.goals.daily_target = self.daily_goal.round() as u32;
         config.goals.notify_on_goal = self.notify_on_goal;
+        config.goals.rest_days = self.rest_days;
+        config.weekdays = self.weekdays.clone();
+        config.working_hours = self.working_hours.clone();
         config.hotkeys.enabled = self.hotkeys_enabled;
         config.hotkeys.toggle = self.hotkey_toggle.clone();
         config.hotkeys.skip = self.hotkey_skip.clone();
         config.hotkeys.reset = self.hotkey_reset.clone();
+        config.hotkeys.mute = self.hotkey_mute.clone();
+        config.hotkeys.media_keys = self.media_keys;
+        config.hotkeys.media_keys_unless_playing = self.media_keys_unless_playing;
         config.accessibility.high_contrast = self.high_contrast;
         config.accessibility.reduced_motion = self.reduced_motion;
+        config.accessibility.power_saver = self.power_saver;
+        config.hue.enabled = self.hue_enabled;
+        config.hue.bridge_ip = self.hue_bridge_ip.trim().to_string();
+        config.hue.app_key = self.hue_app_key.trim().to_string();
+        config.hue.target = self.hue_target.trim().to_string();
+        config.calendar = CalendarConfig {
+            ics_url: self.calendar.ics_url.trim().to_string(),
+            ..self.calendar.clone()
+        };
+        config.slack = SlackConfig {
+            token: self.slack.token.trim().to_string(),
+            status_emoji: self.slack.status_emoji.trim().to_string(),
+            ..self.slack.clone()
+        };
+        config.todoist = TodoistConfig {
+            token: self.todoist.token.trim().to_string(),
+            filter: self.todoist.filter.trim().to_string(),
+            ..self.todoist.clone()
+        };
+        config.taskwarrior = TaskwarriorConfig {
+            command: self.taskwarrior.command.trim().to_string(),
+            filter: self.taskwarrior.filter.trim().to_string(),
+            ..self.taskwarrior.clone()
+        };
+        config.obsidian = ObsidianConfig {
+            vault_path: self.obsidian.vault_path.trim().to_string(),
+            folder: self.obsidian.folder.trim().to_string(),
+            date_format: self.obsidian.date_format.trim().to_string(),
+            ..self.obsidian.clone()
+        };
+        config.activitywatch = ActivityWatchConfig {
+            server_url: self.activitywatch.server_url.trim().to_string(),
+            bucket: self.activitywatch.bucket.trim().to_string(),
+            ..self.activitywatch.clone()
+        };
+        config.notion = NotionConfig {
+            token: self.notion.token.trim().to_string(),
+            database_id: self.notion.database_id.trim().to_string(),
+            title_property: self.notion.title_property.trim().to_string(),
+            date_property: self.notion.date_property.trim().to_string(),
+            duration_property: self.notion.duration_property.trim().to_string(),
+            tag_property: self.notion.tag_property.trim().to_string(),
+            ..self.notion.clone()
+        };
+        config.telegram = TelegramConfig {
+            token: self.telegram.token.trim().to_string(),
+            chat_id: self.telegram.chat_id.trim().to_string(),
+            ..self.telegram.clone()
+        };
+        config.group = GroupConfig {
+            host: self.group.host.trim().to_string(),
+            name: self.group.name.trim().to_string(),
+            code: self.group.code.trim().to_string(),
+            ..self.group.clone()
+        };
+        config.email = EmailConfig {
+            server: self.email.server.trim().to_string(),
+            username: self.email.username.trim().to_string(),
+            from: self.email.from.trim().to_string(),
+            to: self.email.to.trim().to_string(),
+            send_at: self.email.send_at.trim().to_string(),
+            ..self.email.clone()
+        };
+        config.plugins = self.plugins.clone();
         config.appearance.language = self.language;
         config
     }
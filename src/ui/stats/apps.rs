@@ -0,0 +1,87 @@
+use egui::{vec2, Align, Layout, Ui};
+
+use super::super::components::Card;
+use super::super::theme::Theme;
+use super::StatsView;
+
+/// Applications listed separately, the rest is merged into "Other"
+const MAX_APPS: usize = 6;
+
+impl StatsView {
+    /// Focus seconds per application, the smaller ones merged into "Other"
+    pub(super) fn app_rows(&self) -> Vec<(String, i64)> {
+        let mut rows: Vec<(String, i64)> = self.app_focus.iter().take(MAX_APPS).cloned().collect();
+        let other: i64 = self
+            .app_focus
+            .iter()
+            .skip(MAX_APPS)
+            .map(|(_, seconds)| seconds)
+            .sum();
+        if other > 0 {
+            rows.push((crate::i18n::tr().stats.other_tags.to_string(), other));
+        }
+        rows
+    }
+
+    /// Focus time per application for the period of the tag breakdown
+    pub(crate) fn show_apps_card(&self, ui: &mut Ui, theme: &Theme, width: f32) {
+        let t = crate::i18n::tr();
+        let inner_width = width - 32.0; // Account for Card padding (16 * 2)
+        let rows = self.app_rows();
+        let longest = rows
+            .iter()
+            .map(|(_, seconds)| *seconds)
+            .max()
+            .unwrap_or(1)
+            .max(1);
+        let label_width = (inner_width * 0.3).clamp(80.0, 160.0);
+        let value_width = 44.0;
+
+        Card::new().show(ui, theme, |ui| {
+            ui.set_width(inner_width);
+
+            ui.label(
+                egui::RichText::new(format!("{} · {}", t.stats.by_app, self.tag_period.label()))
+                    .size(13.0)
+                    .strong()
+                    .color(theme.text_primary),
+            );
+
+            ui.add_space(12.0);
+
+            for (app, seconds) in rows {
+                ui.horizontal(|ui| {
+                    ui.allocate_ui_with_layout(
+                        vec2(label_width, 18.0),
+                        Layout::left_to_right(Align::Center),
+                        |ui| {
+                            ui.add(
+                                egui::Label::new(
+                                    egui::RichText::new(app)
+                                        .size(12.0)
+                                        .color(theme.text_primary),
+                                )
+                                .truncate(),
+                            );
+                        },
+                    );
+
+                    let bar_width = (ui.available_width() - value_width).max(20.0);
+                    let (track, _) =
+                        ui.allocate_exact_size(vec2(bar_width, 8.0), egui::Sense::hover());
+                    ui.painter().rect_filled(track, 4.0, theme.bg_tertiary);
+                    let share = seconds as f32 / longest as f32;
+                    let filled =
+                        egui::Rect::from_min_size(track.min, vec2(track.width() * share, 8.0));
+                    ui.painter().rect_filled(filled, 4.0, theme.accent.solid());
+
+                    ui.label(
+                        egui::RichText::new(format!("{:.1}h", seconds as f32 / 3600.0))
+                            .size(11.0)
+                            .color(theme.text_muted),
+                    );
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,273 @@
+//! Overview drawn with box-drawing characters, for the retro themes
+
+use egui::{Color32, FontId, RichText, Ui};
+
+use super::super::components::{AsciiBox, AsciiProgressBar, AsciiSparkline};
+use super::super::theme::Theme;
+use super::cards::quick_start_label;
+use super::{StatsAction, StatsView};
+use crate::data::Statistics;
+
+const FONT_SIZE: f32 = 12.0;
+/// Rough width of a monospace character at `FONT_SIZE`
+const CHAR_WIDTH: f32 = FONT_SIZE * 0.6;
+
+/// `label` on the left and `value` on the right of a `width` wide line
+fn table_row(label: &str, value: &str, width: usize) -> String {
+    let gap = width.saturating_sub(label.chars().count() + value.chars().count() + 2);
+    format!(" {}{}{} ", label, " ".repeat(gap), value)
+}
+
+/// `text` cut or padded to exactly `width` characters
+fn fit(text: &str, width: usize) -> String {
+    let mut fitted: String = text.chars().take(width).collect();
+    let len = fitted.chars().count();
+    fitted.push_str(&" ".repeat(width - len));
+    fitted
+}
+
+/// A box with the title set into its top border
+fn titled_box(ui: &mut Ui, theme: &Theme, title: &str, lines: &[String], width: usize) {
+    let font = FontId::monospace(FONT_SIZE);
+    let border = |text: String| {
+        RichText::new(text)
+            .font(font.clone())
+            .color(theme.text_muted)
+    };
+
+    ui.vertical(|ui| {
+        // Lines touch so the borders join up
+        ui.spacing_mut().item_spacing.y = 0.0;
+
+        let title = format!(" {} ", title.to_uppercase());
+        let fill = width.saturating_sub(title.chars().count() + 1);
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 0.0;
+            ui.label(border(format!(
+                "{}{}",
+                AsciiBox::TOP_LEFT,
+                AsciiBox::HORIZONTAL
+            )));
+            ui.label(
+                RichText::new(&title)
+                    .font(font.clone())
+                    .color(theme.accent.solid()),
+            );
+            ui.label(border(format!(
+                "{}{}",
+                AsciiBox::HORIZONTAL.to_string().repeat(fill),
+                AsciiBox::TOP_RIGHT
+            )));
+        });
+        for line in lines {
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                ui.label(border(AsciiBox::VERTICAL.to_string()));
+                ui.label(
+                    RichText::new(fit(line, width))
+                        .font(font.clone())
+                        .color(theme.text_primary),
+                );
+                ui.label(border(AsciiBox::VERTICAL.to_string()));
+            });
+        }
+        ui.label(border(AsciiBox::bottom_line(width)));
+    });
+}
+
+impl StatsView {
+    /// Overview tab as text tables and braille charts
+    pub(crate) fn show_ascii_overview(
+        &self,
+        ui: &mut Ui,
+        stats: &Statistics,
+        theme: &Theme,
+        daily_goal: u32,
+        action: &mut Option<StatsAction>,
+    ) {
+        let t = crate::i18n::tr();
+        // Inner width of the boxes, in characters
+        let width = ((ui.available_width() / CHAR_WIDTH) as usize)
+            .saturating_sub(2)
+            .clamp(28, 64);
+        let bar_width = width.saturating_sub(16).min(24);
+
+        // Today
+        let goal_progress = if daily_goal > 0 {
+            stats.today_pomodoros as f32 / daily_goal as f32
+        } else {
+            0.0
+        };
+        let mut today = vec![
+            table_row(
+                t.stats.daily_goal,
+                &format!("{}/{}", stats.today_pomodoros, daily_goal),
+                width,
+            ),
+            format!(
+                " {}",
+                AsciiProgressBar::render_gradient(goal_progress.min(1.0), bar_width)
+            ),
+            table_row(
+                t.stats.todays_focus,
+                &format!("{:.1}h", stats.today_work_seconds as f32 / 3600.0),
+                width,
+            ),
+            table_row(
+                t.stats.current_streak,
+                &format!("{} {}", stats.current_streak, t.stats.days),
+                width,
+            ),
+        ];
+        if stats.today_plan.planned > 0 {
+            today.push(table_row(
+                t.stats.day_plan,
+                &format!("{}/{}", stats.today_plan.done, stats.today_plan.planned),
+                width,
+            ));
+        }
+        titled_box(ui, theme, t.stats.today, &today, width);
+
+        // Week chart, one bar per day
+        let bar_cells = 3;
+        let mut week: Vec<String> = AsciiSparkline::render(&stats.week_daily_hours, 4, bar_cells)
+            .into_iter()
+            .map(|line| format!(" {}", line))
+            .collect();
+        let days = [
+            t.stats.mon,
+            t.stats.tue,
+            t.stats.wed,
+            t.stats.thu,
+            t.stats.fri,
+            t.stats.sat,
+            t.stats.sun,
+        ];
+        week.push(format!(
+            " {}",
+            days.iter()
+                .map(|day| fit(day, bar_cells))
+                .collect::<Vec<_>>()
+                .join(" ")
+        ));
+        week.push(table_row(
+            t.stats.total_label,
+            &format!("{:.1}h", stats.week_work_seconds as f32 / 3600.0),
+            width,
+        ));
+        let comparison = &stats.week_comparison;
+        week.push(table_row(
+            t.stats.vs_last_week,
+            &format!(
+                "{:+.1}h  {:+}",
+                comparison.hours_delta(),
+                comparison.pomodoros_delta()
+            ),
+            width,
+        ));
+        if let Some(rate) = stats.week_plan.rate() {
+            week.push(table_row(
+                t.stats.plan_kept,
+                &format!("{:.0}%", rate * 100.0),
+                width,
+            ));
+        }
+        ui.add_space(8.0);
+        titled_box(ui, theme, t.stats.this_week, &week, width);
+
+        // All time
+        let all_time = [
+            table_row(
+                t.stats.focus_time,
+                &format!("{:.1}h", stats.total_work_seconds as f32 / 3600.0),
+                width,
+            ),
+            table_row(
+                t.stats.total_sessions,
+                &stats.total_pomodoros.to_string(),
+                width,
+            ),
+            table_row(
+                t.stats.best_streak,
+                &format!("{} {}", stats.longest_streak, t.stats.days),
+                width,
+            ),
+        ];
+        ui.add_space(8.0);
+        titled_box(ui, theme, t.stats.all_time, &all_time, width);
+
+        // Focus by tag, as bars of the period total
+        let slices = self.tag_slices(theme);
+        let total: f32 = slices.iter().map(|slice| slice.value).sum();
+        let label_width = 12;
+        let tags: Vec<String> = if slices.is_empty() {
+            vec![format!(" {}", t.stats.no_focus_time)]
+        } else {
+            slices
+                .iter()
+                .map(|slice| {
+                    format!(
+                        " {} {} {:.1}h",
+                        fit(&slice.label, label_width),
+                        AsciiProgressBar::render(
+                            slice.value / total,
+                            width.saturating_sub(label_width + 12)
+                        ),
+                        slice.value / 3600.0
+                    )
+                })
+                .collect()
+        };
+        ui.add_space(8.0);
+        let title = format!("{} · {}", t.stats.by_tag, self.tag_period.label());
+        titled_box(ui, theme, &title, &tags, width);
+
+        // Focus by application, when tracked
+        let apps = self.app_rows();
+        if let Some(longest) = apps.iter().map(|(_, seconds)| *seconds).max() {
+            let lines: Vec<String> = apps
+                .iter()
+                .map(|(app, seconds)| {
+                    format!(
+                        " {} {} {:.1}h",
+                        fit(app, label_width),
+                        AsciiProgressBar::render(
+                            *seconds as f32 / longest.max(1) as f32,
+                            width.saturating_sub(label_width + 12)
+                        ),
+                        *seconds as f32 / 3600.0
+                    )
+                })
+                .collect();
+            ui.add_space(8.0);
+            let title = format!("{} · {}", t.stats.by_app, self.tag_period.label());
+            titled_box(ui, theme, &title, &lines, width);
+        }
+
+        // Quick start
+        ui.add_space(8.0);
+        ui.horizontal_wrapped(|ui| {
+            for entry in &self.quick_start {
+                let button = ui.add(
+                    egui::Label::new(
+                        RichText::new(format!("[ {} ]", quick_start_label(entry)))
+                            .font(FontId::monospace(FONT_SIZE))
+                            .color(theme.accent.solid()),
+                    )
+                    .sense(egui::Sense::click()),
+                );
+                if button.hovered() {
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                    ui.painter().rect_filled(
+                        button.rect.expand(1.0),
+                        0.0,
+                        Color32::from_white_alpha(12),
+                    );
+                }
+                if button.clicked() {
+                    *action = Some(StatsAction::quick_start(entry));
+                }
+            }
+        });
+    }
+}
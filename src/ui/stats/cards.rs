@@ -4,7 +4,30 @@ use super::super::components::{draw_icon, Card, CircularProgress, Icon};
 use super::super::theme::Theme;
 use super::{stat_row, StatsAction, StatsView};
 use crate::core::Session;
-use crate::data::Statistics;
+use crate::core::SessionType;
+use crate::data::{QuickStartEntry, Statistics};
+
+/// Button text of a quick start entry, made up when no label is set
+pub(super) fn quick_start_label(entry: &QuickStartEntry) -> String {
+    if !entry.label.trim().is_empty() {
+        return entry.label.clone();
+    }
+    let t = crate::i18n::tr();
+    format!(
+        "{} {} {}",
+        entry.minutes,
+        t.common.min,
+        t.session_label(entry.session_type).to_lowercase()
+    )
+}
+
+fn quick_start_icon(entry: &QuickStartEntry) -> Icon {
+    match entry.session_type {
+        SessionType::Work if entry.minutes >= 45 => Icon::Timer,
+        SessionType::Work => Icon::Target,
+        SessionType::ShortBreak | SessionType::LongBreak => Icon::Coffee,
+    }
+}
 
 impl StatsView {
     pub(crate) fn show_compact_timer_card(
@@ -25,6 +48,7 @@ impl StatsView {
                 // Compact circular progress
                 let radius = 28.0;
                 CircularProgress::new(session.timer().progress())
+                    .with_label(t.session_label(session.session_type()))
                     .with_radius(radius)
                     .with_thickness(4.0)
                     .with_colors(start_color, end_color)
@@ -215,18 +239,11 @@ impl StatsView {
         theme: &Theme,
         action: &mut Option<StatsAction>,
     ) {
-        use crate::core::SessionType;
-
-        let t = crate::i18n::tr();
-
         Card::new().show(ui, theme, |ui| {
             ui.set_min_width(ui.available_width());
 
-            for (icon, label, mins, session_type) in [
-                (Icon::Coffee, t.stats.min_break, 5, SessionType::ShortBreak),
-                (Icon::Target, t.stats.min_focus, 25, SessionType::Work),
-                (Icon::Timer, t.stats.min_deep_work, 50, SessionType::Work),
-            ] {
+            for entry in &self.quick_start {
+                let (icon, label) = (quick_start_icon(entry), quick_start_label(entry));
                 let btn_response =
                     ui.allocate_response(vec2(ui.available_width(), 32.0), egui::Sense::click());
                 let btn_rect = btn_response.rect;
@@ -269,10 +286,7 @@ impl StatsView {
                 }
 
                 if btn_response.clicked() {
-                    *action = Some(StatsAction::QuickStart {
-                        session_type,
-                        minutes: mins,
-                    });
+                    *action = Some(StatsAction::quick_start(entry));
                 }
 
                 ui.add_space(4.0);
@@ -296,6 +310,7 @@ impl StatsView {
             ui.set_width(width - 32.0);
             ui.vertical_centered(|ui| {
                 CircularProgress::new(session.timer().progress())
+                    .with_label(t.session_label(session.session_type()))
                     .with_radius(radius)
                     .with_thickness((radius * 0.12).clamp(3.0, 5.0))
                     .with_colors(start_color, end_color)
@@ -351,8 +366,6 @@ impl StatsView {
         width: f32,
         action: &mut Option<StatsAction>,
     ) {
-        use crate::core::SessionType;
-
         let t = crate::i18n::tr();
         let inner_width = width - 32.0;
 
@@ -374,11 +387,8 @@ impl StatsView {
 
             ui.add_space(8.0);
 
-            for (icon, label, mins, session_type) in [
-                (Icon::Coffee, t.stats.min_break, 5, SessionType::ShortBreak),
-                (Icon::Target, t.stats.min_focus, 25, SessionType::Work),
-                (Icon::Timer, t.stats.min_deep_work, 50, SessionType::Work),
-            ] {
+            for entry in &self.quick_start {
+                let (icon, label) = (quick_start_icon(entry), quick_start_label(entry));
                 let btn_width = width - 40.0;
                 let btn_response =
                     ui.allocate_response(vec2(btn_width, 36.0), egui::Sense::click());
@@ -422,10 +432,7 @@ impl StatsView {
                 }
 
                 if btn_response.clicked() {
-                    *action = Some(StatsAction::QuickStart {
-                        session_type,
-                        minutes: mins,
-                    });
+                    *action = Some(StatsAction::quick_start(entry));
                 }
             }
         });
@@ -161,8 +161,15 @@ impl StatsView {
         let gap = ((width - bar_width * 7.0) / 6.0).clamp(4.0, 12.0);
         let total_width = 7.0 * bar_width + 6.0 * gap;
 
-        let (rect, _) =
+        let (rect, response) =
             ui.allocate_exact_size(vec2(total_width, chart_height + 20.0), egui::Sense::hover());
+        let summary = days
+            .iter()
+            .zip(values.iter())
+            .map(|(day, value)| format!("{} {:.1}h", day, value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        crate::ui::a11y::describe(&response, egui::WidgetType::Label, &summary);
         let (accent_start, accent_end) = theme.accent_gradient();
 
         for (i, (day, &value)) in days.iter().zip(values.iter()).enumerate() {
@@ -221,6 +228,7 @@ impl StatsView {
 
         // Export button
         let button_response = IconButton::new(Icon::Download)
+            .with_label(crate::i18n::tr().stats.export_hover)
             .with_size(32.0)
             .with_icon_scale(0.5)
             .show(ui, theme);
@@ -243,7 +251,7 @@ impl StatsView {
         if self.export_dropdown_open {
             let dropdown_pos = button_rect.left_bottom() + vec2(-60.0, 4.0);
 
-            egui::Area::new(button_id)
+            let dropdown = egui::Area::new(button_id)
                 .fixed_pos(dropdown_pos)
                 .order(egui::Order::Foreground)
                 .show(ui.ctx(), |ui| {
@@ -263,70 +271,35 @@ impl StatsView {
 
                             ui.add_space(4.0);
 
-                            // CSV option
-                            let csv_response = ui.allocate_response(
-                                vec2(ui.available_width(), 32.0),
-                                egui::Sense::click(),
-                            );
-                            let csv_rect = csv_response.rect;
-
-                            let bg_color = if csv_response.hovered() {
-                                theme.bg_hover
-                            } else {
-                                egui::Color32::TRANSPARENT
-                            };
-                            ui.painter().rect_filled(csv_rect, 6.0, bg_color);
-
-                            ui.painter().text(
-                                csv_rect.left_center() + vec2(12.0, 0.0),
-                                egui::Align2::LEFT_CENTER,
-                                "CSV (.csv)",
-                                egui::FontId::proportional(13.0),
-                                if csv_response.hovered() {
-                                    theme.text_primary
-                                } else {
-                                    theme.text_secondary
-                                },
-                            );
-
-                            if csv_response.clicked() {
-                                *action = Some(StatsAction::Export {
-                                    format: ExportFormat::Csv,
-                                });
-                                self.export_dropdown_open = false;
+                            let mut options = vec![
+                                (
+                                    "CSV (.csv)".to_string(),
+                                    StatsAction::Export {
+                                        format: ExportFormat::Csv,
+                                    },
+                                ),
+                                (
+                                    "JSON (.json)".to_string(),
+                                    StatsAction::Export {
+                                        format: ExportFormat::Json,
+                                    },
+                                ),
+                            ];
+                            // Formats added by plugins
+                            for export in &self.plugin_exports {
+                                options.push((
+                                    format!("{} (.{})", export.title, export.extension),
+                                    StatsAction::PluginExport {
+                                        export: export.clone(),
+                                    },
+                                ));
                             }
 
-                            // JSON option
-                            let json_response = ui.allocate_response(
-                                vec2(ui.available_width(), 32.0),
-                                egui::Sense::click(),
-                            );
-                            let json_rect = json_response.rect;
-
-                            let bg_color = if json_response.hovered() {
-                                theme.bg_hover
-                            } else {
-                                egui::Color32::TRANSPARENT
-                            };
-                            ui.painter().rect_filled(json_rect, 6.0, bg_color);
-
-                            ui.painter().text(
-                                json_rect.left_center() + vec2(12.0, 0.0),
-                                egui::Align2::LEFT_CENTER,
-                                "JSON (.json)",
-                                egui::FontId::proportional(13.0),
-                                if json_response.hovered() {
-                                    theme.text_primary
-                                } else {
-                                    theme.text_secondary
-                                },
-                            );
-
-                            if json_response.clicked() {
-                                *action = Some(StatsAction::Export {
-                                    format: ExportFormat::Json,
-                                });
-                                self.export_dropdown_open = false;
+                            for (label, option) in options {
+                                if export_option(ui, theme, &label) {
+                                    *action = Some(option);
+                                    self.export_dropdown_open = false;
+                                }
                             }
                         });
                 });
@@ -341,8 +314,7 @@ impl StatsView {
                 // Check if click is outside the dropdown area
                 let click_pos = ui.input(|i| i.pointer.interact_pos());
                 if let Some(pos) = click_pos {
-                    let dropdown_rect = egui::Rect::from_min_size(dropdown_pos, vec2(136.0, 100.0));
-                    if !dropdown_rect.contains(pos) && !button_rect.contains(pos) {
+                    if !dropdown.response.rect.contains(pos) && !button_rect.contains(pos) {
                         self.export_dropdown_open = false;
                     }
                 }
@@ -350,3 +322,28 @@ impl StatsView {
         }
     }
 }
+
+/// An entry of the export dropdown, returning whether it was clicked
+fn export_option(ui: &mut Ui, theme: &Theme, label: &str) -> bool {
+    let response = ui.allocate_response(vec2(ui.available_width(), 32.0), egui::Sense::click());
+    let bg_color = if response.hovered() {
+        theme.bg_hover
+    } else {
+        egui::Color32::TRANSPARENT
+    };
+    ui.painter().rect_filled(response.rect, 6.0, bg_color);
+
+    ui.painter().text(
+        response.rect.left_center() + vec2(12.0, 0.0),
+        egui::Align2::LEFT_CENTER,
+        label,
+        egui::FontId::proportional(13.0),
+        if response.hovered() {
+            theme.text_primary
+        } else {
+            theme.text_secondary
+        },
+    );
+
+    response.clicked()
+}
@@ -0,0 +1,111 @@
+use egui::{vec2, Color32, Ui};
+
+use super::super::components::{draw_icon, Card, Icon};
+use super::super::theme::Theme;
+use super::StatsView;
+use crate::data::Statistics;
+
+/// Direction of a change, for the arrow and its color
+fn trend(delta: f32, theme: &Theme) -> Option<(Icon, Color32)> {
+    if delta > 0.05 {
+        Some((Icon::ChevronUp, theme.success))
+    } else if delta < -0.05 {
+        Some((Icon::ChevronDown, theme.error))
+    } else {
+        None
+    }
+}
+
+impl StatsView {
+    /// This week so far against the same days of last week
+    pub(crate) fn show_comparison_card(
+        &self,
+        ui: &mut Ui,
+        stats: &Statistics,
+        theme: &Theme,
+        width: f32,
+    ) {
+        let t = crate::i18n::tr();
+        let inner_width = width - 32.0; // Account for Card padding (16 * 2)
+        let comparison = &stats.week_comparison;
+        let this_week = &comparison.this_week;
+
+        let rate = this_week
+            .completion_rate()
+            .map(|rate| format!("{:.0}%", rate * 100.0))
+            .unwrap_or_else(|| "—".to_string());
+        let rows = [
+            (
+                t.stats.compare_focus,
+                format!("{:.1}h", this_week.hours()),
+                Some(comparison.hours_delta()),
+                format!("{:+.1}h", comparison.hours_delta()),
+            ),
+            (
+                t.stats.compare_pomodoros,
+                this_week.pomodoros.to_string(),
+                Some(comparison.pomodoros_delta() as f32),
+                format!("{:+}", comparison.pomodoros_delta()),
+            ),
+            (
+                t.stats.completion_rate,
+                rate,
+                comparison.completion_rate_delta(),
+                comparison
+                    .completion_rate_delta()
+                    .map(|delta| format!("{:+.0}%", delta))
+                    .unwrap_or_default(),
+            ),
+        ];
+
+        Card::new().show(ui, theme, |ui| {
+            ui.set_width(inner_width);
+
+            ui.label(
+                egui::RichText::new(t.stats.vs_last_week)
+                    .size(13.0)
+                    .strong()
+                    .color(theme.text_primary),
+            );
+
+            ui.add_space(12.0);
+
+            let column_width = inner_width / rows.len() as f32;
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                for (title, value, delta, delta_text) in rows {
+                    ui.allocate_ui(vec2(column_width, 56.0), |ui| {
+                        ui.vertical(|ui| {
+                            ui.label(
+                                egui::RichText::new(title)
+                                    .size(11.0)
+                                    .color(theme.text_secondary),
+                            );
+                            ui.label(
+                                egui::RichText::new(value)
+                                    .size(20.0)
+                                    .strong()
+                                    .color(theme.text_primary),
+                            );
+                            ui.horizontal(|ui| {
+                                let (icon, color) = delta
+                                    .and_then(|delta| trend(delta, theme))
+                                    .map_or((None, theme.text_muted), |(icon, color)| {
+                                        (Some(icon), color)
+                                    });
+                                if let Some(icon) = icon {
+                                    let (rect, _) = ui.allocate_exact_size(
+                                        vec2(12.0, 12.0),
+                                        egui::Sense::hover(),
+                                    );
+                                    draw_icon(ui, icon, rect, color);
+                                }
+                                ui.label(egui::RichText::new(delta_text).size(11.0).color(color));
+                            });
+                        });
+                    });
+                }
+            });
+        });
+    }
+}
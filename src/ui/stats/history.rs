@@ -0,0 +1,203 @@
+use chrono::{DateTime, Local};
+use egui::{vec2, Align, Layout, Rect, Ui};
+
+use super::super::components::{draw_icon, Card, Icon};
+use super::super::theme::Theme;
+use super::{StatsAction, StatsView};
+use crate::core::SessionType;
+use crate::data::SessionRecord;
+
+impl StatsView {
+    /// Get the day label for the history offset
+    fn history_day_label(&self) -> String {
+        let t = crate::i18n::tr();
+        match self.history_day_offset {
+            0 => t.stats.today.to_string(),
+            -1 => t.stats.yesterday.to_string(),
+            offset => {
+                let day = Local::now().date_naive() + chrono::Duration::days(offset as i64);
+                day.format("%a %d %b").to_string()
+            }
+        }
+    }
+
+    /// Session log of one day with day navigation
+    pub(crate) fn show_history(
+        &self,
+        ui: &mut Ui,
+        theme: &Theme,
+        width: f32,
+        action: &mut Option<StatsAction>,
+    ) {
+        let t = crate::i18n::tr();
+        let inner_width = width - 32.0; // Account for Card padding (16 * 2)
+
+        Card::new().show(ui, theme, |ui| {
+            ui.set_width(inner_width);
+
+            ui.horizontal(|ui| {
+                let prev_btn = ui.add(
+                    egui::Button::new(
+                        egui::RichText::new("<")
+                            .size(14.0)
+                            .color(theme.text_secondary),
+                    )
+                    .fill(egui::Color32::TRANSPARENT)
+                    .min_size(vec2(24.0, 24.0)),
+                );
+                if prev_btn.clicked() {
+                    *action = Some(StatsAction::ShowHistory {
+                        day_offset: self.history_day_offset - 1,
+                    });
+                }
+
+                ui.label(
+                    egui::RichText::new(self.history_day_label())
+                        .size(13.0)
+                        .strong()
+                        .color(theme.text_primary),
+                );
+
+                if self.history_day_offset < 0 {
+                    let next_btn = ui.add(
+                        egui::Button::new(
+                            egui::RichText::new(">")
+                                .size(14.0)
+                                .color(theme.text_secondary),
+                        )
+                        .fill(egui::Color32::TRANSPARENT)
+                        .min_size(vec2(24.0, 24.0)),
+                    );
+                    if next_btn.clicked() {
+                        *action = Some(StatsAction::ShowHistory {
+                            day_offset: self.history_day_offset + 1,
+                        });
+                    }
+                }
+
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    let focus_minutes: i64 = self
+                        .history
+                        .iter()
+                        .filter(|s| s.session_type == SessionType::Work.as_str())
+                        .map(|s| s.duration_seconds)
+                        .sum::<i64>()
+                        / 60;
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} {} · {} min",
+                            self.history.len(),
+                            t.stats.sessions,
+                            focus_minutes
+                        ))
+                        .size(11.0)
+                        .color(theme.text_secondary),
+                    );
+                });
+            });
+
+            ui.add_space(12.0);
+
+            if self.history.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.label(
+                        egui::RichText::new(t.stats.no_sessions)
+                            .size(13.0)
+                            .color(theme.text_muted),
+                    );
+                });
+                return;
+            }
+
+            for record in &self.history {
+                history_row(ui, theme, record);
+            }
+        });
+    }
+}
+
+/// One session: start time, type, duration and whether it was completed
+fn history_row(ui: &mut Ui, theme: &Theme, record: &SessionRecord) {
+    let t = crate::i18n::tr();
+    let session_type = SessionType::from_db_str(&record.session_type);
+    let start = DateTime::parse_from_rfc3339(&record.started_at)
+        .map(|dt| dt.with_timezone(&Local).format("%H:%M").to_string())
+        .unwrap_or_else(|_| "--:--".to_string());
+
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(start)
+                .size(13.0)
+                .monospace()
+                .color(theme.text_secondary),
+        );
+
+        ui.add_space(8.0);
+
+        // Session color dot
+        let (dot_rect, _) = ui.allocate_exact_size(vec2(8.0, 8.0), egui::Sense::hover());
+        let dot_color = match session_type {
+            Some(st) => theme.session_gradient(st).0,
+            None => theme.text_muted,
+        };
+        ui.painter()
+            .circle_filled(dot_rect.center(), 4.0, dot_color);
+
+        ui.add_space(4.0);
+
+        let label = session_type
+            .map(|st| t.session_label(st))
+            .unwrap_or(&record.session_type);
+        ui.label(
+            egui::RichText::new(label)
+                .size(13.0)
+                .color(theme.text_primary),
+        );
+        if let Some(tag) = &record.tag {
+            ui.label(
+                egui::RichText::new(format!("#{}", tag))
+                    .size(11.0)
+                    .color(theme.text_muted),
+            );
+        }
+        if let Some(intent) = &record.intent {
+            ui.label(
+                egui::RichText::new(intent)
+                    .size(11.0)
+                    .italics()
+                    .color(theme.text_secondary),
+            );
+        }
+
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            let (icon_rect, icon_resp) =
+                ui.allocate_exact_size(vec2(14.0, 14.0), egui::Sense::hover());
+            let ir = Rect::from_center_size(icon_rect.center(), vec2(12.0, 12.0));
+            if record.completed {
+                draw_icon(ui, Icon::Check, ir, theme.success);
+                icon_resp.on_hover_text(t.stats.completed);
+            } else {
+                draw_icon(ui, Icon::X, ir, theme.text_muted);
+                icon_resp.on_hover_text(t.stats.interrupted);
+            }
+
+            ui.add_space(6.0);
+
+            ui.label(
+                egui::RichText::new(format_duration(record.duration_seconds))
+                    .size(13.0)
+                    .color(theme.text_secondary),
+            );
+        });
+    });
+}
+
+/// Duration as "25 min", or "mm:ss" under a minute and for partial minutes
+fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    if seconds % 60 == 0 {
+        format!("{} min", seconds / 60)
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
@@ -1,29 +1,46 @@
 //! Stats view with statistics - Responsive layout
 
+mod apps;
+mod ascii;
 mod cards;
 mod charts;
+mod compare;
+mod history;
+mod month;
+mod plan;
+mod plugins;
+mod tags;
+mod timeline;
 
 use egui::{vec2, Align, Layout, Rect, ScrollArea, Ui};
 
 use super::components::{draw_icon, Icon, IconButton};
 use super::theme::Theme;
 use crate::core::Session;
-use crate::data::{ExportFormat, Statistics};
+use crate::data::{ExportFormat, QuickStartEntry, SessionRecord, Statistics};
+use crate::integrations::{PluginExport, PluginPanel};
+
+pub use tags::TagPeriod;
 
 /// Actions from stats view
 #[derive(Debug, Clone, PartialEq)]
 pub enum StatsAction {
     Back,
     OpenSettings,
-    /// Quick start a session with given type and duration in minutes
+    /// Quick start a session with given type, duration in minutes and tag
     QuickStart {
         session_type: crate::core::SessionType,
         minutes: u32,
+        tag: Option<String>,
     },
     /// Export statistics to file
     Export {
         format: ExportFormat,
     },
+    /// Export statistics to file in a plugin's format
+    PluginExport {
+        export: PluginExport,
+    },
     /// Undo the last completed session
     UndoLastSession,
     /// Reset all statistics
@@ -32,6 +49,38 @@ pub enum StatsAction {
     ChangeWeek {
         offset: i32,
     },
+    /// Change the displayed month (offset from current month)
+    ChangeMonth {
+        offset: i32,
+    },
+    /// Open the session log of a day (offset from today)
+    ShowHistory {
+        day_offset: i32,
+    },
+    /// Change the period of the tag breakdown
+    ChangeTagPeriod {
+        period: TagPeriod,
+    },
+}
+
+impl StatsAction {
+    /// Start the session of a quick start button
+    fn quick_start(entry: &QuickStartEntry) -> Self {
+        let tag = entry.tag.trim();
+        StatsAction::QuickStart {
+            session_type: entry.session_type,
+            minutes: entry.minutes.max(1),
+            tag: (!tag.is_empty()).then(|| tag.to_string()),
+        }
+    }
+}
+
+/// Tabs of the stats view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsTab {
+    Overview,
+    Month,
+    History,
 }
 
 /// Stats view showing statistics
@@ -44,6 +93,30 @@ pub struct StatsView {
     pub week_offset: i32,
     /// Cached weekly hours for the selected week
     pub selected_week_hours: Option<Vec<f32>>,
+    /// Currently shown tab
+    pub tab: StatsTab,
+    /// Month offset for the month chart (0 = current month, -1 = previous, etc.)
+    pub month_offset: i32,
+    /// Cached daily hours for the displayed month
+    pub month_hours: Option<Vec<f32>>,
+    /// Day offset for the session log (0 = today, -1 = yesterday, etc.)
+    pub history_day_offset: i32,
+    /// Sessions of the displayed day, latest first
+    pub history: Vec<SessionRecord>,
+    /// Today's sessions for the timeline, latest first
+    pub today_sessions: Vec<SessionRecord>,
+    /// Period of the tag breakdown
+    pub tag_period: TagPeriod,
+    /// Focus seconds per tag in the period, largest first
+    pub tag_focus: Vec<(Option<String>, i64)>,
+    /// Focus seconds per application in the same period, largest first
+    pub app_focus: Vec<(String, i64)>,
+    /// Buttons of the Quick Start list, from the config
+    pub quick_start: Vec<QuickStartEntry>,
+    /// Panels added by plugins
+    pub plugin_panels: Vec<PluginPanel>,
+    /// Export formats added by plugins
+    pub plugin_exports: Vec<PluginExport>,
 }
 
 impl StatsView {
@@ -53,6 +126,18 @@ impl StatsView {
             show_reset_confirmation: false,
             week_offset: 0,
             selected_week_hours: None,
+            tab: StatsTab::Overview,
+            month_offset: 0,
+            month_hours: None,
+            history_day_offset: 0,
+            history: Vec::new(),
+            today_sessions: Vec::new(),
+            tag_period: TagPeriod::Week,
+            tag_focus: Vec::new(),
+            app_focus: Vec::new(),
+            quick_start: Vec::new(),
+            plugin_panels: Vec::new(),
+            plugin_exports: Vec::new(),
         }
     }
 
@@ -124,6 +209,7 @@ impl StatsView {
                 // Header with back and settings buttons - matches settings style
                 ui.horizontal(|ui| {
                     if IconButton::new(Icon::ArrowLeft)
+                        .with_label(crate::i18n::tr().common.back)
                         .with_size(32.0)
                         .with_icon_scale(0.5)
                         .show(ui, theme)
@@ -142,6 +228,7 @@ impl StatsView {
 
                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                         if IconButton::new(Icon::Settings)
+                            .with_label(crate::i18n::tr().nav.settings)
                             .with_size(32.0)
                             .with_icon_scale(0.5)
                             .show(ui, theme)
@@ -162,6 +249,7 @@ impl StatsView {
                         // Reset all stats button
                         if stats.total_pomodoros > 0 {
                             let reset_response = IconButton::new(Icon::Trash)
+                                .with_label(crate::i18n::tr().stats.reset_all_hover)
                                 .with_size(32.0)
                                 .with_icon_scale(0.5)
                                 .show(ui, theme);
@@ -178,6 +266,7 @@ impl StatsView {
                         // Undo last session button
                         if stats.today_pomodoros > 0 {
                             let undo_response = IconButton::new(Icon::RotateCcw)
+                                .with_label(crate::i18n::tr().stats.undo_last_hover)
                                 .with_size(32.0)
                                 .with_icon_scale(0.5)
                                 .show(ui, theme);
@@ -191,7 +280,38 @@ impl StatsView {
                     });
                 });
 
-                ui.add_space(theme.spacing_lg);
+                ui.add_space(theme.spacing_sm);
+
+                // Overview / History tabs
+                ui.horizontal(|ui| {
+                    let t = crate::i18n::tr();
+                    for (tab, label) in [
+                        (StatsTab::Overview, t.stats.overview),
+                        (StatsTab::Month, t.stats.month),
+                        (StatsTab::History, t.stats.history),
+                    ] {
+                        let selected = self.tab == tab;
+                        let text = egui::RichText::new(label).size(13.0).color(if selected {
+                            theme.text_primary
+                        } else {
+                            theme.text_muted
+                        });
+                        if ui.selectable_label(selected, text).clicked() && !selected {
+                            self.tab = tab;
+                            action = match tab {
+                                StatsTab::Overview => None,
+                                StatsTab::Month => Some(StatsAction::ChangeMonth {
+                                    offset: self.month_offset,
+                                }),
+                                StatsTab::History => Some(StatsAction::ShowHistory {
+                                    day_offset: self.history_day_offset,
+                                }),
+                            };
+                        }
+                    }
+                });
+
+                ui.add_space(theme.spacing_md);
 
                 // Main content area with scroll
                 let scroll_max_h = ui.available_height();
@@ -199,7 +319,14 @@ impl StatsView {
                     .auto_shrink([false, false])
                     .max_height(scroll_max_h)
                     .show(ui, |ui| {
-                        if is_wide {
+                        if self.tab == StatsTab::Month {
+                            self.show_month_card(ui, theme, ui.available_width(), &mut action);
+                        } else if self.tab == StatsTab::History {
+                            self.show_history(ui, theme, ui.available_width(), &mut action);
+                        } else if theme.accent.is_retro() {
+                            // Match the TUI timer of the retro themes
+                            self.show_ascii_overview(ui, stats, theme, daily_goal, &mut action);
+                        } else if is_wide {
                             self.show_wide_layout(
                                 ui,
                                 session,
@@ -390,8 +517,38 @@ impl StatsView {
 
                     ui.add_space(spacing);
 
+                    // Today's sessions against the clock
+                    self.show_timeline_card(ui, theme, right_col_width);
+
+                    ui.add_space(spacing);
+
                     // Additional stats row
                     self.show_additional_stats(ui, stats, theme, right_col_width, spacing);
+
+                    ui.add_space(spacing);
+
+                    // This week vs last week
+                    self.show_comparison_card(ui, stats, theme, right_col_width);
+
+                    ui.add_space(spacing);
+
+                    // Day plans kept
+                    if stats.week_plan.days > 0 {
+                        self.show_plan_card(ui, stats, theme, right_col_width);
+                        ui.add_space(spacing);
+                    }
+
+                    // Focus time by tag
+                    self.show_tags_card(ui, theme, right_col_width, action);
+
+                    // Focus time by application, when tracked
+                    if !self.app_focus.is_empty() {
+                        ui.add_space(spacing);
+                        self.show_apps_card(ui, theme, right_col_width);
+                    }
+
+                    // Panels added by plugins
+                    self.show_plugin_panels(ui, theme, right_col_width, spacing);
                 });
             });
         });
@@ -421,12 +578,43 @@ impl StatsView {
 
         ui.add_space(spacing);
 
+        // This week vs last week
+        self.show_comparison_card(ui, stats, theme, ui.available_width());
+
+        ui.add_space(spacing);
+
+        // Day plans kept
+        if stats.week_plan.days > 0 {
+            self.show_plan_card(ui, stats, theme, ui.available_width());
+            ui.add_space(spacing);
+        }
+
         // Week Activity section
         section_header(ui, theme, crate::i18n::tr().stats.week_activity);
         self.show_compact_week_card(ui, stats, theme, action);
 
         ui.add_space(spacing);
 
+        // Today's timeline
+        self.show_timeline_card(ui, theme, ui.available_width());
+
+        ui.add_space(spacing);
+
+        // Tags section
+        section_header(ui, theme, crate::i18n::tr().stats.by_tag);
+        self.show_tags_card(ui, theme, ui.available_width(), action);
+
+        // Focus time by application, when tracked
+        if !self.app_focus.is_empty() {
+            ui.add_space(spacing);
+            self.show_apps_card(ui, theme, ui.available_width());
+        }
+
+        // Panels added by plugins
+        self.show_plugin_panels(ui, theme, ui.available_width(), spacing);
+
+        ui.add_space(spacing);
+
         // Quick Start section
         section_header(ui, theme, crate::i18n::tr().stats.quick_start);
         self.show_compact_presets_card(ui, theme, action);
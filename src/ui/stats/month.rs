@@ -0,0 +1,177 @@
+use chrono::{Datelike, Local, Months, NaiveDate, Weekday};
+use egui::{vec2, Align, Layout, Rect, Ui};
+
+use super::super::components::Card;
+use super::super::theme::Theme;
+use super::{StatsAction, StatsView};
+
+impl StatsView {
+    /// First day of the displayed month
+    pub fn displayed_month(&self) -> NaiveDate {
+        let today = Local::now().date_naive();
+        let start = today.with_day(1).unwrap_or(today);
+        let months = Months::new(self.month_offset.unsigned_abs());
+        if self.month_offset < 0 {
+            start.checked_sub_months(months).unwrap_or(start)
+        } else {
+            start.checked_add_months(months).unwrap_or(start)
+        }
+    }
+
+    /// Month activity chart with month navigation
+    pub(crate) fn show_month_card(
+        &self,
+        ui: &mut Ui,
+        theme: &Theme,
+        width: f32,
+        action: &mut Option<StatsAction>,
+    ) {
+        let inner_width = width - 32.0; // Account for Card padding (16 * 2)
+        let hours = self.month_hours.as_deref().unwrap_or(&[]);
+
+        Card::new().show(ui, theme, |ui| {
+            ui.set_width(inner_width);
+
+            ui.horizontal(|ui| {
+                let prev_btn = ui.add(
+                    egui::Button::new(
+                        egui::RichText::new("<")
+                            .size(14.0)
+                            .color(theme.text_secondary),
+                    )
+                    .fill(egui::Color32::TRANSPARENT)
+                    .min_size(vec2(24.0, 24.0)),
+                );
+                if prev_btn.clicked() {
+                    *action = Some(StatsAction::ChangeMonth {
+                        offset: self.month_offset - 1,
+                    });
+                }
+
+                let label = if self.month_offset == 0 {
+                    crate::i18n::tr().stats.this_month.to_string()
+                } else {
+                    self.displayed_month().format("%B %Y").to_string()
+                };
+                ui.label(
+                    egui::RichText::new(label)
+                        .size(13.0)
+                        .strong()
+                        .color(theme.text_primary),
+                );
+
+                if self.month_offset < 0 {
+                    let next_btn = ui.add(
+                        egui::Button::new(
+                            egui::RichText::new(">")
+                                .size(14.0)
+                                .color(theme.text_secondary),
+                        )
+                        .fill(egui::Color32::TRANSPARENT)
+                        .min_size(vec2(24.0, 24.0)),
+                    );
+                    if next_btn.clicked() {
+                        *action = Some(StatsAction::ChangeMonth {
+                            offset: self.month_offset + 1,
+                        });
+                    }
+                }
+
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    let total = (hours.iter().sum::<f32>() * 10.0).round() / 10.0;
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{:.1}h {}",
+                            total,
+                            crate::i18n::tr().stats.total_label
+                        ))
+                        .size(11.0)
+                        .color(theme.text_secondary),
+                    );
+                });
+            });
+
+            ui.add_space(12.0);
+            self.draw_month_chart(ui, hours, theme, inner_width - 16.0);
+        });
+    }
+
+    /// Bars for each day of the month, with a separator before every Monday
+    fn draw_month_chart(&self, ui: &mut Ui, hours: &[f32], theme: &Theme, width: f32) {
+        let days = hours.len().max(1);
+        let max_value = hours.iter().cloned().fold(1.0_f32, f32::max);
+        let first_day = self.displayed_month();
+
+        let chart_height = 60.0;
+        let gap = 2.0;
+        let bar_width = ((width - gap * (days - 1) as f32) / days as f32).max(3.0);
+        let total_width = days as f32 * bar_width + (days - 1) as f32 * gap;
+
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(total_width, chart_height + 16.0), egui::Sense::hover());
+        let (accent_start, accent_end) = theme.accent_gradient();
+        let summary = hours
+            .iter()
+            .enumerate()
+            .filter(|(_, &value)| value > 0.0)
+            .map(|(i, value)| format!("{} {:.1}h", i + 1, value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        crate::ui::a11y::describe(&response, egui::WidgetType::Label, &summary);
+        let painter = ui.painter();
+
+        let mut hovered = None;
+        for (i, &value) in hours.iter().enumerate() {
+            let date = first_day + chrono::Duration::days(i as i64);
+            let x = rect.left() + i as f32 * (bar_width + gap);
+            let bar_height = (value / max_value) * chart_height;
+
+            // Week separator
+            if date.weekday() == Weekday::Mon && i > 0 {
+                let sep_x = x - gap / 2.0;
+                painter.line_segment(
+                    [
+                        egui::pos2(sep_x, rect.top()),
+                        egui::pos2(sep_x, rect.top() + chart_height),
+                    ],
+                    egui::Stroke::new(1.0, theme.border_default),
+                );
+            }
+
+            let bg_rect =
+                Rect::from_min_size(egui::pos2(x, rect.top()), vec2(bar_width, chart_height));
+            painter.rect_filled(bg_rect, 2.0, theme.bg_tertiary);
+
+            if bar_height > 0.0 {
+                let fill_rect = Rect::from_min_size(
+                    egui::pos2(x, rect.top() + chart_height - bar_height),
+                    vec2(bar_width, bar_height),
+                );
+                let color = Theme::lerp_color(accent_start, accent_end, value / max_value);
+                painter.rect_filled(fill_rect, 2.0, color);
+            }
+
+            // Label the first day and each Monday
+            if i == 0 || date.weekday() == Weekday::Mon {
+                painter.text(
+                    egui::pos2(x, rect.bottom()),
+                    egui::Align2::LEFT_BOTTOM,
+                    date.day().to_string(),
+                    egui::FontId::proportional(9.0),
+                    theme.text_muted,
+                );
+            }
+
+            if response
+                .hover_pos()
+                .is_some_and(|pos| pos.x >= x && pos.x < x + bar_width + gap)
+            {
+                hovered = Some((date, value));
+            }
+        }
+
+        if let Some((date, value)) = hovered {
+            response.on_hover_text(format!("{}: {:.1}h", date.format("%d %b"), value));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use egui::{vec2, Ui};
+
+use super::super::components::Card;
+use super::super::theme::Theme;
+use super::StatsView;
+use crate::data::Statistics;
+
+impl StatsView {
+    /// Today's plan and how well this week's plans were kept
+    pub(crate) fn show_plan_card(
+        &self,
+        ui: &mut Ui,
+        stats: &Statistics,
+        theme: &Theme,
+        width: f32,
+    ) {
+        let t = crate::i18n::tr();
+        let inner_width = width - 32.0; // Account for Card padding (16 * 2)
+        let today = &stats.today_plan;
+        let week = &stats.week_plan;
+
+        let rows = [
+            (
+                t.stats.today,
+                if today.planned > 0 {
+                    format!("{}/{}", today.done, today.planned)
+                } else {
+                    "—".to_string()
+                },
+            ),
+            (
+                t.stats.plan_kept,
+                week.rate()
+                    .map(|rate| format!("{:.0}%", rate * 100.0))
+                    .unwrap_or_else(|| "—".to_string()),
+            ),
+            (t.stats.plan_days, week.days.to_string()),
+        ];
+
+        Card::new().show(ui, theme, |ui| {
+            ui.set_width(inner_width);
+
+            ui.label(
+                egui::RichText::new(t.stats.day_plan)
+                    .size(13.0)
+                    .strong()
+                    .color(theme.text_primary),
+            );
+
+            ui.add_space(12.0);
+
+            let column_width = inner_width / rows.len() as f32;
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                for (title, value) in rows {
+                    ui.allocate_ui(vec2(column_width, 40.0), |ui| {
+                        ui.vertical(|ui| {
+                            ui.label(
+                                egui::RichText::new(title)
+                                    .size(11.0)
+                                    .color(theme.text_secondary),
+                            );
+                            ui.label(
+                                egui::RichText::new(value)
+                                    .size(20.0)
+                                    .strong()
+                                    .color(theme.text_primary),
+                            );
+                        });
+                    });
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,54 @@
+use egui::{Align, Layout, Ui};
+
+use super::super::components::Card;
+use super::super::theme::Theme;
+use super::StatsView;
+
+impl StatsView {
+    /// A card for each panel added by a plugin, as label/value rows
+    pub(crate) fn show_plugin_panels(&self, ui: &mut Ui, theme: &Theme, width: f32, spacing: f32) {
+        let inner_width = width - 32.0; // Account for Card padding (16 * 2)
+
+        for panel in &self.plugin_panels {
+            ui.add_space(spacing);
+
+            Card::new().show(ui, theme, |ui| {
+                ui.set_width(inner_width);
+
+                ui.label(
+                    egui::RichText::new(&panel.title)
+                        .size(13.0)
+                        .strong()
+                        .color(theme.text_primary),
+                );
+
+                ui.add_space(8.0);
+
+                match &panel.rows {
+                    Ok(rows) => {
+                        for row in rows {
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new(&row.label)
+                                        .size(12.0)
+                                        .color(theme.text_secondary),
+                                );
+                                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                    ui.label(
+                                        egui::RichText::new(&row.value)
+                                            .size(12.0)
+                                            .strong()
+                                            .color(theme.text_primary),
+                                    );
+                                });
+                            });
+                        }
+                    }
+                    Err(e) => {
+                        ui.label(egui::RichText::new(e).size(12.0).color(theme.error));
+                    }
+                }
+            });
+        }
+    }
+}
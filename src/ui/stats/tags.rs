@@ -0,0 +1,173 @@
+use chrono::{Datelike, Local, NaiveDate};
+use egui::{vec2, Align, Layout, Ui};
+
+use super::super::components::{Card, DonutChart, DonutSlice};
+use super::super::theme::Theme;
+use super::{StatsAction, StatsView};
+
+/// Tags shown separately, the rest is merged into "Other"
+const MAX_TAG_SLICES: usize = 5;
+
+/// Period covered by the tag breakdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagPeriod {
+    Week,
+    Month,
+    AllTime,
+}
+
+impl TagPeriod {
+    /// First local day of the period, `None` for all time
+    pub fn start(&self) -> Option<NaiveDate> {
+        let today = Local::now().date_naive();
+        match self {
+            TagPeriod::Week => {
+                Some(today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64))
+            }
+            TagPeriod::Month => today.with_day(1),
+            TagPeriod::AllTime => None,
+        }
+    }
+
+    pub(super) fn label(&self) -> &'static str {
+        let t = crate::i18n::tr();
+        match self {
+            TagPeriod::Week => t.stats.this_week,
+            TagPeriod::Month => t.stats.this_month,
+            TagPeriod::AllTime => t.stats.all_time,
+        }
+    }
+}
+
+impl StatsView {
+    /// Donut slices for the loaded tag breakdown
+    pub(super) fn tag_slices(&self, theme: &Theme) -> Vec<DonutSlice> {
+        let t = crate::i18n::tr();
+        let palette = [
+            theme.accent.solid(),
+            theme.success,
+            theme.warning,
+            theme.long_break_start,
+            theme.error,
+        ];
+
+        let mut slices = Vec::new();
+        let mut other = 0;
+        let mut untagged = 0;
+        for (tag, seconds) in &self.tag_focus {
+            match tag {
+                Some(tag) if slices.len() < MAX_TAG_SLICES => slices.push(DonutSlice {
+                    label: format!("#{}", tag),
+                    value: *seconds as f32,
+                    color: palette[slices.len() % palette.len()],
+                }),
+                Some(_) => other += seconds,
+                None => untagged += seconds,
+            }
+        }
+        if other > 0 {
+            slices.push(DonutSlice {
+                label: t.stats.other_tags.to_string(),
+                value: other as f32,
+                color: theme.text_secondary,
+            });
+        }
+        if untagged > 0 {
+            slices.push(DonutSlice {
+                label: t.stats.untagged.to_string(),
+                value: untagged as f32,
+                color: theme.border_strong,
+            });
+        }
+        slices
+    }
+
+    /// Share of focus time per tag for the selected period
+    pub(crate) fn show_tags_card(
+        &self,
+        ui: &mut Ui,
+        theme: &Theme,
+        width: f32,
+        action: &mut Option<StatsAction>,
+    ) {
+        let t = crate::i18n::tr();
+        let inner_width = width - 32.0; // Account for Card padding (16 * 2)
+        let slices = self.tag_slices(theme);
+        let total_seconds: i64 = self.tag_focus.iter().map(|(_, seconds)| seconds).sum();
+
+        Card::new().show(ui, theme, |ui| {
+            ui.set_width(inner_width);
+
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(t.stats.by_tag)
+                        .size(13.0)
+                        .strong()
+                        .color(theme.text_primary),
+                );
+
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    egui::ComboBox::from_id_salt("tag_period")
+                        .selected_text(self.tag_period.label())
+                        .show_ui(ui, |ui| {
+                            for period in [TagPeriod::Week, TagPeriod::Month, TagPeriod::AllTime] {
+                                if ui
+                                    .selectable_label(self.tag_period == period, period.label())
+                                    .clicked()
+                                    && self.tag_period != period
+                                {
+                                    *action = Some(StatsAction::ChangeTagPeriod { period });
+                                }
+                            }
+                        });
+                });
+            });
+
+            ui.add_space(12.0);
+
+            if slices.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.label(
+                        egui::RichText::new(t.stats.no_focus_time)
+                            .size(13.0)
+                            .color(theme.text_muted),
+                    );
+                });
+                return;
+            }
+
+            ui.horizontal(|ui| {
+                let hours = total_seconds as f32 / 3600.0;
+                DonutChart::new(&slices)
+                    .with_radius(44.0)
+                    .with_thickness(12.0)
+                    .with_bg_color(theme.bg_tertiary)
+                    .with_center_text(format!("{:.1}h", hours), theme.text_primary)
+                    .show(ui);
+
+                ui.add_space(16.0);
+
+                // Legend
+                ui.vertical(|ui| {
+                    for slice in &slices {
+                        ui.horizontal(|ui| {
+                            let (dot, _) =
+                                ui.allocate_exact_size(vec2(8.0, 8.0), egui::Sense::hover());
+                            ui.painter().circle_filled(dot.center(), 4.0, slice.color);
+                            ui.label(
+                                egui::RichText::new(&slice.label)
+                                    .size(12.0)
+                                    .color(theme.text_primary),
+                            );
+                            ui.label(
+                                egui::RichText::new(format!("{:.1}h", slice.value / 3600.0))
+                                    .size(11.0)
+                                    .color(theme.text_muted),
+                            );
+                        });
+                    }
+                });
+            });
+        });
+    }
+}
@@ -0,0 +1,200 @@
+use chrono::{DateTime, Local, NaiveTime, Timelike};
+use egui::{vec2, Rect, Ui};
+
+use super::super::components::Card;
+use super::super::theme::Theme;
+use super::StatsView;
+use crate::core::SessionType;
+use crate::data::SessionRecord;
+
+/// A session placed on the clock, in seconds since midnight
+struct Block {
+    start: u32,
+    end: u32,
+    session_type: Option<SessionType>,
+    completed: bool,
+}
+
+impl Block {
+    fn from_record(record: &SessionRecord) -> Option<Self> {
+        let started = DateTime::parse_from_rfc3339(&record.started_at)
+            .ok()?
+            .with_timezone(&Local);
+        let start = started.num_seconds_from_midnight();
+        // Sessions running past midnight are cut at the end of the day
+        let end = (start + record.duration_seconds.max(0) as u32).min(24 * 3600);
+        Some(Self {
+            start,
+            end,
+            session_type: SessionType::from_db_str(&record.session_type),
+            completed: record.completed,
+        })
+    }
+}
+
+impl StatsView {
+    /// Today's sessions against the clock, idle time shows as gaps
+    pub(crate) fn show_timeline_card(&self, ui: &mut Ui, theme: &Theme, width: f32) {
+        let t = crate::i18n::tr();
+        let inner_width = width - 32.0; // Account for Card padding (16 * 2)
+        let blocks: Vec<Block> = self
+            .today_sessions
+            .iter()
+            .filter_map(Block::from_record)
+            .collect();
+
+        Card::new().show(ui, theme, |ui| {
+            ui.set_width(inner_width);
+
+            ui.label(
+                egui::RichText::new(t.stats.today_timeline)
+                    .size(13.0)
+                    .strong()
+                    .color(theme.text_primary),
+            );
+
+            ui.add_space(12.0);
+
+            if blocks.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.label(
+                        egui::RichText::new(t.stats.no_sessions)
+                            .size(13.0)
+                            .color(theme.text_muted),
+                    );
+                });
+                return;
+            }
+
+            draw_timeline(ui, theme, &blocks, inner_width);
+        });
+    }
+}
+
+/// Visible hours: from the first session to the last one or now, whole hours
+fn visible_range(blocks: &[Block], now: u32) -> (u32, u32) {
+    let first = blocks.iter().map(|b| b.start).min().unwrap_or(now);
+    let last = blocks.iter().map(|b| b.end).max().unwrap_or(now).max(now);
+    let from = first / 3600;
+    let to = last.div_ceil(3600).clamp(from + 1, 24);
+    (from * 3600, to * 3600)
+}
+
+fn draw_timeline(ui: &mut Ui, theme: &Theme, blocks: &[Block], width: f32) {
+    let now = Local::now().num_seconds_from_midnight();
+    let (from, to) = visible_range(blocks, now);
+    let span = (to - from) as f32;
+
+    let strip_height = 22.0;
+    let (rect, response) =
+        ui.allocate_exact_size(vec2(width, strip_height + 16.0), egui::Sense::hover());
+    let strip = Rect::from_min_size(rect.min, vec2(width, strip_height));
+    let x_at = |seconds: u32| strip.left() + (seconds.clamp(from, to) - from) as f32 / span * width;
+    let summary = blocks
+        .iter()
+        .map(|b| format!("{}–{}", clock(b.start), clock(b.end)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    crate::ui::a11y::describe(&response, egui::WidgetType::Label, &summary);
+    let painter = ui.painter();
+
+    // Idle background
+    painter.rect_filled(strip, 4.0, theme.bg_tertiary);
+
+    let mut hovered = None;
+    for block in blocks {
+        let left = x_at(block.start);
+        // Keep very short sessions visible
+        let right = x_at(block.end).max(left + 2.0);
+        let block_rect = Rect::from_min_max(
+            egui::pos2(left, strip.top()),
+            egui::pos2(right, strip.bottom()),
+        );
+
+        let base = match block.session_type {
+            Some(st) => theme.session_gradient(st).0,
+            None => theme.text_muted,
+        };
+        let color = if block.completed {
+            base
+        } else {
+            Theme::with_alpha(base, 110)
+        };
+        painter.rect_filled(block_rect, 2.0, color);
+
+        if response
+            .hover_pos()
+            .is_some_and(|pos| block_rect.contains(pos))
+        {
+            hovered = Some(block);
+        }
+    }
+
+    // Hour ticks, thinned out so the labels don't overlap
+    let hours = (to - from) / 3600;
+    let step = (hours as f32 * 28.0 / width).ceil().max(1.0) as u32;
+    for hour in (from / 3600..=to / 3600).step_by(step as usize) {
+        let x = x_at(hour * 3600);
+        painter.line_segment(
+            [
+                egui::pos2(x, strip.bottom()),
+                egui::pos2(x, strip.bottom() + 3.0),
+            ],
+            egui::Stroke::new(1.0, theme.border_default),
+        );
+        let align = if hour * 3600 == from {
+            egui::Align2::LEFT_BOTTOM
+        } else if hour * 3600 == to {
+            egui::Align2::RIGHT_BOTTOM
+        } else {
+            egui::Align2::CENTER_BOTTOM
+        };
+        painter.text(
+            egui::pos2(x, rect.bottom()),
+            align,
+            format!("{:02}", hour % 24),
+            egui::FontId::proportional(9.0),
+            theme.text_muted,
+        );
+    }
+
+    // Current time marker
+    if now > from && now < to {
+        let x = x_at(now);
+        painter.line_segment(
+            [
+                egui::pos2(x, strip.top() - 2.0),
+                egui::pos2(x, strip.bottom() + 2.0),
+            ],
+            egui::Stroke::new(1.5, theme.text_primary),
+        );
+    }
+
+    if let Some(block) = hovered {
+        let t = crate::i18n::tr();
+        let label = block
+            .session_type
+            .map(|st| t.session_label(st))
+            .unwrap_or_default();
+        let status = if block.completed {
+            t.stats.completed
+        } else {
+            t.stats.interrupted
+        };
+        response.on_hover_text(format!(
+            "{}–{} {} · {} min · {}",
+            clock(block.start),
+            clock(block.end),
+            label,
+            (block.end - block.start) / 60,
+            status
+        ));
+    }
+}
+
+/// Seconds since midnight as "HH:MM"
+fn clock(seconds: u32) -> String {
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds.min(24 * 3600 - 1), 0)
+        .map(|time| time.format("%H:%M").to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,369 @@
+//! Tasks view: the list of tasks to spend pomodoros on
+//!
+//! Lists the queued tasks with their estimated and completed pomodoros. The
+//! first task is the active one: focus sessions count towards it.
+
+use egui::{vec2, RichText, Ui};
+
+use super::components::{draw_icon, Icon};
+use super::theme::Theme;
+use crate::data::todo::QueuedTask;
+
+/// Largest estimate that can be set for a task
+pub(super) const MAX_ESTIMATE: u32 = 20;
+
+/// Actions that can be triggered from the tasks view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TasksAction {
+    GoBack,
+    /// Create a task and queue it with an estimate
+    Add {
+        title: String,
+        estimate: u32,
+    },
+    /// Mark a task as done
+    Complete {
+        id: i64,
+        todo_id: i64,
+    },
+    /// Make a task the active one
+    Select(i64),
+    /// Change the estimated pomodoros of a task
+    SetEstimate {
+        id: i64,
+        estimate: u32,
+    },
+    /// New task order, by queue id
+    Reorder(Vec<i64>),
+}
+
+/// Wrapper for DnD: implements DragDropItem via unique queue id
+#[derive(Hash)]
+struct DndTask {
+    id: i64,
+    todo_id: i64,
+    title: String,
+    completed_pomodoros: u32,
+    planned_pomodoros: u32,
+}
+
+impl DndTask {
+    fn from_task(task: &QueuedTask) -> Self {
+        Self {
+            id: task.id,
+            todo_id: task.todo_id,
+            title: task.title.clone(),
+            completed_pomodoros: task.completed_pomodoros,
+            planned_pomodoros: task.planned_pomodoros,
+        }
+    }
+}
+
+/// The tasks view with its input state
+pub struct TasksView {
+    new_title: String,
+    new_estimate: u32,
+}
+
+impl Default for TasksView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TasksView {
+    pub fn new() -> Self {
+        Self {
+            new_title: String::new(),
+            new_estimate: 1,
+        }
+    }
+
+    /// Show the tasks view and return the triggered actions
+    pub fn show(&mut self, ui: &mut Ui, theme: &Theme, tasks: &[QueuedTask]) -> Vec<TasksAction> {
+        let mut actions = Vec::new();
+        let t = crate::i18n::tr();
+
+        // Header
+        ui.horizontal(|ui| {
+            let (arrow_rect, arrow_resp) =
+                ui.allocate_exact_size(vec2(18.0, 18.0), egui::Sense::click());
+            let ir = egui::Rect::from_center_size(arrow_rect.center(), vec2(12.0, 12.0));
+            draw_icon(ui, Icon::ArrowLeft, ir, theme.text_secondary);
+            if arrow_resp.clicked() {
+                actions.push(TasksAction::GoBack);
+            }
+
+            ui.label(
+                RichText::new(t.tasks.title)
+                    .size(14.0)
+                    .strong()
+                    .color(theme.text_primary),
+            );
+
+            if !tasks.is_empty() {
+                let (done, planned) = tasks.iter().fold((0, 0), |(d, p), task| {
+                    (d + task.completed_pomodoros, p + task.planned_pomodoros)
+                });
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.label(
+                        RichText::new(format!("{}/{} pom.", done, planned))
+                            .size(12.0)
+                            .color(theme.text_muted),
+                    );
+                });
+            }
+        });
+
+        ui.add_space(theme.spacing_sm);
+
+        // New task row
+        ui.horizontal(|ui| {
+            let estimate_width = 64.0;
+            let input = ui.add(
+                egui::TextEdit::singleline(&mut self.new_title)
+                    .hint_text(t.tasks.add_hint)
+                    .desired_width((ui.available_width() - estimate_width).max(80.0)),
+            );
+            if let Some(estimate) = estimate_stepper(ui, theme, self.new_estimate) {
+                self.new_estimate = estimate;
+            }
+
+            let submitted = input.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if submitted && !self.new_title.trim().is_empty() {
+                actions.push(TasksAction::Add {
+                    title: self.new_title.trim().to_string(),
+                    estimate: self.new_estimate,
+                });
+                self.new_title.clear();
+                self.new_estimate = 1;
+                input.request_focus();
+            }
+        });
+
+        ui.add_space(theme.spacing_sm);
+
+        if tasks.is_empty() {
+            ui.add_space(theme.spacing_xl);
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    RichText::new(t.tasks.empty)
+                        .size(16.0)
+                        .color(theme.text_muted),
+                );
+                ui.add_space(theme.spacing_sm);
+                ui.label(
+                    RichText::new(t.tasks.empty_hint)
+                        .size(13.0)
+                        .color(theme.text_muted),
+                );
+            });
+            return actions;
+        }
+
+        let mut items: Vec<DndTask> = tasks.iter().map(DndTask::from_task).collect();
+
+        let hover_bg = if theme.is_light {
+            egui::Color32::from_rgba_unmultiplied(0, 0, 0, 12)
+        } else {
+            egui::Color32::from_rgba_unmultiplied(255, 255, 255, 8)
+        };
+
+        let available_height = ui.available_height();
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .max_height(available_height)
+            .show(ui, |ui| {
+                let response = egui_dnd::dnd(ui, "tasks_dnd").show_vec(
+                    &mut items,
+                    |ui, item, handle, state| {
+                        let is_active = state.index == 0 && !state.dragged;
+
+                        let item_resp = egui::Frame::none()
+                            .inner_margin(egui::Margin::symmetric(4.0, 4.0))
+                            .rounding(theme.rounding_sm)
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    // Drag handle
+                                    handle.ui(ui, |ui| {
+                                        let (handle_rect, _) = ui.allocate_exact_size(
+                                            vec2(12.0, 18.0),
+                                            egui::Sense::hover(),
+                                        );
+                                        let ir = egui::Rect::from_center_size(
+                                            handle_rect.center(),
+                                            vec2(10.0, 10.0),
+                                        );
+                                        draw_icon(
+                                            ui,
+                                            Icon::GripVertical,
+                                            ir,
+                                            theme.text_muted.linear_multiply(0.4),
+                                        );
+                                    });
+
+                                    // Check off
+                                    let (check_rect, check_resp) = ui.allocate_exact_size(
+                                        vec2(18.0, 18.0),
+                                        egui::Sense::click(),
+                                    );
+                                    let ir = egui::Rect::from_center_size(
+                                        check_rect.center(),
+                                        vec2(13.0, 13.0),
+                                    );
+                                    let check_color = if check_resp.hovered() {
+                                        theme.success
+                                    } else {
+                                        theme.text_muted
+                                    };
+                                    draw_icon(
+                                        ui,
+                                        if check_resp.hovered() {
+                                            Icon::CheckSquare
+                                        } else {
+                                            Icon::Square
+                                        },
+                                        ir,
+                                        check_color,
+                                    );
+                                    if check_resp.clicked() {
+                                        actions.push(TasksAction::Complete {
+                                            id: item.id,
+                                            todo_id: item.todo_id,
+                                        });
+                                    }
+
+                                    // Title, click to make it the active task
+                                    let mut title =
+                                        RichText::new(&item.title).size(13.0).color(if is_active {
+                                            theme.text_primary
+                                        } else {
+                                            theme.text_secondary
+                                        });
+                                    if is_active {
+                                        title = title.strong();
+                                    }
+                                    ui.with_layout(
+                                        egui::Layout::right_to_left(egui::Align::Center),
+                                        |ui| {
+                                            if let Some(estimate) = estimate_stepper_with_progress(
+                                                ui,
+                                                theme,
+                                                item.completed_pomodoros,
+                                                item.planned_pomodoros,
+                                            ) {
+                                                actions.push(TasksAction::SetEstimate {
+                                                    id: item.id,
+                                                    estimate,
+                                                });
+                                            }
+                                            ui.with_layout(
+                                                egui::Layout::left_to_right(egui::Align::Center),
+                                                |ui| {
+                                                    let label = ui.add(
+                                                        egui::Label::new(title)
+                                                            .truncate()
+                                                            .sense(egui::Sense::click()),
+                                                    );
+                                                    if !is_active {
+                                                        if label.clicked() {
+                                                            actions
+                                                                .push(TasksAction::Select(item.id));
+                                                        }
+                                                        label.on_hover_text(t.tasks.select_hint);
+                                                    }
+                                                },
+                                            );
+                                        },
+                                    );
+                                });
+                            });
+
+                        let item_rect = item_resp.response.rect;
+
+                        if ui.rect_contains_pointer(item_rect) {
+                            ui.painter()
+                                .rect_filled(item_rect, theme.rounding_sm, hover_bg);
+                        }
+
+                        // Active task accent stripe
+                        if is_active {
+                            ui.painter().line_segment(
+                                [
+                                    egui::pos2(item_rect.left() + 1.0, item_rect.top() + 3.0),
+                                    egui::pos2(item_rect.left() + 1.0, item_rect.bottom() - 3.0),
+                                ],
+                                egui::Stroke::new(2.5, theme.accent.solid()),
+                            );
+                        }
+                    },
+                );
+
+                if response.final_update().is_some() {
+                    let ids: Vec<i64> = items.iter().map(|item| item.id).collect();
+                    actions.push(TasksAction::Reorder(ids));
+                }
+            });
+
+        actions
+    }
+}
+
+/// "- n +" control for an estimate, returns the new value when changed
+fn estimate_stepper(ui: &mut Ui, theme: &Theme, estimate: u32) -> Option<u32> {
+    let mut result = None;
+    if step_button(ui, theme, Icon::Minus) && estimate > 1 {
+        result = Some(estimate - 1);
+    }
+    ui.label(
+        RichText::new(estimate.to_string())
+            .size(12.0)
+            .color(theme.text_secondary),
+    );
+    if step_button(ui, theme, Icon::Plus) && estimate < MAX_ESTIMATE {
+        result = Some(estimate + 1);
+    }
+    result
+}
+
+/// "- done/estimate +" control, laid out right to left
+fn estimate_stepper_with_progress(
+    ui: &mut Ui,
+    theme: &Theme,
+    completed: u32,
+    estimate: u32,
+) -> Option<u32> {
+    let mut result = None;
+    if step_button(ui, theme, Icon::Plus) && estimate < MAX_ESTIMATE {
+        result = Some(estimate + 1);
+    }
+    let color = if completed >= estimate {
+        theme.success
+    } else {
+        theme.text_secondary
+    };
+    ui.label(
+        RichText::new(format!("{}/{}", completed, estimate))
+            .size(11.0)
+            .color(color),
+    )
+    .on_hover_text(crate::i18n::tr().tasks.estimate_hint);
+    // Never estimate below what has already been done
+    if step_button(ui, theme, Icon::Minus) && estimate > completed.max(1) {
+        result = Some(estimate - 1);
+    }
+    result
+}
+
+/// Small icon button, returns whether it was clicked
+pub(super) fn step_button(ui: &mut Ui, theme: &Theme, icon: Icon) -> bool {
+    let (rect, resp) = ui.allocate_exact_size(vec2(14.0, 18.0), egui::Sense::click());
+    let ir = egui::Rect::from_center_size(rect.center(), vec2(9.0, 9.0));
+    let color = if resp.hovered() {
+        theme.text_primary
+    } else {
+        theme.text_muted.linear_multiply(0.6)
+    };
+    draw_icon(ui, icon, ir, color);
+    resp.clicked()
+}
@@ -2,76 +2,27 @@
 
 mod colors;
 
+use egui::ecolor::Hsva;
 use egui::Color32;
-use serde::{Deserialize, Serialize};
 
+pub use crate::data::{AccentColor, ThemeMode};
 use crate::platform::is_windows_11;
 
-/// Theme mode options
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
-pub enum ThemeMode {
-    #[default]
-    System,
-    Light,
-    Dark,
-    // Catppuccin flavors
-    CatppuccinLatte,
-    CatppuccinFrappe,
-    CatppuccinMacchiato,
-    CatppuccinMocha,
-}
-
-impl ThemeMode {
-    pub fn all() -> &'static [ThemeMode] {
-        &[
-            ThemeMode::System,
-            ThemeMode::Light,
-            ThemeMode::Dark,
-            ThemeMode::CatppuccinLatte,
-            ThemeMode::CatppuccinFrappe,
-            ThemeMode::CatppuccinMacchiato,
-            ThemeMode::CatppuccinMocha,
-        ]
-    }
-
-    pub fn name(&self) -> &'static str {
-        crate::i18n::tr().theme_name(*self)
-    }
-}
-
-/// Accent color options
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
-pub enum AccentColor {
-    #[default]
-    Blue,
-    Purple,
-    Rose,
-    Emerald,
-    Amber,
-    Cyan,
-    // Retro terminal colors
-    Matrix,     // Green phosphor
-    RetroAmber, // Amber CRT
-    Synthwave,  // Pink/cyan retro
-}
-
 impl AccentColor {
-    pub fn all() -> &'static [AccentColor] {
-        &[
-            AccentColor::Blue,
-            AccentColor::Purple,
-            AccentColor::Rose,
-            AccentColor::Emerald,
-            AccentColor::Amber,
-            AccentColor::Cyan,
-            AccentColor::Matrix,
-            AccentColor::RetroAmber,
-            AccentColor::Synthwave,
-        ]
-    }
-
-    pub fn name(&self) -> &'static str {
-        crate::i18n::tr().accent_name(*self)
+    /// Gradient for a custom color: the end is shifted ~30° along the hue
+    /// wheel, and both ends are darkened on light backgrounds
+    fn custom_gradient(r: u8, g: u8, b: u8, light: bool) -> (Color32, Color32) {
+        let mut start = Hsva::from(Color32::from_rgb(r, g, b));
+        if light {
+            start.v *= 0.85;
+        }
+        let end = Hsva::new(
+            (start.h + 30.0 / 360.0).fract(),
+            start.s,
+            (start.v * 1.1).min(1.0),
+            1.0,
+        );
+        (Color32::from(start), Color32::from(end))
     }
 
     pub fn gradient(&self) -> (Color32, Color32) {
@@ -113,6 +64,7 @@ impl AccentColor {
                 Color32::from_rgb(255, 0, 128), // Hot pink
                 Color32::from_rgb(0, 255, 255), // Cyan
             ),
+            &AccentColor::Custom(r, g, b) => Self::custom_gradient(r, g, b, false),
         }
     }
 
@@ -162,6 +114,7 @@ impl AccentColor {
                 Color32::from_rgb(0, 0, 0),    // Pure black
                 Color32::from_rgb(20, 10, 30), // Black with purple tint
             ),
+            &AccentColor::Custom(r, g, b) => Self::custom_gradient(r, g, b, true),
         }
     }
 
@@ -170,14 +123,6 @@ impl AccentColor {
         self.gradient_light().0
     }
 
-    /// Check if this is a retro/TUI style
-    pub fn is_retro(&self) -> bool {
-        matches!(
-            self,
-            AccentColor::Matrix | AccentColor::RetroAmber | AccentColor::Synthwave
-        )
-    }
-
     /// Get glow color for retro styles
     pub fn glow(&self) -> Color32 {
         match self {
@@ -552,7 +497,9 @@ impl Theme {
     pub fn from_mode(mode: ThemeMode, accent: AccentColor) -> Self {
         match mode {
             ThemeMode::Light => Self::new_light(accent),
-            ThemeMode::Dark => Self::new(accent),
+            // The app picks light or dark from the time of day, this is
+            // only the starting point
+            ThemeMode::Dark | ThemeMode::Schedule => Self::new(accent),
             ThemeMode::System => {
                 if crate::platform::system_uses_light_theme() {
                     Self::new_light(accent)
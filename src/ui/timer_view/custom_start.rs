@@ -0,0 +1,94 @@
+//! One-off session of any length, started right from the timer view
+
+use egui::{vec2, Align, FontId, Key, Layout, RichText, Ui};
+
+use super::{TimerAction, TimerView};
+use crate::core::Session;
+use crate::ui::theme::Theme;
+
+/// Longest one-off session, in minutes
+const MAX_CUSTOM_MINUTES: u32 = 240;
+
+impl TimerView {
+    /// "Custom…" link that expands into a minutes field and a start button.
+    /// Only offered while the timer isn't running.
+    pub(super) fn show_custom_start(
+        &mut self,
+        ui: &mut Ui,
+        session: &Session,
+        theme: &Theme,
+        font: FontId,
+    ) -> Option<TimerAction> {
+        let t = crate::i18n::tr();
+        if session.timer().is_running() {
+            self.custom_minutes = None;
+            return None;
+        }
+
+        let Some(mut minutes) = self.custom_minutes else {
+            let link = ui
+                .add(
+                    egui::Label::new(
+                        RichText::new(t.timer.custom)
+                            .font(font)
+                            .color(theme.text_muted),
+                    )
+                    .sense(egui::Sense::click()),
+                )
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .on_hover_text(t.timer.custom_hint);
+            if link.clicked() {
+                // Start from the length of the current session
+                let current = session.timer().total_duration().as_secs() / 60;
+                self.custom_minutes = Some((current as u32).clamp(1, MAX_CUSTOM_MINUTES));
+            }
+            return None;
+        };
+
+        let mut action = None;
+        let mut close = false;
+        let height = font.size + 10.0;
+        ui.allocate_ui_with_layout(
+            vec2(170.0, height),
+            Layout::left_to_right(Align::Center),
+            |ui| {
+                let field = ui.add(
+                    egui::DragValue::new(&mut minutes)
+                        .range(1..=MAX_CUSTOM_MINUTES)
+                        .speed(0.25)
+                        .suffix(format!(" {}", t.common.min)),
+                );
+                let submitted = field.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+
+                let start = ui.add(
+                    egui::Button::new(
+                        RichText::new(t.timer.start)
+                            .font(font.clone())
+                            .color(theme.text_primary),
+                    )
+                    .fill(theme.bg_tertiary)
+                    .rounding(theme.rounding_sm),
+                );
+                if start.clicked() || submitted {
+                    action = Some(TimerAction::StartCustom { minutes });
+                    close = true;
+                }
+
+                let cancel = ui
+                    .add(
+                        egui::Button::new(RichText::new("×").font(font).color(theme.text_muted))
+                            .frame(false),
+                    )
+                    .on_hover_text(t.common.cancel);
+                // Consumed so Escape doesn't also hide the window
+                let escape = ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, Key::Escape));
+                if cancel.clicked() || escape {
+                    close = true;
+                }
+            },
+        );
+
+        self.custom_minutes = (!close).then_some(minutes);
+        action
+    }
+}
@@ -0,0 +1,96 @@
+//! One-line intent asked for before a work session starts
+
+use egui::{vec2, Align, FontId, Key, Layout, RichText, Ui};
+
+use super::{TimerAction, TimerView};
+use crate::core::Session;
+use crate::ui::theme::Theme;
+
+/// Longest intent kept, in characters
+const MAX_INTENT_CHARS: usize = 80;
+
+impl TimerView {
+    /// Open the intent prompt instead of starting right away
+    pub fn ask_intent(&mut self) {
+        if self.intent_draft.is_none() {
+            self.intent_draft = Some(String::new());
+            self.focus_intent = true;
+        }
+    }
+
+    /// Whether the intent prompt is open
+    pub(super) fn asking_intent(&self) -> bool {
+        self.intent_draft.is_some()
+    }
+
+    /// Text field for the intent with a start button. Starting needs a
+    /// non-empty intent, Escape or × closes the prompt without starting.
+    pub(super) fn show_intent_prompt(
+        &mut self,
+        ui: &mut Ui,
+        session: &Session,
+        theme: &Theme,
+        font: FontId,
+    ) -> Option<TimerAction> {
+        let t = crate::i18n::tr();
+        let mut draft = self.intent_draft.take()?;
+        // Started some other way meanwhile
+        if session.timer().is_running() {
+            return None;
+        }
+
+        let mut action = None;
+        let mut close = false;
+        let height = font.size + 10.0;
+        ui.allocate_ui_with_layout(
+            vec2(260.0, height),
+            Layout::left_to_right(Align::Center),
+            |ui| {
+                let field = ui.add(
+                    egui::TextEdit::singleline(&mut draft)
+                        .hint_text(t.timer.intent_hint)
+                        .char_limit(MAX_INTENT_CHARS)
+                        .font(font.clone())
+                        .desired_width(170.0),
+                );
+                if std::mem::take(&mut self.focus_intent) {
+                    field.request_focus();
+                }
+                let submitted = field.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+
+                let intent = draft.trim();
+                let start = ui.add_enabled(
+                    !intent.is_empty(),
+                    egui::Button::new(
+                        RichText::new(t.timer.start)
+                            .font(font.clone())
+                            .color(theme.text_primary),
+                    )
+                    .fill(theme.bg_tertiary)
+                    .rounding(theme.rounding_sm),
+                );
+                if !intent.is_empty() && (start.clicked() || submitted) {
+                    action = Some(TimerAction::StartWithIntent {
+                        intent: intent.to_string(),
+                    });
+                    close = true;
+                }
+
+                let cancel = ui
+                    .add(
+                        egui::Button::new(RichText::new("×").font(font).color(theme.text_muted))
+                            .frame(false),
+                    )
+                    .on_hover_text(t.common.cancel);
+                // Consumed so Escape doesn't also hide the window
+                let escape = ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, Key::Escape));
+                if cancel.clicked() || escape {
+                    close = true;
+                }
+            },
+        );
+
+        self.intent_draft = (!close).then_some(draft);
+        action
+    }
+}
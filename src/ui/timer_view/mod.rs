@@ -1,16 +1,19 @@
 //! Compact timer view (main widget) - Responsive layout with TUI mode
 
+mod custom_start;
+mod intent;
 mod tui_style;
 
 use egui::{vec2, Align, Layout, RichText, Ui};
 
+use super::a11y;
 use super::components::{CircularProgress, Icon, IconButton};
 use super::theme::Theme;
-use crate::core::Session;
+use crate::core::{Session, TimerState};
 use crate::data::todo::QueuedTask;
 
 /// Actions that can be triggered from the timer view
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TimerAction {
     Toggle,
     Skip,
@@ -19,6 +22,18 @@ pub enum TimerAction {
     OpenSettings,
     OpenTodo,
     OpenQueue,
+    /// Start a one-off session of the current type
+    StartCustom {
+        minutes: u32,
+    },
+    /// Set the length of the idle session without starting it
+    SetDuration {
+        minutes: u32,
+    },
+    /// Start the work session with the intent typed into the prompt
+    StartWithIntent {
+        intent: String,
+    },
 }
 
 // Layout constants for responsive sizing
@@ -40,11 +55,41 @@ const TIME_OFFSET_WRAP: f32 = 1000.0;
 /// The compact timer view with responsive layout
 pub struct TimerView {
     time_offset: f32,
+    /// Length being edited in the custom start field, `None` while closed
+    custom_minutes: Option<u32>,
+    /// Intent being typed before a work session, `None` while not asked
+    intent_draft: Option<String>,
+    /// Focus the intent field on the next frame
+    focus_intent: bool,
+    /// Pomodoros done and planned today, while there's a plan for today
+    pub plan_progress: Option<(i32, i32)>,
 }
 
 impl TimerView {
     pub fn new() -> Self {
-        Self { time_offset: 0.0 }
+        Self {
+            time_offset: 0.0,
+            custom_minutes: None,
+            intent_draft: None,
+            focus_intent: false,
+            plan_progress: None,
+        }
+    }
+
+    /// Today's plan against what was done, with its color
+    fn plan_line(&self, theme: &Theme) -> Option<(String, egui::Color32)> {
+        let (done, planned) = self.plan_progress?;
+        let text = crate::i18n::tr()
+            .plan
+            .progress
+            .replace("{done}", &done.to_string())
+            .replace("{planned}", &planned.to_string());
+        let color = if done >= planned {
+            theme.success
+        } else {
+            theme.text_muted
+        };
+        Some((text, color))
     }
 
     /// Show the timer view and return any action triggered
@@ -58,6 +103,7 @@ impl TimerView {
         window_opacity: u32,
         current_task: Option<&QueuedTask>,
         queue: &[QueuedTask],
+        intent: Option<&str>,
     ) -> Option<TimerAction> {
         // Update animation time (wrap to avoid float precision loss)
         self.time_offset =
@@ -65,7 +111,7 @@ impl TimerView {
 
         // Check if we should use TUI/retro style
         if theme.accent.is_retro() {
-            self.show_tui_style(ui, session, theme, pulse, current_task, queue)
+            self.show_tui_style(ui, session, theme, pulse, current_task, queue, intent)
         } else {
             self.show_modern_style(
                 ui,
@@ -75,6 +121,7 @@ impl TimerView {
                 window_opacity,
                 current_task,
                 queue,
+                intent,
             )
         }
     }
@@ -90,6 +137,7 @@ impl TimerView {
         window_opacity: u32,
         current_task: Option<&QueuedTask>,
         queue: &[QueuedTask],
+        intent: Option<&str>,
     ) -> Option<TimerAction> {
         let t = crate::i18n::tr();
         let mut action = None;
@@ -133,7 +181,15 @@ impl TimerView {
                         theme.bg_tertiary
                     };
 
-                    CircularProgress::new(progress)
+                    // Idle ring can be dragged to set the length, a turn is an hour
+                    let idle = session.timer().state() == TimerState::Idle;
+                    let dragged = CircularProgress::new(progress)
+                        .with_drag_steps(if idle { 60 } else { 0 }, t.timer.drag_hint)
+                        .with_label(format!(
+                            "{}, {}",
+                            t.session_label(session.session_type()),
+                            session.timer().remaining_formatted()
+                        ))
                         .with_radius(timer_radius)
                         .with_thickness(timer_thickness)
                         .with_colors(start_color, end_color)
@@ -157,11 +213,16 @@ impl TimerView {
                                 } else {
                                     base_label_color
                                 };
-                                ui.label(
+                                // Announced when the session changes
+                                let session_label = ui.label(
                                     egui::RichText::new(t.session_label(session.session_type()))
                                         .font(modern_font(label_font_size))
                                         .color(label_color),
                                 );
+                                a11y::announce_changes(
+                                    &session_label,
+                                    t.session_label(session.session_type()),
+                                );
 
                                 ui.add_space(2.0);
 
@@ -176,6 +237,9 @@ impl TimerView {
                                 );
                             });
                         });
+                    if let Some(minutes) = dragged {
+                        action = Some(TimerAction::SetDuration { minutes });
+                    }
 
                     ui.add_space(spacing * 0.5);
 
@@ -208,7 +272,14 @@ impl TimerView {
                                     let play_icon =
                                         if is_running { Icon::Pause } else { Icon::Play };
 
+                                    let play_label = if is_running {
+                                        t.timer.pause
+                                    } else {
+                                        t.timer.start
+                                    };
+
                                     if IconButton::new(play_icon)
+                                        .with_label(play_label)
                                         .with_size(control_btn_size)
                                         .with_icon_scale(0.45)
                                         .filled(false)
@@ -229,6 +300,7 @@ impl TimerView {
                                 Layout::left_to_right(Align::Center),
                                 |ui| {
                                     if IconButton::new(Icon::SkipForward)
+                                        .with_label(t.timer.skip)
                                         .with_size(control_btn_size)
                                         .with_icon_scale(0.45)
                                         .filled(false)
@@ -254,6 +326,28 @@ impl TimerView {
                     // Session progress dots
                     self.show_session_dots(ui, session, theme, min_dim, opacity_factor);
 
+                    let custom_font = modern_font((min_dim * DOT_CAPTION_FACTOR).clamp(10.0, 14.0));
+                    let start = if self.asking_intent() {
+                        self.show_intent_prompt(ui, session, theme, custom_font)
+                    } else {
+                        self.show_custom_start(ui, session, theme, custom_font)
+                    };
+                    if let Some(start) = start {
+                        action = Some(start);
+                    }
+
+                    if let Some(intent) = intent {
+                        ui.label(
+                            RichText::new(format!("\u{201C}{}\u{201D}", intent))
+                                .font(modern_font(13.0))
+                                .color(theme.text_secondary),
+                        );
+                    }
+
+                    if let Some((plan, color)) = self.plan_line(theme) {
+                        ui.label(RichText::new(plan).font(modern_font(11.0)).color(color));
+                    }
+
                     ui.add_space(spacing * 0.5);
 
                     // Current task display (if pinned)
@@ -415,7 +509,7 @@ impl TimerView {
                         ui.add_space(spacing * 0.25);
 
                         // Request repaint during animation
-                        if hover_alpha < 1.0 {
+                        if hover_alpha > 0.0 && hover_alpha < 1.0 {
                             ui.ctx().request_repaint();
                         }
                     }
@@ -445,7 +539,16 @@ impl TimerView {
         // Calculate total width and allocate centered rect
         let dots_width = dot_spacing * (total - 1) as f32 + dot_radius * 2.0;
         let height = dot_radius * 3.0;
-        let (rect, _) = ui.allocate_exact_size(vec2(dots_width, height), egui::Sense::hover());
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(dots_width, height), egui::Sense::hover());
+        let t = crate::i18n::tr();
+        let caption = format!(
+            "{} {}/{}",
+            t.timer.session,
+            session.current_session_in_cycle(),
+            session.total_sessions_in_cycle()
+        );
+        a11y::describe(&response, egui::WidgetType::Label, &caption);
 
         let black = egui::Color32::from_rgb(20, 20, 20);
 
@@ -499,18 +602,12 @@ impl TimerView {
             theme.text_muted
         };
 
-        let t = crate::i18n::tr();
         let modern_font =
             |size: f32| egui::FontId::new(size, egui::FontFamily::Name("Modern".into()));
         ui.label(
-            egui::RichText::new(format!(
-                "{} {}/{}",
-                t.timer.session,
-                session.current_session_in_cycle(),
-                session.total_sessions_in_cycle()
-            ))
-            .font(modern_font(caption_size))
-            .color(text_color),
+            egui::RichText::new(caption)
+                .font(modern_font(caption_size))
+                .color(text_color),
         );
     }
 }
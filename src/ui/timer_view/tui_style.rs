@@ -8,6 +8,7 @@ use crate::ui::theme::Theme;
 
 impl TimerView {
     /// TUI/Retro style with ASCII art
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn show_tui_style(
         &mut self,
         ui: &mut Ui,
@@ -16,6 +17,7 @@ impl TimerView {
         _pulse: f32,
         current_task: Option<&QueuedTask>,
         queue: &[QueuedTask],
+        intent: Option<&str>,
     ) -> Option<TimerAction> {
         let t = crate::i18n::tr();
         let mut action = None;
@@ -65,11 +67,12 @@ impl TimerView {
 
                     let session_label = t.session_label(session.session_type());
 
-                    ui.label(
+                    let label = ui.label(
                         egui::RichText::new(format!("{} {}", spinner, session_label))
                             .font(FontId::monospace(label_font_size * 1.2))
                             .color(accent),
                     );
+                    crate::ui::a11y::announce_changes(&label, session_label);
 
                     ui.add_space(spacing * 0.3);
 
@@ -257,6 +260,24 @@ impl TimerView {
 
                     ui.add_space(spacing * 0.3);
 
+                    let custom_font = FontId::monospace(btn_font_size * 0.9);
+                    let start = if self.asking_intent() {
+                        self.show_intent_prompt(ui, session, theme, custom_font)
+                    } else {
+                        self.show_custom_start(ui, session, theme, custom_font)
+                    };
+                    if let Some(start) = start {
+                        action = Some(start);
+                    }
+
+                    if let Some(intent) = intent {
+                        ui.label(
+                            egui::RichText::new(format!("# {}", intent))
+                                .font(FontId::monospace(btn_font_size))
+                                .color(theme.text_primary),
+                        );
+                    }
+
                     // Current task display
                     if let Some(task) = current_task {
                         ui.label(
@@ -271,6 +292,14 @@ impl TimerView {
                         );
                     }
 
+                    if let Some((plan, color)) = self.plan_line(theme) {
+                        ui.label(
+                            egui::RichText::new(format!("[{}]", plan))
+                                .font(FontId::monospace(btn_font_size * 0.9))
+                                .color(color),
+                        );
+                    }
+
                     // Navigation buttons - fade in/out on hover
                     let is_hovered = ui.ctx().input(|i| {
                         i.pointer
@@ -399,18 +428,13 @@ impl TimerView {
                         ui.add_space(spacing * 0.3 * nav_alpha);
 
                         // Request repaint during animation
-                        if nav_alpha < 1.0 {
+                        if nav_alpha > 0.0 && nav_alpha < 1.0 {
                             ui.ctx().request_repaint();
                         }
                     }
                 });
             }); // ScrollArea
 
-        // Request continuous repaint for animations
-        if is_running {
-            ui.ctx().request_repaint();
-        }
-
         action
     }
 }
@@ -1,6 +1,6 @@
 //! Minimal window title bar - appears on hover
 
-use egui::{vec2, Color32, CursorIcon, Rect, Rounding, Sense, Ui};
+use egui::{pos2, vec2, Align2, Color32, CursorIcon, Rect, Rounding, Sense, Ui};
 
 use super::animations::InteractionState;
 use super::components::{draw_icon, Icon};
@@ -9,15 +9,24 @@ use super::theme::Theme;
 /// Title bar button type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TitleBarButton {
+    MiniMode,
+    Mute,
     AlwaysOnTop,
     Minimize,
     Maximize,
     Close,
+    /// A profile picked from the switcher, by index
+    Profile(usize),
 }
 
 /// Minimal title bar component - shows controls on hover
 pub struct TitleBar {
     id_prefix: &'static str,
+    /// Whether the mini mode and mute buttons are shown (main window only)
+    mini_button: bool,
+    mini_state: InteractionState,
+    mute_state: InteractionState,
+    profile_state: InteractionState,
     pin_state: InteractionState,
     minimize_state: InteractionState,
     maximize_state: InteractionState,
@@ -27,12 +36,19 @@ pub struct TitleBar {
 
 impl TitleBar {
     pub fn new() -> Self {
-        Self::with_id("main")
+        Self {
+            mini_button: true,
+            ..Self::with_id("main")
+        }
     }
 
     pub fn with_id(id_prefix: &'static str) -> Self {
         Self {
             id_prefix,
+            mini_button: false,
+            mini_state: InteractionState::new(),
+            mute_state: InteractionState::new(),
+            profile_state: InteractionState::new(),
             pin_state: InteractionState::new(),
             minimize_state: InteractionState::new(),
             maximize_state: InteractionState::new(),
@@ -44,7 +60,8 @@ impl TitleBar {
     /// Height of the title bar (minimal)
     pub const HEIGHT: f32 = 32.0;
 
-    /// Render the title bar
+    /// Render the title bar. `profiles` is the active profile's name and
+    /// all saved profiles, offered in a switcher on the left.
     /// Returns: (should_drag, clicked_button)
     pub fn show(
         &mut self,
@@ -52,6 +69,8 @@ impl TitleBar {
         theme: &Theme,
         is_maximized: bool,
         is_always_on_top: bool,
+        is_muted: bool,
+        profiles: Option<(&str, &[String])>,
     ) -> (bool, Option<TitleBarButton>) {
         let mut clicked_button = None;
         let mut should_drag = false;
@@ -92,16 +111,29 @@ impl TitleBar {
 
         // Window control buttons (right side) - only visible on hover
         let button_size = vec2(40.0, Self::HEIGHT);
-        let buttons_width = button_size.x * 4.0; // Pin + Minimize + Maximize + Close
+        // [Mini + Mute] + Pin + Minimize + Maximize + Close
+        let button_count = if self.mini_button { 6.0 } else { 4.0 };
+        let buttons_width = button_size.x * button_count;
 
         let buttons_rect = Rect::from_min_size(
             title_bar_rect.right_top() - vec2(buttons_width, 0.0),
             vec2(buttons_width, Self::HEIGHT),
         );
 
+        // Profile switcher (left side)
+        let mut profile_width = 0.0;
+        if let Some((active, names)) = profiles.filter(|(_, names)| !names.is_empty()) {
+            let (width, picked) =
+                self.draw_profile_switcher(ui, title_bar_rect, theme, active, names, hover_t);
+            profile_width = width;
+            if picked.is_some() {
+                clicked_button = picked;
+            }
+        }
+
         // Drag area is everything except the buttons
         let drag_rect = Rect::from_min_max(
-            title_bar_rect.min,
+            title_bar_rect.min + vec2(profile_width, 0.0),
             title_bar_rect.max - vec2(buttons_width, 0.0),
         );
 
@@ -124,11 +156,49 @@ impl TitleBar {
         if hover_t > 0.01 {
             let mut button_x = buttons_rect.left();
 
+            // Mini mode button
+            if self.mini_button {
+                let mini_rect =
+                    Rect::from_min_size(egui::pos2(button_x, buttons_rect.top()), button_size);
+                if let Some(btn) = self.draw_button(
+                    ui,
+                    mini_rect,
+                    TitleBarButton::MiniMode,
+                    theme,
+                    is_maximized,
+                    hover_t,
+                ) {
+                    clicked_button = Some(btn);
+                }
+                button_x += button_size.x;
+
+                // Mute button
+                let mute_rect =
+                    Rect::from_min_size(egui::pos2(button_x, buttons_rect.top()), button_size);
+                if let Some(btn) = self.draw_toggle_button(
+                    ui,
+                    mute_rect,
+                    TitleBarButton::Mute,
+                    theme,
+                    is_muted,
+                    hover_t,
+                ) {
+                    clicked_button = Some(btn);
+                }
+                button_x += button_size.x;
+            }
+
             // Always on top (pin) button
             let pin_rect =
                 Rect::from_min_size(egui::pos2(button_x, buttons_rect.top()), button_size);
-            if let Some(btn) = self.draw_pin_button(ui, pin_rect, theme, is_always_on_top, hover_t)
-            {
+            if let Some(btn) = self.draw_toggle_button(
+                ui,
+                pin_rect,
+                TitleBarButton::AlwaysOnTop,
+                theme,
+                is_always_on_top,
+                hover_t,
+            ) {
                 clicked_button = Some(btn);
             }
             button_x += button_size.x;
@@ -180,6 +250,9 @@ impl TitleBar {
 
         // Request repaint if animating
         if self.bar_hover_state.is_animating()
+            || self.mini_state.is_animating()
+            || self.mute_state.is_animating()
+            || self.profile_state.is_animating()
             || self.pin_state.is_animating()
             || self.minimize_state.is_animating()
             || self.maximize_state.is_animating()
@@ -202,6 +275,9 @@ impl TitleBar {
         bar_hover_t: f32,
     ) -> Option<TitleBarButton> {
         let button_id = match button_type {
+            TitleBarButton::MiniMode => "btn_mini",
+            TitleBarButton::Mute => "btn_mute",
+            TitleBarButton::Profile(_) => "btn_profile",
             TitleBarButton::AlwaysOnTop => "btn_pin",
             TitleBarButton::Minimize => "btn_minimize",
             TitleBarButton::Maximize => "btn_maximize",
@@ -215,6 +291,9 @@ impl TitleBar {
 
         // Get the state for this button type
         let state = match button_type {
+            TitleBarButton::MiniMode => &mut self.mini_state,
+            TitleBarButton::Mute => &mut self.mute_state,
+            TitleBarButton::Profile(_) => &mut self.profile_state,
             TitleBarButton::AlwaysOnTop => &mut self.pin_state,
             TitleBarButton::Minimize => &mut self.minimize_state,
             TitleBarButton::Maximize => &mut self.maximize_state,
@@ -265,7 +344,11 @@ impl TitleBar {
         let icon_rect = Rect::from_center_size(rect.center(), vec2(icon_size, icon_size));
 
         let icon = match button_type {
-            TitleBarButton::AlwaysOnTop => Icon::Pin, // handled by draw_pin_button
+            TitleBarButton::MiniMode => Icon::MiniMode,
+            // Handled by draw_toggle_button and draw_profile_switcher
+            TitleBarButton::Mute => Icon::Volume2,
+            TitleBarButton::AlwaysOnTop => Icon::Pin,
+            TitleBarButton::Profile(_) => Icon::ChevronDown,
             TitleBarButton::Minimize => Icon::Minimize,
             TitleBarButton::Maximize => {
                 if is_maximized {
@@ -281,6 +364,19 @@ impl TitleBar {
 
         if response.hovered() {
             ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+            if button_type == TitleBarButton::MiniMode {
+                egui::show_tooltip_at_pointer(
+                    ui.ctx(),
+                    egui::LayerId::new(
+                        egui::Order::Tooltip,
+                        egui::Id::new(self.id_prefix).with("tooltip_layer"),
+                    ),
+                    egui::Id::new(self.id_prefix).with("mini_tooltip"),
+                    |ui| {
+                        ui.label(crate::i18n::tr().common.mini_mode);
+                    },
+                );
+            }
         }
 
         if response.clicked() {
@@ -290,31 +386,130 @@ impl TitleBar {
         }
     }
 
-    fn draw_pin_button(
+    /// Name of the active profile with a menu of the saved ones. Stays drawn
+    /// while the menu is open. Returns its width and the profile picked.
+    fn draw_profile_switcher(
+        &mut self,
+        ui: &mut Ui,
+        bar_rect: Rect,
+        theme: &Theme,
+        active: &str,
+        names: &[String],
+        bar_hover_t: f32,
+    ) -> (f32, Option<TitleBarButton>) {
+        let font = theme.font_small();
+        let text_width = ui
+            .fonts(|f| f.layout_no_wrap(active.to_string(), font.clone(), Color32::WHITE))
+            .size()
+            .x;
+        let rect = Rect::from_min_size(bar_rect.min, vec2(text_width + 36.0, Self::HEIGHT));
+
+        let response = ui.interact(
+            rect,
+            egui::Id::new(self.id_prefix).with("profile_switcher"),
+            Sense::click(),
+        );
+        let popup_id = egui::Id::new(self.id_prefix).with("profile_popup");
+        if response.clicked() {
+            ui.memory_mut(|m| m.toggle_popup(popup_id));
+        }
+        let open = ui.memory(|m| m.is_popup_open(popup_id));
+
+        self.profile_state.update(
+            response.hovered() || open,
+            response.is_pointer_button_down_on(),
+        );
+        let alpha = if open { 1.0 } else { bar_hover_t };
+        if alpha > 0.01 {
+            let base = Theme::lerp_color(
+                theme.text_muted,
+                theme.text_primary,
+                self.profile_state.hover_t(),
+            );
+            let color = Theme::with_alpha(base, (alpha * 255.0) as u8);
+            let text_pos = pos2(rect.left() + 12.0, rect.center().y);
+            ui.painter()
+                .text(text_pos, Align2::LEFT_CENTER, active, font, color);
+            let icon_rect = Rect::from_center_size(
+                pos2(text_pos.x + text_width + 10.0, rect.center().y),
+                vec2(10.0, 10.0),
+            );
+            draw_icon(ui, Icon::ChevronDown, icon_rect, color);
+        }
+        if response.hovered() {
+            ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+        }
+
+        let mut picked = None;
+        egui::popup_below_widget(
+            ui,
+            popup_id,
+            &response,
+            egui::PopupCloseBehavior::CloseOnClick,
+            |ui| {
+                ui.set_min_width(140.0);
+                for (i, name) in names.iter().enumerate() {
+                    if ui.selectable_label(name.as_str() == active, name).clicked() {
+                        picked = Some(TitleBarButton::Profile(i));
+                    }
+                }
+            },
+        );
+
+        (rect.width(), picked)
+    }
+
+    /// Button that stays highlighted while its option is on (pin, mute)
+    fn draw_toggle_button(
         &mut self,
         ui: &mut Ui,
         rect: Rect,
+        button_type: TitleBarButton,
         theme: &Theme,
-        is_pinned: bool,
+        is_on: bool,
         bar_hover_t: f32,
     ) -> Option<TitleBarButton> {
+        let t = crate::i18n::tr();
+        let (button_id, state, icon, tooltip_id, tooltip_text) = match button_type {
+            TitleBarButton::Mute => (
+                "mute_button",
+                &mut self.mute_state,
+                if is_on { Icon::VolumeX } else { Icon::Volume2 },
+                "mute_tooltip",
+                if is_on {
+                    t.common.unmute_sounds
+                } else {
+                    t.common.mute_sounds
+                },
+            ),
+            _ => (
+                "pin_button",
+                &mut self.pin_state,
+                if is_on { Icon::Pin } else { Icon::PinOff },
+                "pin_tooltip",
+                if is_on {
+                    t.common.unpin_window
+                } else {
+                    t.common.pin_window
+                },
+            ),
+        };
         let response = ui.interact(
             rect,
-            egui::Id::new(self.id_prefix).with("pin_button"),
+            egui::Id::new(self.id_prefix).with(button_id),
             Sense::click(),
         );
 
-        self.pin_state
-            .update(response.hovered(), response.is_pointer_button_down_on());
-        let hover_t = self.pin_state.hover_t();
-        let press_t = self.pin_state.press_t();
+        state.update(response.hovered(), response.is_pointer_button_down_on());
+        let hover_t = state.hover_t();
+        let press_t = state.press_t();
 
         // Button opacity based on bar hover
         let base_alpha = (bar_hover_t * 255.0) as u8;
 
-        // Background color - highlight when pinned
+        // Background color - highlight when on
         let accent_color = theme.accent.solid();
-        let bg_color = if is_pinned {
+        let bg_color = if is_on {
             Color32::from_rgba_unmultiplied(
                 accent_color.r(),
                 accent_color.g(),
@@ -334,8 +529,8 @@ impl TitleBar {
         // Draw background
         ui.painter().rect_filled(rect, 0.0, bg_color);
 
-        // Icon color with opacity - brighter when pinned
-        let icon_color = if is_pinned {
+        // Icon color with opacity - brighter when on
+        let icon_color = if is_on {
             Color32::from_rgba_unmultiplied(
                 accent_color.r(),
                 accent_color.g(),
@@ -351,26 +546,19 @@ impl TitleBar {
         let icon_size = 12.0 - press_t * 1.0;
         let icon_rect = Rect::from_center_size(rect.center(), vec2(icon_size, icon_size));
 
-        let icon = if is_pinned { Icon::Pin } else { Icon::PinOff };
         draw_icon(ui, icon, icon_rect, icon_color);
 
         if response.hovered() {
             ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
 
             // Show tooltip
-            let t = crate::i18n::tr();
-            let tooltip_text = if is_pinned {
-                t.common.unpin_window
-            } else {
-                t.common.pin_window
-            };
             egui::show_tooltip_at_pointer(
                 ui.ctx(),
                 egui::LayerId::new(
                     egui::Order::Tooltip,
                     egui::Id::new(self.id_prefix).with("tooltip_layer"),
                 ),
-                egui::Id::new(self.id_prefix).with("pin_tooltip"),
+                egui::Id::new(self.id_prefix).with(tooltip_id),
                 |ui| {
                     ui.label(tooltip_text);
                 },
@@ -378,7 +566,7 @@ impl TitleBar {
         }
 
         if response.clicked() {
-            Some(TitleBarButton::AlwaysOnTop)
+            Some(button_type)
         } else {
             None
         }
@@ -274,9 +274,14 @@ pub fn render_todo_viewport(ctx: &egui::Context, bridge: &TodoBridge) {
                 });
 
                 // Titlebar
-                let (drag, button) =
-                    vui.titlebar
-                        .show(ui, &snapshot.theme, is_maximized, snapshot.is_always_on_top);
+                let (drag, button) = vui.titlebar.show(
+                    ui,
+                    &snapshot.theme,
+                    is_maximized,
+                    snapshot.is_always_on_top,
+                    false,
+                    None,
+                );
 
                 if drag {
                     ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
@@ -303,6 +308,10 @@ pub fn render_todo_viewport(ctx: &egui::Context, bridge: &TodoBridge) {
                                 },
                             ));
                         }
+                        // Not shown on the todo window
+                        TitleBarButton::MiniMode
+                        | TitleBarButton::Mute
+                        | TitleBarButton::Profile(_) => {}
                     }
                 }
 
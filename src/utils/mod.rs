@@ -27,3 +27,61 @@ pub fn format_hours(seconds: i64) -> String {
     let hours = seconds as f64 / 3600.0;
     format!("{:.1}h", hours)
 }
+
+/// Parse a human duration like `10m`, `90s`, `1h30m` or `25` (minutes) into
+/// seconds, `None` when it's malformed or too long to count
+pub fn parse_duration(input: &str) -> Option<u64> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return None;
+    }
+    // A bare number means minutes
+    if let Ok(minutes) = input.parse::<u64>() {
+        return minutes.checked_mul(60);
+    }
+
+    let mut total = 0u64;
+    let mut number = String::new();
+    for c in input.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let value: u64 = number.parse().ok()?;
+        number.clear();
+        let secs = match c {
+            'h' => value.checked_mul(3600)?,
+            'm' => value.checked_mul(60)?,
+            's' => value,
+            _ => return None,
+        };
+        total = total.checked_add(secs)?;
+    }
+    // Trailing digits without a unit are not allowed ("1h30")
+    number.is_empty().then_some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("25"), Some(25 * 60));
+        assert_eq!(parse_duration("10m"), Some(600));
+        assert_eq!(parse_duration("90s"), Some(90));
+        assert_eq!(parse_duration("1h30m"), Some(5400));
+        assert_eq!(parse_duration("1h30"), None);
+        assert_eq!(parse_duration("soon"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn test_parse_duration_out_of_range() {
+        assert_eq!(parse_duration(&u64::MAX.to_string()), None);
+        assert_eq!(parse_duration("99999999999999999999m"), None);
+        assert_eq!(parse_duration(&format!("{}h", u64::MAX / 3600 + 1)), None);
+        assert_eq!(parse_duration(&format!("{}s1s", u64::MAX)), None);
+        assert_eq!(parse_duration(&format!("{}s", u64::MAX)), Some(u64::MAX));
+    }
+}